@@ -13,6 +13,7 @@ import (
 	"github.com/study/crypto-accounts/pkgs/bip39"
 	"github.com/study/crypto-accounts/pkgs/bip44"
 	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
 	"github.com/study/crypto-accounts/pkgs/crypto/rsa"
 	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
 )
@@ -152,7 +153,7 @@ func cmdGenerate(args []string) {
 }
 
 func generateFromPubkey(chainID address.ChainID, pubkeyHex, format string) {
-	pubkey, err := hex.DecodeString(pubkeyHex)
+	pubkey, err := encoding.DecodeHex(pubkeyHex)
 	if err != nil {
 		fmt.Printf("Error: invalid public key hex: %v\n", err)
 		os.Exit(1)
@@ -431,14 +432,9 @@ func isEd25519Chain(chainID address.ChainID) bool {
 
 // generateFromPrivkey generates an address from a private key
 func generateFromPrivkey(chainID address.ChainID, privkeyHex, format string) {
-	privkey, err := hex.DecodeString(privkeyHex)
+	privkey, err := encoding.DecodeHexSize(privkeyHex, 32)
 	if err != nil {
-		fmt.Printf("Error: invalid private key hex: %v\n", err)
-		os.Exit(1)
-	}
-
-	if len(privkey) != 32 {
-		fmt.Printf("Error: private key must be 32 bytes, got %d bytes\n", len(privkey))
+		fmt.Printf("Error: invalid private key: %v\n", err)
 		os.Exit(1)
 	}
 
@@ -478,7 +474,11 @@ func generateFromPrivkeyEd25519(chainID address.ChainID, privkey []byte) {
 // generateFromPrivkeySecp256k1 generates address for secp256k1 chains
 func generateFromPrivkeySecp256k1(chainID address.ChainID, privkey []byte, format string) {
 	// Derive public key from private key
-	point := secp256k1.PrivateKeyToPublicKey(privkey)
+	point, err := secp256k1.PublicKeyFromPrivateKey(privkey)
+	if err != nil {
+		fmt.Printf("Error: %v\n", err)
+		os.Exit(1)
+	}
 	compressedPubkey := secp256k1.CompressPoint(point)
 	uncompressedPubkey := secp256k1.SerializeUncompressed(point)
 
@@ -522,7 +522,6 @@ func generateFromPrivkeySecp256k1(chainID address.ChainID, privkey []byte, forma
 	// Handle special chain cases
 	var pubkey []byte
 	var addr string
-	var err error
 
 	switch chainID {
 	case address.ChainEthereum, address.ChainBSC, address.ChainPolygon,
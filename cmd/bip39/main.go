@@ -3,6 +3,7 @@ package main
 
 import (
 	"encoding/hex"
+	"errors"
 	"flag"
 	"fmt"
 	"os"
@@ -30,6 +31,12 @@ Examples:
   # Generate 24-word mnemonic
   bip39 generate --words 24
 
+  # Generate 24-word mnemonic from 100 dice rolls
+  bip39 generate --words 24 --dice "3152 6641 ..."
+
+  # Mix dice rolls with the system RNG
+  bip39 generate --words 24 --dice "3152 6641 ..." --mix
+
   # Validate mnemonic
   bip39 validate --mnemonic "abandon abandon ... about"
 
@@ -40,7 +47,10 @@ Examples:
   bip39 seed --mnemonic "abandon abandon ... about" --passphrase "TREZOR"
 
   # Convert entropy to mnemonic
-  bip39 entropy --hex 00000000000000000000000000000000
+  bip39 entropy --hex 9e885d952ad362caeb4efe34a8e91bd2
+
+  # Reproduce a test vector from patterned entropy
+  bip39 entropy --hex 00000000000000000000000000000000 --force
 `
 
 func main() {
@@ -71,6 +81,9 @@ func cmdGenerate(args []string) {
 	fs := flag.NewFlagSet("generate", flag.ExitOnError)
 	words := fs.Int("words", 12, "Number of words (12, 15, 18, 21, or 24)")
 	passphrase := fs.String("passphrase", "", "Optional passphrase for seed generation")
+	dice := fs.String("dice", "", "Derive entropy from dice rolls (1-6) instead of the system RNG")
+	coins := fs.String("coins", "", "Derive entropy from coin flips (H/T) instead of the system RNG")
+	mix := fs.Bool("mix", false, "Mix the dice rolls or coin flips with the system RNG instead of using them alone")
 	fs.Parse(args)
 
 	// Map word count to entropy bits
@@ -88,7 +101,24 @@ func cmdGenerate(args []string) {
 		os.Exit(1)
 	}
 
-	entropy, err := bip39.GenerateEntropy(bits)
+	var entropy []byte
+	var err error
+	switch {
+	case *dice != "":
+		entropy, err = bip39.EntropyFromDice(*dice, bits)
+	case *coins != "":
+		entropy, err = bip39.EntropyFromCoinFlips(*coins, bits)
+	default:
+		entropy, err = bip39.GenerateEntropy(bits)
+	}
+	if err == nil && *mix && (*dice != "" || *coins != "") {
+		entropy, err = bip39.GenerateEntropyMixed(entropy, bits)
+	}
+	if errors.Is(err, bip39.ErrNotEnoughRolls) {
+		fmt.Printf("Error: %v\n", err)
+		fmt.Printf("%d words need at least %d dice rolls or %d coin flips.\n", *words, bip39.MinDiceRolls(bits), bip39.MinCoinFlips(bits))
+		os.Exit(1)
+	}
 	if err != nil {
 		fmt.Printf("Error: failed to generate entropy: %v\n", err)
 		os.Exit(1)
@@ -205,6 +235,7 @@ func cmdEntropy(args []string) {
 	fs := flag.NewFlagSet("entropy", flag.ExitOnError)
 	hexStr := fs.String("hex", "", "Entropy in hexadecimal")
 	mnemonic := fs.String("mnemonic", "", "Mnemonic phrase to convert to entropy")
+	force := fs.Bool("force", false, "Accept weak entropy such as all zero bytes")
 	fs.Parse(args)
 
 	if *hexStr == "" && *mnemonic == "" {
@@ -223,7 +254,12 @@ func cmdEntropy(args []string) {
 			os.Exit(1)
 		}
 
-		mnemonic, err := bip39.NewMnemonic(entropy)
+		mnemonic, err := bip39.NewMnemonicFromUserEntropy(entropy, *force)
+		if errors.Is(err, bip39.ErrWeakEntropy) {
+			fmt.Printf("Error: %v\n", err)
+			fmt.Println("Use --force to convert it anyway, e.g. for a test vector.")
+			os.Exit(1)
+		}
 		if err != nil {
 			fmt.Printf("Error: %v\n", err)
 			os.Exit(1)
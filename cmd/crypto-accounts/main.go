@@ -0,0 +1,449 @@
+// crypto-accounts CLI: key, mnemonic and address generation with JSON output
+package main
+
+import (
+	"context"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"flag"
+	"fmt"
+	"io"
+	"os"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/vanity"
+)
+
+const usage = `crypto-accounts CLI
+
+Usage:
+  crypto-accounts <command> [options]
+
+Commands:
+  mnemonic new        Generate a new mnemonic
+  mnemonic validate   Validate a mnemonic
+  derive              Derive an account from a mnemonic
+  address             Generate an address from a public key
+  vanity              Search for a key whose address matches a prefix, suffix or regex
+  chains              List chains supported by derive --index
+
+All commands write JSON to stdout. Errors are written to stderr as {"error": "..."}.
+Commands that take a mnemonic also accept --input <file> (or - for stdin) holding
+{"mnemonic": "...", "passphrase": "..."}, which keeps secrets out of shell history.
+
+Examples:
+  # Generate a 24-word mnemonic
+  crypto-accounts mnemonic new --words 24
+
+  # Derive the first Ethereum account
+  crypto-accounts derive --chain evm --mnemonic "abandon abandon ... about"
+
+  # Derive at an explicit path
+  crypto-accounts derive --chain evm --path "m/44'/60'/0'/0/0" --input wallet.json
+
+  # Osmosis address from a compressed public key
+  crypto-accounts address --chain cosmos --hrp osmo --pubkey 02...
+
+  # Ethereum key whose address starts with 0xbeef
+  crypto-accounts vanity --chain eth --prefix 0xbeef
+`
+
+func main() {
+	if len(os.Args) < 2 {
+		fmt.Print(usage)
+		os.Exit(1)
+	}
+
+	switch os.Args[1] {
+	case "mnemonic":
+		cmdMnemonic(os.Args[2:])
+	case "derive":
+		cmdDerive(os.Args[2:])
+	case "address":
+		cmdAddress(os.Args[2:])
+	case "vanity":
+		cmdVanity(os.Args[2:])
+	case "chains":
+		cmdChains()
+	case "help", "-h", "--help":
+		fmt.Print(usage)
+	default:
+		fmt.Printf("Unknown command: %s\n\n", os.Args[1])
+		fmt.Print(usage)
+		os.Exit(1)
+	}
+}
+
+func cmdMnemonic(args []string) {
+	if len(args) < 1 {
+		fail(fmt.Errorf("usage: crypto-accounts mnemonic <new|validate> [options]"))
+	}
+
+	switch args[0] {
+	case "new":
+		fs := flag.NewFlagSet("mnemonic new", flag.ExitOnError)
+		words := fs.Int("words", 12, "Number of words (12, 15, 18, 21, or 24)")
+		fs.Parse(args[1:])
+
+		if *words < 12 || *words > 24 || *words%3 != 0 {
+			fail(fmt.Errorf("invalid word count %d: must be 12, 15, 18, 21, or 24", *words))
+		}
+
+		entropy, err := bip39.GenerateEntropy(*words / 3 * 32)
+		if err != nil {
+			fail(err)
+		}
+		mnemonic, err := bip39.NewMnemonic(entropy)
+		if err != nil {
+			fail(err)
+		}
+
+		writeJSON(map[string]any{
+			"mnemonic": mnemonic,
+			"words":    *words,
+		})
+
+	case "validate":
+		fs := flag.NewFlagSet("mnemonic validate", flag.ExitOnError)
+		mnemonicFlag := fs.String("mnemonic", "", "Mnemonic phrase")
+		input := fs.String("input", "", "JSON file with the mnemonic (- for stdin)")
+		fs.Parse(args[1:])
+
+		secret := readSecret(*input, *mnemonicFlag, "")
+		result := map[string]any{"valid": true}
+		if _, err := bip39.MnemonicToEntropy(secret.Mnemonic); err != nil {
+			result = map[string]any{"valid": false, "reason": err.Error()}
+		}
+		writeJSON(result)
+
+	default:
+		fail(fmt.Errorf("unknown mnemonic command: %s", args[0]))
+	}
+}
+
+func cmdDerive(args []string) {
+	fs := flag.NewFlagSet("derive", flag.ExitOnError)
+	chain := fs.String("chain", "", "Chain ID (btc, eth, sol, ...; evm is an alias for eth)")
+	path := fs.String("path", "", "Derivation path (default: the chain's standard path)")
+	index := fs.Uint("index", 0, "Account index on the chain's standard path")
+	count := fs.Uint("count", 1, "Number of consecutive indexes to derive")
+	hrp := fs.String("hrp", "", "Bech32 prefix for Cosmos SDK chains (e.g. osmo)")
+	mnemonicFlag := fs.String("mnemonic", "", "BIP-39 mnemonic phrase")
+	passphrase := fs.String("passphrase", "", "BIP-39 passphrase")
+	input := fs.String("input", "", "JSON file with mnemonic and passphrase (- for stdin)")
+	private := fs.Bool("private", false, "Include private keys in the output")
+	fs.Parse(args)
+
+	chainID := parseChain(*chain)
+	secret := readSecret(*input, *mnemonicFlag, *passphrase)
+
+	if *path != "" {
+		exported, err := deriveAtPath(chainID, *hrp, secret, *path, *private)
+		if err != nil {
+			fail(err)
+		}
+		writeJSON(exported)
+		return
+	}
+
+	if *hrp != "" {
+		fail(fmt.Errorf("--hrp requires --path"))
+	}
+
+	wallet, err := account.NewWalletFromMnemonic(secret.Mnemonic, secret.Passphrase)
+	if err != nil {
+		fail(err)
+	}
+	defer wallet.Destroy()
+
+	accounts := make([]account.AccountJSON, 0, *count)
+	for i := uint32(0); i < uint32(*count); i++ {
+		acc, err := wallet.Account(chainID, uint32(*index)+i)
+		if err != nil {
+			fail(err)
+		}
+		accounts = append(accounts, account.ExportAccount(acc, *private))
+	}
+
+	if len(accounts) == 1 {
+		writeJSON(accounts[0])
+		return
+	}
+	writeJSON(accounts)
+}
+
+// deriveAtPath derives a key at an arbitrary path: SLIP-10 for Ed25519 chains,
+// BIP-32 for everything else.
+func deriveAtPath(chainID address.ChainID, hrp string, secret secretInput, path string, private bool) (account.AccountJSON, error) {
+	if !bip39.ValidateMnemonic(secret.Mnemonic) {
+		return account.AccountJSON{}, bip39.ErrInvalidMnemonic
+	}
+	seed := bip39.NewSeed(secret.Mnemonic, secret.Passphrase)
+
+	parsed, err := bip32.ParsePath(path)
+	if err != nil {
+		return account.AccountJSON{}, err
+	}
+
+	var privateKey, publicKey []byte
+	if isEd25519Chain(chainID) {
+		privateKey, publicKey, err = ed25519.DeriveKeyFromPath(seed, parsed)
+		if err != nil {
+			return account.AccountJSON{}, err
+		}
+	} else {
+		master, err := bip32.NewMasterKey(seed)
+		if err != nil {
+			return account.AccountJSON{}, err
+		}
+		key, err := master.DeriveFromPath(parsed)
+		if err != nil {
+			return account.AccountJSON{}, err
+		}
+		privateKey = key.PrivateKeyBytes()
+		publicKey = secp256k1PublicKey(chainID, privateKey)
+	}
+
+	addr, err := generateAddress(chainID, hrp, publicKey)
+	if err != nil {
+		return account.AccountJSON{}, err
+	}
+
+	exported := account.AccountJSON{
+		Chain:     chainID,
+		Path:      parsed.String(),
+		Address:   addr,
+		PublicKey: hex.EncodeToString(publicKey),
+	}
+	if private {
+		exported.PrivateKey = hex.EncodeToString(privateKey)
+	}
+	return exported, nil
+}
+
+func cmdAddress(args []string) {
+	fs := flag.NewFlagSet("address", flag.ExitOnError)
+	chain := fs.String("chain", "", "Chain ID (btc, eth, cosmos, ...)")
+	pubkey := fs.String("pubkey", "", "Public key in hex")
+	hrp := fs.String("hrp", "", "Bech32 prefix for Cosmos SDK chains (e.g. osmo)")
+	fs.Parse(args)
+
+	chainID := parseChain(*chain)
+	if *pubkey == "" {
+		fail(fmt.Errorf("--pubkey is required"))
+	}
+
+	publicKey, err := hex.DecodeString(*pubkey)
+	if err != nil {
+		fail(fmt.Errorf("invalid public key hex: %w", err))
+	}
+
+	addr, err := generateAddress(chainID, *hrp, publicKey)
+	if err != nil {
+		fail(err)
+	}
+
+	writeJSON(map[string]any{
+		"chain":     chainID,
+		"address":   addr,
+		"publicKey": *pubkey,
+	})
+}
+
+func cmdVanity(args []string) {
+	fs := flag.NewFlagSet("vanity", flag.ExitOnError)
+	chain := fs.String("chain", "", "Chain ID (eth, btc, sol, ...)")
+	prefix := fs.String("prefix", "", "Required address prefix (including any fixed prefix such as 0x)")
+	suffix := fs.String("suffix", "", "Required address suffix")
+	regex := fs.String("regex", "", "Regular expression the address must match")
+	ignoreCase := fs.Bool("ignore-case", false, "Match case-insensitively")
+	maxAttempts := fs.Uint64("max-attempts", 10_000_000, "Give up after this many keys (0 for no limit)")
+	workers := fs.Int("workers", 0, "Number of search goroutines (default: number of CPUs)")
+	pathFormat := fs.String("path-format", "", "Scan HD indexes of a mnemonic instead of random keys (e.g. \"m/44'/60'/0'/0/%d\")")
+	mnemonicFlag := fs.String("mnemonic", "", "BIP-39 mnemonic phrase for --path-format")
+	passphrase := fs.String("passphrase", "", "BIP-39 passphrase")
+	input := fs.String("input", "", "JSON file with mnemonic and passphrase (- for stdin)")
+	fs.Parse(args)
+
+	chainID := parseChain(*chain)
+	if *prefix == "" && *suffix == "" && *regex == "" {
+		fail(fmt.Errorf("--prefix, --suffix or --regex is required"))
+	}
+
+	vchain, err := vanity.ForChain(chainID)
+	if errors.Is(err, vanity.ErrUnsupportedChain) && address.IsSupported(chainID) {
+		vchain = genericVanityChain(chainID)
+	} else if err != nil {
+		fail(err)
+	}
+
+	matchers := []vanity.Matcher{vanity.Affix(*prefix, *suffix)}
+	if *regex != "" {
+		m, err := vanity.Regexp(*regex)
+		if err != nil {
+			fail(err)
+		}
+		matchers = append(matchers, m)
+	}
+	matcher := vanity.All(matchers...)
+	if *ignoreCase {
+		matcher = vanity.IgnoreCase(matcher)
+	}
+
+	source := vanity.RandomSource()
+	if *pathFormat != "" {
+		secret := readSecret(*input, *mnemonicFlag, *passphrase)
+		if !bip39.ValidateMnemonic(secret.Mnemonic) {
+			fail(bip39.ErrInvalidMnemonic)
+		}
+		source, err = vanity.NewHDSource(bip39.NewSeed(secret.Mnemonic, secret.Passphrase), *pathFormat, vchain.Curve)
+		if err != nil {
+			fail(err)
+		}
+	}
+
+	result, err := vanity.Search(context.Background(), vchain, source, matcher, vanity.Options{
+		Workers:     *workers,
+		MaxAttempts: *maxAttempts,
+	})
+	if errors.Is(err, vanity.ErrNotFound) {
+		fail(fmt.Errorf("no match after %d attempts", *maxAttempts))
+	}
+	if err != nil {
+		fail(err)
+	}
+
+	out := map[string]any{
+		"chain":      chainID,
+		"address":    result.Address,
+		"privateKey": hex.EncodeToString(result.PrivateKey),
+		"attempts":   result.Attempts,
+	}
+	if result.Path != "" {
+		out["path"] = result.Path
+	}
+	writeJSON(out)
+}
+
+// genericVanityChain builds a vanity adapter for chains without a dedicated
+// one, formatting keys with the chain's default address generator.
+func genericVanityChain(chainID address.ChainID) vanity.Chain {
+	c := vanity.Chain{ID: chainID, Curve: vanity.Secp256k1}
+	if isEd25519Chain(chainID) {
+		c.Curve = vanity.Ed25519
+	}
+	c.Address = func(privateKey []byte) (string, error) {
+		if c.Curve == vanity.Ed25519 {
+			publicKey, err := ed25519.PrivateKeyToPublicKey(privateKey)
+			if err != nil {
+				return "", err
+			}
+			return address.Generate(chainID, publicKey)
+		}
+		return address.Generate(chainID, secp256k1PublicKey(chainID, privateKey))
+	}
+	return c
+}
+
+func cmdChains() {
+	writeJSON(account.WalletChains())
+}
+
+// secretInput is the JSON accepted by --input.
+type secretInput struct {
+	Mnemonic   string `json:"mnemonic"`
+	Passphrase string `json:"passphrase"`
+}
+
+// readSecret returns the mnemonic and passphrase from --input if set, otherwise from flags.
+func readSecret(input, mnemonic, passphrase string) secretInput {
+	if input == "" {
+		if mnemonic == "" {
+			fail(fmt.Errorf("--mnemonic or --input is required"))
+		}
+		return secretInput{Mnemonic: mnemonic, Passphrase: passphrase}
+	}
+
+	var r io.Reader = os.Stdin
+	if input != "-" {
+		f, err := os.Open(input)
+		if err != nil {
+			fail(err)
+		}
+		defer f.Close()
+		r = f
+	}
+
+	var secret secretInput
+	if err := json.NewDecoder(r).Decode(&secret); err != nil {
+		fail(fmt.Errorf("invalid input JSON: %w", err))
+	}
+	if secret.Mnemonic == "" {
+		fail(fmt.Errorf("input JSON has no mnemonic"))
+	}
+	return secret
+}
+
+// generateAddress formats a public key for a chain, using a custom Bech32 prefix
+// for Cosmos SDK chains when hrp is set.
+func generateAddress(chainID address.ChainID, hrp string, publicKey []byte) (string, error) {
+	if hrp == "" {
+		return address.Generate(chainID, publicKey)
+	}
+	if _, ok := address.CosmosBasedChains()[chainID]; !ok {
+		return "", fmt.Errorf("--hrp is only supported for Cosmos SDK chains")
+	}
+	return address.NewCosmosAddressWithHRP(hrp, chainID).Generate(publicKey)
+}
+
+// secp256k1PublicKey returns the public key encoding the chain's address generator expects.
+func secp256k1PublicKey(chainID address.ChainID, privateKey []byte) []byte {
+	_, isEVM := address.EVMChains()[chainID]
+	if isEVM || chainID == address.ChainTron {
+		return secp256k1.SerializeUncompressed(secp256k1.PrivateKeyToPublicKey(privateKey))
+	}
+	return secp256k1.PrivateKeyToCompressedPublicKey(privateKey)
+}
+
+func isEd25519Chain(chainID address.ChainID) bool {
+	switch chainID {
+	case address.ChainSolana, address.ChainStellar, address.ChainAlgorand,
+		address.ChainNEAR, address.ChainAptos, address.ChainSui, address.ChainCardano:
+		return true
+	default:
+		return false
+	}
+}
+
+// parseChain normalizes a --chain value; "evm" is accepted for Ethereum.
+func parseChain(chain string) address.ChainID {
+	if chain == "" {
+		fail(fmt.Errorf("--chain is required"))
+	}
+
+	chain = strings.ToLower(chain)
+	if chain == "evm" {
+		return address.ChainEthereum
+	}
+	return address.ChainID(chain)
+}
+
+func writeJSON(v any) {
+	enc := json.NewEncoder(os.Stdout)
+	enc.SetIndent("", "  ")
+	if err := enc.Encode(v); err != nil {
+		fail(err)
+	}
+}
+
+func fail(err error) {
+	json.NewEncoder(os.Stderr).Encode(map[string]string{"error": err.Error()})
+	os.Exit(1)
+}
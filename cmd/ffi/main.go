@@ -0,0 +1,189 @@
+//go:build cgo
+
+// C bindings exposing mnemonic generation, account derivation and address
+// formatting to other languages (Swift, Kotlin/JNI, C, ...).
+//
+// Build a shared library and header with:
+//
+//	go build -buildmode=c-shared -o libcryptoaccounts.so ./cmd/ffi
+//
+// or a static library for iOS with -buildmode=c-archive. Functions return a
+// ca_status and write their result to *out. On failure *out holds the error
+// message instead. Every string written to *out must be released with
+// ca_free_string.
+package main
+
+/*
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef int32_t ca_status;
+
+enum {
+	CA_OK = 0,
+	CA_ERR_INVALID_ARGUMENT = 1,
+	CA_ERR_UNSUPPORTED_CHAIN = 2,
+	CA_ERR_DERIVATION = 3,
+};
+*/
+import "C"
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+	"unsafe"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+// main is required by -buildmode=c-shared and c-archive but never runs.
+func main() {}
+
+// ca_generate_mnemonic writes a new mnemonic with the given entropy bits
+// (128, 160, 192, 224 or 256) to *out.
+//
+//export ca_generate_mnemonic
+func ca_generate_mnemonic(bits C.int, out **C.char) C.ca_status {
+	entropy, err := bip39.GenerateEntropy(int(bits))
+	if err != nil {
+		return fail(out, C.CA_ERR_INVALID_ARGUMENT, err)
+	}
+
+	mnemonic, err := bip39.NewMnemonic(entropy)
+	if err != nil {
+		return fail(out, C.CA_ERR_INVALID_ARGUMENT, err)
+	}
+	return succeed(out, mnemonic)
+}
+
+// ca_validate_mnemonic returns 1 if the mnemonic is valid, 0 otherwise.
+//
+//export ca_validate_mnemonic
+func ca_validate_mnemonic(mnemonic *C.char) C.int {
+	return cBool(bip39.ValidateMnemonic(C.GoString(mnemonic)))
+}
+
+// ca_derive_address writes the address of the account at index on chain to *out.
+// The derivation path is the one account.Wallet uses for the chain (see
+// ca_derive_account_json to get it).
+//
+//export ca_derive_address
+func ca_derive_address(mnemonic, passphrase, chain *C.char, index C.uint32_t, out **C.char) C.ca_status {
+	acc, status, err := deriveAccount(mnemonic, passphrase, chain, index)
+	if err != nil {
+		return fail(out, status, err)
+	}
+	return succeed(out, acc.Address())
+}
+
+// ca_derive_account_json writes the account at index on chain to *out as JSON:
+// {"chain", "path", "address", "publicKey"}. The private key is never included.
+//
+//export ca_derive_account_json
+func ca_derive_account_json(mnemonic, passphrase, chain *C.char, index C.uint32_t, out **C.char) C.ca_status {
+	acc, status, err := deriveAccount(mnemonic, passphrase, chain, index)
+	if err != nil {
+		return fail(out, status, err)
+	}
+
+	data, err := json.Marshal(account.ExportAccount(acc, false))
+	if err != nil {
+		return fail(out, C.CA_ERR_DERIVATION, err)
+	}
+	return succeed(out, string(data))
+}
+
+// ca_generate_address writes the address of a raw public key on chain to *out.
+//
+//export ca_generate_address
+func ca_generate_address(chain *C.char, publicKey *C.uint8_t, publicKeyLen C.size_t, out **C.char) C.ca_status {
+	if publicKey == nil || publicKeyLen == 0 {
+		return fail(out, C.CA_ERR_INVALID_ARGUMENT, address.ErrInvalidPublicKey)
+	}
+
+	key := C.GoBytes(unsafe.Pointer(publicKey), C.int(publicKeyLen))
+	addr, err := address.NewFactory().Generate(address.ChainID(C.GoString(chain)), key)
+	if err != nil {
+		return fail(out, statusOf(err), err)
+	}
+	return succeed(out, addr)
+}
+
+// ca_validate_address returns 1 if addr is a valid address on chain, 0 otherwise.
+//
+//export ca_validate_address
+func ca_validate_address(chain, addr *C.char) C.int {
+	return cBool(address.NewFactory().Validate(address.ChainID(C.GoString(chain)), C.GoString(addr)))
+}
+
+// ca_supported_chains writes the comma-separated chains accepted by
+// ca_derive_address to *out.
+//
+//export ca_supported_chains
+func ca_supported_chains(out **C.char) C.ca_status {
+	chains := account.WalletChains()
+	names := make([]string, len(chains))
+	for i, chain := range chains {
+		names[i] = string(chain)
+	}
+	return succeed(out, strings.Join(names, ","))
+}
+
+// ca_free_string releases a string returned through an out parameter.
+// Passing NULL is a no-op.
+//
+//export ca_free_string
+func ca_free_string(s *C.char) {
+	if s != nil {
+		C.free(unsafe.Pointer(s))
+	}
+}
+
+func deriveAccount(mnemonic, passphrase, chain *C.char, index C.uint32_t) (account.ChainAccount, C.ca_status, error) {
+	if mnemonic == nil {
+		return nil, C.CA_ERR_INVALID_ARGUMENT, fmt.Errorf("mnemonic is NULL")
+	}
+
+	wallet, err := account.NewWalletFromMnemonic(C.GoString(mnemonic), C.GoString(passphrase))
+	if err != nil {
+		return nil, C.CA_ERR_INVALID_ARGUMENT, err
+	}
+
+	acc, err := wallet.Account(address.ChainID(C.GoString(chain)), uint32(index))
+	if err != nil {
+		return nil, statusOf(err), err
+	}
+	return acc, C.CA_OK, nil
+}
+
+func statusOf(err error) C.ca_status {
+	if errors.Is(err, address.ErrUnsupportedChain) {
+		return C.CA_ERR_UNSUPPORTED_CHAIN
+	}
+	return C.CA_ERR_DERIVATION
+}
+
+func succeed(out **C.char, value string) C.ca_status {
+	if out != nil {
+		*out = C.CString(value)
+	}
+	return C.CA_OK
+}
+
+func fail(out **C.char, status C.ca_status, err error) C.ca_status {
+	if out != nil {
+		*out = C.CString(err.Error())
+	}
+	return status
+}
+
+func cBool(b bool) C.int {
+	if b {
+		return 1
+	}
+	return 0
+}
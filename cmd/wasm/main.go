@@ -0,0 +1,129 @@
+//go:build js && wasm
+
+// WebAssembly bindings exposing mnemonic generation, account derivation and
+// address formatting to JavaScript.
+//
+// Build with:
+//
+//	GOOS=js GOARCH=wasm go build -o crypto-accounts.wasm ./cmd/wasm
+//
+// and load it with wasm_exec.js from $(go env GOROOT)/lib/wasm. The functions are
+// installed on globalThis.cryptoAccounts. Each returns {error: "..."} on failure.
+// Randomness comes from crypto.getRandomValues through Go's crypto/rand.
+package main
+
+import (
+	"encoding/hex"
+	"fmt"
+	"syscall/js"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+func main() {
+	js.Global().Set("cryptoAccounts", js.ValueOf(map[string]any{
+		"generateMnemonic": js.FuncOf(generateMnemonic),
+		"validateMnemonic": js.FuncOf(validateMnemonic),
+		"deriveAccount":    js.FuncOf(deriveAccount),
+		"generateAddress":  js.FuncOf(generateAddress),
+		"validateAddress":  js.FuncOf(validateAddress),
+		"supportedChains":  js.FuncOf(supportedChains),
+	}))
+
+	// Keep the Go runtime alive for callbacks
+	select {}
+}
+
+// generateMnemonic(bits = 128) -> string
+func generateMnemonic(_ js.Value, args []js.Value) any {
+	bits := 128
+	if len(args) > 0 && args[0].Type() == js.TypeNumber {
+		bits = args[0].Int()
+	}
+
+	entropy, err := bip39.GenerateEntropy(bits)
+	if err != nil {
+		return jsError(err)
+	}
+
+	mnemonic, err := bip39.NewMnemonic(entropy)
+	if err != nil {
+		return jsError(err)
+	}
+	return mnemonic
+}
+
+// validateMnemonic(mnemonic) -> bool
+func validateMnemonic(_ js.Value, args []js.Value) any {
+	if len(args) < 1 {
+		return false
+	}
+	return bip39.ValidateMnemonic(args[0].String())
+}
+
+// deriveAccount(mnemonic, passphrase, chain, index) -> {chain, path, address, publicKey}
+func deriveAccount(_ js.Value, args []js.Value) any {
+	if len(args) < 4 {
+		return jsError(fmt.Errorf("usage: deriveAccount(mnemonic, passphrase, chain, index)"))
+	}
+
+	wallet, err := account.NewWalletFromMnemonic(args[0].String(), args[1].String())
+	if err != nil {
+		return jsError(err)
+	}
+
+	acc, err := wallet.Account(address.ChainID(args[2].String()), uint32(args[3].Int()))
+	if err != nil {
+		return jsError(err)
+	}
+
+	exported := account.ExportAccount(acc, false)
+	return map[string]any{
+		"chain":     string(exported.Chain),
+		"path":      exported.Path,
+		"address":   exported.Address,
+		"publicKey": exported.PublicKey,
+	}
+}
+
+// generateAddress(chain, publicKeyHex) -> string
+func generateAddress(_ js.Value, args []js.Value) any {
+	if len(args) < 2 {
+		return jsError(fmt.Errorf("usage: generateAddress(chain, publicKeyHex)"))
+	}
+
+	publicKey, err := hex.DecodeString(args[1].String())
+	if err != nil {
+		return jsError(fmt.Errorf("invalid public key hex: %w", err))
+	}
+
+	addr, err := address.NewFactory().Generate(address.ChainID(args[0].String()), publicKey)
+	if err != nil {
+		return jsError(err)
+	}
+	return addr
+}
+
+// validateAddress(chain, address) -> bool
+func validateAddress(_ js.Value, args []js.Value) any {
+	if len(args) < 2 {
+		return false
+	}
+	return address.NewFactory().Validate(address.ChainID(args[0].String()), args[1].String())
+}
+
+// supportedChains() -> string[] of chains accepted by deriveAccount
+func supportedChains(_ js.Value, _ []js.Value) any {
+	chains := account.WalletChains()
+	result := make([]any, len(chains))
+	for i, chain := range chains {
+		result[i] = string(chain)
+	}
+	return result
+}
+
+func jsError(err error) any {
+	return map[string]any{"error": err.Error()}
+}
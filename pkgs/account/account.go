@@ -0,0 +1,104 @@
+// Package account derives chain-specific accounts (key pairs and addresses)
+// from BIP-39 mnemonics and seeds.
+//
+// secp256k1 chains use BIP-32 derivation; Ed25519 chains use SLIP-10 with
+// hardened-only paths; P-256 chains use SLIP-10 with BIP-32-style paths.
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// seedFromMnemonic validates a mnemonic and converts it into a BIP-39 seed.
+func seedFromMnemonic(mnemonic, passphrase string) ([]byte, error) {
+	if !bip39.ValidateMnemonic(mnemonic) {
+		return nil, bip39.ErrInvalidMnemonic
+	}
+	return bip39.NewSeed(mnemonic, passphrase), nil
+}
+
+// deriveEd25519 derives an Ed25519 key pair using SLIP-10 at the given path.
+func deriveEd25519(seed []byte, path string) ([]byte, keys.PublicKey, error) {
+	if len(seed) < 16 || len(seed) > 64 {
+		return nil, keys.PublicKey{}, ErrInvalidSeed
+	}
+
+	p, err := bip32.ParsePath(path)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+
+	privateKey, publicKey, err := ed25519.DeriveKeyFromPath(seed, p)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+
+	typed, err := keys.NewPublicKey(keys.Ed25519, publicKey)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+	return privateKey, typed, nil
+}
+
+// deriveP256 derives a NIST P-256 key pair using SLIP-10 at the given path.
+func deriveP256(seed []byte, path string) ([]byte, keys.PublicKey, error) {
+	if len(seed) < 16 || len(seed) > 64 {
+		return nil, keys.PublicKey{}, ErrInvalidSeed
+	}
+
+	p, err := bip32.ParsePath(path)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+
+	privateKey, publicKey, err := p256.DeriveKeyFromPath(seed, p)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+
+	typed, err := keys.NewPublicKey(keys.P256, publicKey)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+	return privateKey, typed, nil
+}
+
+// deriveSecp256k1 derives a BIP-32 secp256k1 key at the given path.
+func deriveSecp256k1(seed []byte, path string) (*bip32.ExtendedKey, error) {
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidSeed, err)
+	}
+	return master.DeriveFromPathString(path)
+}
+
+// ed25519PublicKey derives the public key of a 32-byte Ed25519 seed.
+func ed25519PublicKey(privateKey []byte) (keys.PublicKey, error) {
+	publicKey, err := ed25519.PrivateKeyToPublicKey(privateKey)
+	if err != nil {
+		return keys.PublicKey{}, err
+	}
+	return keys.NewPublicKey(keys.Ed25519, publicKey)
+}
+
+// secp256k1PublicKey derives the compressed public key of a secp256k1 private key.
+func secp256k1PublicKey(privateKey []byte) (keys.PublicKey, error) {
+	return keys.NewPublicKey(keys.Secp256k1Compressed, secp256k1.PrivateKeyToCompressedPublicKey(privateKey))
+}
+
+// uncompressedPublicKey validates a 33-byte compressed secp256k1 key and
+// returns it in the 65-byte encoding.
+func uncompressedPublicKey(compressed []byte) (keys.PublicKey, error) {
+	key, err := keys.NewPublicKey(keys.Secp256k1Compressed, compressed)
+	if err != nil {
+		return keys.PublicKey{}, err
+	}
+	return key.Uncompressed()
+}
@@ -0,0 +1,99 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// AvalanchePathFormat is the BIP-44 path for the X- and P-Chains: m/44'/9000'/0'/0/index
+// The C-Chain is EVM-compatible and uses Ethereum keys and addresses.
+const AvalanchePathFormat = "m/44'/9000'/0'/0/%d"
+
+// AvalancheAccount is a secp256k1 account for the Avalanche X- and P-Chains.
+type AvalancheAccount struct {
+	path       string
+	hrp        string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewAvalancheAccount derives the Avalanche account at m/44'/9000'/0'/0/index.
+func NewAvalancheAccount(seed []byte, index uint32) (*AvalancheAccount, error) {
+	path := fmt.Sprintf(AvalanchePathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &AvalancheAccount{
+		path:       path,
+		hrp:        address.AvalancheXChainHRP,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewAvalancheAccountFromMnemonic derives the Avalanche account at the given index from a mnemonic.
+func NewAvalancheAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*AvalancheAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewAvalancheAccount(seed, index)
+}
+
+// SetHRP sets the Bech32 network prefix (e.g., address.AvalancheFujiHRP).
+func (a *AvalancheAccount) SetHRP(hrp string) {
+	a.hrp = hrp
+}
+
+// Path returns the derivation path.
+func (a *AvalancheAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *AvalancheAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *AvalancheAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *AvalancheAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *AvalancheAccount) Chain() address.ChainID {
+	return address.ChainAvalanche
+}
+
+// Address returns the X-Chain address.
+func (a *AvalancheAccount) Address() string {
+	return a.XChainAddress()
+}
+
+// XChainAddress returns the X-Chain address ("X-avax1...").
+func (a *AvalancheAccount) XChainAddress() string {
+	addr, _ := address.NewAvalancheAddressWithHRP("X", a.hrp).Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// PChainAddress returns the P-Chain address ("P-avax1...").
+func (a *AvalancheAccount) PChainAddress() string {
+	addr, _ := address.NewAvalancheAddressWithHRP("P", a.hrp).Generate(a.publicKey.Bytes())
+	return addr
+}
@@ -0,0 +1,131 @@
+package account
+
+import (
+	"fmt"
+	"maps"
+	"math"
+	"runtime"
+	"sync"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// AccountsParallel returns the accounts at indexes [start, start+count) on a
+// chain, deriving them across workers goroutines (GOMAXPROCS when workers <= 0).
+// Results are in index order and are cached like those of Account.
+//
+// It is meant for pre-generating thousands of addresses: secp256k1 chains share
+// the cached parent node, so each index costs one non-hardened step, and those
+// steps run in parallel under a shared read lock. Destroy waits for a running
+// batch to finish.
+func (w *Wallet) AccountsParallel(chain address.ChainID, start, count uint32, workers int) ([]ChainAccount, error) {
+	accounts := make([]ChainAccount, count)
+	err := w.deriveParallel(chain, start, count, workers, func(i uint32, account ChainAccount) {
+		accounts[i] = account
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	if w.seed.Len() == 0 {
+		// Destroyed after the batch finished: wipe the keys instead of caching them
+		for _, account := range accounts {
+			account.Destroy()
+		}
+		return nil, ErrWalletDestroyed
+	}
+	for i, account := range accounts {
+		key := walletAccountKey{chain: chain, index: start + uint32(i)}
+		if cached, ok := w.accounts[key]; ok {
+			accounts[i] = cached
+			continue
+		}
+		w.accounts[key] = account
+	}
+	return accounts, nil
+}
+
+// AddressesParallel returns the addresses at indexes [start, start+count) on a
+// chain, deriving and encoding them across workers goroutines. Unlike
+// AccountsParallel it does not cache the accounts, so private keys of large
+// batches are not kept in memory.
+func (w *Wallet) AddressesParallel(chain address.ChainID, start, count uint32, workers int) ([]string, error) {
+	addresses := make([]string, count)
+	err := w.deriveParallel(chain, start, count, workers, func(i uint32, account ChainAccount) {
+		addresses[i] = account.Address()
+	})
+	if err != nil {
+		return nil, err
+	}
+	return addresses, nil
+}
+
+// deriveParallel derives indexes [start, start+count) in contiguous chunks, one
+// per worker, and calls fn with each account's offset from start. Each worker
+// derives on a private copy of the wallet's node cache. The workers run under
+// a read lock on w.mu, so Destroy cannot wipe the seed or nodes they read.
+func (w *Wallet) deriveParallel(chain address.ChainID, start, count uint32, workers int, fn func(i uint32, account ChainAccount)) error {
+	if count == 0 {
+		return nil
+	}
+	if uint64(start)+uint64(count)-1 > math.MaxUint32 {
+		return fmt.Errorf("%w: %d accounts from index %d", ErrInvalidIndex, count, start)
+	}
+	if workers <= 0 {
+		workers = runtime.GOMAXPROCS(0)
+	}
+	if uint32(workers) > count {
+		workers = int(count)
+	}
+
+	// Derive the first index under the lock so the parent node is cached
+	// before the cache is copied to the workers.
+	first, err := w.Account(chain, start)
+	if err != nil {
+		return err
+	}
+	fn(0, first)
+
+	w.mu.RLock()
+	defer w.mu.RUnlock()
+	if w.seed.Len() == 0 {
+		return ErrWalletDestroyed
+	}
+
+	chunk := (count - 1 + uint32(workers) - 1) / uint32(workers)
+	errs := make([]error, workers)
+
+	var wg sync.WaitGroup
+	for worker := 0; worker < workers; worker++ {
+		lo := 1 + uint32(worker)*chunk
+		if lo >= count {
+			break
+		}
+		hi := min(lo+chunk, count)
+
+		wg.Add(1)
+		go func(worker int, lo, hi uint32) {
+			defer wg.Done()
+
+			local := &Wallet{seed: w.seed, master: w.master, nodes: maps.Clone(w.nodes)}
+			for i := lo; i < hi; i++ {
+				account, err := local.derive(chain, start+i)
+				if err != nil {
+					errs[worker] = err
+					return
+				}
+				fn(i, account)
+			}
+		}(worker, lo, hi)
+	}
+	wg.Wait()
+
+	for _, err := range errs {
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
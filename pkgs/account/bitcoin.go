@@ -0,0 +1,145 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// BitcoinPathFormat is the derivation path: m/purpose'/0'/account'/0/index
+const BitcoinPathFormat = "m/%d'/0'/%d'/0/%d"
+
+// BitcoinAccount is a Bitcoin mainnet account with legacy and SegWit addresses.
+type BitcoinAccount struct {
+	path       string
+	scriptType ScriptType
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewBitcoinAccount derives a Bitcoin account. The script type picks the
+// purpose: 44' for P2PKH (1...), 49' for P2SH-P2WPKH (3...), 84' for P2WPKH (bc1q...).
+func NewBitcoinAccount(seed []byte, scriptType ScriptType, account, index uint32) (*BitcoinAccount, error) {
+	path := fmt.Sprintf(BitcoinPathFormat, scriptType.Purpose(), account, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &BitcoinAccount{
+		path:       path,
+		scriptType: scriptType,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewBitcoinAccountFromMnemonic derives a Bitcoin account from a mnemonic.
+func NewBitcoinAccountFromMnemonic(mnemonic, passphrase string, scriptType ScriptType, account, index uint32) (*BitcoinAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewBitcoinAccount(seed, scriptType, account, index)
+}
+
+// NewBitcoinAccountFromSeed is NewBitcoinAccount for a bip39.Seed, so that one seed
+// derived from a mnemonic serves every chain.
+func NewBitcoinAccountFromSeed(seed *bip39.Seed, scriptType ScriptType, account, index uint32) (acc *BitcoinAccount, err error) {
+	seed.Use(func(b []byte) { acc, err = NewBitcoinAccount(b, scriptType, account, index) })
+	return acc, err
+}
+
+// NewBitcoinAccountFromParsedMnemonic is NewBitcoinAccountFromMnemonic for a parsed mnemonic.
+// The seed is wiped once the account is derived.
+func NewBitcoinAccountFromParsedMnemonic(mnemonic *bip39.Mnemonic, passphrase string, scriptType ScriptType, account, index uint32) (*BitcoinAccount, error) {
+	seed, err := mnemonic.Seed(passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer seed.Destroy()
+	return NewBitcoinAccountFromSeed(seed, scriptType, account, index)
+}
+
+// CoinType returns the SLIP-44 coin type.
+func (a *BitcoinAccount) CoinType() bip44.CoinType {
+	return bip44.CoinTypeBitcoin
+}
+
+// ScriptType returns the script type the account was derived for.
+func (a *BitcoinAccount) ScriptType() ScriptType {
+	return a.scriptType
+}
+
+// Path returns the derivation path.
+func (a *BitcoinAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *BitcoinAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *BitcoinAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *BitcoinAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *BitcoinAccount) Chain() address.ChainID {
+	return address.ChainBitcoin
+}
+
+// WIF returns the private key in Wallet Import Format (compressed).
+func (a *BitcoinAccount) WIF() string {
+	var wif string
+	a.privateKey.Use(func(key []byte) { wif, _ = EncodeWIF(BitcoinWIFVersion, key, true) })
+	return wif
+}
+
+// Address returns the address matching the account's script type.
+func (a *BitcoinAccount) Address() string {
+	switch a.scriptType {
+	case ScriptP2SHP2WPKH:
+		return a.NestedSegWitAddress()
+	case ScriptP2WPKH:
+		return a.SegWitAddress()
+	default:
+		return a.LegacyAddress()
+	}
+}
+
+// LegacyAddress returns the P2PKH address (1...).
+func (a *BitcoinAccount) LegacyAddress() string {
+	addr, _ := address.NewBitcoinAddress(false).P2PKH(a.publicKey.Bytes())
+	return addr
+}
+
+// NestedSegWitAddress returns the P2SH-P2WPKH address (3...).
+func (a *BitcoinAccount) NestedSegWitAddress() string {
+	redeemScript := p2wpkhRedeemScript(address.Hash160(a.publicKey.Bytes()))
+	addr, _ := address.NewBitcoinAddress(false).P2SH(redeemScript)
+	return addr
+}
+
+// SegWitAddress returns the native SegWit P2WPKH address (bc1q...).
+func (a *BitcoinAccount) SegWitAddress() string {
+	addr, _ := address.NewBitcoinAddress(false).P2WPKH(a.publicKey.Bytes())
+	return addr
+}
@@ -0,0 +1,44 @@
+package account
+
+// ChainAccount is the behaviour shared by every chain account, so wallet code
+// can hold accounts of different chains side by side. Every ChainAccount is
+// also a WatchAccount.
+type ChainAccount interface {
+	WatchAccount
+
+	// PrivateKeyBytes returns a copy of the raw private key, or nil once
+	// destroyed. The caller should wipe the copy once done.
+	PrivateKeyBytes() []byte
+
+	// Destroy wipes the private key held by the account. Address and public
+	// key methods keep working; anything that needs the private key does not.
+	Destroy()
+}
+
+// Compile-time checks that every account type implements ChainAccount.
+var (
+	_ ChainAccount = (*AvalancheAccount)(nil)
+	_ ChainAccount = (*BitcoinAccount)(nil)
+	_ ChainAccount = (*CosmosAccount)(nil)
+	_ ChainAccount = (*DogecoinAccount)(nil)
+	_ ChainAccount = (*EVMAccount)(nil)
+	_ ChainAccount = (*FilecoinAccount)(nil)
+	_ ChainAccount = (*FlowAccount)(nil)
+	_ ChainAccount = (*HarmonyAccount)(nil)
+	_ ChainAccount = (*HederaAccount)(nil)
+	_ ChainAccount = (*ICPAccount)(nil)
+	_ ChainAccount = (*LitecoinAccount)(nil)
+	_ ChainAccount = (*MoneroAccount)(nil)
+	_ ChainAccount = (*NeoAccount)(nil)
+	_ ChainAccount = (*NostrAccount)(nil)
+	_ ChainAccount = (*RoninAccount)(nil)
+	_ ChainAccount = (*SeiAccount)(nil)
+	_ ChainAccount = (*SolanaAccount)(nil)
+	_ ChainAccount = (*StarknetAccount)(nil)
+	_ ChainAccount = (*StellarAccount)(nil)
+	_ ChainAccount = (*SuiAccount)(nil)
+	_ ChainAccount = (*TezosAccount)(nil)
+	_ ChainAccount = (*VeChainAccount)(nil)
+	_ ChainAccount = (*WavesAccount)(nil)
+	_ ChainAccount = (*XRPAccount)(nil)
+)
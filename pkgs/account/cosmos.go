@@ -0,0 +1,99 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// CosmosPathFormat is the Cosmos Hub path used by Keplr and Cosmostation: m/44'/118'/0'/0/index
+const CosmosPathFormat = "m/44'/118'/0'/0/%d"
+
+// CosmosAccount is a secp256k1 Cosmos Hub account.
+type CosmosAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewCosmosAccount derives the Cosmos Hub account at m/44'/118'/0'/0/index.
+func NewCosmosAccount(seed []byte, index uint32) (*CosmosAccount, error) {
+	path := fmt.Sprintf(CosmosPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &CosmosAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewCosmosAccountFromMnemonic derives the Cosmos Hub account at the given index from a mnemonic.
+func NewCosmosAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*CosmosAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewCosmosAccount(seed, index)
+}
+
+// NewCosmosAccountFromSeed is NewCosmosAccount for a bip39.Seed, so that one seed
+// derived from a mnemonic serves every chain.
+func NewCosmosAccountFromSeed(seed *bip39.Seed, index uint32) (acc *CosmosAccount, err error) {
+	seed.Use(func(b []byte) { acc, err = NewCosmosAccount(b, index) })
+	return acc, err
+}
+
+// NewCosmosAccountFromParsedMnemonic is NewCosmosAccountFromMnemonic for a parsed mnemonic.
+// The seed is wiped once the account is derived.
+func NewCosmosAccountFromParsedMnemonic(mnemonic *bip39.Mnemonic, passphrase string, index uint32) (*CosmosAccount, error) {
+	seed, err := mnemonic.Seed(passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer seed.Destroy()
+	return NewCosmosAccountFromSeed(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *CosmosAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *CosmosAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *CosmosAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *CosmosAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *CosmosAccount) Chain() address.ChainID {
+	return address.ChainCosmos
+}
+
+// Address returns the cosmos1... address.
+func (a *CosmosAccount) Address() string {
+	addr, _ := address.NewCosmosAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
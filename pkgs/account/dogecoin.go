@@ -0,0 +1,104 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// DogecoinPathFormat is the default BIP-44 path: m/44'/3'/account'/0/index
+const DogecoinPathFormat = "m/44'/3'/%d'/0/%d"
+
+// DogecoinAccount is a Dogecoin P2PKH account (D... addresses).
+type DogecoinAccount struct {
+	path       string
+	testnet    bool
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewDogecoinAccount derives the Dogecoin account at m/44'/3'/account'/0/index.
+func NewDogecoinAccount(seed []byte, account, index uint32) (*DogecoinAccount, error) {
+	path := fmt.Sprintf(DogecoinPathFormat, account, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &DogecoinAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewDogecoinAccountFromMnemonic derives a Dogecoin account from a mnemonic.
+func NewDogecoinAccountFromMnemonic(mnemonic, passphrase string, account, index uint32) (*DogecoinAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewDogecoinAccount(seed, account, index)
+}
+
+// SetTestnet switches the address and WIF version bytes to testnet.
+func (a *DogecoinAccount) SetTestnet(testnet bool) {
+	a.testnet = testnet
+}
+
+// CoinType returns the SLIP-44 coin type.
+func (a *DogecoinAccount) CoinType() bip44.CoinType {
+	return bip44.CoinTypeDogecoin
+}
+
+// Path returns the derivation path.
+func (a *DogecoinAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *DogecoinAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *DogecoinAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *DogecoinAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *DogecoinAccount) Chain() address.ChainID {
+	return address.ChainDogecoin
+}
+
+// Address returns the P2PKH address: Base58Check(0x1E || HASH160(pubkey)).
+func (a *DogecoinAccount) Address() string {
+	addr, _ := address.NewDogecoinAddress(a.testnet).P2PKH(a.publicKey.Bytes())
+	return addr
+}
+
+// WIF returns the private key in Dogecoin Wallet Import Format (compressed).
+func (a *DogecoinAccount) WIF() string {
+	version := DogecoinWIFVersion
+	if a.testnet {
+		version = DogecoinTestnetWIFVersion
+	}
+
+	var wif string
+	a.privateKey.Use(func(key []byte) { wif, _ = EncodeWIF(version, key, true) })
+	return wif
+}
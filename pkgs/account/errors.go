@@ -0,0 +1,34 @@
+package account
+
+import "errors"
+
+var (
+	// ErrInvalidSeed indicates the seed is too short or otherwise unusable for derivation.
+	ErrInvalidSeed = errors.New("account: invalid seed")
+
+	// ErrInvalidPrivateKey indicates a malformed or out-of-range private key.
+	ErrInvalidPrivateKey = errors.New("account: invalid private key")
+
+	// ErrInvalidPublicKey indicates a malformed public key or address for a watch-only account.
+	ErrInvalidPublicKey = errors.New("account: invalid public key")
+
+	// ErrInvalidExtendedKey indicates an extended key that cannot back a watch-only
+	// account: a private key, or a key that is not at account depth (m/purpose'/coin'/account').
+	ErrInvalidExtendedKey = errors.New("account: invalid extended public key")
+
+	// ErrInvalidIndex indicates an index range that runs past the last index (2^32-1).
+	ErrInvalidIndex = errors.New("account: invalid index range")
+
+	// ErrUnknownKeyFormat indicates a string ImportKey does not recognize as any private key format.
+	ErrUnknownKeyFormat = errors.New("account: unrecognized private key format")
+
+	// ErrWalletDestroyed indicates a Wallet used after Destroy.
+	ErrWalletDestroyed = errors.New("account: wallet destroyed")
+
+	// ErrInvalidSnapshot indicates a wallet snapshot that cannot be parsed or restored.
+	ErrInvalidSnapshot = errors.New("account: invalid wallet snapshot")
+
+	// ErrNotInSnapshot indicates an account a WatchWallet can neither find in
+	// its snapshot nor derive from an extended public key.
+	ErrNotInSnapshot = errors.New("account: account not in snapshot")
+)
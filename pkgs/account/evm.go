@@ -0,0 +1,147 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// EVMPathFormat is the BIP-44 path for EVM chains: m/44'/coinType'/0'/0/index
+const EVMPathFormat = "m/44'/%d'/0'/0/%d"
+
+// evmRegisteredCoinTypes lists EVM chains with their own SLIP-44 coin type.
+// Most wallets derive every EVM chain at coin type 60; some (e.g., ETC wallets,
+// Ledger's Theta and Fantom apps) use the chain's registered coin type instead.
+var evmRegisteredCoinTypes = map[address.ChainID]bip44.CoinType{
+	address.ChainEthereum:        bip44.CoinTypeEthereum,
+	address.ChainEthereumClassic: bip44.CoinTypeEthereumClassic,
+	address.ChainPolygon:         bip44.CoinTypePolygon,
+	address.ChainTheta:           bip44.CoinTypeTheta,
+	address.ChainFantom:          bip44.CoinTypeFantom,
+	address.ChainVeChain:         bip44.CoinTypeVeChain,
+	address.ChainHarmony:         bip44.CoinTypeHarmony,
+}
+
+// EVMAccount is a secp256k1 account on an EVM-compatible chain.
+type EVMAccount struct {
+	path       string
+	chain      address.ChainID
+	coinType   bip44.CoinType
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// EVMRegisteredCoinType returns the SLIP-44 coin type registered for an EVM chain,
+// or 60 (Ethereum) if the chain has none.
+func EVMRegisteredCoinType(chain address.ChainID) bip44.CoinType {
+	if coinType, ok := evmRegisteredCoinTypes[chain]; ok {
+		return coinType
+	}
+	return bip44.CoinTypeEthereum
+}
+
+// NewEVMAccount derives the account at m/44'/60'/0'/0/index, as MetaMask and
+// most EVM wallets do for every chain.
+func NewEVMAccount(seed []byte, chain address.ChainID, index uint32) (*EVMAccount, error) {
+	return NewEVMAccountForCoinType(seed, chain, bip44.CoinTypeEthereum, index)
+}
+
+// NewEVMAccountForCoinType derives the account at m/44'/coinType'/0'/0/index.
+// Use EVMRegisteredCoinType(chain) for wallets that derive at the chain's own coin type.
+func NewEVMAccountForCoinType(seed []byte, chain address.ChainID, coinType bip44.CoinType, index uint32) (*EVMAccount, error) {
+	if _, ok := address.EVMChains()[chain]; !ok {
+		return nil, fmt.Errorf("%w: %s is not an EVM chain", address.ErrUnsupportedChain, chain)
+	}
+
+	path := fmt.Sprintf(EVMPathFormat, coinType, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := uncompressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &EVMAccount{
+		path:       path,
+		chain:      chain,
+		coinType:   coinType,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewEVMAccountFromMnemonic derives the account at m/44'/60'/0'/0/index from a mnemonic.
+func NewEVMAccountFromMnemonic(mnemonic, passphrase string, chain address.ChainID, index uint32) (*EVMAccount, error) {
+	return NewEVMAccountFromMnemonicForCoinType(mnemonic, passphrase, chain, bip44.CoinTypeEthereum, index)
+}
+
+// NewEVMAccountFromMnemonicForCoinType derives the account at m/44'/coinType'/0'/0/index from a mnemonic.
+func NewEVMAccountFromMnemonicForCoinType(mnemonic, passphrase string, chain address.ChainID, coinType bip44.CoinType, index uint32) (*EVMAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewEVMAccountForCoinType(seed, chain, coinType, index)
+}
+
+// NewEVMAccountFromSeed is NewEVMAccount for a bip39.Seed, so that one seed
+// derived from a mnemonic serves every chain.
+func NewEVMAccountFromSeed(seed *bip39.Seed, chain address.ChainID, index uint32) (acc *EVMAccount, err error) {
+	seed.Use(func(b []byte) { acc, err = NewEVMAccount(b, chain, index) })
+	return acc, err
+}
+
+// NewEVMAccountFromParsedMnemonic is NewEVMAccountFromMnemonic for a parsed mnemonic.
+// The seed is wiped once the account is derived.
+func NewEVMAccountFromParsedMnemonic(mnemonic *bip39.Mnemonic, passphrase string, chain address.ChainID, index uint32) (*EVMAccount, error) {
+	seed, err := mnemonic.Seed(passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer seed.Destroy()
+	return NewEVMAccountFromSeed(seed, chain, index)
+}
+
+// Path returns the derivation path.
+func (a *EVMAccount) Path() string {
+	return a.path
+}
+
+// CoinType returns the SLIP-44 coin type used for derivation.
+func (a *EVMAccount) CoinType() bip44.CoinType {
+	return a.coinType
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *EVMAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *EVMAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *EVMAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *EVMAccount) Chain() address.ChainID {
+	return a.chain
+}
+
+// Address returns the EIP-55 checksummed address.
+func (a *EVMAccount) Address() string {
+	addr, _ := address.NewEVMAddress(a.chain).Generate(a.publicKey.Bytes())
+	return addr
+}
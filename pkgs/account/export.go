@@ -0,0 +1,186 @@
+package account
+
+import (
+	"bufio"
+	"encoding/csv"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"io"
+	"math"
+	"strconv"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// ExportFormat selects how ExportAddresses encodes rows.
+type ExportFormat string
+
+// Export formats
+const (
+	// ExportCSV writes a header line and one comma-separated row per address.
+	ExportCSV ExportFormat = "csv"
+
+	// ExportJSON writes a JSON array of AddressRow objects.
+	ExportJSON ExportFormat = "json"
+)
+
+// defaultExportBatch is the number of addresses derived between writes.
+const defaultExportBatch = 1000
+
+// AddressRow is one exported address.
+type AddressRow struct {
+	Index     uint32 `json:"index"`
+	Path      string `json:"path"`
+	Address   string `json:"address"`
+	PublicKey string `json:"publicKey,omitempty"`
+}
+
+// ExportOptions configures ExportAddresses. The zero value writes CSV without
+// public keys on GOMAXPROCS workers.
+type ExportOptions struct {
+	Format ExportFormat
+
+	// PublicKeys adds the hex public key of each address.
+	PublicKeys bool
+
+	// Workers is passed to the parallel derivation; <= 0 means GOMAXPROCS.
+	Workers int
+
+	// BatchSize is the number of addresses derived before they are written.
+	// Zero means 1000.
+	BatchSize uint32
+}
+
+// ExportAddresses derives the addresses at indexes [start, start+count) on a
+// chain and streams them to out, for pre-generating deposit addresses or
+// handing an address list to an auditor. Addresses are derived in batches as
+// AddressesParallel does and are not cached, so memory stays bounded by the
+// batch size. Private keys are never written.
+func (w *Wallet) ExportAddresses(out io.Writer, chain address.ChainID, start, count uint32, opts ExportOptions) error {
+	if count > 0 && uint64(start)+uint64(count)-1 > math.MaxUint32 {
+		return fmt.Errorf("%w: %d accounts from index %d", ErrInvalidIndex, count, start)
+	}
+	if opts.BatchSize == 0 {
+		opts.BatchSize = defaultExportBatch
+	}
+
+	var enc rowEncoder
+	switch opts.Format {
+	case "", ExportCSV:
+		enc = newCSVRowEncoder(out, opts.PublicKeys)
+	case ExportJSON:
+		enc = newJSONRowEncoder(out)
+	default:
+		return fmt.Errorf("account: unknown export format %q", opts.Format)
+	}
+
+	rows := make([]AddressRow, 0, min(count, opts.BatchSize))
+	for done := uint32(0); done < count; {
+		n := min(count-done, opts.BatchSize)
+		rows = rows[:n]
+		err := w.deriveParallel(chain, start+done, n, opts.Workers, func(i uint32, account ChainAccount) {
+			row := AddressRow{Index: start + done + i, Path: account.Path(), Address: account.Address()}
+			if opts.PublicKeys {
+				row.PublicKey = hex.EncodeToString(account.PublicKeyBytes())
+			}
+			rows[i] = row
+		})
+		if err != nil {
+			return err
+		}
+		for _, row := range rows {
+			if err := enc.write(row); err != nil {
+				return err
+			}
+		}
+		done += n
+	}
+	return enc.close()
+}
+
+// rowEncoder writes AddressRows in one export format.
+type rowEncoder interface {
+	write(row AddressRow) error
+	close() error
+}
+
+type csvRowEncoder struct {
+	w          *csv.Writer
+	publicKeys bool
+	header     bool
+}
+
+func newCSVRowEncoder(out io.Writer, publicKeys bool) *csvRowEncoder {
+	return &csvRowEncoder{w: csv.NewWriter(out), publicKeys: publicKeys}
+}
+
+func (e *csvRowEncoder) writeHeader() error {
+	e.header = true
+	header := []string{"index", "path", "address"}
+	if e.publicKeys {
+		header = append(header, "publicKey")
+	}
+	return e.w.Write(header)
+}
+
+func (e *csvRowEncoder) write(row AddressRow) error {
+	if !e.header {
+		if err := e.writeHeader(); err != nil {
+			return err
+		}
+	}
+	record := []string{strconv.FormatUint(uint64(row.Index), 10), row.Path, row.Address}
+	if e.publicKeys {
+		record = append(record, row.PublicKey)
+	}
+	return e.w.Write(record)
+}
+
+func (e *csvRowEncoder) close() error {
+	if !e.header {
+		if err := e.writeHeader(); err != nil {
+			return err
+		}
+	}
+	e.w.Flush()
+	return e.w.Error()
+}
+
+// jsonRowEncoder streams a JSON array one element at a time.
+type jsonRowEncoder struct {
+	w    *bufio.Writer
+	rows int
+}
+
+func newJSONRowEncoder(out io.Writer) *jsonRowEncoder {
+	return &jsonRowEncoder{w: bufio.NewWriter(out)}
+}
+
+func (e *jsonRowEncoder) write(row AddressRow) error {
+	sep := ",\n  "
+	if e.rows == 0 {
+		sep = "[\n  "
+	}
+	e.rows++
+	data, err := json.Marshal(row)
+	if err != nil {
+		return err
+	}
+	if _, err := e.w.WriteString(sep); err != nil {
+		return err
+	}
+	_, err = e.w.Write(data)
+	return err
+}
+
+func (e *jsonRowEncoder) close() error {
+	end := "\n]\n"
+	if e.rows == 0 {
+		end = "[]\n"
+	}
+	if _, err := e.w.WriteString(end); err != nil {
+		return err
+	}
+	return e.w.Flush()
+}
@@ -0,0 +1,92 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// FilecoinPathFormat is the BIP-44 path for Filecoin: m/44'/461'/0'/0/index
+const FilecoinPathFormat = "m/44'/461'/0'/0/%d"
+
+// FilecoinAccount is a secp256k1 Filecoin account with a protocol-1 (f1) address.
+type FilecoinAccount struct {
+	path       string
+	testnet    bool
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewFilecoinAccount derives the Filecoin account at m/44'/461'/0'/0/index.
+func NewFilecoinAccount(seed []byte, index uint32) (*FilecoinAccount, error) {
+	path := fmt.Sprintf(FilecoinPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := uncompressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &FilecoinAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewFilecoinAccountFromMnemonic derives the Filecoin account at the given index from a mnemonic.
+func NewFilecoinAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*FilecoinAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewFilecoinAccount(seed, index)
+}
+
+// SetTestnet switches the address prefix between "f" (mainnet) and "t" (testnet).
+func (a *FilecoinAccount) SetTestnet(testnet bool) {
+	a.testnet = testnet
+}
+
+// Path returns the derivation path.
+func (a *FilecoinAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *FilecoinAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *FilecoinAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *FilecoinAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *FilecoinAccount) Chain() address.ChainID {
+	return address.ChainFilecoin
+}
+
+// Address returns the f1 (or t1) address:
+// base32(Blake2b-160(pubkey) || Blake2b-32(protocol || payload)).
+func (a *FilecoinAccount) Address() string {
+	generator := address.NewFilecoinAddress()
+	if a.testnet {
+		generator = address.NewFilecoinTestnetAddress()
+	}
+
+	addr, _ := generator.F1Address(a.publicKey.Bytes())
+	return addr
+}
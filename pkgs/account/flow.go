@@ -0,0 +1,222 @@
+package account
+
+import (
+	"encoding/binary"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// FlowPathFormat is the path used by the Flow Ledger app and FCL wallets: m/44'/539'/0'/0/index
+const FlowPathFormat = "m/44'/539'/0'/0/%d"
+
+// FlowSignatureAlgorithm identifies the curve of a Flow account key.
+type FlowSignatureAlgorithm uint8
+
+// Signature algorithm codes as used in Flow account keys
+const (
+	FlowECDSAP256      FlowSignatureAlgorithm = 2
+	FlowECDSASecp256k1 FlowSignatureAlgorithm = 3
+)
+
+// FlowHashAlgorithm identifies the hash a Flow account key signs with.
+type FlowHashAlgorithm uint8
+
+// Hash algorithm codes as used in Flow account keys
+const (
+	FlowHashSHA2 FlowHashAlgorithm = 1 // SHA2-256
+	FlowHashSHA3 FlowHashAlgorithm = 3 // SHA3-256
+)
+
+// FlowFullKeyWeight is the weight a single key needs to authorize a transaction alone.
+const FlowFullKeyWeight = 1000
+
+// FlowAccount is a Flow key pair. Flow addresses are assigned by the network when
+// the account is created, so the key is registered through its RLP account key.
+type FlowAccount struct {
+	path          string
+	signatureAlgo FlowSignatureAlgorithm
+	hashAlgo      FlowHashAlgorithm
+	privateKey    *secret.Bytes
+	publicKey     keys.PublicKey
+	encodedKey    []byte // X || Y, as Flow account keys carry it
+}
+
+// NewFlowAccount derives a Flow key at m/44'/539'/0'/0/index on the given curve.
+// P-256 keys use SLIP-10; secp256k1 keys use BIP-32.
+func NewFlowAccount(seed []byte, signatureAlgo FlowSignatureAlgorithm, hashAlgo FlowHashAlgorithm, index uint32) (*FlowAccount, error) {
+	if hashAlgo != FlowHashSHA2 && hashAlgo != FlowHashSHA3 {
+		return nil, fmt.Errorf("unsupported Flow hash algorithm %d", hashAlgo)
+	}
+
+	path := fmt.Sprintf(FlowPathFormat, index)
+
+	var privateKey []byte
+	var publicKey keys.PublicKey
+	switch signatureAlgo {
+	case FlowECDSAP256:
+		var err error
+		privateKey, publicKey, err = deriveP256(seed, path)
+		if err != nil {
+			return nil, err
+		}
+	case FlowECDSASecp256k1:
+		key, err := deriveSecp256k1(seed, path)
+		if err != nil {
+			return nil, err
+		}
+		privateKey = key.PrivateKeyBytes()
+		publicKey, err = uncompressedPublicKey(key.PublicKeyBytes())
+		if err != nil {
+			return nil, err
+		}
+	default:
+		return nil, fmt.Errorf("unsupported Flow signature algorithm %d", signatureAlgo)
+	}
+
+	encodedKey, err := flowPublicKey(signatureAlgo, privateKey, publicKey)
+	if err != nil {
+		return nil, err
+	}
+
+	return &FlowAccount{
+		path:          path,
+		signatureAlgo: signatureAlgo,
+		hashAlgo:      hashAlgo,
+		privateKey:    secret.Take(privateKey),
+		publicKey:     publicKey,
+		encodedKey:    encodedKey,
+	}, nil
+}
+
+// NewFlowAccountFromMnemonic derives a Flow key at the given index from a mnemonic.
+func NewFlowAccountFromMnemonic(mnemonic, passphrase string, signatureAlgo FlowSignatureAlgorithm, hashAlgo FlowHashAlgorithm, index uint32) (*FlowAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewFlowAccount(seed, signatureAlgo, hashAlgo, index)
+}
+
+// flowPublicKey returns the 64-byte X || Y encoding Flow uses for public keys.
+// P-256 keys are held compressed, so their Y is recomputed from the private key.
+func flowPublicKey(signatureAlgo FlowSignatureAlgorithm, privateKey []byte, publicKey keys.PublicKey) ([]byte, error) {
+	if signatureAlgo != FlowECDSAP256 {
+		return publicKey.Bytes()[1:], nil
+	}
+	uncompressed, err := p256.PrivateKeyToPublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+	return uncompressed[1:], nil
+}
+
+// Path returns the derivation path.
+func (a *FlowAccount) Path() string {
+	return a.path
+}
+
+// SignatureAlgorithm returns the curve of the key.
+func (a *FlowAccount) SignatureAlgorithm() FlowSignatureAlgorithm {
+	return a.signatureAlgo
+}
+
+// HashAlgorithm returns the hash the key signs with.
+func (a *FlowAccount) HashAlgorithm() FlowHashAlgorithm {
+	return a.hashAlgo
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte private key.
+func (a *FlowAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *FlowAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 64-byte public key (X || Y, no prefix).
+func (a *FlowAccount) PublicKeyBytes() []byte {
+	return a.encodedKey
+}
+
+// Chain returns the chain identifier.
+func (a *FlowAccount) Chain() address.ChainID {
+	return address.ChainFlow
+}
+
+// Address returns the reference identifier produced by the Flow address generator.
+// The on-chain address is assigned by the network when the account is created.
+func (a *FlowAccount) Address() string {
+	addr, _ := address.NewFlowAddress().Generate(a.encodedKey)
+	return addr
+}
+
+// Hash hashes a message with the key's hash algorithm.
+func (a *FlowAccount) Hash(message []byte) []byte {
+	if a.hashAlgo == FlowHashSHA3 {
+		return address.SHA3256(message)
+	}
+	return address.SHA256Hash(message)
+}
+
+// AccountKey returns the RLP-encoded account key with full weight, as passed to
+// account creation transactions: RLP([publicKey, signAlgo, hashAlgo, weight]).
+func (a *FlowAccount) AccountKey() []byte {
+	return a.AccountKeyWithWeight(FlowFullKeyWeight)
+}
+
+// AccountKeyWithWeight returns the RLP-encoded account key with the given weight.
+func (a *FlowAccount) AccountKeyWithWeight(weight uint32) []byte {
+	return rlpList(
+		rlpBytes(a.encodedKey),
+		rlpUint(uint64(a.signatureAlgo)),
+		rlpUint(uint64(a.hashAlgo)),
+		rlpUint(uint64(weight)),
+	)
+}
+
+// rlpBytes RLP-encodes a byte string.
+func rlpBytes(b []byte) []byte {
+	if len(b) == 1 && b[0] < 0x80 {
+		return []byte{b[0]}
+	}
+	return append(rlpHeader(0x80, len(b)), b...)
+}
+
+// rlpUint RLP-encodes an unsigned integer as its minimal big-endian bytes.
+func rlpUint(v uint64) []byte {
+	buf := binary.BigEndian.AppendUint64(nil, v)
+	i := 0
+	for i < len(buf) && buf[i] == 0 {
+		i++
+	}
+	return rlpBytes(buf[i:])
+}
+
+// rlpList RLP-encodes a list of already-encoded items.
+func rlpList(items ...[]byte) []byte {
+	var payload []byte
+	for _, item := range items {
+		payload = append(payload, item...)
+	}
+	return append(rlpHeader(0xc0, len(payload)), payload...)
+}
+
+// rlpHeader returns the RLP length prefix for a string (0x80) or list (0xc0).
+func rlpHeader(offset byte, length int) []byte {
+	if length < 56 {
+		return []byte{offset + byte(length)}
+	}
+
+	lenBytes := binary.BigEndian.AppendUint64(nil, uint64(length))
+	i := 0
+	for lenBytes[i] == 0 {
+		i++
+	}
+	return append([]byte{offset + 55 + byte(len(lenBytes)-i)}, lenBytes[i:]...)
+}
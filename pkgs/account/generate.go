@@ -0,0 +1,236 @@
+package account
+
+import (
+	"crypto/rand"
+	"errors"
+	"fmt"
+	"io"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// The Generate* functions create accounts from a fresh random private key
+// instead of a seed, for keys that never need to be recovered from a
+// mnemonic. Like crypto/ed25519.GenerateKey they read from rnd, or from
+// crypto/rand.Reader when rnd is nil; pass a fixed reader for reproducible
+// tests. Generated accounts have an empty derivation path.
+
+// randomKey reads a 32-byte private key from rnd, retrying until valid
+// reports true. Each attempt consumes 32 bytes.
+func randomKey(rnd io.Reader, valid func([]byte) bool) ([]byte, error) {
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+	privateKey := make([]byte, 32)
+	for {
+		if _, err := io.ReadFull(rnd, privateKey); err != nil {
+			return nil, fmt.Errorf("account: reading random key: %w", err)
+		}
+		if valid(privateKey) {
+			return privateKey, nil
+		}
+	}
+}
+
+// randomSecp256k1Key returns a random secp256k1 key in [1, n-1] and its
+// compressed public key.
+func randomSecp256k1Key(rnd io.Reader) ([]byte, keys.PublicKey, error) {
+	privateKey, err := randomKey(rnd, secp256k1.IsValidPrivateKey)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+	publicKey, err := secp256k1PublicKey(privateKey)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+	return privateKey, publicKey, nil
+}
+
+// randomEd25519Key returns a random Ed25519 seed; every 32-byte value is valid.
+func randomEd25519Key(rnd io.Reader) ([]byte, keys.PublicKey, error) {
+	privateKey, err := randomKey(rnd, func([]byte) bool { return true })
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+	publicKey, err := ed25519PublicKey(privateKey)
+	if err != nil {
+		return nil, keys.PublicKey{}, err
+	}
+	return privateKey, publicKey, nil
+}
+
+// GenerateBitcoinAccount creates a Bitcoin account with a random key.
+func GenerateBitcoinAccount(rnd io.Reader, scriptType ScriptType) (*BitcoinAccount, error) {
+	privateKey, publicKey, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &BitcoinAccount{
+		scriptType: scriptType,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// GenerateLitecoinAccount creates a Litecoin account with a random key.
+func GenerateLitecoinAccount(rnd io.Reader, scriptType ScriptType) (*LitecoinAccount, error) {
+	privateKey, publicKey, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &LitecoinAccount{
+		scriptType: scriptType,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// GenerateDogecoinAccount creates a mainnet Dogecoin account with a random key.
+func GenerateDogecoinAccount(rnd io.Reader) (*DogecoinAccount, error) {
+	privateKey, publicKey, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &DogecoinAccount{
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// GenerateEVMAccount creates an account on an EVM chain with a random key.
+func GenerateEVMAccount(rnd io.Reader, chain address.ChainID) (*EVMAccount, error) {
+	if _, ok := address.EVMChains()[chain]; !ok {
+		return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+	}
+	privateKey, publicKey, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	uncompressed, err := publicKey.Uncompressed()
+	if err != nil {
+		return nil, err
+	}
+	return &EVMAccount{
+		chain:      chain,
+		coinType:   bip44.CoinTypeEthereum,
+		privateKey: secret.Take(privateKey),
+		publicKey:  uncompressed,
+	}, nil
+}
+
+// GenerateCosmosAccount creates a Cosmos Hub account with a random key.
+func GenerateCosmosAccount(rnd io.Reader) (*CosmosAccount, error) {
+	privateKey, publicKey, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &CosmosAccount{
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// GenerateSeiAccount creates a Sei account with a random key. The coin type
+// only matters for derivation, so it is recorded as SeiCoinType.
+func GenerateSeiAccount(rnd io.Reader) (*SeiAccount, error) {
+	privateKey, publicKey, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &SeiAccount{
+		coinType:   SeiCoinType,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// GenerateSolanaAccount creates a Solana account with a random key.
+func GenerateSolanaAccount(rnd io.Reader) (*SolanaAccount, error) {
+	privateKey, publicKey, err := randomEd25519Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &SolanaAccount{privateKey: secret.Take(privateKey), publicKey: publicKey}, nil
+}
+
+// GenerateSuiAccount creates a Sui Ed25519 account with a random key.
+func GenerateSuiAccount(rnd io.Reader) (*SuiAccount, error) {
+	privateKey, publicKey, err := randomEd25519Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &SuiAccount{privateKey: secret.Take(privateKey), publicKey: publicKey}, nil
+}
+
+// GenerateStellarAccount creates a Stellar account with a random key.
+func GenerateStellarAccount(rnd io.Reader) (*StellarAccount, error) {
+	privateKey, publicKey, err := randomEd25519Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return &StellarAccount{privateKey: secret.Take(privateKey), publicKey: publicKey}, nil
+}
+
+// GenerateNostrAccount creates a Nostr account with a random key.
+func GenerateNostrAccount(rnd io.Reader) (*NostrAccount, error) {
+	privateKey, _, err := randomSecp256k1Key(rnd)
+	if err != nil {
+		return nil, err
+	}
+	return NewNostrAccountFromPrivateKey(privateKey)
+}
+
+// GenerateNeoAccount creates a Neo account with a random P-256 key.
+func GenerateNeoAccount(rnd io.Reader) (*NeoAccount, error) {
+	for {
+		privateKey, err := randomKey(rnd, func([]byte) bool { return true })
+		if err != nil {
+			return nil, err
+		}
+		acc, err := NewNeoAccountFromPrivateKey(privateKey)
+		if errors.Is(err, ErrInvalidPrivateKey) {
+			continue // outside [1, n-1] for P-256
+		}
+		return acc, err
+	}
+}
+
+// GenerateAccount creates an account with a random key on any chain a
+// Wallet supports except Starknet, whose keys are ground from a seed.
+// Bitcoin and Litecoin accounts use native SegWit.
+func GenerateAccount(rnd io.Reader, chain address.ChainID) (ChainAccount, error) {
+	if !walletChainEnabled(chain) {
+		return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+	}
+
+	switch chain {
+	case address.ChainBitcoin:
+		return GenerateBitcoinAccount(rnd, ScriptP2WPKH)
+	case address.ChainLitecoin:
+		return GenerateLitecoinAccount(rnd, ScriptP2WPKH)
+	case address.ChainDogecoin:
+		return GenerateDogecoinAccount(rnd)
+	case address.ChainCosmos:
+		return GenerateCosmosAccount(rnd)
+	case address.ChainSei:
+		return GenerateSeiAccount(rnd)
+	case address.ChainSolana:
+		return GenerateSolanaAccount(rnd)
+	case address.ChainSui:
+		return GenerateSuiAccount(rnd)
+	case address.ChainStellar:
+		return GenerateStellarAccount(rnd)
+	case address.ChainNostr:
+		return GenerateNostrAccount(rnd)
+	case address.ChainNeo:
+		return GenerateNeoAccount(rnd)
+	}
+	if _, ok := address.EVMChains()[chain]; ok {
+		return GenerateEVMAccount(rnd, chain)
+	}
+	return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+}
@@ -0,0 +1,92 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// HarmonyPathFormat is the BIP-44 path used by Harmony wallets: m/44'/1023'/0'/0/index
+const HarmonyPathFormat = "m/44'/1023'/0'/0/%d"
+
+// HarmonyAccount is a Harmony (ONE) account with "one1..." and 0x address forms.
+type HarmonyAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewHarmonyAccount derives the Harmony account at m/44'/1023'/0'/0/index.
+func NewHarmonyAccount(seed []byte, index uint32) (*HarmonyAccount, error) {
+	path := fmt.Sprintf(HarmonyPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := uncompressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &HarmonyAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewHarmonyAccountFromMnemonic derives the Harmony account at the given index from a mnemonic.
+func NewHarmonyAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*HarmonyAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewHarmonyAccount(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *HarmonyAccount) Path() string {
+	return a.path
+}
+
+// CoinType returns the SLIP-44 coin type.
+func (a *HarmonyAccount) CoinType() bip44.CoinType {
+	return bip44.CoinTypeHarmony
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *HarmonyAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *HarmonyAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *HarmonyAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *HarmonyAccount) Chain() address.ChainID {
+	return address.ChainHarmony
+}
+
+// Address returns the Bech32 address ("one1...").
+func (a *HarmonyAccount) Address() string {
+	addr, _ := address.NewHarmonyAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// EVMAddress returns the EIP-55 checksummed 0x form of the address.
+func (a *HarmonyAccount) EVMAddress() string {
+	addr, _ := address.NewHarmonyAddress().GenerateEVM(a.publicKey.Bytes())
+	return addr
+}
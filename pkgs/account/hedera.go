@@ -0,0 +1,167 @@
+package account
+
+import (
+	"encoding/hex"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// Hedera derivation paths. Ed25519 keys follow the SLIP-10 path used by the
+// Hedera SDKs; ECDSA (secp256k1) keys use the Ethereum path so the same
+// mnemonic yields the same EVM address as other wallets.
+const (
+	HederaPathFormat      = "m/44'/3030'/0'/0'/%d'"
+	HederaECDSAPathFormat = "m/44'/60'/0'/0/%d"
+)
+
+// DER prefixes used by the Hedera SDKs for hex-encoded keys
+const (
+	hederaEd25519PrivateDERPrefix   = "302e020100300506032b657004220420"
+	hederaEd25519PublicDERPrefix    = "302a300506032b6570032100"
+	hederaSecp256k1PrivateDERPrefix = "3030020100300706052b8104000a04220420"
+	hederaSecp256k1PublicDERPrefix  = "302d300706052b8104000a032200"
+)
+
+// HederaKeyType selects the key algorithm of a Hedera account.
+type HederaKeyType int
+
+const (
+	HederaKeyEd25519 HederaKeyType = iota
+	HederaKeyECDSA
+)
+
+// HederaAccount is a Hedera key pair. Account IDs (shard.realm.num) are
+// assigned by the network, so the account exposes key-based aliases instead.
+type HederaAccount struct {
+	keyType    HederaKeyType
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewHederaAccount derives an Ed25519 Hedera account at m/44'/3030'/0'/0'/index'.
+func NewHederaAccount(seed []byte, index uint32) (*HederaAccount, error) {
+	path := fmt.Sprintf(HederaPathFormat, index)
+
+	privateKey, publicKey, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &HederaAccount{
+		keyType:    HederaKeyEd25519,
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewHederaECDSAAccount derives an ECDSA (secp256k1) Hedera account at m/44'/60'/0'/0/index.
+func NewHederaECDSAAccount(seed []byte, index uint32) (*HederaAccount, error) {
+	path := fmt.Sprintf(HederaECDSAPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &HederaAccount{
+		keyType:    HederaKeyECDSA,
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewHederaAccountFromMnemonic derives a Hedera account of the given key type from a mnemonic.
+func NewHederaAccountFromMnemonic(mnemonic, passphrase string, keyType HederaKeyType, index uint32) (*HederaAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+
+	if keyType == HederaKeyECDSA {
+		return NewHederaECDSAAccount(seed, index)
+	}
+	return NewHederaAccount(seed, index)
+}
+
+// KeyType returns the key algorithm of the account.
+func (a *HederaAccount) KeyType() HederaKeyType {
+	return a.keyType
+}
+
+// Path returns the derivation path.
+func (a *HederaAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the raw 32-byte private key.
+func (a *HederaAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *HederaAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the raw public key (32-byte Ed25519 or 33-byte compressed secp256k1).
+func (a *HederaAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *HederaAccount) Chain() address.ChainID {
+	return address.ChainHedera
+}
+
+// Address returns the public key alias in shard.realm.alias form (0.0.<hex>).
+func (a *HederaAccount) Address() string {
+	addr, _ := address.NewHederaAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// PrivateKeyDER returns the DER-encoded private key as hex, as accepted by
+// PrivateKey.fromString in the Hedera SDKs.
+func (a *HederaAccount) PrivateKeyDER() string {
+	prefix := hederaEd25519PrivateDERPrefix
+	if a.keyType == HederaKeyECDSA {
+		prefix = hederaSecp256k1PrivateDERPrefix
+	}
+	var der string
+	a.privateKey.Use(func(key []byte) { der = prefix + hex.EncodeToString(key) })
+	return der
+}
+
+// PublicKeyDER returns the DER-encoded public key as hex.
+func (a *HederaAccount) PublicKeyDER() string {
+	if a.keyType == HederaKeyECDSA {
+		return hederaSecp256k1PublicDERPrefix + hex.EncodeToString(a.publicKey.Bytes())
+	}
+	return hederaEd25519PublicDERPrefix + hex.EncodeToString(a.publicKey.Bytes())
+}
+
+// EVMAddress returns the EVM address alias of an ECDSA account as 40 lowercase
+// hex characters (the last 20 bytes of Keccak-256 of the uncompressed key).
+// Ed25519 accounts have no EVM alias.
+func (a *HederaAccount) EVMAddress() (string, error) {
+	if a.keyType != HederaKeyECDSA {
+		return "", fmt.Errorf("%w: EVM aliases require an ECDSA key", address.ErrInvalidPublicKey)
+	}
+
+	uncompressed, err := a.publicKey.Uncompressed()
+	if err != nil {
+		return "", err
+	}
+
+	return hex.EncodeToString(address.Keccak256(uncompressed.Bytes()[1:])[12:]), nil
+}
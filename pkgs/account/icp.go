@@ -0,0 +1,99 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// ICPPathFormat is the BIP-44 path for the Internet Computer: m/44'/223'/0'/0/index
+const ICPPathFormat = "m/44'/223'/0'/0/%d"
+
+// ICPAccount is a secp256k1 Internet Computer identity with its ledger account.
+type ICPAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+	principal  []byte
+}
+
+// NewICPAccount derives the ICP identity at m/44'/223'/0'/0/index.
+func NewICPAccount(seed []byte, index uint32) (*ICPAccount, error) {
+	path := fmt.Sprintf(ICPPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	// Principals are derived from the uncompressed key, matching dfx and agent-js
+	publicKey, err := uncompressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	principal, err := address.NewICPAddress().SelfAuthenticatingPrincipal(publicKey.Bytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &ICPAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+		principal:  principal,
+	}, nil
+}
+
+// NewICPAccountFromMnemonic derives the ICP identity at the given index from a mnemonic.
+func NewICPAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*ICPAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewICPAccount(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *ICPAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *ICPAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *ICPAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *ICPAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *ICPAccount) Chain() address.ChainID {
+	return address.ChainICP
+}
+
+// PrincipalBytes returns the 29-byte self-authenticating principal.
+func (a *ICPAccount) PrincipalBytes() []byte {
+	return a.principal
+}
+
+// Address returns the textual principal (e.g., "xxxxx-xxxxx-...-xxx").
+func (a *ICPAccount) Address() string {
+	addr, _ := address.NewICPAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// AccountIdentifier returns the hex ledger account identifier for a subaccount.
+// A nil subaccount selects the default account.
+func (a *ICPAccount) AccountIdentifier(subaccount []byte) (string, error) {
+	return address.ICPAccountIdentifierHex(a.principal, subaccount)
+}
@@ -0,0 +1,428 @@
+package account
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"slices"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// KeyFormat names an encoding of a private key recognized by ImportKey.
+type KeyFormat string
+
+// Supported private key formats
+const (
+	KeyFormatHex           KeyFormat = "hex"
+	KeyFormatWIF           KeyFormat = "wif"
+	KeyFormatExtended      KeyFormat = "xprv"
+	KeyFormatSuiPrivKey    KeyFormat = "suiprivkey"
+	KeyFormatSolanaKeypair KeyFormat = "solana-keypair"
+	KeyFormatStellarSecret KeyFormat = "stellar-secret"
+	KeyFormatNsec          KeyFormat = "nsec"
+	KeyFormatPKCS8         KeyFormat = "pkcs8"
+)
+
+const solanaKeypairLen = 64
+
+// ImportedKey is a private key decoded by ImportKey. The key itself is held
+// in a secret buffer and read with Bytes; printing an ImportedKey with any
+// fmt verb redacts it.
+type ImportedKey struct {
+	// Format is the encoding the key was given in.
+	Format KeyFormat
+
+	// Scheme is the signature scheme the key is for: SchemeSecp256k1Schnorr
+	// for nsec keys, which Nostr uses with BIP-340, SchemeSecp256k1ECDSA for
+	// other secp256k1 keys and SchemeEd25519. It is empty for bare hex, which
+	// does not say which curve it is for.
+	Scheme signer.Scheme
+
+	// privateKey is the raw 32-byte private key or Ed25519 seed.
+	privateKey *secret.Bytes
+
+	// Compressed is the WIF compression flag; it is true for other formats.
+	Compressed bool
+
+	// Testnet is set for testnet WIF and tprv keys.
+	Testnet bool
+
+	// Extended is the decoded key for KeyFormatExtended.
+	Extended *bip32.ExtendedKey
+
+	// Chains lists the chains Account can build an account for, in
+	// WalletChains order.
+	Chains []address.ChainID
+}
+
+// ImportKey detects the format of an encoded private key, decodes it and
+// reports the chains it can back. Recognized formats are PKCS#8 PEM, Solana
+// keypairs (a JSON byte array as written by solana-keygen, or Base58 as
+// exported by Phantom), Sui "suiprivkey1" Ed25519 keys, Nostr nsec keys,
+// xprv and tprv extended keys, Stellar "S..." secrets, WIF and 32-byte hex.
+//
+// Formats that name their network narrow the chains: WIF reports the chains
+// its version byte belongs to. Hex is ambiguous and reports every chain whose
+// curve accepts the key.
+func ImportKey(encoded string) (*ImportedKey, error) {
+	s := strings.TrimSpace(encoded)
+	switch {
+	case s == "":
+		return nil, ErrUnknownKeyFormat
+	case strings.HasPrefix(s, "-----BEGIN"):
+		return importPKCS8(s)
+	case strings.HasPrefix(s, "["):
+		return importSolanaJSON(s)
+	case strings.HasPrefix(s, keys.SuiPrivateKeyHRP+"1"):
+		return importSuiPrivateKey(s)
+	case strings.HasPrefix(s, NostrPrivateKeyHRP+"1"):
+		privateKey, err := decodeNostrKey(NostrPrivateKeyHRP, s)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+		}
+		return newImportedKey(KeyFormatNsec, signer.SchemeSecp256k1Schnorr, privateKey, address.ChainNostr)
+	case strings.HasPrefix(s, "xprv") || strings.HasPrefix(s, "tprv"):
+		return importExtendedKey(s)
+	case len(s) == 56 && s[0] == 'S':
+		privateKey, err := address.DecodeStellarSeed(s)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+		}
+		return newImportedKey(KeyFormatStellarSecret, signer.SchemeEd25519, privateKey, address.ChainStellar)
+	}
+
+	if raw, err := encoding.DecodeHex(s); err == nil {
+		if len(raw) != 32 {
+			return nil, fmt.Errorf("%w: hex key must be 32 bytes, got %d", ErrInvalidPrivateKey, len(raw))
+		}
+		return importHex(raw)
+	}
+	if version, privateKey, compressed, err := DecodeWIF(s); err == nil {
+		return importWIF(version, privateKey, compressed)
+	}
+	if raw, err := address.Base58Decode(s); err == nil && len(raw) == solanaKeypairLen {
+		return importSolanaKeypair(raw)
+	}
+	return nil, ErrUnknownKeyFormat
+}
+
+// newImportedKey checks privateKey against the scheme's curve and keeps the
+// chains enabled in this build.
+func newImportedKey(format KeyFormat, scheme signer.Scheme, privateKey []byte, chains ...address.ChainID) (*ImportedKey, error) {
+	secp := scheme == signer.SchemeSecp256k1ECDSA || scheme == signer.SchemeSecp256k1Schnorr
+	if len(privateKey) != 32 || (secp && !secp256k1.IsValidPrivateKey(privateKey)) {
+		return nil, ErrInvalidPrivateKey
+	}
+	k := &ImportedKey{
+		Format:     format,
+		Scheme:     scheme,
+		privateKey: secret.Copy(privateKey),
+		Compressed: true,
+	}
+	for _, chain := range WalletChains() {
+		if slices.Contains(chains, chain) {
+			k.Chains = append(k.Chains, chain)
+		}
+	}
+	return k, nil
+}
+
+// secp256k1Chains returns the chains that use a plain secp256k1 private key.
+func secp256k1Chains() []address.ChainID {
+	chains := []address.ChainID{
+		address.ChainBitcoin,
+		address.ChainLitecoin,
+		address.ChainDogecoin,
+		address.ChainCosmos,
+		address.ChainSei,
+		address.ChainNostr,
+	}
+	for chain := range address.EVMChains() {
+		chains = append(chains, chain)
+	}
+	return chains
+}
+
+// ed25519Chains returns the chains that use a plain Ed25519 seed.
+func ed25519Chains() []address.ChainID {
+	return []address.ChainID{address.ChainSolana, address.ChainSui, address.ChainStellar}
+}
+
+func importPKCS8(s string) (*ImportedKey, error) {
+	privateKey, scheme, err := ParsePKCS8PEM([]byte(s))
+	if err != nil {
+		return nil, err
+	}
+	chains := ed25519Chains()
+	if scheme == signer.SchemeSecp256k1ECDSA {
+		chains = secp256k1Chains()
+	}
+	return newImportedKey(KeyFormatPKCS8, scheme, privateKey, chains...)
+}
+
+func importSolanaJSON(s string) (*ImportedKey, error) {
+	var values []int
+	if err := json.Unmarshal([]byte(s), &values); err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	raw := make([]byte, len(values))
+	for i, v := range values {
+		if v < 0 || v > 0xff {
+			return nil, fmt.Errorf("%w: keypair byte %d out of range", ErrInvalidPrivateKey, i)
+		}
+		raw[i] = byte(v)
+	}
+	return importSolanaKeypair(raw)
+}
+
+// importSolanaKeypair accepts a 64-byte seed || public key keypair whose
+// public key half matches the seed.
+func importSolanaKeypair(raw []byte) (*ImportedKey, error) {
+	if len(raw) != solanaKeypairLen {
+		return nil, fmt.Errorf("%w: Solana keypair must be %d bytes, got %d", ErrInvalidPrivateKey, solanaKeypairLen, len(raw))
+	}
+	publicKey, err := ed25519.PrivateKeyToPublicKey(raw[:32])
+	if err != nil || !bytes.Equal(publicKey, raw[32:]) {
+		return nil, fmt.Errorf("%w: keypair public key does not match", ErrInvalidPrivateKey)
+	}
+	return newImportedKey(KeyFormatSolanaKeypair, signer.SchemeEd25519, raw[:32], address.ChainSolana)
+}
+
+// importSuiPrivateKey decodes a Sui keytool key with keys.DecodeSuiPrivateKey.
+// Only Ed25519 keys are supported.
+func importSuiPrivateKey(s string) (*ImportedKey, error) {
+	key, err := keys.DecodeSuiPrivateKey(s)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	defer key.Destroy()
+	if key.Curve() != keys.CurveEd25519 {
+		return nil, fmt.Errorf("%w: unsupported Sui key scheme %s", ErrInvalidPrivateKey, key.Curve())
+	}
+	privateKey := key.Bytes()
+	defer secret.Wipe(privateKey)
+	return newImportedKey(KeyFormatSuiPrivKey, signer.SchemeEd25519, privateKey, address.ChainSui)
+}
+
+func importExtendedKey(s string) (*ImportedKey, error) {
+	key, err := bip32.ParseExtendedKey(s)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	if !key.IsPrivate() {
+		return nil, fmt.Errorf("%w: extended key is public", ErrInvalidPrivateKey)
+	}
+	privateKey := key.PrivateKeyBytes()
+	defer secret.Wipe(privateKey)
+	k, err := newImportedKey(KeyFormatExtended, signer.SchemeSecp256k1ECDSA, privateKey, secp256k1Chains()...)
+	if err != nil {
+		return nil, err
+	}
+	k.Extended = key
+	k.Testnet = key.Network() == bip32.TestNet
+	return k, nil
+}
+
+// importWIF maps a WIF version byte to the chains that use it. Neo shares
+// Bitcoin's version byte and is included when the key is compressed and valid
+// on P-256; Litecoin testnet shares Bitcoin's testnet version.
+func importWIF(version byte, privateKey []byte, compressed bool) (*ImportedKey, error) {
+	var chains []address.ChainID
+	testnet := false
+	switch version {
+	case BitcoinWIFVersion:
+		chains = []address.ChainID{address.ChainBitcoin}
+		if _, err := p256.PrivateKeyToPublicKey(privateKey); err == nil && compressed {
+			chains = append(chains, address.ChainNeo)
+		}
+	case BitcoinTestnetWIFVersion:
+		chains, testnet = []address.ChainID{address.ChainBitcoin, address.ChainLitecoin}, true
+	case LitecoinWIFVersion:
+		chains = []address.ChainID{address.ChainLitecoin}
+	case DogecoinWIFVersion:
+		chains = []address.ChainID{address.ChainDogecoin}
+	case DogecoinTestnetWIFVersion:
+		chains, testnet = []address.ChainID{address.ChainDogecoin}, true
+	default:
+		return nil, fmt.Errorf("%w: unknown WIF version 0x%02x", ErrInvalidPrivateKey, version)
+	}
+
+	k, err := newImportedKey(KeyFormatWIF, signer.SchemeSecp256k1ECDSA, privateKey, chains...)
+	if err != nil {
+		return nil, err
+	}
+	k.Compressed, k.Testnet = compressed, testnet
+	return k, nil
+}
+
+// importHex reports every chain whose curve accepts a bare 32-byte key.
+func importHex(privateKey []byte) (*ImportedKey, error) {
+	chains := ed25519Chains()
+	if secp256k1.IsValidPrivateKey(privateKey) {
+		chains = append(chains, secp256k1Chains()...)
+	}
+	if _, err := p256.PrivateKeyToPublicKey(privateKey); err == nil {
+		chains = append(chains, address.ChainNeo)
+	}
+	return newImportedKey(KeyFormatHex, "", privateKey, chains...)
+}
+
+// NewAccountFromPrivateKey builds an account on chain for a curve-tagged key,
+// as ImportedKey.Account does for a decoded one: secp256k1 keys back the
+// Bitcoin family, Cosmos, Sei, Nostr and EVM chains, Ed25519 keys Solana, Sui
+// and Stellar, and P-256 keys Neo.
+func NewAccountFromPrivateKey(key keys.PrivateKey, chain address.ChainID) (ChainAccount, error) {
+	var scheme signer.Scheme
+	var chains []address.ChainID
+	switch key.Curve() {
+	case keys.CurveSecp256k1:
+		scheme, chains = signer.SchemeSecp256k1ECDSA, secp256k1Chains()
+	case keys.CurveEd25519:
+		scheme, chains = signer.SchemeEd25519, ed25519Chains()
+	case keys.CurveP256:
+		chains = []address.ChainID{address.ChainNeo}
+	default:
+		return nil, ErrInvalidPrivateKey
+	}
+	if !slices.Contains(chains, chain) {
+		return nil, fmt.Errorf("%w: %s key cannot be used on %s", address.ErrUnsupportedChain, key.Curve(), chain)
+	}
+	privateKey := key.Bytes()
+	defer secret.Wipe(privateKey)
+	k, err := newImportedKey(KeyFormatHex, scheme, privateKey, chain)
+	if err != nil {
+		return nil, err
+	}
+	defer k.Destroy()
+	return k.Account(chain)
+}
+
+// Bytes returns a copy of the raw 32-byte private key or Ed25519 seed, or nil
+// once destroyed. The caller should wipe the copy once done.
+func (k *ImportedKey) Bytes() []byte {
+	return k.privateKey.Bytes()
+}
+
+// Destroy wipes the private key, and the extended key if there is one.
+// Account fails afterwards; accounts already built keep their own copy.
+func (k *ImportedKey) Destroy() {
+	k.privateKey.Destroy()
+	if k.Extended != nil {
+		k.Extended.Wipe()
+	}
+}
+
+// String describes the key with the private key redacted.
+func (k *ImportedKey) String() string {
+	return fmt.Sprintf("ImportedKey{Format: %s, Scheme: %s, Chains: %v, PrivateKey: %s}", k.Format, k.Scheme, k.Chains, secret.Redacted)
+}
+
+// GoString keeps %#v from printing the private key.
+func (k *ImportedKey) GoString() string {
+	return "&account." + k.String()
+}
+
+// Format implements fmt.Formatter so that no verb prints the private key;
+// %#v uses GoString.
+func (k *ImportedKey) Format(f fmt.State, verb rune) {
+	if verb == 'v' && f.Flag('#') {
+		io.WriteString(f, k.GoString())
+		return
+	}
+	io.WriteString(f, k.String())
+}
+
+// Account builds an account for one of k.Chains with an empty derivation
+// path. Bitcoin and Litecoin accounts use native SegWit, which always hashes
+// the compressed public key, even for an uncompressed WIF. Dogecoin accounts
+// from a testnet WIF are switched to testnet.
+func (k *ImportedKey) Account(chain address.ChainID) (ChainAccount, error) {
+	if !slices.Contains(k.Chains, chain) {
+		return nil, fmt.Errorf("%w: %s key cannot be used on %s", address.ErrUnsupportedChain, k.Format, chain)
+	}
+	privateKey := k.privateKey.Bytes()
+	if privateKey == nil {
+		return nil, fmt.Errorf("%w: key destroyed", ErrInvalidPrivateKey)
+	}
+	defer secret.Wipe(privateKey)
+
+	switch chain {
+	case address.ChainNostr:
+		return NewNostrAccountFromPrivateKey(privateKey)
+	case address.ChainNeo:
+		return NewNeoAccountFromPrivateKey(privateKey)
+	case address.ChainSolana, address.ChainSui, address.ChainStellar:
+		publicKey, err := ed25519PublicKey(privateKey)
+		if err != nil {
+			return nil, err
+		}
+		switch chain {
+		case address.ChainSolana:
+			return &SolanaAccount{privateKey: secret.Take(privateKey), publicKey: publicKey}, nil
+		case address.ChainSui:
+			return &SuiAccount{privateKey: secret.Take(privateKey), publicKey: publicKey}, nil
+		default:
+			return &StellarAccount{privateKey: secret.Take(privateKey), publicKey: publicKey}, nil
+		}
+	}
+
+	publicKey, err := secp256k1PublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+
+	switch chain {
+	case address.ChainBitcoin:
+		return &BitcoinAccount{
+			scriptType: ScriptP2WPKH,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+	case address.ChainLitecoin:
+		return &LitecoinAccount{
+			scriptType: ScriptP2WPKH,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+	case address.ChainDogecoin:
+		return &DogecoinAccount{
+			testnet:    k.Testnet,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+	case address.ChainCosmos:
+		return &CosmosAccount{
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+	case address.ChainSei:
+		return &SeiAccount{
+			coinType:   SeiCoinType,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+	}
+
+	uncompressed, err := publicKey.Uncompressed()
+	if err != nil {
+		return nil, err
+	}
+	return &EVMAccount{
+		chain:      chain,
+		coinType:   bip44.CoinTypeEthereum,
+		privateKey: secret.Take(privateKey),
+		publicKey:  uncompressed,
+	}, nil
+}
@@ -0,0 +1,87 @@
+package account
+
+import (
+	"iter"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// indexCount is the number of account indexes, 2^32.
+const indexCount = 1 << 32
+
+// AccountIter enumerates a chain's accounts lazily, in index order:
+//
+//	it := wallet.Iter(address.ChainEthereum).Skip(10).Take(50)
+//	for index, acc := range it.All() {
+//		fmt.Println(index, acc.Path(), acc.Address())
+//	}
+//	if err := it.Err(); err != nil {
+//		...
+//	}
+//
+// Each account is derived when the loop reaches it. Accounts the wallet has
+// already cached are reused, but new ones are not added to the cache, so
+// walking a long range keeps only the BIP-32 parent node in memory. Since
+// Destroy cannot reach them, wipe the private keys of uncached accounts the
+// caller keeps.
+type AccountIter struct {
+	w     *Wallet
+	chain address.ChainID
+
+	// start and end bound the index range [start, end).
+	start, end uint64
+
+	err error
+}
+
+// Iter returns an iterator over every account on a chain, from index 0.
+func (w *Wallet) Iter(chain address.ChainID) *AccountIter {
+	return &AccountIter{w: w, chain: chain, end: indexCount}
+}
+
+// Skip drops the next n indexes and returns it.
+func (it *AccountIter) Skip(n uint32) *AccountIter {
+	it.start = min(it.start+uint64(n), it.end)
+	return it
+}
+
+// Take limits the iterator to the next n indexes and returns it.
+func (it *AccountIter) Take(n uint32) *AccountIter {
+	it.end = min(it.end, it.start+uint64(n))
+	return it
+}
+
+// All returns an iterator over the index and account of each remaining
+// index. A derivation error stops the iteration; check Err after the loop.
+func (it *AccountIter) All() iter.Seq2[uint32, ChainAccount] {
+	return func(yield func(uint32, ChainAccount) bool) {
+		it.err = nil
+		for i := it.start; i < it.end; i++ {
+			account, err := it.w.peek(it.chain, uint32(i))
+			if err != nil {
+				it.err = err
+				return
+			}
+			if !yield(uint32(i), account) {
+				return
+			}
+		}
+	}
+}
+
+// Err returns the error that stopped the last iteration, if any.
+func (it *AccountIter) Err() error {
+	return it.err
+}
+
+// peek returns the account at index like Account, but without caching a
+// newly derived account.
+func (w *Wallet) peek(chain address.ChainID, index uint32) (ChainAccount, error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	if account, ok := w.accounts[walletAccountKey{chain: chain, index: index}]; ok {
+		return account, nil
+	}
+	return w.derive(chain, index)
+}
@@ -0,0 +1,182 @@
+package account
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// SecretsPolicy selects whether an account's private key is serialized.
+type SecretsPolicy int
+
+// Secrets policies
+const (
+	// SecretsExclude leaves the private key out. It is the zero value, so
+	// forgetting to choose a policy never writes a secret.
+	SecretsExclude SecretsPolicy = iota
+
+	// SecretsInclude writes the private key, for deliberate backups and
+	// exports only.
+	SecretsInclude
+)
+
+// AccountJSON is the serialized form of a ChainAccount.
+// Keys are hex-encoded; PrivateKey is only set when explicitly exported.
+type AccountJSON struct {
+	Chain      address.ChainID `json:"chain"`
+	Path       string          `json:"path,omitempty"`
+	Address    string          `json:"address"`
+	PublicKey  string          `json:"publicKey"`
+	PrivateKey string          `json:"privateKey,omitempty"`
+
+	// HashAlgorithm is the hash a Flow key signs with.
+	HashAlgorithm FlowHashAlgorithm `json:"hashAlgorithm,omitempty"`
+
+	// Nonce is the nonce a Waves account was derived with.
+	Nonce uint32 `json:"nonce,omitempty"`
+}
+
+// ExportAccount returns the serializable form of an account. The private key
+// is included only when includePrivateKey is true.
+func ExportAccount(a ChainAccount, includePrivateKey bool) AccountJSON {
+	exported := AccountJSON{
+		Chain:     a.Chain(),
+		Path:      a.Path(),
+		Address:   a.Address(),
+		PublicKey: hex.EncodeToString(a.PublicKeyBytes()),
+	}
+	switch a := a.(type) {
+	case *FlowAccount:
+		exported.HashAlgorithm = a.hashAlgo
+	case *WavesAccount:
+		exported.Nonce = a.nonce
+	}
+	if includePrivateKey {
+		exported.PrivateKey = hex.EncodeToString(a.PrivateKeyBytes())
+	}
+	return exported
+}
+
+// MarshalAccount encodes an account as AccountJSON, with its private key only
+// under SecretsInclude.
+func MarshalAccount(a ChainAccount, policy SecretsPolicy) ([]byte, error) {
+	return json.Marshal(ExportAccount(a, policy == SecretsInclude))
+}
+
+// UnmarshalAccount decodes an AccountJSON written by MarshalAccount or an
+// account's MarshalJSON.
+//
+// Under SecretsExclude it returns a watch-only account with the stored path,
+// address and public key, ignoring any private key in data. Under
+// SecretsInclude data must hold a private key; the account is rebuilt from
+// it as the same account type, with the stored path and the script type,
+// network or key curve that reproduce the stored address, and returned as a
+// ChainAccount. It returns ErrInvalidPrivateKey if the key does not produce
+// the stored address and public key.
+func UnmarshalAccount(data []byte, policy SecretsPolicy) (WatchAccount, error) {
+	var decoded AccountJSON
+	if err := json.Unmarshal(data, &decoded); err != nil {
+		return nil, err
+	}
+	if decoded.Address == "" {
+		return nil, fmt.Errorf("%w: no address", ErrInvalidPublicKey)
+	}
+	publicKey, err := hex.DecodeString(decoded.PublicKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPublicKey, err)
+	}
+
+	if policy != SecretsInclude {
+		return &storedAccount{
+			chain:     decoded.Chain,
+			path:      decoded.Path,
+			address:   decoded.Address,
+			publicKey: publicKey,
+		}, nil
+	}
+
+	privateKey, err := hex.DecodeString(decoded.PrivateKey)
+	if err != nil || len(privateKey) == 0 {
+		return nil, fmt.Errorf("%w: no private key to restore", ErrInvalidPrivateKey)
+	}
+	defer secret.Wipe(privateKey)
+	return restoreAccount(decoded, publicKey, privateKey)
+}
+
+// Accounts marshal to AccountJSON without their private keys, so persisting or
+// transmitting an account never writes a secret unless ExportAccount(a, true) is used.
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *AvalancheAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *BitcoinAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *CosmosAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *DogecoinAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *EVMAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *FilecoinAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *FlowAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *HarmonyAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *HederaAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *ICPAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *LitecoinAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *MoneroAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *NeoAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *NostrAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *RoninAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *SeiAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *SolanaAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *StarknetAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *StellarAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *SuiAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *TezosAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *VeChainAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *WavesAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
+
+// MarshalJSON implements json.Marshaler without the private key.
+func (a *XRPAccount) MarshalJSON() ([]byte, error) { return json.Marshal(ExportAccount(a, false)) }
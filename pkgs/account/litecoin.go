@@ -0,0 +1,126 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// LitecoinPathFormat is the derivation path: m/purpose'/2'/account'/0/index
+const LitecoinPathFormat = "m/%d'/2'/%d'/0/%d"
+
+// LitecoinAccount is a Litecoin account with legacy and SegWit addresses.
+type LitecoinAccount struct {
+	path       string
+	scriptType ScriptType
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewLitecoinAccount derives a Litecoin account. The script type picks the
+// purpose: 44' for P2PKH (L...), 49' for P2SH-P2WPKH (M...), 84' for P2WPKH (ltc1...).
+func NewLitecoinAccount(seed []byte, scriptType ScriptType, account, index uint32) (*LitecoinAccount, error) {
+	path := fmt.Sprintf(LitecoinPathFormat, scriptType.Purpose(), account, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &LitecoinAccount{
+		path:       path,
+		scriptType: scriptType,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewLitecoinAccountFromMnemonic derives a Litecoin account from a mnemonic.
+func NewLitecoinAccountFromMnemonic(mnemonic, passphrase string, scriptType ScriptType, account, index uint32) (*LitecoinAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewLitecoinAccount(seed, scriptType, account, index)
+}
+
+// CoinType returns the SLIP-44 coin type.
+func (a *LitecoinAccount) CoinType() bip44.CoinType {
+	return bip44.CoinTypeLitecoin
+}
+
+// ScriptType returns the script type the account was derived for.
+func (a *LitecoinAccount) ScriptType() ScriptType {
+	return a.scriptType
+}
+
+// Path returns the derivation path.
+func (a *LitecoinAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *LitecoinAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *LitecoinAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *LitecoinAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *LitecoinAccount) Chain() address.ChainID {
+	return address.ChainLitecoin
+}
+
+// WIF returns the private key in Litecoin Wallet Import Format (compressed).
+func (a *LitecoinAccount) WIF() string {
+	var wif string
+	a.privateKey.Use(func(key []byte) { wif, _ = EncodeWIF(LitecoinWIFVersion, key, true) })
+	return wif
+}
+
+// Address returns the address matching the account's script type.
+func (a *LitecoinAccount) Address() string {
+	switch a.scriptType {
+	case ScriptP2SHP2WPKH:
+		return a.NestedSegWitAddress()
+	case ScriptP2WPKH:
+		return a.SegWitAddress()
+	default:
+		return a.LegacyAddress()
+	}
+}
+
+// LegacyAddress returns the P2PKH address (L...).
+func (a *LitecoinAccount) LegacyAddress() string {
+	addr, _ := address.NewLitecoinAddress(false).P2PKH(a.publicKey.Bytes())
+	return addr
+}
+
+// NestedSegWitAddress returns the P2SH-P2WPKH address (M...).
+func (a *LitecoinAccount) NestedSegWitAddress() string {
+	redeemScript := p2wpkhRedeemScript(address.Hash160(a.publicKey.Bytes()))
+	addr, _ := address.NewLitecoinAddress(false).P2SH(redeemScript)
+	return addr
+}
+
+// SegWitAddress returns the native SegWit P2WPKH address (ltc1...).
+func (a *LitecoinAccount) SegWitAddress() string {
+	addr, _ := address.NewLitecoinAddress(false).Bech32(a.publicKey.Bytes())
+	return addr
+}
@@ -0,0 +1,284 @@
+package account
+
+import (
+	"encoding/base64"
+	"encoding/binary"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strconv"
+	"unicode/utf8"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/keys"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// bitcoinMessageMagics are the signed-message prefixes of the Bitcoin family.
+var bitcoinMessageMagics = map[address.ChainID]string{
+	address.ChainBitcoin:  "Bitcoin Signed Message:\n",
+	address.ChainLitecoin: "Litecoin Signed Message:\n",
+	address.ChainDogecoin: "Dogecoin Signed Message:\n",
+}
+
+// BIP-137 signed-message header bases; the header is base + recovery id. A
+// compressed key adds 4, and nested and native SegWit a further 4 or 8.
+const (
+	BIP137Uncompressed = 27
+	BIP137P2PKH        = 31
+	BIP137P2SHP2WPKH   = 35
+	BIP137P2WPKH       = 39
+)
+
+const (
+	// eip191VOffset is added to the recovery id to form personal_sign's v.
+	eip191VOffset = 27
+
+	// suiEd25519SigFlag starts a serialized Sui Ed25519 signature.
+	suiEd25519SigFlag = 0x00
+)
+
+// Solana off-chain message (v0) limits and formats.
+const (
+	solanaOffchainDomain     = "\xffsolana offchain"
+	solanaOffchainMaxLedger  = 1212
+	solanaOffchainMax        = 65515
+	solanaFormatASCII        = 0
+	solanaFormatLimitedUTF8  = 1
+	solanaFormatExtendedUTF8 = 2
+)
+
+// SignMessage signs message with the account's key, wrapped in the envelope
+// the chain's wallets use for off-chain "sign message" requests, and returns
+// the signature in the form those wallets exchange:
+//
+//   - EVM chains: EIP-191 personal_sign; 0x-prefixed hex r || s || v, v = 27 or 28
+//   - Bitcoin, Litecoin, Dogecoin: "<Coin> Signed Message:\n" with a BIP-137
+//     header for the account's script type; Base64
+//   - Cosmos, Sei: ADR-36 sign doc for the account's address; Base64 r || s
+//   - Sui: personal message intent; Base64 flag || signature || public key
+//   - Solana: off-chain message v0; Base58
+//
+// Other chains return address.ErrUnsupportedChain.
+func SignMessage(a SigningAccount, message []byte) (string, error) {
+	s, err := a.Signer()
+	if err != nil {
+		return "", err
+	}
+	return SignMessageWith(s, a, message)
+}
+
+// SignMessage signs message with the wallet account at index on chain, as
+// the package-level SignMessage does.
+func (w *Wallet) SignMessage(chain address.ChainID, index uint32, message []byte) (string, error) {
+	acc, err := w.Account(chain, index)
+	if err != nil {
+		return "", err
+	}
+	signing, ok := acc.(SigningAccount)
+	if !ok {
+		return "", fmt.Errorf("%w: %s has no message signer", address.ErrUnsupportedChain, chain)
+	}
+	return SignMessage(signing, message)
+}
+
+// SignMessageWith signs message for a watch-only account with a signer that
+// holds its key, such as a remote or hardware signer; see SignMessage.
+func SignMessageWith(s signer.Signer, a WatchAccount, message []byte) (string, error) {
+	chain := a.Chain()
+	if _, ok := address.EVMChains()[chain]; ok {
+		sig, err := signMessageECDSA(s, chain, EIP191Hash(message))
+		if err != nil {
+			return "", err
+		}
+		rsv, err := sig.Recoverable(eip191VOffset)
+		if err != nil {
+			return "", err
+		}
+		return "0x" + hex.EncodeToString(rsv), nil
+	}
+
+	switch chain {
+	case address.ChainBitcoin, address.ChainLitecoin, address.ChainDogecoin:
+		digest, err := BitcoinMessageHash(chain, message)
+		if err != nil {
+			return "", err
+		}
+		sig, err := signMessageECDSA(s, chain, digest)
+		if err != nil {
+			return "", err
+		}
+		id, ok := sig.RecoveryID()
+		if !ok {
+			return "", keys.ErrNotRecoverable
+		}
+		header := byte(BIP137P2PKH)
+		if typed, ok := a.(interface{ ScriptType() ScriptType }); ok {
+			switch typed.ScriptType() {
+			case ScriptP2SHP2WPKH:
+				header = BIP137P2SHP2WPKH
+			case ScriptP2WPKH:
+				header = BIP137P2WPKH
+			}
+		}
+		return base64.StdEncoding.EncodeToString(append([]byte{header + id}, sig.Bytes()...)), nil
+
+	case address.ChainCosmos, address.ChainSei:
+		sig, err := signMessageECDSA(s, chain, address.SHA256Hash(ADR36SignDoc(a.Address(), message)))
+		if err != nil {
+			return "", err
+		}
+		return base64.StdEncoding.EncodeToString(sig.Bytes()), nil
+
+	case address.ChainSui:
+		if err := requireScheme(s, chain, signer.SchemeEd25519); err != nil {
+			return "", err
+		}
+		sig, err := s.Sign(SuiPersonalMessageDigest(message))
+		if err != nil {
+			return "", err
+		}
+		serialized := append([]byte{suiEd25519SigFlag}, sig...)
+		return base64.StdEncoding.EncodeToString(append(serialized, s.PublicKey()...)), nil
+
+	case address.ChainSolana:
+		if err := requireScheme(s, chain, signer.SchemeEd25519); err != nil {
+			return "", err
+		}
+		payload, err := SolanaOffchainMessage(message)
+		if err != nil {
+			return "", err
+		}
+		sig, err := s.Sign(payload)
+		if err != nil {
+			return "", err
+		}
+		return address.Base58Encode(sig), nil
+	}
+	return "", fmt.Errorf("%w: no message signing for %s", address.ErrUnsupportedChain, chain)
+}
+
+// requireScheme rejects a signer of the wrong scheme for chain.
+func requireScheme(s signer.Signer, chain address.ChainID, scheme signer.Scheme) error {
+	if s.Scheme() != scheme {
+		return fmt.Errorf("%w: %s needs %s, got %s", signer.ErrSchemeMismatch, chain, scheme, s.Scheme())
+	}
+	return nil
+}
+
+// signMessageECDSA signs a digest and keeps the recovery id.
+func signMessageECDSA(s signer.Signer, chain address.ChainID, digest []byte) (keys.Signature, error) {
+	if err := requireScheme(s, chain, signer.SchemeSecp256k1ECDSA); err != nil {
+		return keys.Signature{}, err
+	}
+	sig, err := keys.Sign(s, digest)
+	if err != nil {
+		return keys.Signature{}, err
+	}
+	return sig.Normalize()
+}
+
+// EIP191Hash returns the personal_sign digest:
+// keccak256("\x19Ethereum Signed Message:\n" || len(message) || message).
+func EIP191Hash(message []byte) []byte {
+	prefix := "\x19Ethereum Signed Message:\n" + strconv.Itoa(len(message))
+	return address.Keccak256(append([]byte(prefix), message...))
+}
+
+// BitcoinMessageHash returns the signed-message digest of Bitcoin, Litecoin
+// or Dogecoin: the double SHA-256 of the chain's magic and the message, each
+// prefixed with its CompactSize length.
+func BitcoinMessageHash(chain address.ChainID, message []byte) ([]byte, error) {
+	magic, ok := bitcoinMessageMagics[chain]
+	if !ok {
+		return nil, fmt.Errorf("%w: no signed-message magic for %s", address.ErrUnsupportedChain, chain)
+	}
+	data := appendCompactSize(nil, uint64(len(magic)))
+	data = append(data, magic...)
+	data = appendCompactSize(data, uint64(len(message)))
+	return address.DoubleSHA256(append(data, message...)), nil
+}
+
+// appendCompactSize appends a Bitcoin variable-length integer.
+func appendCompactSize(b []byte, n uint64) []byte {
+	switch {
+	case n < 0xfd:
+		return append(b, byte(n))
+	case n <= 0xffff:
+		return binary.LittleEndian.AppendUint16(append(b, 0xfd), uint16(n))
+	case n <= 0xffffffff:
+		return binary.LittleEndian.AppendUint32(append(b, 0xfe), uint32(n))
+	default:
+		return binary.LittleEndian.AppendUint64(append(b, 0xff), n)
+	}
+}
+
+// ADR36SignDoc returns the ADR-36 amino sign doc for arbitrary data: a
+// MsgSignData from signer with an empty chain id, zero fee, account number
+// and sequence, with keys in sorted order.
+func ADR36SignDoc(signerAddress string, message []byte) []byte {
+	type value struct {
+		Data   string `json:"data"`
+		Signer string `json:"signer"`
+	}
+	type msg struct {
+		Type  string `json:"type"`
+		Value value  `json:"value"`
+	}
+	type fee struct {
+		Amount []struct{} `json:"amount"`
+		Gas    string     `json:"gas"`
+	}
+	doc := struct {
+		AccountNumber string `json:"account_number"`
+		ChainID       string `json:"chain_id"`
+		Fee           fee    `json:"fee"`
+		Memo          string `json:"memo"`
+		Msgs          []msg  `json:"msgs"`
+		Sequence      string `json:"sequence"`
+	}{
+		AccountNumber: "0",
+		Fee:           fee{Amount: []struct{}{}, Gas: "0"},
+		Msgs: []msg{{
+			Type:  "sign/MsgSignData",
+			Value: value{Data: base64.StdEncoding.EncodeToString(message), Signer: signerAddress},
+		}},
+		Sequence: "0",
+	}
+	data, _ := json.Marshal(doc)
+	return data
+}
+
+// SuiPersonalMessageDigest returns the Blake2b-256 of the PersonalMessage intent
+// (scope 3, version 0, app Sui) followed by the BCS-encoded message bytes.
+func SuiPersonalMessageDigest(message []byte) []byte {
+	data := []byte{3, 0, 0}
+	data = binary.AppendUvarint(data, uint64(len(message)))
+	return address.Blake2b256(append(data, message...))
+}
+
+// SolanaOffchainMessage builds a v0 off-chain message: the signing domain,
+// version, format, little-endian length and the message. The format is
+// restricted ASCII or UTF-8 short enough for a Ledger, or extended UTF-8.
+func SolanaOffchainMessage(message []byte) ([]byte, error) {
+	if len(message) == 0 || len(message) > solanaOffchainMax {
+		return nil, fmt.Errorf("account: Solana off-chain message must be 1-%d bytes, got %d", solanaOffchainMax, len(message))
+	}
+	if !utf8.Valid(message) {
+		return nil, errors.New("account: Solana off-chain message must be UTF-8")
+	}
+	format := byte(solanaFormatExtendedUTF8)
+	if len(message) <= solanaOffchainMaxLedger {
+		format = solanaFormatASCII
+		for _, c := range message {
+			if c < 0x20 || c > 0x7e {
+				format = solanaFormatLimitedUTF8
+				break
+			}
+		}
+	}
+	data := append([]byte(solanaOffchainDomain), 0, format)
+	data = binary.LittleEndian.AppendUint16(data, uint16(len(message)))
+	return append(data, message...), nil
+}
@@ -0,0 +1,171 @@
+package account
+
+import (
+	"encoding/binary"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// MoneroPathFormat is the SLIP-10 path used for Monero: m/44'/128'/account'
+const MoneroPathFormat = "m/44'/128'/%d'"
+
+// MoneroAccount holds the Monero spend and view key pairs.
+type MoneroAccount struct {
+	path           string
+	testnet        bool
+	privateSpend   *secret.Bytes
+	privateView    *secret.Bytes
+	publicSpend    *ed25519.Point
+	publicView     *ed25519.Point
+	publicSpendKey []byte
+	publicViewKey  []byte
+}
+
+// NewMoneroAccount derives a Monero account from a seed.
+// The SLIP-10 key at m/44'/128'/account' is hashed with Keccak-256 and reduced
+// mod l to obtain the private spend key (the scheme used by Trezor).
+func NewMoneroAccount(seed []byte, account uint32) (*MoneroAccount, error) {
+	path := fmt.Sprintf(MoneroPathFormat, account)
+
+	key, _, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	acc, err := NewMoneroAccountFromSpendKey(ed25519.ScalarReduce(address.Keccak256(key)))
+	if err != nil {
+		return nil, err
+	}
+	acc.path = path
+
+	return acc, nil
+}
+
+// NewMoneroAccountFromMnemonic derives a Monero account from a BIP-39 mnemonic.
+func NewMoneroAccountFromMnemonic(mnemonic, passphrase string, account uint32) (*MoneroAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewMoneroAccount(seed, account)
+}
+
+// NewMoneroAccountFromSpendKey builds an account from a 32-byte private spend key.
+// The private view key is Keccak-256(spend) reduced mod l, as in the Monero wallet.
+func NewMoneroAccountFromSpendKey(spendKey []byte) (*MoneroAccount, error) {
+	if len(spendKey) != 32 {
+		return nil, fmt.Errorf("%w: Monero spend key must be 32 bytes", ErrInvalidPrivateKey)
+	}
+
+	privateSpend := ed25519.ScalarReduce(spendKey)
+	privateView := ed25519.ScalarReduce(address.Keccak256(privateSpend))
+
+	publicSpend := ed25519.ScalarBaseMult(privateSpend)
+	publicView := ed25519.ScalarBaseMult(privateView)
+
+	return &MoneroAccount{
+		privateSpend:   secret.Take(privateSpend),
+		privateView:    secret.Take(privateView),
+		publicSpend:    publicSpend,
+		publicView:     publicView,
+		publicSpendKey: publicSpend.Bytes(),
+		publicViewKey:  publicView.Bytes(),
+	}, nil
+}
+
+// SetTestnet switches the address network bytes to testnet.
+func (a *MoneroAccount) SetTestnet(testnet bool) {
+	a.testnet = testnet
+}
+
+// Path returns the derivation path, or an empty string for imported accounts.
+func (a *MoneroAccount) Path() string {
+	return a.path
+}
+
+// PrivateSpendKey returns a copy of the 32-byte private spend key.
+func (a *MoneroAccount) PrivateSpendKey() []byte {
+	return a.privateSpend.Bytes()
+}
+
+// PrivateViewKey returns a copy of the 32-byte private view key.
+func (a *MoneroAccount) PrivateViewKey() []byte {
+	return a.privateView.Bytes()
+}
+
+// PublicSpendKey returns the 32-byte public spend key.
+func (a *MoneroAccount) PublicSpendKey() []byte {
+	return a.publicSpendKey
+}
+
+// PublicViewKey returns the 32-byte public view key.
+func (a *MoneroAccount) PublicViewKey() []byte {
+	return a.publicViewKey
+}
+
+// PrivateKeyBytes returns a copy of the private spend key.
+func (a *MoneroAccount) PrivateKeyBytes() []byte {
+	return a.privateSpend.Bytes()
+}
+
+// Destroy wipes the private spend and view keys. Subaddress no longer works
+// afterwards.
+func (a *MoneroAccount) Destroy() {
+	a.privateSpend.Destroy()
+	a.privateView.Destroy()
+}
+
+// PublicKeyBytes returns the public spend key followed by the public view key.
+func (a *MoneroAccount) PublicKeyBytes() []byte {
+	return append(append([]byte{}, a.publicSpendKey...), a.publicViewKey...)
+}
+
+// Chain returns the chain identifier.
+func (a *MoneroAccount) Chain() address.ChainID {
+	return address.ChainMonero
+}
+
+// Address returns the primary (standard) address.
+func (a *MoneroAccount) Address() string {
+	addr, _ := a.generator().GenerateStandard(a.publicSpendKey, a.publicViewKey)
+	return addr
+}
+
+// Subaddress returns the subaddress at (major, minor).
+// Index (0, 0) is the primary address.
+//
+//	m = Hs("SubAddr\0" || a || major || minor)
+//	D = B + m*G, C = a*D
+func (a *MoneroAccount) Subaddress(major, minor uint32) string {
+	if major == 0 && minor == 0 {
+		return a.Address()
+	}
+
+	var spend, view *ed25519.Point
+	a.privateView.Use(func(privateView []byte) {
+		data := make([]byte, 0, 8+32+8)
+		data = append(data, []byte("SubAddr\x00")...)
+		data = append(data, privateView...)
+		data = binary.LittleEndian.AppendUint32(data, major)
+		data = binary.LittleEndian.AppendUint32(data, minor)
+		m := ed25519.ScalarReduce(address.Keccak256(data))
+		secret.Wipe(data)
+
+		spend = ed25519.Add(a.publicSpend, ed25519.ScalarBaseMult(m))
+		view = ed25519.ScalarMult(spend, ed25519.ScalarFromBytes(privateView))
+	})
+
+	addr, _ := a.generator().GenerateSubaddress(spend.Bytes(), view.Bytes())
+	return addr
+}
+
+// generator returns the address generator for the account's network.
+func (a *MoneroAccount) generator() *address.MoneroAddress {
+	if a.testnet {
+		return address.NewMoneroTestnetAddress()
+	}
+	return address.NewMoneroAddress()
+}
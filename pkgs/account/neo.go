@@ -0,0 +1,231 @@
+package account
+
+import (
+	"bytes"
+	"crypto/aes"
+	"errors"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+	"golang.org/x/crypto/scrypt"
+)
+
+// NeoPathFormat is the path used by Neo wallets (Neon, Ledger): m/44'/888'/0'/0/index
+const NeoPathFormat = "m/44'/888'/0'/0/%d"
+
+// NeoWIFVersion is the WIF version byte for Neo private keys (same as Bitcoin).
+const NeoWIFVersion byte = 0x80
+
+// NEP-2 scrypt parameters used by Neo wallets
+const (
+	NEP2ScryptN = 16384
+	NEP2ScryptR = 8
+	NEP2ScryptP = 8
+)
+
+// nep2Prefix is the 3-byte prefix of a NEP-2 payload, giving keys starting with "6P".
+var nep2Prefix = []byte{0x01, 0x42, 0xe0}
+
+// ErrInvalidNEP2 indicates a malformed NEP-2 key or a wrong passphrase.
+var ErrInvalidNEP2 = errors.New("account: invalid NEP-2 key or passphrase")
+
+// NeoAccount is a Neo N3 account backed by a NIST P-256 key.
+type NeoAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewNeoAccount derives the Neo account at m/44'/888'/0'/0/index using SLIP-10 on P-256.
+func NewNeoAccount(seed []byte, index uint32) (*NeoAccount, error) {
+	path := fmt.Sprintf(NeoPathFormat, index)
+
+	privateKey, publicKey, err := deriveP256(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &NeoAccount{
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewNeoAccountFromMnemonic derives the Neo account at the given index from a mnemonic.
+func NewNeoAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*NeoAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewNeoAccount(seed, index)
+}
+
+// NewNeoAccountFromPrivateKey creates a Neo account from a raw 32-byte P-256 private key.
+// The account has no derivation path.
+func NewNeoAccountFromPrivateKey(privateKey []byte) (*NeoAccount, error) {
+	compressed, err := p256.PrivateKeyToCompressedPublicKey(privateKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.P256, compressed)
+	if err != nil {
+		return nil, err
+	}
+
+	return &NeoAccount{
+		privateKey: secret.Copy(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewNeoAccountFromWIF imports a Neo account from a compressed WIF key.
+func NewNeoAccountFromWIF(wif string) (*NeoAccount, error) {
+	version, privateKey, compressed, err := DecodeWIF(wif)
+	if err != nil {
+		return nil, err
+	}
+	if version != NeoWIFVersion || !compressed {
+		return nil, fmt.Errorf("%w: not a Neo WIF key", ErrInvalidPrivateKey)
+	}
+	return NewNeoAccountFromPrivateKey(privateKey)
+}
+
+// NewNeoAccountFromNEP2 decrypts a NEP-2 key with the passphrase and checks
+// the embedded address hash.
+func NewNeoAccountFromNEP2(nep2, passphrase string) (*NeoAccount, error) {
+	decoded, err := address.Base58Decode(nep2)
+	if err != nil || len(decoded) != 43 {
+		return nil, ErrInvalidNEP2
+	}
+
+	payload, checksum := decoded[:39], decoded[39:]
+	if !bytes.Equal(address.Checksum4(payload), checksum) || !bytes.Equal(payload[:3], nep2Prefix) {
+		return nil, ErrInvalidNEP2
+	}
+
+	addressHash := payload[3:7]
+	derived, err := nep2DeriveKey(passphrase, addressHash)
+	if err != nil {
+		return nil, err
+	}
+
+	block, err := aes.NewCipher(derived[32:])
+	if err != nil {
+		return nil, err
+	}
+
+	privateKey := make([]byte, 32)
+	block.Decrypt(privateKey[:16], payload[7:23])
+	block.Decrypt(privateKey[16:], payload[23:39])
+	for i := range privateKey {
+		privateKey[i] ^= derived[i]
+	}
+
+	account, err := NewNeoAccountFromPrivateKey(privateKey)
+	if err != nil {
+		return nil, ErrInvalidNEP2
+	}
+	if !bytes.Equal(nep2AddressHash(account.Address()), addressHash) {
+		return nil, ErrInvalidNEP2
+	}
+
+	return account, nil
+}
+
+// Path returns the derivation path.
+func (a *NeoAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte P-256 private key.
+func (a *NeoAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *NeoAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed P-256 public key.
+func (a *NeoAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *NeoAccount) Chain() address.ChainID {
+	return address.ChainNeo
+}
+
+// Address returns the Neo N3 address (starts with 'N').
+func (a *NeoAccount) Address() string {
+	addr, _ := address.NewNeoAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// VerificationScript returns the account's single-signature verification script.
+func (a *NeoAccount) VerificationScript() []byte {
+	script, _ := address.NeoVerificationScript(a.publicKey.Bytes())
+	return script
+}
+
+// ScriptHash returns the script hash in display form (0x-prefixed, big-endian).
+func (a *NeoAccount) ScriptHash() string {
+	return address.FormatNeoScriptHash(address.NeoScriptHash(a.VerificationScript()))
+}
+
+// WIF returns the private key in compressed Wallet Import Format.
+func (a *NeoAccount) WIF() (wif string, err error) {
+	a.privateKey.Use(func(key []byte) { wif, err = EncodeWIF(NeoWIFVersion, key, true) })
+	return wif, err
+}
+
+// NEP2 encrypts the private key with a passphrase (NEP-2):
+// AES-256-ECB(key XOR scrypt[:32], scrypt[32:]) with the scrypt salt taken from
+// the address hash, encoded as Base58Check(0x01 0x42 0xe0 || addresshash || ciphertext).
+func (a *NeoAccount) NEP2(passphrase string) (string, error) {
+	addressHash := nep2AddressHash(a.Address())
+
+	derived, err := nep2DeriveKey(passphrase, addressHash)
+	if err != nil {
+		return "", err
+	}
+
+	block, err := aes.NewCipher(derived[32:])
+	if err != nil {
+		return "", err
+	}
+
+	xored := make([]byte, 32)
+	a.privateKey.Use(func(key []byte) {
+		for i := range key {
+			xored[i] = key[i] ^ derived[i]
+		}
+	})
+	defer secret.Wipe(xored)
+
+	encrypted := make([]byte, 32)
+	block.Encrypt(encrypted[:16], xored[:16])
+	block.Encrypt(encrypted[16:], xored[16:])
+
+	payload := make([]byte, 0, 36)
+	payload = append(payload, addressHash...)
+	payload = append(payload, encrypted...)
+
+	return address.Base58CheckEncodeMultiVersion(nep2Prefix, payload), nil
+}
+
+// nep2AddressHash returns the first 4 bytes of SHA256(SHA256(address)).
+func nep2AddressHash(addr string) []byte {
+	return address.Checksum4([]byte(addr))
+}
+
+// nep2DeriveKey derives the 64-byte NEP-2 key with scrypt.
+func nep2DeriveKey(passphrase string, addressHash []byte) ([]byte, error) {
+	return scrypt.Key([]byte(passphrase), addressHash, NEP2ScryptN, NEP2ScryptR, NEP2ScryptP, 64)
+}
@@ -0,0 +1,281 @@
+package account
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strconv"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// NostrPathFormat is the NIP-06 derivation path: m/44'/1237'/account'/0/0
+const NostrPathFormat = "m/44'/1237'/%d'/0/0"
+
+// NIP-19 Bech32 prefixes
+const (
+	NostrPublicKeyHRP  = "npub"
+	NostrPrivateKeyHRP = "nsec"
+)
+
+// ErrInvalidNostrEvent indicates an event whose id or signature does not match its content.
+var ErrInvalidNostrEvent = errors.New("account: invalid nostr event")
+
+// NostrAccount is a Nostr identity: a secp256k1 key with a BIP-340 x-only public key.
+type NostrAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NostrEvent is a NIP-01 event.
+type NostrEvent struct {
+	ID        string     `json:"id"`
+	PubKey    string     `json:"pubkey"`
+	CreatedAt int64      `json:"created_at"`
+	Kind      int        `json:"kind"`
+	Tags      [][]string `json:"tags"`
+	Content   string     `json:"content"`
+	Sig       string     `json:"sig"`
+}
+
+// NewNostrAccount derives the Nostr key at m/44'/1237'/account'/0/0 (NIP-06).
+func NewNostrAccount(seed []byte, account uint32) (*NostrAccount, error) {
+	path := fmt.Sprintf(NostrPathFormat, account)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	acc, err := NewNostrAccountFromPrivateKey(key.PrivateKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+	acc.path = path
+	return acc, nil
+}
+
+// NewNostrAccountFromMnemonic derives the Nostr key for an account index from a mnemonic.
+func NewNostrAccountFromMnemonic(mnemonic, passphrase string, account uint32) (*NostrAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewNostrAccount(seed, account)
+}
+
+// NewNostrAccountFromPrivateKey creates a Nostr account from a raw 32-byte private key.
+// The derivation path of the returned account is empty.
+func NewNostrAccountFromPrivateKey(privateKey []byte) (*NostrAccount, error) {
+	if len(privateKey) != 32 {
+		return nil, ErrInvalidPrivateKey
+	}
+
+	xOnly, err := secp256k1.XOnlyPublicKey(privateKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1XOnly, xOnly)
+	if err != nil {
+		return nil, err
+	}
+
+	return &NostrAccount{
+		privateKey: secret.Copy(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewNostrAccountFromNsec creates a Nostr account from an "nsec1..." string.
+func NewNostrAccountFromNsec(nsec string) (*NostrAccount, error) {
+	privateKey, err := decodeNostrKey(NostrPrivateKeyHRP, nsec)
+	if err != nil {
+		return nil, err
+	}
+	return NewNostrAccountFromPrivateKey(privateKey)
+}
+
+// DecodeNPub decodes an "npub1..." string into a 32-byte x-only public key.
+func DecodeNPub(npub string) ([]byte, error) {
+	return decodeNostrKey(NostrPublicKeyHRP, npub)
+}
+
+// decodeNostrKey decodes a NIP-19 Bech32 key with the expected prefix.
+func decodeNostrKey(hrp, encoded string) ([]byte, error) {
+	gotHRP, data, encoding, err := address.Bech32Decode(encoded)
+	if err != nil {
+		return nil, err
+	}
+	if gotHRP != hrp || encoding != address.Bech32Standard || len(data) != 32 {
+		return nil, fmt.Errorf("%w: expected %s key", address.ErrInvalidAddress, hrp)
+	}
+	return data, nil
+}
+
+// Path returns the derivation path.
+func (a *NostrAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *NostrAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *NostrAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte x-only public key.
+func (a *NostrAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// PublicKeyHex returns the x-only public key as hex, as used in events and relays.
+func (a *NostrAccount) PublicKeyHex() string {
+	return hex.EncodeToString(a.publicKey.Bytes())
+}
+
+// Chain returns the chain identifier.
+func (a *NostrAccount) Chain() address.ChainID {
+	return address.ChainNostr
+}
+
+// NPub returns the NIP-19 public key ("npub1...").
+func (a *NostrAccount) NPub() string {
+	npub, _ := address.Bech32Encode(NostrPublicKeyHRP, a.publicKey.Bytes(), address.Bech32Standard)
+	return npub
+}
+
+// NSec returns the NIP-19 private key ("nsec1...").
+func (a *NostrAccount) NSec() string {
+	var nsec string
+	a.privateKey.Use(func(key []byte) { nsec, _ = address.Bech32Encode(NostrPrivateKeyHRP, key, address.Bech32Standard) })
+	return nsec
+}
+
+// Address returns the npub, the user-facing Nostr identifier.
+func (a *NostrAccount) Address() string {
+	return a.NPub()
+}
+
+// SignEvent sets the event's pubkey, id and BIP-340 signature.
+func (a *NostrAccount) SignEvent(event *NostrEvent) error {
+	s, err := a.Signer()
+	if err != nil {
+		return err
+	}
+	return SignNostrEvent(s, event)
+}
+
+// SignNostrEvent sets the event's pubkey, id and signature using any BIP-340
+// Schnorr signer, such as a remote signer holding the nsec.
+func SignNostrEvent(s signer.Signer, event *NostrEvent) error {
+	if s.Scheme() != signer.SchemeSecp256k1Schnorr {
+		return fmt.Errorf("%w: Nostr needs %s, got %s", signer.ErrSchemeMismatch, signer.SchemeSecp256k1Schnorr, s.Scheme())
+	}
+
+	event.PubKey = hex.EncodeToString(s.PublicKey())
+	id := NostrEventID(event)
+
+	sig, err := s.Sign(id)
+	if err != nil {
+		return err
+	}
+
+	event.ID = hex.EncodeToString(id)
+	event.Sig = hex.EncodeToString(sig)
+	return nil
+}
+
+// VerifyNostrEvent checks that an event's id matches its content and that the
+// signature is valid for its pubkey.
+func VerifyNostrEvent(event *NostrEvent) error {
+	id := NostrEventID(event)
+	if event.ID != hex.EncodeToString(id) {
+		return fmt.Errorf("%w: id mismatch", ErrInvalidNostrEvent)
+	}
+
+	pub, err := hex.DecodeString(event.PubKey)
+	if err != nil {
+		return fmt.Errorf("%w: bad pubkey", ErrInvalidNostrEvent)
+	}
+	sig, err := hex.DecodeString(event.Sig)
+	if err != nil {
+		return fmt.Errorf("%w: bad signature encoding", ErrInvalidNostrEvent)
+	}
+
+	if !secp256k1.SchnorrVerify(pub, id, sig) {
+		return fmt.Errorf("%w: signature mismatch", ErrInvalidNostrEvent)
+	}
+	return nil
+}
+
+// NostrEventID computes the NIP-01 event id:
+// SHA256 of the serialized [0, pubkey, created_at, kind, tags, content].
+func NostrEventID(event *NostrEvent) []byte {
+	var b strings.Builder
+	b.WriteString(`[0,`)
+	writeNostrString(&b, event.PubKey)
+	b.WriteByte(',')
+	b.WriteString(strconv.FormatInt(event.CreatedAt, 10))
+	b.WriteByte(',')
+	b.WriteString(strconv.Itoa(event.Kind))
+	b.WriteString(`,[`)
+	for i, tag := range event.Tags {
+		if i > 0 {
+			b.WriteByte(',')
+		}
+		b.WriteByte('[')
+		for j, item := range tag {
+			if j > 0 {
+				b.WriteByte(',')
+			}
+			writeNostrString(&b, item)
+		}
+		b.WriteByte(']')
+	}
+	b.WriteString(`],`)
+	writeNostrString(&b, event.Content)
+	b.WriteByte(']')
+
+	id := sha256.Sum256([]byte(b.String()))
+	return id[:]
+}
+
+// writeNostrString writes a JSON string using the NIP-01 escaping rules:
+// only quote, backslash and the \n \r \t \b \f control characters are escaped,
+// everything else is written verbatim as UTF-8.
+func writeNostrString(b *strings.Builder, s string) {
+	b.WriteByte('"')
+	for _, r := range s {
+		switch r {
+		case '"':
+			b.WriteString(`\"`)
+		case '\\':
+			b.WriteString(`\\`)
+		case '\n':
+			b.WriteString(`\n`)
+		case '\r':
+			b.WriteString(`\r`)
+		case '\t':
+			b.WriteString(`\t`)
+		case '\b':
+			b.WriteString(`\b`)
+		case '\f':
+			b.WriteString(`\f`)
+		default:
+			b.WriteRune(r)
+		}
+	}
+	b.WriteByte('"')
+}
@@ -0,0 +1,71 @@
+package account
+
+import (
+	"encoding/pem"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// PKCS8PEMType is the PEM block type of an unencrypted PKCS#8 private key.
+const PKCS8PEMType = "PRIVATE KEY"
+
+// MarshalPKCS8 encodes an account's private key as a DER PKCS#8
+// PrivateKeyInfo: an EC key on secp256k1 for ECDSA and Schnorr accounts,
+// RFC 8410 for Ed25519 accounts.
+func MarshalPKCS8(a SigningAccount) ([]byte, error) {
+	s, err := a.Signer()
+	if err != nil {
+		return nil, err
+	}
+	privateKey := a.PrivateKeyBytes()
+	defer secret.Wipe(privateKey)
+	switch s.Scheme() {
+	case signer.SchemeSecp256k1ECDSA, signer.SchemeSecp256k1Schnorr:
+		return secp256k1.MarshalPKCS8PrivateKey(privateKey)
+	case signer.SchemeEd25519:
+		return ed25519.MarshalPKCS8PrivateKey(privateKey)
+	default:
+		return nil, fmt.Errorf("%w: %s", signer.ErrUnsupportedScheme, s.Scheme())
+	}
+}
+
+// MarshalPKCS8PEM encodes an account's private key as a "PRIVATE KEY" PEM
+// block, the format `openssl pkey` and most secret managers read.
+func MarshalPKCS8PEM(a SigningAccount) ([]byte, error) {
+	der, err := MarshalPKCS8(a)
+	if err != nil {
+		return nil, err
+	}
+	return pem.EncodeToMemory(&pem.Block{Type: PKCS8PEMType, Bytes: der}), nil
+}
+
+// ParsePKCS8 decodes a DER PKCS#8 private key and returns the raw 32-byte key
+// with its curve, reported as SchemeSecp256k1ECDSA or SchemeEd25519. PKCS#8
+// does not distinguish ECDSA from Schnorr keys; pass a secp256k1 key to
+// NewNostrAccountFromPrivateKey to use it for Schnorr.
+func ParsePKCS8(der []byte) ([]byte, signer.Scheme, error) {
+	if privateKey, err := secp256k1.ParsePKCS8PrivateKey(der); err == nil {
+		return privateKey, signer.SchemeSecp256k1ECDSA, nil
+	}
+	if privateKey, err := ed25519.ParsePKCS8PrivateKey(der); err == nil {
+		return privateKey, signer.SchemeEd25519, nil
+	}
+	return nil, "", fmt.Errorf("%w: not a secp256k1 or Ed25519 PKCS#8 key", ErrInvalidPrivateKey)
+}
+
+// ParsePKCS8PEM decodes the first PEM block in data, which must be an
+// unencrypted PKCS#8 "PRIVATE KEY", as ParsePKCS8 does.
+func ParsePKCS8PEM(data []byte) ([]byte, signer.Scheme, error) {
+	block, _ := pem.Decode(data)
+	if block == nil {
+		return nil, "", fmt.Errorf("%w: no PEM block", ErrInvalidPrivateKey)
+	}
+	if block.Type != PKCS8PEMType {
+		return nil, "", fmt.Errorf("%w: PEM type %q, want %q", ErrInvalidPrivateKey, block.Type, PKCS8PEMType)
+	}
+	return ParsePKCS8(block.Bytes)
+}
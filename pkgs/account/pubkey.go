@@ -0,0 +1,126 @@
+package account
+
+import "github.com/study/crypto-accounts/pkgs/keys"
+
+// KeyedAccount is a WatchAccount whose public key is on a standard curve and
+// can be returned as a keys.PublicKey. The key is validated once when the
+// account is built. Three chains only provide PublicKeyBytes: Monero accounts
+// are a spend and view key pair rather than one key, Starknet keys are on the
+// STARK curve, and Waves keys are Curve25519 (Montgomery) points, none of
+// which has a KeyType.
+type KeyedAccount interface {
+	WatchAccount
+
+	// PublicKey returns the account's public key tagged with its curve.
+	PublicKey() keys.PublicKey
+}
+
+// Compile-time checks for the accounts with a typed public key.
+var (
+	_ KeyedAccount = (*AvalancheAccount)(nil)
+	_ KeyedAccount = (*BitcoinAccount)(nil)
+	_ KeyedAccount = (*BitcoinWatchAccount)(nil)
+	_ KeyedAccount = (*CosmosAccount)(nil)
+	_ KeyedAccount = (*CosmosWatchAccount)(nil)
+	_ KeyedAccount = (*DogecoinAccount)(nil)
+	_ KeyedAccount = (*EVMAccount)(nil)
+	_ KeyedAccount = (*EVMWatchAccount)(nil)
+	_ KeyedAccount = (*FilecoinAccount)(nil)
+	_ KeyedAccount = (*FlowAccount)(nil)
+	_ KeyedAccount = (*HarmonyAccount)(nil)
+	_ KeyedAccount = (*HederaAccount)(nil)
+	_ KeyedAccount = (*ICPAccount)(nil)
+	_ KeyedAccount = (*LitecoinAccount)(nil)
+	_ KeyedAccount = (*NeoAccount)(nil)
+	_ KeyedAccount = (*NostrAccount)(nil)
+	_ KeyedAccount = (*RoninAccount)(nil)
+	_ KeyedAccount = (*SeiAccount)(nil)
+	_ KeyedAccount = (*SolanaAccount)(nil)
+	_ KeyedAccount = (*SolanaWatchAccount)(nil)
+	_ KeyedAccount = (*StellarAccount)(nil)
+	_ KeyedAccount = (*SuiAccount)(nil)
+	_ KeyedAccount = (*SuiWatchAccount)(nil)
+	_ KeyedAccount = (*TezosAccount)(nil)
+	_ KeyedAccount = (*VeChainAccount)(nil)
+	_ KeyedAccount = (*XRPAccount)(nil)
+)
+
+// PublicKey returns the compressed secp256k1 public key.
+func (a *AvalancheAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed secp256k1 public key.
+func (a *BitcoinAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed secp256k1 public key.
+func (a *CosmosAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed secp256k1 public key.
+func (a *DogecoinAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the uncompressed secp256k1 public key.
+func (a *EVMAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the uncompressed secp256k1 public key.
+func (a *FilecoinAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the P-256 public key, compressed, or the uncompressed
+// secp256k1 public key.
+func (a *FlowAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the uncompressed secp256k1 public key.
+func (a *HarmonyAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the Ed25519 or compressed secp256k1 public key.
+func (a *HederaAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the uncompressed secp256k1 public key.
+func (a *ICPAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed secp256k1 public key.
+func (a *LitecoinAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed P-256 public key.
+func (a *NeoAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the x-only secp256k1 public key.
+func (a *NostrAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the uncompressed secp256k1 public key.
+func (a *RoninAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed secp256k1 public key.
+func (a *SeiAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the Ed25519 public key.
+func (a *SolanaAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the Ed25519 public key.
+func (a *StellarAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the Ed25519 public key.
+func (a *SuiAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the Ed25519 public key.
+func (a *TezosAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the uncompressed secp256k1 public key.
+func (a *VeChainAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the compressed secp256k1 or Ed25519 public key. Unlike
+// PublicKeyBytes, an Ed25519 key has no 0xED prefix.
+func (a *XRPAccount) PublicKey() keys.PublicKey { return a.publicKey }
+
+// PublicKey returns the watched public key.
+func (a *BitcoinWatchAccount) PublicKey() keys.PublicKey { return a.account.PublicKey() }
+
+// PublicKey returns the watched public key.
+func (a *CosmosWatchAccount) PublicKey() keys.PublicKey { return a.account.PublicKey() }
+
+// PublicKey returns the watched public key.
+func (a *EVMWatchAccount) PublicKey() keys.PublicKey { return a.account.PublicKey() }
+
+// PublicKey returns the watched public key.
+func (a *SolanaWatchAccount) PublicKey() keys.PublicKey { return a.account.PublicKey() }
+
+// PublicKey returns the watched public key.
+func (a *SuiWatchAccount) PublicKey() keys.PublicKey { return a.account.PublicKey() }
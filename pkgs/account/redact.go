@@ -0,0 +1,127 @@
+package account
+
+import (
+	"encoding/hex"
+	"fmt"
+	"io"
+	"strings"
+)
+
+// Redacted is printed in place of secret material.
+const Redacted = "[REDACTED]"
+
+// Accounts implement fmt.Formatter so that logging an account with any verb
+// (%v, %+v, %#v, %s, %x, ...) never prints its private key. Use DebugUnsafe
+// when the key itself is needed, e.g. in tests.
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *AvalancheAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *BitcoinAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *CosmosAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *DogecoinAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *EVMAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *FilecoinAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *FlowAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *HarmonyAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *HederaAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *ICPAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *LitecoinAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *MoneroAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *NeoAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *NostrAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *RoninAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *SeiAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *SolanaAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *StarknetAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *StellarAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *SuiAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *TezosAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *VeChainAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *WavesAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the private key.
+func (a *XRPAccount) Format(f fmt.State, verb rune) { formatAccount(f, verb, a) }
+
+// Format implements fmt.Formatter without printing the seed.
+func (w *Wallet) Format(f fmt.State, verb rune) {
+	io.WriteString(f, "Wallet{seed: "+Redacted+"}")
+}
+
+// formatAccount writes the public parts of an account and redacts its private key.
+// %#v prints the Go-syntax type name; every other verb prints the short form.
+func formatAccount(f fmt.State, verb rune, a ChainAccount) {
+	name := accountTypeName(a)
+	if verb == 'v' && f.Flag('#') {
+		name = "&account." + name
+	}
+
+	fields := append(describeAccount(a), "privateKey: "+Redacted)
+	io.WriteString(f, name+"{"+strings.Join(fields, ", ")+"}")
+}
+
+// DebugUnsafe renders an account including its private key.
+// Only use it in tests or local debugging.
+func DebugUnsafe(a ChainAccount) string {
+	fields := append(describeAccount(a), "privateKey: "+hex.EncodeToString(a.PrivateKeyBytes()))
+	return accountTypeName(a) + "{" + strings.Join(fields, ", ") + "}"
+}
+
+// describeAccount returns the public fields of an account.
+func describeAccount(a ChainAccount) []string {
+	return []string{
+		"chain: " + string(a.Chain()),
+		"path: " + a.Path(),
+		"address: " + a.Address(),
+		"publicKey: " + hex.EncodeToString(a.PublicKeyBytes()),
+	}
+}
+
+// accountTypeName returns the account's type name without package or pointer.
+func accountTypeName(a ChainAccount) string {
+	name := fmt.Sprintf("%T", a)
+	return name[strings.LastIndex(name, ".")+1:]
+}
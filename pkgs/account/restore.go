@@ -0,0 +1,273 @@
+package account
+
+import (
+	"bytes"
+	"fmt"
+	"math/big"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/crypto/stark"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// restoreAccount rebuilds the account an export describes from its private
+// key. The key does not fix every setting of an account (the Bitcoin script
+// type, the network, the curve of a Hedera, XRP or Flow key), so every
+// variant the chain has is built and the one whose address and public key
+// match the export is kept. Settings that change neither are read from the
+// path (EVM and Sei coin types) or from the export itself (Flow hash
+// algorithm, Waves nonce).
+func restoreAccount(exported AccountJSON, publicKey, privateKey []byte) (ChainAccount, error) {
+	candidates, err := restoreCandidates(exported, privateKey)
+	if err != nil {
+		return nil, err
+	}
+
+	var restored ChainAccount
+	for _, a := range candidates {
+		if restored == nil && a.Address() == exported.Address && bytes.Equal(a.PublicKeyBytes(), publicKey) {
+			restored = a
+			continue
+		}
+		a.Destroy()
+	}
+	if restored == nil {
+		return nil, fmt.Errorf("%w: key does not match address %s", ErrInvalidPrivateKey, exported.Address)
+	}
+	return restored, nil
+}
+
+// restoreCandidates builds every account variant of the export's chain that
+// privateKey can back, each with its own copy of the key.
+func restoreCandidates(exported AccountJSON, privateKey []byte) ([]ChainAccount, error) {
+	chain, path := exported.Chain, exported.Path
+
+	switch chain {
+	case address.ChainNostr:
+		a, err := NewNostrAccountFromPrivateKey(privateKey)
+		if err != nil {
+			return nil, err
+		}
+		a.path = path
+		return []ChainAccount{a}, nil
+	case address.ChainNeo:
+		a, err := NewNeoAccountFromPrivateKey(privateKey)
+		if err != nil {
+			return nil, err
+		}
+		a.path = path
+		return []ChainAccount{a}, nil
+	case address.ChainMonero:
+		var candidates []ChainAccount
+		for _, testnet := range []bool{false, true} {
+			a, err := NewMoneroAccountFromSpendKey(privateKey)
+			if err != nil {
+				return nil, err
+			}
+			a.path, a.testnet = path, testnet
+			candidates = append(candidates, a)
+		}
+		return candidates, nil
+	case address.ChainWaves:
+		chainID := address.WavesMainnetChainID
+		if raw, err := address.Base58Decode(exported.Address); err == nil && len(raw) > 1 {
+			chainID = raw[1]
+		}
+		a, err := newWavesAccountFromPrivateKey(privateKey, chainID)
+		if err != nil {
+			return nil, err
+		}
+		a.nonce = exported.Nonce
+		return []ChainAccount{a}, nil
+	case address.ChainStarknet:
+		publicKey, err := stark.PublicKey(new(big.Int).SetBytes(privateKey))
+		if err != nil || len(privateKey) != 32 {
+			return nil, ErrInvalidPrivateKey
+		}
+		return []ChainAccount{&StarknetAccount{
+			path:       path,
+			privateKey: secret.Copy(privateKey),
+			publicKey:  stark.FeltBytes(publicKey),
+		}}, nil
+	case address.ChainSolana, address.ChainSui, address.ChainStellar, address.ChainTezos:
+		publicKey, err := ed25519PublicKey(privateKey)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+		}
+		switch chain {
+		case address.ChainSolana:
+			return []ChainAccount{&SolanaAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: publicKey}}, nil
+		case address.ChainSui:
+			return []ChainAccount{&SuiAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: publicKey}}, nil
+		case address.ChainStellar:
+			return []ChainAccount{&StellarAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: publicKey}}, nil
+		default:
+			return []ChainAccount{&TezosAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: publicKey}}, nil
+		}
+	case address.ChainHedera, address.ChainRipple:
+		return restoreEd25519OrSecp256k1(chain, path, privateKey)
+	case address.ChainFlow:
+		return restoreFlow(exported, privateKey)
+	}
+
+	if !secp256k1.IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	publicKey, err := secp256k1PublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+	uncompressed, err := publicKey.Uncompressed()
+	if err != nil {
+		return nil, err
+	}
+
+	var candidates []ChainAccount
+	switch chain {
+	case address.ChainBitcoin:
+		for _, scriptType := range []ScriptType{ScriptP2PKH, ScriptP2SHP2WPKH, ScriptP2WPKH} {
+			candidates = append(candidates, &BitcoinAccount{path: path, scriptType: scriptType, privateKey: secret.Copy(privateKey), publicKey: publicKey})
+		}
+	case address.ChainLitecoin:
+		for _, scriptType := range []ScriptType{ScriptP2PKH, ScriptP2SHP2WPKH, ScriptP2WPKH} {
+			candidates = append(candidates, &LitecoinAccount{path: path, scriptType: scriptType, privateKey: secret.Copy(privateKey), publicKey: publicKey})
+		}
+	case address.ChainDogecoin:
+		for _, testnet := range []bool{false, true} {
+			candidates = append(candidates, &DogecoinAccount{path: path, testnet: testnet, privateKey: secret.Copy(privateKey), publicKey: publicKey})
+		}
+	case address.ChainFilecoin:
+		for _, testnet := range []bool{false, true} {
+			candidates = append(candidates, &FilecoinAccount{path: path, testnet: testnet, privateKey: secret.Copy(privateKey), publicKey: uncompressed})
+		}
+	case address.ChainCosmos:
+		candidates = append(candidates, &CosmosAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: publicKey})
+	case address.ChainSei:
+		coinType, ok := pathCoinType(path)
+		if !ok {
+			coinType = SeiCoinType
+		}
+		candidates = append(candidates, &SeiAccount{path: path, coinType: coinType, privateKey: secret.Copy(privateKey), publicKey: publicKey})
+	case address.ChainAvalanche:
+		hrp := address.AvalancheXChainHRP
+		if rest, ok := strings.CutPrefix(exported.Address, "X-"); ok && strings.LastIndex(rest, "1") > 0 {
+			hrp = rest[:strings.LastIndex(rest, "1")]
+		}
+		candidates = append(candidates, &AvalancheAccount{path: path, hrp: hrp, privateKey: secret.Copy(privateKey), publicKey: publicKey})
+	case address.ChainICP:
+		principal, err := address.NewICPAddress().SelfAuthenticatingPrincipal(uncompressed.Bytes())
+		if err != nil {
+			return nil, err
+		}
+		candidates = append(candidates, &ICPAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: uncompressed, principal: principal})
+	case address.ChainHarmony:
+		candidates = append(candidates, &HarmonyAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: uncompressed})
+	case address.ChainRonin:
+		candidates = append(candidates, &RoninAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: uncompressed})
+	case address.ChainVeChain:
+		candidates = append(candidates, &VeChainAccount{path: path, privateKey: secret.Copy(privateKey), publicKey: uncompressed})
+	default:
+		if _, ok := address.EVMChains()[chain]; !ok {
+			return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+		}
+		coinType := bip44.CoinTypeEthereum
+		if c, ok := pathCoinType(path); ok {
+			coinType = bip44.CoinType(c)
+		}
+		candidates = append(candidates, &EVMAccount{path: path, chain: chain, coinType: coinType, privateKey: secret.Copy(privateKey), publicKey: uncompressed})
+	}
+	return candidates, nil
+}
+
+// restoreEd25519OrSecp256k1 builds the Ed25519 and, if the key is valid on
+// secp256k1, the ECDSA variant of a Hedera or XRP account.
+func restoreEd25519OrSecp256k1(chain address.ChainID, path string, privateKey []byte) ([]ChainAccount, error) {
+	edKey, err := ed25519PublicKey(privateKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	var ecKey keys.PublicKey
+	if secp256k1.IsValidPrivateKey(privateKey) {
+		if ecKey, err = secp256k1PublicKey(privateKey); err != nil {
+			return nil, err
+		}
+	}
+
+	if chain == address.ChainHedera {
+		candidates := []ChainAccount{&HederaAccount{keyType: HederaKeyEd25519, path: path, privateKey: secret.Copy(privateKey), publicKey: edKey}}
+		if !ecKey.IsZero() {
+			candidates = append(candidates, &HederaAccount{keyType: HederaKeyECDSA, path: path, privateKey: secret.Copy(privateKey), publicKey: ecKey})
+		}
+		return candidates, nil
+	}
+	candidates := []ChainAccount{&XRPAccount{keyType: XRPKeyEd25519, path: path, privateKey: secret.Copy(privateKey), publicKey: edKey}}
+	if !ecKey.IsZero() {
+		candidates = append(candidates, &XRPAccount{keyType: XRPKeySecp256k1, path: path, privateKey: secret.Copy(privateKey), publicKey: ecKey})
+	}
+	return candidates, nil
+}
+
+// restoreFlow builds the P-256 and secp256k1 variants of a Flow account that
+// the key is valid for, with the exported hash algorithm (SHA3-256 if the
+// export predates it).
+func restoreFlow(exported AccountJSON, privateKey []byte) ([]ChainAccount, error) {
+	hashAlgo := exported.HashAlgorithm
+	switch hashAlgo {
+	case 0:
+		hashAlgo = FlowHashSHA3
+	case FlowHashSHA2, FlowHashSHA3:
+	default:
+		return nil, fmt.Errorf("unsupported Flow hash algorithm %d", hashAlgo)
+	}
+
+	var candidates []ChainAccount
+	if compressed, err := p256.PrivateKeyToCompressedPublicKey(privateKey); err == nil {
+		publicKey, err := keys.NewPublicKey(keys.P256, compressed)
+		if err != nil {
+			return nil, err
+		}
+		candidates = append(candidates, newRestoredFlowAccount(exported.Path, FlowECDSAP256, hashAlgo, privateKey, publicKey))
+	}
+	if secp256k1.IsValidPrivateKey(privateKey) {
+		compressed, err := secp256k1PublicKey(privateKey)
+		if err != nil {
+			return nil, err
+		}
+		publicKey, err := compressed.Uncompressed()
+		if err != nil {
+			return nil, err
+		}
+		candidates = append(candidates, newRestoredFlowAccount(exported.Path, FlowECDSASecp256k1, hashAlgo, privateKey, publicKey))
+	}
+	if len(candidates) == 0 {
+		return nil, ErrInvalidPrivateKey
+	}
+	return candidates, nil
+}
+
+func newRestoredFlowAccount(path string, signatureAlgo FlowSignatureAlgorithm, hashAlgo FlowHashAlgorithm, privateKey []byte, publicKey keys.PublicKey) *FlowAccount {
+	encodedKey, _ := flowPublicKey(signatureAlgo, privateKey, publicKey)
+	return &FlowAccount{
+		path:          path,
+		signatureAlgo: signatureAlgo,
+		hashAlgo:      hashAlgo,
+		privateKey:    secret.Copy(privateKey),
+		publicKey:     publicKey,
+		encodedKey:    encodedKey,
+	}
+}
+
+// pathCoinType returns the coin type level of a BIP-44 path.
+func pathCoinType(path string) (uint32, bool) {
+	p, err := bip32.ParsePath(path)
+	if err != nil || len(p) < 2 || p[1] < bip32.HardenedKeyStart {
+		return 0, false
+	}
+	return p[1] - bip32.HardenedKeyStart, true
+}
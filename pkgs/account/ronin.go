@@ -0,0 +1,86 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// RoninPathFormat is the path used by Ronin Wallet (Ethereum's coin type): m/44'/60'/0'/0/index
+const RoninPathFormat = "m/44'/60'/0'/0/%d"
+
+// RoninAccount is a Ronin account, displayed as "ronin:..." by Ronin Wallet.
+type RoninAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewRoninAccount derives the Ronin account at m/44'/60'/0'/0/index.
+func NewRoninAccount(seed []byte, index uint32) (*RoninAccount, error) {
+	path := fmt.Sprintf(RoninPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := uncompressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &RoninAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewRoninAccountFromMnemonic derives the Ronin account at the given index from a mnemonic.
+func NewRoninAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*RoninAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewRoninAccount(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *RoninAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *RoninAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *RoninAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *RoninAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *RoninAccount) Chain() address.ChainID {
+	return address.ChainRonin
+}
+
+// Address returns the address in Ronin Wallet's format ("ronin:...").
+func (a *RoninAccount) Address() string {
+	addr, _ := address.NewRoninAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// EVMAddress returns the EIP-55 checksummed 0x form of the address.
+func (a *RoninAccount) EVMAddress() string {
+	addr, _ := address.NewEVMAddress(address.ChainRonin).Generate(a.publicKey.Bytes())
+	return addr
+}
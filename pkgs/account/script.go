@@ -0,0 +1,48 @@
+package account
+
+// ScriptType selects the output script of a UTXO account and, with it,
+// the BIP-43 purpose of its derivation path.
+type ScriptType int
+
+const (
+	// ScriptP2PKH is a legacy pay-to-public-key-hash output (BIP-44).
+	ScriptP2PKH ScriptType = iota
+	// ScriptP2SHP2WPKH is a P2WPKH output nested in P2SH (BIP-49).
+	ScriptP2SHP2WPKH
+	// ScriptP2WPKH is a native SegWit v0 output (BIP-84).
+	ScriptP2WPKH
+)
+
+// Purpose returns the BIP-43 purpose used with the script type.
+func (s ScriptType) Purpose() uint32 {
+	switch s {
+	case ScriptP2SHP2WPKH:
+		return 49
+	case ScriptP2WPKH:
+		return 84
+	default:
+		return 44
+	}
+}
+
+// String returns the script type name.
+func (s ScriptType) String() string {
+	switch s {
+	case ScriptP2PKH:
+		return "p2pkh"
+	case ScriptP2SHP2WPKH:
+		return "p2sh-p2wpkh"
+	case ScriptP2WPKH:
+		return "p2wpkh"
+	default:
+		return "unknown"
+	}
+}
+
+// p2wpkhRedeemScript returns the witness v0 key hash program (OP_0 <20-byte hash>)
+// used as the redeem script of a nested SegWit output.
+func p2wpkhRedeemScript(pubKeyHash []byte) []byte {
+	script := make([]byte, 0, 22)
+	script = append(script, 0x00, 0x14)
+	return append(script, pubKeyHash...)
+}
@@ -0,0 +1,124 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// Sei coin types: native wallets (Compass, Keplr) use the Cosmos coin type,
+// while EVM wallets (MetaMask) derive the same mnemonic with the Ethereum one.
+const (
+	SeiCoinType    = uint32(bip44.CoinTypeCosmos)
+	SeiEVMCoinType = uint32(bip44.CoinTypeEthereum)
+)
+
+// SeiPathFormat is the BIP-44 path for Sei: m/44'/coinType'/0'/0/index
+const SeiPathFormat = "m/44'/%d'/0'/0/%d"
+
+// SeiAccount is a secp256k1 Sei account with both a Bech32 and an EVM address.
+type SeiAccount struct {
+	path       string
+	coinType   uint32
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// SeiAddresses holds both address forms of a single Sei key.
+type SeiAddresses struct {
+	Bech32 string // sei1... (HASH160 of the compressed key)
+	EVM    string // 0x... (Keccak-256 of the uncompressed key, EIP-55)
+}
+
+// NewSeiAccount derives the Sei account at m/44'/coinType'/0'/0/index.
+// Use SeiCoinType for native wallets or SeiEVMCoinType for MetaMask-compatible keys.
+func NewSeiAccount(seed []byte, coinType, index uint32) (*SeiAccount, error) {
+	if coinType != SeiCoinType && coinType != SeiEVMCoinType {
+		return nil, fmt.Errorf("unsupported Sei coin type: %d", coinType)
+	}
+
+	path := fmt.Sprintf(SeiPathFormat, coinType, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &SeiAccount{
+		path:       path,
+		coinType:   coinType,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewSeiAccountFromMnemonic derives the Sei account for the given coin type and index from a mnemonic.
+func NewSeiAccountFromMnemonic(mnemonic, passphrase string, coinType, index uint32) (*SeiAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewSeiAccount(seed, coinType, index)
+}
+
+// Path returns the derivation path.
+func (a *SeiAccount) Path() string {
+	return a.path
+}
+
+// CoinType returns the SLIP-44 coin type used for derivation.
+func (a *SeiAccount) CoinType() uint32 {
+	return a.coinType
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *SeiAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *SeiAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *SeiAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *SeiAccount) Chain() address.ChainID {
+	return address.ChainSei
+}
+
+// Address returns the Bech32 address ("sei1...").
+func (a *SeiAccount) Address() string {
+	addr, _ := address.NewSeiAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// EVMAddress returns the EIP-55 checksummed EVM address of the same key.
+func (a *SeiAccount) EVMAddress() string {
+	uncompressed, err := a.publicKey.Uncompressed()
+	if err != nil {
+		return ""
+	}
+	addr, _ := address.NewEVMAddress(address.ChainSei).Generate(uncompressed.Bytes())
+	return addr
+}
+
+// Addresses returns both the Bech32 and the EVM address of the account.
+func (a *SeiAccount) Addresses() SeiAddresses {
+	return SeiAddresses{
+		Bech32: a.Address(),
+		EVM:    a.EVMAddress(),
+	}
+}
@@ -0,0 +1,94 @@
+package account
+
+import (
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// SigningAccount is a ChainAccount that can produce a signer.Signer for its key.
+// Chain signing code takes a signer.Signer, so an in-memory account key and a
+// remote or hardware signer are interchangeable.
+type SigningAccount interface {
+	ChainAccount
+
+	// Signer returns a signer for the account's private key.
+	Signer() (signer.Signer, error)
+}
+
+// Compile-time checks for the accounts that provide a signer.
+var (
+	_ SigningAccount = (*AvalancheAccount)(nil)
+	_ SigningAccount = (*BitcoinAccount)(nil)
+	_ SigningAccount = (*CosmosAccount)(nil)
+	_ SigningAccount = (*DogecoinAccount)(nil)
+	_ SigningAccount = (*EVMAccount)(nil)
+	_ SigningAccount = (*FilecoinAccount)(nil)
+	_ SigningAccount = (*HarmonyAccount)(nil)
+	_ SigningAccount = (*ICPAccount)(nil)
+	_ SigningAccount = (*LitecoinAccount)(nil)
+	_ SigningAccount = (*NostrAccount)(nil)
+	_ SigningAccount = (*RoninAccount)(nil)
+	_ SigningAccount = (*SeiAccount)(nil)
+	_ SigningAccount = (*SolanaAccount)(nil)
+	_ SigningAccount = (*StellarAccount)(nil)
+	_ SigningAccount = (*SuiAccount)(nil)
+	_ SigningAccount = (*TezosAccount)(nil)
+	_ SigningAccount = (*VeChainAccount)(nil)
+)
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *AvalancheAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *BitcoinAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *CosmosAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *DogecoinAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *EVMAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *FilecoinAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *HarmonyAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *ICPAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *LitecoinAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a BIP-340 Schnorr signer for the account key.
+func (a *NostrAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1Schnorr, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *RoninAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *SeiAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// Signer returns an Ed25519 signer for the account key.
+func (a *SolanaAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeEd25519, a.privateKey) }
+
+// Signer returns an Ed25519 signer for the account key.
+func (a *StellarAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeEd25519, a.privateKey) }
+
+// Signer returns an Ed25519 signer for the account key.
+func (a *SuiAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeEd25519, a.privateKey) }
+
+// Signer returns an Ed25519 signer for the account key.
+func (a *TezosAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeEd25519, a.privateKey) }
+
+// Signer returns a secp256k1 ECDSA signer for the account key.
+func (a *VeChainAccount) Signer() (signer.Signer, error) { return newSigner(signer.SchemeSecp256k1ECDSA, a.privateKey) }
+
+// newSigner builds an in-memory signer holding its own copy of privateKey.
+func newSigner(scheme signer.Scheme, privateKey *secret.Bytes) (s signer.Signer, err error) {
+	privateKey.Use(func(key []byte) { s, err = signer.New(scheme, key) })
+	return s, err
+}
@@ -0,0 +1,296 @@
+package account
+
+import (
+	"cmp"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"slices"
+	"strings"
+	"sync"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// SnapshotVersion is the snapshot format written by this package.
+const SnapshotVersion = 1
+
+// WalletSnapshot is the public state of a Wallet: for each chain the
+// extended public key addresses are derived from, the addresses derived so
+// far and the next unused index. It holds no private key or seed, so a
+// service can persist it in the clear and resume address generation with a
+// WatchWallet instead of loading the seed at startup.
+type WalletSnapshot struct {
+	Version int             `json:"version"`
+	Chains  []ChainSnapshot `json:"chains"`
+}
+
+// ChainSnapshot is one chain of a WalletSnapshot.
+type ChainSnapshot struct {
+	Chain address.ChainID `json:"chain"`
+
+	// Path and Xpub are the parent node of the chain's addresses, such as
+	// m/84'/0'/0'/0 for Bitcoin. They are empty for hardened-only chains
+	// (Solana, Sui, Stellar, ...), which cannot derive new addresses from a
+	// public key.
+	Path string `json:"path,omitempty"`
+	Xpub string `json:"xpub,omitempty"`
+
+	// Next is one past the highest index handed out so far.
+	Next uint32 `json:"next"`
+
+	// Accounts are the derived addresses, in index order.
+	Accounts []AddressRow `json:"accounts"`
+}
+
+// Snapshot returns the public state of the wallet: every account it has
+// derived, grouped by chain, and the extended public key of each BIP-32
+// chain's parent node. Chains lists more chains to include even if no
+// account has been derived on them yet.
+func (w *Wallet) Snapshot(chains ...address.ChainID) (*WalletSnapshot, error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	if w.seed.Len() == 0 {
+		return nil, ErrWalletDestroyed
+	}
+
+	byChain := make(map[address.ChainID]*ChainSnapshot)
+	entry := func(chain address.ChainID) (*ChainSnapshot, error) {
+		if c, ok := byChain[chain]; ok {
+			return c, nil
+		}
+		if !walletChainEnabled(chain) {
+			return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+		}
+		c := &ChainSnapshot{Chain: chain, Accounts: []AddressRow{}}
+		if path, ok := secp256k1Path(chain, 0); ok {
+			c.Path = path[:strings.LastIndex(path, "/")]
+			parent, err := w.parentNode(c.Path)
+			if err != nil {
+				return nil, err
+			}
+			xpub, err := parent.Neuter()
+			if err != nil {
+				return nil, err
+			}
+			c.Xpub = xpub.String()
+		}
+		byChain[chain] = c
+		return c, nil
+	}
+
+	for key, account := range w.accounts {
+		c, err := entry(key.chain)
+		if err != nil {
+			return nil, err
+		}
+		c.Accounts = append(c.Accounts, AddressRow{
+			Index:     key.index,
+			Path:      account.Path(),
+			Address:   account.Address(),
+			PublicKey: hex.EncodeToString(account.PublicKeyBytes()),
+		})
+		c.Next = max(c.Next, key.index+1)
+	}
+	for _, chain := range chains {
+		if _, err := entry(chain); err != nil {
+			return nil, err
+		}
+	}
+
+	s := &WalletSnapshot{Version: SnapshotVersion, Chains: []ChainSnapshot{}}
+	for _, c := range byChain {
+		slices.SortFunc(c.Accounts, func(a, b AddressRow) int { return cmp.Compare(a.Index, b.Index) })
+		s.Chains = append(s.Chains, *c)
+	}
+	slices.SortFunc(s.Chains, func(a, b ChainSnapshot) int { return cmp.Compare(a.Chain, b.Chain) })
+	return s, nil
+}
+
+// Marshal returns the snapshot as indented JSON.
+func (s *WalletSnapshot) Marshal() ([]byte, error) {
+	return json.MarshalIndent(s, "", "  ")
+}
+
+// ParseWalletSnapshot parses a snapshot written by Marshal.
+func ParseWalletSnapshot(data []byte) (*WalletSnapshot, error) {
+	var s WalletSnapshot
+	if err := json.Unmarshal(data, &s); err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidSnapshot, err)
+	}
+	if s.Version != SnapshotVersion {
+		return nil, fmt.Errorf("%w: version %d", ErrInvalidSnapshot, s.Version)
+	}
+	return &s, nil
+}
+
+// WatchWallet resumes address generation from a WalletSnapshot. It returns
+// the snapshot's accounts without deriving them again and derives new
+// indexes on BIP-32 chains from the snapshot's extended public keys. Its
+// accounts are watch-only.
+type WatchWallet struct {
+	mu     sync.Mutex
+	chains map[address.ChainID]*watchChain
+}
+
+// watchChain is the restored state of one chain.
+type watchChain struct {
+	path     string
+	parent   *bip32.ExtendedKey
+	next     uint32
+	accounts map[uint32]*storedAccount
+}
+
+// NewWatchWallet restores a WatchWallet from a snapshot. It returns
+// ErrInvalidSnapshot if an extended key is private or not at the path a
+// Wallet uses for its chain, or an account's public key is not hex.
+func NewWatchWallet(s *WalletSnapshot) (*WatchWallet, error) {
+	w := &WatchWallet{chains: make(map[address.ChainID]*watchChain)}
+	for _, c := range s.Chains {
+		wc := &watchChain{path: c.Path, next: c.Next, accounts: make(map[uint32]*storedAccount)}
+		if c.Xpub != "" {
+			parent, err := bip32.ParseExtendedKey(c.Xpub)
+			if err != nil {
+				return nil, fmt.Errorf("%w: %s xpub: %v", ErrInvalidSnapshot, c.Chain, err)
+			}
+			path, ok := secp256k1Path(c.Chain, 0)
+			if !ok || c.Path != path[:strings.LastIndex(path, "/")] {
+				return nil, fmt.Errorf("%w: %s has no extended key at %q", ErrInvalidSnapshot, c.Chain, c.Path)
+			}
+			if parent.IsPrivate() || int(parent.Depth()) != strings.Count(c.Path, "/") {
+				return nil, fmt.Errorf("%w: %s xpub is not a public key at %s", ErrInvalidSnapshot, c.Chain, c.Path)
+			}
+			wc.parent = parent
+		}
+		for _, row := range c.Accounts {
+			publicKey, err := hex.DecodeString(row.PublicKey)
+			if err != nil {
+				return nil, fmt.Errorf("%w: %s index %d public key", ErrInvalidSnapshot, c.Chain, row.Index)
+			}
+			wc.accounts[row.Index] = &storedAccount{
+				chain:     c.Chain,
+				path:      row.Path,
+				address:   row.Address,
+				publicKey: publicKey,
+			}
+		}
+		w.chains[c.Chain] = wc
+	}
+	return w, nil
+}
+
+// Account returns the account at index on a chain. Accounts missing from
+// the snapshot are derived from the chain's extended public key; on chains
+// without one Account returns ErrNotInSnapshot.
+func (w *WatchWallet) Account(chain address.ChainID, index uint32) (WatchAccount, error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+	return w.account(chain, index)
+}
+
+// Next returns the account at the chain's next unused index and advances
+// the index.
+func (w *WatchWallet) Next(chain address.ChainID) (WatchAccount, error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	wc, ok := w.chains[chain]
+	if !ok {
+		return nil, fmt.Errorf("%w: %s", ErrNotInSnapshot, chain)
+	}
+	if wc.next == bip32.HardenedKeyStart {
+		return nil, fmt.Errorf("%w: %s has no unused index left", ErrInvalidIndex, chain)
+	}
+	account, err := w.account(chain, wc.next)
+	if err != nil {
+		return nil, err
+	}
+	wc.next++
+	return account, nil
+}
+
+// Snapshot returns the wallet's current state, including the accounts it
+// derived since it was restored, for saving before the service stops.
+func (w *WatchWallet) Snapshot() *WalletSnapshot {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	s := &WalletSnapshot{Version: SnapshotVersion, Chains: []ChainSnapshot{}}
+	for chain, wc := range w.chains {
+		c := ChainSnapshot{Chain: chain, Path: wc.path, Next: wc.next, Accounts: []AddressRow{}}
+		if wc.parent != nil {
+			c.Xpub = wc.parent.String()
+		}
+		for index, account := range wc.accounts {
+			c.Accounts = append(c.Accounts, AddressRow{
+				Index:     index,
+				Path:      account.path,
+				Address:   account.address,
+				PublicKey: hex.EncodeToString(account.publicKey),
+			})
+		}
+		slices.SortFunc(c.Accounts, func(a, b AddressRow) int { return cmp.Compare(a.Index, b.Index) })
+		s.Chains = append(s.Chains, c)
+	}
+	slices.SortFunc(s.Chains, func(a, b ChainSnapshot) int { return cmp.Compare(a.Chain, b.Chain) })
+	return s
+}
+
+// account returns a cached account or derives it. The caller must hold w.mu.
+func (w *WatchWallet) account(chain address.ChainID, index uint32) (WatchAccount, error) {
+	wc, ok := w.chains[chain]
+	if !ok {
+		return nil, fmt.Errorf("%w: %s", ErrNotInSnapshot, chain)
+	}
+	if account, ok := wc.accounts[index]; ok {
+		return account, nil
+	}
+
+	path, ok := secp256k1Path(chain, index)
+	if wc.parent == nil || !ok {
+		return nil, fmt.Errorf("%w: %s index %d", ErrNotInSnapshot, chain, index)
+	}
+	if bip32.IsHardened(index) {
+		return nil, fmt.Errorf("%w: %d is a hardened index", ErrInvalidIndex, index)
+	}
+	key, err := wc.parent.DeriveFromPathString("m" + path[strings.LastIndex(path, "/"):])
+	if err != nil {
+		return nil, err
+	}
+	derived, err := secp256k1Account(chain, path, nil, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	account := &storedAccount{
+		chain:     chain,
+		path:      path,
+		address:   derived.Address(),
+		publicKey: derived.PublicKeyBytes(),
+	}
+	wc.accounts[index] = account
+	return account, nil
+}
+
+// storedAccount is a watch-only account rebuilt from serialized public data,
+// by a WatchWallet or UnmarshalAccount.
+type storedAccount struct {
+	chain     address.ChainID
+	path      string
+	address   string
+	publicKey []byte
+}
+
+// Path returns the derivation path.
+func (a *storedAccount) Path() string { return a.path }
+
+// PublicKeyBytes returns the public key in the chain's native encoding.
+func (a *storedAccount) PublicKeyBytes() []byte { return a.publicKey }
+
+// Address returns the account's default address.
+func (a *storedAccount) Address() string { return a.address }
+
+// Chain returns the chain identifier.
+func (a *storedAccount) Chain() address.ChainID { return a.chain }
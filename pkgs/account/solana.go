@@ -0,0 +1,94 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// SolanaPathFormat is the path used by Phantom and Solflare: m/44'/501'/account'/0'
+const SolanaPathFormat = "m/44'/501'/%d'/0'"
+
+// SolanaAccount is a Solana key pair derived with SLIP-10 Ed25519.
+type SolanaAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewSolanaAccount derives the Solana account at m/44'/501'/index'/0' from a seed.
+func NewSolanaAccount(seed []byte, index uint32) (*SolanaAccount, error) {
+	path := fmt.Sprintf(SolanaPathFormat, index)
+
+	privateKey, publicKey, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &SolanaAccount{
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewSolanaAccountFromMnemonic derives the Solana account at the given index from a mnemonic.
+func NewSolanaAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*SolanaAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewSolanaAccount(seed, index)
+}
+
+// NewSolanaAccountFromSeed is NewSolanaAccount for a bip39.Seed, so that one seed
+// derived from a mnemonic serves every chain.
+func NewSolanaAccountFromSeed(seed *bip39.Seed, index uint32) (acc *SolanaAccount, err error) {
+	seed.Use(func(b []byte) { acc, err = NewSolanaAccount(b, index) })
+	return acc, err
+}
+
+// NewSolanaAccountFromParsedMnemonic is NewSolanaAccountFromMnemonic for a parsed mnemonic.
+// The seed is wiped once the account is derived.
+func NewSolanaAccountFromParsedMnemonic(mnemonic *bip39.Mnemonic, passphrase string, index uint32) (*SolanaAccount, error) {
+	seed, err := mnemonic.Seed(passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer seed.Destroy()
+	return NewSolanaAccountFromSeed(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *SolanaAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte Ed25519 seed.
+func (a *SolanaAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *SolanaAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte Ed25519 public key.
+func (a *SolanaAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *SolanaAccount) Chain() address.ChainID {
+	return address.ChainSolana
+}
+
+// Address returns the Base58-encoded public key.
+func (a *SolanaAccount) Address() string {
+	addr, _ := address.NewSolanaAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
@@ -0,0 +1,128 @@
+package account
+
+import (
+	"crypto/sha256"
+	"encoding/binary"
+	"fmt"
+	"math/big"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/crypto/stark"
+)
+
+// StarknetPathFormat is the path used by Argent X and Braavos: m/44'/9004'/0'/0/index
+const StarknetPathFormat = "m/44'/9004'/0'/0/%d"
+
+// EIP-2645 layer and application names used by StarkEx deployments
+const (
+	EIP2645LayerStarkEx           = "starkex"
+	EIP2645ApplicationStarkDeploy = "starkdeployement"
+)
+
+// StarknetAccount is a Stark curve key pair with its counterfactual account address.
+type StarknetAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  []byte
+}
+
+// EIP2645Path builds the EIP-2645 path
+// m/2645'/layer'/application'/eth_address_1'/eth_address_2'/index
+// where layer and application are the low 31 bits of SHA256(name) and
+// eth_address_1, eth_address_2 are the low and next 31 bits of the Ethereum address.
+func EIP2645Path(layer, application string, ethAddress []byte, index uint32) (string, error) {
+	if len(ethAddress) != 20 {
+		return "", fmt.Errorf("ethereum address must be 20 bytes, got %d", len(ethAddress))
+	}
+
+	eth := new(big.Int).SetBytes(ethAddress)
+	mask := big.NewInt(0x7fffffff)
+	eth1 := new(big.Int).And(eth, mask)
+	eth2 := new(big.Int).And(eth.Rsh(eth, 31), mask)
+
+	return fmt.Sprintf("m/2645'/%d'/%d'/%d'/%d'/%d",
+		eip2645Component(layer), eip2645Component(application), eth1.Uint64(), eth2.Uint64(), index), nil
+}
+
+// eip2645Component returns the low 31 bits of SHA256(name).
+func eip2645Component(name string) uint32 {
+	hash := sha256.Sum256([]byte(name))
+	return binary.BigEndian.Uint32(hash[28:]) & 0x7fffffff
+}
+
+// NewStarknetAccount derives the Starknet key at m/44'/9004'/0'/0/index.
+func NewStarknetAccount(seed []byte, index uint32) (*StarknetAccount, error) {
+	return NewStarknetAccountAtPath(seed, fmt.Sprintf(StarknetPathFormat, index))
+}
+
+// NewStarknetAccountAtPath derives a secp256k1 key at an arbitrary path (e.g., from
+// EIP2645Path) and grinds it into a valid Stark private key.
+func NewStarknetAccountAtPath(seed []byte, path string) (*StarknetAccount, error) {
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	privateKey, err := stark.GrindKey(key.PrivateKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := stark.PublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+
+	return &StarknetAccount{
+		path:       path,
+		privateKey: secret.Take(stark.FeltBytes(privateKey)),
+		publicKey:  stark.FeltBytes(publicKey),
+	}, nil
+}
+
+// NewStarknetAccountFromMnemonic derives the Starknet key at the given index from a mnemonic.
+func NewStarknetAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*StarknetAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewStarknetAccount(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *StarknetAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte Stark private key.
+func (a *StarknetAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *StarknetAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte Stark public key (x-coordinate).
+func (a *StarknetAccount) PublicKeyBytes() []byte {
+	return a.publicKey
+}
+
+// Chain returns the chain identifier.
+func (a *StarknetAccount) Chain() address.ChainID {
+	return address.ChainStarknet
+}
+
+// Address returns the counterfactual OpenZeppelin account address.
+func (a *StarknetAccount) Address() string {
+	addr, _ := address.NewStarknetAddress().Generate(a.publicKey)
+	return addr
+}
+
+// AddressForClass returns the counterfactual address of an account contract of the
+// given class whose constructor takes the public key, deployed with the public key as salt.
+func (a *StarknetAccount) AddressForClass(classHash *big.Int) (string, error) {
+	return address.NewStarknetAddressWithClassHash(classHash).Generate(a.publicKey)
+}
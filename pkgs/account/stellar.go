@@ -0,0 +1,106 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// StellarPathFormat is the SEP-0005 derivation path: m/44'/148'/account'
+const StellarPathFormat = "m/44'/148'/%d'"
+
+// StellarAccount is a Stellar key pair derived with SLIP-10 Ed25519.
+type StellarAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewStellarAccount derives the Stellar account at m/44'/148'/index' from a seed.
+func NewStellarAccount(seed []byte, index uint32) (*StellarAccount, error) {
+	path := fmt.Sprintf(StellarPathFormat, index)
+
+	privateKey, publicKey, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &StellarAccount{
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewStellarAccountFromMnemonic derives the Stellar account at the given index from a mnemonic.
+func NewStellarAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*StellarAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewStellarAccount(seed, index)
+}
+
+// NewStellarAccountFromSecret imports an account from a secret seed StrKey ('S...').
+func NewStellarAccountFromSecret(seed string) (*StellarAccount, error) {
+	privateKey, err := address.DecodeStellarSeed(seed)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := ed25519PublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+
+	return &StellarAccount{
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// Path returns the derivation path, or an empty string for imported accounts.
+func (a *StellarAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte Ed25519 seed.
+func (a *StellarAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *StellarAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte Ed25519 public key.
+func (a *StellarAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *StellarAccount) Chain() address.ChainID {
+	return address.ChainStellar
+}
+
+// Address returns the account address ('G...').
+func (a *StellarAccount) Address() string {
+	addr, _ := address.NewStellarAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// Secret returns the secret seed StrKey ('S...').
+func (a *StellarAccount) Secret() string {
+	var seed string
+	a.privateKey.Use(func(key []byte) { seed, _ = address.EncodeStellarSeed(key) })
+	return seed
+}
+
+// MuxedAddress returns the muxed account address ('M...') for the given ID.
+func (a *StellarAccount) MuxedAddress(id uint64) string {
+	addr, _ := address.EncodeStellarMuxed(a.publicKey.Bytes(), id)
+	return addr
+}
@@ -0,0 +1,94 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// SuiPathFormat is the Ed25519 path used by Sui Wallet: m/44'/784'/account'/0'/0'
+const SuiPathFormat = "m/44'/784'/%d'/0'/0'"
+
+// SuiAccount is a Sui Ed25519 key pair derived with SLIP-10.
+type SuiAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewSuiAccount derives the Sui account at m/44'/784'/index'/0'/0' from a seed.
+func NewSuiAccount(seed []byte, index uint32) (*SuiAccount, error) {
+	path := fmt.Sprintf(SuiPathFormat, index)
+
+	privateKey, publicKey, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &SuiAccount{
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewSuiAccountFromMnemonic derives the Sui account at the given index from a mnemonic.
+func NewSuiAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*SuiAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewSuiAccount(seed, index)
+}
+
+// NewSuiAccountFromSeed is NewSuiAccount for a bip39.Seed, so that one seed
+// derived from a mnemonic serves every chain.
+func NewSuiAccountFromSeed(seed *bip39.Seed, index uint32) (acc *SuiAccount, err error) {
+	seed.Use(func(b []byte) { acc, err = NewSuiAccount(b, index) })
+	return acc, err
+}
+
+// NewSuiAccountFromParsedMnemonic is NewSuiAccountFromMnemonic for a parsed mnemonic.
+// The seed is wiped once the account is derived.
+func NewSuiAccountFromParsedMnemonic(mnemonic *bip39.Mnemonic, passphrase string, index uint32) (*SuiAccount, error) {
+	seed, err := mnemonic.Seed(passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer seed.Destroy()
+	return NewSuiAccountFromSeed(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *SuiAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte Ed25519 seed.
+func (a *SuiAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *SuiAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte Ed25519 public key.
+func (a *SuiAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *SuiAccount) Chain() address.ChainID {
+	return address.ChainSui
+}
+
+// Address returns the 0x-prefixed BLAKE2b-256 hash of the flagged public key.
+func (a *SuiAccount) Address() string {
+	addr, _ := address.NewSuiAddress().Generate(a.publicKey.Bytes())
+	return addr
+}
@@ -0,0 +1,129 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// TezosPathFormat is the SLIP-10 path used by Tezos wallets: m/44'/1729'/account'/0'
+const TezosPathFormat = "m/44'/1729'/%d'/0'"
+
+// TezosAccount is an Ed25519 Tezos account (tz1 address).
+type TezosAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewTezosAccount derives the Tezos account at m/44'/1729'/account'/0'.
+func NewTezosAccount(seed []byte, account uint32) (*TezosAccount, error) {
+	path := fmt.Sprintf(TezosPathFormat, account)
+
+	privateKey, publicKey, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &TezosAccount{
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewTezosAccountFromMnemonic derives a Tezos account from a mnemonic.
+func NewTezosAccountFromMnemonic(mnemonic, passphrase string, account uint32) (*TezosAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewTezosAccount(seed, account)
+}
+
+// NewTezosAccountFromSecretKey imports an account from an "edsk" secret key
+// in either the 54-character seed form or the 98-character expanded form.
+func NewTezosAccountFromSecretKey(secretKey string) (*TezosAccount, error) {
+	var seed []byte
+	switch len(secretKey) {
+	case 54:
+		decoded, err := address.Base58CheckDecodeWithPrefix(address.TezosEd25519SeedPrefix, secretKey)
+		if err != nil {
+			return nil, err
+		}
+		seed = decoded
+	case 98:
+		decoded, err := address.Base58CheckDecodeWithPrefix(address.TezosEd25519SecretKeyPrefix, secretKey)
+		if err != nil {
+			return nil, err
+		}
+		seed = decoded[:32]
+	default:
+		return nil, fmt.Errorf("%w: unrecognized edsk length %d", ErrInvalidPrivateKey, len(secretKey))
+	}
+
+	publicKey, err := ed25519PublicKey(seed)
+	if err != nil {
+		return nil, err
+	}
+
+	return &TezosAccount{
+		privateKey: secret.Take(seed),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// Path returns the derivation path, or an empty string for imported accounts.
+func (a *TezosAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte Ed25519 seed.
+func (a *TezosAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *TezosAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte Ed25519 public key.
+func (a *TezosAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *TezosAccount) Chain() address.ChainID {
+	return address.ChainTezos
+}
+
+// Address returns the tz1 address (Base58Check of the Blake2b-160 key hash).
+func (a *TezosAccount) Address() string {
+	addr, _ := address.NewTezosAddress().GenerateTz1(a.publicKey.Bytes())
+	return addr
+}
+
+// EncodedPublicKey returns the "edpk" encoded public key.
+func (a *TezosAccount) EncodedPublicKey() string {
+	return address.Base58CheckEncodeWithPrefix(address.TezosEd25519PKPrefix, a.publicKey.Bytes())
+}
+
+// EncodedSecretKey returns the 98-character "edsk" secret key (seed || public key),
+// the form used by octez-client and Taquito.
+func (a *TezosAccount) EncodedSecretKey() string {
+	secretKey := make([]byte, 0, 64)
+	a.privateKey.Use(func(key []byte) { secretKey = append(secretKey, key...) })
+	secretKey = append(secretKey, a.publicKey.Bytes()...)
+	defer secret.Wipe(secretKey)
+	return address.Base58CheckEncodeWithPrefix(address.TezosEd25519SecretKeyPrefix, secretKey)
+}
+
+// EncodedSeed returns the 54-character "edsk" seed.
+func (a *TezosAccount) EncodedSeed() string {
+	var seed string
+	a.privateKey.Use(func(key []byte) { seed = address.Base58CheckEncodeWithPrefix(address.TezosEd25519SeedPrefix, key) })
+	return seed
+}
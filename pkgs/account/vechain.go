@@ -0,0 +1,95 @@
+package account
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// VeChainPathFormat is the BIP-44 path used by Sync, VeWorld and Ledger: m/44'/818'/0'/0/index
+const VeChainPathFormat = "m/44'/818'/0'/0/%d"
+
+// VeChainAccount is a VeChainThor account. Addresses are built like Ethereum's
+// (Keccak-256 of the uncompressed key) but keys are derived at coin type 818.
+type VeChainAccount struct {
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey
+}
+
+// NewVeChainAccount derives the VeChain account at m/44'/818'/0'/0/index.
+func NewVeChainAccount(seed []byte, index uint32) (*VeChainAccount, error) {
+	path := fmt.Sprintf(VeChainPathFormat, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := uncompressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &VeChainAccount{
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewVeChainAccountFromMnemonic derives the VeChain account at the given index from a mnemonic.
+func NewVeChainAccountFromMnemonic(mnemonic, passphrase string, index uint32) (*VeChainAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return NewVeChainAccount(seed, index)
+}
+
+// Path returns the derivation path.
+func (a *VeChainAccount) Path() string {
+	return a.path
+}
+
+// CoinType returns the SLIP-44 coin type.
+func (a *VeChainAccount) CoinType() bip44.CoinType {
+	return bip44.CoinTypeVeChain
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte secp256k1 private key.
+func (a *VeChainAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *VeChainAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *VeChainAccount) PublicKeyBytes() []byte {
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *VeChainAccount) Chain() address.ChainID {
+	return address.ChainVeChain
+}
+
+// Address returns the checksummed address, as displayed by Sync and VeWorld
+// (the same mixed-case checksum as EIP-55).
+func (a *VeChainAccount) Address() string {
+	addr, _ := address.NewEVMAddress(address.ChainVeChain).Generate(a.publicKey.Bytes())
+	return addr
+}
+
+// ThorAddress returns the lowercase 0x address used by the Thor REST API,
+// thor-devkit and Connex, which compare addresses case-sensitively.
+func (a *VeChainAccount) ThorAddress() string {
+	return strings.ToLower(a.Address())
+}
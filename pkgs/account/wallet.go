@@ -0,0 +1,357 @@
+package account
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"sync"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// Wallet derives accounts for any supported chain from a single seed.
+//
+// Accounts are derived on first use and cached. For secp256k1 chains the
+// wallet also caches the BIP-32 parent node of each path (e.g. m/44'/60'/0'/0),
+// so deriving further indexes costs a single non-hardened step.
+//
+// The seed is held in a secret.Bytes buffer. Call Destroy when the wallet is
+// no longer needed to wipe it along with every derived key.
+type Wallet struct {
+	seed *secret.Bytes
+
+	mu       sync.RWMutex
+	master   *bip32.ExtendedKey
+	nodes    map[string]*bip32.ExtendedKey
+	accounts map[walletAccountKey]ChainAccount
+}
+
+type walletAccountKey struct {
+	chain address.ChainID
+	index uint32
+}
+
+// NewWallet creates a wallet from a BIP-39 seed.
+func NewWallet(seed []byte) (*Wallet, error) {
+	if len(seed) < 16 || len(seed) > 64 {
+		return nil, ErrInvalidSeed
+	}
+
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidSeed, err)
+	}
+
+	return &Wallet{
+		seed:     secret.Copy(seed),
+		master:   master,
+		nodes:    make(map[string]*bip32.ExtendedKey),
+		accounts: make(map[walletAccountKey]ChainAccount),
+	}, nil
+}
+
+// NewWalletFromMnemonic creates a wallet from a BIP-39 mnemonic and passphrase.
+func NewWalletFromMnemonic(mnemonic, passphrase string) (*Wallet, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(seed)
+	return NewWallet(seed)
+}
+
+// NewWalletFromSeed is NewWallet for a bip39.Seed. The wallet keeps its own
+// copy, so the seed may be destroyed once NewWalletFromSeed returns.
+func NewWalletFromSeed(seed *bip39.Seed) (w *Wallet, err error) {
+	seed.Use(func(b []byte) { w, err = NewWallet(b) })
+	return w, err
+}
+
+// NewWalletFromParsedMnemonic is NewWalletFromMnemonic for a parsed mnemonic.
+func NewWalletFromParsedMnemonic(mnemonic *bip39.Mnemonic, passphrase string) (*Wallet, error) {
+	seed, err := mnemonic.Seed(passphrase)
+	if err != nil {
+		return nil, err
+	}
+	defer seed.Destroy()
+	return NewWalletFromSeed(seed)
+}
+
+// Destroy wipes the seed, the cached BIP-32 nodes and the private keys of all
+// accounts the wallet has returned. Neither the wallet nor those accounts may
+// be used afterwards; further derivation fails with ErrWalletDestroyed.
+func (w *Wallet) Destroy() {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	w.seed.Destroy()
+	w.master.Wipe()
+	for _, node := range w.nodes {
+		node.Wipe()
+	}
+	for _, account := range w.accounts {
+		account.Destroy()
+	}
+	clear(w.nodes)
+	clear(w.accounts)
+}
+
+// Account returns the account at the given index on a chain, deriving it on first use.
+// The index is the address index for chains with BIP-44 address paths and the
+// account index for hardened-only (Ed25519) chains. Bitcoin and Litecoin accounts
+// use native SegWit (BIP-84); EVM chains use m/44'/60'/0'/0/index.
+func (w *Wallet) Account(chain address.ChainID, index uint32) (ChainAccount, error) {
+	w.mu.Lock()
+	defer w.mu.Unlock()
+
+	key := walletAccountKey{chain: chain, index: index}
+	if account, ok := w.accounts[key]; ok {
+		return account, nil
+	}
+
+	account, err := w.derive(chain, index)
+	if err != nil {
+		return nil, err
+	}
+
+	w.accounts[key] = account
+	return account, nil
+}
+
+// Accounts returns the accounts at indexes [0, count) on a chain.
+func (w *Wallet) Accounts(chain address.ChainID, count uint32) ([]ChainAccount, error) {
+	accounts := make([]ChainAccount, 0, count)
+	for i := uint32(0); i < count; i++ {
+		account, err := w.Account(chain, i)
+		if err != nil {
+			return nil, err
+		}
+		accounts = append(accounts, account)
+	}
+	return accounts, nil
+}
+
+// WalletChains returns the chains a Wallet can derive accounts for.
+// Chains left out of the build with a ca_no_* build tag are omitted.
+func WalletChains() []address.ChainID {
+	candidates := []address.ChainID{
+		address.ChainBitcoin, address.ChainLitecoin, address.ChainDogecoin,
+		address.ChainCosmos, address.ChainSei, address.ChainSolana, address.ChainSui,
+		address.ChainStellar, address.ChainNostr, address.ChainNeo, address.ChainStarknet,
+	}
+
+	evmChains := make([]address.ChainID, 0, len(address.EVMChains()))
+	for chain := range address.EVMChains() {
+		evmChains = append(evmChains, chain)
+	}
+	sort.Slice(evmChains, func(i, j int) bool { return evmChains[i] < evmChains[j] })
+
+	chains := make([]address.ChainID, 0, len(candidates)+len(evmChains))
+	for _, chain := range append(candidates, evmChains...) {
+		if walletChainEnabled(chain) {
+			chains = append(chains, chain)
+		}
+	}
+	return chains
+}
+
+// walletCoinTypes are the SLIP-44 coin types a Wallet derives with. Every EVM
+// chain uses Ethereum's, as MetaMask does.
+var walletCoinTypes = map[address.ChainID]bip44.CoinType{
+	address.ChainBitcoin:  bip44.CoinTypeBitcoin,
+	address.ChainLitecoin: bip44.CoinTypeLitecoin,
+	address.ChainDogecoin: bip44.CoinTypeDogecoin,
+	address.ChainCosmos:   bip44.CoinTypeCosmos,
+	address.ChainSei:      bip44.CoinTypeCosmos,
+	address.ChainSolana:   bip44.CoinTypeSolana,
+	address.ChainSui:      bip44.CoinTypeSui,
+	address.ChainStellar:  bip44.CoinTypeStellar,
+	address.ChainNostr:    bip44.CoinTypeNostr,
+	address.ChainNeo:      bip44.CoinTypeNeo,
+	address.ChainStarknet: bip44.CoinTypeStarknet,
+}
+
+// WalletCoinType returns the SLIP-44 coin type in the paths a Wallet derives
+// for chain.
+func WalletCoinType(chain address.ChainID) (bip44.CoinType, error) {
+	if !walletChainEnabled(chain) {
+		return 0, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+	}
+	if coinType, ok := walletCoinTypes[chain]; ok {
+		return coinType, nil
+	}
+	if _, ok := address.EVMChains()[chain]; ok {
+		return bip44.CoinTypeEthereum, nil
+	}
+	return 0, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+}
+
+// walletChainEnabled reports whether a chain is included in the build. Nostr
+// has no address generator in the factory and is always available.
+func walletChainEnabled(chain address.ChainID) bool {
+	return chain == address.ChainNostr || address.IsSupported(chain)
+}
+
+// derive builds a new account. The caller must hold w.mu.
+func (w *Wallet) derive(chain address.ChainID, index uint32) (ChainAccount, error) {
+	if !walletChainEnabled(chain) {
+		return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+	}
+	if w.seed.Len() == 0 {
+		return nil, ErrWalletDestroyed
+	}
+
+	switch chain {
+	case address.ChainSolana, address.ChainSui, address.ChainStellar,
+		address.ChainNostr, address.ChainNeo, address.ChainStarknet:
+		var (
+			account ChainAccount
+			err     error
+		)
+		w.seed.Use(func(seed []byte) {
+			account, err = seedAccount(chain, seed, index)
+		})
+		return account, err
+	}
+
+	path, ok := secp256k1Path(chain, index)
+	if !ok {
+		return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+	}
+	key, err := w.secp256k1Key(path)
+	if err != nil {
+		return nil, err
+	}
+	return secp256k1Account(chain, path, key.PrivateKeyBytes(), key.PublicKeyBytes())
+}
+
+// secp256k1Path returns the path of the account at index on a chain the
+// wallet derives with BIP-32, or false for the other chains.
+func secp256k1Path(chain address.ChainID, index uint32) (string, bool) {
+	switch chain {
+	case address.ChainBitcoin:
+		return fmt.Sprintf(BitcoinPathFormat, ScriptP2WPKH.Purpose(), 0, index), true
+	case address.ChainLitecoin:
+		return fmt.Sprintf(LitecoinPathFormat, ScriptP2WPKH.Purpose(), 0, index), true
+	case address.ChainDogecoin:
+		return fmt.Sprintf(DogecoinPathFormat, 0, index), true
+	case address.ChainCosmos:
+		return fmt.Sprintf(CosmosPathFormat, index), true
+	case address.ChainSei:
+		return fmt.Sprintf(SeiPathFormat, SeiCoinType, index), true
+	}
+	if _, ok := address.EVMChains()[chain]; ok {
+		return fmt.Sprintf(EVMPathFormat, bip44.CoinTypeEthereum, index), true
+	}
+	return "", false
+}
+
+// secp256k1Account builds the account at path from its keys. compressed is
+// the 33-byte public key; privateKey is nil for watch-only use.
+func secp256k1Account(chain address.ChainID, path string, privateKey, compressed []byte) (ChainAccount, error) {
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, compressed)
+	if err != nil {
+		return nil, err
+	}
+
+	switch chain {
+	case address.ChainBitcoin:
+		return &BitcoinAccount{
+			path:       path,
+			scriptType: ScriptP2WPKH,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+
+	case address.ChainLitecoin:
+		return &LitecoinAccount{
+			path:       path,
+			scriptType: ScriptP2WPKH,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+
+	case address.ChainDogecoin:
+		return &DogecoinAccount{
+			path:       path,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+
+	case address.ChainCosmos:
+		return &CosmosAccount{
+			path:       path,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+
+	case address.ChainSei:
+		return &SeiAccount{
+			path:       path,
+			coinType:   SeiCoinType,
+			privateKey: secret.Take(privateKey),
+			publicKey:  publicKey,
+		}, nil
+	}
+
+	uncompressed, err := publicKey.Uncompressed()
+	if err != nil {
+		return nil, err
+	}
+	return &EVMAccount{
+		path:       path,
+		chain:      chain,
+		coinType:   bip44.CoinTypeEthereum,
+		privateKey: secret.Take(privateKey),
+		publicKey:  uncompressed,
+	}, nil
+}
+
+// seedAccount derives an account on a chain that does not use the wallet's
+// BIP-32 tree.
+func seedAccount(chain address.ChainID, seed []byte, index uint32) (ChainAccount, error) {
+	switch chain {
+	case address.ChainSolana:
+		return NewSolanaAccount(seed, index)
+	case address.ChainSui:
+		return NewSuiAccount(seed, index)
+	case address.ChainStellar:
+		return NewStellarAccount(seed, index)
+	case address.ChainNostr:
+		return NewNostrAccount(seed, index)
+	case address.ChainNeo:
+		return NewNeoAccount(seed, index)
+	default:
+		return NewStarknetAccount(seed, index)
+	}
+}
+
+// secp256k1Key derives the key at path from the cached parent node, deriving
+// and caching the parent on first use. The caller must hold w.mu.
+func (w *Wallet) secp256k1Key(path string) (*bip32.ExtendedKey, error) {
+	cut := strings.LastIndex(path, "/")
+	parent, err := w.parentNode(path[:cut])
+	if err != nil {
+		return nil, err
+	}
+	return parent.DeriveFromPathString("m" + path[cut:])
+}
+
+// parentNode returns the node at parentPath from the cache, deriving and
+// caching it on first use. The caller must hold w.mu.
+func (w *Wallet) parentNode(parentPath string) (*bip32.ExtendedKey, error) {
+	if parent, ok := w.nodes[parentPath]; ok {
+		return parent, nil
+	}
+	parent, err := w.master.DeriveFromPathString(parentPath)
+	if err != nil {
+		return nil, err
+	}
+	w.nodes[parentPath] = parent
+	return parent, nil
+}
@@ -0,0 +1,283 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// WatchAccount is the public half of an account: enough to show and monitor
+// addresses, but with no private key. Watch-only types have no PrivateKeyBytes
+// or Signer method, so signing with them does not compile.
+type WatchAccount interface {
+	// Path returns the derivation path, or an empty string when it is unknown
+	// (imported private keys, watch-only accounts built from a bare public key).
+	Path() string
+
+	// PublicKeyBytes returns the public key in the chain's native encoding.
+	PublicKeyBytes() []byte
+
+	// Address returns the account's default address.
+	Address() string
+
+	// Chain returns the chain identifier.
+	Chain() address.ChainID
+}
+
+// Compile-time checks that the watch-only types implement WatchAccount.
+var (
+	_ WatchAccount = (*BitcoinWatchAccount)(nil)
+	_ WatchAccount = (*CosmosWatchAccount)(nil)
+	_ WatchAccount = (*EVMWatchAccount)(nil)
+	_ WatchAccount = (*SolanaWatchAccount)(nil)
+	_ WatchAccount = (*SuiWatchAccount)(nil)
+)
+
+// BitcoinWatchAccount is a watch-only Bitcoin account.
+type BitcoinWatchAccount struct {
+	account BitcoinAccount
+}
+
+// NewBitcoinWatchAccount creates a watch-only Bitcoin account from a compressed
+// or uncompressed public key. Addresses use the compressed key.
+func NewBitcoinWatchAccount(publicKey []byte, scriptType ScriptType) (*BitcoinWatchAccount, error) {
+	compressed, err := compressedPublicKey(publicKey)
+	if err != nil {
+		return nil, err
+	}
+	return &BitcoinWatchAccount{account: BitcoinAccount{scriptType: scriptType, publicKey: compressed}}, nil
+}
+
+// NewBitcoinWatchAccountFromXpub derives the receive address at index from an
+// account-level xpub (m/purpose'/0'/account'), i.e. the key at .../0/index.
+func NewBitcoinWatchAccountFromXpub(xpub string, scriptType ScriptType, index uint32) (*BitcoinWatchAccount, error) {
+	key, account, err := deriveFromAccountXpub(xpub, index)
+	if err != nil {
+		return nil, err
+	}
+	publicKey, err := compressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+	return &BitcoinWatchAccount{account: BitcoinAccount{
+		path:       fmt.Sprintf(BitcoinPathFormat, scriptType.Purpose(), account, index),
+		scriptType: scriptType,
+		publicKey:  publicKey,
+	}}, nil
+}
+
+// Path returns the derivation path, if known.
+func (a *BitcoinWatchAccount) Path() string { return a.account.Path() }
+
+// ScriptType returns the script type used for Address.
+func (a *BitcoinWatchAccount) ScriptType() ScriptType { return a.account.ScriptType() }
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *BitcoinWatchAccount) PublicKeyBytes() []byte { return a.account.PublicKeyBytes() }
+
+// Chain returns the chain identifier.
+func (a *BitcoinWatchAccount) Chain() address.ChainID { return a.account.Chain() }
+
+// Address returns the address matching the account's script type.
+func (a *BitcoinWatchAccount) Address() string { return a.account.Address() }
+
+// LegacyAddress returns the P2PKH address (1...).
+func (a *BitcoinWatchAccount) LegacyAddress() string { return a.account.LegacyAddress() }
+
+// NestedSegWitAddress returns the P2SH-P2WPKH address (3...).
+func (a *BitcoinWatchAccount) NestedSegWitAddress() string { return a.account.NestedSegWitAddress() }
+
+// SegWitAddress returns the native SegWit P2WPKH address (bc1q...).
+func (a *BitcoinWatchAccount) SegWitAddress() string { return a.account.SegWitAddress() }
+
+// EVMWatchAccount is a watch-only account on an EVM-compatible chain.
+type EVMWatchAccount struct {
+	account EVMAccount
+}
+
+// NewEVMWatchAccount creates a watch-only EVM account from a compressed or uncompressed public key.
+func NewEVMWatchAccount(publicKey []byte, chain address.ChainID) (*EVMWatchAccount, error) {
+	if _, ok := address.EVMChains()[chain]; !ok {
+		return nil, fmt.Errorf("%w: %s is not an EVM chain", address.ErrUnsupportedChain, chain)
+	}
+
+	compressed, err := compressedPublicKey(publicKey)
+	if err != nil {
+		return nil, err
+	}
+	uncompressed, err := compressed.Uncompressed()
+	if err != nil {
+		return nil, err
+	}
+	return &EVMWatchAccount{account: EVMAccount{
+		chain:     chain,
+		publicKey: uncompressed,
+	}}, nil
+}
+
+// NewEVMWatchAccountFromXpub derives the address at index from an account-level
+// xpub (m/44'/60'/account'), as exported by hardware wallets for Ethereum.
+func NewEVMWatchAccountFromXpub(xpub string, chain address.ChainID, index uint32) (*EVMWatchAccount, error) {
+	key, account, err := deriveFromAccountXpub(xpub, index)
+	if err != nil {
+		return nil, err
+	}
+
+	watch, err := NewEVMWatchAccount(key.PublicKeyBytes(), chain)
+	if err != nil {
+		return nil, err
+	}
+	watch.account.path = fmt.Sprintf("m/44'/60'/%d'/0/%d", account, index)
+	return watch, nil
+}
+
+// Path returns the derivation path, if known.
+func (a *EVMWatchAccount) Path() string { return a.account.Path() }
+
+// PublicKeyBytes returns the 65-byte uncompressed public key.
+func (a *EVMWatchAccount) PublicKeyBytes() []byte { return a.account.PublicKeyBytes() }
+
+// Chain returns the chain identifier.
+func (a *EVMWatchAccount) Chain() address.ChainID { return a.account.Chain() }
+
+// Address returns the EIP-55 checksummed address.
+func (a *EVMWatchAccount) Address() string { return a.account.Address() }
+
+// CosmosWatchAccount is a watch-only Cosmos Hub account.
+type CosmosWatchAccount struct {
+	account CosmosAccount
+}
+
+// NewCosmosWatchAccount creates a watch-only Cosmos account from a secp256k1 public key.
+func NewCosmosWatchAccount(publicKey []byte) (*CosmosWatchAccount, error) {
+	compressed, err := compressedPublicKey(publicKey)
+	if err != nil {
+		return nil, err
+	}
+	return &CosmosWatchAccount{account: CosmosAccount{publicKey: compressed}}, nil
+}
+
+// NewCosmosWatchAccountFromXpub derives the address at index from an
+// account-level xpub (m/44'/118'/account').
+func NewCosmosWatchAccountFromXpub(xpub string, index uint32) (*CosmosWatchAccount, error) {
+	key, account, err := deriveFromAccountXpub(xpub, index)
+	if err != nil {
+		return nil, err
+	}
+	publicKey, err := compressedPublicKey(key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+	return &CosmosWatchAccount{account: CosmosAccount{
+		path:      fmt.Sprintf("m/44'/118'/%d'/0/%d", account, index),
+		publicKey: publicKey,
+	}}, nil
+}
+
+// Path returns the derivation path, if known.
+func (a *CosmosWatchAccount) Path() string { return a.account.Path() }
+
+// PublicKeyBytes returns the 33-byte compressed public key.
+func (a *CosmosWatchAccount) PublicKeyBytes() []byte { return a.account.PublicKeyBytes() }
+
+// Chain returns the chain identifier.
+func (a *CosmosWatchAccount) Chain() address.ChainID { return a.account.Chain() }
+
+// Address returns the cosmos1... address.
+func (a *CosmosWatchAccount) Address() string { return a.account.Address() }
+
+// SolanaWatchAccount is a watch-only Solana account.
+type SolanaWatchAccount struct {
+	account SolanaAccount
+}
+
+// NewSolanaWatchAccount creates a watch-only Solana account from a 32-byte Ed25519 public key.
+func NewSolanaWatchAccount(publicKey []byte) (*SolanaWatchAccount, error) {
+	key, err := keys.NewPublicKey(keys.Ed25519, publicKey)
+	if err != nil {
+		return nil, ErrInvalidPublicKey
+	}
+	return &SolanaWatchAccount{account: SolanaAccount{publicKey: key}}, nil
+}
+
+// NewSolanaWatchAccountFromAddress creates a watch-only Solana account from a
+// Base58 address, which is the public key itself.
+func NewSolanaWatchAccountFromAddress(addr string) (*SolanaWatchAccount, error) {
+	publicKey, err := address.Base58Decode(addr)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPublicKey, err)
+	}
+	return NewSolanaWatchAccount(publicKey)
+}
+
+// Path returns an empty string; Ed25519 public keys cannot be derived without the seed.
+func (a *SolanaWatchAccount) Path() string { return a.account.Path() }
+
+// PublicKeyBytes returns the 32-byte Ed25519 public key.
+func (a *SolanaWatchAccount) PublicKeyBytes() []byte { return a.account.PublicKeyBytes() }
+
+// Chain returns the chain identifier.
+func (a *SolanaWatchAccount) Chain() address.ChainID { return a.account.Chain() }
+
+// Address returns the Base58 address.
+func (a *SolanaWatchAccount) Address() string { return a.account.Address() }
+
+// SuiWatchAccount is a watch-only Sui account.
+type SuiWatchAccount struct {
+	account SuiAccount
+}
+
+// NewSuiWatchAccount creates a watch-only Sui account from a 32-byte Ed25519 public key.
+// Sui addresses are hashes, so an address alone is not enough.
+func NewSuiWatchAccount(publicKey []byte) (*SuiWatchAccount, error) {
+	key, err := keys.NewPublicKey(keys.Ed25519, publicKey)
+	if err != nil {
+		return nil, ErrInvalidPublicKey
+	}
+	return &SuiWatchAccount{account: SuiAccount{publicKey: key}}, nil
+}
+
+// Path returns an empty string; Ed25519 public keys cannot be derived without the seed.
+func (a *SuiWatchAccount) Path() string { return a.account.Path() }
+
+// PublicKeyBytes returns the 32-byte Ed25519 public key.
+func (a *SuiWatchAccount) PublicKeyBytes() []byte { return a.account.PublicKeyBytes() }
+
+// Chain returns the chain identifier.
+func (a *SuiWatchAccount) Chain() address.ChainID { return a.account.Chain() }
+
+// Address returns the 0x-prefixed Sui address.
+func (a *SuiWatchAccount) Address() string { return a.account.Address() }
+
+// deriveFromAccountXpub parses an account-level xpub and derives its receive
+// key at index (.../0/index). It returns the key and the xpub's account number.
+func deriveFromAccountXpub(xpub string, index uint32) (*bip32.ExtendedKey, uint32, error) {
+	key, err := bip32.ParseExtendedKey(xpub)
+	if err != nil {
+		return nil, 0, fmt.Errorf("%w: %v", ErrInvalidExtendedKey, err)
+	}
+	if key.IsPrivate() {
+		return nil, 0, fmt.Errorf("%w: got a private key, export the xpub instead", ErrInvalidExtendedKey)
+	}
+	if key.Depth() != 3 || !bip32.IsHardened(key.ChildIndex()) {
+		return nil, 0, fmt.Errorf("%w: depth %d, want an account-level key (depth 3)", ErrInvalidExtendedKey, key.Depth())
+	}
+
+	child, err := key.DeriveFromPathString(fmt.Sprintf("m/0/%d", index))
+	if err != nil {
+		return nil, 0, err
+	}
+	return child, key.ChildIndex() - bip32.HardenedKeyStart, nil
+}
+
+// compressedPublicKey validates a secp256k1 public key and returns it compressed.
+func compressedPublicKey(publicKey []byte) (keys.PublicKey, error) {
+	point, err := secp256k1.ParsePublicKey(publicKey)
+	if err != nil {
+		return keys.PublicKey{}, fmt.Errorf("%w: %v", ErrInvalidPublicKey, err)
+	}
+	return keys.NewPublicKey(keys.Secp256k1Compressed, secp256k1.CompressPoint(point))
+}
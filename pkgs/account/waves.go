@@ -0,0 +1,121 @@
+package account
+
+import (
+	"crypto/ecdh"
+	"crypto/sha256"
+	"encoding/binary"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// WavesAccount is a Waves account with a Curve25519 key pair.
+//
+// Waves does not use BIP-32: keys come from the seed phrase itself, so any
+// phrase (typically 15 words) is accepted as-is. The account seed is
+// SecureHash(nonce || phrase), the private key is SHA256(account seed) clamped
+// for Curve25519, and each nonce yields a different account.
+type WavesAccount struct {
+	nonce      uint32
+	chainID    byte
+	privateKey *secret.Bytes
+	publicKey  []byte
+}
+
+// NewWavesAccount derives the Waves mainnet account for a seed phrase and nonce
+// (0 for the first account), as Waves Keeper and Waves.Exchange do.
+func NewWavesAccount(seedPhrase string, nonce uint32) (*WavesAccount, error) {
+	return NewWavesAccountWithChainID(seedPhrase, nonce, address.WavesMainnetChainID)
+}
+
+// NewWavesAccountWithChainID derives the Waves account for the given network byte.
+func NewWavesAccountWithChainID(seedPhrase string, nonce uint32, chainID byte) (*WavesAccount, error) {
+	if seedPhrase == "" {
+		return nil, ErrInvalidSeed
+	}
+
+	data := binary.BigEndian.AppendUint32(nil, nonce)
+	data = append(data, seedPhrase...)
+	accountSeed := address.WavesSecureHash(data)
+
+	privateKey := sha256.Sum256(accountSeed)
+	privateKey[0] &= 248
+	privateKey[31] &= 127
+	privateKey[31] |= 64
+
+	account, err := newWavesAccountFromPrivateKey(privateKey[:], chainID)
+	if err != nil {
+		return nil, err
+	}
+	account.nonce = nonce
+
+	return account, nil
+}
+
+// NewWavesAccountFromPrivateKey creates a Waves mainnet account from a 32-byte
+// Curve25519 private key.
+func NewWavesAccountFromPrivateKey(privateKey []byte) (*WavesAccount, error) {
+	return newWavesAccountFromPrivateKey(privateKey, address.WavesMainnetChainID)
+}
+
+func newWavesAccountFromPrivateKey(privateKey []byte, chainID byte) (*WavesAccount, error) {
+	key, err := ecdh.X25519().NewPrivateKey(privateKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+
+	return &WavesAccount{
+		chainID:    chainID,
+		privateKey: secret.Copy(privateKey),
+		publicKey:  key.PublicKey().Bytes(),
+	}, nil
+}
+
+// Path returns an empty string; Waves accounts are selected by nonce, not path.
+func (a *WavesAccount) Path() string {
+	return ""
+}
+
+// Nonce returns the nonce the account was derived with.
+func (a *WavesAccount) Nonce() uint32 {
+	return a.nonce
+}
+
+// PrivateKeyBytes returns a copy of the 32-byte Curve25519 private key.
+func (a *WavesAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *WavesAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 32-byte Curve25519 public key.
+func (a *WavesAccount) PublicKeyBytes() []byte {
+	return a.publicKey
+}
+
+// PrivateKeyBase58 returns the private key in Base58, as Waves wallets display it.
+func (a *WavesAccount) PrivateKeyBase58() string {
+	var encoded string
+	a.privateKey.Use(func(key []byte) { encoded = address.Base58Encode(key) })
+	return encoded
+}
+
+// PublicKeyBase58 returns the public key in Base58, as Waves wallets display it.
+func (a *WavesAccount) PublicKeyBase58() string {
+	return address.Base58Encode(a.publicKey)
+}
+
+// Chain returns the chain identifier.
+func (a *WavesAccount) Chain() address.ChainID {
+	return address.ChainWaves
+}
+
+// Address returns the Waves address for the account's network.
+func (a *WavesAccount) Address() string {
+	addr, _ := address.NewWavesAddressWithChainID(a.chainID).Generate(a.publicKey)
+	return addr
+}
@@ -0,0 +1,42 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// WIF version bytes for Bitcoin-derived chains
+const (
+	BitcoinWIFVersion         byte = 0x80
+	BitcoinTestnetWIFVersion  byte = 0xEF
+	LitecoinWIFVersion        byte = 0xB0
+	DogecoinWIFVersion        byte = 0x9E
+	DogecoinTestnetWIFVersion byte = 0xF1
+)
+
+// EncodeWIF encodes a private key in Wallet Import Format:
+// Base58Check(version || key || 0x01 if compressed).
+func EncodeWIF(version byte, privateKey []byte, compressed bool) (string, error) {
+	if len(privateKey) != 32 {
+		return "", fmt.Errorf("%w: expected 32 bytes, got %d", ErrInvalidPrivateKey, len(privateKey))
+	}
+
+	payload := make([]byte, 0, 33)
+	payload = append(payload, privateKey...)
+	if compressed {
+		payload = append(payload, 0x01)
+	}
+
+	return address.Base58CheckEncode(version, payload), nil
+}
+
+// DecodeWIF decodes a WIF string into its version byte, private key and compression flag.
+func DecodeWIF(wif string) (version byte, privateKey []byte, compressed bool, err error) {
+	version, privateKey, compressed, err = keys.DecodeWIF(wif)
+	if err != nil {
+		return 0, nil, false, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	return version, privateKey, compressed, nil
+}
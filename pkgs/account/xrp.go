@@ -0,0 +1,142 @@
+package account
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// XRP derivation paths. secp256k1 follows BIP-44 at coin type 144, as
+// Ledger and Xumm do.
+//
+// XRPEd25519PathFormat is a convention of this library only: the hardened
+// SLIP-10 equivalent of the BIP-44 path. The XRP Ledger derives Ed25519 keys
+// from a 16-byte family seed ("sEd..."), not from a BIP-39 mnemonic, and no
+// XRP wallet derives this path, so another wallet given the same mnemonic
+// will not find these accounts. Move them by their private key instead.
+const (
+	XRPPathFormat        = "m/44'/144'/%d'/0/%d"
+	XRPEd25519PathFormat = "m/44'/144'/%d'/0'/%d'"
+)
+
+// XRPEd25519Prefix marks Ed25519 public keys on the XRP Ledger.
+const XRPEd25519Prefix byte = 0xED
+
+// XRPKeyType selects the signing algorithm of an XRP account.
+type XRPKeyType int
+
+const (
+	XRPKeySecp256k1 XRPKeyType = iota
+	XRPKeyEd25519
+)
+
+// XRPAccount is an XRP Ledger key pair with its classic address.
+type XRPAccount struct {
+	keyType    XRPKeyType
+	path       string
+	privateKey *secret.Bytes
+	publicKey  keys.PublicKey // without the 0xED prefix
+}
+
+// NewXRPAccount derives a secp256k1 XRP account at m/44'/144'/account'/0/index.
+func NewXRPAccount(seed []byte, account, index uint32) (*XRPAccount, error) {
+	path := fmt.Sprintf(XRPPathFormat, account, index)
+
+	key, err := deriveSecp256k1(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	publicKey, err := keys.NewPublicKey(keys.Secp256k1Compressed, key.PublicKeyBytes())
+	if err != nil {
+		return nil, err
+	}
+
+	return &XRPAccount{
+		keyType:    XRPKeySecp256k1,
+		path:       path,
+		privateKey: secret.Take(key.PrivateKeyBytes()),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewXRPEd25519Account derives an Ed25519 XRP account at
+// m/44'/144'/account'/0'/index'. The path is not interoperable; see
+// XRPEd25519PathFormat.
+func NewXRPEd25519Account(seed []byte, account, index uint32) (*XRPAccount, error) {
+	path := fmt.Sprintf(XRPEd25519PathFormat, account, index)
+
+	privateKey, publicKey, err := deriveEd25519(seed, path)
+	if err != nil {
+		return nil, err
+	}
+
+	return &XRPAccount{
+		keyType:    XRPKeyEd25519,
+		path:       path,
+		privateKey: secret.Take(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// NewXRPAccountFromMnemonic derives an XRP account of the given key type from
+// a mnemonic. Only secp256k1 accounts can be recovered from the same mnemonic
+// in other XRP wallets; see XRPEd25519PathFormat.
+func NewXRPAccountFromMnemonic(mnemonic, passphrase string, keyType XRPKeyType, account, index uint32) (*XRPAccount, error) {
+	seed, err := seedFromMnemonic(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+
+	if keyType == XRPKeyEd25519 {
+		return NewXRPEd25519Account(seed, account, index)
+	}
+	return NewXRPAccount(seed, account, index)
+}
+
+// KeyType returns the signing algorithm of the account.
+func (a *XRPAccount) KeyType() XRPKeyType {
+	return a.keyType
+}
+
+// Path returns the derivation path.
+func (a *XRPAccount) Path() string {
+	return a.path
+}
+
+// PrivateKeyBytes returns a copy of the raw 32-byte private key.
+func (a *XRPAccount) PrivateKeyBytes() []byte {
+	return a.privateKey.Bytes()
+}
+
+// Destroy wipes the private key.
+func (a *XRPAccount) Destroy() {
+	a.privateKey.Destroy()
+}
+
+// PublicKeyBytes returns the 33-byte public key as used on the ledger
+// (compressed secp256k1, or 0xED followed by the Ed25519 key).
+func (a *XRPAccount) PublicKeyBytes() []byte {
+	if a.keyType == XRPKeyEd25519 {
+		return append([]byte{XRPEd25519Prefix}, a.publicKey.Bytes()...)
+	}
+	return a.publicKey.Bytes()
+}
+
+// Chain returns the chain identifier.
+func (a *XRPAccount) Chain() address.ChainID {
+	return address.ChainRipple
+}
+
+// Address returns the classic address ('r...').
+func (a *XRPAccount) Address() string {
+	addr, _ := address.NewRippleAddress().Generate(a.PublicKeyBytes())
+	return addr
+}
+
+// XAddress returns the X-address for the account, optionally with a destination tag.
+func (a *XRPAccount) XAddress(tag *uint32, testnet bool) (string, error) {
+	return address.EncodeXAddress(a.Address(), tag, testnet)
+}
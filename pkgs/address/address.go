@@ -67,6 +67,8 @@ const (
 	ChainArbitrum     ChainID = "arb"
 	ChainVeChain      ChainID = "vet"
 	ChainTheta        ChainID = "theta"
+	ChainHarmony      ChainID = "one"
+	ChainRonin        ChainID = "ron"
 
 	// Other chains
 	ChainBinanceBEP2  ChainID = "bnb"
@@ -84,6 +86,10 @@ const (
 	ChainHedera       ChainID = "hbar"
 	ChainICP          ChainID = "icp"
 	ChainDash         ChainID = "dash"
+	ChainStarknet     ChainID = "strk"
+	ChainNostr        ChainID = "nostr"
+	ChainNeo          ChainID = "neo"
+	ChainWaves        ChainID = "waves"
 	ChainEthereumClassic ChainID = "etc"
 )
 
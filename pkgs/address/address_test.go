@@ -1,7 +1,9 @@
 package address
 
 import (
+	"bytes"
 	"encoding/hex"
+	"errors"
 	"testing"
 )
 
@@ -210,6 +212,24 @@ func TestStellarAddress(t *testing.T) {
 	}
 }
 
+func TestStellarMuxedCanonical(t *testing.T) {
+	// SEP-23 test vectors: GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ
+	// with ID 0, and the same address with the unused last bit set
+	const (
+		muxed        = "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAAAAAAAACJUQ"
+		nonCanonical = "MA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJUAAAAAAAAAAAACJUR"
+	)
+	publicKey, _ := hex.DecodeString("3f0c34bf93ad0d9971d04ccc90f705511c838aad9734a4a2fb0d7a03fc7fe89a")
+
+	pub, id, err := DecodeStellarMuxed(muxed)
+	if err != nil || !bytes.Equal(pub, publicKey) || id != 0 {
+		t.Errorf("DecodeStellarMuxed() = (%x, %d, %v), want (%x, 0)", pub, id, err, publicKey)
+	}
+	if _, _, err := DecodeStellarMuxed(nonCanonical); !errors.Is(err, ErrInvalidAddress) {
+		t.Errorf("DecodeStellarMuxed(non-canonical) error = %v, want ErrInvalidAddress", err)
+	}
+}
+
 func TestRippleAddress(t *testing.T) {
 	xrp := NewRippleAddress()
 
@@ -254,77 +274,6 @@ func TestCosmosAddress(t *testing.T) {
 	}
 }
 
-func TestAlgorandAddress(t *testing.T) {
-	algo := NewAlgorandAddress()
-
-	// 32-byte Ed25519 public key
-	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	addr, err := algo.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Algorand addresses are 58 characters
-	if len(addr) != 58 {
-		t.Errorf("Address length = %d, want 58", len(addr))
-	}
-
-	if !algo.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-}
-
-func TestPolkadotAddress(t *testing.T) {
-	dot := NewPolkadotAddress()
-
-	// 32-byte public key
-	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	addr, err := dot.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Polkadot addresses start with 1
-	if addr[0] != '1' {
-		t.Errorf("Address should start with 1, got %c", addr[0])
-	}
-
-	if !dot.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-}
-
-func TestAptosAddress(t *testing.T) {
-	aptos := NewAptosAddress()
-
-	// 32-byte Ed25519 public key
-	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	addr, err := aptos.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Aptos addresses start with 0x
-	if addr[:2] != "0x" {
-		t.Error("Address should start with 0x")
-	}
-
-	// Should be 66 characters (0x + 64 hex chars)
-	if len(addr) != 66 {
-		t.Errorf("Address length = %d, want 66", len(addr))
-	}
-
-	if !aptos.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-}
-
 func TestSuiAddress(t *testing.T) {
 	sui := NewSuiAddress()
 
@@ -352,111 +301,6 @@ func TestSuiAddress(t *testing.T) {
 	}
 }
 
-func TestNEARAddress(t *testing.T) {
-	near := NewNEARAddress()
-
-	// 32-byte Ed25519 public key
-	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	addr, err := near.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// NEAR implicit addresses are 64 hex characters
-	if len(addr) != 64 {
-		t.Errorf("Address length = %d, want 64", len(addr))
-	}
-
-	if !near.ValidateImplicit(addr) {
-		t.Error("Implicit address validation failed")
-	}
-
-	// Test named address validation
-	if !near.ValidateNamed("alice.near") {
-		t.Error("Named address 'alice.near' should be valid")
-	}
-
-	if !near.ValidateNamed("bob.alice.near") {
-		t.Error("Named address 'bob.alice.near' should be valid")
-	}
-
-	if near.ValidateNamed("-invalid") {
-		t.Error("Named address '-invalid' should be invalid")
-	}
-}
-
-func TestCardanoAddress(t *testing.T) {
-	ada := NewCardanoAddress()
-
-	// 32-byte Ed25519 public key
-	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	addr, err := ada.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Cardano mainnet addresses start with "addr1"
-	if len(addr) < 5 || addr[:4] != "addr" {
-		t.Errorf("Address should start with 'addr', got %s", addr[:10])
-	}
-
-	if !ada.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-
-	// Test enterprise address type
-	addrType, err := ada.GetAddressType(addr)
-	if err != nil {
-		t.Fatalf("GetAddressType() error = %v", err)
-	}
-	if addrType != "enterprise (key)" {
-		t.Errorf("Expected enterprise address type, got %s", addrType)
-	}
-
-	// Test testnet address
-	adaTestnet := NewCardanoTestnetAddress()
-	testnetAddr, err := adaTestnet.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() testnet error = %v", err)
-	}
-
-	// Testnet addresses have "addr_test" prefix
-	if len(testnetAddr) < 9 || testnetAddr[:9] != "addr_test" {
-		t.Errorf("Testnet address should start with 'addr_test', got %s", testnetAddr[:15])
-	}
-
-	if !adaTestnet.Validate(testnetAddr) {
-		t.Error("Testnet address validation failed")
-	}
-}
-
-func TestBitcoinCashAddress(t *testing.T) {
-	bch := NewBitcoinCashAddress(false)
-
-	// Compressed public key
-	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	addr, err := bch.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Bitcoin Cash addresses start with bitcoincash:q
-	if addr[:13] != "bitcoincash:q" {
-		t.Errorf("Address should start with bitcoincash:q, got %s", addr[:13])
-	}
-
-	// Test that the address was generated
-	if len(addr) < 42 {
-		t.Error("Address too short")
-	}
-}
-
 func TestFactory(t *testing.T) {
 	factory := NewFactory()
 
@@ -483,6 +327,23 @@ func TestFactory(t *testing.T) {
 	}
 }
 
+func TestIsSupported(t *testing.T) {
+	for _, chainID := range NewFactory().ListSupportedChains() {
+		if !IsSupported(chainID) {
+			t.Errorf("IsSupported(%s) = false for a chain in NewFactory", chainID)
+		}
+	}
+	if IsSupported("unsupported") {
+		t.Error("IsSupported(unsupported) = true")
+	}
+
+	for _, info := range ListAllChainInfo() {
+		if !IsSupported(info.ID) {
+			t.Errorf("ListAllChainInfo() includes %s, which is not in the build", info.ID)
+		}
+	}
+}
+
 func TestBase58Encoding(t *testing.T) {
 	tests := []struct {
 		input    []byte
@@ -534,6 +395,88 @@ func TestBech32Encoding(t *testing.T) {
 	}
 }
 
+func TestBech32Variants(t *testing.T) {
+	// Valid strings from BIP-173 and BIP-350
+	tests := []struct {
+		str      string
+		encoding Bech32Encoding
+	}{
+		{"A12UEL5L", Bech32Standard},
+		{"abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw", Bech32Standard},
+		{"A1LQFN3A", Bech32m},
+		{"abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx", Bech32m},
+	}
+
+	for _, tt := range tests {
+		_, _, encoding, err := Bech32Decode(tt.str)
+		if err != nil || encoding != tt.encoding {
+			t.Errorf("Bech32Decode(%s) = %s, %v, want %s", tt.str, encoding, err, tt.encoding)
+		}
+
+		if _, _, err := Bech32DecodeAs(tt.str, tt.encoding); err != nil {
+			t.Errorf("Bech32DecodeAs(%s, %s) error = %v", tt.str, tt.encoding, err)
+		}
+		other := Bech32m
+		if tt.encoding == Bech32m {
+			other = Bech32Standard
+		}
+		if _, _, err := Bech32DecodeAs(tt.str, other); !errors.Is(err, ErrInvalidChecksum) {
+			t.Errorf("Bech32DecodeAs(%s, %s) error = %v, want ErrInvalidChecksum", tt.str, other, err)
+		}
+	}
+
+	// BIP-350 Taproot vector: witness v1 with the x-only generator
+	const taproot = "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"
+	program, _ := hex.DecodeString("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+
+	encoded, err := SegWitEncode("bc", 1, program)
+	if err != nil || encoded != taproot {
+		t.Errorf("SegWitEncode(v1) = %s, %v, want %s", encoded, err, taproot)
+	}
+	_, version, decoded, err := SegWitDecode(taproot)
+	if err != nil || version != 1 || !bytes.Equal(decoded, program) {
+		t.Errorf("SegWitDecode(%s) = %d %x, %v", taproot, version, decoded, err)
+	}
+}
+
+// bech32BenchPrograms returns 1000 distinct 20-byte programs, the size of a
+// P2WPKH or Cosmos account address.
+func bech32BenchPrograms() [][]byte {
+	programs := make([][]byte, 1000)
+	for i := range programs {
+		programs[i] = Hash160([]byte{byte(i >> 8), byte(i)})
+	}
+	return programs
+}
+
+func BenchmarkBech32Encode(b *testing.B) {
+	programs := bech32BenchPrograms()
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		for _, program := range programs {
+			if _, err := Bech32Encode("cosmos", program, Bech32Standard); err != nil {
+				b.Fatal(err)
+			}
+		}
+	}
+}
+
+func BenchmarkBech32Decode(b *testing.B) {
+	programs := bech32BenchPrograms()
+	encoded := make([]string, len(programs))
+	for i, program := range programs {
+		encoded[i], _ = SegWitEncode("bc", 0, program)
+	}
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		for _, s := range encoded {
+			if _, _, _, err := SegWitDecode(s); err != nil {
+				b.Fatal(err)
+			}
+		}
+	}
+}
+
 func TestHash160(t *testing.T) {
 	// Test vector
 	input, _ := hex.DecodeString("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798")
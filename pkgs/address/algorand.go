@@ -1,3 +1,5 @@
+//go:build !ca_no_algorand
+
 package address
 
 import (
@@ -5,6 +7,11 @@ import (
 	"fmt"
 )
 
+// Build with -tags ca_no_algorand to leave Algorand out of the build.
+func init() {
+	registerChain(ChainAlgorand, func() AddressGenerator { return NewAlgorandAddress() })
+}
+
 // Custom Base32 encoding for Algorand (no padding)
 var algorandBase32 = base32.StdEncoding.WithPadding(base32.NoPadding)
 
@@ -0,0 +1,30 @@
+//go:build !ca_no_algorand
+
+package address
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+func TestAlgorandAddress(t *testing.T) {
+	algo := NewAlgorandAddress()
+
+	// 32-byte Ed25519 public key
+	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	addr, err := algo.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Algorand addresses are 58 characters
+	if len(addr) != 58 {
+		t.Errorf("Address length = %d, want 58", len(addr))
+	}
+
+	if !algo.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+}
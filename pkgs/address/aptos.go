@@ -1,18 +1,37 @@
+//go:build !ca_no_aptos
+
 package address
 
 import (
+	"encoding/binary"
 	"encoding/hex"
 	"fmt"
 	"strings"
 )
 
-// Aptos signature scheme identifiers
+// Build with -tags ca_no_aptos to leave Aptos out of the build.
+func init() {
+	registerChain(ChainAptos, func() AddressGenerator { return NewAptosAddress() })
+}
+
+// Aptos authentication key scheme identifiers
+const (
+	AptosEd25519Scheme      byte = 0x00
+	AptosMultiEd25519Scheme byte = 0x01
+	AptosSingleKeyScheme    byte = 0x02
+	AptosMultiKeyScheme     byte = 0x03
+)
+
+// Aptos AnyPublicKey variants used by the single-key scheme
 const (
-	AptosEd25519Scheme   byte = 0x00
-	AptosSecp256k1Scheme byte = 0x01
-	AptosMultiEd25519    byte = 0x02
+	AptosAnyKeyEd25519        byte = 0x00
+	AptosAnyKeySecp256k1Ecdsa byte = 0x01
+	AptosAnyKeySecp256r1Ecdsa byte = 0x02
 )
 
+// AptosMaxMultiEd25519Keys is the maximum number of keys in a multi-ed25519 account
+const AptosMaxMultiEd25519Keys = 32
+
 // AptosAddress generates Aptos addresses
 type AptosAddress struct{}
 
@@ -32,34 +51,131 @@ func (a *AptosAddress) Generate(publicKey []byte) (string, error) {
 	return a.GenerateWithScheme(publicKey, AptosEd25519Scheme)
 }
 
-// GenerateWithScheme creates an Aptos address with a specific signature scheme
-func (a *AptosAddress) GenerateWithScheme(publicKey []byte, scheme byte) (string, error) {
-	var expectedLen int
+// GenerateWithScheme creates an Aptos address from serialized key material and a scheme
+// For a fresh account the address equals the authentication key: SHA3-256(material || scheme)
+// Use AptosMultiEd25519AuthKey or AptosSingleKeyAuthKey to build the material for other schemes
+func (a *AptosAddress) GenerateWithScheme(material []byte, scheme byte) (string, error) {
 	switch scheme {
 	case AptosEd25519Scheme:
-		expectedLen = 32
-	case AptosSecp256k1Scheme:
-		expectedLen = 33
+		if len(material) != 32 {
+			return "", fmt.Errorf("invalid public key length: expected 32, got %d", len(material))
+		}
+	case AptosMultiEd25519Scheme, AptosSingleKeyScheme, AptosMultiKeyScheme:
+		if len(material) == 0 {
+			return "", fmt.Errorf("empty key material")
+		}
 	default:
 		return "", fmt.Errorf("unsupported signature scheme: %d", scheme)
 	}
 
+	return "0x" + hex.EncodeToString(AptosAuthenticationKey(material, scheme)), nil
+}
+
+// AptosAuthenticationKey computes an authentication key: SHA3-256(material || scheme)
+func AptosAuthenticationKey(material []byte, scheme byte) []byte {
+	data := make([]byte, len(material)+1)
+	copy(data, material)
+	data[len(material)] = scheme
+
+	return SHA3256(data)
+}
+
+// AptosMultiEd25519AuthKey computes the authentication key of a K-of-N multi-ed25519 account
+// Material: public_key_1 || ... || public_key_n || threshold
+func AptosMultiEd25519AuthKey(publicKeys [][]byte, threshold uint8) ([]byte, error) {
+	material, err := AptosMultiEd25519PublicKey(publicKeys, threshold)
+	if err != nil {
+		return nil, err
+	}
+	return AptosAuthenticationKey(material, AptosMultiEd25519Scheme), nil
+}
+
+// AptosMultiEd25519PublicKey serializes a multi-ed25519 public key: keys followed by the threshold
+func AptosMultiEd25519PublicKey(publicKeys [][]byte, threshold uint8) ([]byte, error) {
+	if len(publicKeys) == 0 || len(publicKeys) > AptosMaxMultiEd25519Keys {
+		return nil, fmt.Errorf("multi-ed25519 requires 1 to %d keys, got %d", AptosMaxMultiEd25519Keys, len(publicKeys))
+	}
+	if threshold == 0 || int(threshold) > len(publicKeys) {
+		return nil, fmt.Errorf("invalid threshold %d for %d keys", threshold, len(publicKeys))
+	}
+
+	material := make([]byte, 0, 32*len(publicKeys)+1)
+	for _, pk := range publicKeys {
+		if len(pk) != 32 {
+			return nil, fmt.Errorf("invalid Ed25519 public key length: expected 32, got %d", len(pk))
+		}
+		material = append(material, pk...)
+	}
+
+	return append(material, threshold), nil
+}
+
+// AptosSingleKeyAuthKey computes the authentication key of a single-key account
+// Material: BCS(AnyPublicKey) = variant || ULEB128(len) || public key
+// Secp256k1 keys must be 65 bytes (uncompressed)
+func AptosSingleKeyAuthKey(variant byte, publicKey []byte) ([]byte, error) {
+	var expectedLen int
+	switch variant {
+	case AptosAnyKeyEd25519:
+		expectedLen = 32
+	case AptosAnyKeySecp256k1Ecdsa, AptosAnyKeySecp256r1Ecdsa:
+		expectedLen = 65
+	default:
+		return nil, fmt.Errorf("unsupported public key variant: %d", variant)
+	}
+
 	if len(publicKey) != expectedLen {
-		return "", fmt.Errorf("invalid public key length: expected %d, got %d", expectedLen, len(publicKey))
+		return nil, fmt.Errorf("invalid public key length: expected %d, got %d", expectedLen, len(publicKey))
 	}
 
-	// Aptos address generation:
-	// 1. Append scheme byte to public key
-	// 2. SHA3-256 hash
-	// 3. Result is the 32-byte address
-	data := make([]byte, len(publicKey)+1)
-	copy(data, publicKey)
-	data[len(publicKey)] = scheme
+	material := []byte{variant}
+	material = append(material, bcsBytes(publicKey)...)
 
-	hash := SHA3256(data)
+	return AptosAuthenticationKey(material, AptosSingleKeyScheme), nil
+}
+
+// AptosRotationProofChallenge is the payload both the current and the new key sign
+// to rotate an account's authentication key (0x1::account::rotate_authentication_key)
+// After rotation the account address no longer matches its authentication key.
+type AptosRotationProofChallenge struct {
+	SequenceNumber uint64
+	Originator     []byte // 32-byte account address
+	CurrentAuthKey []byte // 32-byte authentication key currently on chain
+	NewPublicKey   []byte // serialized public key for the new scheme
+}
+
+// SigningMessage returns the bytes to sign: BCS(TypeInfo) || BCS(challenge)
+// where TypeInfo is 0x1::account::RotationProofChallenge
+func (c *AptosRotationProofChallenge) SigningMessage() ([]byte, error) {
+	if len(c.Originator) != 32 || len(c.CurrentAuthKey) != 32 {
+		return nil, fmt.Errorf("originator and current auth key must be 32 bytes")
+	}
 
-	// Format as 0x-prefixed hex string
-	return "0x" + hex.EncodeToString(hash), nil
+	framework := make([]byte, 32)
+	framework[31] = 0x01
+
+	msg := make([]byte, 0, 160+len(c.NewPublicKey))
+	msg = append(msg, framework...)
+	msg = append(msg, bcsBytes([]byte("account"))...)
+	msg = append(msg, bcsBytes([]byte("RotationProofChallenge"))...)
+	msg = binary.LittleEndian.AppendUint64(msg, c.SequenceNumber)
+	msg = append(msg, c.Originator...)
+	msg = append(msg, c.CurrentAuthKey...)
+	msg = append(msg, bcsBytes(c.NewPublicKey)...)
+
+	return msg, nil
+}
+
+// bcsBytes serializes a byte vector as BCS: ULEB128(length) || bytes
+func bcsBytes(data []byte) []byte {
+	out := make([]byte, 0, len(data)+5)
+	n := uint64(len(data))
+	for n >= 0x80 {
+		out = append(out, byte(n)|0x80)
+		n >>= 7
+	}
+	out = append(out, byte(n))
+	return append(out, data...)
 }
 
 // Validate checks if an Aptos address is valid
@@ -0,0 +1,87 @@
+//go:build !ca_no_aptos
+
+package address
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+func TestAptosAddress(t *testing.T) {
+	aptos := NewAptosAddress()
+
+	// 32-byte Ed25519 public key
+	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	addr, err := aptos.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Aptos addresses start with 0x
+	if addr[:2] != "0x" {
+		t.Error("Address should start with 0x")
+	}
+
+	// Should be 66 characters (0x + 64 hex chars)
+	if len(addr) != 66 {
+		t.Errorf("Address length = %d, want 66", len(addr))
+	}
+
+	if !aptos.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+}
+
+func TestAptosAuthenticationKeys(t *testing.T) {
+	pk1, _ := hex.DecodeString("8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c")
+	pk2, _ := hex.DecodeString("8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394")
+	pk3, _ := hex.DecodeString("ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d1")
+
+	edAuthKey := AptosAuthenticationKey(pk1, AptosEd25519Scheme)
+	if hex.EncodeToString(edAuthKey) != "7df415e5b21bdaa8b2946e8f1f4278b39904e51a69627494cd3e6f2996732fbd" {
+		t.Errorf("Ed25519 auth key = %x", edAuthKey)
+	}
+
+	multiAuthKey, err := AptosMultiEd25519AuthKey([][]byte{pk1, pk2, pk3}, 2)
+	if err != nil {
+		t.Fatalf("AptosMultiEd25519AuthKey() error = %v", err)
+	}
+	if hex.EncodeToString(multiAuthKey) != "e103d0e6e67b017524bebf94ae151df6a70c6f354178a88a9a3865bcafabfdb4" {
+		t.Errorf("multi-ed25519 auth key = %x", multiAuthKey)
+	}
+	if _, err := AptosMultiEd25519AuthKey([][]byte{pk1, pk2}, 3); err == nil {
+		t.Error("AptosMultiEd25519AuthKey() accepted threshold > keys")
+	}
+
+	singleAuthKey, err := AptosSingleKeyAuthKey(AptosAnyKeyEd25519, pk1)
+	if err != nil {
+		t.Fatalf("AptosSingleKeyAuthKey() error = %v", err)
+	}
+	if hex.EncodeToString(singleAuthKey) != "bbe6af385b3f36c98c46770ac79c0db8f3c7d2a58addf0f567dfd38f8f34a036" {
+		t.Errorf("single-key auth key = %x", singleAuthKey)
+	}
+	if _, err := AptosSingleKeyAuthKey(AptosAnyKeySecp256k1Ecdsa, pk1); err == nil {
+		t.Error("AptosSingleKeyAuthKey() accepted a 32-byte secp256k1 key")
+	}
+
+	// Rotate the Ed25519 account to the 2-of-3 multi-ed25519 key
+	newPublicKey, _ := AptosMultiEd25519PublicKey([][]byte{pk1, pk2, pk3}, 2)
+	challenge := &AptosRotationProofChallenge{
+		SequenceNumber: 5,
+		Originator:     edAuthKey,
+		CurrentAuthKey: edAuthKey,
+		NewPublicKey:   newPublicKey,
+	}
+	msg, err := challenge.SigningMessage()
+	if err != nil {
+		t.Fatalf("SigningMessage() error = %v", err)
+	}
+	if len(msg) != 233 {
+		t.Errorf("SigningMessage() length = %d, want 233", len(msg))
+	}
+	if hex.EncodeToString(SHA3256(msg)) != "28935c9de1a9b91ae6e40fbec8e52b6d7e64487b3f4cb926b800052a3539adf3" {
+		t.Errorf("SigningMessage() hash = %x", SHA3256(msg))
+	}
+}
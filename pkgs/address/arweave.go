@@ -1,3 +1,5 @@
+//go:build !ca_no_arweave
+
 package address
 
 import (
@@ -7,6 +9,11 @@ import (
 	"strings"
 )
 
+// Build with -tags ca_no_arweave to leave Arweave out of the build.
+func init() {
+	registerChain(ChainArweave, func() AddressGenerator { return NewArweaveAddress() })
+}
+
 // ArweaveAddress generates Arweave (AR) addresses
 // Arweave addresses are Base64URL-encoded SHA-256 hashes of RSA public keys
 type ArweaveAddress struct{}
@@ -0,0 +1,55 @@
+//go:build !ca_no_arweave
+
+package address
+
+import (
+	"strings"
+	"testing"
+)
+
+// TestArweaveAddress tests Arweave (AR) address generation
+func TestArweaveAddress(t *testing.T) {
+	arweave := NewArweaveAddress()
+
+	// RSA public key modulus (256 bytes for 2048-bit RSA)
+	// Using a dummy 256-byte key for testing
+	pubKey := make([]byte, 256)
+	for i := range pubKey {
+		pubKey[i] = byte(i)
+	}
+
+	// Generate address
+	addr, err := arweave.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Arweave addresses are 43 characters (Base64URL of SHA-256)
+	if len(addr) != 43 {
+		t.Errorf("Address length = %d, want 43", len(addr))
+	}
+
+	// Validate
+	if !arweave.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+
+	// Test GetAddressType
+	addrType, err := arweave.GetAddressType(addr)
+	if err != nil {
+		t.Fatalf("GetAddressType() error = %v", err)
+	}
+	if !strings.Contains(addrType, "RSA") {
+		t.Errorf("GetAddressType() = %s, want RSA type", addrType)
+	}
+
+	// Test invalid address (wrong length)
+	if arweave.Validate("tooshort") {
+		t.Error("Should reject address with wrong length")
+	}
+
+	// Test invalid address
+	if arweave.Validate("invalid!@#$%^&*()") {
+		t.Error("Should reject invalid address with special chars")
+	}
+}
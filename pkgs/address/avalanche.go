@@ -10,21 +10,29 @@ const (
 	AvalancheXChainHRP = "avax"
 	AvalanchePChainHRP = "avax"
 	AvalancheCChainHRP = "" // C-Chain uses Ethereum addresses
+	AvalancheFujiHRP   = "fuji"
 )
 
 // AvalancheAddress generates Avalanche addresses
 type AvalancheAddress struct {
 	chainType string // "X", "P", or "C"
+	hrp       string
 }
 
 // NewAvalancheXChainAddress creates an X-Chain address generator
 func NewAvalancheXChainAddress() *AvalancheAddress {
-	return &AvalancheAddress{chainType: "X"}
+	return &AvalancheAddress{chainType: "X", hrp: AvalancheXChainHRP}
 }
 
 // NewAvalanchePChainAddress creates a P-Chain address generator
 func NewAvalanchePChainAddress() *AvalancheAddress {
-	return &AvalancheAddress{chainType: "P"}
+	return &AvalancheAddress{chainType: "P", hrp: AvalanchePChainHRP}
+}
+
+// NewAvalancheAddressWithHRP creates an X- or P-Chain address generator for a custom network HRP
+// (e.g., "fuji" for the testnet)
+func NewAvalancheAddressWithHRP(chainType, hrp string) *AvalancheAddress {
+	return &AvalancheAddress{chainType: chainType, hrp: hrp}
 }
 
 // NewAvalancheCChainAddress creates a C-Chain address generator (uses Ethereum format)
@@ -52,7 +60,7 @@ func (a *AvalancheAddress) Generate(publicKey []byte) (string, error) {
 	hash := Hash160(publicKey)
 
 	// Encode with Bech32
-	addr, err := Bech32Encode(AvalancheXChainHRP, hash, Bech32Standard)
+	addr, err := Bech32Encode(a.hrp, hash, Bech32Standard)
 	if err != nil {
 		return "", err
 	}
@@ -10,14 +10,21 @@ const (
 	bech32Charset = "qpzry9x8gf2tvdw0s3jn54khce6mua7l"
 )
 
-var bech32CharsetMap = func() map[byte]int {
-	m := make(map[byte]int)
-	for i, c := range []byte(bech32Charset) {
-		m[c] = i
+// bech32CharsetRev maps a character to its 5-bit value, or -1 for a
+// character outside the charset. CashAddr uses the same charset.
+var bech32CharsetRev = func() (rev [256]int8) {
+	for i := range rev {
+		rev[i] = -1
 	}
-	return m
+	for i := 0; i < len(bech32Charset); i++ {
+		rev[bech32Charset[i]] = int8(i)
+	}
+	return rev
 }()
 
+// bech32Generator holds the BCH code generator coefficients of the checksum.
+var bech32Generator = [5]int{0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3}
+
 // Bech32Encoding represents the Bech32 variant
 type Bech32Encoding int
 
@@ -26,16 +33,37 @@ const (
 	Bech32m                              // BIP-350
 )
 
+// Checksum constants: the polymod of a valid string equals the variant's constant
+const (
+	bech32Const  = 1
+	bech32mConst = 0x2bc830a3
+)
+
+// String returns the variant name.
+func (e Bech32Encoding) String() string {
+	if e == Bech32m {
+		return "bech32m"
+	}
+	return "bech32"
+}
+
+// checksumConst returns the constant for the variant.
+func (e Bech32Encoding) checksumConst() int {
+	if e == Bech32m {
+		return bech32mConst
+	}
+	return bech32Const
+}
+
 // bech32Polymod calculates the Bech32 polymod checksum
 func bech32Polymod(values []int) int {
-	generator := []int{0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3}
 	chk := 1
 	for _, v := range values {
 		top := chk >> 25
 		chk = (chk&0x1ffffff)<<5 ^ v
 		for i := 0; i < 5; i++ {
 			if (top>>uint(i))&1 == 1 {
-				chk ^= generator[i]
+				chk ^= bech32Generator[i]
 			}
 		}
 	}
@@ -56,23 +84,14 @@ func bech32HRPExpand(hrp string) []int {
 // bech32VerifyChecksum verifies the Bech32 checksum
 func bech32VerifyChecksum(hrp string, data []int, encoding Bech32Encoding) bool {
 	values := append(bech32HRPExpand(hrp), data...)
-	expectedConst := 1
-	if encoding == Bech32m {
-		expectedConst = 0x2bc830a3
-	}
-	return bech32Polymod(values) == expectedConst
+	return bech32Polymod(values) == encoding.checksumConst()
 }
 
 // bech32CreateChecksum creates a Bech32 checksum
 func bech32CreateChecksum(hrp string, data []int, encoding Bech32Encoding) []int {
 	values := append(bech32HRPExpand(hrp), data...)
 	values = append(values, 0, 0, 0, 0, 0, 0)
-	polymod := bech32Polymod(values)
-	expectedConst := 1
-	if encoding == Bech32m {
-		expectedConst = 0x2bc830a3
-	}
-	polymod ^= expectedConst
+	polymod := bech32Polymod(values) ^ encoding.checksumConst()
 	checksum := make([]int, 6)
 	for i := 0; i < 6; i++ {
 		checksum[i] = (polymod >> uint(5*(5-i))) & 31
@@ -97,6 +116,7 @@ func Bech32Encode(hrp string, data []byte, encoding Bech32Encoding) (string, err
 
 	// Build result
 	result := strings.Builder{}
+	result.Grow(len(hrp) + 1 + len(converted) + len(checksum))
 	result.WriteString(strings.ToLower(hrp))
 	result.WriteByte('1')
 
@@ -110,8 +130,46 @@ func Bech32Encode(hrp string, data []byte, encoding Bech32Encoding) (string, err
 	return result.String(), nil
 }
 
-// Bech32Decode decodes a Bech32 string
+// Bech32DecodeAs decodes a string that must carry the checksum of the given
+// variant. Use it where the format fixes the variant (Bech32 for Cosmos, Bech32m
+// for Taproot) so a string valid only under the other one is rejected.
+func Bech32DecodeAs(str string, encoding Bech32Encoding) (hrp string, data []byte, err error) {
+	hrp, data, got, err := Bech32Decode(str)
+	if err != nil {
+		return "", nil, err
+	}
+	if got != encoding {
+		return "", nil, fmt.Errorf("%w: %s checksum, want %s", ErrInvalidChecksum, got, encoding)
+	}
+	return hrp, data, nil
+}
+
+// Bech32Decode decodes a Bech32 or Bech32m string and reports which variant
+// its checksum matched.
 func Bech32Decode(str string) (hrp string, data []byte, encoding Bech32Encoding, err error) {
+	hrp, groups, encoding, err := bech32DecodeGroups(str)
+	if err != nil {
+		return "", nil, 0, err
+	}
+
+	// Convert back to 8-bit
+	converted, err := convertBits(groups, 5, 8, false)
+	if err != nil {
+		return "", nil, 0, err
+	}
+
+	// Convert []int to []byte
+	result := make([]byte, len(converted))
+	for i, v := range converted {
+		result[i] = byte(v)
+	}
+
+	return hrp, result, encoding, nil
+}
+
+// bech32DecodeGroups decodes a Bech32 or Bech32m string into its HRP and the
+// 5-bit groups of its data part, without the checksum.
+func bech32DecodeGroups(str string) (hrp string, groups []int, encoding Bech32Encoding, err error) {
 	// Check for mixed case
 	lower := strings.ToLower(str)
 	upper := strings.ToUpper(str)
@@ -129,14 +187,20 @@ func Bech32Decode(str string) (hrp string, data []byte, encoding Bech32Encoding,
 	hrp = str[:pos]
 	dataStr := str[pos+1:]
 
+	for i := 0; i < len(hrp); i++ {
+		if hrp[i] < 33 || hrp[i] > 126 {
+			return "", nil, 0, fmt.Errorf("invalid character %q in bech32 human-readable part", hrp[i])
+		}
+	}
+
 	// Decode data part
 	intData := make([]int, len(dataStr))
 	for i, c := range []byte(dataStr) {
-		idx, ok := bech32CharsetMap[c]
-		if !ok {
+		idx := bech32CharsetRev[c]
+		if idx < 0 {
 			return "", nil, 0, fmt.Errorf("invalid character '%c' in bech32 string", c)
 		}
-		intData[i] = idx
+		intData[i] = int(idx)
 	}
 
 	// Verify checksum for both encodings
@@ -148,19 +212,7 @@ func Bech32Decode(str string) (hrp string, data []byte, encoding Bech32Encoding,
 		return "", nil, 0, ErrInvalidChecksum
 	}
 
-	// Remove checksum and convert back to 8-bit
-	converted, err := convertBits(intData[:len(intData)-6], 5, 8, false)
-	if err != nil {
-		return "", nil, 0, err
-	}
-
-	// Convert []int to []byte
-	result := make([]byte, len(converted))
-	for i, v := range converted {
-		result[i] = byte(v)
-	}
-
-	return hrp, result, encoding, nil
+	return hrp, intData[:len(intData)-6], encoding, nil
 }
 
 // convertBits converts between bit groupings
@@ -228,6 +280,7 @@ func SegWitEncode(hrp string, witnessVersion int, witnessProgram []byte) (string
 
 	// Build result
 	result := strings.Builder{}
+	result.Grow(len(hrp) + 1 + len(data) + len(checksum))
 	result.WriteString(strings.ToLower(hrp))
 	result.WriteByte('1')
 
@@ -241,22 +294,23 @@ func SegWitEncode(hrp string, witnessVersion int, witnessProgram []byte) (string
 	return result.String(), nil
 }
 
-// SegWitDecode decodes a SegWit address
+// SegWitDecode decodes a SegWit address, enforcing the BIP-173 and BIP-350
+// rules on the witness version, checksum variant and program length.
 func SegWitDecode(str string) (hrp string, witnessVersion int, witnessProgram []byte, err error) {
-	hrp, data, encoding, err := Bech32Decode(str)
+	hrp, groups, encoding, err := bech32DecodeGroups(str)
 	if err != nil {
 		return "", 0, nil, err
 	}
 
-	if len(data) < 1 {
+	if len(groups) < 1 {
 		return "", 0, nil, fmt.Errorf("empty data")
 	}
 
-	// Get witness version from decoded data before conversion
-	lower := strings.ToLower(str)
-	pos := strings.LastIndex(lower, "1")
-	dataStr := lower[pos+1:]
-	witnessVersion = bech32CharsetMap[dataStr[0]]
+	// The witness version is the first 5-bit group; the program follows
+	witnessVersion = groups[0]
+	if witnessVersion > 16 {
+		return "", 0, nil, fmt.Errorf("invalid witness version: %d", witnessVersion)
+	}
 
 	// Verify encoding matches version
 	if witnessVersion == 0 && encoding != Bech32Standard {
@@ -266,23 +320,17 @@ func SegWitDecode(str string) (hrp string, witnessVersion int, witnessProgram []
 		return "", 0, nil, fmt.Errorf("invalid encoding for witness version > 0")
 	}
 
-	// The data returned from Bech32Decode already has the witness version as the first byte
-	// but since we converted from 5-bit to 8-bit, we need to decode differently
-
-	// Re-decode to get 5-bit data
-	intData := make([]int, len(dataStr))
-	for i, c := range []byte(dataStr) {
-		intData[i] = bech32CharsetMap[c]
-	}
-
-	// Remove checksum and witness version
-	programData := intData[1 : len(intData)-6]
-
 	// Convert 5-bit to 8-bit
-	program, err := convertBits(programData, 5, 8, false)
+	program, err := convertBits(groups[1:], 5, 8, false)
 	if err != nil {
 		return "", 0, nil, err
 	}
+	if len(program) < 2 || len(program) > 40 {
+		return "", 0, nil, fmt.Errorf("invalid witness program length: %d", len(program))
+	}
+	if witnessVersion == 0 && len(program) != 20 && len(program) != 32 {
+		return "", 0, nil, fmt.Errorf("invalid witness v0 program length: %d", len(program))
+	}
 
 	witnessProgram = make([]byte, len(program))
 	for i, v := range program {
@@ -1,3 +1,5 @@
+//go:build !ca_no_bitcoin
+
 package address
 
 import (
@@ -5,6 +7,11 @@ import (
 	"strings"
 )
 
+// Build with -tags ca_no_bitcoin to leave Bitcoin Cash out of the build.
+func init() {
+	registerChain(ChainBitcoinCash, func() AddressGenerator { return NewBitcoinCashAddress(false) })
+}
+
 // Bitcoin Cash address types
 const (
 	BCHTypeP2PKH byte = 0x00
@@ -170,11 +177,11 @@ func (b *BitcoinCashAddress) Validate(address string) bool {
 	// Decode data
 	decoded := make([]int, len(data))
 	for i, c := range []byte(data) {
-		idx := strings.IndexByte(cashAddrCharset, c)
+		idx := bech32CharsetRev[c]
 		if idx < 0 {
 			return false
 		}
-		decoded[i] = idx
+		decoded[i] = int(idx)
 	}
 
 	// Verify checksum
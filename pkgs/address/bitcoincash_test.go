@@ -0,0 +1,31 @@
+//go:build !ca_no_bitcoin
+
+package address
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+func TestBitcoinCashAddress(t *testing.T) {
+	bch := NewBitcoinCashAddress(false)
+
+	// Compressed public key
+	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	addr, err := bch.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Bitcoin Cash addresses start with bitcoincash:q
+	if addr[:13] != "bitcoincash:q" {
+		t.Errorf("Address should start with bitcoincash:q, got %s", addr[:13])
+	}
+
+	// Test that the address was generated
+	if len(addr) < 42 {
+		t.Error("Address too short")
+	}
+}
@@ -0,0 +1,95 @@
+package address
+
+import (
+	"os/exec"
+	"slices"
+	"strings"
+	"testing"
+)
+
+// chainTags lists the chains each ca_no_* build tag leaves out of the factory.
+var chainTags = map[string][]ChainID{
+	"ca_no_bitcoin": {ChainBitcoin, ChainLitecoin, ChainDogecoin, ChainBitcoinCash},
+	"ca_no_evm": {
+		ChainEthereum, ChainBSC, ChainPolygon, ChainFantom, ChainOptimism, ChainArbitrum,
+		ChainVeChain, ChainTheta, ChainEthereumClassic, ChainHarmony, ChainRonin, ChainAvalanche,
+	},
+	"ca_no_cosmos":   {ChainCosmos, ChainBinanceBEP2, ChainSei},
+	"ca_no_solana":   {ChainSolana},
+	"ca_no_sui":      {ChainSui},
+	"ca_no_tron":     {ChainTron},
+	"ca_no_ripple":   {ChainRipple},
+	"ca_no_stellar":  {ChainStellar},
+	"ca_no_algorand": {ChainAlgorand},
+	"ca_no_near":     {ChainNEAR},
+	"ca_no_cardano":  {ChainCardano},
+	"ca_no_polkadot": {ChainPolkadot},
+	"ca_no_aptos":    {ChainAptos},
+	"ca_no_tezos":    {ChainTezos},
+	"ca_no_zcash":    {ChainZcash},
+	"ca_no_kaspa":    {ChainKaspa},
+	"ca_no_stacks":   {ChainStacks},
+	"ca_no_filecoin": {ChainFilecoin},
+	"ca_no_hedera":   {ChainHedera},
+	"ca_no_icp":      {ChainICP},
+	"ca_no_eos":      {ChainEOS},
+	"ca_no_flow":     {ChainFlow},
+	"ca_no_arweave":  {ChainArweave},
+	"ca_no_monero":   {ChainMonero},
+	"ca_no_starknet": {ChainStarknet},
+	"ca_no_neo":      {ChainNeo},
+	"ca_no_waves":    {ChainWaves},
+}
+
+func TestChainTagsCoverFactory(t *testing.T) {
+	for _, chainID := range NewFactory().ListSupportedChains() {
+		var tags []string
+		for tag, chains := range chainTags {
+			if slices.Contains(chains, chainID) {
+				tags = append(tags, tag)
+			}
+		}
+		if len(tags) != 1 {
+			t.Errorf("%s is left out by build tags %v, want exactly one", chainID, tags)
+		}
+	}
+}
+
+// TestBuildTags builds the module once per ca_no_* tag and checks that the
+// factory of a tagged build lacks exactly the tag's chains.
+func TestBuildTags(t *testing.T) {
+	if testing.Short() {
+		t.Skip("builds the module once per build tag")
+	}
+	goTool, err := exec.LookPath("go")
+	if err != nil {
+		t.Skip("go command not found")
+	}
+
+	all := strings.Fields(runGo(t, goTool, ".", "run", "./testdata/chains"))
+	for tag, chains := range chainTags {
+		t.Run(tag, func(t *testing.T) {
+			runGo(t, goTool, "../..", "build", "-tags", tag, "./...")
+
+			built := strings.Fields(runGo(t, goTool, ".", "run", "-tags", tag, "./testdata/chains"))
+			for _, chainID := range all {
+				want := !slices.Contains(chains, ChainID(chainID))
+				if got := slices.Contains(built, chainID); got != want {
+					t.Errorf("-tags %s: %s in factory = %v, want %v", tag, chainID, got, want)
+				}
+			}
+		})
+	}
+}
+
+// runGo runs the go command in dir and returns its output.
+func runGo(t *testing.T, goTool, dir string, args ...string) string {
+	t.Helper()
+	cmd := exec.Command(goTool, args...)
+	cmd.Dir = dir
+	out, err := cmd.CombinedOutput()
+	if err != nil {
+		t.Fatalf("go %s: %v\n%s", strings.Join(args, " "), err, out)
+	}
+	return string(out)
+}
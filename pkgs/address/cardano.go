@@ -1,12 +1,16 @@
+//go:build !ca_no_cardano
+
 package address
 
 import (
-	"crypto/sha256"
 	"fmt"
-
-	"golang.org/x/crypto/blake2b"
 )
 
+// Build with -tags ca_no_cardano to leave Cardano out of the build.
+func init() {
+	registerChain(ChainCardano, func() AddressGenerator { return NewCardanoAddress() })
+}
+
 // Cardano address types (Shelley era)
 const (
 	// Address type nibbles (upper 4 bits of first byte)
@@ -74,7 +78,7 @@ func (c *CardanoAddress) GenerateEnterpriseAddress(publicKey []byte) (string, er
 	}
 
 	// Hash the public key using Blake2b-224
-	keyHash := blake2b224(publicKey)
+	keyHash := Blake2b224(publicKey)
 
 	// Build address bytes
 	// First byte: address type (upper nibble) | network tag (lower nibble)
@@ -105,8 +109,8 @@ func (c *CardanoAddress) GenerateBaseAddress(paymentKey, stakeKey []byte) (strin
 	}
 
 	// Hash both keys using Blake2b-224
-	paymentHash := blake2b224(paymentKey)
-	stakeHash := blake2b224(stakeKey)
+	paymentHash := Blake2b224(paymentKey)
+	stakeHash := Blake2b224(stakeKey)
 
 	// Build address bytes
 	var header byte
@@ -137,7 +141,7 @@ func (c *CardanoAddress) GenerateRewardAddress(stakeKey []byte) (string, error)
 	}
 
 	// Hash the stake key using Blake2b-224
-	stakeHash := blake2b224(stakeKey)
+	stakeHash := Blake2b224(stakeKey)
 
 	// Build address bytes
 	var header byte
@@ -299,15 +303,3 @@ func (c *CardanoAddress) GetAddressType(address string) (string, error) {
 		return "unknown", nil
 	}
 }
-
-// blake2b224 computes Blake2b-224 hash (28 bytes)
-func blake2b224(data []byte) []byte {
-	h, err := blake2b.New(28, nil)
-	if err != nil {
-		// Fallback to SHA256 truncated (should never happen)
-		hash := sha256.Sum256(data)
-		return hash[:28]
-	}
-	h.Write(data)
-	return h.Sum(nil)
-}
@@ -0,0 +1,229 @@
+package address
+
+import (
+	"encoding/hex"
+	"fmt"
+	"strings"
+)
+
+// AnyAddress is an address whose chain was detected from its format, for
+// tools that accept "paste any address" input.
+type AnyAddress struct {
+	// Chain is the detected chain. Formats several chains share resolve to
+	// the first of Chains: Ethereum for 0x EVM addresses, Sui for 0x 32-byte
+	// addresses and Bitcoin for testnet Base58 versions.
+	Chain ChainID
+
+	// Chains lists every chain in the build that uses the format, Chain
+	// first.
+	Chains []ChainID
+
+	// Type names the format: p2pkh, p2sh, p2wpkh, p2wsh, p2tr or segwit on
+	// the Bitcoin family, evm, hex32, base58 (Solana), tron or bech32
+	// (Cosmos SDK).
+	Type string
+
+	// Payload is what the address encodes: the 20-byte hash, the witness
+	// program, or the 32-byte public key or account address.
+	Payload []byte
+
+	// Address is the input with surrounding white space removed.
+	Address string
+}
+
+// evmChains are the 0x 20-byte chains, in the order AnyAddress lists them.
+var evmChains = []ChainID{
+	ChainEthereum, ChainBSC, ChainPolygon, ChainArbitrum, ChainOptimism, ChainAvalanche,
+	ChainFantom, ChainEthereumClassic, ChainVeChain, ChainTheta,
+}
+
+// base58Versions maps the Base58Check version bytes of 20-byte hashes to
+// their chains and type.
+var base58Versions = map[byte]struct {
+	chains []ChainID
+	typ    string
+}{
+	BitcoinP2PKHVersion:         {[]ChainID{ChainBitcoin}, "p2pkh"},
+	BitcoinP2SHVersion:          {[]ChainID{ChainBitcoin}, "p2sh"},
+	BitcoinTestnetP2PKHVersion:  {[]ChainID{ChainBitcoin, ChainLitecoin}, "p2pkh"},
+	BitcoinTestnetP2SHVersion:   {[]ChainID{ChainBitcoin, ChainDogecoin}, "p2sh"},
+	LitecoinP2PKHVersion:        {[]ChainID{ChainLitecoin}, "p2pkh"},
+	LitecoinP2SHVersion:         {[]ChainID{ChainLitecoin}, "p2sh"},
+	LitecoinTestnetP2SHVersion:  {[]ChainID{ChainLitecoin}, "p2sh"},
+	DogecoinP2PKHVersion:        {[]ChainID{ChainDogecoin}, "p2pkh"},
+	DogecoinP2SHVersion:         {[]ChainID{ChainDogecoin}, "p2sh"},
+	DogecoinTestnetP2PKHVersion: {[]ChainID{ChainDogecoin}, "p2pkh"},
+	TronAddressPrefix:           {[]ChainID{ChainTron}, "tron"},
+}
+
+// segwitHRPs maps SegWit human-readable parts to their chain.
+var segwitHRPs = map[string]ChainID{
+	"bc":   ChainBitcoin,
+	"tb":   ChainBitcoin,
+	"bcrt": ChainBitcoin,
+	"ltc":  ChainLitecoin,
+	"tltc": ChainLitecoin,
+}
+
+// cosmosHRPs maps account HRPs of Cosmos SDK chains to their chain; the
+// valoper and valcons forms are recognized too.
+var cosmosHRPs = map[string]ChainID{
+	"cosmos": ChainCosmos,
+	"bnb":    ChainBinanceBEP2,
+	"sei":    ChainSei,
+}
+
+// ParseAnyAddress detects the chain of an address from its format and
+// validates it for that chain:
+//
+//   - 1..., 3..., m..., L..., D..., T... Base58Check with a 20-byte hash
+//   - bc1..., tb1..., ltc1... SegWit
+//   - 0x with 20 bytes for EVM chains, 0x with 32 bytes for Sui and Aptos
+//   - Base58 32-byte public keys for Solana
+//   - cosmos1..., bnb1..., sei1... Bech32
+//
+// An address in a recognized format that fails validation returns the
+// chain's *ValidationError, such as ErrInvalidChecksum. Other input returns
+// ErrInvalidAddress, and a format whose chains are all left out of the build
+// ErrUnsupportedChain.
+func ParseAnyAddress(s string) (*AnyAddress, error) {
+	addr := strings.TrimSpace(s)
+	a, verr := detectAddress(addr)
+	if verr != nil && len(a.Chains) == 0 {
+		return nil, fmt.Errorf("%w: %q is not in a known format", ErrInvalidAddress, addr)
+	}
+	if verr != nil {
+		verr.Chain, verr.Address = a.Chains[0], addr
+		return nil, verr
+	}
+
+	a.Address = addr
+	a.Chains = inBuild(a.Chains)
+	if len(a.Chains) == 0 {
+		return nil, fmt.Errorf("%w: %s address %q", ErrUnsupportedChain, a.Type, addr)
+	}
+	a.Chain = a.Chains[0]
+	return a, nil
+}
+
+// UnmarshalText parses an address with ParseAnyAddress.
+func (a *AnyAddress) UnmarshalText(text []byte) error {
+	parsed, err := ParseAnyAddress(string(text))
+	if err != nil {
+		return err
+	}
+	*a = *parsed
+	return nil
+}
+
+// MarshalText returns the address.
+func (a AnyAddress) MarshalText() ([]byte, error) {
+	return []byte(a.Address), nil
+}
+
+// String returns the address.
+func (a AnyAddress) String() string {
+	return a.Address
+}
+
+// detectAddress recognizes the format of addr and validates it. Chains is
+// set when the format was recognized, even if validation failed.
+func detectAddress(addr string) (*AnyAddress, *ValidationError) {
+	a := &AnyAddress{}
+	if addr == "" {
+		return a, invalid(ErrInvalidLength, "")
+	}
+
+	if strings.HasPrefix(addr, "0x") || strings.HasPrefix(addr, "0X") {
+		switch len(addr) - 2 {
+		case 40:
+			a.Chains, a.Type = evmChains, "evm"
+			if verr := validateEVM(addr); verr != nil {
+				return a, verr
+			}
+		case 64:
+			a.Chains, a.Type = []ChainID{ChainSui, ChainAptos}, "hex32"
+			if verr := validateSui(strings.ToLower(addr[:2]) + addr[2:]); verr != nil {
+				return a, verr
+			}
+		default:
+			return a, invalid(ErrInvalidLength, fmt.Sprintf("%d hex digits, want 40 or 64", len(addr)-2))
+		}
+		a.Payload, _ = hex.DecodeString(addr[2:])
+		return a, nil
+	}
+
+	if i := strings.LastIndexByte(addr, '1'); i > 0 {
+		hrp := strings.ToLower(addr[:i])
+		if chain, ok := segwitHRPs[hrp]; ok {
+			a.Chains = []ChainID{chain}
+			return detectSegWit(a, addr, hrp)
+		}
+		base := strings.TrimSuffix(strings.TrimSuffix(hrp, "valoper"), "valcons")
+		if chain, ok := cosmosHRPs[base]; ok {
+			a.Chains, a.Type = []ChainID{chain}, "bech32"
+			if verr := validateCosmos(addr, base); verr != nil {
+				return a, verr
+			}
+			_, a.Payload, _, _ = Bech32Decode(addr)
+			return a, nil
+		}
+	}
+
+	decoded, err := Base58Decode(addr)
+	if err != nil {
+		return a, invalid(ErrInvalidEncoding, err.Error())
+	}
+	switch len(decoded) {
+	case 32:
+		a.Chains, a.Type, a.Payload = []ChainID{ChainSolana}, "base58", decoded
+		return a, nil
+	case 25:
+		v, ok := base58Versions[decoded[0]]
+		if !ok {
+			return a, invalid(ErrInvalidVersion, fmt.Sprintf("version byte 0x%02x", decoded[0]))
+		}
+		a.Chains, a.Type = v.chains, v.typ
+		_, payload, err := Base58CheckDecode(addr)
+		if err != nil {
+			return a, invalid(ErrInvalidChecksum, "")
+		}
+		a.Payload = payload
+		return a, nil
+	default:
+		return a, invalid(ErrInvalidLength, fmt.Sprintf("%d bytes", len(decoded)))
+	}
+}
+
+// detectSegWit validates a SegWit address and names its output type.
+func detectSegWit(a *AnyAddress, addr, hrp string) (*AnyAddress, *ValidationError) {
+	a.Type = "segwit"
+	if verr := validateSegWit(addr, hrp); verr != nil {
+		return a, verr
+	}
+	_, version, program, err := SegWitDecode(addr)
+	if err != nil {
+		return a, invalid(ErrInvalidEncoding, err.Error())
+	}
+	a.Payload = program
+	switch {
+	case version == 0 && len(program) == 20:
+		a.Type = "p2wpkh"
+	case version == 0:
+		a.Type = "p2wsh"
+	case version == 1 && len(program) == 32:
+		a.Type = "p2tr"
+	}
+	return a, nil
+}
+
+// inBuild returns the chains the default factory has a generator for.
+func inBuild(chains []ChainID) []ChainID {
+	var out []ChainID
+	for _, chain := range chains {
+		if _, err := DefaultFactory.Get(chain); err == nil {
+			out = append(out, chain)
+		}
+	}
+	return out
+}
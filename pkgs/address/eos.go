@@ -1,3 +1,5 @@
+//go:build !ca_no_eos
+
 package address
 
 import (
@@ -5,6 +7,11 @@ import (
 	"strings"
 )
 
+// Build with -tags ca_no_eos to leave EOS out of the build.
+func init() {
+	registerChain(ChainEOS, func() AddressGenerator { return NewEOSAddress() })
+}
+
 // EOS name character set (base31 without certain characters)
 // a-z, 1-5, and . (period)
 const eosNameCharset = ".12345abcdefghijklmnopqrstuvwxyz"
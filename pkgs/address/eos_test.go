@@ -0,0 +1,69 @@
+//go:build !ca_no_eos
+
+package address
+
+import (
+	"encoding/hex"
+	"strings"
+	"testing"
+)
+
+// TestEOSAddress tests EOS address/public key generation
+func TestEOSAddress(t *testing.T) {
+	eos := NewEOSAddress()
+
+	// Compressed public key (33 bytes)
+	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	// Generate EOS public key
+	addr, err := eos.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// EOS public keys start with "EOS"
+	if !strings.HasPrefix(addr, "EOS") {
+		t.Errorf("Address should start with EOS, got %s", addr[:3])
+	}
+
+	// Validate
+	if !eos.Validate(addr) {
+		t.Error("EOS public key validation failed")
+	}
+
+	// Test PUB_K1 format
+	pubK1Addr, err := eos.GeneratePubK1Key(pubKey)
+	if err != nil {
+		t.Fatalf("GeneratePubK1Key() error = %v", err)
+	}
+	if !strings.HasPrefix(pubK1Addr, "PUB_K1_") {
+		t.Errorf("Address should start with PUB_K1_, got %s", pubK1Addr[:7])
+	}
+	if !eos.Validate(pubK1Addr) {
+		t.Error("PUB_K1 key validation failed")
+	}
+
+	// Test account name validation
+	if !eos.ValidateAccountName("eosio") {
+		t.Error("Should accept valid account name 'eosio'")
+	}
+	if !eos.ValidateAccountName("myaccount123") {
+		t.Error("Should accept valid account name 'myaccount123'")
+	}
+	if eos.ValidateAccountName("InvalidName") {
+		t.Error("Should reject uppercase account name")
+	}
+	if eos.ValidateAccountName("toolongaccountname") {
+		t.Error("Should reject account name longer than 12 chars")
+	}
+
+	// Test GetAddressType
+	addrType, err := eos.GetAddressType(addr)
+	if err != nil {
+		t.Fatalf("GetAddressType() error = %v", err)
+	}
+	if !strings.Contains(addrType, "Legacy") {
+		t.Errorf("GetAddressType() = %s, want Legacy type", addrType)
+	}
+}
@@ -176,5 +176,7 @@ func EVMChains() map[ChainID]*EthereumAddress {
 		ChainVeChain:         NewEVMAddress(ChainVeChain),
 		ChainTheta:           NewEVMAddress(ChainTheta),
 		ChainEthereumClassic: NewEVMAddress(ChainEthereumClassic),
+		ChainHarmony:         NewEVMAddress(ChainHarmony),
+		ChainRonin:           NewEVMAddress(ChainRonin),
 	}
 }
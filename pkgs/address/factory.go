@@ -9,72 +9,35 @@ type Factory struct {
 	generators map[ChainID]AddressGenerator
 }
 
-// NewFactory creates a new address generator factory
+// NewFactory creates a new address generator factory with a generator for
+// every chain in the build.
 func NewFactory() *Factory {
 	f := &Factory{
-		generators: make(map[ChainID]AddressGenerator),
+		generators: make(map[ChainID]AddressGenerator, len(registry)),
+	}
+	for chainID, newGenerator := range registry {
+		f.Register(chainID, newGenerator())
 	}
-	f.registerDefaults()
 	return f
 }
 
-// registerDefaults registers all default address generators
-func (f *Factory) registerDefaults() {
-	// Bitcoin-family
-	f.Register(ChainBitcoin, NewBitcoinAddress(false))
-	f.Register(ChainLitecoin, NewLitecoinAddress(false))
-	f.Register(ChainDogecoin, NewDogecoinAddress(false))
-	f.Register(ChainBitcoinCash, NewBitcoinCashAddress(false))
-
-	// Ethereum-family (EVM)
-	f.Register(ChainEthereum, NewEthereumAddress())
-	f.Register(ChainBSC, NewEVMAddress(ChainBSC))
-	f.Register(ChainPolygon, NewEVMAddress(ChainPolygon))
-	f.Register(ChainFantom, NewEVMAddress(ChainFantom))
-	f.Register(ChainOptimism, NewEVMAddress(ChainOptimism))
-	f.Register(ChainArbitrum, NewEVMAddress(ChainArbitrum))
-	f.Register(ChainVeChain, NewEVMAddress(ChainVeChain))
-	f.Register(ChainTheta, NewEVMAddress(ChainTheta))
-	f.Register(ChainEthereumClassic, NewEVMAddress(ChainEthereumClassic))
-	f.Register(ChainAvalanche, NewAvalancheCChainAddress()) // C-Chain is EVM
-
-	// Cosmos-family (Bech32)
-	f.Register(ChainCosmos, NewCosmosAddress())
-	f.Register(ChainBinanceBEP2, NewBinanceBEP2Address())
-	f.Register(ChainSei, NewSeiAddress())
-
-	// TRON
-	f.Register(ChainTron, NewTronAddress(false))
-
-	// Ripple
-	f.Register(ChainRipple, NewRippleAddress())
-
-	// Ed25519-based
-	f.Register(ChainSolana, NewSolanaAddress())
-	f.Register(ChainStellar, NewStellarAddress())
-	f.Register(ChainAlgorand, NewAlgorandAddress())
-	f.Register(ChainNEAR, NewNEARAddress())
-	f.Register(ChainCardano, NewCardanoAddress())
-
-	// Polkadot-family (SS58)
-	f.Register(ChainPolkadot, NewPolkadotAddress())
-
-	// New generation chains
-	f.Register(ChainAptos, NewAptosAddress())
-	f.Register(ChainSui, NewSuiAddress())
-
-	// Other chains
-	f.Register(ChainTezos, NewTezosAddress())
-	f.Register(ChainZcash, NewZcashAddress())
-	f.Register(ChainKaspa, NewKaspaAddress())
-	f.Register(ChainStacks, NewStacksAddress())
-	f.Register(ChainFilecoin, NewFilecoinAddress())
-	f.Register(ChainHedera, NewHederaAddress())
-	f.Register(ChainICP, NewICPAddress())
-	f.Register(ChainEOS, NewEOSAddress())
-	f.Register(ChainFlow, NewFlowAddress())
-	f.Register(ChainArweave, NewArweaveAddress())
-	f.Register(ChainMonero, NewMoneroAddress())
+// registry maps each chain in the build to its generator constructor.
+//
+// Every chain registers itself with registerChain from an init function in a
+// file guarded by its ca_no_* build tag (ca_no_tron, ca_no_ripple, ...; the
+// Bitcoin, EVM and Cosmos families share ca_no_bitcoin, ca_no_evm and
+// ca_no_cosmos). A chain whose encoder no other package calls carries the tag
+// and the init function on its implementation file, so a tagged build does
+// not compile it at all. The others are used directly by pkgs/account and
+// friends and keep their implementation; the tag drops their feature_*.go
+// registration, and with it the factory's reference to their code.
+var registry = make(map[ChainID]func() AddressGenerator)
+
+// registerChain adds a chain to NewFactory. DefaultFactory is built before
+// init functions run, so the chain is added to it too.
+func registerChain(chainID ChainID, newGenerator func() AddressGenerator) {
+	registry[chainID] = newGenerator
+	DefaultFactory.Register(chainID, newGenerator())
 }
 
 // Register adds a new address generator to the factory
@@ -153,6 +116,8 @@ func GetChainInfo(chainID ChainID) *ChainInfo {
 		ChainArbitrum:        {ChainArbitrum, "Arbitrum", "ARB", "Keccak256", "Same as Ethereum"},
 		ChainVeChain:         {ChainVeChain, "VeChain", "VET", "Keccak256", "Same as Ethereum"},
 		ChainTheta:           {ChainTheta, "Theta", "THETA", "Keccak256", "Same as Ethereum"},
+		ChainHarmony:         {ChainHarmony, "Harmony", "ONE", "Bech32/Ethereum", "Starts with 'one1', or 0x EVM form"},
+		ChainRonin:           {ChainRonin, "Ronin", "RON", "Keccak256", "Shown as 'ronin:' + lowercase hex"},
 		ChainBinanceBEP2:     {ChainBinanceBEP2, "Binance Chain", "BNB", "Bech32", "Starts with 'bnb'"},
 		ChainNEAR:            {ChainNEAR, "NEAR Protocol", "NEAR", "Hex/Named", "64 hex chars or named accounts"},
 		ChainAlgorand:        {ChainAlgorand, "Algorand", "ALGO", "Base32", "58 characters"},
@@ -168,6 +133,9 @@ func GetChainInfo(chainID ChainID) *ChainInfo {
 		ChainEOS:             {ChainEOS, "EOS", "EOS", "Base58/Name", "12-char account names"},
 		ChainFlow:            {ChainFlow, "Flow", "FLOW", "Hex", "0x-prefixed, 16 hex chars"},
 		ChainArweave:         {ChainArweave, "Arweave", "AR", "Base64URL", "43 characters (SHA-256)"},
+		ChainStarknet:        {ChainStarknet, "Starknet", "STRK", "Hex", "0x-prefixed felt, counterfactual account contract"},
+		ChainNeo:             {ChainNeo, "Neo N3", "NEO", "Base58Check", "Starts with 'N' (P-256 script hash)"},
+		ChainWaves:           {ChainWaves, "Waves", "WAVES", "Base58", "Starts with '3P' (mainnet)"},
 	}
 
 	info, ok := chainInfoMap[chainID]
@@ -177,20 +145,24 @@ func GetChainInfo(chainID ChainID) *ChainInfo {
 	return info
 }
 
-// ListAllChainInfo returns information about all supported chains
+// ListAllChainInfo returns information about all chains included in the build
 func ListAllChainInfo() []*ChainInfo {
 	chains := []ChainID{
 		ChainBitcoin, ChainEthereum, ChainLitecoin, ChainDogecoin, ChainRipple,
 		ChainStellar, ChainCardano, ChainPolkadot, ChainSolana, ChainAvalanche, ChainCosmos,
 		ChainTron, ChainBitcoinCash, ChainBSC, ChainPolygon, ChainFantom,
-		ChainOptimism, ChainArbitrum, ChainVeChain, ChainTheta, ChainBinanceBEP2,
+		ChainOptimism, ChainArbitrum, ChainVeChain, ChainTheta, ChainHarmony, ChainRonin, ChainBinanceBEP2,
 		ChainNEAR, ChainAlgorand, ChainAptos, ChainSui, ChainSei, ChainEthereumClassic,
 		ChainTezos, ChainZcash, ChainKaspa, ChainStacks, ChainFilecoin,
 		ChainHedera, ChainICP, ChainEOS, ChainFlow, ChainArweave, ChainMonero,
+		ChainStarknet, ChainNeo, ChainWaves,
 	}
 
 	infos := make([]*ChainInfo, 0, len(chains))
 	for _, chainID := range chains {
+		if !IsSupported(chainID) {
+			continue
+		}
 		if info := GetChainInfo(chainID); info != nil {
 			infos = append(infos, info)
 		}
@@ -210,3 +182,10 @@ func Generate(chainID ChainID, publicKey []byte) (string, error) {
 func Validate(chainID ChainID, address string) bool {
 	return DefaultFactory.Validate(chainID, address)
 }
+
+// IsSupported reports whether the default factory has a generator for the chain,
+// i.e. the chain is known and was not left out with its ca_no_* build tag.
+func IsSupported(chainID ChainID) bool {
+	_, err := DefaultFactory.Get(chainID)
+	return err == nil
+}
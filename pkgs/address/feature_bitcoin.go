@@ -0,0 +1,12 @@
+//go:build !ca_no_bitcoin
+
+package address
+
+// Registers Bitcoin, Litecoin and Dogecoin in NewFactory; Bitcoin Cash registers
+// from bitcoincash.go under the same tag. Build with -tags ca_no_bitcoin to leave
+// them out.
+func init() {
+	registerChain(ChainBitcoin, func() AddressGenerator { return NewBitcoinAddress(false) })
+	registerChain(ChainLitecoin, func() AddressGenerator { return NewLitecoinAddress(false) })
+	registerChain(ChainDogecoin, func() AddressGenerator { return NewDogecoinAddress(false) })
+}
@@ -0,0 +1,11 @@
+//go:build !ca_no_cosmos
+
+package address
+
+// Registers Cosmos SDK chains (ATOM, BNB BEP2, SEI) in NewFactory.
+// Build with -tags ca_no_cosmos to leave them out.
+func init() {
+	registerChain(ChainCosmos, func() AddressGenerator { return NewCosmosAddress() })
+	registerChain(ChainBinanceBEP2, func() AddressGenerator { return NewBinanceBEP2Address() })
+	registerChain(ChainSei, func() AddressGenerator { return NewSeiAddress() })
+}
@@ -0,0 +1,20 @@
+//go:build !ca_no_evm
+
+package address
+
+// Registers EVM-compatible chains, including Harmony, Ronin and the Avalanche C-Chain in NewFactory.
+// Build with -tags ca_no_evm to leave them out.
+func init() {
+	registerChain(ChainEthereum, func() AddressGenerator { return NewEthereumAddress() })
+	registerChain(ChainBSC, func() AddressGenerator { return NewEVMAddress(ChainBSC) })
+	registerChain(ChainPolygon, func() AddressGenerator { return NewEVMAddress(ChainPolygon) })
+	registerChain(ChainFantom, func() AddressGenerator { return NewEVMAddress(ChainFantom) })
+	registerChain(ChainOptimism, func() AddressGenerator { return NewEVMAddress(ChainOptimism) })
+	registerChain(ChainArbitrum, func() AddressGenerator { return NewEVMAddress(ChainArbitrum) })
+	registerChain(ChainVeChain, func() AddressGenerator { return NewEVMAddress(ChainVeChain) })
+	registerChain(ChainTheta, func() AddressGenerator { return NewEVMAddress(ChainTheta) })
+	registerChain(ChainEthereumClassic, func() AddressGenerator { return NewEVMAddress(ChainEthereumClassic) })
+	registerChain(ChainHarmony, func() AddressGenerator { return NewHarmonyAddress() })
+	registerChain(ChainRonin, func() AddressGenerator { return NewRoninAddress() })
+	registerChain(ChainAvalanche, func() AddressGenerator { return NewAvalancheCChainAddress() })
+}
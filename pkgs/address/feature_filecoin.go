@@ -0,0 +1,9 @@
+//go:build !ca_no_filecoin
+
+package address
+
+// Registers Filecoin in NewFactory.
+// Build with -tags ca_no_filecoin to leave it out.
+func init() {
+	registerChain(ChainFilecoin, func() AddressGenerator { return NewFilecoinAddress() })
+}
@@ -0,0 +1,9 @@
+//go:build !ca_no_flow
+
+package address
+
+// Registers Flow in NewFactory.
+// Build with -tags ca_no_flow to leave it out.
+func init() {
+	registerChain(ChainFlow, func() AddressGenerator { return NewFlowAddress() })
+}
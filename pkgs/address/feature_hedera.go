@@ -0,0 +1,9 @@
+//go:build !ca_no_hedera
+
+package address
+
+// Registers Hedera in NewFactory.
+// Build with -tags ca_no_hedera to leave it out.
+func init() {
+	registerChain(ChainHedera, func() AddressGenerator { return NewHederaAddress() })
+}
@@ -0,0 +1,9 @@
+//go:build !ca_no_icp
+
+package address
+
+// Registers the Internet Computer in NewFactory.
+// Build with -tags ca_no_icp to leave it out.
+func init() {
+	registerChain(ChainICP, func() AddressGenerator { return NewICPAddress() })
+}
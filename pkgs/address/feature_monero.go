@@ -0,0 +1,9 @@
+//go:build !ca_no_monero
+
+package address
+
+// Registers Monero in NewFactory.
+// Build with -tags ca_no_monero to leave it out.
+func init() {
+	registerChain(ChainMonero, func() AddressGenerator { return NewMoneroAddress() })
+}
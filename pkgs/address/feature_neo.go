@@ -0,0 +1,9 @@
+//go:build !ca_no_neo
+
+package address
+
+// Registers Neo N3 in NewFactory.
+// Build with -tags ca_no_neo to leave it out.
+func init() {
+	registerChain(ChainNeo, func() AddressGenerator { return NewNeoAddress() })
+}
@@ -0,0 +1,9 @@
+//go:build !ca_no_ripple
+
+package address
+
+// Registers XRP Ledger in NewFactory.
+// Build with -tags ca_no_ripple to leave it out.
+func init() {
+	registerChain(ChainRipple, func() AddressGenerator { return NewRippleAddress() })
+}
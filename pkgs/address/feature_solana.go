@@ -0,0 +1,9 @@
+//go:build !ca_no_solana
+
+package address
+
+// Registers Solana in NewFactory.
+// Build with -tags ca_no_solana to leave it out.
+func init() {
+	registerChain(ChainSolana, func() AddressGenerator { return NewSolanaAddress() })
+}
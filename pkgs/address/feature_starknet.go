@@ -0,0 +1,9 @@
+//go:build !ca_no_starknet
+
+package address
+
+// Registers Starknet in NewFactory.
+// Build with -tags ca_no_starknet to leave it out.
+func init() {
+	registerChain(ChainStarknet, func() AddressGenerator { return NewStarknetAddress() })
+}
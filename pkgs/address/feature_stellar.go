@@ -0,0 +1,9 @@
+//go:build !ca_no_stellar
+
+package address
+
+// Registers Stellar in NewFactory.
+// Build with -tags ca_no_stellar to leave it out.
+func init() {
+	registerChain(ChainStellar, func() AddressGenerator { return NewStellarAddress() })
+}
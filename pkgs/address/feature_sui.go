@@ -0,0 +1,9 @@
+//go:build !ca_no_sui
+
+package address
+
+// Registers Sui in NewFactory.
+// Build with -tags ca_no_sui to leave it out.
+func init() {
+	registerChain(ChainSui, func() AddressGenerator { return NewSuiAddress() })
+}
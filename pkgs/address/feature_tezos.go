@@ -0,0 +1,9 @@
+//go:build !ca_no_tezos
+
+package address
+
+// Registers Tezos in NewFactory.
+// Build with -tags ca_no_tezos to leave it out.
+func init() {
+	registerChain(ChainTezos, func() AddressGenerator { return NewTezosAddress() })
+}
@@ -0,0 +1,9 @@
+//go:build !ca_no_tron
+
+package address
+
+// Registers TRON in NewFactory.
+// Build with -tags ca_no_tron to leave it out.
+func init() {
+	registerChain(ChainTron, func() AddressGenerator { return NewTronAddress(false) })
+}
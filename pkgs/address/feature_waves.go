@@ -0,0 +1,9 @@
+//go:build !ca_no_waves
+
+package address
+
+// Registers Waves in NewFactory.
+// Build with -tags ca_no_waves to leave it out.
+func init() {
+	registerChain(ChainWaves, func() AddressGenerator { return NewWavesAddress() })
+}
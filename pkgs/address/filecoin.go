@@ -2,8 +2,6 @@ package address
 
 import (
 	"fmt"
-
-	"golang.org/x/crypto/blake2b"
 )
 
 // Filecoin protocol indicators
@@ -54,7 +52,7 @@ func (f *FilecoinAddress) F1Address(publicKey []byte) (string, error) {
 	}
 
 	// Hash the public key with Blake2b-160
-	hash := filecoinBlake2b160(publicKey)
+	hash := Blake2b160(publicKey)
 
 	// Calculate checksum: Blake2b-32 of (protocol + hash)
 	checksumInput := make([]byte, 1+len(hash))
@@ -190,24 +188,9 @@ func (f *FilecoinAddress) DecodeAddress(address string) (*AddressInfo, error) {
 	}, nil
 }
 
-// filecoinBlake2b160 computes Blake2b-160 hash
-func filecoinBlake2b160(data []byte) []byte {
-	h, err := blake2b.New(20, nil)
-	if err != nil {
-		return nil
-	}
-	h.Write(data)
-	return h.Sum(nil)
-}
-
 // filecoinBlake2b32 computes Blake2b-32 (4 bytes) for checksum
 func filecoinBlake2b32(data []byte) []byte {
-	h, err := blake2b.New(4, nil)
-	if err != nil {
-		return nil
-	}
-	h.Write(data)
-	return h.Sum(nil)
+	return blake2bSum(data, 4)
 }
 
 // filecoinBase32Encode encodes data to base32 (lowercase, no padding)
@@ -0,0 +1,98 @@
+package address
+
+import (
+	"fmt"
+	"strings"
+)
+
+// HarmonyHRP is the Bech32 prefix for Harmony addresses
+const HarmonyHRP = "one"
+
+// HarmonyAddress generates Harmony (ONE) addresses
+// Harmony accounts are EVM accounts; explorers show both the 0x form and the
+// Bech32 "one1..." encoding of the same 20 bytes.
+type HarmonyAddress struct {
+	evm *EthereumAddress
+}
+
+// NewHarmonyAddress creates a new Harmony address generator
+func NewHarmonyAddress() *HarmonyAddress {
+	return &HarmonyAddress{evm: NewEVMAddress(ChainHarmony)}
+}
+
+// ChainID returns the chain identifier
+func (h *HarmonyAddress) ChainID() ChainID {
+	return ChainHarmony
+}
+
+// Generate creates a "one1..." address from a public key
+// Public key should be 64 or 65 bytes (uncompressed secp256k1)
+func (h *HarmonyAddress) Generate(publicKey []byte) (string, error) {
+	evmAddr, err := h.evm.Generate(publicKey)
+	if err != nil {
+		return "", err
+	}
+	return HarmonyFromEVM(evmAddr)
+}
+
+// GenerateEVM creates the 0x (EIP-55) form of the address from a public key
+func (h *HarmonyAddress) GenerateEVM(publicKey []byte) (string, error) {
+	return h.evm.Generate(publicKey)
+}
+
+// HarmonyFromEVM converts a 0x address to its "one1..." form
+func HarmonyFromEVM(evmAddress string) (string, error) {
+	info, err := NewEVMAddress(ChainHarmony).DecodeAddress(evmAddress)
+	if err != nil {
+		return "", err
+	}
+	return Bech32Encode(HarmonyHRP, info.PublicKey, Bech32Standard)
+}
+
+// HarmonyToEVM converts a "one1..." address to its EIP-55 checksummed 0x form
+func HarmonyToEVM(oneAddress string) (string, error) {
+	hrp, data, encoding, err := Bech32Decode(oneAddress)
+	if err != nil {
+		return "", err
+	}
+	if hrp != HarmonyHRP || encoding != Bech32Standard || len(data) != 20 {
+		return "", ErrInvalidAddress
+	}
+	return NewEVMAddress(ChainHarmony).toChecksumAddress(data), nil
+}
+
+// Validate checks if an address is a valid Harmony address in either form
+func (h *HarmonyAddress) Validate(address string) bool {
+	if strings.HasPrefix(address, "0x") || strings.HasPrefix(address, "0X") {
+		return h.evm.Validate(address)
+	}
+	_, err := HarmonyToEVM(address)
+	return err == nil
+}
+
+// DecodeAddress decodes a Harmony address in either form
+func (h *HarmonyAddress) DecodeAddress(address string) (*AddressInfo, error) {
+	if !h.Validate(address) {
+		return nil, ErrInvalidAddress
+	}
+
+	evmAddr := address
+	addrType := AddressTypeEthereum
+	if !strings.HasPrefix(strings.ToLower(address), "0x") {
+		converted, err := HarmonyToEVM(address)
+		if err != nil {
+			return nil, fmt.Errorf("invalid Harmony address: %w", err)
+		}
+		evmAddr = converted
+		addrType = AddressTypeBech32
+	}
+
+	info, err := h.evm.DecodeAddress(evmAddr)
+	if err != nil {
+		return nil, err
+	}
+	info.Address = address
+	info.Type = addrType
+
+	return info, nil
+}
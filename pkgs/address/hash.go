@@ -1,89 +1,78 @@
 package address
 
 import (
-	"crypto/sha256"
-	"crypto/sha512"
-
-	"golang.org/x/crypto/ripemd160"
-	"golang.org/x/crypto/sha3"
-	"golang.org/x/crypto/blake2b"
+	"github.com/study/crypto-accounts/pkgs/crypto/hash"
 )
 
+// The helpers below are the address package's names for the shared
+// implementations in pkgs/crypto/hash.
+
 // Hash160 performs SHA256 followed by RIPEMD160 (Bitcoin-style)
 func Hash160(data []byte) []byte {
-	sha := sha256.Sum256(data)
-	ripemd := ripemd160.New()
-	ripemd.Write(sha[:])
-	return ripemd.Sum(nil)
+	return hash.Hash160(data)
 }
 
 // DoubleSHA256 performs SHA256 twice (Bitcoin-style)
 func DoubleSHA256(data []byte) []byte {
-	first := sha256.Sum256(data)
-	second := sha256.Sum256(first[:])
-	return second[:]
+	return hash.DoubleSHA256(data)
 }
 
 // SHA256Hash performs a single SHA256 hash
 func SHA256Hash(data []byte) []byte {
-	hash := sha256.Sum256(data)
-	return hash[:]
+	return hash.SHA256(data)
 }
 
 // SHA512Hash performs a single SHA512 hash
 func SHA512Hash(data []byte) []byte {
-	hash := sha512.Sum512(data)
-	return hash[:]
+	return hash.SHA512(data)
 }
 
 // Keccak256 performs Keccak-256 hash (Ethereum-style)
 func Keccak256(data []byte) []byte {
-	hash := sha3.NewLegacyKeccak256()
-	hash.Write(data)
-	return hash.Sum(nil)
+	return hash.Keccak256(data)
 }
 
 // SHA3256 performs SHA3-256 hash
 func SHA3256(data []byte) []byte {
-	hash := sha3.New256()
-	hash.Write(data)
-	return hash.Sum(nil)
+	return hash.SHA3256(data)
 }
 
 // Blake2b256 performs BLAKE2b-256 hash
 func Blake2b256(data []byte) []byte {
-	hash, _ := blake2b.New256(nil)
-	hash.Write(data)
-	return hash.Sum(nil)
+	return hash.Blake2b256(data)
 }
 
 // Blake2b512 performs BLAKE2b-512 hash
 func Blake2b512(data []byte) []byte {
-	hash, _ := blake2b.New512(nil)
-	hash.Write(data)
-	return hash.Sum(nil)
+	return hash.Blake2b(data, 64)
+}
+
+// Blake2b224 performs BLAKE2b-224 hash (Cardano-style)
+func Blake2b224(data []byte) []byte {
+	return hash.Blake2b(data, 28)
 }
 
 // Blake2b160 performs BLAKE2b-160 hash (Filecoin-style)
 func Blake2b160(data []byte) []byte {
-	hash, _ := blake2b.New(20, nil)
-	hash.Write(data)
-	return hash.Sum(nil)
+	return hash.Blake2b(data, 20)
+}
+
+// blake2bSum performs an unkeyed BLAKE2b hash of size bytes
+func blake2bSum(data []byte, size int) []byte {
+	return hash.Blake2b(data, size)
 }
 
 // RIPEMD160Hash performs RIPEMD-160 hash
 func RIPEMD160Hash(data []byte) []byte {
-	hash := ripemd160.New()
-	hash.Write(data)
-	return hash.Sum(nil)
+	return hash.RIPEMD160(data)
 }
 
 // Checksum4 calculates a 4-byte checksum using double SHA256
 func Checksum4(data []byte) []byte {
-	return DoubleSHA256(data)[:4]
+	return hash.Checksum(data)
 }
 
 // Checksum4Keccak calculates a 4-byte checksum using Keccak256
 func Checksum4Keccak(data []byte) []byte {
-	return Keccak256(data)[:4]
+	return hash.Keccak256(data)[:4]
 }
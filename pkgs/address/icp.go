@@ -3,7 +3,9 @@ package address
 import (
 	"crypto/sha256"
 	"encoding/binary"
+	"encoding/hex"
 	"fmt"
+	"hash/crc32"
 	"strings"
 )
 
@@ -27,10 +29,23 @@ func (i *ICPAddress) ChainID() ChainID {
 }
 
 // Generate creates an ICP Principal ID from a public key
-// Supports Ed25519 (32 bytes) or Secp256k1 (33 bytes compressed)
+// Supports Ed25519 (32 bytes) or Secp256k1 (33 bytes compressed, 65 bytes uncompressed)
+// Note: agent-js and dfx identify secp256k1 keys by their uncompressed form
 func (i *ICPAddress) Generate(publicKey []byte) (string, error) {
-	if len(publicKey) != 32 && len(publicKey) != 33 {
-		return "", fmt.Errorf("invalid public key length: expected 32 (Ed25519) or 33 (Secp256k1), got %d", len(publicKey))
+	principal, err := i.SelfAuthenticatingPrincipal(publicKey)
+	if err != nil {
+		return "", err
+	}
+
+	// Encode as textual representation
+	return i.encodePrincipal(principal), nil
+}
+
+// SelfAuthenticatingPrincipal returns the raw principal bytes for a public key:
+// SHA-224(DER-encoded public key) || 0x02
+func (i *ICPAddress) SelfAuthenticatingPrincipal(publicKey []byte) ([]byte, error) {
+	if len(publicKey) != 32 && len(publicKey) != 33 && len(publicKey) != 65 {
+		return nil, fmt.Errorf("invalid public key length: expected 32 (Ed25519), 33 or 65 (Secp256k1), got %d", len(publicKey))
 	}
 
 	// Create DER-encoded public key representation
@@ -51,8 +66,7 @@ func (i *ICPAddress) Generate(publicKey []byte) (string, error) {
 	copy(principalBytes, hash[:])
 	principalBytes[28] = ICPPrincipalSelfAuthenticating
 
-	// Encode as textual representation
-	return i.encodePrincipal(principalBytes), nil
+	return principalBytes, nil
 }
 
 // wrapEd25519PublicKey wraps an Ed25519 public key with DER encoding
@@ -69,13 +83,14 @@ func (i *ICPAddress) wrapEd25519PublicKey(publicKey []byte) []byte {
 
 // wrapSecp256k1PublicKey wraps a secp256k1 public key with DER encoding
 func (i *ICPAddress) wrapSecp256k1PublicKey(publicKey []byte) []byte {
-	// DER header for secp256k1 public key
+	// DER header for secp256k1 public key; lengths depend on the key encoding
+	// (65 bytes uncompressed: SEQUENCE 86 / BIT STRING 66, 33 bytes compressed: 54 / 34)
 	derHeader := []byte{
-		0x30, 0x56, // SEQUENCE, length 86
+		0x30, byte(21 + len(publicKey)), // SEQUENCE
 		0x30, 0x10, // SEQUENCE, length 16
 		0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, // OID 1.2.840.10045.2.1 (ecPublicKey)
 		0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a, // OID 1.3.132.0.10 (secp256k1)
-		0x03, 0x42, 0x00, // BIT STRING, length 66, no unused bits
+		0x03, byte(1 + len(publicKey)), 0x00, // BIT STRING, no unused bits
 	}
 	return append(derHeader, publicKey...)
 }
@@ -83,7 +98,7 @@ func (i *ICPAddress) wrapSecp256k1PublicKey(publicKey []byte) []byte {
 // encodePrincipal encodes principal bytes to textual representation
 func (i *ICPAddress) encodePrincipal(data []byte) string {
 	// Calculate CRC32 checksum
-	crc := i.crc32(data)
+	crc := i.checksum(data)
 
 	// Prepend checksum to data
 	withChecksum := make([]byte, 4+len(data))
@@ -97,21 +112,10 @@ func (i *ICPAddress) encodePrincipal(data []byte) string {
 	return i.groupWithDashes(encoded, 5)
 }
 
-// crc32 calculates CRC32 checksum for ICP
-func (i *ICPAddress) crc32(data []byte) uint32 {
-	// ICP uses CRC-32 with polynomial 0x04C11DB7 (ISO 3309)
-	var crc uint32 = 0xFFFFFFFF
-	for _, b := range data {
-		crc ^= uint32(b) << 24
-		for j := 0; j < 8; j++ {
-			if crc&0x80000000 != 0 {
-				crc = (crc << 1) ^ 0x04C11DB7
-			} else {
-				crc <<= 1
-			}
-		}
-	}
-	return crc
+// checksum calculates the CRC32 checksum for ICP
+// ICP uses the standard (reflected) CRC-32 from ISO 3309 / ITU-T V.42, as in zlib
+func (i *ICPAddress) checksum(data []byte) uint32 {
+	return crc32.ChecksumIEEE(data)
 }
 
 // base32Encode encodes data to base32 (lowercase, no padding)
@@ -177,7 +181,7 @@ func (i *ICPAddress) Validate(address string) bool {
 	data := decoded[4:]
 
 	// Verify checksum
-	expectedChecksum := i.crc32(data)
+	expectedChecksum := i.checksum(data)
 	return checksum == expectedChecksum
 }
 
@@ -261,3 +265,36 @@ func (i *ICPAddress) DecodeAddress(address string) (*AddressInfo, error) {
 		Type:      AddressTypeBase32,
 	}, nil
 }
+
+// ICPAccountIdentifier computes the ledger account identifier for a principal and subaccount:
+// CRC32(hash) || hash, where hash = SHA-224("\x0Aaccount-id" || principal || subaccount)
+// A nil subaccount selects the default (all-zero) subaccount.
+func ICPAccountIdentifier(principal, subaccount []byte) ([]byte, error) {
+	if subaccount == nil {
+		subaccount = make([]byte, 32)
+	}
+	if len(subaccount) != 32 {
+		return nil, fmt.Errorf("subaccount must be 32 bytes, got %d", len(subaccount))
+	}
+
+	h := sha256.New224()
+	h.Write([]byte("\x0Aaccount-id"))
+	h.Write(principal)
+	h.Write(subaccount)
+	hash := h.Sum(nil)
+
+	identifier := make([]byte, 4+len(hash))
+	binary.BigEndian.PutUint32(identifier, crc32.ChecksumIEEE(hash))
+	copy(identifier[4:], hash)
+
+	return identifier, nil
+}
+
+// ICPAccountIdentifierHex returns the account identifier as a 64-character hex string
+func ICPAccountIdentifierHex(principal, subaccount []byte) (string, error) {
+	identifier, err := ICPAccountIdentifier(principal, subaccount)
+	if err != nil {
+		return "", err
+	}
+	return hex.EncodeToString(identifier), nil
+}
@@ -1,3 +1,5 @@
+//go:build !ca_no_kaspa
+
 package address
 
 import (
@@ -5,6 +7,11 @@ import (
 	"strings"
 )
 
+// Build with -tags ca_no_kaspa to leave Kaspa out of the build.
+func init() {
+	registerChain(ChainKaspa, func() AddressGenerator { return NewKaspaAddress() })
+}
+
 // Kaspa address types
 const (
 	KaspaAddressTypeP2PK  = 0x00 // Pay to Public Key (ECDSA)
@@ -0,0 +1,48 @@
+//go:build !ca_no_kaspa
+
+package address
+
+import (
+	"encoding/hex"
+	"strings"
+	"testing"
+)
+
+// TestKaspaAddress tests Kaspa (KAS) address generation
+func TestKaspaAddress(t *testing.T) {
+	kaspa := NewKaspaAddress()
+
+	// Compressed public key (33 bytes)
+	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	// Generate address
+	addr, err := kaspa.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Kaspa addresses start with "kaspa1" (Bech32 format)
+	if !strings.HasPrefix(addr, "kaspa1") {
+		t.Errorf("Address should start with kaspa1, got %s", addr)
+	}
+
+	// Validate
+	if !kaspa.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+
+	// Test GetAddressType
+	addrType, err := kaspa.GetAddressType(addr)
+	if err != nil {
+		t.Fatalf("GetAddressType() error = %v", err)
+	}
+	if !strings.Contains(addrType, "P2PK") {
+		t.Errorf("GetAddressType() = %s, want P2PK type", addrType)
+	}
+
+	// Test invalid address
+	if kaspa.Validate("invalid") {
+		t.Error("Should reject invalid address")
+	}
+}
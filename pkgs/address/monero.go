@@ -2,8 +2,6 @@ package address
 
 import (
 	"fmt"
-
-	"golang.org/x/crypto/sha3"
 )
 
 // Monero network bytes
@@ -74,7 +72,7 @@ func (m *MoneroAddress) GenerateStandard(spendPubKey, viewPubKey []byte) (string
 	copy(payload[33:65], viewPubKey)
 
 	// Calculate Keccak-256 checksum (first 4 bytes)
-	checksum := keccak256(payload)[:4]
+	checksum := Keccak256(payload)[:4]
 
 	// Combine payload and checksum
 	full := append(payload, checksum...)
@@ -101,7 +99,7 @@ func (m *MoneroAddress) GenerateSubaddress(spendPubKey, viewPubKey []byte) (stri
 	copy(payload[1:33], spendPubKey)
 	copy(payload[33:65], viewPubKey)
 
-	checksum := keccak256(payload)[:4]
+	checksum := Keccak256(payload)[:4]
 	full := append(payload, checksum...)
 
 	return moneroBase58Encode(full), nil
@@ -146,7 +144,7 @@ func (m *MoneroAddress) Validate(address string) bool {
 	payloadLen := len(decoded) - 4
 	payload := decoded[:payloadLen]
 	checksum := decoded[payloadLen:]
-	expectedChecksum := keccak256(payload)[:4]
+	expectedChecksum := Keccak256(payload)[:4]
 
 	for i := 0; i < 4; i++ {
 		if checksum[i] != expectedChecksum[i] {
@@ -216,13 +214,6 @@ func (m *MoneroAddress) DecodeAddress(address string) (*AddressInfo, error) {
 	}, nil
 }
 
-// keccak256 computes Keccak-256 hash
-func keccak256(data []byte) []byte {
-	h := sha3.NewLegacyKeccak256()
-	h.Write(data)
-	return h.Sum(nil)
-}
-
 // Monero Base58 alphabet (same as Bitcoin but different encoding)
 const moneroBase58Alphabet = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
 
@@ -1,3 +1,5 @@
+//go:build !ca_no_near
+
 package address
 
 import (
@@ -7,6 +9,11 @@ import (
 	"strings"
 )
 
+// Build with -tags ca_no_near to leave NEAR out of the build.
+func init() {
+	registerChain(ChainNEAR, func() AddressGenerator { return NewNEARAddress() })
+}
+
 // NEARAddress generates NEAR Protocol addresses
 type NEARAddress struct{}
 
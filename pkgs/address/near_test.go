@@ -0,0 +1,43 @@
+//go:build !ca_no_near
+
+package address
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+func TestNEARAddress(t *testing.T) {
+	near := NewNEARAddress()
+
+	// 32-byte Ed25519 public key
+	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	addr, err := near.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// NEAR implicit addresses are 64 hex characters
+	if len(addr) != 64 {
+		t.Errorf("Address length = %d, want 64", len(addr))
+	}
+
+	if !near.ValidateImplicit(addr) {
+		t.Error("Implicit address validation failed")
+	}
+
+	// Test named address validation
+	if !near.ValidateNamed("alice.near") {
+		t.Error("Named address 'alice.near' should be valid")
+	}
+
+	if !near.ValidateNamed("bob.alice.near") {
+		t.Error("Named address 'bob.alice.near' should be valid")
+	}
+
+	if near.ValidateNamed("-invalid") {
+		t.Error("Named address '-invalid' should be invalid")
+	}
+}
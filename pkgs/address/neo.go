@@ -0,0 +1,151 @@
+package address
+
+import (
+	"encoding/hex"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+)
+
+// Neo N3 address constants
+const (
+	NeoAddressVersion byte = 0x35 // Results in addresses starting with 'N'
+	NeoScriptHashLen       = 20
+)
+
+// Neo VM opcodes and the System.Crypto.CheckSig interop hash used in
+// single-signature verification scripts
+const (
+	neoOpPushData1 byte = 0x0c
+	neoOpSyscall   byte = 0x41
+)
+
+var neoCheckSigHash = []byte{0x56, 0xe7, 0xb3, 0x27}
+
+// NeoAddress generates Neo N3 addresses
+// An address is Base58Check(0x35 || script hash), where the script hash is
+// Hash160 of the account's verification script
+type NeoAddress struct{}
+
+// NewNeoAddress creates a new Neo N3 address generator
+func NewNeoAddress() *NeoAddress {
+	return &NeoAddress{}
+}
+
+// ChainID returns the chain identifier
+func (n *NeoAddress) ChainID() ChainID {
+	return ChainNeo
+}
+
+// Generate creates a Neo N3 address from a P-256 public key
+// Public key should be 33 bytes (compressed) or 65 bytes (uncompressed)
+func (n *NeoAddress) Generate(publicKey []byte) (string, error) {
+	script, err := NeoVerificationScript(publicKey)
+	if err != nil {
+		return "", err
+	}
+	return NeoAddressFromScriptHash(NeoScriptHash(script))
+}
+
+// NeoVerificationScript builds the single-signature verification script:
+// PUSHDATA1 <33-byte compressed key> SYSCALL System.Crypto.CheckSig
+func NeoVerificationScript(publicKey []byte) ([]byte, error) {
+	switch len(publicKey) {
+	case 33:
+		if publicKey[0] != 0x02 && publicKey[0] != 0x03 {
+			return nil, ErrInvalidPublicKey
+		}
+	case 65:
+		compressed, err := p256.CompressPublicKey(publicKey)
+		if err != nil {
+			return nil, ErrInvalidPublicKey
+		}
+		publicKey = compressed
+	default:
+		return nil, ErrInvalidPublicKey
+	}
+
+	script := make([]byte, 0, 40)
+	script = append(script, neoOpPushData1, byte(len(publicKey)))
+	script = append(script, publicKey...)
+	script = append(script, neoOpSyscall)
+	script = append(script, neoCheckSigHash...)
+
+	return script, nil
+}
+
+// NeoScriptHash computes the script hash (Hash160) of a verification script
+// The bytes are in the order used inside addresses and transactions (little-endian)
+func NeoScriptHash(script []byte) []byte {
+	return Hash160(script)
+}
+
+// NeoAddressFromScriptHash encodes a 20-byte script hash as an address
+func NeoAddressFromScriptHash(scriptHash []byte) (string, error) {
+	if len(scriptHash) != NeoScriptHashLen {
+		return "", fmt.Errorf("%w: script hash must be %d bytes, got %d", ErrInvalidKeyLength, NeoScriptHashLen, len(scriptHash))
+	}
+	return Base58CheckEncode(NeoAddressVersion, scriptHash), nil
+}
+
+// NeoScriptHashFromAddress decodes an address into its 20-byte script hash
+func NeoScriptHashFromAddress(address string) ([]byte, error) {
+	version, payload, err := Base58CheckDecode(address)
+	if err != nil {
+		return nil, err
+	}
+	if version != NeoAddressVersion {
+		return nil, ErrInvalidVersion
+	}
+	if len(payload) != NeoScriptHashLen {
+		return nil, ErrInvalidAddress
+	}
+	return payload, nil
+}
+
+// FormatNeoScriptHash renders a script hash the way Neo tooling displays it:
+// 0x-prefixed big-endian hex (the reverse of the stored byte order)
+func FormatNeoScriptHash(scriptHash []byte) string {
+	reversed := make([]byte, len(scriptHash))
+	for i, b := range scriptHash {
+		reversed[len(scriptHash)-1-i] = b
+	}
+	return "0x" + hex.EncodeToString(reversed)
+}
+
+// ParseNeoScriptHash parses a 0x-prefixed big-endian script hash into stored byte order
+func ParseNeoScriptHash(s string) ([]byte, error) {
+	decoded, err := encoding.DecodeHexSize(s, NeoScriptHashLen)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidAddress, err)
+	}
+
+	scriptHash := make([]byte, NeoScriptHashLen)
+	for i, b := range decoded {
+		scriptHash[NeoScriptHashLen-1-i] = b
+	}
+	return scriptHash, nil
+}
+
+// Validate checks if an address is a valid Neo N3 address
+func (n *NeoAddress) Validate(address string) bool {
+	_, err := NeoScriptHashFromAddress(address)
+	return err == nil
+}
+
+// DecodeAddress decodes a Neo N3 address
+func (n *NeoAddress) DecodeAddress(address string) (*AddressInfo, error) {
+	scriptHash, err := NeoScriptHashFromAddress(address)
+	if err != nil {
+		return nil, err
+	}
+
+	return &AddressInfo{
+		Address:   address,
+		PublicKey: scriptHash, // Script hash, not public key
+		ChainID:   ChainNeo,
+		Type:      AddressTypeBase58Check,
+		Version:   NeoAddressVersion,
+	}, nil
+}
@@ -1,6 +1,7 @@
 package address
 
 import (
+	"bytes"
 	"encoding/hex"
 	"strings"
 	"testing"
@@ -73,123 +74,6 @@ func TestTezosAddressSecp256k1(t *testing.T) {
 	}
 }
 
-// TestZcashAddress tests Zcash (ZEC) transparent address generation
-func TestZcashAddress(t *testing.T) {
-	zcash := NewZcashAddress()
-
-	// Compressed public key (33 bytes)
-	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	// Generate t1 address
-	addr, err := zcash.P2PKH(pubKey)
-	if err != nil {
-		t.Fatalf("P2PKH() error = %v", err)
-	}
-
-	// Zcash transparent addresses start with 't'
-	if addr[0] != 't' {
-		t.Errorf("Address should start with t, got %c", addr[0])
-	}
-
-	// Validate
-	if !zcash.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-
-	// Test GetAddressType
-	addrType, err := zcash.GetAddressType(addr)
-	if err != nil {
-		t.Fatalf("GetAddressType() error = %v", err)
-	}
-	if addrType != "P2PKH (t1)" {
-		t.Errorf("GetAddressType() = %s, want P2PKH (t1)", addrType)
-	}
-
-	// Test invalid address
-	if zcash.Validate("invalid") {
-		t.Error("Should reject invalid address")
-	}
-}
-
-// TestKaspaAddress tests Kaspa (KAS) address generation
-func TestKaspaAddress(t *testing.T) {
-	kaspa := NewKaspaAddress()
-
-	// Compressed public key (33 bytes)
-	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	// Generate address
-	addr, err := kaspa.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Kaspa addresses start with "kaspa1" (Bech32 format)
-	if !strings.HasPrefix(addr, "kaspa1") {
-		t.Errorf("Address should start with kaspa1, got %s", addr)
-	}
-
-	// Validate
-	if !kaspa.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-
-	// Test GetAddressType
-	addrType, err := kaspa.GetAddressType(addr)
-	if err != nil {
-		t.Fatalf("GetAddressType() error = %v", err)
-	}
-	if !strings.Contains(addrType, "P2PK") {
-		t.Errorf("GetAddressType() = %s, want P2PK type", addrType)
-	}
-
-	// Test invalid address
-	if kaspa.Validate("invalid") {
-		t.Error("Should reject invalid address")
-	}
-}
-
-// TestStacksAddress tests Stacks (STX) address generation
-func TestStacksAddress(t *testing.T) {
-	stacks := NewStacksAddress()
-
-	// Compressed public key (33 bytes)
-	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	// Generate address
-	addr, err := stacks.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Stacks addresses start with "S"
-	if addr[0] != 'S' {
-		t.Errorf("Address should start with S, got %c", addr[0])
-	}
-
-	// Validate
-	if !stacks.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-
-	// Test GetAddressType
-	addrType, err := stacks.GetAddressType(addr)
-	if err != nil {
-		t.Fatalf("GetAddressType() error = %v", err)
-	}
-	if !strings.Contains(addrType, "Mainnet") {
-		t.Errorf("GetAddressType() = %s, want Mainnet type", addrType)
-	}
-
-	// Test invalid address
-	if stacks.Validate("invalid") {
-		t.Error("Should reject invalid address")
-	}
-}
-
 // TestFilecoinAddress tests Filecoin (FIL) address generation
 func TestFilecoinAddress(t *testing.T) {
 	filecoin := NewFilecoinAddress()
@@ -320,63 +204,20 @@ func TestICPAddress(t *testing.T) {
 	}
 }
 
-// TestEOSAddress tests EOS address/public key generation
-func TestEOSAddress(t *testing.T) {
-	eos := NewEOSAddress()
-
-	// Compressed public key (33 bytes)
-	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
-	pubKey, _ := hex.DecodeString(pubKeyHex)
-
-	// Generate EOS public key
-	addr, err := eos.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// EOS public keys start with "EOS"
-	if !strings.HasPrefix(addr, "EOS") {
-		t.Errorf("Address should start with EOS, got %s", addr[:3])
-	}
-
-	// Validate
-	if !eos.Validate(addr) {
-		t.Error("EOS public key validation failed")
-	}
-
-	// Test PUB_K1 format
-	pubK1Addr, err := eos.GeneratePubK1Key(pubKey)
-	if err != nil {
-		t.Fatalf("GeneratePubK1Key() error = %v", err)
-	}
-	if !strings.HasPrefix(pubK1Addr, "PUB_K1_") {
-		t.Errorf("Address should start with PUB_K1_, got %s", pubK1Addr[:7])
-	}
-	if !eos.Validate(pubK1Addr) {
-		t.Error("PUB_K1 key validation failed")
-	}
+// TestICPAnonymousPrincipal checks the textual encoding against the well-known anonymous principal
+func TestICPAnonymousPrincipal(t *testing.T) {
+	icp := NewICPAddress()
 
-	// Test account name validation
-	if !eos.ValidateAccountName("eosio") {
-		t.Error("Should accept valid account name 'eosio'")
-	}
-	if !eos.ValidateAccountName("myaccount123") {
-		t.Error("Should accept valid account name 'myaccount123'")
-	}
-	if eos.ValidateAccountName("InvalidName") {
-		t.Error("Should reject uppercase account name")
-	}
-	if eos.ValidateAccountName("toolongaccountname") {
-		t.Error("Should reject account name longer than 12 chars")
+	if got := icp.encodePrincipal([]byte{ICPPrincipalAnonymous}); got != "2vxsx-fae" {
+		t.Errorf("encodePrincipal(0x04) = %s, want 2vxsx-fae", got)
 	}
 
-	// Test GetAddressType
-	addrType, err := eos.GetAddressType(addr)
+	accountID, err := ICPAccountIdentifierHex([]byte{ICPPrincipalAnonymous}, nil)
 	if err != nil {
-		t.Fatalf("GetAddressType() error = %v", err)
+		t.Fatalf("ICPAccountIdentifierHex() error = %v", err)
 	}
-	if !strings.Contains(addrType, "Legacy") {
-		t.Errorf("GetAddressType() = %s, want Legacy type", addrType)
+	if accountID != "1c7a48ba6a562aa9eaa2481a9049cdf0433b9738c992d698c31d8abf89cadc79" {
+		t.Errorf("ICPAccountIdentifierHex() = %s", accountID)
 	}
 }
 
@@ -426,53 +267,6 @@ func TestFlowAddress(t *testing.T) {
 	}
 }
 
-// TestArweaveAddress tests Arweave (AR) address generation
-func TestArweaveAddress(t *testing.T) {
-	arweave := NewArweaveAddress()
-
-	// RSA public key modulus (256 bytes for 2048-bit RSA)
-	// Using a dummy 256-byte key for testing
-	pubKey := make([]byte, 256)
-	for i := range pubKey {
-		pubKey[i] = byte(i)
-	}
-
-	// Generate address
-	addr, err := arweave.Generate(pubKey)
-	if err != nil {
-		t.Fatalf("Generate() error = %v", err)
-	}
-
-	// Arweave addresses are 43 characters (Base64URL of SHA-256)
-	if len(addr) != 43 {
-		t.Errorf("Address length = %d, want 43", len(addr))
-	}
-
-	// Validate
-	if !arweave.Validate(addr) {
-		t.Error("Address validation failed")
-	}
-
-	// Test GetAddressType
-	addrType, err := arweave.GetAddressType(addr)
-	if err != nil {
-		t.Fatalf("GetAddressType() error = %v", err)
-	}
-	if !strings.Contains(addrType, "RSA") {
-		t.Errorf("GetAddressType() = %s, want RSA type", addrType)
-	}
-
-	// Test invalid address (wrong length)
-	if arweave.Validate("tooshort") {
-		t.Error("Should reject address with wrong length")
-	}
-
-	// Test invalid address
-	if arweave.Validate("invalid!@#$%^&*()") {
-		t.Error("Should reject invalid address with special chars")
-	}
-}
-
 // TestMoneroAddress tests Monero (XMR) address generation
 func TestMoneroAddress(t *testing.T) {
 	monero := NewMoneroAddress()
@@ -571,3 +365,123 @@ func TestNewChainsFactory(t *testing.T) {
 		}
 	}
 }
+
+// TestHarmonyAddress tests conversion between the one1 and 0x forms
+func TestHarmonyAddress(t *testing.T) {
+	evmAddr := "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+	oneAddr := "one1npvwllfr9dqr8erajqqr6s0vxnk2ak55x3u259"
+
+	got, err := HarmonyFromEVM(evmAddr)
+	if err != nil {
+		t.Fatalf("HarmonyFromEVM() error = %v", err)
+	}
+	if got != oneAddr {
+		t.Errorf("HarmonyFromEVM() = %s, want %s", got, oneAddr)
+	}
+
+	back, err := HarmonyToEVM(oneAddr)
+	if err != nil {
+		t.Fatalf("HarmonyToEVM() error = %v", err)
+	}
+	if back != evmAddr {
+		t.Errorf("HarmonyToEVM() = %s, want %s", back, evmAddr)
+	}
+
+	harmony := NewHarmonyAddress()
+	if !harmony.Validate(oneAddr) || !harmony.Validate(evmAddr) {
+		t.Error("Validate() rejected a valid address")
+	}
+	if harmony.Validate("cosmos1npvwllfr9dqr8erajqqr6s0vxnk2ak55x3u259") {
+		t.Error("Validate() accepted a non-Harmony Bech32 address")
+	}
+}
+
+// TestRoninAddress tests rendering and parsing of ronin: addresses
+func TestRoninAddress(t *testing.T) {
+	evmAddr := "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+
+	ronin, err := FormatRoninAddress(evmAddr)
+	if err != nil {
+		t.Fatalf("FormatRoninAddress() error = %v", err)
+	}
+	if ronin != "ronin:9858effd232b4033e47d90003d41ec34ecaeda94" {
+		t.Errorf("FormatRoninAddress() = %s", ronin)
+	}
+
+	for _, input := range []string{ronin, "RONIN:9858EFFD232B4033E47D90003D41EC34ECAEDA94", evmAddr} {
+		parsed, err := ParseRoninAddress(input)
+		if err != nil {
+			t.Errorf("ParseRoninAddress(%s) error = %v", input, err)
+			continue
+		}
+		if parsed != evmAddr {
+			t.Errorf("ParseRoninAddress(%s) = %s, want %s", input, parsed, evmAddr)
+		}
+	}
+
+	if NewRoninAddress().Validate("ronin:9858effd232b4033e47d90003d41ec34ecaeda") {
+		t.Error("Validate() accepted a short address")
+	}
+}
+
+func TestNeoAddress(t *testing.T) {
+	compressed, _ := hex.DecodeString("033a4d051b04b7fc0230d2b1aaedfd5a84be279a5361a7358db665ad7857787f1b")
+	uncompressed, _ := hex.DecodeString("043a4d051b04b7fc0230d2b1aaedfd5a84be279a5361a7358db665ad7857787f1b15ed7609f7613aa742fbd7813baeae4e50bfc9b15ba2a3e42d28290ac5a46319")
+	const expected = "NM7Aky765FG8NhhwtxjXRx7jEL1cnw7PBP"
+
+	gen := NewNeoAddress()
+	for _, key := range [][]byte{compressed, uncompressed} {
+		addr, err := gen.Generate(key)
+		if err != nil {
+			t.Fatalf("Generate() error = %v", err)
+		}
+		if addr != expected {
+			t.Errorf("Generate() = %s, want %s", addr, expected)
+		}
+	}
+
+	scriptHash, err := NeoScriptHashFromAddress(expected)
+	if err != nil {
+		t.Fatalf("NeoScriptHashFromAddress() error = %v", err)
+	}
+	display := FormatNeoScriptHash(scriptHash)
+	if display != "0x69ecca587293047be4c59159bf8bc399985c160d" {
+		t.Errorf("FormatNeoScriptHash() = %s", display)
+	}
+
+	parsed, err := ParseNeoScriptHash(display)
+	if err != nil {
+		t.Fatalf("ParseNeoScriptHash() error = %v", err)
+	}
+	if !bytes.Equal(parsed, scriptHash) {
+		t.Errorf("ParseNeoScriptHash() = %x, want %x", parsed, scriptHash)
+	}
+
+	if gen.Validate("1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH") {
+		t.Error("Validate() accepted a Bitcoin address")
+	}
+}
+
+func TestWavesAddress(t *testing.T) {
+	publicKey, _ := Base58Decode("4KxUVD9NtyRJjU3BCvPgJSttoJX7cb3DMdDTNucLN121")
+
+	mainnet := NewWavesAddress()
+	addr, err := mainnet.Generate(publicKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+	if addr != "3P9KR33QyXwfTXv8kKtNGZYtgKk3RXSUk36" {
+		t.Errorf("Generate() = %s", addr)
+	}
+	if !mainnet.Validate(addr) {
+		t.Errorf("Validate(%s) = false", addr)
+	}
+
+	testnet := NewWavesAddressWithChainID(WavesTestnetChainID)
+	if testnet.Validate(addr) {
+		t.Error("testnet generator accepted a mainnet address")
+	}
+	if mainnet.Validate("3P9KR33QyXwfTXv8kKtNGZYtgKk3RXSUk37") {
+		t.Error("Validate() accepted a bad checksum")
+	}
+}
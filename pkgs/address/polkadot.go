@@ -1,9 +1,16 @@
+//go:build !ca_no_polkadot
+
 package address
 
 import (
 	"fmt"
 )
 
+// Build with -tags ca_no_polkadot to leave Polkadot out of the build.
+func init() {
+	registerChain(ChainPolkadot, func() AddressGenerator { return NewPolkadotAddress() })
+}
+
 // SS58 network prefixes
 const (
 	SS58Polkadot  byte = 0  // Polkadot mainnet
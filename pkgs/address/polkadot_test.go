@@ -0,0 +1,30 @@
+//go:build !ca_no_polkadot
+
+package address
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+func TestPolkadotAddress(t *testing.T) {
+	dot := NewPolkadotAddress()
+
+	// 32-byte public key
+	pubKeyHex := "0000000000000000000000000000000000000000000000000000000000000001"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	addr, err := dot.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Polkadot addresses start with 1
+	if addr[0] != '1' {
+		t.Errorf("Address should start with 1, got %c", addr[0])
+	}
+
+	if !dot.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+}
@@ -1,6 +1,8 @@
 package address
 
 import (
+	"bytes"
+	"encoding/binary"
 	"fmt"
 )
 
@@ -100,3 +102,87 @@ func (r *RippleAddress) DecodeAddress(address string) (*AddressInfo, error) {
 		Version:   decoded[0],
 	}, nil
 }
+
+// X-address prefixes (XLS-5d)
+var (
+	xAddressMainnetPrefix = []byte{0x05, 0x44} // Addresses start with 'X'
+	xAddressTestnetPrefix = []byte{0x04, 0x93} // Addresses start with 'T'
+)
+
+// XAddressInfo holds the fields packed into an X-address
+type XAddressInfo struct {
+	ClassicAddress string
+	Tag            uint32
+	HasTag         bool
+	Testnet        bool
+}
+
+// EncodeXAddress packs a classic address and optional destination tag into an X-address
+// Payload: prefix (2) + account ID (20) + flag (1) + tag (4, little-endian) + reserved (4)
+func EncodeXAddress(classicAddress string, tag *uint32, testnet bool) (string, error) {
+	info, err := NewRippleAddress().DecodeAddress(classicAddress)
+	if err != nil {
+		return "", err
+	}
+
+	prefix := xAddressMainnetPrefix
+	if testnet {
+		prefix = xAddressTestnetPrefix
+	}
+
+	payload := make([]byte, 0, 31)
+	payload = append(payload, prefix...)
+	payload = append(payload, info.PublicKey...)
+
+	tagBytes := make([]byte, 8)
+	if tag != nil {
+		payload = append(payload, 0x01)
+		binary.LittleEndian.PutUint32(tagBytes, *tag)
+	} else {
+		payload = append(payload, 0x00)
+	}
+	payload = append(payload, tagBytes...)
+
+	checksum := DoubleSHA256(payload)[:4]
+	return rippleBase58.Encode(append(payload, checksum...)), nil
+}
+
+// DecodeXAddress unpacks an X-address into its classic address and destination tag
+func DecodeXAddress(xAddress string) (*XAddressInfo, error) {
+	decoded, err := rippleBase58.Decode(xAddress)
+	if err != nil || len(decoded) != 35 {
+		return nil, ErrInvalidAddress
+	}
+
+	payload := decoded[:31]
+	if !bytes.Equal(DoubleSHA256(payload)[:4], decoded[31:]) {
+		return nil, ErrInvalidChecksum
+	}
+
+	info := &XAddressInfo{}
+	switch {
+	case bytes.Equal(payload[:2], xAddressMainnetPrefix):
+	case bytes.Equal(payload[:2], xAddressTestnetPrefix):
+		info.Testnet = true
+	default:
+		return nil, ErrInvalidVersion
+	}
+
+	// Only 32-bit tags are defined; the reserved bytes must be zero
+	flag := payload[22]
+	if flag > 1 || !bytes.Equal(payload[27:31], []byte{0, 0, 0, 0}) {
+		return nil, ErrInvalidAddress
+	}
+	info.HasTag = flag == 1
+	info.Tag = binary.LittleEndian.Uint32(payload[23:27])
+	if !info.HasTag && info.Tag != 0 {
+		return nil, ErrInvalidAddress
+	}
+
+	classic := make([]byte, 21)
+	classic[0] = RippleAccountPrefix
+	copy(classic[1:], payload[2:22])
+	info.ClassicAddress = rippleBase58.Encode(append(classic, DoubleSHA256(classic)[:4]...))
+
+	return info, nil
+}
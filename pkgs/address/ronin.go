@@ -0,0 +1,79 @@
+package address
+
+import (
+	"strings"
+)
+
+// RoninPrefix is the prefix Ronin Wallet uses when displaying addresses
+const RoninPrefix = "ronin:"
+
+// RoninAddress generates Ronin addresses
+// Ronin is EVM-compatible; its wallet renders the 20-byte address as "ronin:" + lowercase hex
+type RoninAddress struct {
+	evm *EthereumAddress
+}
+
+// NewRoninAddress creates a new Ronin address generator
+func NewRoninAddress() *RoninAddress {
+	return &RoninAddress{evm: NewEVMAddress(ChainRonin)}
+}
+
+// ChainID returns the chain identifier
+func (r *RoninAddress) ChainID() ChainID {
+	return ChainRonin
+}
+
+// Generate creates a "ronin:..." address from a public key
+// Public key should be 64 or 65 bytes (uncompressed secp256k1)
+func (r *RoninAddress) Generate(publicKey []byte) (string, error) {
+	evmAddr, err := r.evm.Generate(publicKey)
+	if err != nil {
+		return "", err
+	}
+	return FormatRoninAddress(evmAddr)
+}
+
+// FormatRoninAddress renders a 0x address in the "ronin:" form
+func FormatRoninAddress(evmAddress string) (string, error) {
+	if !NewEVMAddress(ChainRonin).Validate(evmAddress) {
+		return "", ErrInvalidAddress
+	}
+	return RoninPrefix + strings.ToLower(evmAddress[2:]), nil
+}
+
+// ParseRoninAddress accepts an address in "ronin:" or 0x form and returns
+// the EIP-55 checksummed 0x form
+func ParseRoninAddress(address string) (string, error) {
+	hexPart := address
+	if strings.HasPrefix(strings.ToLower(address), RoninPrefix) {
+		hexPart = "0x" + address[len(RoninPrefix):]
+	}
+
+	info, err := NewEVMAddress(ChainRonin).DecodeAddress(hexPart)
+	if err != nil {
+		return "", err
+	}
+	return NewEVMAddress(ChainRonin).toChecksumAddress(info.PublicKey), nil
+}
+
+// Validate checks if an address is a valid Ronin address in either form
+func (r *RoninAddress) Validate(address string) bool {
+	_, err := ParseRoninAddress(address)
+	return err == nil
+}
+
+// DecodeAddress decodes a Ronin address in either form
+func (r *RoninAddress) DecodeAddress(address string) (*AddressInfo, error) {
+	evmAddr, err := ParseRoninAddress(address)
+	if err != nil {
+		return nil, ErrInvalidAddress
+	}
+
+	info, err := r.evm.DecodeAddress(evmAddr)
+	if err != nil {
+		return nil, err
+	}
+	info.Address = address
+
+	return info, nil
+}
@@ -1,11 +1,17 @@
+//go:build !ca_no_stacks
+
 package address
 
 import (
-	"crypto/sha256"
 	"fmt"
 	"strings"
 )
 
+// Build with -tags ca_no_stacks to leave Stacks out of the build.
+func init() {
+	registerChain(ChainStacks, func() AddressGenerator { return NewStacksAddress() })
+}
+
 // Stacks address version bytes
 const (
 	StacksMainnetSingleSig = 22  // P (mainnet single-sig)
@@ -123,9 +129,7 @@ func c32CheckEncode(version byte, data []byte) (string, error) {
 	payload[0] = version
 	copy(payload[1:], data)
 
-	hash1 := sha256.Sum256(payload)
-	hash2 := sha256.Sum256(hash1[:])
-	checksum := hash2[:4]
+	checksum := Checksum4(payload)
 
 	// Combine payload and checksum
 	full := append(payload, checksum...)
@@ -177,9 +181,7 @@ func c32CheckDecode(address string) (byte, []byte, error) {
 	payload[0] = version
 	copy(payload[1:], data)
 
-	hash1 := sha256.Sum256(payload)
-	hash2 := sha256.Sum256(hash1[:])
-	expectedChecksum := hash2[:4]
+	expectedChecksum := Checksum4(payload)
 
 	for i := 0; i < 4; i++ {
 		if checksum[i] != expectedChecksum[i] {
@@ -0,0 +1,48 @@
+//go:build !ca_no_stacks
+
+package address
+
+import (
+	"encoding/hex"
+	"strings"
+	"testing"
+)
+
+// TestStacksAddress tests Stacks (STX) address generation
+func TestStacksAddress(t *testing.T) {
+	stacks := NewStacksAddress()
+
+	// Compressed public key (33 bytes)
+	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	// Generate address
+	addr, err := stacks.Generate(pubKey)
+	if err != nil {
+		t.Fatalf("Generate() error = %v", err)
+	}
+
+	// Stacks addresses start with "S"
+	if addr[0] != 'S' {
+		t.Errorf("Address should start with S, got %c", addr[0])
+	}
+
+	// Validate
+	if !stacks.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+
+	// Test GetAddressType
+	addrType, err := stacks.GetAddressType(addr)
+	if err != nil {
+		t.Fatalf("GetAddressType() error = %v", err)
+	}
+	if !strings.Contains(addrType, "Mainnet") {
+		t.Errorf("GetAddressType() = %s, want Mainnet type", addrType)
+	}
+
+	// Test invalid address
+	if stacks.Validate("invalid") {
+		t.Error("Should reject invalid address")
+	}
+}
@@ -0,0 +1,125 @@
+package address
+
+import (
+	"encoding/hex"
+	"fmt"
+	"math/big"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/stark"
+)
+
+// StarknetOZAccountClassHash is the class hash of the OpenZeppelin account contract (v0.8.1)
+const StarknetOZAccountClassHash = "0x061dac032f228abef9c6626f995015233097ae253a7f72d68552db02f2971b8f"
+
+// starknetContractAddressPrefix is the felt encoding of "STARKNET_CONTRACT_ADDRESS"
+var starknetContractAddressPrefix = new(big.Int).SetBytes([]byte("STARKNET_CONTRACT_ADDRESS"))
+
+// starknetAddressBound is the upper bound for contract addresses: 2^251 - 256
+var starknetAddressBound = new(big.Int).Sub(new(big.Int).Lsh(big.NewInt(1), 251), big.NewInt(256))
+
+// StarknetAddress generates counterfactual Starknet account addresses
+// Starknet accounts are contracts; the address depends on the account class,
+// the deployment salt and the constructor calldata, not only on the public key.
+type StarknetAddress struct {
+	classHash *big.Int
+}
+
+// NewStarknetAddress creates a generator for OpenZeppelin account addresses
+func NewStarknetAddress() *StarknetAddress {
+	classHash, _ := ParseStarknetFelt(StarknetOZAccountClassHash)
+	return &StarknetAddress{classHash: classHash}
+}
+
+// NewStarknetAddressWithClassHash creates a generator for a custom account class
+// whose constructor takes the public key as its only argument
+func NewStarknetAddressWithClassHash(classHash *big.Int) *StarknetAddress {
+	return &StarknetAddress{classHash: classHash}
+}
+
+// ChainID returns the chain identifier
+func (s *StarknetAddress) ChainID() ChainID {
+	return ChainStarknet
+}
+
+// Generate creates the counterfactual account address for a Stark public key
+// Public key should be 32 bytes (big-endian x-coordinate); it is used as both
+// the deployment salt and the constructor calldata
+func (s *StarknetAddress) Generate(publicKey []byte) (string, error) {
+	if len(publicKey) != 32 {
+		return "", fmt.Errorf("invalid public key length: expected 32, got %d", len(publicKey))
+	}
+
+	pub := new(big.Int).SetBytes(publicKey)
+	addr, err := StarknetContractAddress(big.NewInt(0), pub, s.classHash, []*big.Int{pub})
+	if err != nil {
+		return "", err
+	}
+
+	return FormatStarknetFelt(addr), nil
+}
+
+// StarknetContractAddress computes the address of a contract deployed with the given
+// deployer, salt, class hash and constructor calldata:
+// pedersen("STARKNET_CONTRACT_ADDRESS", deployer, salt, class_hash, pedersen(calldata)) mod (2^251 - 256)
+func StarknetContractAddress(deployer, salt, classHash *big.Int, calldata []*big.Int) (*big.Int, error) {
+	calldataHash, err := stark.PedersenHashOnElements(calldata...)
+	if err != nil {
+		return nil, err
+	}
+
+	h, err := stark.PedersenHashOnElements(starknetContractAddressPrefix, deployer, salt, classHash, calldataHash)
+	if err != nil {
+		return nil, err
+	}
+
+	return h.Mod(h, starknetAddressBound), nil
+}
+
+// FormatStarknetFelt formats a field element as 0x-prefixed, 64-character hex
+func FormatStarknetFelt(n *big.Int) string {
+	return "0x" + hex.EncodeToString(stark.FeltBytes(n))
+}
+
+// ParseStarknetFelt parses a 0x-prefixed hex field element
+func ParseStarknetFelt(s string) (*big.Int, error) {
+	cleaned := strings.TrimPrefix(strings.ToLower(s), "0x")
+	if len(cleaned) == 0 || len(cleaned) > 64 {
+		return nil, ErrInvalidAddress
+	}
+
+	n, ok := new(big.Int).SetString(cleaned, 16)
+	if !ok || n.Cmp(stark.P) >= 0 {
+		return nil, ErrInvalidAddress
+	}
+	return n, nil
+}
+
+// Validate checks if a Starknet address is valid
+func (s *StarknetAddress) Validate(address string) bool {
+	if !strings.HasPrefix(address, "0x") {
+		return false
+	}
+
+	n, err := ParseStarknetFelt(address)
+	if err != nil {
+		return false
+	}
+	return n.Cmp(starknetAddressBound) < 0
+}
+
+// DecodeAddress decodes a Starknet address
+func (s *StarknetAddress) DecodeAddress(address string) (*AddressInfo, error) {
+	if !s.Validate(address) {
+		return nil, ErrInvalidAddress
+	}
+
+	n, _ := ParseStarknetFelt(address)
+
+	return &AddressInfo{
+		Address:   address,
+		PublicKey: stark.FeltBytes(n), // Contract address, not a key
+		ChainID:   ChainStarknet,
+		Type:      AddressTypeBase58, // Actually hex, but no specific type
+	}, nil
+}
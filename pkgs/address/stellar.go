@@ -2,6 +2,7 @@ package address
 
 import (
 	"encoding/base32"
+	"encoding/binary"
 	"fmt"
 )
 
@@ -36,22 +37,7 @@ func (s *StellarAddress) Generate(publicKey []byte) (string, error) {
 		return "", fmt.Errorf("Stellar requires 32-byte Ed25519 public key, got %d bytes", len(publicKey))
 	}
 
-	// Create payload: version byte + public key
-	payload := make([]byte, 33)
-	payload[0] = StellarAccountPrefix
-	copy(payload[1:], publicKey)
-
-	// Calculate CRC16-XModem checksum
-	checksum := crc16XModem(payload)
-
-	// Create final data: payload + checksum (little-endian)
-	final := make([]byte, 35)
-	copy(final, payload)
-	final[33] = byte(checksum & 0xFF)
-	final[34] = byte(checksum >> 8)
-
-	// Base32 encode
-	return stellarBase32.EncodeToString(final), nil
+	return stellarEncode(StellarAccountPrefix, publicKey), nil
 }
 
 // Validate checks if a Stellar address is valid
@@ -61,26 +47,8 @@ func (s *StellarAddress) Validate(address string) bool {
 		return false
 	}
 
-	decoded, err := stellarBase32.DecodeString(address)
-	if err != nil {
-		return false
-	}
-
-	if len(decoded) != 35 {
-		return false
-	}
-
-	// Verify version byte
-	if decoded[0] != StellarAccountPrefix {
-		return false
-	}
-
-	// Verify checksum
-	payload := decoded[:33]
-	expectedChecksum := crc16XModem(payload)
-	actualChecksum := uint16(decoded[33]) | uint16(decoded[34])<<8
-
-	return expectedChecksum == actualChecksum
+	_, err := stellarDecode(StellarAccountPrefix, address, 32)
+	return err == nil
 }
 
 // DecodeAddress decodes a Stellar address
@@ -100,6 +68,95 @@ func (s *StellarAddress) DecodeAddress(address string) (*AddressInfo, error) {
 	}, nil
 }
 
+// EncodeStellarSeed encodes a 32-byte Ed25519 seed as a secret StrKey ('S...')
+func EncodeStellarSeed(seed []byte) (string, error) {
+	if len(seed) != 32 {
+		return "", fmt.Errorf("%w: Stellar seed must be 32 bytes, got %d", ErrInvalidPrivateKey, len(seed))
+	}
+	return stellarEncode(StellarSeedPrefix, seed), nil
+}
+
+// DecodeStellarSeed decodes a secret StrKey ('S...') into the 32-byte Ed25519 seed
+func DecodeStellarSeed(secret string) ([]byte, error) {
+	if len(secret) != 56 || secret[0] != 'S' {
+		return nil, ErrInvalidAddress
+	}
+	return stellarDecode(StellarSeedPrefix, secret, 32)
+}
+
+// EncodeStellarMuxed encodes a muxed account address ('M...')
+// The payload is the Ed25519 public key followed by the 64-bit ID (big-endian)
+func EncodeStellarMuxed(publicKey []byte, id uint64) (string, error) {
+	if len(publicKey) != 32 {
+		return "", fmt.Errorf("%w: Stellar requires 32-byte Ed25519 public key, got %d bytes", ErrInvalidPublicKey, len(publicKey))
+	}
+
+	payload := make([]byte, 40)
+	copy(payload, publicKey)
+	binary.BigEndian.PutUint64(payload[32:], id)
+
+	return stellarEncode(StellarMuxedPrefix, payload), nil
+}
+
+// DecodeStellarMuxed decodes a muxed account address into its public key and ID
+func DecodeStellarMuxed(address string) ([]byte, uint64, error) {
+	if len(address) != 69 || address[0] != 'M' {
+		return nil, 0, ErrInvalidAddress
+	}
+
+	payload, err := stellarDecode(StellarMuxedPrefix, address, 40)
+	if err != nil {
+		return nil, 0, err
+	}
+
+	return payload[:32], binary.BigEndian.Uint64(payload[32:]), nil
+}
+
+// stellarEncode builds a StrKey: version byte + payload + CRC16 (little-endian)
+func stellarEncode(version byte, payload []byte) string {
+	data := make([]byte, 1+len(payload)+2)
+	data[0] = version
+	copy(data[1:], payload)
+
+	checksum := crc16XModem(data[:1+len(payload)])
+	data[len(data)-2] = byte(checksum & 0xFF)
+	data[len(data)-1] = byte(checksum >> 8)
+
+	return stellarBase32.EncodeToString(data)
+}
+
+// stellarDecode parses a StrKey and returns its payload after checking the
+// version byte, payload length and checksum. As SEP-23 requires, the input
+// must also be the canonical encoding of what it decodes to: base32 ignores
+// the unused low bits of the last character, so without the check a muxed
+// address would have two spellings.
+func stellarDecode(version byte, encoded string, payloadLen int) ([]byte, error) {
+	decoded, err := stellarBase32.DecodeString(encoded)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidAddress, err)
+	}
+	if stellarBase32.EncodeToString(decoded) != encoded {
+		return nil, fmt.Errorf("%w: non-canonical encoding", ErrInvalidAddress)
+	}
+
+	if len(decoded) != 1+payloadLen+2 {
+		return nil, ErrInvalidAddress
+	}
+
+	if decoded[0] != version {
+		return nil, ErrInvalidVersion
+	}
+
+	body := decoded[:1+payloadLen]
+	expectedChecksum := crc16XModem(body)
+	actualChecksum := uint16(decoded[1+payloadLen]) | uint16(decoded[2+payloadLen])<<8
+	if expectedChecksum != actualChecksum {
+		return nil, ErrInvalidChecksum
+	}
+
+	return decoded[1 : 1+payloadLen], nil
+}
+
 // crc16XModem calculates CRC16-XModem checksum
 func crc16XModem(data []byte) uint16 {
 	crc := uint16(0)
@@ -0,0 +1,18 @@
+// Command chains prints the chains in the address factory, one per line.
+// TestBuildTags runs it under each ca_no_* build tag.
+package main
+
+import (
+	"fmt"
+	"slices"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+func main() {
+	chains := address.DefaultFactory.ListSupportedChains()
+	slices.Sort(chains)
+	for _, chainID := range chains {
+		fmt.Println(chainID)
+	}
+}
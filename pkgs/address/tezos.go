@@ -1,9 +1,10 @@
 package address
 
 import (
+	"bytes"
 	"fmt"
 
-	"golang.org/x/crypto/blake2b"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
 )
 
 // Tezos address prefixes (Base58Check)
@@ -17,6 +18,10 @@ var (
 	TezosEd25519PKPrefix   = []byte{13, 15, 37, 217} // edpk
 	TezosSecp256k1PKPrefix = []byte{3, 254, 226, 86} // sppk
 	TezosP256PKPrefix      = []byte{3, 178, 139, 127} // p2pk
+
+	// Secret key prefixes
+	TezosEd25519SeedPrefix      = []byte{13, 15, 58, 7}  // edsk (32-byte seed, 54 chars)
+	TezosEd25519SecretKeyPrefix = []byte{43, 246, 78, 7} // edsk (64-byte seed || public key, 98 chars)
 )
 
 // TezosKeyType represents the cryptographic curve used
@@ -75,7 +80,7 @@ func (t *TezosAddress) Generate(publicKey []byte) (string, error) {
 	}
 
 	// Hash the public key with Blake2b-160
-	hash := blake2b160(publicKey)
+	hash := Blake2b160(publicKey)
 
 	// Encode with Base58Check using the appropriate prefix
 	return Base58CheckEncodeWithPrefix(prefix, hash), nil
@@ -86,7 +91,7 @@ func (t *TezosAddress) GenerateTz1(publicKey []byte) (string, error) {
 	if len(publicKey) != 32 {
 		return "", fmt.Errorf("Ed25519 public key must be 32 bytes")
 	}
-	hash := blake2b160(publicKey)
+	hash := Blake2b160(publicKey)
 	return Base58CheckEncodeWithPrefix(TezosEd25519PKHPrefix, hash), nil
 }
 
@@ -95,7 +100,7 @@ func (t *TezosAddress) GenerateTz2(publicKey []byte) (string, error) {
 	if len(publicKey) != 33 {
 		return "", fmt.Errorf("Secp256k1 public key must be 33 bytes (compressed)")
 	}
-	hash := blake2b160(publicKey)
+	hash := Blake2b160(publicKey)
 	return Base58CheckEncodeWithPrefix(TezosSecp256k1PKHPrefix, hash), nil
 }
 
@@ -104,7 +109,7 @@ func (t *TezosAddress) GenerateTz3(publicKey []byte) (string, error) {
 	if len(publicKey) != 33 {
 		return "", fmt.Errorf("P256 public key must be 33 bytes (compressed)")
 	}
-	hash := blake2b160(publicKey)
+	hash := Blake2b160(publicKey)
 	return Base58CheckEncodeWithPrefix(TezosP256PKHPrefix, hash), nil
 }
 
@@ -121,29 +126,9 @@ func (t *TezosAddress) Validate(address string) bool {
 		return false
 	}
 
-	// Decode Base58
-	decoded, err := Base58Decode(address)
-	if err != nil {
-		return false
-	}
-
-	// Should have 3-byte prefix + 20-byte hash + 4-byte checksum = 27 bytes
-	if len(decoded) != 27 {
-		return false
-	}
-
-	// Verify checksum
-	payload := decoded[:23]
-	checksum := decoded[23:]
-	expectedChecksum := DoubleSHA256(payload)[:4]
-
-	for i := 0; i < 4; i++ {
-		if checksum[i] != expectedChecksum[i] {
-			return false
-		}
-	}
-
-	return true
+	// 3-byte prefix + 20-byte hash, checksummed
+	_, payload, err := encoding.Base58CheckDecodeVersion(address, 3)
+	return err == nil && len(payload) == 20
 }
 
 // GetAddressType returns the type of Tezos address
@@ -188,31 +173,21 @@ func (t *TezosAddress) DecodeAddress(address string) (*AddressInfo, error) {
 	}, nil
 }
 
-// blake2b160 computes Blake2b-160 hash (20 bytes)
-func blake2b160(data []byte) []byte {
-	h, err := blake2b.New(20, nil)
-	if err != nil {
-		// Fallback should never happen
-		return nil
-	}
-	h.Write(data)
-	return h.Sum(nil)
-}
-
 // Base58CheckEncodeWithPrefix encodes data with a prefix using Base58Check
 func Base58CheckEncodeWithPrefix(prefix, data []byte) string {
-	// Combine prefix and data
-	payload := make([]byte, len(prefix)+len(data))
-	copy(payload, prefix)
-	copy(payload[len(prefix):], data)
+	return encoding.Base58CheckEncodeVersion(prefix, data)
+}
 
-	// Calculate checksum (double SHA256)
-	checksum := DoubleSHA256(payload)[:4]
+// Base58CheckDecodeWithPrefix decodes a Base58Check string and strips the expected prefix
+func Base58CheckDecodeWithPrefix(prefix []byte, str string) ([]byte, error) {
+	version, payload, err := encoding.Base58CheckDecodeVersion(str, len(prefix))
+	if err != nil {
+		return nil, base58CheckError(err)
+	}
 
-	// Combine payload and checksum
-	result := make([]byte, len(payload)+4)
-	copy(result, payload)
-	copy(result[len(payload):], checksum)
+	if !bytes.Equal(version, prefix) {
+		return nil, ErrInvalidVersion
+	}
 
-	return Base58Encode(result)
+	return payload, nil
 }
@@ -0,0 +1,231 @@
+package address
+
+import (
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+// Reasons reported by ValidateAddress, alongside ErrInvalidChecksum and ErrInvalidVersion
+var (
+	ErrInvalidEncoding = errors.New("invalid encoding")
+	ErrInvalidLength   = errors.New("invalid length")
+	ErrInvalidPrefix   = errors.New("invalid prefix")
+	ErrNetworkMismatch = errors.New("network mismatch")
+)
+
+// ValidationError explains why an address is not valid for a chain.
+// It matches both ErrInvalidAddress and its Reason with errors.Is.
+type ValidationError struct {
+	Chain   ChainID
+	Address string
+	Reason  error
+	Detail  string
+}
+
+// Error returns the error message.
+func (e *ValidationError) Error() string {
+	msg := fmt.Sprintf("invalid %s address %q", e.Chain, e.Address)
+	if e.Reason != nil {
+		msg += ": " + e.Reason.Error()
+	}
+	if e.Detail != "" {
+		msg += ": " + e.Detail
+	}
+	return msg
+}
+
+// Unwrap returns ErrInvalidAddress and the reason.
+func (e *ValidationError) Unwrap() []error {
+	if e.Reason == nil {
+		return []error{ErrInvalidAddress}
+	}
+	return []error{ErrInvalidAddress, e.Reason}
+}
+
+// ValidateAddress checks a user-supplied address for a chain and returns nil or
+// a *ValidationError describing the problem.
+//
+// Bitcoin (Base58Check, Bech32 and Bech32m with BIP-350 witness rules), 0x EVM
+// chains (hex with EIP-55 checksum when mixed case), Solana, Sui and Cosmos-SDK
+// chains (Bech32 with the chain's HRP) get detailed reasons. Other chains are
+// checked with their generator's Validate and report only ErrInvalidAddress.
+func ValidateAddress(chain ChainID, addr string) error {
+	gen, err := DefaultFactory.Get(chain)
+	if err != nil {
+		return err
+	}
+
+	var verr *ValidationError
+	switch g := gen.(type) {
+	case *BitcoinAddress:
+		verr = validateBitcoin(addr, g.testnet)
+	case *CosmosAddress:
+		verr = validateCosmos(addr, g.hrp)
+	case *SolanaAddress:
+		verr = validateSolana(addr)
+	case *EthereumAddress:
+		verr = validateEVM(addr)
+	case *SuiAddress:
+		verr = validateSui(addr)
+	default:
+		if !gen.Validate(addr) {
+			verr = &ValidationError{}
+		}
+	}
+
+	if verr == nil {
+		return nil
+	}
+	verr.Chain, verr.Address = chain, addr
+	return verr
+}
+
+// invalid returns a ValidationError with a reason; ValidateAddress fills in the rest.
+func invalid(reason error, detail string) *ValidationError {
+	return &ValidationError{Reason: reason, Detail: detail}
+}
+
+// validateBitcoin checks Base58Check (P2PKH, P2SH) and SegWit addresses.
+func validateBitcoin(addr string, testnet bool) *ValidationError {
+	hrp, wantHRP, otherHRP := "", "bc", "tb"
+	if testnet {
+		wantHRP, otherHRP = "tb", "bc"
+	}
+	if i := strings.LastIndexByte(addr, '1'); i > 0 {
+		hrp = strings.ToLower(addr[:i])
+	}
+	if hrp == wantHRP || hrp == otherHRP || hrp == "bcrt" {
+		return validateSegWit(addr, wantHRP)
+	}
+
+	version, payload, err := Base58CheckDecode(addr)
+	switch {
+	case errors.Is(err, ErrInvalidChecksum):
+		return invalid(ErrInvalidChecksum, "")
+	case err != nil:
+		return invalid(ErrInvalidEncoding, err.Error())
+	case len(payload) != 20:
+		return invalid(ErrInvalidLength, fmt.Sprintf("payload is %d bytes, want 20", len(payload)))
+	}
+
+	mainnet := version == BitcoinP2PKHVersion || version == BitcoinP2SHVersion
+	test := version == BitcoinTestnetP2PKHVersion || version == BitcoinTestnetP2SHVersion
+	switch {
+	case (mainnet && !testnet) || (test && testnet):
+		return nil
+	case mainnet || test:
+		return invalid(ErrNetworkMismatch, fmt.Sprintf("version byte 0x%02x", version))
+	default:
+		return invalid(ErrInvalidVersion, fmt.Sprintf("version byte 0x%02x", version))
+	}
+}
+
+// validateSegWit applies the BIP-173 and BIP-350 rules to a SegWit address.
+func validateSegWit(addr, wantHRP string) *ValidationError {
+	hrp, groups, encoding, err := bech32DecodeGroups(addr)
+	switch {
+	case errors.Is(err, ErrInvalidChecksum):
+		return invalid(ErrInvalidChecksum, "")
+	case err != nil:
+		return invalid(ErrInvalidEncoding, err.Error())
+	case hrp != wantHRP:
+		return invalid(ErrNetworkMismatch, fmt.Sprintf("hrp %q, want %q", hrp, wantHRP))
+	case len(groups) == 0:
+		return invalid(ErrInvalidLength, "missing witness version")
+	}
+
+	// The witness version is the first 5-bit group of the data part
+	version := groups[0]
+	if version > 16 {
+		return invalid(ErrInvalidVersion, fmt.Sprintf("witness version %d", version))
+	}
+	if version == 0 && encoding != Bech32Standard {
+		return invalid(ErrInvalidChecksum, "witness version 0 must use Bech32")
+	}
+	if version > 0 && encoding != Bech32m {
+		return invalid(ErrInvalidChecksum, fmt.Sprintf("witness version %d must use Bech32m", version))
+	}
+
+	program, err := convertBits(groups[1:], 5, 8, false)
+	if err != nil {
+		return invalid(ErrInvalidEncoding, err.Error())
+	}
+	if len(program) < 2 || len(program) > 40 {
+		return invalid(ErrInvalidLength, fmt.Sprintf("witness program is %d bytes", len(program)))
+	}
+	if version == 0 && len(program) != 20 && len(program) != 32 {
+		return invalid(ErrInvalidLength, fmt.Sprintf("witness v0 program is %d bytes, want 20 or 32", len(program)))
+	}
+	return nil
+}
+
+// validateEVM checks a 0x-prefixed 20-byte hex address. All-lowercase and
+// all-uppercase addresses carry no checksum; mixed case must match EIP-55.
+func validateEVM(addr string) *ValidationError {
+	if !strings.HasPrefix(addr, "0x") && !strings.HasPrefix(addr, "0X") {
+		return invalid(ErrInvalidPrefix, "missing 0x")
+	}
+	body := addr[2:]
+	if len(body) != 40 {
+		return invalid(ErrInvalidLength, fmt.Sprintf("%d hex digits, want 40", len(body)))
+	}
+	raw, err := hex.DecodeString(body)
+	if err != nil {
+		return invalid(ErrInvalidEncoding, "not hex")
+	}
+
+	if body == strings.ToLower(body) || body == strings.ToUpper(body) {
+		return nil
+	}
+	if want := NewEthereumAddress().toChecksumAddress(raw); addr[2:] != want[2:] {
+		return invalid(ErrInvalidChecksum, "EIP-55 mismatch, want " + want)
+	}
+	return nil
+}
+
+// validateCosmos checks a Bech32 account, validator operator or consensus
+// address with a 20- or 32-byte payload.
+func validateCosmos(addr, wantHRP string) *ValidationError {
+	hrp, data, encoding, err := Bech32Decode(addr)
+	switch {
+	case errors.Is(err, ErrInvalidChecksum):
+		return invalid(ErrInvalidChecksum, "")
+	case err != nil:
+		return invalid(ErrInvalidEncoding, err.Error())
+	case encoding != Bech32Standard:
+		return invalid(ErrInvalidChecksum, "Cosmos addresses use Bech32, not Bech32m")
+	case hrp != wantHRP && hrp != wantHRP+"valoper" && hrp != wantHRP+"valcons":
+		return invalid(ErrInvalidPrefix, fmt.Sprintf("hrp %q, want %q", hrp, wantHRP))
+	case len(data) != 20 && len(data) != 32:
+		return invalid(ErrInvalidLength, fmt.Sprintf("payload is %d bytes, want 20 or 32", len(data)))
+	}
+	return nil
+}
+
+// validateSolana checks a Base58 Ed25519 public key.
+func validateSolana(addr string) *ValidationError {
+	decoded, err := Base58Decode(addr)
+	if err != nil {
+		return invalid(ErrInvalidEncoding, err.Error())
+	}
+	if len(decoded) != 32 {
+		return invalid(ErrInvalidLength, fmt.Sprintf("%d bytes, want 32", len(decoded)))
+	}
+	return nil
+}
+
+// validateSui checks a 0x-prefixed 32-byte hex address.
+func validateSui(addr string) *ValidationError {
+	if !strings.HasPrefix(addr, "0x") {
+		return invalid(ErrInvalidPrefix, "missing 0x")
+	}
+	if len(addr)-2 != 64 {
+		return invalid(ErrInvalidLength, fmt.Sprintf("%d hex digits, want 64", len(addr)-2))
+	}
+	if _, err := hex.DecodeString(addr[2:]); err != nil {
+		return invalid(ErrInvalidEncoding, "not hex")
+	}
+	return nil
+}
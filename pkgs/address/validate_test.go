@@ -0,0 +1,153 @@
+package address
+
+import (
+	"encoding/json"
+	"errors"
+	"slices"
+	"strings"
+	"testing"
+)
+
+func TestValidateAddress(t *testing.T) {
+	tests := []struct {
+		name  string
+		chain ChainID
+		addr  string
+		want  error // nil for a valid address
+	}{
+		{"btc p2pkh", ChainBitcoin, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", nil},
+		{"btc p2wpkh", ChainBitcoin, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", nil},
+		{"btc p2wpkh upper", ChainBitcoin, "BC1QCR8TE4KR609GCAWUTMRZA0J4XV80JY8Z306FYU", nil},
+		{"btc p2tr", ChainBitcoin, "bc1pqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqqenm", nil},
+		{"btc p2tr with bech32", ChainBitcoin, "bc1pqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq5us4ke", ErrInvalidChecksum},
+		{"btc v0 bad length", ChainBitcoin, "bc1qqqqqqqqqqqqqqqqqqqqqqqqqqqv8de0q", ErrInvalidLength},
+		{"btc testnet segwit", ChainBitcoin, "tb1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnl25zw8", ErrNetworkMismatch},
+		{"btc testnet p2pkh", ChainBitcoin, "mfWyW5fc9NUj75YAnFgoRLrjxgLDn2MMth", ErrNetworkMismatch},
+		{"btc litecoin version", ChainBitcoin, "LKDyUEtTR1HXamkiEphisSiBJu6o3ZPE34", ErrInvalidVersion},
+		{"btc bad checksum", ChainBitcoin, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb", ErrInvalidChecksum},
+		{"btc short payload", ChainBitcoin, "11GsChQR2U32pvwJcDNPoYHhGcnz5Rv", ErrInvalidLength},
+		{"btc bad character", ChainBitcoin, "1A1zP1eP5QGefi2DMPTfTL5SLmv7Divf0a", ErrInvalidEncoding},
+
+		{"eth checksummed", ChainEthereum, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94", nil},
+		{"eth lowercase", ChainEthereum, "0x9858effd232b4033e47d90003d41ec34ecaeda94", nil},
+		{"eth bad checksum", ChainEthereum, "0x9858eFFD232B4033E47d90003D41EC34EcaEda94", ErrInvalidChecksum},
+		{"eth no prefix", ChainPolygon, "9858effd232b4033e47d90003d41ec34ecaeda94", ErrInvalidPrefix},
+		{"eth short", ChainEthereum, "0x9858effd232b4033e47d90003d41ec34ecaeda", ErrInvalidLength},
+		{"eth not hex", ChainEthereum, "0x9858effd232b4033e47d90003d41ec34ecaedazz", ErrInvalidEncoding},
+
+		{"atom", ChainCosmos, "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4", nil},
+		{"atom 32-byte", ChainCosmos, "cosmos1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq0fr2sh", nil},
+		{"atom wrong hrp", ChainCosmos, "osmo1qqqsyqcyq5rqwzqfpg9scrgwpugpzysntdz28t", ErrInvalidPrefix},
+		{"atom bech32m", ChainCosmos, "cosmos1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnk2pk5m", ErrInvalidChecksum},
+		{"atom mixed case", ChainCosmos, "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdaL4", ErrInvalidEncoding},
+
+		{"sol", ChainSolana, "11111111111111111111111111111111", nil},
+		{"sol short", ChainSolana, "1111", ErrInvalidLength},
+		{"sol bad character", ChainSolana, "0OIl", ErrInvalidEncoding},
+
+		{"sui", ChainSui, "0x0000000000000000000000000000000000000000000000000000000000000002", nil},
+		{"sui short", ChainSui, "0x2", ErrInvalidLength},
+		{"sui no prefix", ChainSui, "0000000000000000000000000000000000000000000000000000000000000002", ErrInvalidPrefix},
+
+		{"trx fallback", ChainTron, "not-an-address", ErrInvalidAddress},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if !IsSupported(tt.chain) {
+				t.Skipf("%s left out of the build", tt.chain)
+			}
+
+			err := ValidateAddress(tt.chain, tt.addr)
+			if tt.want == nil {
+				if err != nil {
+					t.Errorf("ValidateAddress() error = %v, want nil", err)
+				}
+				return
+			}
+
+			var verr *ValidationError
+			if !errors.As(err, &verr) {
+				t.Fatalf("ValidateAddress() error = %v, want *ValidationError", err)
+			}
+			if !errors.Is(err, tt.want) || !errors.Is(err, ErrInvalidAddress) {
+				t.Errorf("ValidateAddress() error = %v, want %v", err, tt.want)
+			}
+			if verr.Chain != tt.chain || verr.Address != tt.addr {
+				t.Errorf("ValidationError = %s %q", verr.Chain, verr.Address)
+			}
+		})
+	}
+
+	if err := ValidateAddress("nope", "x"); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("ValidateAddress(unknown) error = %v, want ErrUnsupportedChain", err)
+	}
+}
+
+func TestParseAnyAddress(t *testing.T) {
+	tests := []struct {
+		addr       string
+		chain      ChainID
+		typ        string
+		payloadLen int
+	}{
+		{"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", ChainBitcoin, "p2pkh", 20},
+		{"3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", ChainBitcoin, "p2sh", 20},
+		{" bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu\n", ChainBitcoin, "p2wpkh", 20},
+		{"bc1pqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqqenm", ChainBitcoin, "p2tr", 32},
+		{"LUWPbpM43E2p7ZSh8cyTBEkvpHmr3cB8Ez", ChainLitecoin, "p2pkh", 20},
+		{"ltc1qjmxnz78nmc8nq77wuxh25n2es7rzm5c2rkk4wh", ChainLitecoin, "p2wpkh", 20},
+		{"DBus3bamQjgJULBJtYXpEzDWQRwF5iwxgC", ChainDogecoin, "p2pkh", 20},
+		{"TMVQGm1qAQYVdetCeGRRkTWYYrLXuHK2HC", ChainTron, "tron", 20},
+		{"0x9858EfFD232B4033E47d90003D41EC34EcaEda94", ChainEthereum, "evm", 20},
+		{"0x0000000000000000000000000000000000000000000000000000000000000002", ChainSui, "hex32", 32},
+		{"So11111111111111111111111111111111111111112", ChainSolana, "base58", 32},
+		{"cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4", ChainCosmos, "bech32", 20},
+	}
+	for _, tt := range tests {
+		a, err := ParseAnyAddress(tt.addr)
+		if err != nil {
+			t.Errorf("ParseAnyAddress(%q) error = %v", tt.addr, err)
+			continue
+		}
+		if a.Chain != tt.chain || a.Chains[0] != tt.chain || a.Type != tt.typ || len(a.Payload) != tt.payloadLen {
+			t.Errorf("ParseAnyAddress(%q) = %s %v %s %x", tt.addr, a.Chain, a.Chains, a.Type, a.Payload)
+		}
+		if a.String() != strings.TrimSpace(tt.addr) {
+			t.Errorf("String() = %q", a.String())
+		}
+	}
+
+	evm, _ := ParseAnyAddress("0x9858EfFD232B4033E47d90003D41EC34EcaEda94")
+	if !slices.Contains(evm.Chains, ChainPolygon) {
+		t.Errorf("EVM Chains = %v, want Polygon among them", evm.Chains)
+	}
+
+	// Recognized formats report the chain's validation error
+	for _, tt := range []struct {
+		addr string
+		want error
+	}{
+		{"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb", ErrInvalidChecksum},
+		{"0x9858eFFD232B4033E47d90003D41EC34EcaEda94", ErrInvalidChecksum},
+		{"bc1pqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq5us4ke", ErrInvalidChecksum},
+		{"cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdaL4", ErrInvalidEncoding},
+	} {
+		_, err := ParseAnyAddress(tt.addr)
+		var verr *ValidationError
+		if !errors.Is(err, tt.want) || !errors.As(err, &verr) || verr.Chain == "" {
+			t.Errorf("ParseAnyAddress(%q) error = %v, want %v", tt.addr, err, tt.want)
+		}
+	}
+
+	for _, addr := range []string{"", "hello world", "0x1234", "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"} {
+		if _, err := ParseAnyAddress(addr); !errors.Is(err, ErrInvalidAddress) {
+			t.Errorf("ParseAnyAddress(%q) error = %v, want ErrInvalidAddress", addr, err)
+		}
+	}
+
+	var got struct{ To AnyAddress }
+	if err := json.Unmarshal([]byte(`{"To":"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"}`), &got); err != nil || got.To.Chain != ChainBitcoin {
+		t.Errorf("json.Unmarshal() = %+v, %v", got, err)
+	}
+}
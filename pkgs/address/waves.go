@@ -0,0 +1,91 @@
+package address
+
+import (
+	"bytes"
+	"fmt"
+)
+
+// Waves address constants
+const (
+	WavesAddressVersion  byte = 0x01
+	WavesMainnetChainID  byte = 'W' // Results in addresses starting with "3P"
+	WavesTestnetChainID  byte = 'T' // Results in addresses starting with "3M"
+	WavesStagenetChainID byte = 'S'
+	WavesAddressLength        = 26
+)
+
+// WavesAddress generates Waves addresses
+// Address = Base58(version || chainID || SecureHash(publicKey)[:20] || checksum)
+// where SecureHash is Keccak256(Blake2b256(data)) and the checksum is the first
+// 4 bytes of SecureHash over the preceding 22 bytes
+type WavesAddress struct {
+	chainID byte
+}
+
+// NewWavesAddress creates a new Waves address generator for mainnet
+func NewWavesAddress() *WavesAddress {
+	return &WavesAddress{chainID: WavesMainnetChainID}
+}
+
+// NewWavesAddressWithChainID creates a Waves address generator for the given network byte
+func NewWavesAddressWithChainID(chainID byte) *WavesAddress {
+	return &WavesAddress{chainID: chainID}
+}
+
+// ChainID returns the chain identifier
+func (w *WavesAddress) ChainID() ChainID {
+	return ChainWaves
+}
+
+// WavesSecureHash computes Keccak256(Blake2b256(data)), the hash Waves uses
+// for addresses and account seeds
+func WavesSecureHash(data []byte) []byte {
+	return Keccak256(Blake2b256(data))
+}
+
+// Generate creates a Waves address from a public key
+// Public key should be 32 bytes (Curve25519)
+func (w *WavesAddress) Generate(publicKey []byte) (string, error) {
+	if len(publicKey) != 32 {
+		return "", fmt.Errorf("Waves requires 32-byte Curve25519 public key, got %d bytes", len(publicKey))
+	}
+
+	data := make([]byte, 0, WavesAddressLength)
+	data = append(data, WavesAddressVersion, w.chainID)
+	data = append(data, WavesSecureHash(publicKey)[:20]...)
+	data = append(data, WavesSecureHash(data)[:4]...)
+
+	return Base58Encode(data), nil
+}
+
+// Validate checks if a Waves address is valid for this generator's network
+func (w *WavesAddress) Validate(address string) bool {
+	decoded, err := Base58Decode(address)
+	if err != nil || len(decoded) != WavesAddressLength {
+		return false
+	}
+
+	if decoded[0] != WavesAddressVersion || decoded[1] != w.chainID {
+		return false
+	}
+
+	checksum := WavesSecureHash(decoded[:22])[:4]
+	return bytes.Equal(decoded[22:], checksum)
+}
+
+// DecodeAddress decodes a Waves address
+func (w *WavesAddress) DecodeAddress(address string) (*AddressInfo, error) {
+	if !w.Validate(address) {
+		return nil, ErrInvalidAddress
+	}
+
+	decoded, _ := Base58Decode(address)
+
+	return &AddressInfo{
+		Address:   address,
+		PublicKey: decoded[2:22], // Public key hash, not public key
+		ChainID:   ChainWaves,
+		Type:      AddressTypeBase58,
+		Version:   decoded[0],
+	}, nil
+}
@@ -1,9 +1,16 @@
+//go:build !ca_no_zcash
+
 package address
 
 import (
 	"fmt"
 )
 
+// Build with -tags ca_no_zcash to leave Zcash out of the build.
+func init() {
+	registerChain(ChainZcash, func() AddressGenerator { return NewZcashAddress() })
+}
+
 // Zcash version bytes
 const (
 	// Mainnet transparent addresses
@@ -0,0 +1,47 @@
+//go:build !ca_no_zcash
+
+package address
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+// TestZcashAddress tests Zcash (ZEC) transparent address generation
+func TestZcashAddress(t *testing.T) {
+	zcash := NewZcashAddress()
+
+	// Compressed public key (33 bytes)
+	pubKeyHex := "0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"
+	pubKey, _ := hex.DecodeString(pubKeyHex)
+
+	// Generate t1 address
+	addr, err := zcash.P2PKH(pubKey)
+	if err != nil {
+		t.Fatalf("P2PKH() error = %v", err)
+	}
+
+	// Zcash transparent addresses start with 't'
+	if addr[0] != 't' {
+		t.Errorf("Address should start with t, got %c", addr[0])
+	}
+
+	// Validate
+	if !zcash.Validate(addr) {
+		t.Error("Address validation failed")
+	}
+
+	// Test GetAddressType
+	addrType, err := zcash.GetAddressType(addr)
+	if err != nil {
+		t.Fatalf("GetAddressType() error = %v", err)
+	}
+	if addrType != "P2PKH (t1)" {
+		t.Errorf("GetAddressType() = %s, want P2PKH (t1)", addrType)
+	}
+
+	// Test invalid address
+	if zcash.Validate("invalid") {
+		t.Error("Should reject invalid address")
+	}
+}
@@ -2,6 +2,9 @@ package bip32
 
 import (
 	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
 	"testing"
 )
 
@@ -256,3 +259,95 @@ func TestKeyInterface(t *testing.T) {
 		t.Error("Private key should be 32 bytes")
 	}
 }
+
+func TestWipe(t *testing.T) {
+	seed, _ := hex.DecodeString("000102030405060708090a0b0c0d0e0f")
+	master, _ := NewMasterKey(seed)
+
+	// Accessors return copies, so clearing one leaves the key intact
+	clear(master.PrivateKeyBytes())
+	clear(master.ChainCode())
+	if got := master.String(); got != "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi" {
+		t.Errorf("String() after clearing copies = %s", got)
+	}
+
+	master.Wipe()
+	if master.PrivateKeyBytes() != nil || master.ChainCode() != nil || master.PublicKeyBytes() != nil {
+		t.Error("key material still readable after Wipe")
+	}
+	if _, err := master.Child(0); !errors.Is(err, ErrDerivationFailed) {
+		t.Errorf("Child() after Wipe error = %v, want ErrDerivationFailed", err)
+	}
+	master.Wipe()
+}
+
+func TestGoStringRedactsKeyMaterial(t *testing.T) {
+	seed, _ := hex.DecodeString("000102030405060708090a0b0c0d0e0f")
+	master, _ := NewMasterKey(seed)
+
+	out := fmt.Sprintf("%#v", master)
+	for _, secret := range [][]byte{master.PrivateKeyBytes(), master.ChainCode()} {
+		if strings.Contains(out, hex.EncodeToString(secret)) {
+			t.Errorf("%%#v leaked key material: %s", out)
+		}
+	}
+}
+
+func TestFormatRedactsPrivateKey(t *testing.T) {
+	seed, _ := hex.DecodeString("000102030405060708090a0b0c0d0e0f")
+	master, _ := NewMasterKey(seed)
+	public, _ := master.Neuter()
+
+	for _, format := range []string{"%v", "%s", "%+v", "%q", "%x"} {
+		if out := fmt.Sprintf(format, master); out != "[REDACTED]" {
+			t.Errorf("Sprintf(%q, xprv) = %s, want [REDACTED]", format, out)
+		}
+	}
+	if out := fmt.Sprint(master); out != "[REDACTED]" {
+		t.Errorf("Sprint(xprv) = %s", out)
+	}
+	if out := fmt.Sprintf("%v", public); out != public.String() {
+		t.Errorf("Sprintf(%%v, xpub) = %s, want %s", out, public.String())
+	}
+}
+
+func TestTextMarshaling(t *testing.T) {
+	path := MustParsePath("m/44'/60'/0'/0/5")
+	text, err := path.MarshalText()
+	if err != nil || string(text) != "m/44'/60'/0'/0/5" {
+		t.Fatalf("DerivationPath.MarshalText() = %s, %v", text, err)
+	}
+
+	var parsedPath DerivationPath
+	if err := parsedPath.UnmarshalText(text); err != nil || parsedPath.String() != path.String() {
+		t.Errorf("DerivationPath.UnmarshalText() = %s, %v", parsedPath, err)
+	}
+
+	seed, _ := hex.DecodeString("000102030405060708090a0b0c0d0e0f")
+	master, _ := NewMasterKey(seed)
+
+	if _, err := master.MarshalText(); err != ErrPrivateKeyMarshal {
+		t.Errorf("private MarshalText() error = %v, want ErrPrivateKeyMarshal", err)
+	}
+
+	xprv, err := SecretExtendedKey{master}.MarshalText()
+	if err != nil || string(xprv) != master.String() {
+		t.Errorf("SecretExtendedKey.MarshalText() = %s, %v", xprv, err)
+	}
+
+	public, _ := master.Neuter()
+	xpub, err := public.(*ExtendedKey).MarshalText()
+	if err != nil || string(xpub) != public.String() {
+		t.Fatalf("public MarshalText() = %s, %v", xpub, err)
+	}
+
+	var parsed ExtendedKey
+	if err := parsed.UnmarshalText(xpub); err != nil || parsed.String() != public.String() {
+		t.Errorf("UnmarshalText() = %s, %v", parsed.String(), err)
+	}
+
+	var secret SecretExtendedKey
+	if err := secret.UnmarshalText(xprv); err != nil || secret.String() != master.String() {
+		t.Errorf("SecretExtendedKey.UnmarshalText() error = %v", err)
+	}
+}
@@ -5,6 +5,7 @@ import (
 
 	"github.com/study/crypto-accounts/pkgs/crypto/hash"
 	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
 )
 
 // Child derives a child extended key at the given index.
@@ -22,7 +23,9 @@ func (k *ExtendedKey) Child(index uint32) (Key, error) {
 	data := buildChildData(k, index, isHardened)
 
 	// HMAC-SHA512 with chain code as key
-	I := hash.HMACSHA512(k.chainCode, data)
+	var I []byte
+	k.chainCode.Use(func(chainCode []byte) { I = hash.HMACSHA512(chainCode, data) })
+	secret.Wipe(data)
 	IL := I[:32]
 	IR := I[32:]
 
@@ -32,13 +35,14 @@ func (k *ExtendedKey) Child(index uint32) (Key, error) {
 	}
 
 	childKey, err := deriveChildKey(k, IL)
+	secret.Wipe(IL)
 	if err != nil {
 		return nil, err
 	}
 
 	return &ExtendedKey{
-		key:        childKey,
-		chainCode:  IR,
+		key:        secret.Take(childKey),
+		chainCode:  secret.Take(IR),
 		depth:      k.depth + 1,
 		parentFP:   k.Fingerprint(),
 		childIndex: index,
@@ -53,7 +57,7 @@ func buildChildData(k *ExtendedKey, index uint32, isHardened bool) []byte {
 
 	if isHardened {
 		// Hardened: 0x00 || ser256(kpar) || ser32(i)
-		copy(data, k.key)
+		k.key.Use(func(key []byte) { copy(data, key) })
 	} else {
 		// Normal: serP(point(kpar)) || ser32(i)
 		copy(data, k.PublicKeyBytes())
@@ -63,18 +67,28 @@ func buildChildData(k *ExtendedKey, index uint32, isHardened bool) []byte {
 	return data
 }
 
-// deriveChildKey derives the child key bytes.
+// deriveChildKey derives the child key bytes. A wiped parent fails with
+// ErrDerivationFailed.
 func deriveChildKey(k *ExtendedKey, IL []byte) ([]byte, error) {
-	if k.isPrivate {
-		return derivePrivateChildKey(k.key[1:], IL)
-	}
-	return derivePublicChildKey(k.key, IL)
+	var childKey []byte
+	err := ErrDerivationFailed
+	k.key.Use(func(key []byte) {
+		switch {
+		case len(key) != 33:
+		case k.isPrivate:
+			childKey, err = derivePrivateChildKey(key[1:], IL)
+		default:
+			childKey, err = derivePublicChildKey(key, IL)
+		}
+	})
+	return childKey, err
 }
 
 // derivePrivateChildKey derives a private child key from a private parent.
 func derivePrivateChildKey(parentKey, IL []byte) ([]byte, error) {
 	// child key = (IL + kpar) mod n
 	childKeyBytes := secp256k1.AddPrivateKeys(parentKey, IL)
+	defer secret.Wipe(childKeyBytes)
 
 	if !secp256k1.IsValidPrivateKey(childKeyBytes) {
 		return nil, ErrDerivationFailed
@@ -113,8 +127,8 @@ func (k *ExtendedKey) Neuter() (Key, error) {
 	}
 
 	return &ExtendedKey{
-		key:        k.PublicKeyBytes(),
-		chainCode:  copyBytes(k.chainCode),
+		key:        secret.Copy(k.PublicKeyBytes()),
+		chainCode:  k.chainCode.Clone(),
 		depth:      k.depth,
 		parentFP:   copyBytes(k.parentFP),
 		childIndex: k.childIndex,
@@ -126,8 +140,8 @@ func (k *ExtendedKey) Neuter() (Key, error) {
 // clone creates a deep copy of the extended key.
 func (k *ExtendedKey) clone() *ExtendedKey {
 	return &ExtendedKey{
-		key:        copyBytes(k.key),
-		chainCode:  copyBytes(k.chainCode),
+		key:        k.key.Clone(),
+		chainCode:  k.chainCode.Clone(),
 		depth:      k.depth,
 		parentFP:   copyBytes(k.parentFP),
 		childIndex: k.childIndex,
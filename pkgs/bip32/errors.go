@@ -20,4 +20,7 @@ var (
 
 	// ErrInvalidSerializedKey indicates the serialized key data is malformed.
 	ErrInvalidSerializedKey = errors.New("bip32: invalid serialized key")
+
+	// ErrPrivateKeyMarshal indicates an attempt to marshal a private key without opting in.
+	ErrPrivateKeyMarshal = errors.New("bip32: refusing to marshal private key; wrap it in SecretExtendedKey")
 )
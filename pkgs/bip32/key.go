@@ -5,6 +5,7 @@ package bip32
 import (
 	"github.com/study/crypto-accounts/pkgs/crypto/hash"
 	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
 )
 
 // HardenedKeyStart is the index at which hardened child keys begin (2^31).
@@ -52,13 +53,13 @@ type Key interface {
 
 // ExtendedKey implements the Key interface.
 type ExtendedKey struct {
-	key         []byte   // 33 bytes: 0x00 + private key, or compressed public key
-	chainCode   []byte   // 32 bytes
-	depth       uint8    // 0 for master
-	parentFP    []byte   // 4 bytes
-	childIndex  uint32   // 0 for master
-	network     *Network // network configuration
-	isPrivate   bool
+	key        *secret.Bytes // 33 bytes: 0x00 + private key, or compressed public key
+	chainCode  *secret.Bytes // 32 bytes
+	depth      uint8         // 0 for master
+	parentFP   []byte        // 4 bytes
+	childIndex uint32        // 0 for master
+	network    *Network      // network configuration
+	isPrivate  bool
 }
 
 // Ensure ExtendedKey implements Key interface
@@ -88,13 +89,13 @@ func NewMasterKeyWithNetwork(seed []byte, network *Network) (*ExtendedKey, error
 	}
 
 	// Create extended key with 0x00 prefix for private key
-	key := make([]byte, 33)
-	key[0] = 0x00
-	copy(key[1:], IL)
+	key := secret.New(33)
+	key.Use(func(b []byte) { copy(b[1:], IL) })
+	secret.Wipe(IL)
 
 	return &ExtendedKey{
 		key:        key,
-		chainCode:  IR,
+		chainCode:  secret.Take(IR),
 		depth:      0,
 		parentFP:   []byte{0x00, 0x00, 0x00, 0x00},
 		childIndex: 0,
@@ -108,25 +109,45 @@ func (k *ExtendedKey) IsPrivate() bool {
 	return k.isPrivate
 }
 
-// PublicKeyBytes returns the 33-byte compressed public key.
+// PublicKeyBytes returns the 33-byte compressed public key, or nil once wiped.
 func (k *ExtendedKey) PublicKeyBytes() []byte {
 	if !k.isPrivate {
-		return k.key
+		return k.key.Bytes()
 	}
-	return secp256k1.PrivateKeyToCompressedPublicKey(k.key[1:])
+	var pub []byte
+	k.key.Use(func(key []byte) {
+		if len(key) == 33 {
+			pub = secp256k1.PrivateKeyToCompressedPublicKey(key[1:])
+		}
+	})
+	return pub
 }
 
-// PrivateKeyBytes returns the 32-byte private key, or nil if public.
+// PrivateKeyBytes returns a copy of the 32-byte private key, or nil if public
+// or wiped. The caller should wipe the copy once done.
 func (k *ExtendedKey) PrivateKeyBytes() []byte {
 	if !k.isPrivate {
 		return nil
 	}
-	return k.key[1:]
+	var priv []byte
+	k.key.Use(func(key []byte) {
+		if len(key) == 33 {
+			priv = copyBytes(key[1:])
+		}
+	})
+	return priv
 }
 
-// ChainCode returns the 32-byte chain code.
+// ChainCode returns a copy of the 32-byte chain code, or nil once wiped.
 func (k *ExtendedKey) ChainCode() []byte {
-	return k.chainCode
+	return k.chainCode.Bytes()
+}
+
+// Wipe destroys the key and chain code buffers. The key is unusable afterwards;
+// copies previously returned by PrivateKeyBytes and ChainCode are not touched.
+func (k *ExtendedKey) Wipe() {
+	k.key.Destroy()
+	k.chainCode.Destroy()
 }
 
 // Depth returns the derivation depth.
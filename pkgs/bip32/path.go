@@ -92,6 +92,21 @@ func (p DerivationPath) String() string {
 	return strings.Join(parts, "/")
 }
 
+// MarshalText implements encoding.TextMarshaler, encoding the path as "m/44'/0'/0'".
+func (p DerivationPath) MarshalText() ([]byte, error) {
+	return []byte(p.String()), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler.
+func (p *DerivationPath) UnmarshalText(text []byte) error {
+	parsed, err := ParsePath(string(text))
+	if err != nil {
+		return err
+	}
+	*p = parsed
+	return nil
+}
+
 // DeriveFromPath derives a child key following the given derivation path.
 func (k *ExtendedKey) DeriveFromPath(path DerivationPath) (*ExtendedKey, error) {
 	current := k
@@ -3,8 +3,12 @@ package bip32
 import (
 	"bytes"
 	"encoding/binary"
+	"fmt"
+	"io"
 
 	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
 )
 
 const (
@@ -37,19 +41,88 @@ func (k *ExtendedKey) Serialize() []byte {
 	buf.Write(indexBytes)
 
 	// 32 bytes: chain code
-	buf.Write(k.chainCode)
+	k.chainCode.Use(func(chainCode []byte) { buf.Write(chainCode) })
 
 	// 33 bytes: key data
-	buf.Write(k.key)
+	k.key.Use(func(key []byte) { buf.Write(key) })
 
 	return buf.Bytes()
 }
 
-// String returns the Base58Check encoded extended key.
+// String returns the Base58Check encoded extended key, the xprv of a private
+// key. fmt does not call it for private keys; see Format.
 func (k *ExtendedKey) String() string {
 	return encoding.Base58CheckEncode(k.Serialize())
 }
 
+// Format implements fmt.Formatter so that logging a private key with any verb
+// prints [REDACTED] instead of its xprv; %#v uses GoString. Public keys print
+// as their xpub.
+func (k *ExtendedKey) Format(f fmt.State, verb rune) {
+	switch {
+	case verb == 'v' && f.Flag('#'):
+		io.WriteString(f, k.GoString())
+	case k.isPrivate:
+		io.WriteString(f, secret.Redacted)
+	default:
+		io.WriteString(f, k.String())
+	}
+}
+
+// SecretExtendedKey opts a private extended key in to text and JSON marshaling.
+// A bare *ExtendedKey only marshals public keys, so secrets are never written by accident.
+type SecretExtendedKey struct {
+	*ExtendedKey
+}
+
+// MarshalText implements encoding.TextMarshaler, encoding the key as xprv/xpub.
+func (k SecretExtendedKey) MarshalText() ([]byte, error) {
+	if k.ExtendedKey == nil {
+		return nil, ErrInvalidKeyData
+	}
+	return []byte(k.ExtendedKey.String()), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler for xprv and xpub strings.
+func (k *SecretExtendedKey) UnmarshalText(text []byte) error {
+	parsed, err := ParseExtendedKey(string(text))
+	if err != nil {
+		return err
+	}
+	k.ExtendedKey = parsed
+	return nil
+}
+
+// MarshalText implements encoding.TextMarshaler for public keys (xpub).
+// Private keys return ErrPrivateKeyMarshal; use SecretExtendedKey or Neuter.
+func (k *ExtendedKey) MarshalText() ([]byte, error) {
+	if k.isPrivate {
+		return nil, ErrPrivateKeyMarshal
+	}
+	return []byte(k.String()), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler for xpub and xprv strings.
+func (k *ExtendedKey) UnmarshalText(text []byte) error {
+	parsed, err := ParseExtendedKey(string(text))
+	if err != nil {
+		return err
+	}
+	*k = *parsed
+	return nil
+}
+
+// GoString implements fmt.GoStringer so that %#v does not dump the raw key
+// material. Private keys and chain codes are redacted; public keys are shown.
+func (k *ExtendedKey) GoString() string {
+	keyData := secret.Redacted
+	if !k.isPrivate {
+		keyData = fmt.Sprintf("%x", k.PublicKeyBytes())
+	}
+	return fmt.Sprintf("&bip32.ExtendedKey{depth: %d, childIndex: %d, parentFP: %x, isPrivate: %t, chainCode: [REDACTED], key: %s}",
+		k.depth, k.childIndex, k.parentFP, k.isPrivate, keyData)
+}
+
 // getVersion returns the appropriate version bytes based on network and key type.
 func (k *ExtendedKey) getVersion() uint32 {
 	if k.isPrivate {
@@ -64,6 +137,7 @@ func ParseExtendedKey(encoded string) (*ExtendedKey, error) {
 	if err != nil {
 		return nil, err
 	}
+	defer secret.Wipe(decoded)
 	return DeserializeExtendedKey(decoded)
 }
 
@@ -87,9 +161,21 @@ func DeserializeExtendedKey(data []byte) (*ExtendedKey, error) {
 		network = DefaultNetwork
 	}
 
+	// Reject the malformed keys of BIP-32 test vector 5
+	if depth == 0 && (!bytes.Equal(parentFP, []byte{0, 0, 0, 0}) || childIndex != 0) {
+		return nil, fmt.Errorf("%w: master key with non-zero parent fingerprint or index", ErrInvalidSerializedKey)
+	}
+	if isPrivate {
+		if key[0] != 0x00 || !secp256k1.IsValidPrivateKey(key[1:]) {
+			return nil, fmt.Errorf("%w: private key out of range", ErrInvalidSerializedKey)
+		}
+	} else if _, err := secp256k1.DecompressPoint(key); err != nil {
+		return nil, fmt.Errorf("%w: invalid public key", ErrInvalidSerializedKey)
+	}
+
 	return &ExtendedKey{
-		key:        copyBytes(key),
-		chainCode:  copyBytes(chainCode),
+		key:        secret.Copy(key),
+		chainCode:  secret.Copy(chainCode),
 		depth:      depth,
 		parentFP:   copyBytes(parentFP),
 		childIndex: childIndex,
@@ -0,0 +1,127 @@
+package bip39
+
+import (
+	"crypto/sha256"
+	"fmt"
+	"slices"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// Correction is a valid mnemonic close to a mistyped one, as returned by
+// CorrectMnemonic.
+type Correction struct {
+	// Mnemonic is the corrected phrase.
+	Mnemonic string
+
+	// Distance is the number of letter edits from the typed phrase, summed
+	// over its words.
+	Distance int
+
+	// Positions are the 1-based positions of the words that changed.
+	Positions []int
+}
+
+// CorrectMnemonic returns every valid mnemonic within maxDistance letter
+// edits of a mistyped one, closest first. Edits are counted as in
+// SuggestWords and summed over the words, so with maxDistance 2 one word may
+// be two edits away or two words one edit each. maxDistance is clamped to 1
+// or 2. The word count must already be right; a valid mnemonic is returned
+// first with Distance 0.
+//
+// Each candidate must pass the checksum, which rules out all but about one
+// in 16 (12 words) to one in 256 (24 words) of the phrases tried.
+func CorrectMnemonic(mnemonic string, maxDistance int) ([]Correction, error) {
+	return CorrectMnemonicWithWordList(mnemonic, DefaultWordList, maxDistance)
+}
+
+// CorrectMnemonicWithWordList is CorrectMnemonic with a specific word list.
+func CorrectMnemonicWithWordList(mnemonic string, wordList WordList, maxDistance int) ([]Correction, error) {
+	maxDistance = min(max(maxDistance, 1), maxSuggestDistance)
+	words := strings.Fields(strings.ToLower(nfkd(mnemonic)))
+	if !isValidWordCount(len(words)) {
+		return nil, fmt.Errorf("%w: got %d", ErrInvalidMnemonicLength, len(words))
+	}
+
+	// near[i] lists the words within maxDistance of word i, in list order
+	type nearWord struct {
+		index, distance int
+	}
+	near := make([][]nearWord, len(words))
+	for i, word := range words {
+		typed := []rune(word)
+		for index := range wordList.Size() {
+			if d := editDistance(typed, []rune(nfkd(wordList.WordAt(index)))); d <= maxDistance {
+				near[i] = append(near[i], nearWord{index, d})
+			}
+		}
+	}
+
+	var corrections []Correction
+	indices := make([]int, len(words))
+	var changed []int
+	var search func(pos, budget int)
+	search = func(pos, budget int) {
+		if pos == len(words) {
+			if checksumValid(indices) {
+				phrase := make([]string, len(indices))
+				for i, index := range indices {
+					phrase[i] = wordList.WordAt(index)
+				}
+				corrections = append(corrections, Correction{
+					Mnemonic:  strings.Join(phrase, " "),
+					Distance:  maxDistance - budget,
+					Positions: slices.Clone(changed),
+				})
+			}
+			return
+		}
+		for _, n := range near[pos] {
+			if n.distance > budget {
+				continue
+			}
+			indices[pos] = n.index
+			if n.distance > 0 {
+				changed = append(changed, pos+1)
+			}
+			search(pos+1, budget-n.distance)
+			if n.distance > 0 {
+				changed = changed[:len(changed)-1]
+			}
+		}
+	}
+	search(0, maxDistance)
+
+	slices.SortStableFunc(corrections, func(a, b Correction) int {
+		return a.Distance - b.Distance
+	})
+	return corrections, nil
+}
+
+// checksumValid reports whether word indices encode entropy followed by its
+// checksum, the reverse of the packing NewMnemonicWithWordList does.
+func checksumValid(indices []int) bool {
+	totalBits := len(indices) * 11
+	checksumBits := len(indices) / 3
+	entropyBits := totalBits - checksumBits
+	bit := func(i int) bool {
+		return indices[i/11]&(1<<(10-i%11)) != 0
+	}
+
+	entropy := make([]byte, entropyBits/8)
+	defer secret.Wipe(entropy)
+	for i := range entropyBits {
+		if bit(i) {
+			entropy[i/8] |= 1 << (7 - i%8)
+		}
+	}
+
+	hash := sha256.Sum256(entropy)
+	for i := range checksumBits {
+		if bit(entropyBits+i) != (hash[0]&(1<<(7-i)) != 0) {
+			return false
+		}
+	}
+	return true
+}
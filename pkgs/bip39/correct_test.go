@@ -0,0 +1,85 @@
+package bip39
+
+import (
+	"errors"
+	"slices"
+	"testing"
+)
+
+func TestCorrectMnemonic(t *testing.T) {
+	const mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow"
+
+	tests := []struct {
+		name  string
+		typed string
+		want  []Correction
+	}{
+		{
+			"valid",
+			mnemonic,
+			[]Correction{{mnemonic, 0, nil}},
+		},
+		{
+			"extra letter",
+			"legal winner thank year wave sausage worth usefull legal winner thank yellow",
+			[]Correction{
+				{mnemonic, 1, []int{8}},
+				{"legal winner thank year wage sausage worth useful legal winner thank yellow", 2, []int{5, 8}},
+			},
+		},
+		{
+			"missing letter in the checksum word",
+			"Legal winner thank year wave sausage worth useful legal winner thank yelow",
+			[]Correction{
+				{mnemonic, 1, []int{12}},
+				{"legal winner thank wear wave sausage worth useful legal winner thank below", 2, []int{4, 12}},
+				{"legal winner thank year wage sausage worth useful legal winner thank yellow", 2, []int{5, 12}},
+				{"legal winner thank year wave sausage north useful legal winner thank below", 2, []int{7, 12}},
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got, err := CorrectMnemonic(tt.typed, 2)
+			if err != nil {
+				t.Fatalf("CorrectMnemonic() error = %v", err)
+			}
+			if len(got) != len(tt.want) {
+				t.Fatalf("CorrectMnemonic() = %+v, want %+v", got, tt.want)
+			}
+			for i := range got {
+				if got[i].Mnemonic != tt.want[i].Mnemonic || got[i].Distance != tt.want[i].Distance ||
+					!slices.Equal(got[i].Positions, tt.want[i].Positions) {
+					t.Errorf("CorrectMnemonic()[%d] = %+v, want %+v", i, got[i], tt.want[i])
+				}
+			}
+		})
+	}
+
+	// One edit only keeps the closest
+	got, err := CorrectMnemonic("legal winner thank year wave sausage worth usefull legal winner thank yellow", 1)
+	if err != nil || len(got) != 1 || got[0].Mnemonic != mnemonic {
+		t.Errorf("CorrectMnemonic(1 edit) = %+v, %v", got, err)
+	}
+
+	if _, err := CorrectMnemonic("legal winner thank", 2); !errors.Is(err, ErrInvalidMnemonicLength) {
+		t.Errorf("CorrectMnemonic(3 words) error = %v, want ErrInvalidMnemonicLength", err)
+	}
+}
+
+func TestChecksumValid(t *testing.T) {
+	for _, mnemonic := range []string{
+		"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+		"legal winner thank year wave sausage worth useful legal winner thank yellow",
+		"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+	} {
+		var indices []int
+		for _, word := range splitWords(mnemonic) {
+			indices = append(indices, English.WordIndex(word))
+		}
+		if got, want := checksumValid(indices), ValidateMnemonic(mnemonic); got != want {
+			t.Errorf("checksumValid(%q) = %v, want %v", mnemonic, got, want)
+		}
+	}
+}
@@ -0,0 +1,86 @@
+package bip39
+
+import (
+	"crypto/sha256"
+	"fmt"
+	"math"
+	"unicode"
+)
+
+// bitsPerDiceRoll is the entropy of one roll of a fair six-sided die.
+var bitsPerDiceRoll = math.Log2(6)
+
+// MinDiceRolls returns how many rolls of a fair die EntropyFromDice needs
+// for bits of entropy: 50 for 128 bits and 100 for 256.
+func MinDiceRolls(bits int) int {
+	return int(math.Ceil(float64(bits) / bitsPerDiceRoll))
+}
+
+// MinCoinFlips returns how many coin flips EntropyFromCoinFlips needs for
+// bits of entropy, one per bit.
+func MinCoinFlips(bits int) int {
+	return bits
+}
+
+// EntropyFromDice turns rolls of a six-sided die, written as the digits 1
+// to 6, into bits of entropy for NewMnemonic, for seeds generated offline
+// from physical randomness. Spaces, commas and dashes between rolls are
+// ignored. The entropy is the SHA-256 hash of the rolls as ASCII digits,
+// truncated to bits, which evens out a slightly biased die; the same rolls
+// give the same 24 words as a Coldcard. At least MinDiceRolls(bits) rolls
+// are required, and rolls that are all the same number return
+// ErrWeakEntropy.
+func EntropyFromDice(rolls string, bits int) ([]byte, error) {
+	return entropyFromRolls(rolls, bits, MinDiceRolls(bits), func(c rune) (byte, bool) {
+		if c >= '1' && c <= '6' {
+			return byte(c), true
+		}
+		return 0, false
+	})
+}
+
+// EntropyFromCoinFlips is EntropyFromDice for coin flips, written as H and
+// T or 1 and 0 in either case. At least MinCoinFlips(bits) flips are
+// required; they are hashed as the digits 1 for heads and 0 for tails.
+func EntropyFromCoinFlips(flips string, bits int) ([]byte, error) {
+	return entropyFromRolls(flips, bits, MinCoinFlips(bits), func(c rune) (byte, bool) {
+		switch c {
+		case 'H', 'h', '1':
+			return '1', true
+		case 'T', 't', '0':
+			return '0', true
+		default:
+			return 0, false
+		}
+	})
+}
+
+// entropyFromRolls normalizes rolls with digit, checks there are at least
+// need of them, and hashes them down to bits of entropy.
+func entropyFromRolls(rolls string, bits, need int, digit func(rune) (byte, bool)) ([]byte, error) {
+	if !isValidEntropyBits(bits) {
+		return nil, ErrInvalidEntropyLength
+	}
+
+	var normalized []byte
+	for _, c := range rolls {
+		if c == ',' || c == '-' || unicode.IsSpace(c) {
+			continue
+		}
+		d, ok := digit(c)
+		if !ok {
+			// The position only, so the rolls stay out of logs
+			return nil, fmt.Errorf("%w: roll %d", ErrInvalidRoll, len(normalized)+1)
+		}
+		normalized = append(normalized, d)
+	}
+	if len(normalized) < need {
+		return nil, fmt.Errorf("%w: got %d, need %d for %d bits", ErrNotEnoughRolls, len(normalized), need, bits)
+	}
+	if isRepeating(normalized, 1) {
+		return nil, ErrWeakEntropy
+	}
+
+	hash := sha256.Sum256(normalized)
+	return hash[:bits/8], nil
+}
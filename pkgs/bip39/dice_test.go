@@ -0,0 +1,84 @@
+package bip39
+
+import (
+	"bytes"
+	"encoding/hex"
+	"errors"
+	"strings"
+	"testing"
+)
+
+func TestEntropyFromDice(t *testing.T) {
+	rolls := strings.Repeat("123456", 8) + "1234" // 52 rolls
+
+	// SHA-256 of the rolls as ASCII digits, truncated to 128 bits
+	entropy, err := EntropyFromDice(rolls, 128)
+	if err != nil {
+		t.Fatalf("EntropyFromDice() error = %v", err)
+	}
+	if got := hex.EncodeToString(entropy); got != "09c8ff8e7c99300e2ec65d0bcc99105c" {
+		t.Errorf("EntropyFromDice() = %s", got)
+	}
+
+	// Separators are ignored
+	spaced, err := EntropyFromDice(strings.Join(strings.Split(rolls, ""), ", "), 128)
+	if err != nil || !bytes.Equal(spaced, entropy) {
+		t.Errorf("EntropyFromDice(spaced) = %x, %v, want %x", spaced, err, entropy)
+	}
+
+	tests := []struct {
+		name    string
+		rolls   string
+		bits    int
+		wantErr error
+	}{
+		{"too few rolls", rolls[:49], 128, ErrNotEnoughRolls},
+		{"too few for 256 bits", rolls, 256, ErrNotEnoughRolls},
+		{"zero", "0" + rolls, 128, ErrInvalidRoll},
+		{"seven", rolls + "7", 128, ErrInvalidRoll},
+		{"all sixes", strings.Repeat("6", 50), 128, ErrWeakEntropy},
+		{"invalid bits", rolls, 100, ErrInvalidEntropyLength},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := EntropyFromDice(tt.rolls, tt.bits); !errors.Is(err, tt.wantErr) {
+				t.Errorf("EntropyFromDice() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestEntropyFromCoinFlips(t *testing.T) {
+	flips := strings.Repeat("HTHHTTHT", 16)
+	entropy, err := EntropyFromCoinFlips(flips, 128)
+	if err != nil {
+		t.Fatalf("EntropyFromCoinFlips() error = %v", err)
+	}
+	if got := hex.EncodeToString(entropy); got != "cfd7bd40a5b4c2489418ee59a24f82f6" {
+		t.Errorf("EntropyFromCoinFlips() = %s", got)
+	}
+
+	// Lower case and binary digits are the same flips
+	binary := strings.NewReplacer("H", "1", "T", "0").Replace(flips)
+	for _, same := range []string{strings.ToLower(flips), binary} {
+		if got, _ := EntropyFromCoinFlips(same, 128); !bytes.Equal(got, entropy) {
+			t.Errorf("EntropyFromCoinFlips(%q) = %x, want %x", same, got, entropy)
+		}
+	}
+
+	if _, err := EntropyFromCoinFlips(flips[:127], 128); !errors.Is(err, ErrNotEnoughRolls) {
+		t.Errorf("EntropyFromCoinFlips(127 flips) error = %v, want ErrNotEnoughRolls", err)
+	}
+	if _, err := EntropyFromCoinFlips(flips+"X", 128); !errors.Is(err, ErrInvalidRoll) {
+		t.Errorf("EntropyFromCoinFlips(X) error = %v, want ErrInvalidRoll", err)
+	}
+}
+
+func TestMinDiceRolls(t *testing.T) {
+	want := map[int]int{128: 50, 160: 62, 192: 75, 224: 87, 256: 100}
+	for bits, rolls := range want {
+		if got := MinDiceRolls(bits); got != rolls {
+			t.Errorf("MinDiceRolls(%d) = %d, want %d", bits, got, rolls)
+		}
+	}
+}
@@ -0,0 +1,103 @@
+package bip39
+
+import (
+	"crypto/sha256"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// maxWeakPeriod is the longest repeating byte pattern CheckEntropy rejects.
+const maxWeakPeriod = 4
+
+// CheckEntropy reports whether user-supplied entropy is fit for a wallet.
+// It returns ErrInvalidEntropyLength unless entropy is 16, 20, 24, 28 or 32
+// bytes, and ErrWeakEntropy if it is all one byte (the all-zero entropy
+// gives the well-known "abandon ... about" wallet), a byte pattern of up to
+// four bytes repeated, or a run of bytes counting up or down by a constant
+// step. Entropy from GenerateEntropy passes with overwhelming probability.
+func CheckEntropy(entropy []byte) error {
+	if !isValidEntropyBits(len(entropy) * 8) {
+		return ErrInvalidEntropyLength
+	}
+	for period := 1; period <= maxWeakPeriod; period++ {
+		if isRepeating(entropy, period) {
+			return ErrWeakEntropy
+		}
+	}
+	if isProgression(entropy) {
+		return ErrWeakEntropy
+	}
+	return nil
+}
+
+// NewMnemonicFromUserEntropy is NewMnemonic for entropy that comes from a
+// user rather than GenerateEntropy: it rejects the inputs CheckEntropy
+// flags. Set force to skip the weakness checks, for example to reproduce a
+// published test vector; the length is always checked.
+func NewMnemonicFromUserEntropy(entropy []byte, force bool) (string, error) {
+	if !force {
+		if err := CheckEntropy(entropy); err != nil {
+			return "", err
+		}
+	}
+	return NewMnemonic(entropy)
+}
+
+// GenerateEntropyMixed is GenerateEntropy for key ceremonies that trust
+// neither the system RNG nor their own randomness alone: it mixes the system
+// entropy with userEntropy, such as dice rolls or the output of
+// EntropyFromDice, using MixEntropy. The result is unpredictable as long as
+// either source is.
+func GenerateEntropyMixed(userEntropy []byte, bits int) ([]byte, error) {
+	entropy, err := GenerateEntropy(bits)
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(entropy)
+	return MixEntropy(entropy, userEntropy)
+}
+
+// MixEntropy returns entropy XORed with the SHA-256 hash of userEntropy,
+// truncated to the length of entropy, which must be 16, 20, 24, 28 or 32
+// bytes. userEntropy may be of any non-zero length. GenerateEntropyMixed
+// uses it; call it directly to reproduce a ceremony whose system entropy was
+// recorded.
+func MixEntropy(entropy, userEntropy []byte) ([]byte, error) {
+	if !isValidEntropyBits(len(entropy) * 8) {
+		return nil, fmt.Errorf("%w: got %d bytes", ErrInvalidEntropyLength, len(entropy))
+	}
+	if len(userEntropy) == 0 {
+		return nil, fmt.Errorf("%w: no user entropy to mix in", ErrWeakEntropy)
+	}
+
+	hash := sha256.Sum256(userEntropy)
+	defer secret.Wipe(hash[:])
+	mixed := make([]byte, len(entropy))
+	for i := range mixed {
+		mixed[i] = entropy[i] ^ hash[i]
+	}
+	return mixed, nil
+}
+
+// isRepeating reports whether b is its first period bytes over and over.
+func isRepeating(b []byte, period int) bool {
+	for i := period; i < len(b); i++ {
+		if b[i] != b[i-period] {
+			return false
+		}
+	}
+	return true
+}
+
+// isProgression reports whether consecutive bytes of b differ by the same
+// step, modulo 256, as in 00 01 02 ... or ff fe fd ....
+func isProgression(b []byte) bool {
+	step := b[1] - b[0]
+	for i := 2; i < len(b); i++ {
+		if b[i]-b[i-1] != step {
+			return false
+		}
+	}
+	return true
+}
@@ -0,0 +1,99 @@
+package bip39
+
+import (
+	"encoding/hex"
+	"errors"
+	"testing"
+)
+
+func TestCheckEntropy(t *testing.T) {
+	tests := []struct {
+		name    string
+		entropy string // hex
+		wantErr error
+	}{
+		{"BIP-39 vector", "9e885d952ad362caeb4efe34a8e91bd2", nil},
+		{"BIP-39 vector 256-bit", "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c", nil},
+		{"all zero", "00000000000000000000000000000000", ErrWeakEntropy},
+		{"all ff", "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", ErrWeakEntropy},
+		{"repeated pair", "7f807f807f807f807f807f807f807f80", ErrWeakEntropy},
+		{"repeated word", "deadbeefdeadbeefdeadbeefdeadbeefdeadbeef", ErrWeakEntropy},
+		{"counting up", "000102030405060708090a0b0c0d0e0f", ErrWeakEntropy},
+		{"counting down", "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0", ErrWeakEntropy},
+		{"too short", "0102", ErrInvalidEntropyLength},
+		{"odd length", "9e885d952ad362caeb4efe34a8e91bd2aa", ErrInvalidEntropyLength},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			entropy, _ := hex.DecodeString(tt.entropy)
+			if err := CheckEntropy(entropy); !errors.Is(err, tt.wantErr) {
+				t.Errorf("CheckEntropy() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+
+	for _, bits := range ValidEntropyBits {
+		entropy, _ := GenerateEntropy(bits)
+		if err := CheckEntropy(entropy); err != nil {
+			t.Errorf("CheckEntropy(GenerateEntropy(%d)) error = %v", bits, err)
+		}
+	}
+}
+
+func TestNewMnemonicFromUserEntropy(t *testing.T) {
+	zero := make([]byte, 16)
+	if _, err := NewMnemonicFromUserEntropy(zero, false); !errors.Is(err, ErrWeakEntropy) {
+		t.Errorf("NewMnemonicFromUserEntropy(zero) error = %v, want ErrWeakEntropy", err)
+	}
+
+	mnemonic, err := NewMnemonicFromUserEntropy(zero, true)
+	if err != nil {
+		t.Fatalf("NewMnemonicFromUserEntropy(zero, force) error = %v", err)
+	}
+	if want := "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"; mnemonic != want {
+		t.Errorf("NewMnemonicFromUserEntropy(zero, force) = %q, want %q", mnemonic, want)
+	}
+
+	if _, err := NewMnemonicFromUserEntropy(zero[:15], true); !errors.Is(err, ErrInvalidEntropyLength) {
+		t.Errorf("force skipped the length check: error = %v", err)
+	}
+}
+
+func TestMixEntropy(t *testing.T) {
+	// SHA-256("abc") = ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad
+	mixed, err := MixEntropy(make([]byte, 16), []byte("abc"))
+	if err != nil {
+		t.Fatalf("MixEntropy() error = %v", err)
+	}
+	if got := hex.EncodeToString(mixed); got != "ba7816bf8f01cfea414140de5dae2223" {
+		t.Errorf("MixEntropy() = %s", got)
+	}
+
+	entropy, _ := hex.DecodeString("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+	mixed, _ = MixEntropy(entropy, []byte("abc"))
+	if got := hex.EncodeToString(mixed); got != "4587e94070fe3015bebebf21a251dddc4ffc9e5c69e885634bef009e0dffea52" {
+		t.Errorf("MixEntropy(32 bytes) = %s", got)
+	}
+
+	if _, err := MixEntropy(make([]byte, 15), []byte("abc")); !errors.Is(err, ErrInvalidEntropyLength) {
+		t.Errorf("MixEntropy(15 bytes) error = %v, want ErrInvalidEntropyLength", err)
+	}
+	if _, err := MixEntropy(make([]byte, 16), nil); !errors.Is(err, ErrWeakEntropy) {
+		t.Errorf("MixEntropy(no user entropy) error = %v, want ErrWeakEntropy", err)
+	}
+}
+
+func TestGenerateEntropyMixed(t *testing.T) {
+	a, err := GenerateEntropyMixed([]byte("3141592653"), 256)
+	if err != nil {
+		t.Fatalf("GenerateEntropyMixed() error = %v", err)
+	}
+	b, _ := GenerateEntropyMixed([]byte("3141592653"), 256)
+	if len(a) != 32 || hex.EncodeToString(a) == hex.EncodeToString(b) {
+		t.Errorf("GenerateEntropyMixed() = %x, %x", a, b)
+	}
+	if _, err := GenerateEntropyMixed([]byte("3141592653"), 100); !errors.Is(err, ErrInvalidEntropyLength) {
+		t.Errorf("GenerateEntropyMixed(100 bits) error = %v, want ErrInvalidEntropyLength", err)
+	}
+}
@@ -8,6 +8,18 @@ var (
 	// Valid lengths are 128, 160, 192, 224, or 256 bits (16, 20, 24, 28, or 32 bytes).
 	ErrInvalidEntropyLength = errors.New("invalid entropy length: must be 128, 160, 192, 224, or 256 bits")
 
+	// ErrWeakEntropy is returned by CheckEntropy when user-supplied entropy
+	// follows an obvious pattern, such as all zero bytes.
+	ErrWeakEntropy = errors.New("weak entropy: bytes follow an obvious pattern")
+
+	// ErrInvalidRoll is returned by EntropyFromDice and EntropyFromCoinFlips
+	// for a character that is not a die roll or coin flip.
+	ErrInvalidRoll = errors.New("invalid roll: dice must be 1 to 6, coin flips H or T")
+
+	// ErrNotEnoughRolls is returned when there are too few dice rolls or coin
+	// flips for the requested entropy.
+	ErrNotEnoughRolls = errors.New("not enough rolls for the requested entropy")
+
 	// ErrInvalidMnemonicLength is returned when mnemonic word count is not valid.
 	// Valid word counts are 12, 15, 18, 21, or 24.
 	ErrInvalidMnemonicLength = errors.New("invalid mnemonic length: must be 12, 15, 18, 21, or 24 words")
@@ -18,6 +30,26 @@ var (
 	// ErrInvalidChecksum is returned when mnemonic checksum verification fails.
 	ErrInvalidChecksum = errors.New("invalid mnemonic: checksum mismatch")
 
+	// ErrInvalidRecoveryPattern is returned by RecoverMissingWord when the
+	// pattern does not mark exactly one word with "?".
+	ErrInvalidRecoveryPattern = errors.New("invalid recovery pattern: mark exactly one word with ?")
+
+	// ErrInvalidShareCount is returned by SplitXOR and CombineXOR for fewer
+	// than two shares.
+	ErrInvalidShareCount = errors.New("invalid share count: XOR splitting needs at least 2 shares")
+
+	// ErrMismatchedShares is returned by CombineXOR for shares of different
+	// word counts.
+	ErrMismatchedShares = errors.New("shares have different word counts")
+
+	// ErrMnemonicDestroyed is returned by the methods of a Mnemonic after
+	// Destroy.
+	ErrMnemonicDestroyed = errors.New("mnemonic has been destroyed")
+
+	// ErrInvalidWordList is returned by NewWordList and ReadWordList for a
+	// list that is not 2048 distinct words.
+	ErrInvalidWordList = errors.New("invalid word list: must be 2048 distinct words")
+
 	// ErrWordNotFound is returned when a word is not in the word list.
 	ErrWordNotFound = errors.New("word not found in word list")
 )
@@ -0,0 +1,217 @@
+//go:build ignore
+
+// gen_nfkd generates nfkd_tables.go from the Unicode character database:
+// the full compatibility decomposition of every code point that has one,
+// and the ranges of nonzero canonical combining classes. It defaults to the
+// Unicode version of Go's unicode package, so nfkd agrees with the rest of
+// the standard library; rerun it with go generate after a Go upgrade.
+//
+//	go run gen_nfkd.go [-ucd UnicodeData.txt] [-version 15.0.0] [-output nfkd_tables.go]
+package main
+
+import (
+	"bufio"
+	"bytes"
+	"flag"
+	"fmt"
+	"go/format"
+	"io"
+	"log"
+	"maps"
+	"net/http"
+	"os"
+	"slices"
+	"strconv"
+	"strings"
+	"unicode"
+)
+
+// Hangul syllables decompose by arithmetic, as in nfkd.go.
+const (
+	hangulBase  = 0xac00
+	hangulCount = 11172
+	jamoLBase   = 0x1100
+	jamoVBase   = 0x1161
+	jamoTBase   = 0x11a7
+	jamoVCount  = 21
+	jamoTCount  = 28
+)
+
+func main() {
+	version := flag.String("version", unicode.Version, "Unicode version to download")
+	ucd := flag.String("ucd", "", "read UnicodeData.txt from this file instead of downloading it")
+	output := flag.String("output", "nfkd_tables.go", "file to write")
+	flag.Parse()
+	log.SetFlags(0)
+	log.SetPrefix("gen_nfkd: ")
+
+	source := fmt.Sprintf("https://www.unicode.org/Public/%s/ucd/UnicodeData.txt", *version)
+	var data []byte
+	var err error
+	if *ucd != "" {
+		data, err = os.ReadFile(*ucd)
+	} else {
+		data, err = download(source)
+	}
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	decompositions, classes, err := parseUnicodeData(data)
+	if err != nil {
+		log.Fatal(err)
+	}
+	src, err := format.Source(generate(*version, source, decompositions, classes))
+	if err != nil {
+		log.Fatal(err)
+	}
+	if err := os.WriteFile(*output, src, 0644); err != nil {
+		log.Fatal(err)
+	}
+}
+
+func download(url string) ([]byte, error) {
+	resp, err := http.Get(url)
+	if err != nil {
+		return nil, err
+	}
+	defer resp.Body.Close()
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("%s: %s", url, resp.Status)
+	}
+	return io.ReadAll(resp.Body)
+}
+
+// parseUnicodeData reads the decomposition mapping (field 5, with any
+// <compat> tag dropped) and the canonical combining class (field 3) of
+// every code point. The First/Last ranges have neither and are skipped.
+func parseUnicodeData(data []byte) (map[rune][]rune, map[rune]uint8, error) {
+	decompositions := make(map[rune][]rune)
+	classes := make(map[rune]uint8)
+
+	scanner := bufio.NewScanner(bytes.NewReader(data))
+	for line := 1; scanner.Scan(); line++ {
+		fields := strings.Split(scanner.Text(), ";")
+		if len(fields) != 15 {
+			return nil, nil, fmt.Errorf("line %d: %d fields, want 15", line, len(fields))
+		}
+		cp, err := strconv.ParseUint(fields[0], 16, 32)
+		if err != nil {
+			return nil, nil, fmt.Errorf("line %d: %v", line, err)
+		}
+		r := rune(cp)
+
+		class, err := strconv.ParseUint(fields[3], 10, 8)
+		if err != nil {
+			return nil, nil, fmt.Errorf("line %d: %v", line, err)
+		}
+		if class != 0 {
+			classes[r] = uint8(class)
+		}
+
+		for _, part := range strings.Fields(fields[5]) {
+			if strings.HasPrefix(part, "<") {
+				continue
+			}
+			c, err := strconv.ParseUint(part, 16, 32)
+			if err != nil {
+				return nil, nil, fmt.Errorf("line %d: %v", line, err)
+			}
+			decompositions[r] = append(decompositions[r], rune(c))
+		}
+	}
+	return decompositions, classes, scanner.Err()
+}
+
+// decompose appends the full decomposition of r to out.
+func decompose(out []rune, r rune, decompositions map[rune][]rune) []rune {
+	if index := r - hangulBase; index >= 0 && index < hangulCount {
+		out = append(out,
+			jamoLBase+index/(jamoVCount*jamoTCount),
+			jamoVBase+index%(jamoVCount*jamoTCount)/jamoTCount)
+		if t := index % jamoTCount; t != 0 {
+			out = append(out, jamoTBase+t)
+		}
+		return out
+	}
+	d, ok := decompositions[r]
+	if !ok {
+		return append(out, r)
+	}
+	for _, c := range d {
+		out = decompose(out, c, decompositions)
+	}
+	return out
+}
+
+// canonicalOrder sorts each run of combining marks by class, keeping marks of
+// the same class in order.
+func canonicalOrder(runes []rune, classes map[rune]uint8) {
+	for i := 0; i < len(runes); i++ {
+		j := i
+		for j < len(runes) && classes[runes[j]] != 0 {
+			j++
+		}
+		if j-i > 1 {
+			slices.SortStableFunc(runes[i:j], func(a, b rune) int {
+				return int(classes[a]) - int(classes[b])
+			})
+		}
+		i = j
+	}
+}
+
+func generate(version, source string, decompositions map[rune][]rune, classes map[rune]uint8) []byte {
+	var b bytes.Buffer
+	fmt.Fprintf(&b, "// Code generated by gen_nfkd.go from the Unicode %s character database. DO NOT EDIT.\n\n", version)
+	b.WriteString("package bip39\n\n")
+
+	b.WriteString("// nfkdDecompositions maps each code point that NFKD changes to its full\n")
+	b.WriteString("// decomposition, canonically ordered. Hangul syllables are decomposed\n")
+	b.WriteString("// algorithmically and are not listed.\n")
+	fmt.Fprintf(&b, "// The data is Unicode %s, the version of Go's unicode package.\n", version)
+	fmt.Fprintf(&b, "// Source: %s\n", source)
+	b.WriteString("var nfkdDecompositions = map[rune]string{\n")
+	for _, r := range slices.Sorted(maps.Keys(decompositions)) {
+		runes := decompose(nil, r, decompositions)
+		canonicalOrder(runes, classes)
+		fmt.Fprintf(&b, "\t0x%04x: %s,\n", r, quote(runes))
+	}
+	b.WriteString("}\n\n")
+
+	b.WriteString("// combiningClasses are the ranges of code points with a nonzero canonical\n")
+	b.WriteString("// combining class, in order.\n")
+	b.WriteString("var combiningClasses = []struct {\n\tlo, hi rune\n\tclass  uint8\n}{\n")
+	codePoints := slices.Sorted(maps.Keys(classes))
+	for i := 0; i < len(codePoints); {
+		lo, class := codePoints[i], classes[codePoints[i]]
+		j := i + 1
+		for j < len(codePoints) && codePoints[j] == codePoints[j-1]+1 && classes[codePoints[j]] == class {
+			j++
+		}
+		fmt.Fprintf(&b, "\t{0x%04x, 0x%04x, %d},\n", lo, codePoints[j-1], class)
+		i = j
+	}
+	b.WriteString("}\n")
+	return b.Bytes()
+}
+
+// quote writes runes as a Go string literal in plain ASCII: printable
+// characters as themselves and everything else, including the quote and
+// backslash, as a \u or \U escape.
+func quote(runes []rune) string {
+	var b strings.Builder
+	b.WriteByte('"')
+	for _, r := range runes {
+		switch {
+		case r >= 0x20 && r < 0x7f && r != '"' && r != '\\':
+			b.WriteRune(r)
+		case r <= 0xffff:
+			fmt.Fprintf(&b, `\u%04x`, r)
+		default:
+			fmt.Fprintf(&b, `\U%08x`, r)
+		}
+	}
+	b.WriteByte('"')
+	return b.String()
+}
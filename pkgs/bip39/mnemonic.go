@@ -3,6 +3,7 @@ package bip39
 import (
 	"crypto/rand"
 	"crypto/sha256"
+	"fmt"
 	"strings"
 )
 
@@ -33,7 +34,8 @@ func GenerateEntropy(bits int) ([]byte, error) {
 	return entropy, nil
 }
 
-// NewMnemonic generates a mnemonic phrase from entropy.
+// NewMnemonic generates a mnemonic phrase from entropy, which must be 16,
+// 20, 24, 28 or 32 bytes; other lengths return ErrInvalidEntropyLength.
 func NewMnemonic(entropy []byte) (string, error) {
 	return NewMnemonicWithWordList(entropy, DefaultWordList)
 }
@@ -42,7 +44,7 @@ func NewMnemonic(entropy []byte) (string, error) {
 func NewMnemonicWithWordList(entropy []byte, wordList WordList) (string, error) {
 	entropyBits := len(entropy) * 8
 	if !isValidEntropyBits(entropyBits) {
-		return "", ErrInvalidEntropyLength
+		return "", fmt.Errorf("%w: got %d bytes", ErrInvalidEntropyLength, len(entropy))
 	}
 
 	// Calculate checksum
@@ -87,12 +89,12 @@ func MnemonicToEntropy(mnemonic string) ([]byte, error) {
 
 // MnemonicToEntropyWithWordList converts a mnemonic phrase back to entropy using a specific word list.
 func MnemonicToEntropyWithWordList(mnemonic string, wordList WordList) ([]byte, error) {
-	words := strings.Fields(mnemonic)
+	words := strings.Fields(nfkd(mnemonic))
 	wordCount := len(words)
 
 	// Validate word count
 	if !isValidWordCount(wordCount) {
-		return nil, ErrInvalidMnemonicLength
+		return nil, fmt.Errorf("%w: got %d", ErrInvalidMnemonicLength, wordCount)
 	}
 
 	// Convert words to bit array
@@ -100,9 +102,10 @@ func MnemonicToEntropyWithWordList(mnemonic string, wordList WordList) ([]byte,
 	bits := make([]bool, totalBits)
 
 	for i, word := range words {
+		// Errors name the position only, so the word stays out of logs
 		index := wordList.WordIndex(word)
 		if index == -1 {
-			return nil, ErrInvalidMnemonic
+			return nil, fmt.Errorf("%w: word %d", ErrInvalidMnemonic, i+1)
 		}
 
 		for j := 0; j < 11; j++ {
@@ -141,8 +144,22 @@ func ValidateMnemonic(mnemonic string) bool {
 
 // ValidateMnemonicWithWordList checks if a mnemonic phrase is valid using a specific word list.
 func ValidateMnemonicWithWordList(mnemonic string, wordList WordList) bool {
+	return CheckMnemonicWithWordList(mnemonic, wordList) == nil
+}
+
+// CheckMnemonic is ValidateMnemonic for import flows that tell the user what
+// is wrong: it returns ErrInvalidMnemonicLength for a word count other than
+// 12, 15, 18, 21 or 24, ErrInvalidMnemonic naming the position of the first
+// unknown word, or ErrInvalidChecksum when the words are valid but the
+// checksum bits do not match the entropy.
+func CheckMnemonic(mnemonic string) error {
+	return CheckMnemonicWithWordList(mnemonic, DefaultWordList)
+}
+
+// CheckMnemonicWithWordList is CheckMnemonic with a specific word list.
+func CheckMnemonicWithWordList(mnemonic string, wordList WordList) error {
 	_, err := MnemonicToEntropyWithWordList(mnemonic, wordList)
-	return err == nil
+	return err
 }
 
 // isValidEntropyBits checks if entropy bit length is valid.
@@ -2,6 +2,7 @@ package bip39
 
 import (
 	"encoding/hex"
+	"errors"
 	"testing"
 )
 
@@ -84,6 +85,14 @@ func TestNewMnemonic(t *testing.T) {
 	}
 }
 
+func TestNewMnemonicInvalidLength(t *testing.T) {
+	for _, n := range []int{0, 15, 17, 31, 33, 64} {
+		if _, err := NewMnemonic(make([]byte, n)); !errors.Is(err, ErrInvalidEntropyLength) {
+			t.Errorf("NewMnemonic(%d bytes) error = %v, want %v", n, err, ErrInvalidEntropyLength)
+		}
+	}
+}
+
 func TestMnemonicToEntropy(t *testing.T) {
 	tests := []struct {
 		name     string
@@ -179,6 +188,52 @@ func TestValidateMnemonic(t *testing.T) {
 	}
 }
 
+func TestCheckMnemonic(t *testing.T) {
+	tests := []struct {
+		name     string
+		mnemonic string
+		wantErr  error
+	}{
+		{
+			name:     "valid",
+			mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+		},
+		{
+			name:     "invalid word count",
+			mnemonic: "abandon abandon abandon",
+			wantErr:  ErrInvalidMnemonicLength,
+		},
+		{
+			name:     "invalid word",
+			mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invalid",
+			wantErr:  ErrInvalidMnemonic,
+		},
+		{
+			name:     "invalid checksum",
+			mnemonic: "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+			wantErr:  ErrInvalidChecksum,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			err := CheckMnemonic(tt.mnemonic)
+			if !errors.Is(err, tt.wantErr) {
+				t.Fatalf("CheckMnemonic() error = %v, want %v", err, tt.wantErr)
+			}
+			if _, err := MnemonicToSeed(tt.mnemonic, ""); !errors.Is(err, tt.wantErr) {
+				t.Errorf("MnemonicToSeed() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+
+	// The error names the position of an unknown word, never the word
+	err := CheckMnemonic("abandon abandon secretword abandon abandon abandon abandon abandon abandon abandon abandon about")
+	if err == nil || err.Error() != "invalid mnemonic: contains unknown words: word 3" {
+		t.Errorf("CheckMnemonic() error = %v, want the word's position", err)
+	}
+}
+
 func TestMnemonicRoundTrip(t *testing.T) {
 	// Test round-trip for all valid entropy sizes
 	for _, bits := range ValidEntropyBits {
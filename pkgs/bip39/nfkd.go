@@ -0,0 +1,87 @@
+package bip39
+
+//go:generate go run gen_nfkd.go
+
+import (
+	"slices"
+	"sort"
+	"unicode/utf8"
+)
+
+// Hangul syllables decompose into two or three conjoining jamo by
+// arithmetic (Unicode section 3.12).
+const (
+	hangulBase  = 0xac00
+	hangulCount = 11172
+	jamoLBase   = 0x1100
+	jamoVBase   = 0x1161
+	jamoTBase   = 0x11a7
+	jamoVCount  = 21
+	jamoTCount  = 28
+)
+
+// nfkd returns s in Unicode Normalization Form KD, which BIP-39 requires of
+// mnemonics and passphrases before they are hashed. Compatibility characters
+// such as full-width letters and the ideographic space become their plain
+// forms, and precomposed letters such as é split into a base letter and
+// combining marks, so every way of typing a phrase gives the same seed.
+// Strings that are not valid UTF-8 are returned unchanged.
+func nfkd(s string) string {
+	if isASCII(s) || !utf8.ValidString(s) {
+		return s
+	}
+
+	runes := make([]rune, 0, len(s))
+	for _, r := range s {
+		if d, ok := nfkdDecompositions[r]; ok {
+			runes = append(runes, []rune(d)...)
+		} else if index := r - hangulBase; index >= 0 && index < hangulCount {
+			runes = append(runes,
+				jamoLBase+index/(jamoVCount*jamoTCount),
+				jamoVBase+index%(jamoVCount*jamoTCount)/jamoTCount)
+			if t := index % jamoTCount; t != 0 {
+				runes = append(runes, jamoTBase+t)
+			}
+		} else {
+			runes = append(runes, r)
+		}
+	}
+
+	// Canonical ordering: sort each run of combining marks by class, keeping
+	// marks of the same class in order
+	for i := 0; i < len(runes); i++ {
+		j := i
+		for j < len(runes) && combiningClass(runes[j]) != 0 {
+			j++
+		}
+		if j-i > 1 {
+			slices.SortStableFunc(runes[i:j], func(a, b rune) int {
+				return int(combiningClass(a)) - int(combiningClass(b))
+			})
+		}
+		i = j
+	}
+	return string(runes)
+}
+
+// combiningClass returns the canonical combining class of r, zero for
+// starters.
+func combiningClass(r rune) uint8 {
+	if r < 0x300 {
+		return 0
+	}
+	i := sort.Search(len(combiningClasses), func(i int) bool { return combiningClasses[i].hi >= r })
+	if i < len(combiningClasses) && combiningClasses[i].lo <= r {
+		return combiningClasses[i].class
+	}
+	return 0
+}
+
+func isASCII(s string) bool {
+	for i := range len(s) {
+		if s[i] >= utf8.RuneSelf {
+			return false
+		}
+	}
+	return true
+}
@@ -0,0 +1,57 @@
+package bip39
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+func TestNFKD(t *testing.T) {
+	tests := []struct {
+		name string
+		in   string
+		want string
+	}{
+		{"ASCII", "abandon about", "abandon about"},
+		{"precomposed", "caf\u00e9", "cafe\u0301"},
+		{"already decomposed", "cafe\u0301", "cafe\u0301"},
+		{"ligature", "\ufb01nal", "final"},
+		{"full-width", "\uff21\uff22\uff23", "ABC"},
+		{"ideographic space", "\u3042\u3000\u3044", "\u3042 \u3044"},
+		{"katakana voiced mark", "\u30ac", "\u30ab\u3099"},
+		{"hangul LV", "\uac00", "\u1100\u1161"},
+		{"hangul LVT", "\ud55c", "\u1112\u1161\u11ab"},
+		{"recursive", "\u1e69", "s\u0323\u0307"},
+		{"reordered marks", "a\u0301\u0323", "a\u0323\u0301"},
+		{"invalid UTF-8", "caf\xe9", "caf\xe9"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := nfkd(tt.in); got != tt.want {
+				t.Errorf("nfkd(%+q) = %+q, want %+q", tt.in, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestNewSeedNormalizes(t *testing.T) {
+	// The passphrase of the BIP-39 Japanese test vectors, with compatibility
+	// and precomposed characters: ㍍ガバ...
+	const mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+	const passphrase = "\u334d\u30ac\u30d0\u30f4\u30a1\u3071\u3070\u3050\u309e\u3061\u3062\u5341\u4eba\u5341\u8272"
+
+	seed := NewSeed(mnemonic, passphrase)
+	want := "ba553eedefe76e67e2602dc20184c564010859faada929a090dd2c57aacb204ceefd15404ab50ef3e8dbeae5195aeae64b0def4d2eead1cdc728a33ced520ffd"
+	if got := hex.EncodeToString(seed); got != want {
+		t.Errorf("NewSeed() = %s, want %s", got, want)
+	}
+	if got := hex.EncodeToString(NewSeed(mnemonic, nfkd(passphrase))); got != want {
+		t.Errorf("NewSeed(normalized) = %s, want %s", got, want)
+	}
+
+	// Full-width words name the same mnemonic
+	fullWidth := "\uff41\uff42\uff41\uff4e\uff44\uff4f\uff4e" + mnemonic[len("abandon"):]
+	if !ValidateMnemonic(fullWidth) {
+		t.Errorf("ValidateMnemonic(full-width) = false, want true")
+	}
+}
@@ -0,0 +1,141 @@
+package bip39
+
+import (
+	"bytes"
+	"fmt"
+	"io"
+	"iter"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// Mnemonic is a validated mnemonic phrase held in a secret.Bytes buffer.
+// Passing a *Mnemonic around instead of a string keeps the phrase in one
+// place that Destroy wipes, and that the garbage collector wipes if Destroy
+// is never called.
+//
+// The phrase is stored NFKD-normalized with single spaces between words.
+// String and every fmt verb print secret.Redacted; use Phrase to display it
+// to the user. A destroyed Mnemonic returns ErrMnemonicDestroyed.
+type Mnemonic struct {
+	phrase    *secret.Bytes
+	wordList  WordList
+	wordCount int
+}
+
+// ParseMnemonic checks a mnemonic with CheckMnemonic and returns it as a
+// Mnemonic. The phrase string itself cannot be wiped, so callers reading it
+// from a []byte should wipe that slice once ParseMnemonic returns.
+func ParseMnemonic(mnemonic string) (*Mnemonic, error) {
+	return ParseMnemonicWithWordList(mnemonic, DefaultWordList)
+}
+
+// ParseMnemonicWithWordList is ParseMnemonic with a specific word list.
+func ParseMnemonicWithWordList(mnemonic string, wordList WordList) (*Mnemonic, error) {
+	if err := CheckMnemonicWithWordList(mnemonic, wordList); err != nil {
+		return nil, err
+	}
+	words := strings.Fields(nfkd(mnemonic))
+	return &Mnemonic{
+		phrase:    secret.Take([]byte(strings.Join(words, " "))),
+		wordList:  wordList,
+		wordCount: len(words),
+	}, nil
+}
+
+// MnemonicFromEntropy is NewMnemonic returning a Mnemonic.
+func MnemonicFromEntropy(entropy []byte) (*Mnemonic, error) {
+	return MnemonicFromEntropyWithWordList(entropy, DefaultWordList)
+}
+
+// MnemonicFromEntropyWithWordList is MnemonicFromEntropy with a specific word list.
+func MnemonicFromEntropyWithWordList(entropy []byte, wordList WordList) (*Mnemonic, error) {
+	mnemonic, err := NewMnemonicWithWordList(entropy, wordList)
+	if err != nil {
+		return nil, err
+	}
+	return ParseMnemonicWithWordList(mnemonic, wordList)
+}
+
+// Phrase returns the mnemonic as a string of space-separated words, or ""
+// once destroyed. The string is a copy that Destroy cannot reach.
+func (m *Mnemonic) Phrase() string {
+	var phrase string
+	m.phrase.Use(func(b []byte) { phrase = string(b) })
+	return phrase
+}
+
+// Words returns an iterator over the words of the mnemonic, in order. It
+// yields nothing once the mnemonic is destroyed.
+func (m *Mnemonic) Words() iter.Seq[string] {
+	return func(yield func(string) bool) {
+		var words []string
+		m.phrase.Use(func(b []byte) {
+			if b == nil {
+				return
+			}
+			for _, word := range bytes.Split(b, []byte{' '}) {
+				words = append(words, string(word))
+			}
+		})
+		for _, word := range words {
+			if !yield(word) {
+				return
+			}
+		}
+	}
+}
+
+// WordCount returns the number of words: 12, 15, 18, 21 or 24.
+func (m *Mnemonic) WordCount() int {
+	return m.wordCount
+}
+
+// WordList returns the word list, and so the language, of the mnemonic.
+func (m *Mnemonic) WordList() WordList {
+	return m.wordList
+}
+
+// Entropy returns the entropy the mnemonic encodes. The caller owns the
+// returned slice and should wipe it when done.
+func (m *Mnemonic) Entropy() ([]byte, error) {
+	var entropy []byte
+	err := ErrMnemonicDestroyed
+	m.phrase.Use(func(b []byte) {
+		if b != nil {
+			entropy, err = MnemonicToEntropyWithWordList(string(b), m.wordList)
+		}
+	})
+	return entropy, err
+}
+
+// Seed derives the BIP-39 seed of the mnemonic with a passphrase, as
+// NewSeed does.
+func (m *Mnemonic) Seed(passphrase string) (*Seed, error) {
+	var seed []byte
+	m.phrase.Use(func(b []byte) {
+		if b != nil {
+			seed = newSeed(b, passphrase)
+		}
+	})
+	if seed == nil {
+		return nil, ErrMnemonicDestroyed
+	}
+	return &Seed{buf: secret.Take(seed)}, nil
+}
+
+// Destroy wipes the phrase. It is safe to call more than once.
+func (m *Mnemonic) Destroy() {
+	m.phrase.Destroy()
+}
+
+// String returns secret.Redacted.
+func (m *Mnemonic) String() string {
+	return secret.Redacted
+}
+
+// Format implements fmt.Formatter so that no verb prints the phrase.
+func (m *Mnemonic) Format(f fmt.State, verb rune) {
+	io.WriteString(f, secret.Redacted)
+}
@@ -0,0 +1,81 @@
+package bip39
+
+import (
+	"bytes"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"slices"
+	"strings"
+	"testing"
+)
+
+func TestParseMnemonic(t *testing.T) {
+	const phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+	m, err := ParseMnemonic("  abandon abandon abandon abandon abandon abandon\tabandon abandon abandon abandon abandon about\n")
+	if err != nil {
+		t.Fatalf("ParseMnemonic() error = %v", err)
+	}
+	if got := m.Phrase(); got != phrase {
+		t.Errorf("Phrase() = %q, want %q", got, phrase)
+	}
+	if got := slices.Collect(m.Words()); !slices.Equal(got, strings.Fields(phrase)) {
+		t.Errorf("Words() = %v", got)
+	}
+	if m.WordCount() != 12 || m.WordList() != English {
+		t.Errorf("WordCount() = %d, WordList() = %v", m.WordCount(), m.WordList())
+	}
+
+	entropy, err := m.Entropy()
+	if err != nil || !bytes.Equal(entropy, make([]byte, 16)) {
+		t.Errorf("Entropy() = %x, %v", entropy, err)
+	}
+	seed, err := m.Seed("TREZOR")
+	if err != nil {
+		t.Fatalf("Seed() error = %v", err)
+	}
+	seed.Use(func(b []byte) {
+		if !bytes.Equal(b, NewSeed(phrase, "TREZOR")) {
+			t.Errorf("Seed() = %x", b)
+		}
+	})
+
+	for _, s := range []string{m.String(), fmt.Sprintf("%v %+v %#v %s %q %x", m, m, m, m, m, m)} {
+		if strings.Contains(s, "abandon") || strings.Contains(s, hex.EncodeToString([]byte("abandon"))) {
+			t.Errorf("formatting leaked the phrase: %s", s)
+		}
+	}
+
+	m.Destroy()
+	m.Destroy()
+	if m.Phrase() != "" || len(slices.Collect(m.Words())) != 0 {
+		t.Error("Destroy() left the phrase readable")
+	}
+	if _, err := m.Seed(""); !errors.Is(err, ErrMnemonicDestroyed) {
+		t.Errorf("Seed() after Destroy error = %v, want ErrMnemonicDestroyed", err)
+	}
+	if _, err := m.Entropy(); !errors.Is(err, ErrMnemonicDestroyed) {
+		t.Errorf("Entropy() after Destroy error = %v, want ErrMnemonicDestroyed", err)
+	}
+
+	if _, err := ParseMnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"); !errors.Is(err, ErrInvalidChecksum) {
+		t.Errorf("ParseMnemonic(bad checksum) error = %v, want ErrInvalidChecksum", err)
+	}
+}
+
+func TestMnemonicFromEntropy(t *testing.T) {
+	entropy, _ := hex.DecodeString("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f")
+	m, err := MnemonicFromEntropy(entropy)
+	if err != nil {
+		t.Fatalf("MnemonicFromEntropy() error = %v", err)
+	}
+	want := "legal winner thank year wave sausage worth useful legal winner thank yellow"
+	if got := m.Phrase(); got != want {
+		t.Errorf("Phrase() = %q, want %q", got, want)
+	}
+
+	if _, err := MnemonicFromEntropy(make([]byte, 15)); !errors.Is(err, ErrInvalidEntropyLength) {
+		t.Errorf("MnemonicFromEntropy(15 bytes) error = %v, want ErrInvalidEntropyLength", err)
+	}
+}
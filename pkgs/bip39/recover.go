@@ -0,0 +1,140 @@
+package bip39
+
+import (
+	"context"
+	"fmt"
+	"runtime"
+	"slices"
+	"strings"
+	"sync"
+)
+
+// MissingWord marks the unknown word in a pattern passed to
+// RecoverMissingWord. Appended to letters it marks a partial word.
+const MissingWord = "?"
+
+// RecoverOptions configures RecoverMissingWord. The zero value uses the
+// defaults.
+type RecoverOptions struct {
+	// WordList is the word list of the mnemonic. Nil means DefaultWordList.
+	WordList WordList
+
+	// Confirm, if set, is called with each candidate mnemonic that passes the
+	// checksum, typically to derive an address and compare it with one the
+	// wallet is known to hold. Only candidates it accepts are returned; an
+	// error stops the search. Confirm is called from several goroutines at
+	// once.
+	Confirm func(mnemonic string) (bool, error)
+
+	// Workers is the number of goroutines that try candidates. Zero means
+	// runtime.GOMAXPROCS(0).
+	Workers int
+}
+
+// RecoverMissingWord finds the mnemonics a backup with one unreadable word
+// could be. The pattern is the mnemonic with that word replaced by "?", such
+// as "legal winner ? year ...", or by the letters that are still legible
+// followed by "?", such as "th?". Every word of the list (or every word with
+// that prefix) is tried in the gap; RecoverMissingWord returns those that pass
+// the checksum and opts.Confirm, in word list order.
+//
+// Without Confirm a 12-word pattern usually leaves about 128 candidates and a
+// 24-word pattern about 8, since the checksum holds 4 to 8 bits.
+func RecoverMissingWord(ctx context.Context, pattern string, opts RecoverOptions) ([]string, error) {
+	wordList := opts.WordList
+	if wordList == nil {
+		wordList = DefaultWordList
+	}
+
+	words := strings.Fields(nfkd(pattern))
+	if !isValidWordCount(len(words)) {
+		return nil, fmt.Errorf("%w: got %d", ErrInvalidMnemonicLength, len(words))
+	}
+	missing := -1
+	var prefix string
+	for i, word := range words {
+		if p, ok := strings.CutSuffix(word, MissingWord); ok {
+			if missing != -1 {
+				return nil, fmt.Errorf("%w: words %d and %d", ErrInvalidRecoveryPattern, missing+1, i+1)
+			}
+			missing, prefix = i, p
+		} else if wordList.WordIndex(word) == -1 {
+			return nil, fmt.Errorf("%w: word %d", ErrInvalidMnemonic, i+1)
+		}
+	}
+	if missing == -1 {
+		return nil, ErrInvalidRecoveryPattern
+	}
+
+	candidates := CompleteWordWithWordList(prefix, wordList)
+	if len(candidates) == 0 {
+		return nil, nil
+	}
+	workers := opts.Workers
+	if workers <= 0 {
+		workers = runtime.GOMAXPROCS(0)
+	}
+	workers = min(workers, len(candidates))
+
+	searchCtx, cancel := context.WithCancel(ctx)
+	defer cancel()
+
+	var (
+		found      = make([]bool, len(candidates))
+		confirmErr error
+		errOnce    sync.Once
+		wg         sync.WaitGroup
+	)
+	chunk := (len(candidates) + workers - 1) / workers
+	for lo := 0; lo < len(candidates); lo += chunk {
+		hi := min(lo+chunk, len(candidates))
+
+		wg.Add(1)
+		go func(lo, hi int) {
+			defer wg.Done()
+
+			phrase := slices.Clone(words)
+			for i := lo; i < hi; i++ {
+				if searchCtx.Err() != nil {
+					return
+				}
+				phrase[missing] = candidates[i]
+				mnemonic := strings.Join(phrase, " ")
+				if CheckMnemonicWithWordList(mnemonic, wordList) != nil {
+					continue
+				}
+				if opts.Confirm != nil {
+					ok, err := opts.Confirm(mnemonic)
+					if err != nil {
+						errOnce.Do(func() {
+							confirmErr = err
+							cancel()
+						})
+						return
+					}
+					if !ok {
+						continue
+					}
+				}
+				found[i] = true
+			}
+		}(lo, hi)
+	}
+	wg.Wait()
+
+	if confirmErr != nil {
+		return nil, confirmErr
+	}
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+
+	var mnemonics []string
+	for i, word := range candidates {
+		if found[i] {
+			words[missing] = word
+			mnemonics = append(mnemonics, strings.Join(words, " "))
+		}
+	}
+	return mnemonics, nil
+}
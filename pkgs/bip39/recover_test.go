@@ -0,0 +1,79 @@
+package bip39
+
+import (
+	"context"
+	"errors"
+	"slices"
+	"testing"
+)
+
+func TestRecoverMissingWord(t *testing.T) {
+	const mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow"
+	const pattern = "legal winner ? year wave sausage worth useful legal winner thank yellow"
+	ctx := context.Background()
+
+	got, err := RecoverMissingWord(ctx, pattern, RecoverOptions{})
+	if err != nil {
+		t.Fatalf("RecoverMissingWord() error = %v", err)
+	}
+	if len(got) != 118 || !slices.Contains(got, mnemonic) {
+		t.Errorf("RecoverMissingWord() = %d candidates, want 118 including the original", len(got))
+	}
+	if got[0] != "legal winner able year wave sausage worth useful legal winner thank yellow" {
+		t.Errorf("RecoverMissingWord()[0] = %q", got[0])
+	}
+	for _, candidate := range got {
+		if err := CheckMnemonic(candidate); err != nil {
+			t.Errorf("candidate %q: %v", candidate, err)
+		}
+	}
+
+	// A legible prefix narrows the search
+	got, err = RecoverMissingWord(ctx, "legal winner TH? year wave sausage worth useful legal winner thank yellow", RecoverOptions{Workers: 1})
+	if err != nil || !slices.Equal(got, []string{mnemonic}) {
+		t.Errorf("RecoverMissingWord(prefix) = %q, %v", got, err)
+	}
+
+	// Confirm picks the one that matches
+	got, err = RecoverMissingWord(ctx, pattern, RecoverOptions{
+		Confirm: func(candidate string) (bool, error) { return candidate == mnemonic, nil },
+	})
+	if err != nil || !slices.Equal(got, []string{mnemonic}) {
+		t.Errorf("RecoverMissingWord(Confirm) = %q, %v", got, err)
+	}
+
+	errConfirm := errors.New("node unreachable")
+	_, err = RecoverMissingWord(ctx, pattern, RecoverOptions{
+		Confirm: func(string) (bool, error) { return false, errConfirm },
+	})
+	if !errors.Is(err, errConfirm) {
+		t.Errorf("RecoverMissingWord(Confirm error) error = %v, want %v", err, errConfirm)
+	}
+
+	canceled, cancel := context.WithCancel(ctx)
+	cancel()
+	if _, err := RecoverMissingWord(canceled, pattern, RecoverOptions{}); !errors.Is(err, context.Canceled) {
+		t.Errorf("RecoverMissingWord(canceled) error = %v, want context.Canceled", err)
+	}
+}
+
+func TestRecoverMissingWordInvalid(t *testing.T) {
+	tests := []struct {
+		name    string
+		pattern string
+		wantErr error
+	}{
+		{"no gap", "legal winner thank year wave sausage worth useful legal winner thank yellow", ErrInvalidRecoveryPattern},
+		{"two gaps", "legal winner ? year wave sausage worth useful legal ? thank yellow", ErrInvalidRecoveryPattern},
+		{"unknown word", "legal winner ? year wave sausage worth useful legal winnr thank yellow", ErrInvalidMnemonic},
+		{"word missing", "legal winner ? year wave sausage worth useful legal winner thank", ErrInvalidMnemonicLength},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := RecoverMissingWord(context.Background(), tt.pattern, RecoverOptions{}); !errors.Is(err, tt.wantErr) {
+				t.Errorf("RecoverMissingWord() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+}
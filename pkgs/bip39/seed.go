@@ -1,8 +1,12 @@
 package bip39
 
 import (
+	"context"
 	"crypto/sha512"
+	"fmt"
+	"io"
 
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
 	"golang.org/x/crypto/pbkdf2"
 )
 
@@ -18,10 +22,94 @@ const (
 )
 
 // NewSeed generates a 512-bit seed from a mnemonic phrase.
-// The passphrase is optional and can be empty.
+// The passphrase is optional and can be empty. Both are NFKD-normalized
+// first, so a phrase or passphrase with accented or full-width characters
+// gives the same seed as in other wallets however it was typed.
 func NewSeed(mnemonic string, passphrase string) []byte {
-	salt := SaltPrefix + passphrase
-	return pbkdf2.Key([]byte(mnemonic), []byte(salt), PBKDF2Iterations, SeedSize, sha512.New)
+	return newSeed([]byte(nfkd(mnemonic)), passphrase)
+}
+
+// newSeed derives the seed of an already normalized mnemonic.
+func newSeed(mnemonic []byte, passphrase string) []byte {
+	salt := SaltPrefix + nfkd(passphrase)
+	return pbkdf2.Key(mnemonic, []byte(salt), PBKDF2Iterations, SeedSize, sha512.New)
+}
+
+// MnemonicToSeed is NewSeed for phrases a user typed or imported: NewSeed
+// derives a seed from any string, while MnemonicToSeed first checks the
+// mnemonic with CheckMnemonic and returns its error.
+func MnemonicToSeed(mnemonic string, passphrase string) ([]byte, error) {
+	if err := CheckMnemonic(mnemonic); err != nil {
+		return nil, err
+	}
+	return NewSeed(mnemonic, passphrase), nil
+}
+
+// Seed is a BIP-39 seed held in a secret.Bytes buffer. Deriving a seed runs
+// 2048 rounds of PBKDF2, so callers deriving accounts on several chains from
+// one mnemonic should derive a Seed once and pass it to each account
+// constructor. Destroy wipes it; String and every fmt verb print
+// secret.Redacted.
+type Seed struct {
+	buf *secret.Bytes
+}
+
+// SeedFromMnemonic is MnemonicToSeed returning a Seed.
+func SeedFromMnemonic(mnemonic string, passphrase string) (*Seed, error) {
+	seed, err := MnemonicToSeed(mnemonic, passphrase)
+	if err != nil {
+		return nil, err
+	}
+	return &Seed{buf: secret.Take(seed)}, nil
+}
+
+// Use calls fn with the seed bytes, which fn must not retain. A destroyed
+// seed is passed as nil.
+func (s *Seed) Use(fn func(seed []byte)) {
+	s.buf.Use(fn)
+}
+
+// Destroy wipes the seed. It is safe to call more than once.
+func (s *Seed) Destroy() {
+	s.buf.Destroy()
+}
+
+// String returns secret.Redacted.
+func (s *Seed) String() string {
+	return secret.Redacted
+}
+
+// Format implements fmt.Formatter so that no verb prints the seed.
+func (s *Seed) Format(f fmt.State, verb rune) {
+	io.WriteString(f, secret.Redacted)
+}
+
+// NewSeedContext is NewSeed for callers that must not wait on PBKDF2, such
+// as request handlers and UI event loops. The derivation runs in its own
+// goroutine; if ctx is done first NewSeedContext returns ctx.Err() at once
+// and the seed is wiped when the derivation finishes.
+func NewSeedContext(ctx context.Context, mnemonic string, passphrase string) ([]byte, error) {
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+	// Unbuffered, so a seed is only ever handed over to a caller that is
+	// still waiting; otherwise the worker keeps it and wipes it.
+	done := make(chan []byte)
+	go func() {
+		seed := NewSeed(mnemonic, passphrase)
+		select {
+		case done <- seed:
+		case <-ctx.Done():
+			secret.Wipe(seed)
+		}
+	}()
+
+	select {
+	case seed := <-done:
+		return seed, nil
+	case <-ctx.Done():
+		return nil, ctx.Err()
+	}
 }
 
 // NewSeedFromEntropy generates entropy, creates a mnemonic, and derives a seed.
@@ -1,7 +1,11 @@
 package bip39
 
 import (
+	"bytes"
+	"context"
 	"encoding/hex"
+	"errors"
+	"fmt"
 	"testing"
 )
 
@@ -133,6 +137,50 @@ func splitWords(s string) []string {
 	return words
 }
 
+func TestNewSeedContext(t *testing.T) {
+	mnemonic := "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+	seed, err := NewSeedContext(context.Background(), mnemonic, "TREZOR")
+	if err != nil {
+		t.Fatalf("NewSeedContext() error = %v", err)
+	}
+	if !bytes.Equal(seed, NewSeed(mnemonic, "TREZOR")) {
+		t.Errorf("NewSeedContext() = %x, want NewSeed()", seed)
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	if _, err := NewSeedContext(ctx, mnemonic, ""); !errors.Is(err, context.Canceled) {
+		t.Errorf("NewSeedContext(canceled) error = %v, want context.Canceled", err)
+	}
+}
+
+func TestSeedFromMnemonic(t *testing.T) {
+	mnemonic := "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+	seed, err := SeedFromMnemonic(mnemonic, "TREZOR")
+	if err != nil {
+		t.Fatalf("SeedFromMnemonic() error = %v", err)
+	}
+	seed.Use(func(b []byte) {
+		if !bytes.Equal(b, NewSeed(mnemonic, "TREZOR")) {
+			t.Errorf("SeedFromMnemonic() = %x, want NewSeed()", b)
+		}
+	})
+	if got := fmt.Sprintf("%v %x %s", seed, seed, seed); got != "[REDACTED] [REDACTED] [REDACTED]" {
+		t.Errorf("Sprintf(seed) = %s", got)
+	}
+
+	seed.Destroy()
+	seed.Use(func(b []byte) {
+		if b != nil {
+			t.Errorf("Use() after Destroy = %x, want nil", b)
+		}
+	})
+
+	if _, err := SeedFromMnemonic("abandon abandon abandon", ""); !errors.Is(err, ErrInvalidMnemonicLength) {
+		t.Errorf("SeedFromMnemonic(3 words) error = %v, want ErrInvalidMnemonicLength", err)
+	}
+}
+
 func TestSeedSize(t *testing.T) {
 	if SeedSize != 64 {
 		t.Errorf("SeedSize = %d, want 64", SeedSize)
@@ -0,0 +1,62 @@
+package bip39
+
+import (
+	"crypto/sha512"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"golang.org/x/crypto/pbkdf2"
+)
+
+// MiniSecretSize is the size of a Substrate mini-secret in bytes.
+const MiniSecretSize = 32
+
+// SubstrateSeed derives the 64-byte seed of the substrate-bip39 scheme used
+// by Polkadot-family wallets. It differs from NewSeed only in the PBKDF2
+// input: the mnemonic's entropy rather than its phrase, so the same words
+// give different keys on Substrate chains than on BIP-32 chains. The salt is
+// "mnemonic" followed by the NFKD-normalized password, as polkadot-js does.
+// entropy must be 16 to 32 bytes, a multiple of 4.
+func SubstrateSeed(entropy []byte, password string) ([]byte, error) {
+	if len(entropy) < 16 || len(entropy) > 32 || len(entropy)%4 != 0 {
+		return nil, fmt.Errorf("%w: got %d bytes", ErrInvalidEntropyLength, len(entropy))
+	}
+	salt := SaltPrefix + nfkd(password)
+	return pbkdf2.Key(entropy, []byte(salt), PBKDF2Iterations, SeedSize, sha512.New), nil
+}
+
+// MiniSecretFromEntropy returns the Substrate mini-secret for entropy: the
+// first 32 bytes of SubstrateSeed, from which sr25519 and ed25519 keys are
+// expanded.
+func MiniSecretFromEntropy(entropy []byte, password string) ([]byte, error) {
+	seed, err := SubstrateSeed(entropy, password)
+	if err != nil {
+		return nil, err
+	}
+	miniSecret := make([]byte, MiniSecretSize)
+	copy(miniSecret, seed)
+	secret.Wipe(seed)
+	return miniSecret, nil
+}
+
+// MiniSecretFromMnemonic checks a mnemonic with CheckMnemonic and returns
+// its Substrate mini-secret.
+func MiniSecretFromMnemonic(mnemonic, password string) ([]byte, error) {
+	entropy, err := MnemonicToEntropy(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(entropy)
+	return MiniSecretFromEntropy(entropy, password)
+}
+
+// MiniSecret returns the Substrate mini-secret of the mnemonic, as
+// MiniSecretFromMnemonic does.
+func (m *Mnemonic) MiniSecret(password string) ([]byte, error) {
+	entropy, err := m.Entropy()
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(entropy)
+	return MiniSecretFromEntropy(entropy, password)
+}
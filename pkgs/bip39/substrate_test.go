@@ -0,0 +1,71 @@
+package bip39
+
+import (
+	"encoding/hex"
+	"errors"
+	"testing"
+)
+
+// Test vectors from https://github.com/paritytech/substrate-bip39
+func TestSubstrateSeed(t *testing.T) {
+	tests := []struct {
+		entropy string
+		seed    string
+	}{
+		{
+			"00000000000000000000000000000000",
+			"44e9d125f037ac1d51f0a7d3649689d422c2af8b1ec8e00d71db4d7bf6d127e33f50c3d5c84fa3e5399c72d6cbbbbc4a49bf76f76d952f479d74655a2ef2d453",
+		},
+		{
+			"7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+			"4313249608fe8ac10fd5886c92c4579007272cb77c21551ee5b8d60b780416850f1e26c1f4b8d88ece681cb058ab66d6182bc2ce5a03181f7b74c27576b5c8bf",
+		},
+	}
+
+	for _, tt := range tests {
+		entropy, _ := hex.DecodeString(tt.entropy)
+		seed, err := SubstrateSeed(entropy, "Substrate")
+		if err != nil {
+			t.Fatalf("SubstrateSeed(%s) error = %v", tt.entropy, err)
+		}
+		if got := hex.EncodeToString(seed); got != tt.seed {
+			t.Errorf("SubstrateSeed(%s) = %s, want %s", tt.entropy, got, tt.seed)
+		}
+
+		miniSecret, err := MiniSecretFromEntropy(entropy, "Substrate")
+		if err != nil || hex.EncodeToString(miniSecret) != tt.seed[:2*MiniSecretSize] {
+			t.Errorf("MiniSecretFromEntropy(%s) = %x, %v", tt.entropy, miniSecret, err)
+		}
+	}
+
+	if _, err := SubstrateSeed(make([]byte, 18), ""); !errors.Is(err, ErrInvalidEntropyLength) {
+		t.Errorf("SubstrateSeed(18 bytes) error = %v, want ErrInvalidEntropyLength", err)
+	}
+}
+
+func TestMiniSecretFromMnemonic(t *testing.T) {
+	const mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow"
+	want := "4313249608fe8ac10fd5886c92c4579007272cb77c21551ee5b8d60b78041685"
+
+	miniSecret, err := MiniSecretFromMnemonic(mnemonic, "Substrate")
+	if err != nil {
+		t.Fatalf("MiniSecretFromMnemonic() error = %v", err)
+	}
+	if got := hex.EncodeToString(miniSecret); got != want {
+		t.Errorf("MiniSecretFromMnemonic() = %s, want %s", got, want)
+	}
+
+	// Not the BIP-39 seed of the same words
+	if seed := NewSeed(mnemonic, "Substrate"); hex.EncodeToString(seed[:MiniSecretSize]) == want {
+		t.Error("MiniSecretFromMnemonic() matches the BIP-39 seed")
+	}
+
+	m, _ := ParseMnemonic(mnemonic)
+	if got, err := m.MiniSecret("Substrate"); err != nil || hex.EncodeToString(got) != want {
+		t.Errorf("Mnemonic.MiniSecret() = %x, %v, want %s", got, err, want)
+	}
+
+	if _, err := MiniSecretFromMnemonic("legal winner thank year wave sausage worth useful legal winner thank thank", ""); !errors.Is(err, ErrInvalidChecksum) {
+		t.Errorf("MiniSecretFromMnemonic(bad checksum) error = %v, want ErrInvalidChecksum", err)
+	}
+}
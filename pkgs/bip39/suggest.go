@@ -0,0 +1,86 @@
+package bip39
+
+import "strings"
+
+// maxSuggestDistance is the largest edit distance SuggestWords reports;
+// beyond two edits almost every short word is a candidate.
+const maxSuggestDistance = 2
+
+// CompleteWord returns the words of the default word list that start with
+// prefix, in list order, for autocompletion as a user types a mnemonic.
+// The first four letters of an English word identify it, so a prefix of
+// four or more letters matches at most one word.
+func CompleteWord(prefix string) []string {
+	return CompleteWordWithWordList(prefix, DefaultWordList)
+}
+
+// CompleteWordWithWordList is CompleteWord with a specific word list.
+func CompleteWordWithWordList(prefix string, wordList WordList) []string {
+	prefix = strings.ToLower(nfkd(prefix))
+	if searcher, ok := wordList.(PrefixSearcher); ok {
+		return searcher.WordsWithPrefix(prefix)
+	}
+	var matches []string
+	for i := range wordList.Size() {
+		if word := wordList.WordAt(i); strings.HasPrefix(nfkd(word), prefix) {
+			matches = append(matches, word)
+		}
+	}
+	return matches
+}
+
+// SuggestWords returns the words of the default word list closest to a
+// mistyped word, for recovery UIs: those at the smallest edit distance, in
+// list order. An edit inserts, deletes or replaces a letter, or swaps two
+// adjacent letters. SuggestWords returns nil when no word is within two
+// edits, and just the word itself when it is in the list.
+func SuggestWords(word string) []string {
+	return SuggestWordsWithWordList(word, DefaultWordList)
+}
+
+// SuggestWordsWithWordList is SuggestWords with a specific word list.
+func SuggestWordsWithWordList(word string, wordList WordList) []string {
+	typed := []rune(strings.ToLower(nfkd(word)))
+	best := maxSuggestDistance + 1
+	var suggestions []string
+	for i := range wordList.Size() {
+		candidate := wordList.WordAt(i)
+		d := editDistance(typed, []rune(nfkd(candidate)))
+		switch {
+		case d < best:
+			best = d
+			suggestions = append(suggestions[:0], candidate)
+		case d == best:
+			suggestions = append(suggestions, candidate)
+		}
+	}
+	return suggestions
+}
+
+// editDistance returns the optimal string alignment distance between a and
+// b: the number of insertions, deletions, substitutions and transpositions
+// of adjacent runes that turn a into b, editing no substring twice.
+func editDistance(a, b []rune) int {
+	// Rows i-2, i-1 and i of the dynamic programming table
+	prev2 := make([]int, len(b)+1)
+	prev := make([]int, len(b)+1)
+	cur := make([]int, len(b)+1)
+	for j := range prev {
+		prev[j] = j
+	}
+	for i := 1; i <= len(a); i++ {
+		cur[0] = i
+		for j := 1; j <= len(b); j++ {
+			cost := 1
+			if a[i-1] == b[j-1] {
+				cost = 0
+			}
+			cur[j] = min(prev[j]+1, cur[j-1]+1, prev[j-1]+cost)
+			if i > 1 && j > 1 && a[i-1] == b[j-2] && a[i-2] == b[j-1] {
+				cur[j] = min(cur[j], prev2[j-2]+1)
+			}
+		}
+		prev2, prev, cur = prev, cur, prev2
+	}
+	return prev[len(b)]
+}
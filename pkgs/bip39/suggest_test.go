@@ -0,0 +1,74 @@
+package bip39
+
+import (
+	"slices"
+	"testing"
+)
+
+func TestCompleteWord(t *testing.T) {
+	tests := []struct {
+		prefix string
+		want   []string
+	}{
+		{"ab", []string{"abandon", "ability", "able", "about", "above", "absent", "absorb", "abstract", "absurd", "abuse"}},
+		{"aban", []string{"abandon"}},
+		{"ABAN", []string{"abandon"}},
+		{"abandon", []string{"abandon"}},
+		{"abandons", nil},
+		{"xyz", nil},
+	}
+
+	for _, tt := range tests {
+		if got := CompleteWord(tt.prefix); !slices.Equal(got, tt.want) {
+			t.Errorf("CompleteWord(%q) = %v, want %v", tt.prefix, got, tt.want)
+		}
+	}
+
+	// Four letters identify every word
+	for _, word := range English.Words() {
+		if len(word) >= 4 && len(CompleteWord(word[:4])) != 1 {
+			t.Errorf("CompleteWord(%q) is ambiguous", word[:4])
+		}
+	}
+}
+
+func TestSuggestWords(t *testing.T) {
+	tests := []struct {
+		word string
+		want []string
+	}{
+		{"abandon", []string{"abandon"}},
+		{"abandn", []string{"abandon"}},
+		{"ABANDN", []string{"abandon"}},
+		{"aboot", []string{"about"}},
+		{"wrold", []string{"world"}},
+		{"recieve", []string{"receive"}},
+		{"bok", []string{"book", "box", "boy"}},
+		{"xyzzyq", nil},
+	}
+
+	for _, tt := range tests {
+		if got := SuggestWords(tt.word); !slices.Equal(got, tt.want) {
+			t.Errorf("SuggestWords(%q) = %v, want %v", tt.word, got, tt.want)
+		}
+	}
+}
+
+func TestEditDistance(t *testing.T) {
+	tests := []struct {
+		a, b string
+		want int
+	}{
+		{"", "", 0},
+		{"", "abc", 3},
+		{"kitten", "sitting", 3},
+		{"world", "wrold", 1},
+		{"ca", "abc", 3},
+	}
+
+	for _, tt := range tests {
+		if got := editDistance([]rune(tt.a), []rune(tt.b)); got != tt.want {
+			t.Errorf("editDistance(%q, %q) = %d, want %d", tt.a, tt.b, got, tt.want)
+		}
+	}
+}
@@ -1,12 +1,29 @@
 package bip39
 
-// WordList represents a BIP-39 word list.
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"slices"
+	"sort"
+	"strings"
+	"unicode"
+)
+
+// WordListSize is the number of words in a BIP-39 word list.
+const WordListSize = 2048
+
+// WordList represents a BIP-39 word list. Besides the lists this package
+// ships, NewWordList and ReadWordList build one from any 2048 words, and
+// callers may implement it themselves, e.g. over a compressed list in
+// firmware. Functions in this package only use WordIndex, WordAt and Size,
+// so an implementation may build the Words slice on demand.
 type WordList interface {
 	// Words returns all words in the word list.
 	Words() []string
 
-	// WordIndex returns the index of a word in the word list.
-	// Returns -1 if the word is not found.
+	// WordIndex returns the index of a word in the word list, comparing
+	// NFKD-normalized forms. Returns -1 if the word is not found.
 	WordIndex(word string) int
 
 	// WordAt returns the word at the given index.
@@ -17,22 +34,86 @@ type WordList interface {
 	Size() int
 }
 
+// PrefixSearcher is implemented by word lists that find the words starting
+// with a prefix faster than by trying every word. CompleteWordWithWordList
+// uses it when the word list provides it.
+type PrefixSearcher interface {
+	// WordsWithPrefix returns the words whose NFKD form starts with prefix,
+	// which is already NFKD-normalized, in list order.
+	WordsWithPrefix(prefix string) []string
+}
+
 // wordListImpl implements WordList interface.
 type wordListImpl struct {
 	words   []string
 	wordMap map[string]int
+
+	// normalized holds the NFKD form of each word, and sorted the word
+	// indexes in order of those forms, for prefix search.
+	normalized []string
+	sorted     []int
 }
 
-// newWordList creates a new word list from a slice of words.
+// newWordList creates a new word list from a slice of words. Words are
+// indexed by their NFKD form, so a list stored with precomposed letters
+// matches normalized input.
 func newWordList(words []string) *wordListImpl {
 	wordMap := make(map[string]int, len(words))
+	normalized := make([]string, len(words))
+	sorted := make([]int, len(words))
 	for i, word := range words {
-		wordMap[word] = i
+		normalized[i] = nfkd(word)
+		wordMap[normalized[i]] = i
+		sorted[i] = i
 	}
+	slices.SortFunc(sorted, func(a, b int) int {
+		return strings.Compare(normalized[a], normalized[b])
+	})
 	return &wordListImpl{
-		words:   words,
-		wordMap: wordMap,
+		words:      words,
+		wordMap:    wordMap,
+		normalized: normalized,
+		sorted:     sorted,
+	}
+}
+
+// NewWordList returns a word list of the given words, for languages this
+// package does not ship. There must be exactly 2048 distinct words, none
+// empty or containing whitespace; ErrInvalidWordList names the first
+// problem. The slice is copied.
+func NewWordList(words []string) (WordList, error) {
+	if len(words) != WordListSize {
+		return nil, fmt.Errorf("%w: got %d words", ErrInvalidWordList, len(words))
+	}
+	for i, word := range words {
+		if word == "" || strings.ContainsFunc(word, unicode.IsSpace) {
+			return nil, fmt.Errorf("%w: word %d is empty or contains whitespace", ErrInvalidWordList, i+1)
+		}
 	}
+	w := newWordList(slices.Clone(words))
+	for i, index := range w.sorted[1:] {
+		if prev := w.sorted[i]; w.normalized[prev] == w.normalized[index] {
+			return nil, fmt.Errorf("%w: words %d and %d are the same", ErrInvalidWordList, min(prev, index)+1, max(prev, index)+1)
+		}
+	}
+	return w, nil
+}
+
+// ReadWordList reads a word list in the format of the BIP-39 repository, one
+// word per line, and checks it as NewWordList does. Surrounding whitespace
+// and blank lines are ignored.
+func ReadWordList(r io.Reader) (WordList, error) {
+	var words []string
+	scanner := bufio.NewScanner(r)
+	for scanner.Scan() {
+		if word := strings.TrimSpace(scanner.Text()); word != "" {
+			words = append(words, word)
+		}
+	}
+	if err := scanner.Err(); err != nil {
+		return nil, err
+	}
+	return NewWordList(words)
 }
 
 func (w *wordListImpl) Words() []string {
@@ -40,7 +121,7 @@ func (w *wordListImpl) Words() []string {
 }
 
 func (w *wordListImpl) WordIndex(word string) int {
-	if idx, ok := w.wordMap[word]; ok {
+	if idx, ok := w.wordMap[nfkd(word)]; ok {
 		return idx
 	}
 	return -1
@@ -54,6 +135,24 @@ func (w *wordListImpl) Size() int {
 	return len(w.words)
 }
 
+func (w *wordListImpl) WordsWithPrefix(prefix string) []string {
+	start := sort.Search(len(w.sorted), func(i int) bool { return w.normalized[w.sorted[i]] >= prefix })
+	var indexes []int
+	for _, index := range w.sorted[start:] {
+		if !strings.HasPrefix(w.normalized[index], prefix) {
+			break
+		}
+		indexes = append(indexes, index)
+	}
+	slices.Sort(indexes)
+
+	var words []string
+	for _, index := range indexes {
+		words = append(words, w.words[index])
+	}
+	return words
+}
+
 // English is the official BIP-39 English word list.
 var English WordList = newWordList(englishWords)
 
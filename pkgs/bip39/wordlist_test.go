@@ -0,0 +1,88 @@
+package bip39
+
+import (
+	"errors"
+	"slices"
+	"strings"
+	"testing"
+)
+
+func TestReadWordList(t *testing.T) {
+	text := "  " + strings.Join(English.Words(), "\r\n") + "\n\n"
+	wordList, err := ReadWordList(strings.NewReader(text))
+	if err != nil {
+		t.Fatalf("ReadWordList() error = %v", err)
+	}
+	if wordList.Size() != WordListSize || wordList.WordAt(0) != "abandon" || wordList.WordIndex("zoo") != 2047 {
+		t.Errorf("ReadWordList() = %d words", wordList.Size())
+	}
+
+	entropy := make([]byte, 16)
+	got, _ := NewMnemonicWithWordList(entropy, wordList)
+	want, _ := NewMnemonic(entropy)
+	if got != want {
+		t.Errorf("NewMnemonicWithWordList() = %q, want %q", got, want)
+	}
+}
+
+func TestNewWordList(t *testing.T) {
+	// A reversed list: another language as far as the package can tell
+	reversed := slices.Clone(English.Words())
+	slices.Reverse(reversed)
+	wordList, err := NewWordList(reversed)
+	if err != nil {
+		t.Fatalf("NewWordList() error = %v", err)
+	}
+	if wordList.WordIndex("abandon") != 2047 {
+		t.Errorf("WordIndex(abandon) = %d, want 2047", wordList.WordIndex("abandon"))
+	}
+	if got, want := CompleteWordWithWordList("abs", wordList), []string{"absurd", "abstract", "absorb", "absent"}; !slices.Equal(got, want) {
+		t.Errorf("CompleteWordWithWordList(abs) = %v, want %v", got, want)
+	}
+	reversed[0] = "changed"
+	if wordList.WordAt(0) != "zoo" {
+		t.Error("NewWordList() aliases its argument")
+	}
+
+	duplicate := slices.Clone(English.Words())
+	duplicate[2000] = "abandon"
+	tests := []struct {
+		name    string
+		words   []string
+		wantErr string
+	}{
+		{"short", English.Words()[:2047], "got 2047 words"},
+		{"empty word", append(slices.Clone(English.Words()[:2047]), ""), "word 2048 is empty"},
+		{"space", append([]string{"two words"}, English.Words()[1:]...), "word 1 is empty or contains whitespace"},
+		{"duplicate", duplicate, "words 1 and 2001 are the same"},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			_, err := NewWordList(tt.words)
+			if !errors.Is(err, ErrInvalidWordList) || !strings.Contains(err.Error(), tt.wantErr) {
+				t.Errorf("NewWordList() error = %v, want %q", err, tt.wantErr)
+			}
+		})
+	}
+}
+
+// sliceWordList is a WordList without prefix search.
+type sliceWordList []string
+
+func (l sliceWordList) Words() []string { return l }
+func (l sliceWordList) WordIndex(word string) int { return slices.Index(l, word) }
+func (l sliceWordList) WordAt(index int) string { return l[index] }
+func (l sliceWordList) Size() int { return len(l) }
+
+func TestCustomWordList(t *testing.T) {
+	custom := sliceWordList(English.Words())
+	if got := CompleteWordWithWordList("aban", custom); !slices.Equal(got, []string{"abandon"}) {
+		t.Errorf("CompleteWordWithWordList() = %v", got)
+	}
+	if got := SuggestWordsWithWordList("abandn", custom); !slices.Equal(got, []string{"abandon"}) {
+		t.Errorf("SuggestWordsWithWordList() = %v", got)
+	}
+	if err := CheckMnemonicWithWordList("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", custom); err != nil {
+		t.Errorf("CheckMnemonicWithWordList() error = %v", err)
+	}
+}
@@ -0,0 +1,97 @@
+package bip39
+
+import (
+	"crypto/rand"
+	"fmt"
+	"io"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// SplitXOR splits a mnemonic into n share mnemonics of the same length whose
+// entropies XOR to the mnemonic's entropy, the scheme Coldcard calls Seed
+// XOR. Each share is itself a valid mnemonic, so a share found on its own
+// looks like an ordinary, empty wallet.
+//
+// EVERY share is required to recover the mnemonic: there is no threshold, and
+// losing any one share loses the wallet. Any n-1 shares reveal nothing about
+// it. Use package slip39 when some shares may be lost.
+//
+// The first n-1 shares are random, read from rnd or from crypto/rand.Reader
+// when rnd is nil. n must be at least 2.
+func SplitXOR(rnd io.Reader, mnemonic string, n int) ([]string, error) {
+	return SplitXORWithWordList(rnd, mnemonic, n, DefaultWordList)
+}
+
+// SplitXORWithWordList is SplitXOR with a specific word list.
+func SplitXORWithWordList(rnd io.Reader, mnemonic string, n int, wordList WordList) ([]string, error) {
+	if n < 2 {
+		return nil, fmt.Errorf("%w: got %d", ErrInvalidShareCount, n)
+	}
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+	entropy, err := MnemonicToEntropyWithWordList(mnemonic, wordList)
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(entropy)
+
+	shares := make([]string, n)
+	share := make([]byte, len(entropy))
+	defer secret.Wipe(share)
+	for i := range n - 1 {
+		if _, err := io.ReadFull(rnd, share); err != nil {
+			return nil, err
+		}
+		for j := range entropy {
+			entropy[j] ^= share[j]
+		}
+		if shares[i], err = NewMnemonicWithWordList(share, wordList); err != nil {
+			return nil, err
+		}
+	}
+	// What is left of the entropy is the last share
+	if shares[n-1], err = NewMnemonicWithWordList(entropy, wordList); err != nil {
+		return nil, err
+	}
+	return shares, nil
+}
+
+// CombineXOR recovers the mnemonic split by SplitXOR from all of its shares,
+// in any order. Shares must have the same word count, or CombineXOR returns
+// ErrMismatchedShares. A missing or wrong share is not detected: the result
+// is a valid but different mnemonic, so check the recovered wallet's
+// addresses against a known one.
+func CombineXOR(shares []string) (string, error) {
+	return CombineXORWithWordList(shares, DefaultWordList)
+}
+
+// CombineXORWithWordList is CombineXOR with a specific word list.
+func CombineXORWithWordList(shares []string, wordList WordList) (string, error) {
+	if len(shares) < 2 {
+		return "", fmt.Errorf("%w: got %d", ErrInvalidShareCount, len(shares))
+	}
+
+	var entropy []byte
+	defer func() { secret.Wipe(entropy) }()
+	for i, share := range shares {
+		shareEntropy, err := MnemonicToEntropyWithWordList(share, wordList)
+		if err != nil {
+			return "", fmt.Errorf("share %d: %w", i+1, err)
+		}
+		if entropy == nil {
+			entropy = shareEntropy
+			continue
+		}
+		if len(shareEntropy) != len(entropy) {
+			secret.Wipe(shareEntropy)
+			return "", fmt.Errorf("%w: share %d", ErrMismatchedShares, i+1)
+		}
+		for j := range entropy {
+			entropy[j] ^= shareEntropy[j]
+		}
+		secret.Wipe(shareEntropy)
+	}
+	return NewMnemonicWithWordList(entropy, wordList)
+}
@@ -0,0 +1,74 @@
+package bip39
+
+import (
+	"bytes"
+	"errors"
+	"slices"
+	"testing"
+)
+
+func TestSplitXOR(t *testing.T) {
+	const mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+	// Zero entropy split with 7f... and 80... leaves ff... for the last share
+	rnd := bytes.NewReader(append(bytes.Repeat([]byte{0x7f}, 16), bytes.Repeat([]byte{0x80}, 16)...))
+	shares, err := SplitXOR(rnd, mnemonic, 3)
+	if err != nil {
+		t.Fatalf("SplitXOR() error = %v", err)
+	}
+	want := []string{
+		"legal winner thank year wave sausage worth useful legal winner thank yellow",
+		"letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+		"zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+	}
+	if !slices.Equal(shares, want) {
+		t.Errorf("SplitXOR() = %q, want %q", shares, want)
+	}
+
+	for _, order := range [][]string{shares, {shares[2], shares[0], shares[1]}} {
+		got, err := CombineXOR(order)
+		if err != nil || got != mnemonic {
+			t.Errorf("CombineXOR() = %q, %v, want %q", got, err, mnemonic)
+		}
+	}
+
+	// A missing share gives another valid mnemonic, not an error
+	if got, err := CombineXOR(shares[:2]); err != nil || got == mnemonic {
+		t.Errorf("CombineXOR(2 of 3) = %q, %v", got, err)
+	}
+}
+
+func TestSplitXORRandom(t *testing.T) {
+	const mnemonic = "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title"
+	shares, err := SplitXOR(nil, mnemonic, 2)
+	if err != nil {
+		t.Fatalf("SplitXOR() error = %v", err)
+	}
+	if len(shares) != 2 || !ValidateMnemonic(shares[0]) || !ValidateMnemonic(shares[1]) || len(splitWords(shares[0])) != 24 {
+		t.Fatalf("SplitXOR() = %q", shares)
+	}
+	if got, err := CombineXOR(shares); err != nil || got != mnemonic {
+		t.Errorf("CombineXOR() = %q, %v, want %q", got, err, mnemonic)
+	}
+}
+
+func TestSplitXORInvalid(t *testing.T) {
+	const mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+	if _, err := SplitXOR(nil, mnemonic, 1); !errors.Is(err, ErrInvalidShareCount) {
+		t.Errorf("SplitXOR(1 share) error = %v, want ErrInvalidShareCount", err)
+	}
+	if _, err := SplitXOR(nil, "abandon abandon abandon", 2); !errors.Is(err, ErrInvalidMnemonicLength) {
+		t.Errorf("SplitXOR(3 words) error = %v, want ErrInvalidMnemonicLength", err)
+	}
+	if _, err := CombineXOR([]string{mnemonic}); !errors.Is(err, ErrInvalidShareCount) {
+		t.Errorf("CombineXOR(1 share) error = %v, want ErrInvalidShareCount", err)
+	}
+	long := "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote"
+	if _, err := CombineXOR([]string{mnemonic, long}); !errors.Is(err, ErrMismatchedShares) {
+		t.Errorf("CombineXOR(12 and 24 words) error = %v, want ErrMismatchedShares", err)
+	}
+	if _, err := CombineXOR([]string{mnemonic, "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"}); !errors.Is(err, ErrInvalidChecksum) {
+		t.Errorf("CombineXOR(bad share) error = %v, want ErrInvalidChecksum", err)
+	}
+}
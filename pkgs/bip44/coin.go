@@ -1,6 +1,8 @@
 // Package bip44 implements BIP-44 multi-account hierarchy for deterministic wallets.
 package bip44
 
+import "strings"
+
 // CoinType represents a cryptocurrency coin type as defined in SLIP-44.
 // https://github.com/satoshilabs/slips/blob/master/slip-0044.md
 type CoinType uint32
@@ -14,14 +16,37 @@ const (
 	CoinTypeDash            CoinType = 5
 	CoinTypeEthereum        CoinType = 60
 	CoinTypeEthereumClassic CoinType = 61
+	CoinTypeCosmos          CoinType = 118
+	CoinTypeMonero          CoinType = 128
+	CoinTypeZcash           CoinType = 133
 	CoinTypeRipple          CoinType = 144
 	CoinTypeBitcoinCash     CoinType = 145
 	CoinTypeStellar         CoinType = 148
 	CoinTypeTron            CoinType = 195
-	CoinTypeBinance         CoinType = 714
+	CoinTypeICP             CoinType = 223
+	CoinTypeAlgorand        CoinType = 283
+	CoinTypePolkadot        CoinType = 354
+	CoinTypeNear            CoinType = 397
+	CoinTypeKusama          CoinType = 434
+	CoinTypeFilecoin        CoinType = 461
+	CoinTypeTheta           CoinType = 500
 	CoinTypeSolana          CoinType = 501
+	CoinTypeFlow            CoinType = 539
+	CoinTypeTON             CoinType = 607
+	CoinTypeAptos           CoinType = 637
+	CoinTypeBinance         CoinType = 714
+	CoinTypeSui             CoinType = 784
+	CoinTypeVeChain         CoinType = 818
+	CoinTypeNeo             CoinType = 888
 	CoinTypePolygon         CoinType = 966
+	CoinTypeFantom          CoinType = 1007
+	CoinTypeHarmony         CoinType = 1023
+	CoinTypeNostr           CoinType = 1237
+	CoinTypeTezos           CoinType = 1729
+	CoinTypeCardano         CoinType = 1815
+	CoinTypeHedera          CoinType = 3030
 	CoinTypeAvalanche       CoinType = 9000
+	CoinTypeStarknet        CoinType = 9004
 )
 
 // CoinInfo contains metadata about a cryptocurrency.
@@ -100,6 +125,12 @@ var coinRegistry = map[CoinType]CoinInfo{
 		Name:     "Tron",
 		Decimals: 6,
 	},
+	CoinTypeFlow: {
+		Type:     CoinTypeFlow,
+		Symbol:   "FLOW",
+		Name:     "Flow",
+		Decimals: 8,
+	},
 	CoinTypeBinance: {
 		Type:     CoinTypeBinance,
 		Symbol:   "BNB",
@@ -112,18 +143,150 @@ var coinRegistry = map[CoinType]CoinInfo{
 		Name:     "Solana",
 		Decimals: 9,
 	},
+	CoinTypeVeChain: {
+		Type:     CoinTypeVeChain,
+		Symbol:   "VET",
+		Name:     "VeChain",
+		Decimals: 18,
+	},
+	CoinTypeNeo: {
+		Type:     CoinTypeNeo,
+		Symbol:   "NEO",
+		Name:     "Neo",
+		Decimals: 0,
+	},
 	CoinTypePolygon: {
 		Type:     CoinTypePolygon,
 		Symbol:   "MATIC",
 		Name:     "Polygon",
 		Decimals: 18,
 	},
+	CoinTypeTheta: {
+		Type:     CoinTypeTheta,
+		Symbol:   "THETA",
+		Name:     "Theta",
+		Decimals: 18,
+	},
+	CoinTypeFantom: {
+		Type:     CoinTypeFantom,
+		Symbol:   "FTM",
+		Name:     "Fantom",
+		Decimals: 18,
+	},
+	CoinTypeHarmony: {
+		Type:     CoinTypeHarmony,
+		Symbol:   "ONE",
+		Name:     "Harmony",
+		Decimals: 18,
+	},
 	CoinTypeAvalanche: {
 		Type:     CoinTypeAvalanche,
 		Symbol:   "AVAX",
 		Name:     "Avalanche",
 		Decimals: 18,
 	},
+	CoinTypeCosmos: {
+		Type:     CoinTypeCosmos,
+		Symbol:   "ATOM",
+		Name:     "Cosmos Hub",
+		Decimals: 6,
+	},
+	CoinTypeMonero: {
+		Type:     CoinTypeMonero,
+		Symbol:   "XMR",
+		Name:     "Monero",
+		Decimals: 12,
+	},
+	CoinTypeZcash: {
+		Type:     CoinTypeZcash,
+		Symbol:   "ZEC",
+		Name:     "Zcash",
+		Decimals: 8,
+	},
+	CoinTypeICP: {
+		Type:     CoinTypeICP,
+		Symbol:   "ICP",
+		Name:     "Internet Computer",
+		Decimals: 8,
+	},
+	CoinTypeAlgorand: {
+		Type:     CoinTypeAlgorand,
+		Symbol:   "ALGO",
+		Name:     "Algorand",
+		Decimals: 6,
+	},
+	CoinTypePolkadot: {
+		Type:     CoinTypePolkadot,
+		Symbol:   "DOT",
+		Name:     "Polkadot",
+		Decimals: 10,
+	},
+	CoinTypeNear: {
+		Type:     CoinTypeNear,
+		Symbol:   "NEAR",
+		Name:     "NEAR Protocol",
+		Decimals: 24,
+	},
+	CoinTypeKusama: {
+		Type:     CoinTypeKusama,
+		Symbol:   "KSM",
+		Name:     "Kusama",
+		Decimals: 12,
+	},
+	CoinTypeFilecoin: {
+		Type:     CoinTypeFilecoin,
+		Symbol:   "FIL",
+		Name:     "Filecoin",
+		Decimals: 18,
+	},
+	CoinTypeTON: {
+		Type:     CoinTypeTON,
+		Symbol:   "TON",
+		Name:     "Toncoin",
+		Decimals: 9,
+	},
+	CoinTypeAptos: {
+		Type:     CoinTypeAptos,
+		Symbol:   "APT",
+		Name:     "Aptos",
+		Decimals: 8,
+	},
+	CoinTypeSui: {
+		Type:     CoinTypeSui,
+		Symbol:   "SUI",
+		Name:     "Sui",
+		Decimals: 9,
+	},
+	CoinTypeNostr: {
+		Type:     CoinTypeNostr,
+		Symbol:   "NOSTR",
+		Name:     "Nostr",
+		Decimals: 0,
+	},
+	CoinTypeTezos: {
+		Type:     CoinTypeTezos,
+		Symbol:   "XTZ",
+		Name:     "Tezos",
+		Decimals: 6,
+	},
+	CoinTypeCardano: {
+		Type:     CoinTypeCardano,
+		Symbol:   "ADA",
+		Name:     "Cardano",
+		Decimals: 6,
+	},
+	CoinTypeHedera: {
+		Type:     CoinTypeHedera,
+		Symbol:   "HBAR",
+		Name:     "Hedera",
+		Decimals: 8,
+	},
+	CoinTypeStarknet: {
+		Type:     CoinTypeStarknet,
+		Symbol:   "STRK",
+		Name:     "Starknet",
+		Decimals: 18,
+	},
 }
 
 // GetCoinInfo returns the coin information for a given coin type.
@@ -148,3 +311,29 @@ func ListCoins() []CoinInfo {
 	}
 	return coins
 }
+
+// LookupCoin finds a registered coin by symbol ("ATOM") or name ("Cosmos
+// Hub"), ignoring case. Symbols are tried first, so a symbol that is also
+// another coin's name resolves to the coin with that symbol.
+func LookupCoin(symbolOrName string) (*CoinInfo, bool) {
+	var byName *CoinInfo
+	for _, info := range coinRegistry {
+		if strings.EqualFold(info.Symbol, symbolOrName) {
+			return &info, true
+		}
+		if byName == nil && strings.EqualFold(info.Name, symbolOrName) {
+			byName = &info
+		}
+	}
+	return byName, byName != nil
+}
+
+// Info returns the coin's registry entry, or nil if it is not registered.
+func (c CoinType) Info() *CoinInfo {
+	return GetCoinInfo(c)
+}
+
+// Path returns the receiving address path m/44'/coin'/account'/0/addressIndex.
+func (c CoinType) Path(account, addressIndex uint32) *Path {
+	return NewPath(c, account, ExternalChain, addressIndex)
+}
@@ -0,0 +1,41 @@
+package bip44
+
+import (
+	"testing"
+)
+
+func TestLookupCoin(t *testing.T) {
+	tests := []struct {
+		query string
+		want  CoinType
+	}{
+		{"BTC", CoinTypeBitcoin},
+		{"atom", CoinTypeCosmos},
+		{"Cosmos Hub", CoinTypeCosmos},
+		{"sui", CoinTypeSui},
+		{"ethereum classic", CoinTypeEthereumClassic},
+	}
+
+	for _, tt := range tests {
+		info, ok := LookupCoin(tt.query)
+		if !ok || info.Type != tt.want {
+			t.Errorf("LookupCoin(%q) = %v, %v, want %d", tt.query, info, ok, tt.want)
+		}
+	}
+
+	if info, ok := LookupCoin("not-a-coin"); ok {
+		t.Errorf("LookupCoin(not-a-coin) = %v, want not found", info)
+	}
+}
+
+func TestCoinTypePath(t *testing.T) {
+	if got := CoinTypeCosmos.Path(0, 7).String(); got != "m/44'/118'/0'/0/7" {
+		t.Errorf("CoinTypeCosmos.Path(0, 7) = %s", got)
+	}
+	if info := CoinTypeSolana.Info(); info == nil || info.Symbol != "SOL" {
+		t.Errorf("CoinTypeSolana.Info() = %v", info)
+	}
+	if info := CoinType(999999).Info(); info != nil {
+		t.Errorf("CoinType(999999).Info() = %v, want nil", info)
+	}
+}
@@ -0,0 +1,193 @@
+// Package caip builds and parses CAIP-2 chain IDs (eip155:1,
+// cosmos:cosmoshub-4, ...) and CAIP-10 account IDs (chain ID plus address),
+// the identifiers WalletConnect and other chain-agnostic protocols use.
+package caip
+
+import (
+	"errors"
+	"fmt"
+	"regexp"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+var (
+	// ErrInvalidChainID is returned for a string that is not a CAIP-2 chain ID.
+	ErrInvalidChainID = errors.New("caip: invalid chain id")
+
+	// ErrInvalidAccountID is returned for a string that is not a CAIP-10 account ID.
+	ErrInvalidAccountID = errors.New("caip: invalid account id")
+
+	// ErrUnsupportedChain is returned for chains without a known CAIP-2 ID.
+	ErrUnsupportedChain = errors.New("caip: unsupported chain")
+)
+
+// Syntax of CAIP-2 and CAIP-10
+var (
+	namespaceRe = regexp.MustCompile(`^[-a-z0-9]{3,8}$`)
+	referenceRe = regexp.MustCompile(`^[-_a-zA-Z0-9]{1,32}$`)
+	addressRe   = regexp.MustCompile(`^[-.%a-zA-Z0-9]{1,128}$`)
+)
+
+// ChainID is a CAIP-2 chain ID.
+type ChainID struct {
+	// Namespace is the chain family, such as eip155, bip122 or cosmos.
+	Namespace string
+
+	// Reference identifies the chain in its namespace: the EIP-155 chain ID,
+	// the start of the genesis block hash, or the Cosmos chain-id.
+	Reference string
+}
+
+// Mainnet CAIP-2 IDs of the chains in this library. Bitcoin-family and
+// Solana references are the first 32 characters of the genesis block hash,
+// Polkadot's the first 32 hex digits of its genesis hash.
+var chainIDs = map[address.ChainID]ChainID{
+	address.ChainEthereum:        {"eip155", "1"},
+	address.ChainOptimism:        {"eip155", "10"},
+	address.ChainBSC:             {"eip155", "56"},
+	address.ChainEthereumClassic: {"eip155", "61"},
+	address.ChainPolygon:         {"eip155", "137"},
+	address.ChainFantom:          {"eip155", "250"},
+	address.ChainTheta:           {"eip155", "361"},
+	address.ChainRonin:           {"eip155", "2020"},
+	address.ChainArbitrum:        {"eip155", "42161"},
+	address.ChainAvalanche:       {"eip155", "43114"},
+	address.ChainHarmony:         {"eip155", "1666600000"},
+
+	address.ChainBitcoin:     {"bip122", "000000000019d6689c085ae165831e93"},
+	address.ChainLitecoin:    {"bip122", "12a765e31ffd4059bada1e25190f6e98"},
+	address.ChainDogecoin:    {"bip122", "1a91e3dace36e2be3bf030a65679fe82"},
+	address.ChainBitcoinCash: {"bip122", "000000000000000000651ef99cb9fcbe"},
+
+	address.ChainCosmos:      {"cosmos", "cosmoshub-4"},
+	address.ChainSei:         {"cosmos", "pacific-1"},
+	address.ChainBinanceBEP2: {"cosmos", "Binance-Chain-Tigris"},
+
+	address.ChainSolana:   {"solana", "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"},
+	address.ChainPolkadot: {"polkadot", "91b171bb158e2d3848fa23a9f1c25182"},
+	address.ChainStellar:  {"stellar", "pubnet"},
+	address.ChainTezos:    {"tezos", "NetXdQprcVkpaWU"},
+	address.ChainNEAR:     {"near", "mainnet"},
+	address.ChainSui:      {"sui", "mainnet"},
+}
+
+// ForChain returns the CAIP-2 ID of a chain's mainnet.
+func ForChain(chain address.ChainID) (ChainID, error) {
+	id, ok := chainIDs[chain]
+	if !ok {
+		return ChainID{}, fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+	}
+	return id, nil
+}
+
+// ParseChainID parses a CAIP-2 chain ID such as "eip155:1".
+func ParseChainID(s string) (ChainID, error) {
+	namespace, reference, ok := strings.Cut(s, ":")
+	if !ok || !namespaceRe.MatchString(namespace) || !referenceRe.MatchString(reference) {
+		return ChainID{}, fmt.Errorf("%w: %q", ErrInvalidChainID, s)
+	}
+	return ChainID{Namespace: namespace, Reference: reference}, nil
+}
+
+// String returns the chain ID as namespace:reference.
+func (c ChainID) String() string {
+	return c.Namespace + ":" + c.Reference
+}
+
+// Chain returns the library's chain for the ID, or false if the ID is not
+// the mainnet of a supported chain.
+func (c ChainID) Chain() (address.ChainID, bool) {
+	for chain, id := range chainIDs {
+		if id == c {
+			return chain, true
+		}
+	}
+	return "", false
+}
+
+// MarshalText returns the chain ID as namespace:reference.
+func (c ChainID) MarshalText() ([]byte, error) {
+	return []byte(c.String()), nil
+}
+
+// UnmarshalText parses a chain ID with ParseChainID.
+func (c *ChainID) UnmarshalText(text []byte) error {
+	parsed, err := ParseChainID(string(text))
+	if err != nil {
+		return err
+	}
+	*c = parsed
+	return nil
+}
+
+// AccountID is a CAIP-10 account ID: an address qualified by its chain.
+type AccountID struct {
+	ChainID ChainID
+	Address string
+}
+
+// ForAccount returns the CAIP-10 ID of an account's default address on its
+// chain's mainnet, such as
+// eip155:1:0x9858EfFD232B4033E47d90003D41EC34EcaEda94.
+func ForAccount(acc account.WatchAccount) (AccountID, error) {
+	chainID, err := ForChain(acc.Chain())
+	if err != nil {
+		return AccountID{}, err
+	}
+	return NewAccountID(chainID, acc.Address())
+}
+
+// NewAccountID returns the CAIP-10 ID of an address on a chain.
+func NewAccountID(chainID ChainID, addr string) (AccountID, error) {
+	if !addressRe.MatchString(addr) {
+		return AccountID{}, fmt.Errorf("%w: address %q", ErrInvalidAccountID, addr)
+	}
+	return AccountID{ChainID: chainID, Address: addr}, nil
+}
+
+// ParseAccountID parses a CAIP-10 account ID such as
+// "cosmos:cosmoshub-4:cosmos1...". When the chain is supported the address
+// must also be valid for it.
+func ParseAccountID(s string) (AccountID, error) {
+	i := strings.LastIndexByte(s, ':')
+	if i < 0 {
+		return AccountID{}, fmt.Errorf("%w: %q", ErrInvalidAccountID, s)
+	}
+	chainID, err := ParseChainID(s[:i])
+	if err != nil {
+		return AccountID{}, fmt.Errorf("%w: %q", ErrInvalidAccountID, s)
+	}
+	id, err := NewAccountID(chainID, s[i+1:])
+	if err != nil {
+		return AccountID{}, err
+	}
+	if chain, ok := chainID.Chain(); ok {
+		if err := address.ValidateAddress(chain, id.Address); err != nil && !errors.Is(err, address.ErrUnsupportedChain) {
+			return AccountID{}, fmt.Errorf("%w: %w", ErrInvalidAccountID, err)
+		}
+	}
+	return id, nil
+}
+
+// String returns the account ID as chain_id:address.
+func (a AccountID) String() string {
+	return a.ChainID.String() + ":" + a.Address
+}
+
+// MarshalText returns the account ID as chain_id:address.
+func (a AccountID) MarshalText() ([]byte, error) {
+	return []byte(a.String()), nil
+}
+
+// UnmarshalText parses an account ID with ParseAccountID.
+func (a *AccountID) UnmarshalText(text []byte) error {
+	parsed, err := ParseAccountID(string(text))
+	if err != nil {
+		return err
+	}
+	*a = parsed
+	return nil
+}
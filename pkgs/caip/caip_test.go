@@ -0,0 +1,92 @@
+package caip
+
+import (
+	"encoding/json"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+func TestChainID(t *testing.T) {
+	for chain, want := range map[address.ChainID]string{
+		address.ChainEthereum: "eip155:1",
+		address.ChainPolygon:  "eip155:137",
+		address.ChainBitcoin:  "bip122:000000000019d6689c085ae165831e93",
+		address.ChainCosmos:   "cosmos:cosmoshub-4",
+		address.ChainSolana:   "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+	} {
+		id, err := ForChain(chain)
+		if err != nil || id.String() != want {
+			t.Errorf("ForChain(%s) = %s, %v, want %s", chain, id, err, want)
+		}
+		parsed, err := ParseChainID(want)
+		if err != nil || parsed != id {
+			t.Errorf("ParseChainID(%s) = %+v, %v", want, parsed, err)
+		}
+		if got, ok := parsed.Chain(); !ok || got != chain {
+			t.Errorf("Chain() = %s, %v, want %s", got, ok, chain)
+		}
+	}
+
+	if _, err := ForChain(address.ChainMonero); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("ForChain(xmr) error = %v, want ErrUnsupportedChain", err)
+	}
+	if _, ok := (ChainID{"eip155", "11155111"}).Chain(); ok {
+		t.Error("Chain() found Sepolia")
+	}
+	for _, s := range []string{"", "eip155", "eip155:", "EIP155:1", "ab:1", "eip155:1:2", "cosmos:" + strings.Repeat("a", 33)} {
+		if _, err := ParseChainID(s); !errors.Is(err, ErrInvalidChainID) {
+			t.Errorf("ParseChainID(%q) error = %v, want ErrInvalidChainID", s, err)
+		}
+	}
+}
+
+func TestAccountID(t *testing.T) {
+	eth, err := account.NewEVMAccountFromMnemonic(testMnemonic, "", address.ChainEthereum, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	id, err := ForAccount(eth)
+	const want = "eip155:1:0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+	if err != nil || id.String() != want {
+		t.Fatalf("ForAccount() = %s, %v, want %s", id, err, want)
+	}
+
+	parsed, err := ParseAccountID(want)
+	if err != nil || parsed != id {
+		t.Errorf("ParseAccountID() = %+v, %v", parsed, err)
+	}
+	cosmos := "cosmos:cosmoshub-4:cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4"
+	if parsed, err := ParseAccountID(cosmos); err != nil || parsed.Address != "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4" {
+		t.Errorf("ParseAccountID(%s) = %+v, %v", cosmos, parsed, err)
+	}
+
+	// Unknown chains are accepted unchecked; known ones validate the address
+	if _, err := ParseAccountID("eip155:11155111:0xabc"); err != nil {
+		t.Errorf("ParseAccountID(sepolia) error = %v", err)
+	}
+	for _, s := range []string{
+		"eip155:1:0x9858eFFD232B4033E47d90003D41EC34EcaEda94", // bad EIP-55 checksum
+		"eip155:1",
+		"eip155:1:",
+		"eip155:1:0x98 58",
+	} {
+		if _, err := ParseAccountID(s); !errors.Is(err, ErrInvalidAccountID) {
+			t.Errorf("ParseAccountID(%q) error = %v, want ErrInvalidAccountID", s, err)
+		}
+	}
+
+	data, err := json.Marshal(struct{ Account AccountID }{id})
+	if err != nil || string(data) != `{"Account":"`+want+`"}` {
+		t.Errorf("json.Marshal() = %s, %v", data, err)
+	}
+	var decoded struct{ Account AccountID }
+	if err := json.Unmarshal(data, &decoded); err != nil || decoded.Account != id {
+		t.Errorf("json.Unmarshal() = %+v, %v", decoded, err)
+	}
+}
@@ -0,0 +1,160 @@
+// Package capabilities reports what the library supports in the current
+// build: the chains left in by the ca_no_* build tags, the address types of
+// each, and the signature and key derivation schemes. Tools and UIs built on
+// the library can list these instead of hardcoding them.
+package capabilities
+
+import (
+	"slices"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// Derivation names a scheme for deriving keys from a BIP-39 seed.
+type Derivation string
+
+// Derivation schemes used by account.Wallet
+const (
+	// DerivationBIP32 is BIP-32 on secp256k1.
+	DerivationBIP32 Derivation = "bip32-secp256k1"
+
+	// DerivationSLIP10Ed25519 is SLIP-10 on Ed25519, hardened levels only.
+	DerivationSLIP10Ed25519 Derivation = "slip10-ed25519"
+
+	// DerivationSLIP10P256 is SLIP-10 on NIST P-256.
+	DerivationSLIP10P256 Derivation = "slip10-nist256p1"
+
+	// DerivationStarkGrind is BIP-32 on secp256k1 followed by grinding the
+	// key into the Stark curve order.
+	DerivationStarkGrind Derivation = "bip32-stark-grind"
+)
+
+// Curve names, as used in Chain.Curve
+const (
+	CurveSecp256k1 = "secp256k1"
+	CurveEd25519   = "ed25519"
+	CurveP256      = "p256"
+	CurveStark     = "stark"
+)
+
+// Capabilities is everything the current build supports.
+type Capabilities struct {
+	Chains []Chain `json:"chains"`
+
+	// SignatureSchemes are the schemes signer.New accepts.
+	SignatureSchemes []signer.Scheme `json:"signature_schemes"`
+
+	// Derivations are the seed derivation schemes of the wallet chains.
+	Derivations []Derivation `json:"derivations"`
+
+	// Secp256k1Backend is the secp256k1 implementation compiled in, "go" or
+	// "libsecp256k1" (see the ca_libsecp256k1 build tag).
+	Secp256k1Backend string `json:"secp256k1_backend"`
+}
+
+// Chain describes one supported chain.
+type Chain struct {
+	ID     address.ChainID `json:"id"`
+	Name   string          `json:"name"`
+	Symbol string          `json:"symbol,omitempty"`
+
+	// AddressFormat is the encoding, as in address.ChainInfo.AddressType.
+	AddressFormat string `json:"address_format,omitempty"`
+
+	// AddressTypes lists the address kinds the address package can build
+	// for chains that have more than one, such as "p2wpkh" and "p2tr".
+	AddressTypes []string `json:"address_types,omitempty"`
+
+	// Wallet is set when account.Wallet derives accounts on the chain;
+	// Curve and Derivation are only filled in for those chains.
+	Wallet     bool       `json:"wallet"`
+	Curve      string     `json:"curve,omitempty"`
+	Derivation Derivation `json:"derivation,omitempty"`
+}
+
+// addressTypes are the address kinds of chains with more than one.
+var addressTypes = map[address.ChainID][]string{
+	address.ChainBitcoin:     {"p2pkh", "p2sh", "p2wpkh", "p2wsh", "p2tr"},
+	address.ChainLitecoin:    {"p2pkh", "p2sh", "p2wpkh"},
+	address.ChainDogecoin:    {"p2pkh", "p2sh"},
+	address.ChainBitcoinCash: {"p2pkh", "p2sh"},
+	address.ChainZcash:       {"p2pkh", "p2sh"},
+	address.ChainCosmos:      {"account", "validator", "consensus"},
+	address.ChainBinanceBEP2: {"account", "validator", "consensus"},
+	address.ChainSei:         {"account", "validator", "consensus"},
+	address.ChainHarmony:     {"bech32", "evm"},
+}
+
+// Get returns the capabilities of the current build. Chains are in
+// address.ListAllChainInfo order, followed by wallet chains without an
+// address generator, such as Nostr.
+func Get() Capabilities {
+	walletChains := account.WalletChains()
+
+	var chains []Chain
+	seen := make(map[address.ChainID]bool)
+	for _, info := range address.ListAllChainInfo() {
+		chains = append(chains, newChain(info.ID, walletChains))
+		seen[info.ID] = true
+	}
+	for _, id := range walletChains {
+		if !seen[id] {
+			chains = append(chains, newChain(id, walletChains))
+		}
+	}
+
+	return Capabilities{
+		Chains: chains,
+		SignatureSchemes: []signer.Scheme{
+			signer.SchemeSecp256k1ECDSA,
+			signer.SchemeSecp256k1Schnorr,
+			signer.SchemeEd25519,
+		},
+		Derivations: []Derivation{
+			DerivationBIP32,
+			DerivationSLIP10Ed25519,
+			DerivationSLIP10P256,
+			DerivationStarkGrind,
+		},
+		Secp256k1Backend: secp256k1.Backend(),
+	}
+}
+
+// Chain returns the capabilities of one chain, or false if the chain is not
+// in the build.
+func (c Capabilities) Chain(id address.ChainID) (Chain, bool) {
+	i := slices.IndexFunc(c.Chains, func(chain Chain) bool { return chain.ID == id })
+	if i < 0 {
+		return Chain{}, false
+	}
+	return c.Chains[i], true
+}
+
+func newChain(id address.ChainID, walletChains []address.ChainID) Chain {
+	chain := Chain{ID: id, Name: string(id), AddressTypes: addressTypes[id]}
+	if info := address.GetChainInfo(id); info != nil {
+		chain.Name, chain.Symbol, chain.AddressFormat = info.Name, info.Symbol, info.AddressType
+	}
+	if slices.Contains(walletChains, id) {
+		chain.Wallet = true
+		chain.Curve, chain.Derivation = walletScheme(id)
+	}
+	return chain
+}
+
+// walletScheme returns the curve and derivation account.Wallet uses on a chain.
+func walletScheme(id address.ChainID) (string, Derivation) {
+	switch id {
+	case address.ChainSolana, address.ChainSui, address.ChainStellar:
+		return CurveEd25519, DerivationSLIP10Ed25519
+	case address.ChainNeo:
+		return CurveP256, DerivationSLIP10P256
+	case address.ChainStarknet:
+		return CurveStark, DerivationStarkGrind
+	default:
+		return CurveSecp256k1, DerivationBIP32
+	}
+}
@@ -0,0 +1,62 @@
+package capabilities
+
+import (
+	"encoding/json"
+	"slices"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+func TestGet(t *testing.T) {
+	c := Get()
+	if len(c.Chains) != len(address.ListAllChainInfo())+1 {
+		t.Errorf("len(Chains) = %d, want every chain info plus Nostr", len(c.Chains))
+	}
+
+	for _, tt := range []struct {
+		id         address.ChainID
+		wallet     bool
+		curve      string
+		derivation Derivation
+	}{
+		{address.ChainBitcoin, true, CurveSecp256k1, DerivationBIP32},
+		{address.ChainPolygon, true, CurveSecp256k1, DerivationBIP32},
+		{address.ChainSolana, true, CurveEd25519, DerivationSLIP10Ed25519},
+		{address.ChainNeo, true, CurveP256, DerivationSLIP10P256},
+		{address.ChainStarknet, true, CurveStark, DerivationStarkGrind},
+		{address.ChainNostr, true, CurveSecp256k1, DerivationBIP32},
+		{address.ChainMonero, false, "", ""},
+	} {
+		chain, ok := c.Chain(tt.id)
+		if !ok {
+			t.Errorf("Chain(%s) not found", tt.id)
+			continue
+		}
+		if chain.Wallet != tt.wallet || chain.Curve != tt.curve || chain.Derivation != tt.derivation {
+			t.Errorf("Chain(%s) = %+v", tt.id, chain)
+		}
+	}
+
+	btc, _ := c.Chain(address.ChainBitcoin)
+	if btc.Name != "Bitcoin" || !slices.Contains(btc.AddressTypes, "p2tr") {
+		t.Errorf("Chain(btc) = %+v", btc)
+	}
+	if _, ok := c.Chain("nope"); ok {
+		t.Error("Chain(nope) found")
+	}
+	if !slices.Contains(c.SignatureSchemes, signer.SchemeSecp256k1Schnorr) {
+		t.Errorf("SignatureSchemes = %v", c.SignatureSchemes)
+	}
+	if c.Secp256k1Backend != secp256k1.Backend() {
+		t.Errorf("Secp256k1Backend = %q, want %q", c.Secp256k1Backend, secp256k1.Backend())
+	}
+
+	data, err := json.Marshal(c)
+	if err != nil || !strings.Contains(string(data), `"derivation":"slip10-ed25519"`) {
+		t.Errorf("json.Marshal() = %s, %v", data, err)
+	}
+}
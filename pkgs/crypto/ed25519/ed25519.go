@@ -5,6 +5,7 @@ package ed25519
 import (
 	"crypto/ed25519"
 	"crypto/sha512"
+	"crypto/x509"
 	"errors"
 )
 
@@ -72,6 +73,29 @@ func Verify(publicKey, message, signature []byte) bool {
 	return ed25519.Verify(publicKey, message, signature)
 }
 
+// MarshalPKCS8PrivateKey encodes a 32-byte private key (seed) as a DER
+// PKCS#8 PrivateKeyInfo (RFC 8410), as OpenSSL writes Ed25519 keys.
+func MarshalPKCS8PrivateKey(privateKey []byte) ([]byte, error) {
+	if len(privateKey) != PrivateKeySize {
+		return nil, ErrInvalidPrivateKey
+	}
+	return x509.MarshalPKCS8PrivateKey(ed25519.NewKeyFromSeed(privateKey))
+}
+
+// ParsePKCS8PrivateKey decodes a DER PKCS#8 Ed25519 private key and returns
+// the 32-byte seed.
+func ParsePKCS8PrivateKey(der []byte) ([]byte, error) {
+	key, err := x509.ParsePKCS8PrivateKey(der)
+	if err != nil {
+		return nil, err
+	}
+	privateKey, ok := key.(ed25519.PrivateKey)
+	if !ok {
+		return nil, ErrInvalidPrivateKey
+	}
+	return privateKey.Seed(), nil
+}
+
 // GenerateKeyPair generates a new Ed25519 key pair from a seed.
 // Returns (publicKey, expandedPrivateKey, error)
 func GenerateKeyPair(seed []byte) ([]byte, []byte, error) {
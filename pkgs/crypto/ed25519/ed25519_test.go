@@ -1,7 +1,9 @@
 package ed25519
 
 import (
+	"crypto/sha512"
 	"encoding/hex"
+	"math/big"
 	"testing"
 )
 
@@ -117,3 +119,60 @@ func TestInvalidInputs(t *testing.T) {
 		t.Error("Should fail with invalid signature size")
 	}
 }
+
+func TestScalarBaseMultMatchesStdlib(t *testing.T) {
+	privateKey, _ := hex.DecodeString("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+
+	// RFC 8032 clamping of SHA-512(seed)[:32] gives the signing scalar
+	h := sha512.Sum512(privateKey)
+	scalar := h[:32]
+	scalar[0] &= 248
+	scalar[31] &= 127
+	scalar[31] |= 64
+
+	point := ScalarBaseMult(scalar)
+	want, _ := PrivateKeyToPublicKey(privateKey)
+	if hex.EncodeToString(point.Bytes()) != hex.EncodeToString(want) {
+		t.Errorf("ScalarBaseMult() = %x, want %x", point.Bytes(), want)
+	}
+
+	decoded, err := DecodePoint(want)
+	if err != nil {
+		t.Fatalf("DecodePoint() error = %v", err)
+	}
+	if !decoded.Equal(point) {
+		t.Error("DecodePoint() round trip mismatch")
+	}
+
+	// l * B is the identity
+	if !ScalarMult(BasePoint(), Order()).Equal(Identity()) {
+		t.Error("L * B should be the identity")
+	}
+
+	// Order hands out a copy
+	Order().SetInt64(1)
+	if Order().Cmp(big.NewInt(1)) == 0 {
+		t.Error("Order() returned the package's own value")
+	}
+}
+
+func TestPKCS8(t *testing.T) {
+	// RFC 8410 section 10.3
+	seed := "d4ee72dbf913584ad5b6d8f1f769f8ad3afe7c28cbf1d4fbe097a88f44755842"
+	want := "302e020100300506032b657004220420" + seed
+
+	privateKey, _ := hex.DecodeString(seed)
+	der, err := MarshalPKCS8PrivateKey(privateKey)
+	if err != nil || hex.EncodeToString(der) != want {
+		t.Errorf("MarshalPKCS8PrivateKey() = %x, %v, want %s", der, err, want)
+	}
+
+	parsed, err := ParsePKCS8PrivateKey(der)
+	if err != nil || hex.EncodeToString(parsed) != seed {
+		t.Errorf("ParsePKCS8PrivateKey() = %x, %v", parsed, err)
+	}
+
+	if _, err := MarshalPKCS8PrivateKey([]byte("short")); err != ErrInvalidPrivateKey {
+		t.Errorf("MarshalPKCS8PrivateKey(short) error = %v, want ErrInvalidPrivateKey", err)
+	}
+}
@@ -0,0 +1,200 @@
+package ed25519
+
+import (
+	"errors"
+	"math/big"
+)
+
+// Edwards25519 curve parameters: -x^2 + y^2 = 1 + d*x^2*y^2 over GF(2^255 - 19).
+// They are unexported so no caller can modify them; Order returns a copy of L.
+var (
+	// fieldP is the prime P of the field of the curve
+	fieldP, _ = new(big.Int).SetString("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed", 16)
+
+	// order is the order L of the prime-order subgroup generated by the base point
+	order, _ = new(big.Int).SetString("1000000000000000000000000000000014def9dea2f79cd65812631a5cf5d3ed", 16)
+
+	// curveD is the curve constant d = -121665/121666 mod P
+	curveD, _ = new(big.Int).SetString("52036cee2b6ffe738cc740797779e89800700a4d4141d8ab75eb4dca135978a3", 16)
+
+	// baseX is the x-coordinate of the base point
+	baseX, _ = new(big.Int).SetString("216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a", 16)
+
+	// baseY is the y-coordinate of the base point (4/5 mod P)
+	baseY, _ = new(big.Int).SetString("6666666666666666666666666666666666666666666666666666666666666658", 16)
+
+	// sqrtM1 is a square root of -1 mod P (2^((P-1)/4))
+	sqrtM1, _ = new(big.Int).SetString("2b8324804fc1df0b2b4d00993dfbd7a72f431806ad2fe478c4ee1b274a0ea0b0", 16)
+)
+
+// Order returns a copy of L, the order of the prime-order subgroup generated
+// by the base point.
+func Order() *big.Int {
+	return new(big.Int).Set(order)
+}
+
+// ErrInvalidPoint indicates the encoding is not a point on the curve.
+var ErrInvalidPoint = errors.New("invalid point encoding")
+
+// Point represents a point on the Edwards25519 curve in affine coordinates.
+type Point struct {
+	X, Y *big.Int
+}
+
+// BasePoint returns the standard base point B.
+func BasePoint() *Point {
+	return &Point{
+		X: new(big.Int).Set(baseX),
+		Y: new(big.Int).Set(baseY),
+	}
+}
+
+// Identity returns the neutral element (0, 1).
+func Identity() *Point {
+	return &Point{
+		X: big.NewInt(0),
+		Y: big.NewInt(1),
+	}
+}
+
+// Equal returns true if two points are equal.
+func (p *Point) Equal(other *Point) bool {
+	return p.X.Cmp(other.X) == 0 && p.Y.Cmp(other.Y) == 0
+}
+
+// Add performs point addition using the complete twisted Edwards formula:
+// x3 = (x1*y2 + y1*x2) / (1 + d*x1*x2*y1*y2)
+// y3 = (y1*y2 + x1*x2) / (1 - d*x1*x2*y1*y2)
+func Add(p1, p2 *Point) *Point {
+	x1y2 := new(big.Int).Mul(p1.X, p2.Y)
+	y1x2 := new(big.Int).Mul(p1.Y, p2.X)
+	y1y2 := new(big.Int).Mul(p1.Y, p2.Y)
+	x1x2 := new(big.Int).Mul(p1.X, p2.X)
+
+	dxy := new(big.Int).Mul(x1x2, y1y2)
+	dxy.Mul(dxy, curveD)
+	dxy.Mod(dxy, fieldP)
+
+	xNum := new(big.Int).Add(x1y2, y1x2)
+	xDen := new(big.Int).Add(big.NewInt(1), dxy)
+	x3 := xNum.Mul(xNum, new(big.Int).ModInverse(xDen, fieldP))
+	x3.Mod(x3, fieldP)
+
+	yNum := new(big.Int).Add(y1y2, x1x2)
+	yDen := new(big.Int).Sub(big.NewInt(1), dxy)
+	yDen.Mod(yDen, fieldP)
+	y3 := yNum.Mul(yNum, new(big.Int).ModInverse(yDen, fieldP))
+	y3.Mod(y3, fieldP)
+
+	return &Point{X: x3, Y: y3}
+}
+
+// ScalarMult performs scalar multiplication k * P using double-and-add.
+func ScalarMult(p *Point, k *big.Int) *Point {
+	result := Identity()
+	addend := &Point{X: new(big.Int).Set(p.X), Y: new(big.Int).Set(p.Y)}
+
+	for i := 0; i < k.BitLen(); i++ {
+		if k.Bit(i) == 1 {
+			result = Add(result, addend)
+		}
+		addend = Add(addend, addend)
+	}
+
+	return result
+}
+
+// ScalarBaseMult computes k * B for a 32-byte little-endian scalar.
+func ScalarBaseMult(k []byte) *Point {
+	return ScalarMult(BasePoint(), ScalarFromBytes(k))
+}
+
+// Bytes returns the 32-byte encoding: little-endian y with the sign of x in the top bit.
+func (p *Point) Bytes() []byte {
+	out := littleEndian(p.Y, 32)
+	if p.X.Bit(0) == 1 {
+		out[31] |= 0x80
+	}
+	return out
+}
+
+// DecodePoint parses a 32-byte point encoding.
+func DecodePoint(encoded []byte) (*Point, error) {
+	if len(encoded) != 32 {
+		return nil, ErrInvalidPoint
+	}
+
+	buf := make([]byte, 32)
+	copy(buf, encoded)
+	sign := buf[31] >> 7
+	buf[31] &= 0x7F
+
+	y := ScalarFromBytes(buf)
+	if y.Cmp(fieldP) >= 0 {
+		return nil, ErrInvalidPoint
+	}
+
+	// x^2 = (y^2 - 1) / (d*y^2 + 1)
+	y2 := new(big.Int).Mul(y, y)
+	y2.Mod(y2, fieldP)
+	u := new(big.Int).Sub(y2, big.NewInt(1))
+	u.Mod(u, fieldP)
+	v := new(big.Int).Mul(curveD, y2)
+	v.Add(v, big.NewInt(1))
+	v.Mod(v, fieldP)
+	x2 := u.Mul(u, new(big.Int).ModInverse(v, fieldP))
+	x2.Mod(x2, fieldP)
+
+	// Candidate root x = x2^((P+3)/8); fix up with sqrt(-1) if needed
+	exp := new(big.Int).Add(fieldP, big.NewInt(3))
+	exp.Rsh(exp, 3)
+	x := new(big.Int).Exp(x2, exp, fieldP)
+
+	check := new(big.Int).Mul(x, x)
+	check.Mod(check, fieldP)
+	if check.Cmp(x2) != 0 {
+		x.Mul(x, sqrtM1)
+		x.Mod(x, fieldP)
+		check.Mul(x, x)
+		check.Mod(check, fieldP)
+		if check.Cmp(x2) != 0 {
+			return nil, ErrInvalidPoint
+		}
+	}
+
+	if x.Sign() == 0 && sign == 1 {
+		return nil, ErrInvalidPoint
+	}
+	if uint(x.Bit(0)) != uint(sign) {
+		x.Sub(fieldP, x)
+	}
+
+	return &Point{X: x, Y: y}, nil
+}
+
+// ScalarFromBytes interprets a byte slice as a little-endian integer.
+func ScalarFromBytes(b []byte) *big.Int {
+	reversed := make([]byte, len(b))
+	for i := range b {
+		reversed[len(b)-1-i] = b[i]
+	}
+	return new(big.Int).SetBytes(reversed)
+}
+
+// ScalarReduce reduces a little-endian byte string modulo L and returns
+// the 32-byte little-endian result (Monero's sc_reduce32 for 32-byte input).
+func ScalarReduce(b []byte) []byte {
+	k := ScalarFromBytes(b)
+	k.Mod(k, order)
+	return littleEndian(k, 32)
+}
+
+// littleEndian encodes a non-negative integer as a fixed-size little-endian byte slice.
+func littleEndian(n *big.Int, size int) []byte {
+	be := n.Bytes()
+	out := make([]byte, size)
+	for i := 0; i < len(be) && i < size; i++ {
+		out[i] = be[len(be)-1-i]
+	}
+	return out
+}
@@ -3,7 +3,6 @@ package encoding
 
 import (
 	"errors"
-	"math/big"
 
 	"github.com/study/crypto-accounts/pkgs/crypto/hash"
 )
@@ -17,90 +16,70 @@ var (
 	ErrInvalidDataLength = errors.New("invalid data length")
 )
 
-// alphabetMap maps characters to their base58 values
-var alphabetMap = func() map[byte]int64 {
-	m := make(map[byte]int64)
-	for i, c := range base58Alphabet {
-		m[byte(c)] = int64(i)
-	}
-	return m
-}()
+// Base58Alphabet is a Base58 digit ordering. Bitcoin's is the default; Ripple
+// and Flickr use their own.
+type Base58Alphabet struct {
+	chars  string
+	values [256]int8
+}
 
-// Base58Encode encodes bytes to a Base58 string.
-func Base58Encode(input []byte) string {
-	if len(input) == 0 {
-		return ""
+// NewBase58Alphabet creates an alphabet from 58 distinct ASCII characters.
+// The first character encodes leading zero bytes.
+func NewBase58Alphabet(chars string) *Base58Alphabet {
+	if len(chars) != 58 {
+		panic("encoding: base58 alphabet must have 58 characters")
 	}
-
-	// Count leading zeros
-	leadingZeros := countLeadingZeros(input)
-
-	// Convert to big integer
-	num := new(big.Int).SetBytes(input)
-	base := big.NewInt(58)
-	zero := big.NewInt(0)
-	mod := new(big.Int)
-
-	// Convert to base58
-	var result []byte
-	for num.Cmp(zero) > 0 {
-		num.DivMod(num, base, mod)
-		result = append(result, base58Alphabet[mod.Int64()])
+	a := &Base58Alphabet{chars: chars}
+	for i := range a.values {
+		a.values[i] = -1
 	}
-
-	// Add leading '1's for each leading zero byte
-	for i := 0; i < leadingZeros; i++ {
-		result = append(result, '1')
+	for i := 0; i < len(chars); i++ {
+		a.values[chars[i]] = int8(i)
 	}
+	return a
+}
 
-	// Reverse the result
-	reverse(result)
+// BitcoinBase58 is the Bitcoin alphabet used by Base58Encode and Base58Decode.
+var BitcoinBase58 = NewBase58Alphabet(base58Alphabet)
 
-	return string(result)
+// Base58Encode encodes bytes to a Base58 string.
+func Base58Encode(input []byte) string {
+	return BitcoinBase58.Encode(input)
 }
 
 // Base58Decode decodes a Base58 string to bytes.
 func Base58Decode(input string) ([]byte, error) {
+	return BitcoinBase58.Decode(input)
+}
+
+// Encode encodes bytes to a Base58 string.
+func (a *Base58Alphabet) Encode(input []byte) string {
 	if len(input) == 0 {
-		return nil, nil
+		return ""
 	}
-
-	// Count leading '1's
-	leadingOnes := 0
-	for _, c := range input {
-		if c != '1' {
-			break
-		}
-		leadingOnes++
+	if base58Educational {
+		return a.encodeBigInt(input)
 	}
+	return a.encodeBytes(input)
+}
 
-	// Convert from base58 to big integer
-	num := big.NewInt(0)
-	base := big.NewInt(58)
-
-	for _, c := range input {
-		val, ok := alphabetMap[byte(c)]
-		if !ok {
-			return nil, ErrInvalidBase58
-		}
-		num.Mul(num, base)
-		num.Add(num, big.NewInt(val))
+// Decode decodes a Base58 string to bytes.
+func (a *Base58Alphabet) Decode(input string) ([]byte, error) {
+	if len(input) == 0 {
+		return nil, nil
 	}
-
-	// Convert to bytes
-	decoded := num.Bytes()
-
-	// Add leading zeros
-	result := make([]byte, leadingOnes+len(decoded))
-	copy(result[leadingOnes:], decoded)
-
-	return result, nil
+	if base58Educational {
+		return a.decodeBigInt(input)
+	}
+	return a.decodeBytes(input)
 }
 
 // Base58CheckEncode encodes bytes with a 4-byte checksum appended.
 func Base58CheckEncode(input []byte) string {
-	checksum := hash.Checksum(input)
-	return Base58Encode(append(input, checksum...))
+	data := make([]byte, 0, len(input)+4)
+	data = append(data, input...)
+	data = append(data, hash.Checksum(input)...)
+	return Base58Encode(data)
 }
 
 // Base58CheckDecode decodes a Base58Check string and verifies the checksum.
@@ -123,6 +102,28 @@ func Base58CheckDecode(input string) ([]byte, error) {
 	return decoded[:len(decoded)-4], nil
 }
 
+// Base58CheckEncodeVersion encodes version || payload with a 4-byte checksum,
+// as used for addresses (1-byte version), WIF keys and extended keys (4 bytes).
+func Base58CheckEncodeVersion(version, payload []byte) string {
+	data := make([]byte, 0, len(version)+len(payload))
+	data = append(data, version...)
+	data = append(data, payload...)
+	return Base58CheckEncode(data)
+}
+
+// Base58CheckDecodeVersion decodes a Base58Check string, verifies the checksum
+// and splits off a versionLen-byte version prefix.
+func Base58CheckDecodeVersion(input string, versionLen int) (version, payload []byte, err error) {
+	data, err := Base58CheckDecode(input)
+	if err != nil {
+		return nil, nil, err
+	}
+	if len(data) < versionLen {
+		return nil, nil, ErrInvalidDataLength
+	}
+	return data[:versionLen], data[versionLen:], nil
+}
+
 // Helper functions
 
 func countLeadingZeros(data []byte) int {
@@ -0,0 +1,85 @@
+package encoding
+
+import (
+	"fmt"
+	"math/big"
+)
+
+// Base58 by big-integer division
+//
+// This is the textbook form of the algorithm, kept for study: Encode uses it
+// when the library is built with -tags ca_base58_educational, and the tests
+// check it against the same vectors as the default implementation.
+//
+// Base58 reads the input bytes as one big-endian number and writes that
+// number in base 58, most significant digit first, using the alphabet's
+// characters as digits. For the two bytes 0x01 0x00:
+//
+//	0x0100 = 256 = 4*58 + 24   ->  digits [4, 24]  ->  "5R"
+//
+// Because leading zero bytes do not change the number, each one is written
+// as a separate leading "zero digit", the alphabet's first character ("1"
+// in Bitcoin's alphabet). That is why every version-0 Bitcoin address
+// starts with 1. Decoding reverses both steps.
+//
+// Each division by 58 walks the whole number, so the cost is quadratic in
+// the input length; that is fine for keys and addresses.
+
+// encodeBigInt encodes input by repeated division of a big.Int.
+func (a *Base58Alphabet) encodeBigInt(input []byte) string {
+	// Leading zero bytes carry no value; remember how many there were
+	leadingZeros := countLeadingZeros(input)
+
+	// Read all the bytes as one big-endian number
+	num := new(big.Int).SetBytes(input)
+	base := big.NewInt(58)
+	mod := new(big.Int)
+
+	// Each division yields the next digit, least significant first
+	var result []byte
+	for num.Sign() > 0 {
+		num.DivMod(num, base, mod)
+		result = append(result, a.chars[mod.Int64()])
+	}
+
+	// Add a leading zero digit for each leading zero byte
+	for i := 0; i < leadingZeros; i++ {
+		result = append(result, a.chars[0])
+	}
+
+	// Digits were produced in reverse; put the most significant first
+	reverse(result)
+
+	return string(result)
+}
+
+// decodeBigInt decodes input by multiply-and-add into a big.Int.
+func (a *Base58Alphabet) decodeBigInt(input string) ([]byte, error) {
+	// Count leading zero digits; each is one leading zero byte
+	leadingZeros := 0
+	for leadingZeros < len(input) && input[leadingZeros] == a.chars[0] {
+		leadingZeros++
+	}
+
+	// Horner's rule: num = num*58 + digit, most significant digit first
+	num := big.NewInt(0)
+	base := big.NewInt(58)
+
+	for i := 0; i < len(input); i++ {
+		val := a.values[input[i]]
+		if val < 0 {
+			return nil, fmt.Errorf("%w: invalid character %q", ErrInvalidBase58, input[i])
+		}
+		num.Mul(num, base)
+		num.Add(num, big.NewInt(int64(val)))
+	}
+
+	// Write the number back as big-endian bytes
+	decoded := num.Bytes()
+
+	// Restore the leading zero bytes
+	result := make([]byte, leadingZeros+len(decoded))
+	copy(result[leadingZeros:], decoded)
+
+	return result, nil
+}
@@ -0,0 +1,77 @@
+package encoding
+
+import "fmt"
+
+// encodeBytes is the default encoder. It computes the same digits as
+// encodeBigInt, but converts base 256 to base 58 in place in a digit
+// buffer, like Bitcoin Core, without allocating a big.Int per step.
+func (a *Base58Alphabet) encodeBytes(input []byte) string {
+	zeros := countLeadingZeros(input)
+
+	// log(256)/log(58) is about 1.37, so this many digits always suffice
+	size := (len(input)-zeros)*138/100 + 1
+	digits := make([]byte, size)
+	length := 0
+	for _, b := range input[zeros:] {
+		// digits = digits*256 + b, carrying from the least significant end
+		carry := int(b)
+		i := 0
+		for j := size - 1; (carry != 0 || i < length) && j >= 0; j-- {
+			carry += 256 * int(digits[j])
+			digits[j] = byte(carry % 58)
+			carry /= 58
+			i++
+		}
+		length = i
+	}
+
+	start := size - length
+	for start < size && digits[start] == 0 {
+		start++
+	}
+	result := make([]byte, zeros+size-start)
+	for i := 0; i < zeros; i++ {
+		result[i] = a.chars[0]
+	}
+	for i, d := range digits[start:] {
+		result[zeros+i] = a.chars[d]
+	}
+	return string(result)
+}
+
+// decodeBytes is the default decoder, the inverse of encodeBytes.
+func (a *Base58Alphabet) decodeBytes(input string) ([]byte, error) {
+	zeros := 0
+	for zeros < len(input) && input[zeros] == a.chars[0] {
+		zeros++
+	}
+
+	// log(58)/log(256) is about 0.733
+	size := (len(input)-zeros)*733/1000 + 1
+	decoded := make([]byte, size)
+	length := 0
+	for i := zeros; i < len(input); i++ {
+		val := a.values[input[i]]
+		if val < 0 {
+			return nil, fmt.Errorf("%w: invalid character %q", ErrInvalidBase58, input[i])
+		}
+		// decoded = decoded*58 + val
+		carry := int(val)
+		j := 0
+		for k := size - 1; (carry != 0 || j < length) && k >= 0; k-- {
+			carry += 58 * int(decoded[k])
+			decoded[k] = byte(carry)
+			carry >>= 8
+			j++
+		}
+		length = j
+	}
+
+	start := size - length
+	for start < size && decoded[start] == 0 {
+		start++
+	}
+	result := make([]byte, zeros+size-start)
+	copy(result[zeros:], decoded[start:])
+	return result, nil
+}
@@ -3,6 +3,7 @@ package encoding
 import (
 	"bytes"
 	"encoding/hex"
+	"errors"
 	"testing"
 )
 
@@ -351,3 +352,114 @@ func TestLeadingZeroPreservation(t *testing.T) {
 		}
 	}
 }
+
+func TestBase58CheckVersion(t *testing.T) {
+	// Uncompressed WIF from the Bitcoin wiki
+	const wif = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"
+	key, _ := hex.DecodeString("0c28fca386c7a227600b2fe50b7cae11ec86d3bf1f0fe471be89827e19d72aa1d")
+
+	if got := Base58CheckEncodeVersion([]byte{0x80}, key); got != wif {
+		t.Errorf("Base58CheckEncodeVersion() = %s, want %s", got, wif)
+	}
+
+	version, payload, err := Base58CheckDecodeVersion(wif, 1)
+	if err != nil {
+		t.Fatalf("Base58CheckDecodeVersion() error = %v", err)
+	}
+	if !bytes.Equal(version, []byte{0x80}) || !bytes.Equal(payload, key) {
+		t.Errorf("Base58CheckDecodeVersion() = %x %x", version, payload)
+	}
+
+	if _, _, err := Base58CheckDecodeVersion(wif[:len(wif)-1]+"K", 1); !errors.Is(err, ErrInvalidChecksum) {
+		t.Errorf("Base58CheckDecodeVersion(tampered) error = %v, want ErrInvalidChecksum", err)
+	}
+	if _, _, err := Base58CheckDecodeVersion(Base58CheckEncode([]byte{1, 2}), 4); !errors.Is(err, ErrInvalidDataLength) {
+		t.Errorf("Base58CheckDecodeVersion(short) error = %v, want ErrInvalidDataLength", err)
+	}
+
+	// Encoding must not write into the caller's spare capacity
+	input := make([]byte, 2, 8)
+	Base58CheckEncode(input)
+	if extra := input[:4]; !bytes.Equal(extra[2:], []byte{0, 0}) {
+		t.Error("Base58CheckEncode() modified the input's backing array")
+	}
+}
+
+func TestBase58Alphabet(t *testing.T) {
+	ripple := NewBase58Alphabet("rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz")
+
+	data := []byte{0, 0, 0x12, 0x34}
+	encoded := ripple.Encode(data)
+	if encoded[:2] != "rr" {
+		t.Errorf("Encode() = %s, want leading zeros as 'r'", encoded)
+	}
+	decoded, err := ripple.Decode(encoded)
+	if err != nil || !bytes.Equal(decoded, data) {
+		t.Errorf("Decode(%s) = %x, %v", encoded, decoded, err)
+	}
+
+	if _, err := ripple.Decode("r0"); !errors.Is(err, ErrInvalidBase58) {
+		t.Errorf("Decode(r0) error = %v, want ErrInvalidBase58", err)
+	}
+}
+
+// TestBase58Implementations checks the big-integer and byte-buffer
+// implementations against each other, whichever one the build selects.
+func TestBase58Implementations(t *testing.T) {
+	// From Bitcoin Core's base58_encode_decode.json
+	vectors := []struct {
+		hex, base58 string
+	}{
+		{"61", "2g"},
+		{"626262", "a3gV"},
+		{"636363", "aPEr"},
+		{"0100", "5R"},
+		{"73696d706c792061206c6f6e6720737472696e67", "2cFupjhnEsSn59qHXstmK2ffpLv2"},
+		{"00eb15231dfceb60925886b67d065299925915aeb172c06647", "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L"},
+		{"516b6fcd0f", "ABnLTmg"},
+		{"bf4f89001e670274dd", "3SEo3LWLoPntC"},
+		{"572e4794", "3EFU7m"},
+		{"ecac89cad93923c02321", "EJDM8drfXA6uyA"},
+		{"10c8511e", "Rt5zm"},
+		{"00000000000000000000", "1111111111"},
+		{"000111d38e5fc9071ffcd20b4a763cc9ae4f252bb4e48fd66a835e252ada93ff480d6dd43dc62a641155a5", "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"},
+	}
+	for _, v := range vectors {
+		data, _ := hex.DecodeString(v.hex)
+		for name, encoded := range map[string]string{
+			"big.Int": BitcoinBase58.encodeBigInt(data),
+			"bytes":   BitcoinBase58.encodeBytes(data),
+		} {
+			if encoded != v.base58 {
+				t.Errorf("%s encode(%s) = %s, want %s", name, v.hex, encoded, v.base58)
+			}
+		}
+		for name, decode := range map[string]func(string) ([]byte, error){
+			"big.Int": BitcoinBase58.decodeBigInt,
+			"bytes":   BitcoinBase58.decodeBytes,
+		} {
+			if decoded, err := decode(v.base58); err != nil || !bytes.Equal(decoded, data) {
+				t.Errorf("%s decode(%s) = %x, %v", name, v.base58, decoded, err)
+			}
+		}
+	}
+
+	// Leading zeros of every length, and a run of 0xff bytes
+	for n := 0; n < 40; n++ {
+		data := append(make([]byte, n%5), bytes.Repeat([]byte{0xff}, n)...)
+		if len(data) == 0 {
+			continue
+		}
+		slow, fast := BitcoinBase58.encodeBigInt(data), BitcoinBase58.encodeBytes(data)
+		if slow != fast {
+			t.Errorf("encode(%x): big.Int %s, bytes %s", data, slow, fast)
+		}
+		if decoded, err := BitcoinBase58.decodeBytes(slow); err != nil || !bytes.Equal(decoded, data) {
+			t.Errorf("bytes decode(%s) = %x, %v", slow, decoded, err)
+		}
+	}
+
+	if _, err := BitcoinBase58.decodeBytes("1I"); !errors.Is(err, ErrInvalidBase58) {
+		t.Errorf("decodeBytes(1I) error = %v, want ErrInvalidBase58", err)
+	}
+}
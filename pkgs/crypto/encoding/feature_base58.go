@@ -0,0 +1,7 @@
+//go:build !ca_base58_educational
+
+package encoding
+
+// base58Educational selects the Base58 implementation. Build with
+// -tags ca_base58_educational to use the big-integer one instead.
+const base58Educational = false
@@ -0,0 +1,7 @@
+//go:build ca_base58_educational
+
+package encoding
+
+// base58Educational is true because the ca_base58_educational build tag is
+// set, so Encode and Decode use the big-integer implementation.
+const base58Educational = true
@@ -0,0 +1,41 @@
+package encoding
+
+import (
+	"encoding/hex"
+	"errors"
+	"fmt"
+)
+
+// ErrInvalidHex is returned for input that is not hex after an optional 0x prefix.
+var ErrInvalidHex = errors.New("invalid hex string")
+
+// DecodeHex decodes user-supplied hex with or without a 0x or 0X prefix.
+func DecodeHex(s string) ([]byte, error) {
+	if len(s) >= 2 && s[0] == '0' && (s[1] == 'x' || s[1] == 'X') {
+		s = s[2:]
+	}
+	data, err := hex.DecodeString(s)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidHex, err)
+	}
+	return data, nil
+}
+
+// DecodeHexSize decodes like DecodeHex and fails with ErrInvalidDataLength
+// unless the result is exactly size bytes, so a truncated or padded key,
+// hash or address fails with the lengths in the message.
+func DecodeHexSize(s string, size int) ([]byte, error) {
+	data, err := DecodeHex(s)
+	if err != nil {
+		return nil, err
+	}
+	if len(data) != size {
+		return nil, fmt.Errorf("%w: got %d bytes, want %d", ErrInvalidDataLength, len(data), size)
+	}
+	return data, nil
+}
+
+// EncodeHex returns data as lowercase hex with a 0x prefix.
+func EncodeHex(data []byte) string {
+	return "0x" + hex.EncodeToString(data)
+}
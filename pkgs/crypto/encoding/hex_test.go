@@ -0,0 +1,37 @@
+package encoding
+
+import (
+	"bytes"
+	"errors"
+	"testing"
+)
+
+func TestDecodeHex(t *testing.T) {
+	want := []byte{0xde, 0xad, 0xbe, 0xef}
+	for _, s := range []string{"deadbeef", "0xdeadbeef", "0XDEADBEEF"} {
+		got, err := DecodeHex(s)
+		if err != nil || !bytes.Equal(got, want) {
+			t.Errorf("DecodeHex(%q) = %x, %v", s, got, err)
+		}
+	}
+	for _, s := range []string{"0xabc", "0xzz", "x00"} {
+		if _, err := DecodeHex(s); !errors.Is(err, ErrInvalidHex) {
+			t.Errorf("DecodeHex(%q) error = %v, want ErrInvalidHex", s, err)
+		}
+	}
+	if got := EncodeHex(want); got != "0xdeadbeef" {
+		t.Errorf("EncodeHex() = %s", got)
+	}
+}
+
+func TestDecodeHexSize(t *testing.T) {
+	if got, err := DecodeHexSize("0x0102", 2); err != nil || len(got) != 2 {
+		t.Errorf("DecodeHexSize() = %x, %v", got, err)
+	}
+	if _, err := DecodeHexSize("0x010203", 2); !errors.Is(err, ErrInvalidDataLength) || err.Error() != "invalid data length: got 3 bytes, want 2" {
+		t.Errorf("DecodeHexSize(3 bytes) error = %v", err)
+	}
+	if _, err := DecodeHexSize("0x0g", 1); !errors.Is(err, ErrInvalidHex) {
+		t.Errorf("DecodeHexSize(bad hex) error = %v, want ErrInvalidHex", err)
+	}
+}
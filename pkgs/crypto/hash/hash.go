@@ -6,7 +6,9 @@ import (
 	"crypto/sha256"
 	"crypto/sha512"
 
+	"golang.org/x/crypto/blake2b"
 	"golang.org/x/crypto/ripemd160"
+	"golang.org/x/crypto/sha3"
 )
 
 // SHA256 computes the SHA-256 hash of the input data.
@@ -22,6 +24,43 @@ func DoubleSHA256(data []byte) []byte {
 	return second[:]
 }
 
+// SHA512 computes the SHA-512 hash of the input data.
+func SHA512(data []byte) []byte {
+	h := sha512.Sum512(data)
+	return h[:]
+}
+
+// Keccak256 computes the Keccak-256 hash used by Ethereum. It uses the original
+// Keccak padding and differs from SHA3-256.
+func Keccak256(data []byte) []byte {
+	h := sha3.NewLegacyKeccak256()
+	h.Write(data)
+	return h.Sum(nil)
+}
+
+// SHA3256 computes the FIPS 202 SHA3-256 hash of the input data.
+func SHA3256(data []byte) []byte {
+	h := sha3.Sum256(data)
+	return h[:]
+}
+
+// Blake2b256 computes the 32-byte BLAKE2b hash of the input data.
+func Blake2b256(data []byte) []byte {
+	h := blake2b.Sum256(data)
+	return h[:]
+}
+
+// Blake2b computes an unkeyed BLAKE2b hash of size bytes (1 to 64), as used
+// for Filecoin (20, 4), Tezos (20) and Cardano (28) key hashes.
+func Blake2b(data []byte, size int) []byte {
+	h, err := blake2b.New(size, nil)
+	if err != nil {
+		panic("hash: invalid BLAKE2b size")
+	}
+	h.Write(data)
+	return h.Sum(nil)
+}
+
 // RIPEMD160 computes the RIPEMD-160 hash of the input data.
 func RIPEMD160(data []byte) []byte {
 	h := ripemd160.New()
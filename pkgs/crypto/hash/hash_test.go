@@ -255,6 +255,41 @@ func TestVerifyChecksumRoundTrip(t *testing.T) {
 	}
 }
 
+func TestChainHashes(t *testing.T) {
+	tests := []struct {
+		name     string
+		fn       func([]byte) []byte
+		input    string
+		expected string
+	}{
+		{"SHA512 abc", SHA512, "abc", "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"},
+		{"Keccak256 empty", Keccak256, "", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"},
+		{"Keccak256 abc", Keccak256, "abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"},
+		{"SHA3256 abc", SHA3256, "abc", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"},
+		{"Blake2b256 abc", Blake2b256, "abc", "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"},
+		{"Blake2b-224 abc", func(b []byte) []byte { return Blake2b(b, 28) }, "abc", "9bd237b02a29e43bdd6738afa5b53ff0eee178d6210b618e4511aec8"},
+		{"Blake2b-160 abc", func(b []byte) []byte { return Blake2b(b, 20) }, "abc", "384264f676f39536840523f284921cdc68b6846b"},
+		{"Blake2b-32 abc", func(b []byte) []byte { return Blake2b(b, 4) }, "abc", "63906248"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if result := tt.fn([]byte(tt.input)); !bytes.Equal(result, hexToBytes(tt.expected)) {
+				t.Errorf("%s = %x, want %s", tt.name, result, tt.expected)
+			}
+		})
+	}
+}
+
+func TestBlake2bInvalidSize(t *testing.T) {
+	defer func() {
+		if recover() == nil {
+			t.Error("Blake2b(size 65) did not panic")
+		}
+	}()
+	Blake2b(nil, 65)
+}
+
 // Helper functions
 func hexToBytes(s string) []byte {
 	b, _ := hex.DecodeString(s)
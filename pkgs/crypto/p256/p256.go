@@ -0,0 +1,151 @@
+// Package p256 provides NIST P-256 (secp256r1) key operations and SLIP-10
+// hierarchical derivation for the curve.
+// Used by: Neo, Flow
+package p256
+
+import (
+	"crypto/ecdh"
+	"crypto/elliptic"
+	"crypto/hmac"
+	"crypto/sha512"
+	"encoding/binary"
+	"errors"
+	"math/big"
+)
+
+const (
+	// PrivateKeySize is the size of a P-256 private key
+	PrivateKeySize = 32
+
+	// CompressedPubKeyLen is the length of a compressed public key
+	CompressedPubKeyLen = 33
+
+	// UncompressedPubKeyLen is the length of an uncompressed public key
+	UncompressedPubKeyLen = 65
+)
+
+// slip10Key is the HMAC key for SLIP-10 master key generation on P-256
+const slip10Key = "Nist256p1 seed"
+
+var (
+	ErrInvalidPrivateKey = errors.New("invalid P-256 private key")
+	ErrInvalidPublicKey  = errors.New("invalid P-256 public key")
+	ErrInvalidSeed       = errors.New("seed must be between 16 and 64 bytes")
+)
+
+// N is the order of the P-256 curve
+var N = elliptic.P256().Params().N
+
+// PrivateKeyToPublicKey derives the 65-byte uncompressed public key from a private key.
+func PrivateKeyToPublicKey(privateKey []byte) ([]byte, error) {
+	key, err := ecdh.P256().NewPrivateKey(privateKey)
+	if err != nil {
+		return nil, ErrInvalidPrivateKey
+	}
+	return key.PublicKey().Bytes(), nil
+}
+
+// PrivateKeyToCompressedPublicKey derives the 33-byte compressed public key from a private key.
+func PrivateKeyToCompressedPublicKey(privateKey []byte) ([]byte, error) {
+	uncompressed, err := PrivateKeyToPublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+	return CompressPublicKey(uncompressed)
+}
+
+// CompressPublicKey converts a 65-byte uncompressed public key to 33 bytes.
+func CompressPublicKey(uncompressed []byte) ([]byte, error) {
+	if len(uncompressed) != UncompressedPubKeyLen || uncompressed[0] != 0x04 {
+		return nil, ErrInvalidPublicKey
+	}
+
+	compressed := make([]byte, CompressedPubKeyLen)
+	compressed[0] = 0x02 | (uncompressed[64] & 0x01)
+	copy(compressed[1:], uncompressed[1:33])
+
+	return compressed, nil
+}
+
+// DeriveKeyFromPath derives a P-256 key using SLIP-10 at the given path.
+// Unlike Ed25519, both hardened and non-hardened indices are supported.
+// Returns (privateKey, compressedPublicKey, error)
+func DeriveKeyFromPath(seed []byte, path []uint32) ([]byte, []byte, error) {
+	if len(seed) < 16 || len(seed) > 64 {
+		return nil, nil, ErrInvalidSeed
+	}
+
+	key, chainCode := slip10MasterKey(seed)
+
+	var err error
+	for _, index := range path {
+		key, chainCode, err = slip10DeriveChild(key, chainCode, index)
+		if err != nil {
+			return nil, nil, err
+		}
+	}
+
+	publicKey, err := PrivateKeyToCompressedPublicKey(key)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	return key, publicKey, nil
+}
+
+// slip10MasterKey derives the master key and chain code, re-hashing until the
+// key is a valid scalar.
+func slip10MasterKey(seed []byte) ([]byte, []byte) {
+	h := hmacSHA512([]byte(slip10Key), seed)
+	for !isValidScalar(h[:32]) {
+		h = hmacSHA512([]byte(slip10Key), h)
+	}
+	return h[:32], h[32:]
+}
+
+// slip10DeriveChild derives a child key: k_i = IL + k_par mod N.
+// If IL >= N or k_i = 0, the derivation is retried with 0x01 || IR || index.
+func slip10DeriveChild(key, chainCode []byte, index uint32) ([]byte, []byte, error) {
+	var data []byte
+	if index >= 0x80000000 {
+		data = append([]byte{0x00}, key...)
+	} else {
+		publicKey, err := PrivateKeyToCompressedPublicKey(key)
+		if err != nil {
+			return nil, nil, err
+		}
+		data = publicKey
+	}
+	data = binary.BigEndian.AppendUint32(data, index)
+
+	parent := new(big.Int).SetBytes(key)
+	for {
+		h := hmacSHA512(chainCode, data)
+
+		il := new(big.Int).SetBytes(h[:32])
+		child := new(big.Int).Add(il, parent)
+		child.Mod(child, N)
+
+		if il.Cmp(N) < 0 && child.Sign() != 0 {
+			childKey := make([]byte, PrivateKeySize)
+			child.FillBytes(childKey)
+			return childKey, h[32:], nil
+		}
+
+		data = append([]byte{0x01}, h[32:]...)
+		data = binary.BigEndian.AppendUint32(data, index)
+	}
+}
+
+// isValidScalar checks that a key is in the range [1, N).
+func isValidScalar(key []byte) bool {
+	k := new(big.Int).SetBytes(key)
+	return k.Sign() > 0 && k.Cmp(N) < 0
+}
+
+// hmacSHA512 computes HMAC-SHA512.
+func hmacSHA512(key, data []byte) []byte {
+	mac := hmac.New(sha512.New, key)
+	mac.Write(data)
+	return mac.Sum(nil)
+}
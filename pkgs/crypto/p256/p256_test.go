@@ -0,0 +1,74 @@
+package p256
+
+import (
+	"encoding/hex"
+	"testing"
+)
+
+// SLIP-10 test vector 1 for nist256p1
+func TestDeriveKeyFromPath(t *testing.T) {
+	seed, _ := hex.DecodeString("000102030405060708090a0b0c0d0e0f")
+
+	tests := []struct {
+		name       string
+		path       []uint32
+		privateKey string
+		publicKey  string
+	}{
+		{
+			name:       "m",
+			path:       nil,
+			privateKey: "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2",
+			publicKey:  "0266874dc6ade47b3ecd096745ca09bcd29638dd52c2c12117b11ed3e458cfa9e8",
+		},
+		{
+			name:       "m/0'/1/2'/2/1000000000",
+			path:       []uint32{0x80000000, 1, 0x80000002, 2, 1000000000},
+			privateKey: "21c4f269ef0a5fd1badf47eeacebeeaa3de22eb8e5b0adcd0f27dd99d34d0119",
+			publicKey:  "02216cd26d31147f72427a453c443ed2cde8a1e53c9cc44e5ddf739725413fe3f4",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			priv, pub, err := DeriveKeyFromPath(seed, tt.path)
+			if err != nil {
+				t.Fatalf("DeriveKeyFromPath() error = %v", err)
+			}
+			if hex.EncodeToString(priv) != tt.privateKey {
+				t.Errorf("private key = %x, want %s", priv, tt.privateKey)
+			}
+			if hex.EncodeToString(pub) != tt.publicKey {
+				t.Errorf("public key = %x, want %s", pub, tt.publicKey)
+			}
+		})
+	}
+
+	if _, _, err := DeriveKeyFromPath(seed[:15], nil); err == nil {
+		t.Error("DeriveKeyFromPath() accepted a 15-byte seed")
+	}
+}
+
+func TestPublicKeyEncoding(t *testing.T) {
+	priv, _ := hex.DecodeString("612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2")
+
+	uncompressed, err := PrivateKeyToPublicKey(priv)
+	if err != nil {
+		t.Fatalf("PrivateKeyToPublicKey() error = %v", err)
+	}
+	if len(uncompressed) != UncompressedPubKeyLen || uncompressed[0] != 0x04 {
+		t.Fatalf("unexpected uncompressed key %x", uncompressed)
+	}
+
+	compressed, err := CompressPublicKey(uncompressed)
+	if err != nil {
+		t.Fatalf("CompressPublicKey() error = %v", err)
+	}
+	if hex.EncodeToString(compressed) != "0266874dc6ade47b3ecd096745ca09bcd29638dd52c2c12117b11ed3e458cfa9e8" {
+		t.Errorf("compressed key = %x", compressed)
+	}
+
+	if _, err := PrivateKeyToPublicKey(make([]byte, 32)); err == nil {
+		t.Error("PrivateKeyToPublicKey() accepted a zero key")
+	}
+}
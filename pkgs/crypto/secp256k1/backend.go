@@ -0,0 +1,33 @@
+package secp256k1
+
+import "math/big"
+
+// pointBackend is the scalar multiplication the rest of the package is built
+// on. The pure Go backend below is the default. The ca_libsecp256k1 build tag
+// swaps in bitcoin-core's libsecp256k1 through cgo, which is faster and
+// constant time; builds without cgo, such as WebAssembly, keep the Go
+// backend even with the tag, so every secp256k1 chain works either way.
+type pointBackend interface {
+	// baseMult returns k * G.
+	baseMult(k *big.Int) *Point
+
+	// mult returns k * p.
+	mult(p *Point, k *big.Int) *Point
+}
+
+// Backend returns the name of the compiled-in backend, "go" or
+// "libsecp256k1".
+func Backend() string {
+	return backendName
+}
+
+// goBackend is the math/big implementation of this package.
+type goBackend struct{}
+
+func (goBackend) baseMult(k *big.Int) *Point {
+	return scalarBaseMult(k)
+}
+
+func (goBackend) mult(p *Point, k *big.Int) *Point {
+	return scalarMult(p, k)
+}
@@ -0,0 +1,7 @@
+//go:build !ca_libsecp256k1 || !cgo
+
+package secp256k1
+
+const backendName = "go"
+
+var backend pointBackend = goBackend{}
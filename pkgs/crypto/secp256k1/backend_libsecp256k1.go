@@ -0,0 +1,82 @@
+//go:build ca_libsecp256k1 && cgo
+
+package secp256k1
+
+/*
+#cgo LDFLAGS: -lsecp256k1
+#include <secp256k1.h>
+*/
+import "C"
+
+import (
+	"math/big"
+	"sync"
+	"unsafe"
+)
+
+const backendName = "libsecp256k1"
+
+var backend pointBackend = cBackend{}
+
+// cContext is the shared libsecp256k1 context. A context without
+// randomization is only read by the functions used here, so it is safe to
+// share between goroutines.
+var cContext = sync.OnceValue(func() *C.secp256k1_context {
+	return C.secp256k1_context_create(C.SECP256K1_CONTEXT_NONE)
+})
+
+// cBackend multiplies with libsecp256k1. Inputs the library rejects, such as
+// points off the curve, fall back to the Go backend so both backends return
+// the same results.
+type cBackend struct{}
+
+func (cBackend) baseMult(k *big.Int) *Point {
+	scalar := new(big.Int).Mod(k, N)
+	if scalar.Sign() == 0 {
+		return Infinity()
+	}
+	seckey := scalar.FillBytes(make([]byte, 32))
+	defer clear(seckey)
+
+	var pub C.secp256k1_pubkey
+	if C.secp256k1_ec_pubkey_create(cContext(), &pub, (*C.uchar)(unsafe.Pointer(&seckey[0]))) != 1 {
+		return scalarBaseMult(k)
+	}
+	return cPoint(&pub)
+}
+
+func (cBackend) mult(p *Point, k *big.Int) *Point {
+	if k.Sign() <= 0 || p.IsInfinity() || !p.IsOnCurve() {
+		return scalarMult(p, k)
+	}
+	// The group has prime order N, so k * p = (k mod N) * p
+	scalar := new(big.Int).Mod(k, N)
+	if scalar.Sign() == 0 {
+		return Infinity()
+	}
+
+	encoded := make([]byte, 65)
+	encoded[0] = 0x04
+	p.X.FillBytes(encoded[1:33])
+	p.Y.FillBytes(encoded[33:])
+	var pub C.secp256k1_pubkey
+	if C.secp256k1_ec_pubkey_parse(cContext(), &pub, (*C.uchar)(unsafe.Pointer(&encoded[0])), C.size_t(len(encoded))) != 1 {
+		return scalarMult(p, k)
+	}
+	tweak := scalar.FillBytes(make([]byte, 32))
+	if C.secp256k1_ec_pubkey_tweak_mul(cContext(), &pub, (*C.uchar)(unsafe.Pointer(&tweak[0]))) != 1 {
+		return scalarMult(p, k)
+	}
+	return cPoint(&pub)
+}
+
+// cPoint converts a libsecp256k1 public key to a Point.
+func cPoint(pub *C.secp256k1_pubkey) *Point {
+	out := make([]byte, 65)
+	n := C.size_t(len(out))
+	C.secp256k1_ec_pubkey_serialize(cContext(), (*C.uchar)(unsafe.Pointer(&out[0])), &n, pub, C.SECP256K1_EC_UNCOMPRESSED)
+	return &Point{
+		X: new(big.Int).SetBytes(out[1:33]),
+		Y: new(big.Int).SetBytes(out[33:65]),
+	}
+}
@@ -0,0 +1,54 @@
+package secp256k1
+
+import (
+	"math/big"
+	"sync"
+)
+
+// Fixed-base multiplication splits the scalar into 4-bit windows and adds one
+// precomputed multiple of G per window, which replaces the 256 doublings of
+// ScalarMult with at most 64 additions.
+const (
+	baseWindowBits = 4
+	baseWindows    = 256 / baseWindowBits
+	baseWindowSize = 1 << baseWindowBits
+)
+
+// baseTable returns the shared table of generator multiples: entry [i][j] is
+// j * 2^(4i) * G. It is computed once, on first use, and only read afterwards,
+// so it is safe to share between goroutines.
+var baseTable = sync.OnceValue(func() *[baseWindows][baseWindowSize]*Point {
+	var table [baseWindows][baseWindowSize]*Point
+
+	base := Generator()
+	for i := range table {
+		table[i][0] = Infinity()
+		table[i][1] = base
+		for j := 2; j < baseWindowSize; j++ {
+			table[i][j] = Add(table[i][j-1], base)
+		}
+		// The next window's base is 16 times this one
+		base = Double(table[i][baseWindowSize/2])
+	}
+	return &table
+})
+
+// scalarBaseMult computes k * G with the shared table.
+func scalarBaseMult(k *big.Int) *Point {
+	if k.Sign() < 0 || k.BitLen() > 256 {
+		k = new(big.Int).Mod(k, N)
+	}
+
+	table := baseTable()
+	result := Infinity()
+	for i := 0; i < baseWindows; i++ {
+		var window uint
+		for b := baseWindowBits - 1; b >= 0; b-- {
+			window = window<<1 | k.Bit(i*baseWindowBits+b)
+		}
+		if window != 0 {
+			result = Add(result, table[i][window])
+		}
+	}
+	return result
+}
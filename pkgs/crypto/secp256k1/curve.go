@@ -60,6 +60,21 @@ func (p *Point) Equal(other *Point) bool {
 	return p.X.Cmp(other.X) == 0 && p.Y.Cmp(other.Y) == 0
 }
 
+// IsOnCurve reports whether p is a point of the curve with coordinates in
+// [0, P). The point at infinity is not on the curve.
+func (p *Point) IsOnCurve() bool {
+	if p.X == nil || p.Y == nil || p.X.Sign() < 0 || p.Y.Sign() < 0 || p.X.Cmp(P) >= 0 || p.Y.Cmp(P) >= 0 {
+		return false
+	}
+	// y^2 = x^3 + 7
+	lhs := new(big.Int).Mul(p.Y, p.Y)
+	lhs.Mod(lhs, P)
+	rhs := new(big.Int).Exp(p.X, big.NewInt(3), P)
+	rhs.Add(rhs, big.NewInt(7))
+	rhs.Mod(rhs, P)
+	return lhs.Cmp(rhs) == 0
+}
+
 // Add performs point addition: P1 + P2.
 func Add(p1, p2 *Point) *Point {
 	if p1.IsInfinity() {
@@ -129,8 +144,14 @@ func Double(p *Point) *Point {
 	return &Point{X: x3, Y: y3}
 }
 
-// ScalarMult performs scalar multiplication: k * P using double-and-add algorithm.
+// ScalarMult performs scalar multiplication: k * P, with the compiled-in
+// backend.
 func ScalarMult(p *Point, k *big.Int) *Point {
+	return backend.mult(p, k)
+}
+
+// scalarMult performs scalar multiplication with the double-and-add algorithm.
+func scalarMult(p *Point, k *big.Int) *Point {
 	result := Infinity()
 	addend := p.Clone()
 
@@ -147,7 +168,7 @@ func ScalarMult(p *Point, k *big.Int) *Point {
 // ScalarBaseMult performs scalar multiplication with the generator point: k * G.
 func ScalarBaseMult(k []byte) *Point {
 	scalar := new(big.Int).SetBytes(k)
-	return ScalarMult(Generator(), scalar)
+	return backend.baseMult(scalar)
 }
 
 // IsValidPrivateKey checks if a byte slice is a valid private key.
@@ -243,3 +243,85 @@ func hexToBytes(s string) []byte {
 	b, _ := hex.DecodeString(s)
 	return b
 }
+
+func TestScalarBaseMultTable(t *testing.T) {
+	scalars := []*big.Int{
+		big.NewInt(0),
+		big.NewInt(1),
+		big.NewInt(15),
+		big.NewInt(16),
+		new(big.Int).Sub(N, big.NewInt(1)),
+		N,
+		new(big.Int).Lsh(big.NewInt(1), 255),
+		new(big.Int).Add(N, big.NewInt(7)),
+	}
+	seed, _ := new(big.Int).SetString("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721", 16)
+	scalars = append(scalars, seed)
+
+	for _, k := range scalars {
+		want := ScalarMult(Generator(), k)
+		if got := scalarBaseMult(k); !got.Equal(want) {
+			t.Errorf("scalarBaseMult(%x) = (%x, %x), want (%x, %x)", k, got.X, got.Y, want.X, want.Y)
+		}
+	}
+
+	// The table is shared; concurrent first use must be safe
+	done := make(chan *Point)
+	for i := 0; i < 4; i++ {
+		go func() { done <- ScalarBaseMult(seed.Bytes()) }()
+	}
+	want := ScalarMult(Generator(), seed)
+	for i := 0; i < 4; i++ {
+		if got := <-done; !got.Equal(want) {
+			t.Error("concurrent ScalarBaseMult() returned a wrong point")
+		}
+	}
+}
+
+func BenchmarkScalarBaseMult(b *testing.B) {
+	k, _ := hex.DecodeString("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721")
+	baseTable()
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		ScalarBaseMult(k)
+	}
+}
+
+func BenchmarkScalarMultGenerator(b *testing.B) {
+	k, _ := new(big.Int).SetString("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721", 16)
+	for i := 0; i < b.N; i++ {
+		ScalarMult(Generator(), k)
+	}
+}
+
+// TestBackend checks the compiled-in backend against the Go implementation,
+// which matters when building with -tags ca_libsecp256k1.
+func TestBackend(t *testing.T) {
+	if name := Backend(); name != "go" && name != "libsecp256k1" {
+		t.Errorf("Backend() = %q", name)
+	}
+
+	seed, _ := new(big.Int).SetString("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721", 16)
+	points := []*Point{Generator(), scalarMult(Generator(), seed), Infinity()}
+	scalars := []*big.Int{
+		big.NewInt(0),
+		big.NewInt(1),
+		big.NewInt(2),
+		new(big.Int).Sub(N, big.NewInt(1)),
+		N,
+		new(big.Int).Add(N, big.NewInt(3)),
+		seed,
+	}
+	for _, p := range points {
+		for _, k := range scalars {
+			if got, want := ScalarMult(p, k), scalarMult(p, k); !got.Equal(want) {
+				t.Errorf("ScalarMult((%x, %x), %x) = (%x, %x), want (%x, %x)", p.X, p.Y, k, got.X, got.Y, want.X, want.Y)
+			}
+		}
+	}
+	for _, k := range scalars {
+		if got, want := ScalarBaseMult(k.Bytes()), scalarBaseMult(k); !got.Equal(want) {
+			t.Errorf("ScalarBaseMult(%x) = (%x, %x), want (%x, %x)", k, got.X, got.Y, want.X, want.Y)
+		}
+	}
+}
@@ -0,0 +1,209 @@
+package secp256k1
+
+import (
+	"encoding/asn1"
+	"errors"
+	"math/big"
+)
+
+// DER encodings used by KMS and HSM APIs and OpenSSL: ECDSA signatures as an
+// ASN.1 SEQUENCE of two INTEGERs, public keys as a SubjectPublicKeyInfo and
+// private keys as a PKCS#8 PrivateKeyInfo wrapping an RFC 5915 ECPrivateKey.
+
+var (
+	// ErrInvalidDER is returned for malformed or non-canonical DER input.
+	ErrInvalidDER = errors.New("invalid DER encoding")
+
+	// ErrRecoveryFailed is returned when no recovery id yields the public key.
+	ErrRecoveryFailed = errors.New("signature does not recover to public key")
+)
+
+var (
+	oidECPublicKey = asn1.ObjectIdentifier{1, 2, 840, 10045, 2, 1}
+	oidSecp256k1   = asn1.ObjectIdentifier{1, 3, 132, 0, 10}
+)
+
+type derSignature struct {
+	R, S *big.Int
+}
+
+type pkixAlgorithm struct {
+	Algorithm  asn1.ObjectIdentifier
+	Parameters asn1.ObjectIdentifier
+}
+
+type subjectPublicKeyInfo struct {
+	Algorithm pkixAlgorithm
+	PublicKey asn1.BitString
+}
+
+// privateKeyInfo is a PKCS#8 PrivateKeyInfo; trailing attributes are ignored.
+type privateKeyInfo struct {
+	Version    int
+	Algorithm  pkixAlgorithm
+	PrivateKey []byte
+}
+
+type ecPrivateKey struct {
+	Version    int
+	PrivateKey []byte
+	NamedCurve asn1.ObjectIdentifier `asn1:"optional,explicit,tag:0"`
+	PublicKey  asn1.BitString        `asn1:"optional,explicit,tag:1"`
+}
+
+// secp256k1Algorithm identifies an EC key on secp256k1.
+var secp256k1Algorithm = pkixAlgorithm{Algorithm: oidECPublicKey, Parameters: oidSecp256k1}
+
+// ParseDERSignature converts a DER-encoded ECDSA signature into the compact
+// 64-byte r || s form. S is returned as encoded; see NormalizeSignature.
+func ParseDERSignature(der []byte) ([]byte, error) {
+	var sig derSignature
+	rest, err := asn1.Unmarshal(der, &sig)
+	if err != nil || len(rest) != 0 {
+		return nil, ErrInvalidDER
+	}
+	if sig.R.Sign() <= 0 || sig.R.Cmp(N) >= 0 || sig.S.Sign() <= 0 || sig.S.Cmp(N) >= 0 {
+		return nil, ErrInvalidSignature
+	}
+	compact := make([]byte, 0, ECDSASignatureLen)
+	compact = append(compact, scalarBytes(sig.R)...)
+	return append(compact, scalarBytes(sig.S)...), nil
+}
+
+// SerializeDERSignature converts a compact r || s signature to DER.
+func SerializeDERSignature(signature []byte) ([]byte, error) {
+	if len(signature) != ECDSASignatureLen {
+		return nil, ErrInvalidSignature
+	}
+	return asn1.Marshal(derSignature{
+		R: new(big.Int).SetBytes(signature[:32]),
+		S: new(big.Int).SetBytes(signature[32:]),
+	})
+}
+
+// NormalizeSignature returns a compact signature with S in the lower half of
+// the curve order, as Bitcoin (BIP-62) and Ethereum (EIP-2) require, and
+// reports whether S was flipped. Flipping S negates R's y coordinate, so a
+// recovery id computed before normalizing must have its low bit toggled.
+func NormalizeSignature(signature []byte) ([]byte, bool, error) {
+	if len(signature) != ECDSASignatureLen {
+		return nil, false, ErrInvalidSignature
+	}
+	s := new(big.Int).SetBytes(signature[32:])
+	out := append([]byte(nil), signature...)
+	if s.Cmp(halfN) <= 0 {
+		return out, false, nil
+	}
+	copy(out[32:], scalarBytes(s.Sub(N, s)))
+	return out, true, nil
+}
+
+// RecoveryID finds the recovery id (0-3) under which a compact signature of
+// hash recovers to publicKey. Signers that only return r and s, such as cloud
+// KMS, need it to produce Ethereum's v or a Bitcoin signed-message header.
+func RecoveryID(publicKey, hash, signature []byte) (byte, error) {
+	pub, err := ParsePublicKey(publicKey)
+	if err != nil {
+		return 0, err
+	}
+	for id := byte(0); id < 4; id++ {
+		q, err := RecoverPublicKey(hash, signature, id)
+		if err == nil && q.Equal(pub) {
+			return id, nil
+		}
+		if errors.Is(err, ErrInvalidHash) {
+			return 0, err
+		}
+	}
+	return 0, ErrRecoveryFailed
+}
+
+// ParsePKIXPublicKey parses a DER SubjectPublicKeyInfo holding a secp256k1
+// key, the format AWS KMS GetPublicKey and GCP KMS return, and returns the
+// 33-byte compressed public key. crypto/x509 does not know the curve.
+func ParsePKIXPublicKey(der []byte) ([]byte, error) {
+	var info subjectPublicKeyInfo
+	rest, err := asn1.Unmarshal(der, &info)
+	if err != nil || len(rest) != 0 {
+		return nil, ErrInvalidDER
+	}
+	if !info.Algorithm.Algorithm.Equal(oidECPublicKey) || !info.Algorithm.Parameters.Equal(oidSecp256k1) {
+		return nil, ErrInvalidPublicKey
+	}
+	if info.PublicKey.BitLength != 8*len(info.PublicKey.Bytes) {
+		return nil, ErrInvalidDER
+	}
+	point, err := ParsePublicKey(info.PublicKey.Bytes)
+	if err != nil {
+		return nil, err
+	}
+	return CompressPoint(point), nil
+}
+
+// MarshalPKIXPublicKey encodes a public key as a DER SubjectPublicKeyInfo
+// with an uncompressed point.
+func MarshalPKIXPublicKey(publicKey []byte) ([]byte, error) {
+	point, err := ParsePublicKey(publicKey)
+	if err != nil {
+		return nil, err
+	}
+	uncompressed := SerializeUncompressed(point)
+	return asn1.Marshal(subjectPublicKeyInfo{
+		Algorithm: secp256k1Algorithm,
+		PublicKey: asn1.BitString{Bytes: uncompressed, BitLength: 8 * len(uncompressed)},
+	})
+}
+
+// MarshalPKCS8PrivateKey encodes a 32-byte private key as a DER PKCS#8
+// PrivateKeyInfo with the public key embedded, as OpenSSL writes EC keys.
+func MarshalPKCS8PrivateKey(privateKey []byte) ([]byte, error) {
+	if len(privateKey) != 32 || !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	publicKey := SerializeUncompressed(PrivateKeyToPublicKey(privateKey))
+	inner, err := asn1.Marshal(ecPrivateKey{
+		Version:    1,
+		PrivateKey: privateKey,
+		PublicKey:  asn1.BitString{Bytes: publicKey, BitLength: 8 * len(publicKey)},
+	})
+	if err != nil {
+		return nil, err
+	}
+	return asn1.Marshal(privateKeyInfo{Algorithm: secp256k1Algorithm, PrivateKey: inner})
+}
+
+// ParsePKCS8PrivateKey decodes a DER PKCS#8 secp256k1 private key and returns
+// the 32-byte key. An embedded public key must match the private key.
+func ParsePKCS8PrivateKey(der []byte) ([]byte, error) {
+	var info privateKeyInfo
+	rest, err := asn1.Unmarshal(der, &info)
+	if err != nil || len(rest) != 0 || info.Version != 0 {
+		return nil, ErrInvalidDER
+	}
+	if !info.Algorithm.Algorithm.Equal(oidECPublicKey) || !info.Algorithm.Parameters.Equal(oidSecp256k1) {
+		return nil, ErrInvalidPrivateKey
+	}
+
+	var key ecPrivateKey
+	rest, err = asn1.Unmarshal(info.PrivateKey, &key)
+	if err != nil || len(rest) != 0 || key.Version != 1 || len(key.PrivateKey) > 32 {
+		return nil, ErrInvalidDER
+	}
+	if key.NamedCurve != nil && !key.NamedCurve.Equal(oidSecp256k1) {
+		return nil, ErrInvalidPrivateKey
+	}
+
+	// RFC 5915 pads the key to the order's length, but some encoders strip leading zeros
+	privateKey := make([]byte, 32)
+	copy(privateKey[32-len(key.PrivateKey):], key.PrivateKey)
+	if !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	if len(key.PublicKey.Bytes) > 0 {
+		point, err := ParsePublicKey(key.PublicKey.Bytes)
+		if err != nil || !point.Equal(PrivateKeyToPublicKey(privateKey)) {
+			return nil, ErrInvalidPublicKey
+		}
+	}
+	return privateKey, nil
+}
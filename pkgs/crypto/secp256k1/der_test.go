@@ -0,0 +1,125 @@
+package secp256k1
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"math/big"
+	"testing"
+)
+
+func TestDERSignature(t *testing.T) {
+	const (
+		r = "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8"
+		s = "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5"
+	)
+	compact, _ := hex.DecodeString(r + s)
+	der := "3045022100" + r + "0220" + s
+
+	got, err := SerializeDERSignature(compact)
+	if err != nil || hex.EncodeToString(got) != der {
+		t.Errorf("SerializeDERSignature() = %x, %v, want %s", got, err, der)
+	}
+	parsed, err := ParseDERSignature(got)
+	if err != nil || !bytes.Equal(parsed, compact) {
+		t.Errorf("ParseDERSignature() = %x, %v", parsed, err)
+	}
+
+	for name, bad := range map[string]string{
+		"trailing data":   der + "00",
+		"non-minimal int": "3046022200" + "00" + r + "0220" + s,
+		"negative r":      "30440220" + r + "0220" + s,
+		"not a sequence":  "3145022100" + r + "0220" + s,
+	} {
+		data, _ := hex.DecodeString(bad)
+		if _, err := ParseDERSignature(data); err == nil {
+			t.Errorf("ParseDERSignature(%s) succeeded", name)
+		}
+	}
+}
+
+func TestNormalizeSignatureAndRecoveryID(t *testing.T) {
+	privateKey := bytes.Repeat([]byte{0x42}, 32)
+	publicKey := PrivateKeyToCompressedPublicKey(privateKey)
+	hash := sha256.Sum256([]byte("kms"))
+	sig, recoveryID, err := SignECDSA(privateKey, hash[:])
+	if err != nil {
+		t.Fatalf("SignECDSA() error = %v", err)
+	}
+
+	if id, err := RecoveryID(publicKey, hash[:], sig); err != nil || id != recoveryID {
+		t.Errorf("RecoveryID() = %d, %v, want %d", id, err, recoveryID)
+	}
+
+	// A high-S twin, as returned by KMS backends that do not normalize
+	high := append([]byte(nil), sig...)
+	sValue := new(big.Int).SetBytes(sig[32:])
+	copy(high[32:], scalarBytes(sValue.Sub(N, sValue)))
+	if !VerifyECDSA(publicKey, hash[:], high) {
+		t.Fatal("high-S twin does not verify")
+	}
+	if id, err := RecoveryID(publicKey, hash[:], high); err != nil || id != recoveryID^1 {
+		t.Errorf("RecoveryID(high S) = %d, %v, want %d", id, err, recoveryID^1)
+	}
+
+	normalized, flipped, err := NormalizeSignature(high)
+	if err != nil || !flipped || !bytes.Equal(normalized, sig) {
+		t.Errorf("NormalizeSignature(high S) = %x, %v, %v", normalized, flipped, err)
+	}
+	if _, flipped, _ := NormalizeSignature(sig); flipped {
+		t.Error("NormalizeSignature(low S) flipped S")
+	}
+
+	other := PrivateKeyToCompressedPublicKey(bytes.Repeat([]byte{0x43}, 32))
+	if _, err := RecoveryID(other, hash[:], sig); !errors.Is(err, ErrRecoveryFailed) {
+		t.Errorf("RecoveryID(other key) error = %v, want ErrRecoveryFailed", err)
+	}
+}
+
+func TestPKIXPublicKey(t *testing.T) {
+	generator := CompressPoint(Generator())
+	der, err := MarshalPKIXPublicKey(generator)
+	if err != nil {
+		t.Fatalf("MarshalPKIXPublicKey() error = %v", err)
+	}
+	want := "3056301006072a8648ce3d020106052b8104000a034200" + hex.EncodeToString(SerializeUncompressed(Generator()))
+	if hex.EncodeToString(der) != want {
+		t.Errorf("MarshalPKIXPublicKey() = %x, want %s", der, want)
+	}
+
+	publicKey, err := ParsePKIXPublicKey(der)
+	if err != nil || !bytes.Equal(publicKey, generator) {
+		t.Errorf("ParsePKIXPublicKey() = %x, %v", publicKey, err)
+	}
+
+	// Same structure, another named curve (1.3.132.0.11)
+	otherCurve := bytes.Replace(der, []byte{0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0a}, []byte{0x06, 0x05, 0x2b, 0x81, 0x04, 0x00, 0x0b}, 1)
+	if _, err := ParsePKIXPublicKey(otherCurve); !errors.Is(err, ErrInvalidPublicKey) {
+		t.Errorf("ParsePKIXPublicKey(other curve) error = %v, want ErrInvalidPublicKey", err)
+	}
+}
+
+func TestPKCS8PrivateKey(t *testing.T) {
+	privateKey, _ := hex.DecodeString("0000000000000000000000000000000000000000000000000000000000000001")
+	want := "308184020100301006072a8648ce3d020106052b8104000a046d306b0201010420" + hex.EncodeToString(privateKey) +
+		"a144034200" + hex.EncodeToString(SerializeUncompressed(Generator()))
+
+	der, err := MarshalPKCS8PrivateKey(privateKey)
+	if err != nil || hex.EncodeToString(der) != want {
+		t.Errorf("MarshalPKCS8PrivateKey() = %x, %v, want %s", der, err, want)
+	}
+	parsed, err := ParsePKCS8PrivateKey(der)
+	if err != nil || !bytes.Equal(parsed, privateKey) {
+		t.Errorf("ParsePKCS8PrivateKey() = %x, %v", parsed, err)
+	}
+
+	// A public key that does not belong to the private key
+	mismatched := bytes.Replace(der, SerializeUncompressed(Generator()), SerializeUncompressed(Double(Generator())), 1)
+	if _, err := ParsePKCS8PrivateKey(mismatched); !errors.Is(err, ErrInvalidPublicKey) {
+		t.Errorf("ParsePKCS8PrivateKey(mismatched public key) error = %v, want ErrInvalidPublicKey", err)
+	}
+	if _, err := MarshalPKCS8PrivateKey(make([]byte, 32)); !errors.Is(err, ErrInvalidPrivateKey) {
+		t.Errorf("MarshalPKCS8PrivateKey(zero) error = %v, want ErrInvalidPrivateKey", err)
+	}
+}
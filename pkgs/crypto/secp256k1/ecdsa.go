@@ -0,0 +1,261 @@
+package secp256k1
+
+import (
+	"crypto/hmac"
+	"crypto/sha256"
+	"errors"
+	"math/big"
+)
+
+// ECDSASignatureLen is the length of a compact (r || s) ECDSA signature
+const ECDSASignatureLen = 64
+
+var (
+	ErrInvalidHash       = errors.New("message hash must be 32 bytes")
+	ErrInvalidSignature  = errors.New("invalid signature")
+	ErrInvalidRecoveryID = errors.New("invalid recovery id")
+
+	// ErrInvalidExtraEntropy is returned for extra nonce entropy that is
+	// neither empty nor 32 bytes.
+	ErrInvalidExtraEntropy = errors.New("extra entropy must be 32 bytes")
+)
+
+// halfN is N/2, the largest S value of a low-S signature.
+var halfN = new(big.Int).Rsh(N, 1)
+
+// SignECDSA signs a 32-byte message hash with an RFC 6979 deterministic nonce.
+// It returns the compact r || s signature with S normalized to the lower half
+// of the curve order (BIP-62, EIP-2), and the recovery id (0-3) of the signature.
+func SignECDSA(privateKey, hash []byte) ([]byte, byte, error) {
+	return SignECDSAWithEntropy(privateKey, hash, nil)
+}
+
+// SignECDSAWithEntropy is SignECDSA with 32 bytes of extra entropy mixed into
+// the RFC 6979 nonce, as section 3.6 allows and libsecp256k1 does with its
+// ndata argument. Fresh random extra entropy protects against fault attacks
+// that compare two signatures of the same hash; a counter makes it possible
+// to grind for a short r, as Bitcoin Core does. Empty extra entropy is
+// plain RFC 6979.
+func SignECDSAWithEntropy(privateKey, hash, extraEntropy []byte) ([]byte, byte, error) {
+	if !IsValidPrivateKey(privateKey) {
+		return nil, 0, ErrInvalidPrivateKey
+	}
+	if len(hash) != 32 {
+		return nil, 0, ErrInvalidHash
+	}
+	if len(extraEntropy) != 0 && len(extraEntropy) != 32 {
+		return nil, 0, ErrInvalidExtraEntropy
+	}
+
+	d := new(big.Int).SetBytes(privateKey)
+	e := hashToInt(hash)
+
+	nonces := newRFC6979(privateKey, hash, extraEntropy)
+	for {
+		k := nonces.next()
+
+		R := backend.baseMult(k)
+		r := new(big.Int).Mod(R.X, N)
+		if r.Sign() == 0 {
+			continue
+		}
+
+		// s = k^-1 * (e + r*d) mod N
+		s := new(big.Int).Mul(r, d)
+		s.Add(s, e)
+		s.Mul(s, new(big.Int).ModInverse(k, N))
+		s.Mod(s, N)
+		if s.Sign() == 0 {
+			continue
+		}
+
+		recoveryID := byte(R.Y.Bit(0))
+		if R.X.Cmp(N) >= 0 {
+			recoveryID |= 2
+		}
+		if s.Cmp(halfN) > 0 {
+			s.Sub(N, s)
+			recoveryID ^= 1
+		}
+
+		sig := make([]byte, 0, ECDSASignatureLen)
+		sig = append(sig, scalarBytes(r)...)
+		sig = append(sig, scalarBytes(s)...)
+		return sig, recoveryID, nil
+	}
+}
+
+// RFC6979Nonce returns the 32-byte nonce SignECDSAWithEntropy uses for a
+// private key, hash and optional extra entropy: the first candidate of the
+// RFC 6979 generator. Signing only moves on to the next candidate when r or
+// s comes out zero, which has negligible probability.
+func RFC6979Nonce(privateKey, hash, extraEntropy []byte) ([]byte, error) {
+	if !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	if len(hash) != 32 {
+		return nil, ErrInvalidHash
+	}
+	if len(extraEntropy) != 0 && len(extraEntropy) != 32 {
+		return nil, ErrInvalidExtraEntropy
+	}
+	return scalarBytes(newRFC6979(privateKey, hash, extraEntropy).next()), nil
+}
+
+// IsDeterministicECDSA reports whether signature is the RFC 6979 signature
+// of hash under privateKey and the extra entropy the signer claims to have
+// used (empty for plain RFC 6979). The high-S twin of the signature is
+// accepted too. An auditor holding the key can use it to check that a
+// signer did not pick its own nonce, which could leak the key.
+func IsDeterministicECDSA(privateKey, hash, signature, extraEntropy []byte) bool {
+	if len(signature) != ECDSASignatureLen {
+		return false
+	}
+	want, _, err := SignECDSAWithEntropy(privateKey, hash, extraEntropy)
+	if err != nil {
+		return false
+	}
+	if hmac.Equal(signature, want) {
+		return true
+	}
+	s := new(big.Int).SetBytes(want[32:])
+	twin := append(want[:32:32], scalarBytes(s.Sub(N, s))...)
+	return hmac.Equal(signature, twin)
+}
+
+// VerifyECDSA verifies a compact r || s signature of a 32-byte hash against a
+// compressed or uncompressed public key. High-S signatures are accepted.
+func VerifyECDSA(publicKey, hash, signature []byte) bool {
+	if len(hash) != 32 || len(signature) != ECDSASignatureLen {
+		return false
+	}
+
+	pub, err := ParsePublicKey(publicKey)
+	if err != nil {
+		return false
+	}
+
+	r := new(big.Int).SetBytes(signature[:32])
+	s := new(big.Int).SetBytes(signature[32:])
+	if r.Sign() == 0 || r.Cmp(N) >= 0 || s.Sign() == 0 || s.Cmp(N) >= 0 {
+		return false
+	}
+
+	// R = (e/s)*G + (r/s)*Q
+	w := new(big.Int).ModInverse(s, N)
+	u1 := new(big.Int).Mul(hashToInt(hash), w)
+	u1.Mod(u1, N)
+	u2 := new(big.Int).Mul(r, w)
+	u2.Mod(u2, N)
+
+	point := Add(backend.baseMult(u1), ScalarMult(pub, u2))
+	if point.IsInfinity() {
+		return false
+	}
+	return new(big.Int).Mod(point.X, N).Cmp(r) == 0
+}
+
+// RecoverPublicKey recovers the public key that produced a compact signature
+// of a 32-byte hash, given the recovery id returned by SignECDSA.
+func RecoverPublicKey(hash, signature []byte, recoveryID byte) (*Point, error) {
+	if len(hash) != 32 {
+		return nil, ErrInvalidHash
+	}
+	if len(signature) != ECDSASignatureLen {
+		return nil, ErrInvalidSignature
+	}
+	if recoveryID > 3 {
+		return nil, ErrInvalidRecoveryID
+	}
+
+	r := new(big.Int).SetBytes(signature[:32])
+	s := new(big.Int).SetBytes(signature[32:])
+	if r.Sign() == 0 || r.Cmp(N) >= 0 || s.Sign() == 0 || s.Cmp(N) >= 0 {
+		return nil, ErrInvalidSignature
+	}
+
+	// R has x = r (+ N for ids 2 and 3) and the parity encoded in bit 0
+	x := new(big.Int).Set(r)
+	if recoveryID&2 != 0 {
+		x.Add(x, N)
+		if x.Cmp(P) >= 0 {
+			return nil, ErrInvalidSignature
+		}
+	}
+	prefix := PrefixEven
+	if recoveryID&1 != 0 {
+		prefix = PrefixOdd
+	}
+	R, err := DecompressPoint(append([]byte{prefix}, scalarBytes(x)...))
+	if err != nil {
+		return nil, ErrInvalidSignature
+	}
+
+	// Q = r^-1 * (s*R - e*G)
+	rInv := new(big.Int).ModInverse(r, N)
+	eG := backend.baseMult(hashToInt(hash))
+	if !eG.IsInfinity() {
+		eG.Y.Sub(P, eG.Y)
+	}
+	Q := ScalarMult(Add(ScalarMult(R, s), eG), rInv)
+	if Q.IsInfinity() {
+		return nil, ErrInvalidSignature
+	}
+	return Q, nil
+}
+
+// hashToInt converts a 32-byte hash to an integer mod N.
+func hashToInt(hash []byte) *big.Int {
+	e := new(big.Int).SetBytes(hash)
+	return e.Mod(e, N)
+}
+
+// rfc6979 generates deterministic ECDSA nonces (RFC 6979 section 3.2, HMAC-SHA256).
+// Extra entropy is appended to the key and hash in steps d and f, as in
+// section 3.6.
+type rfc6979 struct {
+	k, v []byte
+}
+
+func newRFC6979(privateKey, hash, extraEntropy []byte) *rfc6979 {
+	x := scalarBytes(new(big.Int).SetBytes(privateKey))
+	h1 := scalarBytes(hashToInt(hash))
+
+	g := &rfc6979{
+		k: make([]byte, 32),
+		v: make([]byte, 32),
+	}
+	for i := range g.v {
+		g.v[i] = 0x01
+	}
+
+	g.k = g.mac(g.k, g.v, []byte{0x00}, x, h1, extraEntropy)
+	g.v = g.mac(g.k, g.v)
+	g.k = g.mac(g.k, g.v, []byte{0x01}, x, h1, extraEntropy)
+	g.v = g.mac(g.k, g.v)
+	return g
+}
+
+// next returns the next candidate nonce in [1, N-1].
+func (g *rfc6979) next() *big.Int {
+	for {
+		g.v = g.mac(g.k, g.v)
+		k := new(big.Int).SetBytes(g.v)
+		if k.Sign() > 0 && k.Cmp(N) < 0 {
+			// Prepare the state for a retry should this nonce be rejected
+			g.k = g.mac(g.k, g.v, []byte{0x00})
+			g.v = g.mac(g.k, g.v)
+			return k
+		}
+		g.k = g.mac(g.k, g.v, []byte{0x00})
+		g.v = g.mac(g.k, g.v)
+	}
+}
+
+func (g *rfc6979) mac(key []byte, data ...[]byte) []byte {
+	h := hmac.New(sha256.New, key)
+	for _, d := range data {
+		h.Write(d)
+	}
+	return h.Sum(nil)
+}
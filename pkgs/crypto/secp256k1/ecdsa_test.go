@@ -0,0 +1,160 @@
+package secp256k1
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/hex"
+	"math/big"
+	"strings"
+	"testing"
+)
+
+func TestSignECDSA(t *testing.T) {
+	tests := []struct {
+		name       string
+		privateKey string
+		message    string
+		signature  string
+		recoveryID byte
+	}{
+		{
+			// Widely used RFC 6979 vector (e.g. python-ecdsa, trezor-crypto)
+			name:       "key 1",
+			privateKey: "0000000000000000000000000000000000000000000000000000000000000001",
+			message:    "Satoshi Nakamoto",
+			signature:  "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d82442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+			recoveryID: 1,
+		},
+		{
+			name:       "key N-1",
+			privateKey: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+			message:    "All those moments will be lost in time, like tears in rain. Time to die...",
+			signature:  "059385ce615b7ab6a0db2a3b83f0566d3bc750e958121635ba497ccb4e3ce801391bf93814fda99c98014ada8567dd7c067a50ac0a7ef7aa613b87e0eec17eb5",
+			recoveryID: 1,
+		},
+		{
+			name:       "BIP-32 vector key",
+			privateKey: "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+			message:    "hello",
+			signature:  "6e4e4a0ca6011ee6abe9416058ecc49c6bee290053e553e7dce19126924724777577b6c9b1279af1e9ea5319094a19b091e6a698085d407e334b29741555fda0",
+			recoveryID: 0,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			priv, _ := hex.DecodeString(tt.privateKey)
+			hash := sha256.Sum256([]byte(tt.message))
+
+			sig, recoveryID, err := SignECDSA(priv, hash[:])
+			if err != nil {
+				t.Fatalf("SignECDSA() error = %v", err)
+			}
+			if hex.EncodeToString(sig) != tt.signature {
+				t.Errorf("SignECDSA() = %x, want %s", sig, tt.signature)
+			}
+			if recoveryID != tt.recoveryID {
+				t.Errorf("recovery id = %d, want %d", recoveryID, tt.recoveryID)
+			}
+
+			pub := PrivateKeyToCompressedPublicKey(priv)
+			if !VerifyECDSA(pub, hash[:], sig) {
+				t.Error("VerifyECDSA() = false for a valid signature")
+			}
+			if !VerifyECDSA(SerializeUncompressed(PrivateKeyToPublicKey(priv)), hash[:], sig) {
+				t.Error("VerifyECDSA() = false with an uncompressed key")
+			}
+
+			recovered, err := RecoverPublicKey(hash[:], sig, recoveryID)
+			if err != nil {
+				t.Fatalf("RecoverPublicKey() error = %v", err)
+			}
+			if !bytes.Equal(CompressPoint(recovered), pub) {
+				t.Errorf("RecoverPublicKey() = %x, want %x", CompressPoint(recovered), pub)
+			}
+		})
+	}
+}
+
+func TestVerifyECDSARejects(t *testing.T) {
+	priv, _ := hex.DecodeString(strings.Repeat("11", 32))
+	pub := PrivateKeyToCompressedPublicKey(priv)
+	hash := sha256.Sum256([]byte("message"))
+
+	sig, _, err := SignECDSA(priv, hash[:])
+	if err != nil {
+		t.Fatalf("SignECDSA() error = %v", err)
+	}
+
+	other := sha256.Sum256([]byte("other message"))
+	if VerifyECDSA(pub, other[:], sig) {
+		t.Error("VerifyECDSA() accepted a signature of a different message")
+	}
+
+	tampered := append([]byte(nil), sig...)
+	tampered[10] ^= 0x01
+	if VerifyECDSA(pub, hash[:], tampered) {
+		t.Error("VerifyECDSA() accepted a tampered signature")
+	}
+
+	if _, _, err := SignECDSA(priv, []byte("short")); err != ErrInvalidHash {
+		t.Errorf("SignECDSA(short hash) error = %v, want ErrInvalidHash", err)
+	}
+}
+
+func TestSignECDSAWithEntropy(t *testing.T) {
+	priv, _ := hex.DecodeString("0000000000000000000000000000000000000000000000000000000000000001")
+	pub := PrivateKeyToCompressedPublicKey(priv)
+	hash := sha256.Sum256([]byte("Satoshi Nakamoto"))
+
+	// The well-known RFC 6979 nonce of this key and message
+	nonce, err := RFC6979Nonce(priv, hash[:], nil)
+	if err != nil || hex.EncodeToString(nonce) != "8f8a276c19f4149656b280621e358cce24f5f52542772691ee69063b74f15d15" {
+		t.Errorf("RFC6979Nonce() = %x, %v", nonce, err)
+	}
+
+	// Extra entropy 1, little-endian as Bitcoin Core's R grinding counter
+	extra := make([]byte, 32)
+	extra[0] = 1
+	nonce, err = RFC6979Nonce(priv, hash[:], extra)
+	if err != nil || hex.EncodeToString(nonce) != "b8e91d19741f580eb14a4489493c085b7618caabcd0220cb0ac29161d9ce38a3" {
+		t.Errorf("RFC6979Nonce(extra) = %x, %v", nonce, err)
+	}
+	sig, _, err := SignECDSAWithEntropy(priv, hash[:], extra)
+	if err != nil {
+		t.Fatalf("SignECDSAWithEntropy() error = %v", err)
+	}
+	want := "3311d51d1326e30774b2fb1fbfd5e199ebccb43be1db2ce41051eb2d75e4b68f44d2ea67486df31a242363de1f835d583620fea148ee422c8c80b904b53f5ac3"
+	if hex.EncodeToString(sig) != want {
+		t.Errorf("SignECDSAWithEntropy() = %x, want %s", sig, want)
+	}
+	if !VerifyECDSA(pub, hash[:], sig) {
+		t.Error("VerifyECDSA() rejected a signature with extra entropy")
+	}
+
+	if !IsDeterministicECDSA(priv, hash[:], sig, extra) {
+		t.Error("IsDeterministicECDSA() = false with the signing entropy")
+	}
+	if IsDeterministicECDSA(priv, hash[:], sig, nil) {
+		t.Error("IsDeterministicECDSA() = true without the signing entropy")
+	}
+	plain, _, _ := SignECDSA(priv, hash[:])
+	if !IsDeterministicECDSA(priv, hash[:], plain, nil) {
+		t.Error("IsDeterministicECDSA() = false for SignECDSA")
+	}
+	s := new(big.Int).SetBytes(plain[32:])
+	highS := append(plain[:32:32], scalarBytes(s.Sub(N, s))...)
+	if !IsDeterministicECDSA(priv, hash[:], highS, nil) {
+		t.Error("IsDeterministicECDSA() = false for the high-S twin")
+	}
+
+	// Any valid signature with a different nonce is not deterministic
+	other, _, _ := SignECDSAWithEntropy(priv, hash[:], bytes.Repeat([]byte{0xaa}, 32))
+	if !VerifyECDSA(pub, hash[:], other) || IsDeterministicECDSA(priv, hash[:], other, nil) {
+		t.Error("IsDeterministicECDSA() accepted a signature with another nonce")
+	}
+
+	if _, _, err := SignECDSAWithEntropy(priv, hash[:], []byte("short")); err != ErrInvalidExtraEntropy {
+		t.Errorf("SignECDSAWithEntropy(short entropy) error = %v, want ErrInvalidExtraEntropy", err)
+	}
+}
@@ -87,9 +87,15 @@ func ParsePublicKey(data []byte) (*Point, error) {
 		if data[0] != PrefixUncompressed {
 			return nil, ErrInvalidPublicKey
 		}
-		x := new(big.Int).SetBytes(data[1:33])
-		y := new(big.Int).SetBytes(data[33:65])
-		return &Point{X: x, Y: y}, nil
+		p := &Point{
+			X: new(big.Int).SetBytes(data[1:33]),
+			Y: new(big.Int).SetBytes(data[33:65]),
+		}
+		// An off-curve point would reach a zero denominator in Add or Double
+		if !p.IsOnCurve() {
+			return nil, ErrInvalidPublicKey
+		}
+		return p, nil
 
 	default:
 		return nil, ErrInvalidPublicKey
@@ -110,12 +116,25 @@ func SerializeUncompressed(p *Point) []byte {
 	return result
 }
 
-// PrivateKeyToPublicKey derives the public key from a private key.
+// PrivateKeyToPublicKey derives the public key from a private key. The key
+// must be valid (see IsValidPrivateKey); use PublicKeyFromPrivateKey for
+// keys from untrusted input.
 func PrivateKeyToPublicKey(privateKey []byte) *Point {
 	return ScalarBaseMult(privateKey)
 }
 
-// PrivateKeyToCompressedPublicKey derives the compressed public key from a private key.
+// PublicKeyFromPrivateKey derives the public key from a 32-byte private key,
+// returning ErrInvalidPrivateKey for a key that is zero or not below N
+// instead of the point at infinity or the key's reduction modulo N.
+func PublicKeyFromPrivateKey(privateKey []byte) (*Point, error) {
+	if len(privateKey) != 32 || !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	return ScalarBaseMult(privateKey), nil
+}
+
+// PrivateKeyToCompressedPublicKey derives the compressed public key from a
+// private key, which must be valid as for PrivateKeyToPublicKey.
 func PrivateKeyToCompressedPublicKey(privateKey []byte) []byte {
 	point := ScalarBaseMult(privateKey)
 	return CompressPoint(point)
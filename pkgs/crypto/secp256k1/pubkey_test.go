@@ -169,6 +169,17 @@ func TestParsePublicKeyErrors(t *testing.T) {
 			input:   append([]byte{0x05}, make([]byte, 64)...),
 			wantErr: true,
 		},
+		{
+			name:    "uncompressed off curve",
+			input:   append([]byte{PrefixUncompressed}, make([]byte, 64)...),
+			wantErr: true,
+		},
+		{
+			// y = P is zero modulo P, which Double could not invert
+			name:    "uncompressed y out of range",
+			input:   SerializeUncompressed(&Point{X: Gx, Y: P}),
+			wantErr: true,
+		},
 	}
 
 	for _, tt := range tests {
@@ -191,6 +202,19 @@ func TestPrivateKeyToPublicKey(t *testing.T) {
 	}
 }
 
+func TestPublicKeyFromPrivateKey(t *testing.T) {
+	one, _ := hex.DecodeString("0000000000000000000000000000000000000000000000000000000000000001")
+	if p, err := PublicKeyFromPrivateKey(one); err != nil || !p.Equal(Generator()) {
+		t.Errorf("PublicKeyFromPrivateKey(1) = %v, %v", p, err)
+	}
+
+	for _, key := range [][]byte{make([]byte, 32), N.Bytes(), one[1:], nil} {
+		if _, err := PublicKeyFromPrivateKey(key); err != ErrInvalidPrivateKey {
+			t.Errorf("PublicKeyFromPrivateKey(%x) error = %v, want ErrInvalidPrivateKey", key, err)
+		}
+	}
+}
+
 func TestPrivateKeyToCompressedPublicKey(t *testing.T) {
 	// Known test vector from Bitcoin
 	privKey, _ := hex.DecodeString("0000000000000000000000000000000000000000000000000000000000000001")
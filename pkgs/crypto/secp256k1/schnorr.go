@@ -0,0 +1,169 @@
+package secp256k1
+
+import (
+	"crypto/sha256"
+	"errors"
+	"math/big"
+)
+
+const (
+	// XOnlyPubKeyLen is the length of a BIP-340 x-only public key
+	XOnlyPubKeyLen = 32
+
+	// SchnorrSignatureLen is the length of a BIP-340 signature
+	SchnorrSignatureLen = 64
+)
+
+var (
+	ErrInvalidPrivateKey = errors.New("invalid private key")
+	ErrInvalidAuxRand    = errors.New("auxiliary randomness must be 32 bytes")
+)
+
+// TaggedHash computes the BIP-340 tagged hash: SHA256(SHA256(tag) || SHA256(tag) || data).
+func TaggedHash(tag string, data ...[]byte) []byte {
+	tagHash := sha256.Sum256([]byte(tag))
+
+	h := sha256.New()
+	h.Write(tagHash[:])
+	h.Write(tagHash[:])
+	for _, d := range data {
+		h.Write(d)
+	}
+	return h.Sum(nil)
+}
+
+// XOnlyPublicKey returns the 32-byte x-only public key (BIP-340) for a private key.
+func XOnlyPublicKey(privateKey []byte) ([]byte, error) {
+	if !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	return scalarBytes(ScalarBaseMult(privateKey).X), nil
+}
+
+// LiftX returns the point with the given x coordinate and an even Y coordinate.
+func LiftX(x []byte) (*Point, error) {
+	if len(x) != XOnlyPubKeyLen || new(big.Int).SetBytes(x).Cmp(P) >= 0 {
+		return nil, ErrInvalidPublicKey
+	}
+	return DecompressPoint(append([]byte{PrefixEven}, x...))
+}
+
+// TaprootOutputKey returns the x-only BIP-341 output key of a Taproot output
+// with no script tree (BIP-86): P + H_TapTweak(P.x)*G, where P is the even-Y
+// point of the 32-byte internal key.
+func TaprootOutputKey(internalKey []byte) ([]byte, error) {
+	p, err := LiftX(internalKey)
+	if err != nil {
+		return nil, err
+	}
+	tweak := TaggedHash("TapTweak", internalKey)
+	if new(big.Int).SetBytes(tweak).Cmp(N) >= 0 {
+		return nil, ErrInvalidPublicKey
+	}
+	q := Add(p, ScalarBaseMult(tweak))
+	if q.IsInfinity() {
+		return nil, ErrInvalidPublicKey
+	}
+	return scalarBytes(q.X), nil
+}
+
+// SchnorrSign creates a BIP-340 signature of a message.
+// auxRand is 32 bytes of fresh randomness; nil selects all zeros (deterministic signing).
+func SchnorrSign(privateKey, message, auxRand []byte) ([]byte, error) {
+	if !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	if auxRand == nil {
+		auxRand = make([]byte, 32)
+	}
+	if len(auxRand) != 32 {
+		return nil, ErrInvalidAuxRand
+	}
+
+	// Negate the key if needed so that the public key has an even Y
+	d := new(big.Int).SetBytes(privateKey)
+	pub := backend.baseMult(d)
+	if pub.Y.Bit(0) == 1 {
+		d.Sub(N, d)
+	}
+	px := scalarBytes(pub.X)
+
+	// Nonce: k = H_nonce(d XOR H_aux(a) || P || m)
+	t := scalarBytes(d)
+	aux := TaggedHash("BIP0340/aux", auxRand)
+	for i := range t {
+		t[i] ^= aux[i]
+	}
+	k := new(big.Int).SetBytes(TaggedHash("BIP0340/nonce", t, px, message))
+	k.Mod(k, N)
+	if k.Sign() == 0 {
+		return nil, errors.New("schnorr: nonce is zero")
+	}
+
+	r := backend.baseMult(k)
+	if r.Y.Bit(0) == 1 {
+		k.Sub(N, k)
+	}
+	rx := scalarBytes(r.X)
+
+	// s = k + e*d mod N
+	e := schnorrChallenge(rx, px, message)
+	s := new(big.Int).Mul(e, d)
+	s.Add(s, k)
+	s.Mod(s, N)
+
+	sig := make([]byte, 0, SchnorrSignatureLen)
+	sig = append(sig, rx...)
+	sig = append(sig, scalarBytes(s)...)
+
+	if !SchnorrVerify(px, message, sig) {
+		return nil, errors.New("schnorr: produced signature does not verify")
+	}
+
+	return sig, nil
+}
+
+// SchnorrVerify verifies a BIP-340 signature against an x-only public key.
+func SchnorrVerify(publicKey, message, signature []byte) bool {
+	if len(signature) != SchnorrSignatureLen {
+		return false
+	}
+
+	pub, err := LiftX(publicKey)
+	if err != nil {
+		return false
+	}
+
+	r := new(big.Int).SetBytes(signature[:32])
+	s := new(big.Int).SetBytes(signature[32:])
+	if r.Cmp(P) >= 0 || s.Cmp(N) >= 0 {
+		return false
+	}
+
+	// R = s*G - e*P
+	e := schnorrChallenge(signature[:32], publicKey, message)
+	eP := ScalarMult(pub, e)
+	if !eP.IsInfinity() {
+		eP.Y.Sub(P, eP.Y)
+	}
+	point := Add(backend.baseMult(s), eP)
+
+	if point.IsInfinity() || point.Y.Bit(0) == 1 {
+		return false
+	}
+	return point.X.Cmp(r) == 0
+}
+
+// schnorrChallenge computes e = H_challenge(R.x || P.x || m) mod N.
+func schnorrChallenge(rx, px, message []byte) *big.Int {
+	e := new(big.Int).SetBytes(TaggedHash("BIP0340/challenge", rx, px, message))
+	return e.Mod(e, N)
+}
+
+// scalarBytes encodes an integer as 32 big-endian bytes.
+func scalarBytes(n *big.Int) []byte {
+	result := make([]byte, 32)
+	b := n.Bytes()
+	copy(result[32-len(b):], b)
+	return result
+}
@@ -0,0 +1,89 @@
+package secp256k1
+
+import (
+	"bytes"
+	"encoding/hex"
+	"strings"
+	"testing"
+)
+
+// BIP-340 test vectors
+func TestSchnorrSign(t *testing.T) {
+	tests := []struct {
+		name       string
+		privateKey string
+		publicKey  string
+		auxRand    string
+		message    string
+		signature  string
+	}{
+		{
+			name:       "vector 0",
+			privateKey: "0000000000000000000000000000000000000000000000000000000000000003",
+			publicKey:  "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+			auxRand:    "0000000000000000000000000000000000000000000000000000000000000000",
+			message:    "0000000000000000000000000000000000000000000000000000000000000000",
+			signature:  "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA821525F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+		},
+		{
+			name:       "vector 1",
+			privateKey: "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+			publicKey:  "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+			auxRand:    "0000000000000000000000000000000000000000000000000000000000000001",
+			message:    "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+			signature:  "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE33418906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			priv := hexToBytes(tt.privateKey)
+			msg := hexToBytes(tt.message)
+
+			pub, err := XOnlyPublicKey(priv)
+			if err != nil {
+				t.Fatalf("XOnlyPublicKey failed: %v", err)
+			}
+			if got := strings.ToUpper(hex.EncodeToString(pub)); got != tt.publicKey {
+				t.Errorf("public key = %s, want %s", got, tt.publicKey)
+			}
+
+			sig, err := SchnorrSign(priv, msg, hexToBytes(tt.auxRand))
+			if err != nil {
+				t.Fatalf("SchnorrSign failed: %v", err)
+			}
+			if got := strings.ToUpper(hex.EncodeToString(sig)); got != tt.signature {
+				t.Errorf("signature = %s, want %s", got, tt.signature)
+			}
+
+			if !SchnorrVerify(pub, msg, sig) {
+				t.Error("SchnorrVerify rejected a valid signature")
+			}
+		})
+	}
+}
+
+func TestSchnorrVerifyRejectsTampering(t *testing.T) {
+	priv := hexToBytes("B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF")
+	msg := []byte("hello")
+
+	pub, _ := XOnlyPublicKey(priv)
+	sig, err := SchnorrSign(priv, msg, nil)
+	if err != nil {
+		t.Fatalf("SchnorrSign failed: %v", err)
+	}
+
+	if SchnorrVerify(pub, []byte("hellp"), sig) {
+		t.Error("SchnorrVerify accepted a signature over a different message")
+	}
+
+	tampered := bytes.Clone(sig)
+	tampered[63] ^= 0x01
+	if SchnorrVerify(pub, msg, tampered) {
+		t.Error("SchnorrVerify accepted a tampered signature")
+	}
+
+	if SchnorrVerify(pub, msg, sig[:63]) {
+		t.Error("SchnorrVerify accepted a short signature")
+	}
+}
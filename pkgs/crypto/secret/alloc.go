@@ -0,0 +1,12 @@
+//go:build !ca_mlock || !unix
+
+package secret
+
+// alloc returns a heap buffer of n bytes. Build with -tags ca_mlock on Unix
+// to lock secret buffers into RAM instead.
+func alloc(n int) *buffer {
+	return &buffer{b: make([]byte, n)}
+}
+
+// release does nothing: the wiped slice is left to the garbage collector.
+func release(*buffer) {}
@@ -0,0 +1,30 @@
+//go:build ca_mlock && unix
+
+package secret
+
+import "syscall"
+
+// alloc maps n bytes of anonymous memory outside the Go heap, where the
+// garbage collector never copies it, and locks it into RAM. When locking fails
+// (typically RLIMIT_MEMLOCK) the buffer is still usable but not locked.
+func alloc(n int) *buffer {
+	if n == 0 {
+		return &buffer{b: []byte{}}
+	}
+	b, err := syscall.Mmap(-1, 0, n, syscall.PROT_READ|syscall.PROT_WRITE, syscall.MAP_ANON|syscall.MAP_PRIVATE)
+	if err != nil {
+		return &buffer{b: make([]byte, n)}
+	}
+	return &buffer{b: b, mapped: true, locked: syscall.Mlock(b) == nil}
+}
+
+// release unlocks and unmaps a buffer returned by alloc.
+func release(buf *buffer) {
+	if !buf.mapped {
+		return
+	}
+	if buf.locked {
+		_ = syscall.Munlock(buf.b)
+	}
+	_ = syscall.Munmap(buf.b)
+}
@@ -0,0 +1,155 @@
+// Package secret holds key material in buffers that are wiped when released.
+//
+// A Bytes value owns a fixed-size buffer and only lends it out through Use, or
+// hands out a copy through Bytes, so callers do not keep references that outlive
+// it. Destroy zeroes the buffer;
+// buffers that are never destroyed are zeroed when the garbage collector frees
+// them. Building with -tags ca_mlock on Unix additionally allocates buffers
+// outside the Go heap and locks them into RAM so they are never swapped out.
+//
+// Go copies values freely (function arguments, string conversions, the runtime
+// moving goroutine stacks), so wiping is a best-effort reduction of the window
+// in which a secret sits in memory, not a guarantee.
+package secret
+
+import (
+	"crypto/subtle"
+	"fmt"
+	"io"
+	"runtime"
+)
+
+// Redacted is printed in place of the contents of a Bytes.
+const Redacted = "[REDACTED]"
+
+// Bytes is a fixed-size secret buffer. The zero value is an empty, destroyed
+// buffer. A Bytes must not be copied after first use, and Destroy must not run
+// concurrently with Use.
+type Bytes struct {
+	buf     *buffer
+	cleanup runtime.Cleanup
+}
+
+// buffer is the allocation behind a Bytes. It is kept apart from Bytes so the
+// cleanup registered on a Bytes can still reach it once the Bytes is collected.
+type buffer struct {
+	b      []byte
+	mapped bool // allocated outside the Go heap (ca_mlock builds)
+	locked bool
+}
+
+// New returns a zeroed secret buffer of n bytes.
+func New(n int) *Bytes {
+	if n < 0 {
+		panic("secret: negative length")
+	}
+	s := &Bytes{buf: alloc(n)}
+	s.cleanup = runtime.AddCleanup(s, (*buffer).free, s.buf)
+	return s
+}
+
+// Copy returns a secret buffer holding a copy of src. src is left untouched;
+// use Take to move a secret into the buffer.
+func Copy(src []byte) *Bytes {
+	s := New(len(src))
+	copy(s.buf.b, src)
+	return s
+}
+
+// Take returns a secret buffer holding src and wipes src.
+func Take(src []byte) *Bytes {
+	s := Copy(src)
+	Wipe(src)
+	return s
+}
+
+// Len returns the buffer size, or 0 once destroyed.
+func (s *Bytes) Len() int {
+	if s == nil || s.buf == nil {
+		return 0
+	}
+	return len(s.buf.b)
+}
+
+// Locked reports whether the buffer is locked into RAM (ca_mlock builds only).
+func (s *Bytes) Locked() bool {
+	return s != nil && s.buf != nil && s.buf.locked
+}
+
+// Use calls fn with the buffer. fn may modify it but must not retain it, or
+// anything sliced from it, after returning. A destroyed buffer is passed as nil.
+func (s *Bytes) Use(fn func(b []byte)) {
+	if s == nil || s.buf == nil {
+		fn(nil)
+		return
+	}
+	fn(s.buf.b)
+	runtime.KeepAlive(s)
+}
+
+// Bytes returns a copy of the buffer, or nil if it is empty or destroyed. The
+// caller owns the copy and should Wipe it once done.
+func (s *Bytes) Bytes() []byte {
+	var c []byte
+	s.Use(func(b []byte) {
+		if len(b) > 0 {
+			c = make([]byte, len(b))
+			copy(c, b)
+		}
+	})
+	return c
+}
+
+// Equal reports in constant time whether two buffers hold the same bytes.
+func (s *Bytes) Equal(other *Bytes) bool {
+	var equal bool
+	s.Use(func(a []byte) {
+		other.Use(func(b []byte) {
+			equal = subtle.ConstantTimeCompare(a, b) == 1
+		})
+	})
+	return equal
+}
+
+// Clone returns an independent copy of the buffer.
+func (s *Bytes) Clone() *Bytes {
+	var c *Bytes
+	s.Use(func(b []byte) { c = Copy(b) })
+	return c
+}
+
+// Destroy wipes and releases the buffer. It is safe to call more than once.
+func (s *Bytes) Destroy() {
+	if s == nil || s.buf == nil {
+		return
+	}
+	s.cleanup.Stop()
+	s.buf.free()
+	s.buf = nil
+}
+
+// String returns Redacted.
+func (s *Bytes) String() string {
+	return Redacted
+}
+
+// Format implements fmt.Formatter so that no verb prints the contents.
+func (s *Bytes) Format(f fmt.State, verb rune) {
+	io.WriteString(f, Redacted)
+}
+
+// free wipes the buffer and returns it to the allocator. It runs from Destroy
+// or, for buffers never destroyed, from the cleanup once the Bytes is
+// unreachable.
+func (b *buffer) free() {
+	Wipe(b.b)
+	release(b)
+	b.b = nil
+}
+
+// Wipe zeroes b. Use it on plain slices that held a secret once they are no
+// longer needed.
+func Wipe(b []byte) {
+	clear(b)
+	runtime.KeepAlive(b)
+}
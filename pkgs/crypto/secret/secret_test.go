@@ -0,0 +1,91 @@
+package secret
+
+import (
+	"bytes"
+	"fmt"
+	"strings"
+	"testing"
+)
+
+func TestBytes(t *testing.T) {
+	src := []byte{1, 2, 3, 4}
+	s := Copy(src)
+	if s.Len() != 4 {
+		t.Fatalf("Len() = %d, want 4", s.Len())
+	}
+	s.Use(func(b []byte) {
+		if !bytes.Equal(b, src) {
+			t.Errorf("Use() = %x, want %x", b, src)
+		}
+		b[0] = 9
+	})
+	if src[0] != 1 {
+		t.Error("Copy() aliases its source")
+	}
+
+	if !s.Equal(Copy([]byte{9, 2, 3, 4})) || s.Equal(Copy(src)) || s.Equal(New(3)) {
+		t.Error("Equal() mismatch")
+	}
+	clone := s.Clone()
+	if !clone.Equal(s) {
+		t.Error("Clone() differs from original")
+	}
+
+	var borrowed []byte
+	s.Use(func(b []byte) { borrowed = b })
+	mapped := s.buf.mapped // unmapped memory cannot be read back
+	s.Destroy()
+	s.Destroy()
+	if s.Len() != 0 || s.Locked() {
+		t.Errorf("after Destroy: Len() = %d, Locked() = %v", s.Len(), s.Locked())
+	}
+	if !mapped && !bytes.Equal(borrowed, make([]byte, 4)) {
+		t.Errorf("Destroy() left %x", borrowed)
+	}
+	s.Use(func(b []byte) {
+		if b != nil {
+			t.Errorf("Use() after Destroy = %x, want nil", b)
+		}
+	})
+	if clone.Len() != 4 {
+		t.Error("Destroy() affected a clone")
+	}
+
+	var zero Bytes
+	if zero.Len() != 0 || !zero.Equal(&zero) {
+		t.Error("zero value is not an empty buffer")
+	}
+	zero.Destroy()
+}
+
+func TestTake(t *testing.T) {
+	src := []byte("seed material")
+	s := Take(src)
+	if !bytes.Equal(src, make([]byte, len(src))) {
+		t.Errorf("Take() left source %q", src)
+	}
+	s.Use(func(b []byte) {
+		if string(b) != "seed material" {
+			t.Errorf("Take() = %q", b)
+		}
+	})
+}
+
+func TestRedacted(t *testing.T) {
+	s := Copy([]byte("hunter2"))
+	for _, verb := range []string{"%v", "%+v", "%#v", "%s", "%x", "%q"} {
+		got := fmt.Sprintf(verb, s)
+		if got != Redacted || strings.Contains(got, "hunter2") || strings.Contains(got, "68756e") {
+			t.Errorf("Sprintf(%s) = %q, want %q", verb, got, Redacted)
+		}
+	}
+}
+
+func TestWipe(t *testing.T) {
+	b := []byte{0xff, 0xee, 0xdd}
+	Wipe(b)
+	if !bytes.Equal(b, []byte{0, 0, 0}) {
+		t.Errorf("Wipe() = %x", b)
+	}
+	Wipe(nil)
+}
@@ -0,0 +1,35 @@
+package shamir
+
+// Arithmetic in GF(2^8) with the AES (Rijndael) reduction polynomial
+// x^8 + x^4 + x^3 + x + 1, the field SLIP-39 also uses. Addition is XOR.
+// Multiplication runs the same steps for every input, so it does not leak
+// secret bytes through table lookups.
+
+// gfMul multiplies a and b.
+func gfMul(a, b byte) byte {
+	var p byte
+	for i := 0; i < 8; i++ {
+		p ^= a & -(b & 1)
+		carry := -(a >> 7)
+		a = a<<1 ^ 0x1b&carry
+		b >>= 1
+	}
+	return p
+}
+
+// gfInv returns the multiplicative inverse of a as a^254; gfInv(0) is 0.
+func gfInv(a byte) byte {
+	// a^254 = a^(2+4+8+16+32+64+128)
+	result := byte(1)
+	square := a
+	for i := 0; i < 7; i++ {
+		square = gfMul(square, square)
+		result = gfMul(result, square)
+	}
+	return result
+}
+
+// gfDiv divides a by a non-zero b.
+func gfDiv(a, b byte) byte {
+	return gfMul(a, gfInv(b))
+}
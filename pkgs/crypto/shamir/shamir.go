@@ -0,0 +1,208 @@
+// Package shamir splits byte secrets such as seeds and private keys into
+// t-of-n Shamir shares over GF(256). Each byte of the secret is the constant
+// term of its own random polynomial of degree t-1, and share i holds the
+// polynomials evaluated at x = i, so any t shares recover the secret and
+// fewer reveal nothing about it.
+//
+// Shares are plain binary, with no mnemonic encoding; see package slip39 for
+// word shares that other wallets can restore. Split appends a 4-byte SHA-256
+// digest of the secret before splitting, so Combine detects corrupted shares
+// or shares from different splits instead of returning a wrong secret.
+package shamir
+
+import (
+	"bytes"
+	"crypto/rand"
+	"crypto/sha256"
+	"errors"
+	"fmt"
+	"io"
+)
+
+var (
+	// ErrInvalidThreshold is returned for a threshold outside [1, shares]
+	// or more than MaxShares shares.
+	ErrInvalidThreshold = errors.New("shamir: invalid threshold")
+
+	// ErrTooFewShares is returned when Combine gets fewer shares than the
+	// threshold they were split with.
+	ErrTooFewShares = errors.New("shamir: too few shares")
+
+	// ErrInvalidShare is returned for malformed, duplicate or mismatched shares.
+	ErrInvalidShare = errors.New("shamir: invalid share")
+
+	// ErrDigestMismatch is returned when the combined secret does not match
+	// its digest: a share is corrupted or comes from another split.
+	ErrDigestMismatch = errors.New("shamir: secret digest mismatch")
+)
+
+const (
+	// MaxShares is the largest number of shares; x = 0 is the secret itself.
+	MaxShares = 255
+
+	// DigestSize is the number of digest bytes appended to the secret.
+	DigestSize = 4
+)
+
+// Share is one share of a secret.
+type Share struct {
+	// Threshold is the number of shares needed to recover the secret.
+	Threshold int `json:"threshold"`
+
+	// X is the share's evaluation point, from 1 to the number of shares.
+	X byte `json:"x"`
+
+	// Y holds the polynomials evaluated at X, DigestSize bytes longer than
+	// the secret. It is as sensitive as the secret once Threshold shares
+	// are gathered.
+	Y []byte `json:"y"`
+}
+
+// MarshalBinary encodes the share as threshold || x || y.
+func (s Share) MarshalBinary() ([]byte, error) {
+	if s.Threshold < 1 || s.Threshold > MaxShares {
+		return nil, fmt.Errorf("%w: threshold %d", ErrInvalidShare, s.Threshold)
+	}
+	return append([]byte{byte(s.Threshold), s.X}, s.Y...), nil
+}
+
+// UnmarshalBinary decodes a share written by MarshalBinary.
+func (s *Share) UnmarshalBinary(data []byte) error {
+	if len(data) < 2+DigestSize || data[0] == 0 || data[1] == 0 {
+		return fmt.Errorf("%w: malformed encoding", ErrInvalidShare)
+	}
+	s.Threshold, s.X = int(data[0]), data[1]
+	s.Y = append([]byte(nil), data[2:]...)
+	return nil
+}
+
+// Split splits secret into count shares, any threshold of which recover it.
+// It reads the polynomial coefficients from rnd, or from crypto/rand.Reader
+// when rnd is nil.
+func Split(rnd io.Reader, secret []byte, threshold, count int) ([]Share, error) {
+	if count > MaxShares || threshold < 1 || threshold > count {
+		return nil, fmt.Errorf("%w: %d of %d", ErrInvalidThreshold, threshold, count)
+	}
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+
+	digest := sha256.Sum256(secret)
+	value := append(append([]byte(nil), secret...), digest[:DigestSize]...)
+
+	// coefficients[k] holds the degree-k coefficient of every byte's polynomial
+	coefficients := make([][]byte, threshold)
+	coefficients[0] = value
+	for k := 1; k < threshold; k++ {
+		coefficients[k] = make([]byte, len(value))
+		if _, err := io.ReadFull(rnd, coefficients[k]); err != nil {
+			return nil, err
+		}
+	}
+
+	shares := make([]Share, count)
+	for i := range shares {
+		x := byte(i + 1)
+		y := make([]byte, len(value))
+		for j := range y {
+			// Horner's rule from the highest coefficient down
+			var acc byte
+			for k := threshold - 1; k >= 0; k-- {
+				acc = gfMul(acc, x) ^ coefficients[k][j]
+			}
+			y[j] = acc
+		}
+		shares[i] = Share{Threshold: threshold, X: x, Y: y}
+	}
+
+	for _, c := range coefficients[1:] {
+		clear(c)
+	}
+	clear(value)
+	return shares, nil
+}
+
+// Combine recovers the secret from at least Threshold shares of one split.
+// Extra shares beyond the threshold are ignored.
+func Combine(shares []Share) ([]byte, error) {
+	if len(shares) == 0 {
+		return nil, ErrTooFewShares
+	}
+	threshold := shares[0].Threshold
+	if threshold < 1 {
+		return nil, fmt.Errorf("%w: threshold %d", ErrInvalidShare, threshold)
+	}
+	if len(shares) < threshold {
+		return nil, fmt.Errorf("%w: have %d, need %d", ErrTooFewShares, len(shares), threshold)
+	}
+
+	xs := make([]byte, threshold)
+	ys := make([][]byte, threshold)
+	for i, s := range shares[:threshold] {
+		if s.X == 0 {
+			return nil, fmt.Errorf("%w: x must not be 0", ErrInvalidShare)
+		}
+		if s.Threshold != threshold || len(s.Y) != len(shares[0].Y) {
+			return nil, fmt.Errorf("%w: share %d is from another split", ErrInvalidShare, s.X)
+		}
+		xs[i], ys[i] = s.X, s.Y
+	}
+	value, err := Interpolate(xs, ys, 0)
+	if err != nil {
+		return nil, err
+	}
+	if len(value) < DigestSize {
+		return nil, fmt.Errorf("%w: share too short", ErrInvalidShare)
+	}
+
+	secret, check := value[:len(value)-DigestSize], value[len(value)-DigestSize:]
+	digest := sha256.Sum256(secret)
+	if !bytes.Equal(check, digest[:DigestSize]) {
+		clear(value)
+		return nil, ErrDigestMismatch
+	}
+	return secret, nil
+}
+
+// Interpolate evaluates at x the byte-wise polynomials through the points
+// (xs[i], ys[i]) by Lagrange interpolation. The xs must be distinct and the
+// ys of equal length. Combine uses it with x = 0; schemes such as SLIP-39
+// also evaluate at other points.
+func Interpolate(xs []byte, ys [][]byte, x byte) ([]byte, error) {
+	if len(xs) == 0 || len(xs) != len(ys) {
+		return nil, fmt.Errorf("%w: %d points, %d values", ErrInvalidShare, len(xs), len(ys))
+	}
+	for i := range xs {
+		if len(ys[i]) != len(ys[0]) {
+			return nil, fmt.Errorf("%w: values of different lengths", ErrInvalidShare)
+		}
+		for j := range xs[:i] {
+			if xs[i] == xs[j] {
+				return nil, fmt.Errorf("%w: duplicate x %d", ErrInvalidShare, xs[i])
+			}
+		}
+	}
+
+	// Only after every point has been checked: a shortcut taken in the loop
+	// would hide a malformed point later in the input.
+	for i := range xs {
+		if xs[i] == x {
+			return append([]byte(nil), ys[i]...), nil
+		}
+	}
+
+	result := make([]byte, len(ys[0]))
+	for i := range xs {
+		// basis = prod_{j != i} (x - x_j) / (x_i - x_j); subtraction is XOR
+		basis := byte(1)
+		for j := range xs {
+			if j != i {
+				basis = gfMul(basis, gfDiv(x^xs[j], xs[i]^xs[j]))
+			}
+		}
+		for k, y := range ys[i] {
+			result[k] ^= gfMul(basis, y)
+		}
+	}
+	return result, nil
+}
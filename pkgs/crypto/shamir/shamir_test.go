@@ -0,0 +1,113 @@
+package shamir
+
+import (
+	"bytes"
+	"errors"
+	"testing"
+)
+
+func TestGF256(t *testing.T) {
+	// 0x53 and 0xca are inverses in the AES field
+	if got := gfMul(0x53, 0xca); got != 0x01 {
+		t.Errorf("gfMul(0x53, 0xca) = %#x, want 0x01", got)
+	}
+	for a := 1; a < 256; a++ {
+		if gfMul(byte(a), gfInv(byte(a))) != 1 {
+			t.Fatalf("gfInv(%#x) is not an inverse", a)
+		}
+	}
+}
+
+func TestSplitCombine(t *testing.T) {
+	secret := bytes.Repeat([]byte{0x5a, 0x01, 0xff}, 22)[:64]
+	shares, err := Split(nil, secret, 3, 5)
+	if err != nil {
+		t.Fatalf("Split() error = %v", err)
+	}
+	if len(shares) != 5 || len(shares[0].Y) != len(secret)+DigestSize {
+		t.Fatalf("Split() = %d shares of %d bytes", len(shares), len(shares[0].Y))
+	}
+
+	for _, pick := range [][]int{{0, 1, 2}, {4, 2, 0}, {1, 3, 4}, {0, 1, 2, 3, 4}} {
+		var subset []Share
+		for _, i := range pick {
+			subset = append(subset, shares[i])
+		}
+		got, err := Combine(subset)
+		if err != nil || !bytes.Equal(got, secret) {
+			t.Errorf("Combine(%v) = %x, %v", pick, got, err)
+		}
+	}
+
+	if _, err := Combine(shares[:2]); !errors.Is(err, ErrTooFewShares) {
+		t.Errorf("Combine(2 of 3) error = %v, want ErrTooFewShares", err)
+	}
+	if _, err := Combine([]Share{shares[0], shares[0], shares[1]}); !errors.Is(err, ErrInvalidShare) {
+		t.Errorf("Combine(duplicate) error = %v, want ErrInvalidShare", err)
+	}
+
+	corrupted := shares[1]
+	corrupted.Y = append([]byte(nil), corrupted.Y...)
+	corrupted.Y[7] ^= 0x10
+	if _, err := Combine([]Share{shares[0], corrupted, shares[2]}); !errors.Is(err, ErrDigestMismatch) {
+		t.Errorf("Combine(corrupted) error = %v, want ErrDigestMismatch", err)
+	}
+
+	other, _ := Split(nil, secret, 3, 5)
+	if _, err := Combine([]Share{shares[0], shares[1], other[2]}); !errors.Is(err, ErrDigestMismatch) {
+		t.Errorf("Combine(mixed splits) error = %v, want ErrDigestMismatch", err)
+	}
+}
+
+func TestSplitThreshold(t *testing.T) {
+	for _, tt := range [][2]int{{0, 3}, {4, 3}, {2, MaxShares + 1}} {
+		if _, err := Split(nil, []byte("secret"), tt[0], tt[1]); !errors.Is(err, ErrInvalidThreshold) {
+			t.Errorf("Split(%d of %d) error = %v", tt[0], tt[1], err)
+		}
+	}
+
+	// A 1-of-n split stores the secret in every share
+	shares, _ := Split(nil, []byte("secret"), 1, 2)
+	if got, err := Combine(shares[1:]); err != nil || string(got) != "secret" {
+		t.Errorf("Combine(1 of 2) = %q, %v", got, err)
+	}
+}
+
+func TestShareBinary(t *testing.T) {
+	shares, _ := Split(nil, []byte{1, 2, 3}, 2, 3)
+	data, err := shares[2].MarshalBinary()
+	if err != nil || len(data) != 2+3+DigestSize || data[0] != 2 || data[1] != 3 {
+		t.Fatalf("MarshalBinary() = %x, %v", data, err)
+	}
+
+	var decoded Share
+	if err := decoded.UnmarshalBinary(data); err != nil {
+		t.Fatalf("UnmarshalBinary() error = %v", err)
+	}
+	if got, err := Combine([]Share{shares[0], decoded}); err != nil || !bytes.Equal(got, []byte{1, 2, 3}) {
+		t.Errorf("Combine() with a decoded share = %x, %v", got, err)
+	}
+	if err := decoded.UnmarshalBinary([]byte{2, 0, 1, 2, 3, 4}); !errors.Is(err, ErrInvalidShare) {
+		t.Errorf("UnmarshalBinary(x = 0) error = %v, want ErrInvalidShare", err)
+	}
+}
+
+func TestInterpolateValidatesEveryPoint(t *testing.T) {
+	ys := [][]byte{{1, 2}, {3, 4}, {5, 6}}
+	if got, err := Interpolate([]byte{1, 2, 3}, ys, 2); err != nil || !bytes.Equal(got, ys[1]) {
+		t.Errorf("Interpolate(at a point) = %x, %v", got, err)
+	}
+
+	// Each malformed point comes after the one the evaluation hits
+	for name, tt := range map[string]struct {
+		xs []byte
+		ys [][]byte
+	}{
+		"duplicate x":     {[]byte{1, 2, 2}, ys},
+		"length mismatch": {[]byte{1, 2, 3}, [][]byte{{1, 2}, {3, 4}, {5}}},
+	} {
+		if _, err := Interpolate(tt.xs, tt.ys, 1); !errors.Is(err, ErrInvalidShare) {
+			t.Errorf("Interpolate(%s) error = %v, want ErrInvalidShare", name, err)
+		}
+	}
+}
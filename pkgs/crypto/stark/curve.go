@@ -0,0 +1,209 @@
+// Package stark provides elliptic curve operations for the Stark curve used by
+// Starknet and StarkEx: y^2 = x^3 + alpha*x + beta over the 252-bit prime field.
+package stark
+
+import (
+	"crypto/sha256"
+	"errors"
+	"math/big"
+)
+
+// Curve parameters for the Stark curve
+var (
+	// P is the prime field of the curve: 2^251 + 17*2^192 + 1
+	P, _ = new(big.Int).SetString("800000000000011000000000000000000000000000000000000000000000001", 16)
+
+	// N is the order of the curve
+	N, _ = new(big.Int).SetString("800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f", 16)
+
+	// Alpha is the coefficient of x
+	Alpha = big.NewInt(1)
+
+	// Beta is the constant term
+	Beta, _ = new(big.Int).SetString("6f21413efbe40de150e596d72f7a8c5609ad26c15c915c1f4cdfcb99cee9e89", 16)
+
+	// Gx is the x-coordinate of the generator point
+	Gx, _ = new(big.Int).SetString("1ef15c18599971b7beced415a40f0c7deacfd9b0d1819e03d723d8bc943cfca", 16)
+
+	// Gy is the y-coordinate of the generator point
+	Gy, _ = new(big.Int).SetString("5668060aa49730b7be4801df46ec62de53ecd11abe43a32873000c36e8dc1f", 16)
+)
+
+var (
+	ErrInvalidPrivateKey = errors.New("invalid stark private key")
+)
+
+// Point represents a point on the Stark curve.
+// The point at infinity is represented as (0, 0), which is not on the curve.
+type Point struct {
+	X, Y *big.Int
+}
+
+// Generator returns the generator point G of the Stark curve.
+func Generator() *Point {
+	return &Point{
+		X: new(big.Int).Set(Gx),
+		Y: new(big.Int).Set(Gy),
+	}
+}
+
+// Infinity returns the point at infinity (identity element).
+func Infinity() *Point {
+	return &Point{
+		X: big.NewInt(0),
+		Y: big.NewInt(0),
+	}
+}
+
+// IsInfinity returns true if the point is the point at infinity.
+func (p *Point) IsInfinity() bool {
+	return p.X.Sign() == 0 && p.Y.Sign() == 0
+}
+
+// Clone returns a deep copy of the point.
+func (p *Point) Clone() *Point {
+	return &Point{
+		X: new(big.Int).Set(p.X),
+		Y: new(big.Int).Set(p.Y),
+	}
+}
+
+// IsOnCurve reports whether the point satisfies the curve equation.
+func (p *Point) IsOnCurve() bool {
+	y2 := new(big.Int).Mul(p.Y, p.Y)
+	y2.Mod(y2, P)
+
+	rhs := new(big.Int).Exp(p.X, big.NewInt(3), P)
+	rhs.Add(rhs, new(big.Int).Mul(Alpha, p.X))
+	rhs.Add(rhs, Beta)
+	rhs.Mod(rhs, P)
+
+	return y2.Cmp(rhs) == 0
+}
+
+// Add performs point addition: P1 + P2.
+func Add(p1, p2 *Point) *Point {
+	if p1.IsInfinity() {
+		return p2.Clone()
+	}
+	if p2.IsInfinity() {
+		return p1.Clone()
+	}
+
+	if p1.X.Cmp(p2.X) == 0 {
+		if p1.Y.Cmp(p2.Y) == 0 {
+			return Double(p1)
+		}
+		return Infinity()
+	}
+
+	// lambda = (y2 - y1) / (x2 - x1) mod P
+	dy := new(big.Int).Sub(p2.Y, p1.Y)
+	dx := new(big.Int).Sub(p2.X, p1.X)
+	dx.Mod(dx, P)
+	lambda := new(big.Int).Mul(dy, new(big.Int).ModInverse(dx, P))
+	lambda.Mod(lambda, P)
+
+	return lineIntersection(p1, p2, lambda)
+}
+
+// Double performs point doubling: 2P.
+func Double(p *Point) *Point {
+	if p.Y.Sign() == 0 {
+		return Infinity()
+	}
+
+	// lambda = (3 * x^2 + alpha) / (2 * y) mod P
+	numerator := new(big.Int).Mul(p.X, p.X)
+	numerator.Mul(numerator, big.NewInt(3))
+	numerator.Add(numerator, Alpha)
+
+	denominator := new(big.Int).Mul(p.Y, big.NewInt(2))
+	lambda := new(big.Int).Mul(numerator, new(big.Int).ModInverse(denominator, P))
+	lambda.Mod(lambda, P)
+
+	return lineIntersection(p, p, lambda)
+}
+
+// lineIntersection returns the third point on the line through p1 and p2 with
+// slope lambda, reflected over the x-axis.
+func lineIntersection(p1, p2 *Point, lambda *big.Int) *Point {
+	// x3 = lambda^2 - x1 - x2 mod P
+	x3 := new(big.Int).Mul(lambda, lambda)
+	x3.Sub(x3, p1.X)
+	x3.Sub(x3, p2.X)
+	x3.Mod(x3, P)
+
+	// y3 = lambda * (x1 - x3) - y1 mod P
+	y3 := new(big.Int).Sub(p1.X, x3)
+	y3.Mul(y3, lambda)
+	y3.Sub(y3, p1.Y)
+	y3.Mod(y3, P)
+
+	return &Point{X: x3, Y: y3}
+}
+
+// ScalarMult performs scalar multiplication: k * P using double-and-add algorithm.
+func ScalarMult(p *Point, k *big.Int) *Point {
+	result := Infinity()
+	addend := p.Clone()
+
+	for i := 0; i < k.BitLen(); i++ {
+		if k.Bit(i) == 1 {
+			result = Add(result, addend)
+		}
+		addend = Double(addend)
+	}
+
+	return result
+}
+
+// ScalarBaseMult performs scalar multiplication with the generator point: k * G.
+func ScalarBaseMult(k *big.Int) *Point {
+	return ScalarMult(Generator(), k)
+}
+
+// IsValidPrivateKey checks if a private key is in the range [1, N).
+func IsValidPrivateKey(key *big.Int) bool {
+	return key.Sign() > 0 && key.Cmp(N) < 0
+}
+
+// PublicKey returns the Stark public key: the x-coordinate of k * G.
+func PublicKey(privateKey *big.Int) (*big.Int, error) {
+	if !IsValidPrivateKey(privateKey) {
+		return nil, ErrInvalidPrivateKey
+	}
+	return ScalarBaseMult(privateKey).X, nil
+}
+
+// GrindKey maps 32 bytes of key material (typically a BIP-32 private key) to
+// a valid Stark private key, following StarkWare's key derivation:
+// hash SHA256(seed || i) for i = 0, 1, ... until the digest falls below the
+// largest multiple of N that fits in 256 bits, then reduce it modulo N.
+// This avoids the modulo bias of reducing a 256-bit value directly.
+func GrindKey(seed []byte) (*big.Int, error) {
+	if len(seed) != 32 {
+		return nil, ErrInvalidPrivateKey
+	}
+
+	limit := new(big.Int).Lsh(big.NewInt(1), 256)
+	limit.Sub(limit, new(big.Int).Mod(limit, N))
+
+	for i := 0; i < 256; i++ {
+		digest := sha256.Sum256(append(append([]byte(nil), seed...), byte(i)))
+		key := new(big.Int).SetBytes(digest[:])
+		if key.Cmp(limit) < 0 {
+			return key.Mod(key, N), nil
+		}
+	}
+
+	return nil, ErrInvalidPrivateKey
+}
+
+// FeltBytes encodes a field element as 32 big-endian bytes.
+func FeltBytes(n *big.Int) []byte {
+	result := make([]byte, 32)
+	b := n.Bytes()
+	copy(result[32-len(b):], b)
+	return result
+}
@@ -0,0 +1,73 @@
+package stark
+
+import (
+	"math/big"
+	"testing"
+)
+
+func TestCurveParameters(t *testing.T) {
+	// P = 2^251 + 17*2^192 + 1
+	expected := new(big.Int).Lsh(big.NewInt(1), 251)
+	expected.Add(expected, new(big.Int).Lsh(big.NewInt(17), 192))
+	expected.Add(expected, big.NewInt(1))
+	if P.Cmp(expected) != 0 {
+		t.Errorf("P = %x, want %x", P, expected)
+	}
+
+	if !Generator().IsOnCurve() {
+		t.Error("generator is not on the curve")
+	}
+	for i, p := range pedersenPoints {
+		if !p.IsOnCurve() {
+			t.Errorf("Pedersen point P%d is not on the curve", i)
+		}
+	}
+
+	if !ScalarBaseMult(N).IsInfinity() {
+		t.Error("N * G should be the point at infinity")
+	}
+}
+
+func TestPublicKey(t *testing.T) {
+	pub, err := PublicKey(big.NewInt(1))
+	if err != nil {
+		t.Fatalf("PublicKey failed: %v", err)
+	}
+	if pub.Cmp(Gx) != 0 {
+		t.Errorf("PublicKey(1) = %x, want Gx", pub)
+	}
+
+	if _, err := PublicKey(big.NewInt(0)); err == nil {
+		t.Error("PublicKey(0) should fail")
+	}
+	if _, err := PublicKey(N); err == nil {
+		t.Error("PublicKey(N) should fail")
+	}
+}
+
+func TestGrindKey(t *testing.T) {
+	seed := hexToBytes("74f8cf8eac12b34fdcd81ee39bc99e81bb94e2f51d568c0ad29103cd9d48634a")
+
+	key, err := GrindKey(seed)
+	if err != nil {
+		t.Fatalf("GrindKey failed: %v", err)
+	}
+	if key.Text(16) != "1b8e16cdf31892c56c0370f0e4ca0da096ef4e0c81007b3ba10b11452f8971" {
+		t.Errorf("GrindKey = %x", key)
+	}
+	if !IsValidPrivateKey(key) {
+		t.Error("ground key is out of range")
+	}
+
+	if _, err := GrindKey(seed[:31]); err == nil {
+		t.Error("GrindKey should reject short seeds")
+	}
+}
+
+func hexToBytes(s string) []byte {
+	b, ok := new(big.Int).SetString(s, 16)
+	if !ok {
+		panic("invalid hex")
+	}
+	return FeltBytes(b)
+}
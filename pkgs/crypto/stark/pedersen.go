@@ -0,0 +1,84 @@
+package stark
+
+import (
+	"errors"
+	"math/big"
+)
+
+// Pedersen hash constant points (P0 is the shift point)
+var pedersenPoints = [5]*Point{
+	mustPoint(
+		"49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804",
+		"3ca0cfe4b3bc6ddf346d49d06ea0ed34e621062c0e056c1d0405d266e10268a",
+	),
+	mustPoint(
+		"234287dcbaffe7f969c748655fca9e58fa8120b6d56eb0c1080d17957ebe47b",
+		"3b056f100f96fb21e889527d41f4e39940135dd7a6c94cc6ed0268ee89e5615",
+	),
+	mustPoint(
+		"4fa56f376c83db33f9dab2656558f3399099ec1de5e3018b7a6932dba8aa378",
+		"3fa0984c931c9e38113e0c0e47e4401562761f92a7a23b45168f4e80ff5b54d",
+	),
+	mustPoint(
+		"4ba4cc166be8dec764910f75b45f74b40c690c74709e90f3aa372f0bd2d6997",
+		"40301cf5c1751f4b971e46c4ede85fcac5c59a5ce5ae7c48151f27b24b219c",
+	),
+	mustPoint(
+		"54302dcb0e6cc1c6e44cca8f61a63bb2ca65048d53fb325d36ff12c49a58202",
+		"1b77b3e37d13504b348046268d8ae25ce98ad783c25561a879dcc77e99c2426",
+	),
+}
+
+// lowPartMask selects the low 248 bits of a field element
+var lowPartMask = new(big.Int).Sub(new(big.Int).Lsh(big.NewInt(1), 248), big.NewInt(1))
+
+var (
+	ErrFeltOutOfRange = errors.New("field element out of range")
+)
+
+// mustPoint builds a curve point from hex coordinates.
+func mustPoint(x, y string) *Point {
+	px, ok1 := new(big.Int).SetString(x, 16)
+	py, ok2 := new(big.Int).SetString(y, 16)
+	if !ok1 || !ok2 {
+		panic("stark: invalid point constant")
+	}
+	return &Point{X: px, Y: py}
+}
+
+// PedersenHash computes the Starknet Pedersen hash of two field elements:
+// [P0 + a_low*P1 + a_high*P2 + b_low*P3 + b_high*P4].x
+// where x_low is the low 248 bits and x_high the remaining 4 bits.
+func PedersenHash(a, b *big.Int) (*big.Int, error) {
+	result := pedersenPoints[0].Clone()
+
+	for i, x := range []*big.Int{a, b} {
+		if x.Sign() < 0 || x.Cmp(P) >= 0 {
+			return nil, ErrFeltOutOfRange
+		}
+
+		low := new(big.Int).And(x, lowPartMask)
+		high := new(big.Int).Rsh(x, 248)
+
+		result = Add(result, ScalarMult(pedersenPoints[1+2*i], low))
+		result = Add(result, ScalarMult(pedersenPoints[2+2*i], high))
+	}
+
+	return result.X, nil
+}
+
+// PedersenHashOnElements hashes an array of field elements as Starknet's
+// compute_hash_on_elements: h(h(h(h(0, e0), e1), ...), len).
+func PedersenHashOnElements(elements ...*big.Int) (*big.Int, error) {
+	h := big.NewInt(0)
+
+	var err error
+	for _, e := range elements {
+		h, err = PedersenHash(h, e)
+		if err != nil {
+			return nil, err
+		}
+	}
+
+	return PedersenHash(h, big.NewInt(int64(len(elements))))
+}
@@ -0,0 +1,42 @@
+package stark
+
+import (
+	"math/big"
+	"testing"
+)
+
+func hexToInt(s string) *big.Int {
+	n, ok := new(big.Int).SetString(s, 16)
+	if !ok {
+		panic("invalid hex")
+	}
+	return n
+}
+
+// Test vector from StarkWare's crypto library
+func TestPedersenHash(t *testing.T) {
+	a := hexToInt("03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb")
+	b := hexToInt("0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a")
+
+	h, err := PedersenHash(a, b)
+	if err != nil {
+		t.Fatalf("PedersenHash failed: %v", err)
+	}
+	if h.Text(16) != "30e480bed5fe53fa909cc0f8c4d99b8f9f2c016be4c41e13a4848797979c662" {
+		t.Errorf("PedersenHash = %x", h)
+	}
+
+	if _, err := PedersenHash(P, b); err == nil {
+		t.Error("PedersenHash should reject elements >= P")
+	}
+}
+
+func TestPedersenHashOnElements(t *testing.T) {
+	h, err := PedersenHashOnElements(big.NewInt(1), big.NewInt(2), big.NewInt(3))
+	if err != nil {
+		t.Fatalf("PedersenHashOnElements failed: %v", err)
+	}
+	if h.Text(16) != "f9d95fbf356fbeda26538c92f7040abe51bf142350f73c9ee5ba7c660bae71" {
+		t.Errorf("PedersenHashOnElements = %x", h)
+	}
+}
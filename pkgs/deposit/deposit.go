@@ -0,0 +1,132 @@
+// Package deposit hands out fresh derivation indexes for deposit addresses.
+// An IndexAllocator keeps the next unused index per chain and account and
+// records it in a Store before returning an index, so an index is never
+// handed out twice, even across restarts. A crash between recording and
+// using an index only leaves a gap, which account discovery tolerates as
+// long as it stays under the gap limit.
+package deposit
+
+import (
+	"errors"
+	"fmt"
+	"sync"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// ErrExhausted is returned when every non-hardened index has been allocated.
+var ErrExhausted = errors.New("deposit: no unused index left")
+
+// Key identifies an index sequence: a chain and a BIP-44 account on it.
+type Key struct {
+	Chain   address.ChainID `json:"chain"`
+	Account uint32          `json:"account"`
+}
+
+// String returns "chain/account".
+func (k Key) String() string {
+	return fmt.Sprintf("%s/%d", k.Chain, k.Account)
+}
+
+// Store persists the next unused index of each key. Implementations may
+// wrap a file, a database table or a key-value store; they need not be safe
+// for concurrent use, since the allocator serializes its calls.
+type Store interface {
+	// Load returns the next unused index for key, or 0 if none was saved.
+	Load(key Key) (uint32, error)
+
+	// Save records next as the next unused index for key. The allocator
+	// hands out the index below next once Save returns, so Save must not
+	// return before the value is durable.
+	Save(key Key, next uint32) error
+}
+
+// IndexAllocator hands out increasing derivation indexes, safe for
+// concurrent use.
+type IndexAllocator struct {
+	mu    sync.Mutex
+	store Store
+	next  map[Key]uint32
+}
+
+// NewIndexAllocator creates an allocator backed by store. A nil store keeps
+// the indexes in memory only.
+func NewIndexAllocator(store Store) *IndexAllocator {
+	if store == nil {
+		store = NewMemoryStore()
+	}
+	return &IndexAllocator{store: store, next: make(map[Key]uint32)}
+}
+
+// Next reserves and returns the next unused index for key.
+func (a *IndexAllocator) Next(key Key) (uint32, error) {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+
+	index, err := a.load(key)
+	if err != nil {
+		return 0, err
+	}
+	if index >= bip32.HardenedKeyStart {
+		return 0, fmt.Errorf("%w: %s", ErrExhausted, key)
+	}
+	if err := a.save(key, index+1); err != nil {
+		return 0, err
+	}
+	return index, nil
+}
+
+// Peek returns the index Next would return, without reserving it.
+func (a *IndexAllocator) Peek(key Key) (uint32, error) {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+	return a.load(key)
+}
+
+// Advance moves key's next index up to next, for example to discovery's
+// NextExternal after restoring a wallet whose addresses were handed out
+// elsewhere. It never moves the index back.
+func (a *IndexAllocator) Advance(key Key, next uint32) error {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+
+	current, err := a.load(key)
+	if err != nil || next <= current {
+		return err
+	}
+	return a.save(key, next)
+}
+
+// NextAccount reserves the next index of w's account 0 on chain and returns
+// the account at that index, whose address is the new deposit address.
+func (a *IndexAllocator) NextAccount(w *account.Wallet, chain address.ChainID) (account.ChainAccount, error) {
+	index, err := a.Next(Key{Chain: chain})
+	if err != nil {
+		return nil, err
+	}
+	return w.Account(chain, index)
+}
+
+// load returns the cached next index, reading it from the store on first use.
+func (a *IndexAllocator) load(key Key) (uint32, error) {
+	if next, ok := a.next[key]; ok {
+		return next, nil
+	}
+	next, err := a.store.Load(key)
+	if err != nil {
+		return 0, fmt.Errorf("deposit: loading %s: %w", key, err)
+	}
+	a.next[key] = next
+	return next, nil
+}
+
+// save writes next to the store and, once it is durable, to the cache.
+func (a *IndexAllocator) save(key Key, next uint32) error {
+	if err := a.store.Save(key, next); err != nil {
+		return fmt.Errorf("deposit: saving %s: %w", key, err)
+	}
+	a.next[key] = next
+	return nil
+}
@@ -0,0 +1,127 @@
+package deposit
+
+import (
+	"errors"
+	"path/filepath"
+	"sync"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+func TestIndexAllocator(t *testing.T) {
+	a := NewIndexAllocator(nil)
+	eth, btc := Key{Chain: address.ChainEthereum}, Key{Chain: address.ChainBitcoin, Account: 1}
+
+	for want := uint32(0); want < 3; want++ {
+		if got, err := a.Next(eth); err != nil || got != want {
+			t.Errorf("Next(eth) = %d, %v, want %d", got, err, want)
+		}
+	}
+	if got, _ := a.Next(btc); got != 0 {
+		t.Errorf("Next(btc) = %d, want 0", got)
+	}
+	if got, _ := a.Peek(eth); got != 3 {
+		t.Errorf("Peek(eth) = %d, want 3", got)
+	}
+
+	// Advance skips ahead but never back
+	if err := a.Advance(eth, 10); err != nil {
+		t.Fatal(err)
+	}
+	if err := a.Advance(eth, 5); err != nil {
+		t.Fatal(err)
+	}
+	if got, _ := a.Next(eth); got != 10 {
+		t.Errorf("Next(eth) after Advance = %d, want 10", got)
+	}
+
+	if err := a.Advance(btc, bip32.HardenedKeyStart); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := a.Next(btc); !errors.Is(err, ErrExhausted) {
+		t.Errorf("Next() past the last index error = %v, want ErrExhausted", err)
+	}
+}
+
+func TestIndexAllocatorConcurrent(t *testing.T) {
+	a := NewIndexAllocator(NewMemoryStore())
+	key := Key{Chain: address.ChainSolana}
+
+	const n = 100
+	var wg sync.WaitGroup
+	indexes := make([]uint32, n)
+	for i := range indexes {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			indexes[i], _ = a.Next(key)
+		}()
+	}
+	wg.Wait()
+
+	seen := make(map[uint32]bool)
+	for _, index := range indexes {
+		if seen[index] || index >= n {
+			t.Fatalf("index %d handed out twice or out of range", index)
+		}
+		seen[index] = true
+	}
+}
+
+func TestFileStore(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "indexes.json")
+	key := Key{Chain: address.ChainEthereum}
+
+	a := NewIndexAllocator(NewFileStore(path))
+	for i := 0; i < 3; i++ {
+		if _, err := a.Next(key); err != nil {
+			t.Fatalf("Next() error = %v", err)
+		}
+	}
+
+	// A new allocator over the same file continues where the first stopped
+	restarted := NewIndexAllocator(NewFileStore(path))
+	if got, err := restarted.Next(key); err != nil || got != 3 {
+		t.Errorf("Next() after restart = %d, %v, want 3", got, err)
+	}
+	if got, _ := restarted.Next(Key{Chain: address.ChainEthereum, Account: 1}); got != 0 {
+		t.Errorf("Next(account 1) = %d, want 0", got)
+	}
+}
+
+// failingStore fails every Save.
+type failingStore struct{ *MemoryStore }
+
+func (*failingStore) Save(Key, uint32) error { return errors.New("disk full") }
+
+func TestIndexAllocatorSaveError(t *testing.T) {
+	a := NewIndexAllocator(&failingStore{NewMemoryStore()})
+	key := Key{Chain: address.ChainEthereum}
+	if _, err := a.Next(key); err == nil {
+		t.Fatal("Next() succeeded without saving")
+	}
+	if got, _ := a.Peek(key); got != 0 {
+		t.Errorf("Peek() after a failed save = %d, want 0", got)
+	}
+}
+
+func TestNextAccount(t *testing.T) {
+	w, err := account.NewWalletFromMnemonic(testMnemonic, "")
+	if err != nil {
+		t.Fatal(err)
+	}
+	a := NewIndexAllocator(nil)
+	first, err := a.NextAccount(w, address.ChainEthereum)
+	if err != nil {
+		t.Fatalf("NextAccount() error = %v", err)
+	}
+	second, _ := a.NextAccount(w, address.ChainEthereum)
+	if first.Address() != "0x9858EfFD232B4033E47d90003D41EC34EcaEda94" || second.Address() == first.Address() {
+		t.Errorf("NextAccount() = %s, %s", first.Address(), second.Address())
+	}
+}
@@ -0,0 +1,137 @@
+package deposit
+
+import (
+	"encoding/json"
+	"errors"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"runtime"
+	"sync"
+)
+
+// MemoryStore is a Store that keeps indexes in memory, for tests and for
+// services that rebuild their indexes with account discovery on start.
+type MemoryStore struct {
+	mu   sync.Mutex
+	next map[Key]uint32
+}
+
+// NewMemoryStore creates an empty MemoryStore.
+func NewMemoryStore() *MemoryStore {
+	return &MemoryStore{next: make(map[Key]uint32)}
+}
+
+// Load returns the saved index for key.
+func (s *MemoryStore) Load(key Key) (uint32, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.next[key], nil
+}
+
+// Save records the index for key.
+func (s *MemoryStore) Save(key Key, next uint32) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.next[key] = next
+	return nil
+}
+
+// FileStore is a Store that keeps every key's index in one JSON file,
+// {"ethereum/0": 12, ...}. Each Save rewrites the file through a temporary
+// file, synced and renamed over it, and then syncs the directory so the
+// rename itself is durable. A crash leaves either the old or the new
+// contents.
+type FileStore struct {
+	mu   sync.Mutex
+	path string
+}
+
+// NewFileStore creates a FileStore at path. The file is created on the
+// first Save.
+func NewFileStore(path string) *FileStore {
+	return &FileStore{path: path}
+}
+
+// Load returns the saved index for key.
+func (s *FileStore) Load(key Key) (uint32, error) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	indexes, err := s.read()
+	if err != nil {
+		return 0, err
+	}
+	return indexes[key.String()], nil
+}
+
+// Save records the index for key and syncs the file and its directory.
+func (s *FileStore) Save(key Key, next uint32) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	indexes, err := s.read()
+	if err != nil {
+		return err
+	}
+	indexes[key.String()] = next
+	data, err := json.MarshalIndent(indexes, "", "  ")
+	if err != nil {
+		return err
+	}
+
+	tmp, err := os.CreateTemp(filepath.Dir(s.path), filepath.Base(s.path)+".*")
+	if err != nil {
+		return err
+	}
+	defer os.Remove(tmp.Name())
+	if _, err := tmp.Write(data); err != nil {
+		tmp.Close()
+		return err
+	}
+	if err := tmp.Sync(); err != nil {
+		tmp.Close()
+		return err
+	}
+	if err := tmp.Close(); err != nil {
+		return err
+	}
+	if err := os.Rename(tmp.Name(), s.path); err != nil {
+		return err
+	}
+	return syncDir(filepath.Dir(s.path))
+}
+
+// syncDir flushes a directory's entries to disk; until then a rename may be
+// lost in a crash even though the renamed file was synced. Windows cannot
+// sync a directory handle and makes renames durable itself.
+func syncDir(dir string) error {
+	if runtime.GOOS == "windows" {
+		return nil
+	}
+	d, err := os.Open(dir)
+	if err != nil {
+		return err
+	}
+	if err := d.Sync(); err != nil {
+		d.Close()
+		return err
+	}
+	return d.Close()
+}
+
+// read loads the file, treating a missing one as empty.
+func (s *FileStore) read() (map[string]uint32, error) {
+	indexes := make(map[string]uint32)
+	data, err := os.ReadFile(s.path)
+	if errors.Is(err, fs.ErrNotExist) {
+		return indexes, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	if err := json.Unmarshal(data, &indexes); err != nil {
+		return nil, err
+	}
+	return indexes, nil
+}
@@ -0,0 +1,280 @@
+// Package discovery implements BIP-44 account discovery: it walks the accounts
+// of a seed and the external and internal chains of each account, asking a
+// caller-supplied UsedAddressChecker which addresses have history, and stops
+// after a run of unused addresses (the gap limit).
+package discovery
+
+import (
+	"context"
+	"errors"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// DefaultGapLimit is the BIP-44 gap limit: 20 consecutive unused addresses.
+const DefaultGapLimit = 20
+
+// DefaultMaxAccounts bounds the number of accounts scanned.
+const DefaultMaxAccounts = 100
+
+// BIP-44 change levels
+const (
+	External uint32 = 0
+	Internal uint32 = 1
+)
+
+// ErrUnsupportedChain is returned for chains discovery cannot scan.
+var ErrUnsupportedChain = errors.New("discovery: unsupported chain")
+
+// UsedAddressChecker reports whether an address has any on-chain history.
+// Implementations typically query an indexer or node, and may block; they
+// should honor ctx cancellation.
+type UsedAddressChecker interface {
+	IsUsed(ctx context.Context, chain address.ChainID, addr string) (bool, error)
+}
+
+// UsedAddressCheckerFunc adapts a function to a UsedAddressChecker.
+type UsedAddressCheckerFunc func(ctx context.Context, chain address.ChainID, addr string) (bool, error)
+
+// IsUsed calls f.
+func (f UsedAddressCheckerFunc) IsUsed(ctx context.Context, chain address.ChainID, addr string) (bool, error) {
+	return f(ctx, chain, addr)
+}
+
+// Options configures a scan. The zero value uses the defaults.
+type Options struct {
+	// GapLimit is the number of consecutive unused addresses after which a
+	// chain is considered exhausted. Zero means DefaultGapLimit.
+	GapLimit uint32
+
+	// MaxAccounts bounds the number of accounts scanned. Zero means DefaultMaxAccounts.
+	MaxAccounts uint32
+
+	// ScriptType selects the Bitcoin script type and, with it, the purpose
+	// (44, 49 or 84). Ignored for other chains.
+	ScriptType account.ScriptType
+}
+
+// UsedAddress is an address found to have history.
+type UsedAddress struct {
+	Path    string `json:"path"`
+	Address string `json:"address"`
+	Change  uint32 `json:"change"`
+	Index   uint32 `json:"index"`
+}
+
+// Account is a used account and the addresses found in it.
+type Account struct {
+	Index     uint32        `json:"index"`
+	Addresses []UsedAddress `json:"addresses"`
+
+	// NextExternal and NextInternal are the first indexes after the last used
+	// address on each chain, i.e. where the next receive and change addresses go.
+	NextExternal uint32 `json:"nextExternal"`
+	NextInternal uint32 `json:"nextInternal"`
+}
+
+// Result is the outcome of a scan.
+type Result struct {
+	Chain    address.ChainID `json:"chain"`
+	Accounts []Account       `json:"accounts"`
+
+	// NextAccount is the index of the first unused account.
+	NextAccount uint32 `json:"nextAccount"`
+}
+
+// Discover scans the accounts of seed on chain.
+//
+// For secp256k1 chains with a BIP-44 account/change/index layout (Bitcoin,
+// Dogecoin, EVM chains, Cosmos) it follows BIP-44: each account's external
+// chain is scanned until GapLimit unused addresses in a row, then its internal
+// chain, and discovery stops at the first account whose external chain is
+// unused. Ed25519 chains (Solana, Sui) have one address per account, so
+// accounts themselves are scanned until GapLimit unused accounts in a row.
+func Discover(ctx context.Context, seed []byte, chain address.ChainID, checker UsedAddressChecker, opts Options) (*Result, error) {
+	if opts.GapLimit == 0 {
+		opts.GapLimit = DefaultGapLimit
+	}
+	if opts.MaxAccounts == 0 {
+		opts.MaxAccounts = DefaultMaxAccounts
+	}
+	if !address.IsSupported(chain) {
+		return nil, fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+	}
+
+	switch chain {
+	case address.ChainSolana, address.ChainSui:
+		return discoverAccounts(ctx, seed, chain, checker, opts)
+	}
+
+	l, err := layoutFor(chain, opts.ScriptType)
+	if err != nil {
+		return nil, err
+	}
+
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		return nil, err
+	}
+
+	result := &Result{Chain: chain, Accounts: []Account{}}
+	for index := uint32(0); index < opts.MaxAccounts; index++ {
+		accountPath := fmt.Sprintf("m/%d'/%d'/%d'", l.purpose, l.coinType, index)
+		node, err := master.DeriveFromPathString(accountPath)
+		if err != nil {
+			return nil, err
+		}
+
+		acc := Account{Index: index}
+		for _, change := range []uint32{External, Internal} {
+			used, next, err := scanChain(ctx, node, accountPath, change, chain, l, checker, opts.GapLimit)
+			if err != nil {
+				return nil, err
+			}
+			if change == External && len(used) == 0 {
+				result.NextAccount = index
+				return result, nil
+			}
+			acc.Addresses = append(acc.Addresses, used...)
+			if change == External {
+				acc.NextExternal = next
+			} else {
+				acc.NextInternal = next
+			}
+		}
+		result.Accounts = append(result.Accounts, acc)
+		result.NextAccount = index + 1
+	}
+	return result, nil
+}
+
+// scanChain walks one change level of an account until gapLimit unused
+// addresses in a row. It returns the used addresses and the next unused index.
+func scanChain(ctx context.Context, node *bip32.ExtendedKey, accountPath string, change uint32, chain address.ChainID, l layout, checker UsedAddressChecker, gapLimit uint32) ([]UsedAddress, uint32, error) {
+	branch, err := node.DeriveFromPathString(fmt.Sprintf("m/%d", change))
+	if err != nil {
+		return nil, 0, err
+	}
+
+	var used []UsedAddress
+	var next uint32
+	for index, gap := uint32(0), uint32(0); gap < gapLimit; index++ {
+		if err := ctx.Err(); err != nil {
+			return nil, 0, err
+		}
+
+		child, err := branch.DeriveFromPathString(fmt.Sprintf("m/%d", index))
+		if err != nil {
+			return nil, 0, err
+		}
+		addr, err := l.address(child.PublicKeyBytes())
+		if err != nil {
+			return nil, 0, err
+		}
+
+		ok, err := checker.IsUsed(ctx, chain, addr)
+		if err != nil {
+			return nil, 0, err
+		}
+		if !ok {
+			gap++
+			continue
+		}
+
+		used = append(used, UsedAddress{
+			Path:    fmt.Sprintf("%s/%d/%d", accountPath, change, index),
+			Address: addr,
+			Change:  change,
+			Index:   index,
+		})
+		next = index + 1
+		gap = 0
+	}
+	return used, next, nil
+}
+
+// discoverAccounts scans single-address Ed25519 accounts.
+func discoverAccounts(ctx context.Context, seed []byte, chain address.ChainID, checker UsedAddressChecker, opts Options) (*Result, error) {
+	result := &Result{Chain: chain, Accounts: []Account{}}
+	for index, gap := uint32(0), uint32(0); index < opts.MaxAccounts && gap < opts.GapLimit; index++ {
+		if err := ctx.Err(); err != nil {
+			return nil, err
+		}
+
+		var acc account.WatchAccount
+		var err error
+		if chain == address.ChainSolana {
+			acc, err = account.NewSolanaAccount(seed, index)
+		} else {
+			acc, err = account.NewSuiAccount(seed, index)
+		}
+		if err != nil {
+			return nil, err
+		}
+
+		ok, err := checker.IsUsed(ctx, chain, acc.Address())
+		if err != nil {
+			return nil, err
+		}
+		if !ok {
+			gap++
+			continue
+		}
+
+		result.Accounts = append(result.Accounts, Account{
+			Index:     index,
+			Addresses: []UsedAddress{{Path: acc.Path(), Address: acc.Address()}},
+		})
+		result.NextAccount = index + 1
+		gap = 0
+	}
+	return result, nil
+}
+
+// layout describes how a secp256k1 chain maps a public key to an address and
+// which purpose and coin type its accounts live under.
+type layout struct {
+	purpose  uint32
+	coinType uint32
+	address  func(publicKey []byte) (string, error)
+}
+
+func layoutFor(chain address.ChainID, scriptType account.ScriptType) (layout, error) {
+	switch chain {
+	case address.ChainBitcoin:
+		return layout{purpose: scriptType.Purpose(), coinType: 0, address: func(pub []byte) (string, error) {
+			acc, err := account.NewBitcoinWatchAccount(pub, scriptType)
+			if err != nil {
+				return "", err
+			}
+			return acc.Address(), nil
+		}}, nil
+	case address.ChainDogecoin:
+		return layout{purpose: 44, coinType: 3, address: func(pub []byte) (string, error) {
+			return address.NewDogecoinAddress(false).P2PKH(pub)
+		}}, nil
+	case address.ChainCosmos:
+		return layout{purpose: 44, coinType: 118, address: func(pub []byte) (string, error) {
+			acc, err := account.NewCosmosWatchAccount(pub)
+			if err != nil {
+				return "", err
+			}
+			return acc.Address(), nil
+		}}, nil
+	}
+
+	// EVM accounts use coin type 60 on every chain, as NewEVMAccount does
+	if _, ok := address.EVMChains()[chain]; ok {
+		return layout{purpose: 44, coinType: 60, address: func(pub []byte) (string, error) {
+			acc, err := account.NewEVMWatchAccount(pub, chain)
+			if err != nil {
+				return "", err
+			}
+			return acc.Address(), nil
+		}}, nil
+	}
+	return layout{}, fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+}
@@ -0,0 +1,230 @@
+package discovery
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+// usedSet is a checker backed by a fixed set of addresses.
+type usedSet map[string]bool
+
+func (s usedSet) IsUsed(_ context.Context, _ address.ChainID, addr string) (bool, error) {
+	return s[addr], nil
+}
+
+func bitcoinAddress(t *testing.T, seed []byte, accountIndex, change, index uint32) string {
+	t.Helper()
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		t.Fatalf("NewMasterKey() error = %v", err)
+	}
+	key, err := master.DeriveFromPathString(fmt.Sprintf("m/84'/0'/%d'/%d/%d", accountIndex, change, index))
+	if err != nil {
+		t.Fatalf("DeriveFromPathString() error = %v", err)
+	}
+	acc, err := account.NewBitcoinWatchAccount(key.PublicKeyBytes(), account.ScriptP2WPKH)
+	if err != nil {
+		t.Fatalf("NewBitcoinWatchAccount() error = %v", err)
+	}
+	return acc.Address()
+}
+
+func TestDiscoverBitcoin(t *testing.T) {
+	if !address.IsSupported(address.ChainBitcoin) {
+		t.Skip("built without Bitcoin")
+	}
+	seed := bip39.NewSeed(testMnemonic, "")
+
+	used := usedSet{
+		"bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu": true, // m/84'/0'/0'/0/0
+		bitcoinAddress(t, seed, 0, External, 3):      true,
+		bitcoinAddress(t, seed, 0, Internal, 1):      true,
+		bitcoinAddress(t, seed, 1, External, 0):      true,
+		// Beyond the gap limit of account 1, so never reached
+		bitcoinAddress(t, seed, 1, External, 9): true,
+	}
+
+	result, err := Discover(context.Background(), seed, address.ChainBitcoin, used, Options{
+		GapLimit:   5,
+		ScriptType: account.ScriptP2WPKH,
+	})
+	if err != nil {
+		t.Fatalf("Discover() error = %v", err)
+	}
+
+	if result.NextAccount != 2 || len(result.Accounts) != 2 {
+		t.Fatalf("Discover() accounts = %d, nextAccount = %d, want 2, 2", len(result.Accounts), result.NextAccount)
+	}
+
+	first := result.Accounts[0]
+	if len(first.Addresses) != 3 || first.NextExternal != 4 || first.NextInternal != 2 {
+		t.Errorf("account 0 = %d addresses, next %d/%d, want 3, 4/2", len(first.Addresses), first.NextExternal, first.NextInternal)
+	}
+	if first.Addresses[0].Path != "m/84'/0'/0'/0/0" {
+		t.Errorf("account 0 first path = %s", first.Addresses[0].Path)
+	}
+	if got := first.Addresses[2]; got.Change != Internal || got.Path != "m/84'/0'/0'/1/1" {
+		t.Errorf("account 0 change address = %+v", got)
+	}
+
+	second := result.Accounts[1]
+	if len(second.Addresses) != 1 || second.NextExternal != 1 || second.NextInternal != 0 {
+		t.Errorf("account 1 = %d addresses, next %d/%d, want 1, 1/0", len(second.Addresses), second.NextExternal, second.NextInternal)
+	}
+}
+
+func TestDiscoverEd25519(t *testing.T) {
+	if !address.IsSupported(address.ChainSolana) {
+		t.Skip("built without Solana")
+	}
+	seed := bip39.NewSeed(testMnemonic, "")
+
+	used := usedSet{}
+	for _, index := range []uint32{0, 2} {
+		acc, err := account.NewSolanaAccount(seed, index)
+		if err != nil {
+			t.Fatalf("NewSolanaAccount() error = %v", err)
+		}
+		used[acc.Address()] = true
+	}
+
+	result, err := Discover(context.Background(), seed, address.ChainSolana, used, Options{GapLimit: 3})
+	if err != nil {
+		t.Fatalf("Discover() error = %v", err)
+	}
+	if len(result.Accounts) != 2 || result.NextAccount != 3 {
+		t.Errorf("Discover() accounts = %d, nextAccount = %d, want 2, 3", len(result.Accounts), result.NextAccount)
+	}
+	if result.Accounts[1].Addresses[0].Path != "m/44'/501'/2'/0'" {
+		t.Errorf("account path = %s", result.Accounts[1].Addresses[0].Path)
+	}
+}
+
+func TestDiscoverErrors(t *testing.T) {
+	seed := bip39.NewSeed(testMnemonic, "")
+
+	if _, err := Discover(context.Background(), seed, address.ChainStellar, usedSet{}, Options{}); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("Discover(stellar) error = %v, want ErrUnsupportedChain", err)
+	}
+
+	if !address.IsSupported(address.ChainEthereum) {
+		return
+	}
+
+	errBackend := errors.New("backend down")
+	failing := UsedAddressCheckerFunc(func(context.Context, address.ChainID, string) (bool, error) {
+		return false, errBackend
+	})
+	if _, err := Discover(context.Background(), seed, address.ChainEthereum, failing, Options{}); !errors.Is(err, errBackend) {
+		t.Errorf("Discover() error = %v, want checker error", err)
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	if _, err := Discover(ctx, seed, address.ChainEthereum, usedSet{}, Options{}); !errors.Is(err, context.Canceled) {
+		t.Errorf("Discover(canceled) error = %v, want context.Canceled", err)
+	}
+}
+
+func TestVendorTemplates(t *testing.T) {
+	seed := bip39.NewSeed(testMnemonic, "")
+	evm, _ := account.NewEVMAccount(seed, address.ChainPolygon, 2)
+	sol, _ := account.NewSolanaAccount(seed, 2)
+
+	for _, tt := range []struct {
+		vendor Vendor
+		chain  address.ChainID
+		want   string
+	}{
+		{VendorMetaMask, address.ChainPolygon, evm.Path()},
+		{VendorPhantom, address.ChainSolana, sol.Path()},
+		{VendorLedgerLive, address.ChainEthereum, "m/44'/60'/2'/0/0"},
+		{VendorKeplr, address.ChainCosmos, "m/44'/118'/0'/0/2"},
+	} {
+		paths, err := PathsFor(tt.vendor, tt.chain, 2)
+		if err != nil || paths[0] != tt.want {
+			t.Errorf("PathsFor(%s, %s, 2) = %v, %v, want %s first", tt.vendor, tt.chain, paths, err, tt.want)
+		}
+	}
+
+	if _, err := PathsFor(VendorKeplr, address.ChainSolana, 0); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("PathsFor(keplr, sol) error = %v, want ErrUnsupportedChain", err)
+	}
+	if _, err := PathsFor("nope", address.ChainSolana, 0); !errors.Is(err, ErrUnknownVendor) {
+		t.Errorf("PathsFor(nope) error = %v, want ErrUnknownVendor", err)
+	}
+	if len(Vendors()) != 7 {
+		t.Errorf("Vendors() = %v", Vendors())
+	}
+
+	candidates := CandidatePaths(address.ChainSolana, 0)
+	seen := make(map[string]bool)
+	for _, path := range candidates {
+		if seen[path] {
+			t.Errorf("CandidatePaths(sol) repeats %s", path)
+		}
+		seen[path] = true
+		if _, err := bip32.ParsePath(path); err != nil {
+			t.Errorf("CandidatePaths(sol) path %s: %v", path, err)
+		}
+	}
+	if !seen["m/44'/501'/0'/0'"] || !seen["m/44'/501'/0'"] {
+		t.Errorf("CandidatePaths(sol) = %v", candidates)
+	}
+}
+
+func TestRecover(t *testing.T) {
+	ctx := context.Background()
+	for _, tt := range []struct {
+		name, target, path string
+		chain              address.ChainID
+		vendor             Vendor
+		opts               RecoverOptions
+	}{
+		{"ledger live account", "0x78839F6054d7ed13918bAe0473BA31b1Ca9D7265", "m/44'/60'/1'/0/0", address.ChainEthereum, VendorLedgerLive, RecoverOptions{}},
+		{"lowercase", "0x78839f6054d7ed13918bae0473ba31b1ca9d7265", "m/44'/60'/1'/0/0", address.ChainEthereum, VendorLedgerLive, RecoverOptions{}},
+		{"bip86 taproot", "bc1p5cyxnuxmeuwuvkwfem96lqzszd02n6xdcjrs20cac6yqjjwudpxqkedrcr", "m/86'/0'/0'/0/0", address.ChainBitcoin, VendorLedgerLive, RecoverOptions{}},
+		{"phantom", "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk", "m/44'/501'/0'/0'", address.ChainSolana, VendorPhantom, RecoverOptions{}},
+		{"passphrase", "0x4C2c824223f87459C0727DBba5fd9D177F816F11", "m/44'/60'/0'/0/3", address.ChainEthereum, VendorMetaMask,
+			RecoverOptions{Passphrases: []string{"hunter1", "hunter2"}}},
+	} {
+		m, err := Recover(ctx, testMnemonic, tt.target, tt.opts)
+		if err != nil {
+			t.Errorf("%s: Recover() error = %v", tt.name, err)
+			continue
+		}
+		if m.Chain != tt.chain || m.Path != tt.path || len(m.Templates) == 0 {
+			t.Errorf("%s: Recover() = %s %s %v, want %s %s", tt.name, m.Chain, m.Path, m.Templates, tt.chain, tt.path)
+		}
+		found := false
+		for _, tmpl := range m.Templates {
+			found = found || tmpl.Vendor == tt.vendor
+		}
+		if !found {
+			t.Errorf("%s: templates %v lack %s", tt.name, m.Templates, tt.vendor)
+		}
+	}
+
+	// m/44'/60'/0'/0/25 is past the default index range
+	if _, err := Recover(ctx, testMnemonic, "0xf3356C7CcF133B2E98802E1F1527f1A702318f26", RecoverOptions{}); !errors.Is(err, ErrNotFound) {
+		t.Errorf("Recover(index 25) error = %v, want ErrNotFound", err)
+	}
+	if m, err := Recover(ctx, testMnemonic, "0xf3356C7CcF133B2E98802E1F1527f1A702318f26", RecoverOptions{Indexes: 30}); err != nil || m.Path != "m/44'/60'/0'/0/25" {
+		t.Errorf("Recover(Indexes: 30) = %v, %v", m, err)
+	}
+	if _, err := Recover(ctx, testMnemonic, "not an address", RecoverOptions{}); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("Recover(garbage) error = %v, want ErrUnsupportedChain", err)
+	}
+	if _, err := Recover(ctx, "abandon abandon", "0x78839F6054d7ed13918bAe0473BA31b1Ca9D7265", RecoverOptions{}); !errors.Is(err, bip39.ErrInvalidMnemonic) {
+		t.Errorf("Recover(bad mnemonic) error = %v", err)
+	}
+}
@@ -0,0 +1,221 @@
+package discovery
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+// DefaultRecoverIndexes is the number of indexes Recover tries per template.
+const DefaultRecoverIndexes = 20
+
+// ErrNotFound is returned when no known path derives the target address.
+var ErrNotFound = errors.New("discovery: no known path derives the address")
+
+// RecoverOptions configures Recover. The zero value uses the defaults.
+type RecoverOptions struct {
+	// Passphrases are BIP-39 passphrases to try after the empty one.
+	Passphrases []string
+
+	// Indexes is the number of values tried for each template's %d.
+	// Zero means DefaultRecoverIndexes.
+	Indexes uint32
+
+	// Chains restricts the search. By default every chain with registered
+	// templates whose address format accepts the target is searched.
+	Chains []address.ChainID
+}
+
+// Match is where Recover found the target address.
+type Match struct {
+	Chain   address.ChainID `json:"chain"`
+	Path    string          `json:"path"`
+	Address string          `json:"address"`
+
+	// Passphrase is the BIP-39 passphrase that matched; it is not
+	// serialized.
+	Passphrase string `json:"-"`
+
+	// Templates are the vendor conventions that produce Path.
+	Templates []Template `json:"templates"`
+}
+
+// Recover searches for the chain and derivation path that turn mnemonic
+// into target, the usual question when a restored wallet shows a different
+// address than the one that holds the funds. It tries every vendor template
+// (see Templates) with indexes [0, Indexes) on each candidate chain, first
+// without a passphrase and then with each of opts.Passphrases. Bitcoin
+// templates produce the script type of their purpose: P2PKH for 44, nested
+// SegWit for 49, native SegWit for 84 and BIP-86 Taproot for 86.
+//
+// EVM addresses are found as ChainEthereum, since every EVM chain shares
+// Ethereum's templates. Addresses are compared ignoring case.
+func Recover(ctx context.Context, mnemonic, target string, opts RecoverOptions) (*Match, error) {
+	if !bip39.ValidateMnemonic(mnemonic) {
+		return nil, bip39.ErrInvalidMnemonic
+	}
+	if opts.Indexes == 0 {
+		opts.Indexes = DefaultRecoverIndexes
+	}
+
+	chains := opts.Chains
+	if len(chains) == 0 {
+		chains = templateChains()
+	}
+	var candidates []address.ChainID
+	for _, chain := range chains {
+		if address.ValidateAddress(templateChain(chain), target) == nil {
+			candidates = append(candidates, chain)
+		}
+	}
+	if len(candidates) == 0 {
+		return nil, fmt.Errorf("%w: no searchable chain accepts %s", ErrUnsupportedChain, target)
+	}
+
+	for _, passphrase := range append([]string{""}, opts.Passphrases...) {
+		seed := bip39.NewSeed(mnemonic, passphrase)
+		for _, chain := range candidates {
+			match, err := recoverChain(ctx, seed, chain, target, opts.Indexes)
+			if err != nil {
+				return nil, err
+			}
+			if match != nil {
+				match.Passphrase = passphrase
+				return match, nil
+			}
+		}
+	}
+	return nil, fmt.Errorf("%w: %s", ErrNotFound, target)
+}
+
+// recoverChain tries chain's templates for target and returns nil if none matches.
+func recoverChain(ctx context.Context, seed []byte, chain address.ChainID, target string, indexes uint32) (*Match, error) {
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		return nil, err
+	}
+
+	tried := make(map[string]bool)
+	for _, t := range Templates(chain) {
+		for index := uint32(0); index < indexes; index++ {
+			if err := ctx.Err(); err != nil {
+				return nil, err
+			}
+			path := t.Path(index)
+			if tried[path] {
+				continue
+			}
+			tried[path] = true
+
+			addr, err := addressAtPath(master, seed, chain, path)
+			if err != nil {
+				return nil, err
+			}
+			if strings.EqualFold(addr, target) {
+				match := &Match{Chain: chain, Path: path, Address: addr}
+				for _, other := range Templates(chain) {
+					for i := uint32(0); i < indexes; i++ {
+						if other.Path(i) == path {
+							match.Templates = append(match.Templates, other)
+						}
+					}
+				}
+				return match, nil
+			}
+		}
+	}
+	return nil, nil
+}
+
+// addressAtPath derives chain's address at path.
+func addressAtPath(master *bip32.ExtendedKey, seed []byte, chain address.ChainID, path string) (string, error) {
+	switch chain {
+	case address.ChainSolana, address.ChainSui, address.ChainStellar:
+		p, err := bip32.ParsePath(path)
+		if err != nil {
+			return "", err
+		}
+		_, publicKey, err := ed25519.DeriveKeyFromPath(seed, p)
+		if err != nil {
+			return "", err
+		}
+		return address.Generate(chain, publicKey)
+	}
+
+	key, err := master.DeriveFromPathString(path)
+	if err != nil {
+		return "", err
+	}
+	publicKey := key.PublicKeyBytes()
+
+	switch chain {
+	case address.ChainBitcoin, address.ChainLitecoin:
+		return utxoAddress(chain, path, publicKey)
+	case address.ChainDogecoin:
+		return address.NewDogecoinAddress(false).P2PKH(publicKey)
+	}
+	if _, ok := address.EVMChains()[chain]; ok {
+		point, err := secp256k1.DecompressPoint(publicKey)
+		if err != nil {
+			return "", err
+		}
+		return address.NewEVMAddress(chain).Generate(secp256k1.SerializeUncompressed(point))
+	}
+	return address.Generate(chain, publicKey)
+}
+
+// utxoAddress encodes a Bitcoin or Litecoin key with the script type of the
+// path's purpose.
+func utxoAddress(chain address.ChainID, path string, publicKey []byte) (string, error) {
+	p, err := bip32.ParsePath(path)
+	if err != nil || len(p) == 0 {
+		return "", fmt.Errorf("discovery: path %q has no purpose", path)
+	}
+	nested := append([]byte{0x00, 0x14}, address.Hash160(publicKey)...)
+
+	if chain == address.ChainLitecoin {
+		ltc := address.NewLitecoinAddress(false)
+		switch p[0] - bip32.HardenedKeyStart {
+		case 49:
+			return ltc.P2SH(nested)
+		case 84:
+			return ltc.Bech32(publicKey)
+		}
+		return ltc.P2PKH(publicKey)
+	}
+
+	btc := address.NewBitcoinAddress(false)
+	switch p[0] - bip32.HardenedKeyStart {
+	case 49:
+		return btc.P2SH(nested)
+	case 84:
+		return btc.P2WPKH(publicKey)
+	case 86:
+		outputKey, err := secp256k1.TaprootOutputKey(publicKey[1:])
+		if err != nil {
+			return "", err
+		}
+		return btc.P2TR(outputKey)
+	}
+	return btc.P2PKH(publicKey)
+}
+
+// templateChains returns the chains with registered templates, in registry order.
+func templateChains() []address.ChainID {
+	seen := make(map[address.ChainID]bool)
+	var chains []address.ChainID
+	for _, t := range templates {
+		if !seen[t.Chain] {
+			seen[t.Chain] = true
+			chains = append(chains, t.Chain)
+		}
+	}
+	return chains
+}
@@ -0,0 +1,163 @@
+package discovery
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// Vendor identifies a wallet application whose derivation conventions are known.
+type Vendor string
+
+// Wallet vendors with registered templates
+const (
+	VendorMetaMask    Vendor = "metamask"
+	VendorLedgerLive  Vendor = "ledger-live"
+	VendorPhantom     Vendor = "phantom"
+	VendorSolflare    Vendor = "solflare"
+	VendorKeplr       Vendor = "keplr"
+	VendorTrustWallet Vendor = "trust-wallet"
+	VendorExodus      Vendor = "exodus"
+)
+
+// ErrUnknownVendor is returned for a vendor with no registered templates.
+var ErrUnknownVendor = errors.New("discovery: unknown wallet vendor")
+
+// Template is one derivation convention of a wallet vendor. Format holds a
+// single %d, the index the vendor increments when the user adds an account:
+// the address index for MetaMask-style wallets, the account for Ledger Live.
+type Template struct {
+	Vendor Vendor          `json:"vendor"`
+	Chain  address.ChainID `json:"chain"`
+	Format string          `json:"format"`
+	Note   string          `json:"note,omitempty"`
+}
+
+// Path formats the template's path for index.
+func (t Template) Path(index uint32) string {
+	return fmt.Sprintf(t.Format, index)
+}
+
+// templates lists known conventions in the order each vendor tries them:
+// the current default first, then older defaults the vendor still imports.
+// EVM templates are registered on Ethereum and apply to every EVM chain.
+var templates = []Template{
+	{VendorMetaMask, address.ChainEthereum, "m/44'/60'/0'/0/%d", ""},
+
+	{VendorLedgerLive, address.ChainEthereum, "m/44'/60'/%d'/0/0", ""},
+	{VendorLedgerLive, address.ChainEthereum, "m/44'/60'/0'/%d", "Ledger legacy (MEW, MyCrypto)"},
+	{VendorLedgerLive, address.ChainBitcoin, "m/84'/0'/%d'/0/0", "native SegWit"},
+	{VendorLedgerLive, address.ChainBitcoin, "m/86'/0'/%d'/0/0", "Taproot"},
+	{VendorLedgerLive, address.ChainBitcoin, "m/49'/0'/%d'/0/0", "nested SegWit"},
+	{VendorLedgerLive, address.ChainBitcoin, "m/44'/0'/%d'/0/0", "legacy"},
+	{VendorLedgerLive, address.ChainLitecoin, "m/84'/2'/%d'/0/0", "native SegWit"},
+	{VendorLedgerLive, address.ChainLitecoin, "m/49'/2'/%d'/0/0", "nested SegWit"},
+	{VendorLedgerLive, address.ChainDogecoin, "m/44'/3'/%d'/0/0", ""},
+	{VendorLedgerLive, address.ChainSolana, "m/44'/501'/%d'", ""},
+	{VendorLedgerLive, address.ChainCosmos, "m/44'/118'/%d'/0/0", ""},
+	{VendorLedgerLive, address.ChainStellar, "m/44'/148'/%d'", ""},
+
+	{VendorPhantom, address.ChainSolana, "m/44'/501'/%d'/0'", ""},
+	{VendorPhantom, address.ChainSolana, "m/44'/501'/%d'", "deprecated"},
+	{VendorPhantom, address.ChainEthereum, "m/44'/60'/0'/0/%d", ""},
+	{VendorPhantom, address.ChainBitcoin, "m/84'/0'/%d'/0/0", "native SegWit"},
+	{VendorPhantom, address.ChainBitcoin, "m/86'/0'/%d'/0/0", "Taproot"},
+	{VendorPhantom, address.ChainSui, "m/44'/784'/%d'/0'/0'", ""},
+
+	{VendorSolflare, address.ChainSolana, "m/44'/501'/%d'/0'", ""},
+	{VendorSolflare, address.ChainSolana, "m/44'/501'/%d'", "legacy"},
+
+	{VendorKeplr, address.ChainCosmos, "m/44'/118'/0'/0/%d", ""},
+	{VendorKeplr, address.ChainSei, "m/44'/118'/0'/0/%d", ""},
+	{VendorKeplr, address.ChainEthereum, "m/44'/60'/0'/0/%d", "Ethermint chains"},
+
+	{VendorTrustWallet, address.ChainEthereum, "m/44'/60'/0'/0/%d", ""},
+	{VendorTrustWallet, address.ChainBitcoin, "m/84'/0'/0'/0/%d", ""},
+	{VendorTrustWallet, address.ChainLitecoin, "m/84'/2'/0'/0/%d", ""},
+	{VendorTrustWallet, address.ChainDogecoin, "m/44'/3'/0'/0/%d", ""},
+	{VendorTrustWallet, address.ChainCosmos, "m/44'/118'/0'/0/%d", ""},
+	{VendorTrustWallet, address.ChainSolana, "m/44'/501'/%d'", ""},
+	{VendorTrustWallet, address.ChainSui, "m/44'/784'/%d'/0'/0'", ""},
+	{VendorTrustWallet, address.ChainStellar, "m/44'/148'/%d'", ""},
+
+	{VendorExodus, address.ChainEthereum, "m/44'/60'/0'/0/%d", ""},
+	{VendorExodus, address.ChainBitcoin, "m/84'/0'/0'/0/%d", "native SegWit"},
+	{VendorExodus, address.ChainBitcoin, "m/44'/0'/0'/0/%d", "legacy"},
+	{VendorExodus, address.ChainLitecoin, "m/84'/2'/0'/0/%d", ""},
+	{VendorExodus, address.ChainDogecoin, "m/44'/3'/0'/0/%d", ""},
+}
+
+// templateChain maps EVM chains onto the Ethereum templates.
+func templateChain(chain address.ChainID) address.ChainID {
+	if _, ok := address.EVMChains()[chain]; ok {
+		return address.ChainEthereum
+	}
+	return chain
+}
+
+// Vendors returns the vendors with registered templates, sorted.
+func Vendors() []Vendor {
+	seen := make(map[Vendor]bool)
+	var vendors []Vendor
+	for _, t := range templates {
+		if !seen[t.Vendor] {
+			seen[t.Vendor] = true
+			vendors = append(vendors, t.Vendor)
+		}
+	}
+	sort.Slice(vendors, func(i, j int) bool { return vendors[i] < vendors[j] })
+	return vendors
+}
+
+// Templates returns every vendor's templates for chain, in registry order.
+func Templates(chain address.ChainID) []Template {
+	var out []Template
+	for _, t := range templates {
+		if t.Chain == templateChain(chain) {
+			t.Chain = chain
+			out = append(out, t)
+		}
+	}
+	return out
+}
+
+// PathsFor returns the paths vendor would use for chain at index, default
+// first.
+func PathsFor(vendor Vendor, chain address.ChainID, index uint32) ([]string, error) {
+	known := false
+	var paths []string
+	for _, t := range templates {
+		if t.Vendor != vendor {
+			continue
+		}
+		known = true
+		if t.Chain == templateChain(chain) {
+			paths = append(paths, t.Path(index))
+		}
+	}
+	if !known {
+		return nil, fmt.Errorf("%w: %s", ErrUnknownVendor, vendor)
+	}
+	if len(paths) == 0 {
+		return nil, fmt.Errorf("%w: %s has no %s template", ErrUnsupportedChain, vendor, chain)
+	}
+	return paths, nil
+}
+
+// CandidatePaths returns the distinct paths any known vendor would use for
+// chain at index, for recovery tools to try when a user's address does not
+// match the default derivation.
+func CandidatePaths(chain address.ChainID, index uint32) []string {
+	seen := make(map[string]bool)
+	var paths []string
+	for _, t := range Templates(chain) {
+		path := t.Path(index)
+		if !seen[path] {
+			seen[path] = true
+			paths = append(paths, path)
+		}
+	}
+	return paths
+}
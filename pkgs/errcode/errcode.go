@@ -0,0 +1,474 @@
+// Package errcode gives every sentinel error of the library a stable code,
+// such as "bip39.invalid_checksum", for logs, API responses and message
+// catalogs. Error strings stay English and may change wording; codes do not.
+//
+// Localized messages are kept apart from the errors themselves: an
+// application registers a catalog per language with Register and renders
+// errors with Message, which falls back to the English Error string.
+package errcode
+
+import (
+	"errors"
+	"sync"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/bip44"
+	"github.com/study/crypto-accounts/pkgs/caip"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/shamir"
+	"github.com/study/crypto-accounts/pkgs/crypto/stark"
+	"github.com/study/crypto-accounts/pkgs/deposit"
+	"github.com/study/crypto-accounts/pkgs/discovery"
+	"github.com/study/crypto-accounts/pkgs/explain"
+	"github.com/study/crypto-accounts/pkgs/hardware"
+	"github.com/study/crypto-accounts/pkgs/keys"
+	"github.com/study/crypto-accounts/pkgs/keystore"
+	"github.com/study/crypto-accounts/pkgs/keystore/age"
+	"github.com/study/crypto-accounts/pkgs/qr"
+	"github.com/study/crypto-accounts/pkgs/signer"
+	"github.com/study/crypto-accounts/pkgs/slip39"
+	"github.com/study/crypto-accounts/pkgs/testvectors"
+	"github.com/study/crypto-accounts/pkgs/threshold"
+	"github.com/study/crypto-accounts/pkgs/ton"
+	"github.com/study/crypto-accounts/pkgs/ur"
+	"github.com/study/crypto-accounts/pkgs/uri"
+	"github.com/study/crypto-accounts/pkgs/vanity"
+	"github.com/study/crypto-accounts/pkgs/verify"
+)
+
+// Code is a stable identifier of an error, "package.snake_case_name".
+type Code string
+
+// Codes that do not belong to a sentinel error
+const (
+	// Unknown is returned by Of for errors without a registered sentinel.
+	Unknown Code = "unknown"
+
+	// HardwareStatus is a hardware.StatusError with a status word that has
+	// no sentinel of its own.
+	HardwareStatus Code = "hardware.status"
+)
+
+// Codes of the sentinel errors, named after the package and the error
+const (
+	AccountInvalidNostrEvent  Code = "account.invalid_nostr_event"
+	AccountInvalidSeed        Code = "account.invalid_seed"
+	AccountInvalidPrivateKey  Code = "account.invalid_private_key"
+	AccountInvalidPublicKey   Code = "account.invalid_public_key"
+	AccountInvalidExtendedKey Code = "account.invalid_extended_key"
+	AccountInvalidIndex       Code = "account.invalid_index"
+	AccountUnknownKeyFormat   Code = "account.unknown_key_format"
+	AccountWalletDestroyed    Code = "account.wallet_destroyed"
+	AccountInvalidSnapshot    Code = "account.invalid_snapshot"
+	AccountNotInSnapshot      Code = "account.not_in_snapshot"
+	AccountInvalidNEP2        Code = "account.invalid_nep2"
+
+	AddressInvalidEncoding   Code = "address.invalid_encoding"
+	AddressInvalidLength     Code = "address.invalid_length"
+	AddressInvalidPrefix     Code = "address.invalid_prefix"
+	AddressNetworkMismatch   Code = "address.network_mismatch"
+	AddressInvalidChecksum   Code = "address.invalid_checksum"
+	AddressInvalidVersion    Code = "address.invalid_version"
+	AddressInvalidPublicKey  Code = "address.invalid_public_key"
+	AddressInvalidPrivateKey Code = "address.invalid_private_key"
+	AddressUnsupportedChain  Code = "address.unsupported_chain"
+	AddressInvalidAddress    Code = "address.invalid_address"
+	AddressInvalidKeyLength  Code = "address.invalid_key_length"
+
+	AgeMalformed         Code = "age.malformed"
+	AgeNoIdentityMatched Code = "age.no_identity_matched"
+	AgeInvalidRecipient  Code = "age.invalid_recipient"
+	AgeDecryptionFailed  Code = "age.decryption_failed"
+
+	BIP32InvalidSeedLength    Code = "bip32.invalid_seed_length"
+	BIP32InvalidKeyData       Code = "bip32.invalid_key_data"
+	BIP32HardenedFromPublic   Code = "bip32.hardened_from_public"
+	BIP32DerivationFailed     Code = "bip32.derivation_failed"
+	BIP32InvalidPath          Code = "bip32.invalid_path"
+	BIP32InvalidSerializedKey Code = "bip32.invalid_serialized_key"
+	BIP32PrivateKeyMarshal    Code = "bip32.private_key_marshal"
+
+	BIP39InvalidEntropyLength   Code = "bip39.invalid_entropy_length"
+	BIP39WeakEntropy            Code = "bip39.weak_entropy"
+	BIP39InvalidRoll            Code = "bip39.invalid_roll"
+	BIP39NotEnoughRolls         Code = "bip39.not_enough_rolls"
+	BIP39InvalidMnemonicLength  Code = "bip39.invalid_mnemonic_length"
+	BIP39InvalidMnemonic        Code = "bip39.invalid_mnemonic"
+	BIP39InvalidChecksum        Code = "bip39.invalid_checksum"
+	BIP39InvalidRecoveryPattern Code = "bip39.invalid_recovery_pattern"
+	BIP39InvalidShareCount      Code = "bip39.invalid_share_count"
+	BIP39MismatchedShares       Code = "bip39.mismatched_shares"
+	BIP39MnemonicDestroyed      Code = "bip39.mnemonic_destroyed"
+	BIP39InvalidWordList        Code = "bip39.invalid_word_list"
+	BIP39WordNotFound           Code = "bip39.word_not_found"
+
+	BIP44InvalidPath    Code = "bip44.invalid_path"
+	BIP44InvalidPurpose Code = "bip44.invalid_purpose"
+	BIP44InvalidChange  Code = "bip44.invalid_change"
+
+	CAIPInvalidChainID   Code = "caip.invalid_chain_id"
+	CAIPInvalidAccountID Code = "caip.invalid_account_id"
+	CAIPUnsupportedChain Code = "caip.unsupported_chain"
+
+	DepositExhausted Code = "deposit.exhausted"
+
+	DiscoveryUnknownVendor    Code = "discovery.unknown_vendor"
+	DiscoveryNotFound         Code = "discovery.not_found"
+	DiscoveryUnsupportedChain Code = "discovery.unsupported_chain"
+
+	ExplainUnsupportedChain Code = "explain.unsupported_chain"
+	ExplainNonHardened      Code = "explain.non_hardened"
+
+	HardwareRejected        Code = "hardware.rejected"
+	HardwareLocked          Code = "hardware.locked"
+	HardwareWrongApp        Code = "hardware.wrong_app"
+	HardwareInvalidResponse Code = "hardware.invalid_response"
+	HardwareInvalidPath     Code = "hardware.invalid_path"
+	HardwareDataTooLong     Code = "hardware.data_too_long"
+
+	KeysInvalidSignature  Code = "keys.invalid_signature"
+	KeysNotRecoverable    Code = "keys.not_recoverable"
+	KeysInvalidPublicKey  Code = "keys.invalid_public_key"
+	KeysUnknownKeyType    Code = "keys.unknown_key_type"
+	KeysIncompatibleType  Code = "keys.incompatible_type"
+	KeysInvalidPrivateKey Code = "keys.invalid_private_key"
+	KeysUnknownCurve      Code = "keys.unknown_curve"
+	KeysCurveRequired     Code = "keys.curve_required"
+	KeysCurveMismatch     Code = "keys.curve_mismatch"
+
+	KeystoreDecryptionFailed   Code = "keystore.decryption_failed"
+	KeystoreUnsupportedVersion Code = "keystore.unsupported_version"
+	KeystoreUnsupportedKDF     Code = "keystore.unsupported_kdf"
+	KeystoreUnsupportedCipher  Code = "keystore.unsupported_cipher"
+	KeystoreInvalidParams      Code = "keystore.invalid_params"
+	KeystoreWrongKind          Code = "keystore.wrong_kind"
+	KeystoreNoSecret           Code = "keystore.no_secret"
+
+	QRDataTooLong      Code = "qr.data_too_long"
+	QRInvalidCharacter Code = "qr.invalid_character"
+	QRInvalidLevel     Code = "qr.invalid_level"
+	QRInvalidSeedQR    Code = "qr.invalid_seed_qr"
+
+	SignerRemoteSignature   Code = "signer.remote_signature"
+	SignerUnsupportedScheme Code = "signer.unsupported_scheme"
+	SignerSchemeMismatch    Code = "signer.scheme_mismatch"
+
+	SLIP39InvalidSecret     Code = "slip39.invalid_secret"
+	SLIP39InvalidPassphrase Code = "slip39.invalid_passphrase"
+	SLIP39InvalidScheme     Code = "slip39.invalid_scheme"
+	SLIP39InvalidMnemonic   Code = "slip39.invalid_mnemonic"
+	SLIP39InvalidChecksum   Code = "slip39.invalid_checksum"
+	SLIP39MismatchedShares  Code = "slip39.mismatched_shares"
+	SLIP39TooFewShares      Code = "slip39.too_few_shares"
+	SLIP39DigestMismatch    Code = "slip39.digest_mismatch"
+
+	TestVectorsUnknownSuite Code = "testvectors.unknown_suite"
+
+	ThresholdInvalidThreshold  Code = "threshold.invalid_threshold"
+	ThresholdInvalidShare      Code = "threshold.invalid_share"
+	ThresholdInvalidCommitment Code = "threshold.invalid_commitment"
+	ThresholdNonceUsed         Code = "threshold.nonce_used"
+	ThresholdInvalidSignature  Code = "threshold.invalid_signature"
+
+	TONInvalidMnemonic  Code = "ton.invalid_mnemonic"
+	TONInvalidWordCount Code = "ton.invalid_word_count"
+
+	URInvalidUR        Code = "ur.invalid_ur"
+	URInvalidBytewords Code = "ur.invalid_bytewords"
+	URInvalidChecksum  Code = "ur.invalid_checksum"
+	URInvalidCBOR      Code = "ur.invalid_cbor"
+	URWrongType        Code = "ur.wrong_type"
+	URInvalidPart      Code = "ur.invalid_part"
+	URIncomplete       Code = "ur.incomplete"
+	URUnsupported      Code = "ur.unsupported"
+
+	URIInvalidURI       Code = "uri.invalid_uri"
+	URIInvalidAmount    Code = "uri.invalid_amount"
+	URIUnsupportedParam Code = "uri.unsupported_param"
+	URIUnsupportedChain Code = "uri.unsupported_chain"
+
+	VanityNotFound          Code = "vanity.not_found"
+	VanityImpossiblePattern Code = "vanity.impossible_pattern"
+	VanityInvalidKey        Code = "vanity.invalid_key"
+	VanitySourceExhausted   Code = "vanity.source_exhausted"
+	VanityUnsupportedChain  Code = "vanity.unsupported_chain"
+
+	VerifyInvalid Code = "verify.invalid"
+
+	Ed25519InvalidPoint      Code = "ed25519.invalid_point"
+	Ed25519InvalidPrivateKey Code = "ed25519.invalid_private_key"
+	Ed25519InvalidPublicKey  Code = "ed25519.invalid_public_key"
+	Ed25519InvalidSignature  Code = "ed25519.invalid_signature"
+
+	EncodingInvalidBase58     Code = "encoding.invalid_base58"
+	EncodingInvalidChecksum   Code = "encoding.invalid_checksum"
+	EncodingInvalidDataLength Code = "encoding.invalid_data_length"
+	EncodingInvalidHex        Code = "encoding.invalid_hex"
+
+	P256InvalidPrivateKey Code = "p256.invalid_private_key"
+	P256InvalidPublicKey  Code = "p256.invalid_public_key"
+	P256InvalidSeed       Code = "p256.invalid_seed"
+
+	Secp256k1InvalidPrivateKey   Code = "secp256k1.invalid_private_key"
+	Secp256k1InvalidAuxRand      Code = "secp256k1.invalid_aux_rand"
+	Secp256k1InvalidPublicKey    Code = "secp256k1.invalid_public_key"
+	Secp256k1InvalidHash         Code = "secp256k1.invalid_hash"
+	Secp256k1InvalidSignature    Code = "secp256k1.invalid_signature"
+	Secp256k1InvalidRecoveryID   Code = "secp256k1.invalid_recovery_id"
+	Secp256k1InvalidDER          Code = "secp256k1.invalid_der"
+	Secp256k1RecoveryFailed      Code = "secp256k1.recovery_failed"
+	Secp256k1InvalidExtraEntropy Code = "secp256k1.invalid_extra_entropy"
+
+	ShamirInvalidThreshold Code = "shamir.invalid_threshold"
+	ShamirTooFewShares     Code = "shamir.too_few_shares"
+	ShamirInvalidShare     Code = "shamir.invalid_share"
+	ShamirDigestMismatch   Code = "shamir.digest_mismatch"
+
+	StarkFeltOutOfRange    Code = "stark.felt_out_of_range"
+	StarkInvalidPrivateKey Code = "stark.invalid_private_key"
+)
+
+// sentinels maps errors to codes in the order Of checks them. Address
+// validation reasons come before address.ErrInvalidAddress, which every
+// *address.ValidationError also matches.
+var sentinels = []struct {
+	err  error
+	code Code
+}{
+	{account.ErrInvalidNostrEvent, AccountInvalidNostrEvent},
+	{account.ErrInvalidSeed, AccountInvalidSeed},
+	{account.ErrInvalidPrivateKey, AccountInvalidPrivateKey},
+	{account.ErrInvalidPublicKey, AccountInvalidPublicKey},
+	{account.ErrInvalidExtendedKey, AccountInvalidExtendedKey},
+	{account.ErrInvalidIndex, AccountInvalidIndex},
+	{account.ErrUnknownKeyFormat, AccountUnknownKeyFormat},
+	{account.ErrWalletDestroyed, AccountWalletDestroyed},
+	{account.ErrInvalidSnapshot, AccountInvalidSnapshot},
+	{account.ErrNotInSnapshot, AccountNotInSnapshot},
+	{account.ErrInvalidNEP2, AccountInvalidNEP2},
+
+	{address.ErrInvalidEncoding, AddressInvalidEncoding},
+	{address.ErrInvalidLength, AddressInvalidLength},
+	{address.ErrInvalidPrefix, AddressInvalidPrefix},
+	{address.ErrNetworkMismatch, AddressNetworkMismatch},
+	{address.ErrInvalidChecksum, AddressInvalidChecksum},
+	{address.ErrInvalidVersion, AddressInvalidVersion},
+	{address.ErrInvalidPublicKey, AddressInvalidPublicKey},
+	{address.ErrInvalidPrivateKey, AddressInvalidPrivateKey},
+	{address.ErrUnsupportedChain, AddressUnsupportedChain},
+	{address.ErrInvalidAddress, AddressInvalidAddress},
+	{address.ErrInvalidKeyLength, AddressInvalidKeyLength},
+
+	{age.ErrMalformed, AgeMalformed},
+	{age.ErrNoIdentityMatched, AgeNoIdentityMatched},
+	{age.ErrInvalidRecipient, AgeInvalidRecipient},
+	{age.ErrDecryptionFailed, AgeDecryptionFailed},
+
+	{bip32.ErrInvalidSeedLength, BIP32InvalidSeedLength},
+	{bip32.ErrInvalidKeyData, BIP32InvalidKeyData},
+	{bip32.ErrHardenedFromPublic, BIP32HardenedFromPublic},
+	{bip32.ErrDerivationFailed, BIP32DerivationFailed},
+	{bip32.ErrInvalidPath, BIP32InvalidPath},
+	{bip32.ErrInvalidSerializedKey, BIP32InvalidSerializedKey},
+	{bip32.ErrPrivateKeyMarshal, BIP32PrivateKeyMarshal},
+
+	{bip39.ErrInvalidEntropyLength, BIP39InvalidEntropyLength},
+	{bip39.ErrWeakEntropy, BIP39WeakEntropy},
+	{bip39.ErrInvalidRoll, BIP39InvalidRoll},
+	{bip39.ErrNotEnoughRolls, BIP39NotEnoughRolls},
+	{bip39.ErrInvalidMnemonicLength, BIP39InvalidMnemonicLength},
+	{bip39.ErrInvalidMnemonic, BIP39InvalidMnemonic},
+	{bip39.ErrInvalidChecksum, BIP39InvalidChecksum},
+	{bip39.ErrInvalidRecoveryPattern, BIP39InvalidRecoveryPattern},
+	{bip39.ErrInvalidShareCount, BIP39InvalidShareCount},
+	{bip39.ErrMismatchedShares, BIP39MismatchedShares},
+	{bip39.ErrMnemonicDestroyed, BIP39MnemonicDestroyed},
+	{bip39.ErrInvalidWordList, BIP39InvalidWordList},
+	{bip39.ErrWordNotFound, BIP39WordNotFound},
+
+	{bip44.ErrInvalidPath, BIP44InvalidPath},
+	{bip44.ErrInvalidPurpose, BIP44InvalidPurpose},
+	{bip44.ErrInvalidChange, BIP44InvalidChange},
+
+	{caip.ErrInvalidChainID, CAIPInvalidChainID},
+	{caip.ErrInvalidAccountID, CAIPInvalidAccountID},
+	{caip.ErrUnsupportedChain, CAIPUnsupportedChain},
+
+	{deposit.ErrExhausted, DepositExhausted},
+
+	{discovery.ErrUnknownVendor, DiscoveryUnknownVendor},
+	{discovery.ErrNotFound, DiscoveryNotFound},
+	{discovery.ErrUnsupportedChain, DiscoveryUnsupportedChain},
+
+	{explain.ErrUnsupportedChain, ExplainUnsupportedChain},
+	{explain.ErrNonHardened, ExplainNonHardened},
+
+	{hardware.ErrRejected, HardwareRejected},
+	{hardware.ErrLocked, HardwareLocked},
+	{hardware.ErrWrongApp, HardwareWrongApp},
+	{hardware.ErrInvalidResponse, HardwareInvalidResponse},
+	{hardware.ErrInvalidPath, HardwareInvalidPath},
+	{hardware.ErrDataTooLong, HardwareDataTooLong},
+
+	{keys.ErrInvalidSignature, KeysInvalidSignature},
+	{keys.ErrNotRecoverable, KeysNotRecoverable},
+	{keys.ErrInvalidPublicKey, KeysInvalidPublicKey},
+	{keys.ErrUnknownKeyType, KeysUnknownKeyType},
+	{keys.ErrIncompatibleType, KeysIncompatibleType},
+	{keys.ErrInvalidPrivateKey, KeysInvalidPrivateKey},
+	{keys.ErrUnknownCurve, KeysUnknownCurve},
+	{keys.ErrCurveRequired, KeysCurveRequired},
+	{keys.ErrCurveMismatch, KeysCurveMismatch},
+
+	{keystore.ErrDecryptionFailed, KeystoreDecryptionFailed},
+	{keystore.ErrUnsupportedVersion, KeystoreUnsupportedVersion},
+	{keystore.ErrUnsupportedKDF, KeystoreUnsupportedKDF},
+	{keystore.ErrUnsupportedCipher, KeystoreUnsupportedCipher},
+	{keystore.ErrInvalidParams, KeystoreInvalidParams},
+	{keystore.ErrWrongKind, KeystoreWrongKind},
+	{keystore.ErrNoSecret, KeystoreNoSecret},
+
+	{qr.ErrDataTooLong, QRDataTooLong},
+	{qr.ErrInvalidCharacter, QRInvalidCharacter},
+	{qr.ErrInvalidLevel, QRInvalidLevel},
+	{qr.ErrInvalidSeedQR, QRInvalidSeedQR},
+
+	{signer.ErrRemoteSignature, SignerRemoteSignature},
+	{signer.ErrUnsupportedScheme, SignerUnsupportedScheme},
+	{signer.ErrSchemeMismatch, SignerSchemeMismatch},
+
+	{slip39.ErrInvalidSecret, SLIP39InvalidSecret},
+	{slip39.ErrInvalidPassphrase, SLIP39InvalidPassphrase},
+	{slip39.ErrInvalidScheme, SLIP39InvalidScheme},
+	{slip39.ErrInvalidMnemonic, SLIP39InvalidMnemonic},
+	{slip39.ErrInvalidChecksum, SLIP39InvalidChecksum},
+	{slip39.ErrMismatchedShares, SLIP39MismatchedShares},
+	{slip39.ErrTooFewShares, SLIP39TooFewShares},
+	{slip39.ErrDigestMismatch, SLIP39DigestMismatch},
+
+	{testvectors.ErrUnknownSuite, TestVectorsUnknownSuite},
+
+	{threshold.ErrInvalidThreshold, ThresholdInvalidThreshold},
+	{threshold.ErrInvalidShare, ThresholdInvalidShare},
+	{threshold.ErrInvalidCommitment, ThresholdInvalidCommitment},
+	{threshold.ErrNonceUsed, ThresholdNonceUsed},
+	{threshold.ErrInvalidSignature, ThresholdInvalidSignature},
+
+	{ton.ErrInvalidMnemonic, TONInvalidMnemonic},
+	{ton.ErrInvalidWordCount, TONInvalidWordCount},
+
+	{ur.ErrInvalidUR, URInvalidUR},
+	{ur.ErrInvalidBytewords, URInvalidBytewords},
+	{ur.ErrInvalidChecksum, URInvalidChecksum},
+	{ur.ErrInvalidCBOR, URInvalidCBOR},
+	{ur.ErrWrongType, URWrongType},
+	{ur.ErrInvalidPart, URInvalidPart},
+	{ur.ErrIncomplete, URIncomplete},
+	{ur.ErrUnsupported, URUnsupported},
+
+	{uri.ErrInvalidURI, URIInvalidURI},
+	{uri.ErrInvalidAmount, URIInvalidAmount},
+	{uri.ErrUnsupportedParam, URIUnsupportedParam},
+	{uri.ErrUnsupportedChain, URIUnsupportedChain},
+
+	{vanity.ErrNotFound, VanityNotFound},
+	{vanity.ErrImpossiblePattern, VanityImpossiblePattern},
+	{vanity.ErrInvalidKey, VanityInvalidKey},
+	{vanity.ErrSourceExhausted, VanitySourceExhausted},
+	{vanity.ErrUnsupportedChain, VanityUnsupportedChain},
+
+	{verify.ErrInvalid, VerifyInvalid},
+
+	{ed25519.ErrInvalidPoint, Ed25519InvalidPoint},
+	{ed25519.ErrInvalidPrivateKey, Ed25519InvalidPrivateKey},
+	{ed25519.ErrInvalidPublicKey, Ed25519InvalidPublicKey},
+	{ed25519.ErrInvalidSignature, Ed25519InvalidSignature},
+
+	{encoding.ErrInvalidBase58, EncodingInvalidBase58},
+	{encoding.ErrInvalidChecksum, EncodingInvalidChecksum},
+	{encoding.ErrInvalidDataLength, EncodingInvalidDataLength},
+	{encoding.ErrInvalidHex, EncodingInvalidHex},
+
+	{p256.ErrInvalidPrivateKey, P256InvalidPrivateKey},
+	{p256.ErrInvalidPublicKey, P256InvalidPublicKey},
+	{p256.ErrInvalidSeed, P256InvalidSeed},
+
+	{secp256k1.ErrInvalidPrivateKey, Secp256k1InvalidPrivateKey},
+	{secp256k1.ErrInvalidAuxRand, Secp256k1InvalidAuxRand},
+	{secp256k1.ErrInvalidPublicKey, Secp256k1InvalidPublicKey},
+	{secp256k1.ErrInvalidHash, Secp256k1InvalidHash},
+	{secp256k1.ErrInvalidSignature, Secp256k1InvalidSignature},
+	{secp256k1.ErrInvalidRecoveryID, Secp256k1InvalidRecoveryID},
+	{secp256k1.ErrInvalidDER, Secp256k1InvalidDER},
+	{secp256k1.ErrRecoveryFailed, Secp256k1RecoveryFailed},
+	{secp256k1.ErrInvalidExtraEntropy, Secp256k1InvalidExtraEntropy},
+
+	{shamir.ErrInvalidThreshold, ShamirInvalidThreshold},
+	{shamir.ErrTooFewShares, ShamirTooFewShares},
+	{shamir.ErrInvalidShare, ShamirInvalidShare},
+	{shamir.ErrDigestMismatch, ShamirDigestMismatch},
+
+	{stark.ErrFeltOutOfRange, StarkFeltOutOfRange},
+	{stark.ErrInvalidPrivateKey, StarkInvalidPrivateKey},
+}
+
+// Of returns the code of the first sentinel err matches with errors.Is,
+// HardwareStatus for other device status words, Unknown for any other
+// error and "" for nil.
+func Of(err error) Code {
+	if err == nil {
+		return ""
+	}
+	for _, s := range sentinels {
+		if errors.Is(err, s.err) {
+			return s.code
+		}
+	}
+	var status *hardware.StatusError
+	if errors.As(err, &status) {
+		return HardwareStatus
+	}
+	return Unknown
+}
+
+var (
+	catalogsMu sync.RWMutex
+	catalogs   = make(map[string]map[Code]string)
+)
+
+// Register adds messages for a language, such as "ko", replacing earlier
+// messages for the same codes.
+func Register(lang string, messages map[Code]string) {
+	catalogsMu.Lock()
+	defer catalogsMu.Unlock()
+
+	catalog := catalogs[lang]
+	if catalog == nil {
+		catalog = make(map[Code]string, len(messages))
+		catalogs[lang] = catalog
+	}
+	for code, msg := range messages {
+		catalog[code] = msg
+	}
+}
+
+// Message returns err's message in lang if a catalog registered one for its
+// code, and err.Error() otherwise.
+func Message(err error, lang string) string {
+	if err == nil {
+		return ""
+	}
+	catalogsMu.RLock()
+	msg, ok := catalogs[lang][Of(err)]
+	catalogsMu.RUnlock()
+	if ok {
+		return msg
+	}
+	return err.Error()
+}
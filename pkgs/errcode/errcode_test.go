@@ -0,0 +1,55 @@
+package errcode
+
+import (
+	"errors"
+	"fmt"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/hardware"
+)
+
+func TestOf(t *testing.T) {
+	for _, tt := range []struct {
+		err  error
+		want Code
+	}{
+		{nil, ""},
+		{bip39.ErrInvalidChecksum, BIP39InvalidChecksum},
+		{fmt.Errorf("restoring wallet: %w", bip39.ErrInvalidChecksum), BIP39InvalidChecksum},
+		{address.ValidateAddress(address.ChainEthereum, "0x1234"), AddressInvalidLength},
+		{&hardware.StatusError{SW: hardware.SWDenied}, HardwareRejected},
+		{&hardware.StatusError{SW: 0x6a82}, HardwareStatus},
+		{errors.New("something else"), Unknown},
+	} {
+		if got := Of(tt.err); got != tt.want {
+			t.Errorf("Of(%v) = %q, want %q", tt.err, got, tt.want)
+		}
+	}
+}
+
+func TestCodesUnique(t *testing.T) {
+	seen := make(map[Code]bool)
+	for _, s := range sentinels {
+		if seen[s.code] {
+			t.Errorf("code %s used twice", s.code)
+		}
+		seen[s.code] = true
+	}
+}
+
+func TestMessage(t *testing.T) {
+	Register("ko", map[Code]string{BIP39InvalidChecksum: "니모닉 체크섬이 일치하지 않습니다"})
+	err := fmt.Errorf("%w: last word", bip39.ErrInvalidChecksum)
+
+	if got := Message(err, "ko"); got != "니모닉 체크섬이 일치하지 않습니다" {
+		t.Errorf("Message(ko) = %q", got)
+	}
+	if got := Message(err, "en"); got != err.Error() {
+		t.Errorf("Message(en) = %q, want the Error string", got)
+	}
+	if got := Message(bip39.ErrWordNotFound, "ko"); got != bip39.ErrWordNotFound.Error() {
+		t.Errorf("Message() without a translation = %q", got)
+	}
+}
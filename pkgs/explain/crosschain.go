@@ -0,0 +1,109 @@
+package explain
+
+import (
+	"sort"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/hash"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+// KeyView is one secp256k1 key as the chains that use it see it. Chains
+// differ only in which public key encoding they hash and how they encode
+// the digest: Bitcoin and Cosmos hash the compressed key with HASH160, EVM
+// chains and Tron take the last 20 bytes of Keccak-256 of the uncompressed
+// key, and Taproot commits to a tweak of the x-only key.
+type KeyView struct {
+	PrivateKey            Bytes `json:"private_key"`
+	PublicKey             Bytes `json:"public_key"`
+	UncompressedPublicKey Bytes `json:"uncompressed_public_key"`
+	XOnlyPublicKey        Bytes `json:"x_only_public_key"`
+
+	// Hash160 is RIPEMD-160(SHA-256(PublicKey)), the payload of the Bitcoin
+	// P2PKH and P2WPKH addresses and of every Cosmos address.
+	Hash160 Bytes `json:"hash160"`
+
+	// Keccak160 is the last 20 bytes of Keccak-256 of the uncompressed key
+	// without its 0x04 prefix, the payload of the EVM and Tron addresses.
+	Keccak160 Bytes `json:"keccak160"`
+
+	Addresses []ChainAddress `json:"addresses"`
+}
+
+// ChainAddress is one address of a KeyView.
+type ChainAddress struct {
+	Chain   address.ChainID `json:"chain"`
+	Format  string          `json:"format"`
+	Payload Bytes           `json:"payload"`
+	Address string          `json:"address"`
+}
+
+// CrossChain returns privateKey's public key encodings and its mainnet
+// addresses side by side: Bitcoin P2PKH, P2SH-P2WPKH, P2WPKH and BIP-86
+// P2TR, Ethereum (the same address on every EVM chain), Tron and each
+// Cosmos-SDK chain. It returns secp256k1.ErrInvalidPrivateKey unless
+// privateKey is a valid 32-byte scalar.
+func CrossChain(privateKey []byte) (*KeyView, error) {
+	point, err := secp256k1.PublicKeyFromPrivateKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+	compressed := secp256k1.CompressPoint(point)
+	uncompressed := secp256k1.SerializeUncompressed(point)
+	outputKey, err := secp256k1.TaprootOutputKey(compressed[1:])
+	if err != nil {
+		return nil, err
+	}
+
+	v := &KeyView{
+		PrivateKey:            privateKey,
+		PublicKey:             compressed,
+		UncompressedPublicKey: uncompressed,
+		XOnlyPublicKey:        compressed[1:],
+		Hash160:               address.Hash160(compressed),
+		Keccak160:             hash.Keccak256(uncompressed[1:])[12:],
+	}
+	redeemScript := append([]byte{0x00, 0x14}, v.Hash160...)
+
+	btc := address.NewBitcoinAddress(false)
+	entries := []struct {
+		chain   address.ChainID
+		format  string
+		payload []byte
+		encode  func() (string, error)
+	}{
+		{address.ChainBitcoin, "p2pkh", v.Hash160, func() (string, error) { return btc.P2PKH(compressed) }},
+		{address.ChainBitcoin, "p2sh-p2wpkh", address.Hash160(redeemScript), func() (string, error) { return btc.P2SH(redeemScript) }},
+		{address.ChainBitcoin, "p2wpkh", v.Hash160, func() (string, error) { return btc.P2WPKH(compressed) }},
+		{address.ChainBitcoin, "p2tr", outputKey, func() (string, error) { return btc.P2TR(outputKey) }},
+		{address.ChainEthereum, "evm", v.Keccak160, func() (string, error) {
+			return address.NewEVMAddress(address.ChainEthereum).Generate(uncompressed)
+		}},
+		{address.ChainTron, "tron", v.Keccak160, func() (string, error) {
+			return address.NewTronAddress(false).Generate(uncompressed)
+		}},
+	}
+	for _, e := range entries {
+		addr, err := e.encode()
+		if err != nil {
+			return nil, err
+		}
+		v.Addresses = append(v.Addresses, ChainAddress{Chain: e.chain, Format: e.format, Payload: e.payload, Address: addr})
+	}
+
+	cosmos := address.CosmosBasedChains()
+	chains := make([]address.ChainID, 0, len(cosmos))
+	for chain := range cosmos {
+		chains = append(chains, chain)
+	}
+	sort.Slice(chains, func(i, j int) bool { return chains[i] < chains[j] })
+	for _, chain := range chains {
+		gen := cosmos[chain]
+		addr, err := gen.Generate(compressed)
+		if err != nil {
+			return nil, err
+		}
+		v.Addresses = append(v.Addresses, ChainAddress{Chain: chain, Format: "bech32 " + gen.HRP(), Payload: v.Hash160, Address: addr})
+	}
+	return v, nil
+}
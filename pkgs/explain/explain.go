@@ -0,0 +1,423 @@
+// Package explain recomputes a mnemonic-to-address derivation step by step
+// and returns every intermediate value: the entropy and checksum behind the
+// words, the PBKDF2 inputs of the seed, the HMAC-SHA512 input and output of
+// each derivation level and the hash preimages of the address.
+// GenerateMnemonic does the same for creating a new mnemonic. The package
+// exists for teaching and debugging; the results hold secrets and must not
+// be logged or stored.
+package explain
+
+import (
+	"encoding/binary"
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/hash"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+var (
+	// ErrUnsupportedChain is returned for chains Explain has no address steps for.
+	ErrUnsupportedChain = errors.New("explain: unsupported chain")
+
+	// ErrNonHardened is returned for a non-hardened level on an Ed25519 path;
+	// SLIP-10 only defines hardened derivation for Ed25519.
+	ErrNonHardened = errors.New("explain: Ed25519 derivation requires hardened indices")
+)
+
+// Curve names, as used in Explanation.Curve
+const (
+	CurveSecp256k1 = "secp256k1"
+	CurveEd25519   = "ed25519"
+)
+
+// HMAC keys of the master key derivation
+const (
+	bip32SeedKey  = "Bitcoin seed"
+	slip10SeedKey = "ed25519 seed"
+)
+
+// Bytes is a byte string that renders as lowercase hex in text and JSON.
+type Bytes []byte
+
+// String returns the hex encoding.
+func (b Bytes) String() string {
+	return fmt.Sprintf("%x", []byte(b))
+}
+
+// MarshalText implements encoding.TextMarshaler.
+func (b Bytes) MarshalText() ([]byte, error) {
+	return []byte(b.String()), nil
+}
+
+// Explanation is the full walkthrough for one mnemonic, path and chain.
+type Explanation struct {
+	Chain    address.ChainID `json:"chain"`
+	Path     string          `json:"path"`
+	Curve    string          `json:"curve"`
+	Mnemonic MnemonicSteps   `json:"mnemonic"`
+	Seed     SeedSteps       `json:"seed"`
+
+	// Levels starts with the master key (Path "m") followed by one entry
+	// per path component.
+	Levels  []Level      `json:"levels"`
+	Address AddressSteps `json:"address"`
+}
+
+// MnemonicSteps shows how the words encode the entropy and its checksum
+// (BIP-39): entropy || first ENT/32 bits of SHA-256(entropy), split into
+// 11-bit word indices.
+type MnemonicSteps struct {
+	Entropy      Bytes  `json:"entropy"`
+	EntropyBits  string `json:"entropy_bits"`
+	EntropyHash  Bytes  `json:"entropy_hash"`
+	ChecksumBits string `json:"checksum_bits"`
+	Words        []Word `json:"words"`
+}
+
+// Word is one mnemonic word with its index in the word list.
+type Word struct {
+	Word  string `json:"word"`
+	Index int    `json:"index"`
+	Bits  string `json:"bits"`
+}
+
+// SeedSteps shows the PBKDF2-HMAC-SHA512 call that turns the mnemonic into
+// the 64-byte seed.
+type SeedSteps struct {
+	Password   string `json:"password"`
+	Salt       string `json:"salt"`
+	Iterations int    `json:"iterations"`
+	Seed       Bytes  `json:"seed"`
+}
+
+// Level is one HMAC-SHA512 derivation step. For the master key HMACKey is
+// the curve's constant string and HMACData the seed; for a child it is the
+// parent chain code and 0x00 || key || index (hardened) or pubkey || index.
+// The output splits into IL and IR: IR is the new chain code, and the new
+// private key is IL + parent key mod n on secp256k1, or IL itself on Ed25519.
+type Level struct {
+	Path       string `json:"path"`
+	Index      uint32 `json:"index"`
+	Hardened   bool   `json:"hardened"`
+	HMACKey    Bytes  `json:"hmac_key"`
+	HMACData   Bytes  `json:"hmac_data"`
+	IL         Bytes  `json:"il"`
+	IR         Bytes  `json:"ir"`
+	PrivateKey Bytes  `json:"private_key"`
+	PublicKey  Bytes  `json:"public_key"`
+	ChainCode  Bytes  `json:"chain_code"`
+}
+
+// AddressSteps shows how the final public key becomes an address.
+type AddressSteps struct {
+	Format  string     `json:"format"`
+	Hashes  []HashStep `json:"hashes"`
+	Address string     `json:"address"`
+}
+
+// HashStep is one hash or encoding applied on the way to the address.
+type HashStep struct {
+	Name   string `json:"name"`
+	Input  Bytes  `json:"input"`
+	Output Bytes  `json:"output"`
+}
+
+// Explain derives the key at path from mnemonic and passphrase and records
+// each step through to chain's address. Supported chains are Bitcoin (the
+// address type follows the path's purpose: 44, 49 or 84), the EVM chains,
+// Cosmos-SDK chains and Solana.
+func Explain(mnemonic, passphrase, path string, chain address.ChainID) (*Explanation, error) {
+	curve := CurveSecp256k1
+	if chain == address.ChainSolana {
+		curve = CurveEd25519
+	} else if !secp256k1Chain(chain) {
+		return nil, fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+	}
+	indices, err := bip32.ParsePath(path)
+	if err != nil {
+		return nil, err
+	}
+
+	mnemonicSteps, err := explainMnemonic(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	seedSteps := explainSeed(mnemonic, passphrase)
+	seed := []byte(seedSteps.Seed)
+
+	e := &Explanation{
+		Chain:    chain,
+		Path:     indices.String(),
+		Curve:    curve,
+		Mnemonic: *mnemonicSteps,
+		Seed:     seedSteps,
+	}
+	if curve == CurveEd25519 {
+		e.Levels, err = explainSLIP10(seed, indices)
+	} else {
+		e.Levels, err = explainBIP32(seed, indices)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	last := e.Levels[len(e.Levels)-1]
+	e.Address, err = explainAddress(chain, indices, last)
+	if err != nil {
+		return nil, err
+	}
+	return e, nil
+}
+
+func secp256k1Chain(chain address.ChainID) bool {
+	if chain == address.ChainBitcoin {
+		return true
+	}
+	if _, ok := address.EVMChains()[chain]; ok {
+		return true
+	}
+	_, ok := address.CosmosBasedChains()[chain]
+	return ok
+}
+
+func explainMnemonic(mnemonic string) (*MnemonicSteps, error) {
+	entropy, err := bip39.MnemonicToEntropy(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	entropyHash := hash.SHA256(entropy)
+	entropyBits := bitString(entropy, len(entropy)*8)
+
+	steps := &MnemonicSteps{
+		Entropy:      entropy,
+		EntropyBits:  entropyBits,
+		EntropyHash:  entropyHash,
+		ChecksumBits: bitString(entropyHash, len(entropy)*8/32),
+	}
+	for _, word := range strings.Fields(mnemonic) {
+		index := bip39.DefaultWordList.WordIndex(word)
+		steps.Words = append(steps.Words, Word{Word: word, Index: index, Bits: fmt.Sprintf("%011b", index)})
+	}
+	return steps, nil
+}
+
+func explainSeed(mnemonic, passphrase string) SeedSteps {
+	return SeedSteps{
+		Password:   mnemonic,
+		Salt:       bip39.SaltPrefix + passphrase,
+		Iterations: bip39.PBKDF2Iterations,
+		Seed:       bip39.NewSeed(mnemonic, passphrase),
+	}
+}
+
+// bitString renders the first n bits of data as '0' and '1'.
+func bitString(data []byte, n int) string {
+	var b strings.Builder
+	for i := 0; i < n; i++ {
+		b.WriteByte('0' + data[i/8]>>(7-i%8)&1)
+	}
+	return b.String()
+}
+
+// explainBIP32 repeats bip32 derivation, keeping the HMAC inputs and outputs.
+func explainBIP32(seed []byte, indices bip32.DerivationPath) ([]Level, error) {
+	I := hash.HMACSHA512([]byte(bip32SeedKey), seed)
+	if !secp256k1.IsValidPrivateKey(I[:32]) {
+		return nil, bip32.ErrDerivationFailed
+	}
+	master := Level{
+		Path:       "m",
+		HMACKey:    []byte(bip32SeedKey),
+		HMACData:   seed,
+		IL:         I[:32],
+		IR:         I[32:],
+		PrivateKey: I[:32],
+		PublicKey:  secp256k1.PrivateKeyToCompressedPublicKey(I[:32]),
+		ChainCode:  I[32:],
+	}
+	levels := []Level{master}
+
+	parent := master
+	for i, index := range indices {
+		hardened := bip32.IsHardened(index)
+		data := make([]byte, 37)
+		if hardened {
+			copy(data[1:], parent.PrivateKey)
+		} else {
+			copy(data, parent.PublicKey)
+		}
+		binary.BigEndian.PutUint32(data[33:], index)
+
+		I := hash.HMACSHA512(parent.ChainCode, data)
+		if !secp256k1.IsValidPrivateKey(I[:32]) {
+			return nil, bip32.ErrDerivationFailed
+		}
+		privateKey := secp256k1.AddPrivateKeys(parent.PrivateKey, I[:32])
+		if !secp256k1.IsValidPrivateKey(privateKey) {
+			return nil, bip32.ErrDerivationFailed
+		}
+
+		child := Level{
+			Path:       indices[:i+1].String(),
+			Index:      index,
+			Hardened:   hardened,
+			HMACKey:    parent.ChainCode,
+			HMACData:   data,
+			IL:         I[:32],
+			IR:         I[32:],
+			PrivateKey: privateKey,
+			PublicKey:  secp256k1.PrivateKeyToCompressedPublicKey(privateKey),
+			ChainCode:  I[32:],
+		}
+		levels = append(levels, child)
+		parent = child
+	}
+	return levels, nil
+}
+
+// explainSLIP10 repeats SLIP-10 Ed25519 derivation, where every level is
+// hardened and the child key is IL.
+func explainSLIP10(seed []byte, indices bip32.DerivationPath) ([]Level, error) {
+	newLevel := func(path string, index uint32, key, data []byte) (Level, error) {
+		I := hash.HMACSHA512(key, data)
+		publicKey, err := ed25519.PrivateKeyToPublicKey(I[:32])
+		if err != nil {
+			return Level{}, err
+		}
+		return Level{
+			Path:       path,
+			Index:      index,
+			Hardened:   path != "m",
+			HMACKey:    key,
+			HMACData:   data,
+			IL:         I[:32],
+			IR:         I[32:],
+			PrivateKey: I[:32],
+			PublicKey:  publicKey,
+			ChainCode:  I[32:],
+		}, nil
+	}
+
+	parent, err := newLevel("m", 0, []byte(slip10SeedKey), seed)
+	if err != nil {
+		return nil, err
+	}
+	levels := []Level{parent}
+	for i, index := range indices {
+		if !bip32.IsHardened(index) {
+			return nil, fmt.Errorf("%w: %s", ErrNonHardened, indices[:i+1])
+		}
+		data := make([]byte, 37)
+		copy(data[1:], parent.PrivateKey)
+		binary.BigEndian.PutUint32(data[33:], index)
+
+		parent, err = newLevel(indices[:i+1].String(), index, parent.ChainCode, data)
+		if err != nil {
+			return nil, err
+		}
+		levels = append(levels, parent)
+	}
+	return levels, nil
+}
+
+// explainAddress records the hashes from the public key to the address. The
+// final encoding is left to the address package, so the steps always end at
+// the address the rest of the library produces.
+func explainAddress(chain address.ChainID, indices bip32.DerivationPath, key Level) (AddressSteps, error) {
+	switch {
+	case chain == address.ChainSolana:
+		return AddressSteps{
+			Format:  "base58(public key)",
+			Address: address.Base58Encode(key.PublicKey),
+		}, nil
+
+	case chain == address.ChainBitcoin:
+		return explainBitcoinAddress(indices, key.PublicKey)
+	}
+
+	if generator, ok := address.CosmosBasedChains()[chain]; ok {
+		addr, err := generator.Generate(key.PublicKey)
+		if err != nil {
+			return AddressSteps{}, err
+		}
+		return AddressSteps{
+			Format:  "bech32(" + generator.HRP() + ", RIPEMD-160(SHA-256(public key)))",
+			Hashes:  hash160Steps(key.PublicKey),
+			Address: addr,
+		}, nil
+	}
+
+	// EVM: the last 20 bytes of Keccak-256 over the uncompressed X || Y,
+	// with EIP-55 mixed-case checksum
+	point, err := secp256k1.ParsePublicKey(key.PublicKey)
+	if err != nil {
+		return AddressSteps{}, err
+	}
+	uncompressed := secp256k1.SerializeUncompressed(point)
+	digest := hash.Keccak256(uncompressed[1:])
+	addr, err := address.NewEVMAddress(chain).Generate(uncompressed)
+	if err != nil {
+		return AddressSteps{}, err
+	}
+	return AddressSteps{
+		Format: "EIP-55(last 20 bytes of Keccak-256(X || Y))",
+		Hashes: []HashStep{
+			{Name: "keccak256", Input: uncompressed[1:], Output: digest},
+			{Name: "last20", Input: digest, Output: digest[12:]},
+		},
+		Address: addr,
+	}, nil
+}
+
+// explainBitcoinAddress picks P2PKH, P2SH-P2WPKH or P2WPKH from the BIP-44,
+// BIP-49 or BIP-84 purpose; other purposes default to P2WPKH.
+func explainBitcoinAddress(indices bip32.DerivationPath, publicKey []byte) (AddressSteps, error) {
+	btc := address.NewBitcoinAddress(false)
+	steps := hash160Steps(publicKey)
+	pubKeyHash := steps[len(steps)-1].Output
+
+	purpose := uint32(0)
+	if len(indices) > 0 {
+		purpose = indices[0] &^ bip32.HardenedKeyStart
+	}
+	switch purpose {
+	case 44:
+		addr, err := btc.P2PKH(publicKey)
+		return AddressSteps{
+			Format:  "base58check(0x00 || HASH160(public key))",
+			Hashes:  steps,
+			Address: addr,
+		}, err
+	case 49:
+		redeemScript := append([]byte{0x00, 0x14}, pubKeyHash...)
+		addr, err := btc.P2SH(redeemScript)
+		steps = append(steps, hash160Steps(redeemScript)...)
+		return AddressSteps{
+			Format:  "base58check(0x05 || HASH160(0x0014 || HASH160(public key)))",
+			Hashes:  steps,
+			Address: addr,
+		}, err
+	default:
+		addr, err := btc.P2WPKH(publicKey)
+		return AddressSteps{
+			Format:  "segwit v0 bech32(bc, HASH160(public key))",
+			Hashes:  steps,
+			Address: addr,
+		}, err
+	}
+}
+
+// hash160Steps records RIPEMD-160(SHA-256(data)).
+func hash160Steps(data []byte) []HashStep {
+	sha := hash.SHA256(data)
+	return []HashStep{
+		{Name: "sha256", Input: data, Output: sha},
+		{Name: "ripemd160", Input: sha, Output: hash.RIPEMD160(sha)},
+	}
+}
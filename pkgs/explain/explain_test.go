@@ -0,0 +1,158 @@
+package explain
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+func TestExplainMnemonic(t *testing.T) {
+	e, err := Explain(testMnemonic, "", "m/84'/0'/0'/0/0", address.ChainBitcoin)
+	if err != nil {
+		t.Fatalf("Explain() error = %v", err)
+	}
+
+	m := e.Mnemonic
+	if m.EntropyBits != strings.Repeat("0", 128) || m.ChecksumBits != "0011" {
+		t.Errorf("EntropyBits = %s, ChecksumBits = %s", m.EntropyBits, m.ChecksumBits)
+	}
+	if len(m.Words) != 12 || m.Words[11] != (Word{Word: "about", Index: 3, Bits: "00000000011"}) {
+		t.Errorf("Words = %+v", m.Words)
+	}
+	if e.Seed.Salt != "mnemonic" || e.Seed.Iterations != 2048 || len(e.Seed.Seed) != 64 {
+		t.Errorf("Seed = %+v", e.Seed)
+	}
+
+	if len(e.Levels) != 6 || e.Levels[0].Path != "m" || e.Levels[5].Path != "m/84'/0'/0'/0/0" {
+		t.Fatalf("Levels = %d, want master plus 5", len(e.Levels))
+	}
+	if !e.Levels[3].Hardened || e.Levels[4].Hardened {
+		t.Errorf("Hardened flags = %v, %v", e.Levels[3].Hardened, e.Levels[4].Hardened)
+	}
+	for i, level := range e.Levels[1:] {
+		if !bytes.Equal(level.HMACKey, e.Levels[i].ChainCode) {
+			t.Errorf("level %s HMACKey is not the parent chain code", level.Path)
+		}
+	}
+
+	data, err := json.Marshal(e)
+	if err != nil || !strings.Contains(string(data), `"checksum_bits":"0011"`) {
+		t.Errorf("json.Marshal() = %s, %v", data, err)
+	}
+}
+
+func TestGenerateMnemonic(t *testing.T) {
+	// BIP-39 test vector: 7f7f... with the passphrase TREZOR
+	g, err := GenerateMnemonicFromEntropy(bytes.Repeat([]byte{0x7f}, 16), "TREZOR")
+	if err != nil {
+		t.Fatalf("GenerateMnemonicFromEntropy() error = %v", err)
+	}
+	if g.Mnemonic != "legal winner thank year wave sausage worth useful legal winner thank yellow" {
+		t.Errorf("Mnemonic = %q", g.Mnemonic)
+	}
+	if g.Steps.ChecksumBits != "1000" || g.Steps.Words[11] != (Word{Word: "yellow", Index: 2040, Bits: "11111111000"}) {
+		t.Errorf("ChecksumBits = %s, last word = %+v", g.Steps.ChecksumBits, g.Steps.Words[11])
+	}
+	if g.Seed.Salt != "mnemonicTREZOR" || g.Seed.Password != g.Mnemonic ||
+		g.Seed.Seed.String() != "2e8905819b8723fe2c1d161860e5ee1830318dbf49a83bd451cfb8440c28bd6fa457fe1296106559a3c80937a1c1069be3a3a5bd381ee6260e8d9739fce1f607" {
+		t.Errorf("Seed = %+v", g.Seed)
+	}
+
+	g, err = GenerateMnemonic(256, "")
+	if err != nil {
+		t.Fatalf("GenerateMnemonic() error = %v", err)
+	}
+	if len(g.Steps.Words) != 24 || len(g.Steps.EntropyBits) != 256 || len(g.Steps.ChecksumBits) != 8 {
+		t.Errorf("GenerateMnemonic(256) = %d words", len(g.Steps.Words))
+	}
+	if _, err := GenerateMnemonic(100, ""); !errors.Is(err, bip39.ErrInvalidEntropyLength) {
+		t.Errorf("GenerateMnemonic(100) error = %v, want ErrInvalidEntropyLength", err)
+	}
+}
+
+func TestExplainAddress(t *testing.T) {
+	cosmos, _ := account.NewCosmosAccountFromMnemonic(testMnemonic, "", 0)
+	solana, _ := account.NewSolanaAccountFromMnemonic(testMnemonic, "", 0)
+
+	for _, tt := range []struct {
+		chain address.ChainID
+		path  string
+		want  string
+	}{
+		{address.ChainBitcoin, "m/84'/0'/0'/0/0", "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu"},
+		{address.ChainBitcoin, "m/44'/0'/0'/0/0", "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA"},
+		{address.ChainBitcoin, "m/49'/0'/0'/0/0", "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf"},
+		{address.ChainEthereum, "m/44'/60'/0'/0/0", "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"},
+		{address.ChainCosmos, cosmos.Path(), cosmos.Address()},
+		{address.ChainSolana, solana.Path(), solana.Address()},
+	} {
+		e, err := Explain(testMnemonic, "", tt.path, tt.chain)
+		if err != nil {
+			t.Errorf("Explain(%s, %s) error = %v", tt.chain, tt.path, err)
+			continue
+		}
+		if e.Address.Address != tt.want {
+			t.Errorf("Explain(%s, %s) address = %s, want %s", tt.chain, tt.path, e.Address.Address, tt.want)
+		}
+		hashes := e.Address.Hashes
+		for i := 1; i < len(hashes); i++ {
+			if tt.chain != address.ChainBitcoin && !bytes.Equal(hashes[i].Input, hashes[i-1].Output) {
+				t.Errorf("Explain(%s) hash %s does not chain from %s", tt.chain, hashes[i].Name, hashes[i-1].Name)
+			}
+		}
+	}
+
+	if _, err := Explain(testMnemonic, "", "m/44'/501'/0'/0", address.ChainSolana); !errors.Is(err, ErrNonHardened) {
+		t.Errorf("Explain(non-hardened Solana) error = %v, want ErrNonHardened", err)
+	}
+	if _, err := Explain(testMnemonic, "", "m/44'/0'", address.ChainMonero); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("Explain(monero) error = %v, want ErrUnsupportedChain", err)
+	}
+}
+
+func TestCrossChain(t *testing.T) {
+	privateKey := make([]byte, 32)
+	privateKey[31] = 1
+	v, err := CrossChain(privateKey)
+	if err != nil {
+		t.Fatalf("CrossChain() error = %v", err)
+	}
+
+	want := map[string]string{
+		"p2pkh":         "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH",
+		"p2sh-p2wpkh":   "3JvL6Ymt8MVWiCNHC7oWU6nLeHNJKLZGLN",
+		"p2wpkh":        "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+		"p2tr":          "bc1pmfr3p9j00pfxjh0zmgp99y8zftmd3s5pmedqhyptwy6lm87hf5sspknck9",
+		"evm":           "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf",
+		"tron":          "TMVQGm1qAQYVdetCeGRRkTWYYrLXuHK2HC",
+		"bech32 cosmos": "cosmos1w508d6qejxtdg4y5r3zarvary0c5xw7k6ah60c",
+	}
+	seen := 0
+	for _, a := range v.Addresses {
+		if addr, ok := want[a.Format]; ok {
+			seen++
+			if a.Address != addr {
+				t.Errorf("%s address = %s, want %s", a.Format, a.Address, addr)
+			}
+		}
+		// Every Cosmos chain encodes the same HASH160 under its own prefix
+		if strings.HasPrefix(a.Format, "bech32 ") && !bytes.Equal(a.Payload, v.Hash160) {
+			t.Errorf("%s payload = %s, want %s", a.Chain, a.Payload, v.Hash160)
+		}
+	}
+	if seen != len(want) {
+		t.Errorf("found %d of %d expected formats in %+v", seen, len(want), v.Addresses)
+	}
+
+	if _, err := CrossChain(make([]byte, 32)); err == nil {
+		t.Error("CrossChain(zero key) succeeded")
+	}
+}
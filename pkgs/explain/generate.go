@@ -0,0 +1,41 @@
+package explain
+
+import "github.com/study/crypto-accounts/pkgs/bip39"
+
+// Generation is the walkthrough of creating a new mnemonic, from entropy
+// through checksum and word indices to the seed. Like an Explanation it
+// holds the secret and must not be logged or stored.
+type Generation struct {
+	Mnemonic string        `json:"mnemonic"`
+	Steps    MnemonicSteps `json:"steps"`
+	Seed     SeedSteps     `json:"seed"`
+}
+
+// GenerateMnemonic generates bits of entropy (128, 160, 192, 224 or 256), as
+// bip39.GenerateMnemonicAndSeed does, and records every step from the
+// entropy to the seed.
+func GenerateMnemonic(bits int, passphrase string) (*Generation, error) {
+	entropy, err := bip39.GenerateEntropy(bits)
+	if err != nil {
+		return nil, err
+	}
+	return GenerateMnemonicFromEntropy(entropy, passphrase)
+}
+
+// GenerateMnemonicFromEntropy is GenerateMnemonic for given entropy, such as
+// a published test vector.
+func GenerateMnemonicFromEntropy(entropy []byte, passphrase string) (*Generation, error) {
+	mnemonic, err := bip39.NewMnemonic(entropy)
+	if err != nil {
+		return nil, err
+	}
+	steps, err := explainMnemonic(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	return &Generation{
+		Mnemonic: mnemonic,
+		Steps:    *steps,
+		Seed:     explainSeed(mnemonic, passphrase),
+	}, nil
+}
@@ -0,0 +1,177 @@
+// Package hardware drives hardware wallets whose keys never leave the device.
+//
+// A Signer resolves public keys and signs payloads by derivation path; this
+// module's path and address logic decides which path to ask for and turns the
+// returned public key into addresses (see WatchAccount). Devices are reached
+// through a Transport that exchanges raw APDUs, so USB HID, BLE or an emulator
+// such as Speculos is plugged in by the caller and no driver code lives here.
+//
+// Ledger's Ethereum and Bitcoin apps are supported. The APDU builders are
+// exported for callers that speak to a device some other way.
+package hardware
+
+import (
+	"encoding/binary"
+	"errors"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// Transport sends one command APDU to a device and returns the response
+// including the trailing two-byte status word. Implementations handle framing
+// (e.g. Ledger's HID packets) and are not required to be safe for concurrent use.
+type Transport interface {
+	Exchange(apdu []byte) ([]byte, error)
+}
+
+// Signer is a hardware device, or an app on one, that derives keys and signs
+// on-device. Paths use the same bip32.DerivationPath as software wallets.
+type Signer interface {
+	// Scheme returns the signature algorithm of the device's keys.
+	Scheme() signer.Scheme
+
+	// PublicKey returns the public key at path in the scheme's canonical
+	// encoding (33-byte compressed for secp256k1).
+	PublicKey(path bip32.DerivationPath) ([]byte, error)
+
+	// Sign signs payload with the key at path. What payload is depends on the
+	// app, since devices only sign data they can show to the user: see the
+	// Sign method of each implementation.
+	Sign(path bip32.DerivationPath, payload []byte) ([]byte, error)
+}
+
+var (
+	// ErrRejected is returned when the user declines an action on the device.
+	ErrRejected = errors.New("hardware: rejected on device")
+
+	// ErrLocked is returned when the device is locked with its PIN.
+	ErrLocked = errors.New("hardware: device locked")
+
+	// ErrWrongApp is returned when the open app does not understand the
+	// command, usually because a different app (or the dashboard) is open.
+	ErrWrongApp = errors.New("hardware: wrong app open on device")
+
+	// ErrInvalidResponse is returned for a response the protocol does not allow.
+	ErrInvalidResponse = errors.New("hardware: invalid device response")
+
+	// ErrInvalidPath is returned for a path the device cannot encode.
+	ErrInvalidPath = errors.New("hardware: invalid derivation path")
+
+	// ErrDataTooLong is returned for an APDU whose data does not fit in a
+	// short APDU.
+	ErrDataTooLong = errors.New("hardware: APDU data longer than 255 bytes")
+)
+
+// Status words shared by Ledger apps
+const (
+	SWOK              uint16 = 0x9000
+	SWDenied          uint16 = 0x6985
+	SWWrongLength     uint16 = 0x6700
+	SWInvalidData     uint16 = 0x6A80
+	SWINSNotSupported uint16 = 0x6D00
+	SWCLANotSupported uint16 = 0x6E00
+	SWLocked          uint16 = 0x5515
+)
+
+// StatusError is a response whose status word is not SWOK. It matches
+// ErrRejected, ErrLocked or ErrWrongApp with errors.Is where one applies.
+type StatusError struct {
+	SW uint16
+}
+
+// Error returns the status word in hex.
+func (e *StatusError) Error() string {
+	return fmt.Sprintf("hardware: device returned status %04x", e.SW)
+}
+
+// Unwrap returns the sentinel error for well-known status words.
+func (e *StatusError) Unwrap() error {
+	switch e.SW {
+	case SWDenied:
+		return ErrRejected
+	case SWLocked:
+		return ErrLocked
+	case SWINSNotSupported, SWCLANotSupported:
+		return ErrWrongApp
+	default:
+		return nil
+	}
+}
+
+// maxDataLength is the largest data field of a short APDU.
+const maxDataLength = 255
+
+// APDU is a command APDU in the short form Ledger apps accept.
+type APDU struct {
+	CLA, INS, P1, P2 byte
+	Data             []byte
+}
+
+// Bytes encodes the command as CLA INS P1 P2 Lc Data. Data longer than 255
+// bytes returns ErrDataTooLong; the builders in this package never produce one.
+func (a APDU) Bytes() ([]byte, error) {
+	if len(a.Data) > maxDataLength {
+		return nil, fmt.Errorf("%w: %d", ErrDataTooLong, len(a.Data))
+	}
+	out := make([]byte, 0, 5+len(a.Data))
+	out = append(out, a.CLA, a.INS, a.P1, a.P2, byte(len(a.Data)))
+	return append(out, a.Data...), nil
+}
+
+// exchange sends apdu and returns the response data, or a *StatusError.
+func exchange(t Transport, apdu APDU) ([]byte, error) {
+	data, sw, err := exchangeStatus(t, apdu)
+	if err != nil {
+		return nil, err
+	}
+	if sw != SWOK {
+		return nil, &StatusError{SW: sw}
+	}
+	return data, nil
+}
+
+// exchangeStatus sends apdu and splits the response into data and status word.
+func exchangeStatus(t Transport, apdu APDU) ([]byte, uint16, error) {
+	command, err := apdu.Bytes()
+	if err != nil {
+		return nil, 0, err
+	}
+	resp, err := t.Exchange(command)
+	if err != nil {
+		return nil, 0, fmt.Errorf("hardware: exchange: %w", err)
+	}
+	if len(resp) < 2 {
+		return nil, 0, fmt.Errorf("%w: %d bytes, missing status word", ErrInvalidResponse, len(resp))
+	}
+	n := len(resp) - 2
+	return resp[:n], binary.BigEndian.Uint16(resp[n:]), nil
+}
+
+// encodePath serializes a path as Ledger apps expect it: a count byte then
+// each index as 4 bytes big-endian. Apps accept at most 10 levels.
+func encodePath(path bip32.DerivationPath) ([]byte, error) {
+	if len(path) == 0 || len(path) > 10 {
+		return nil, fmt.Errorf("%w: %d levels, want 1 to 10", ErrInvalidPath, len(path))
+	}
+	out := make([]byte, 1, 1+4*len(path))
+	out[0] = byte(len(path))
+	for _, index := range path {
+		out = binary.BigEndian.AppendUint32(out, index)
+	}
+	return out, nil
+}
+
+// chunk splits the header followed by payload into APDU data fields of at most
+// 255 bytes. The header must fit in the first chunk.
+func chunk(header, payload []byte) [][]byte {
+	first := min(maxDataLength-len(header), len(payload))
+	chunks := [][]byte{append(append([]byte{}, header...), payload[:first]...)}
+	for rest := payload[first:]; len(rest) > 0; {
+		n := min(len(rest), maxDataLength)
+		chunks = append(chunks, rest[:n])
+		rest = rest[n:]
+	}
+	return chunks
+}
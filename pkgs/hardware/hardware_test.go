@@ -0,0 +1,321 @@
+package hardware
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/binary"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+// fakeLedger emulates the Ethereum and Bitcoin apps with keys derived from
+// testMnemonic. It runs in its own goroutine so the Bitcoin app can suspend a
+// command and wait for the host's answers to its client commands; it reports
+// problems with t.Errorf since FailNow only works on the test goroutine.
+type fakeLedger struct {
+	t      *testing.T
+	master *bip32.ExtendedKey
+	in     chan []byte
+	out    chan []byte
+
+	personal []byte // personal message being received
+	want     int    // its announced length
+}
+
+func newFakeLedger(t *testing.T) *fakeLedger {
+	master, err := bip32.NewMasterKey(bip39.NewSeed(testMnemonic, ""))
+	if err != nil {
+		t.Fatalf("NewMasterKey() error = %v", err)
+	}
+	f := &fakeLedger{t: t, master: master, in: make(chan []byte), out: make(chan []byte)}
+	go f.serve()
+	t.Cleanup(func() { close(f.in) })
+	return f
+}
+
+func (f *fakeLedger) Exchange(apdu []byte) ([]byte, error) {
+	f.in <- apdu
+	return <-f.out, nil
+}
+
+func (f *fakeLedger) serve() {
+	for apdu := range f.in {
+		data, sw := f.handle(apdu)
+		f.out <- binary.BigEndian.AppendUint16(data, sw)
+	}
+}
+
+func (f *fakeLedger) handle(apdu []byte) ([]byte, uint16) {
+	if len(apdu) < 5 || int(apdu[4]) != len(apdu)-5 {
+		return nil, SWWrongLength
+	}
+	data := apdu[5:]
+	switch [2]byte{apdu[0], apdu[1]} {
+	case [2]byte{ethCLA, ethINSGetAddress}:
+		key := f.derive(data)
+		publicKey := secp256k1.SerializeUncompressed(secp256k1.PrivateKeyToPublicKey(key.PrivateKeyBytes()))
+		addr, _ := address.NewEthereumAddress().Generate(publicKey)
+		resp := append([]byte{65}, publicKey...)
+		resp = append(resp, 40)
+		return append(resp, strings.ToLower(addr[2:])...), SWOK
+
+	case [2]byte{ethCLA, ethINSSignPersonal}:
+		if apdu[2] == ethP1First {
+			n := 1 + 4*int(data[0])
+			f.personal = append([]byte{}, data[:n]...)
+			f.want = int(binary.BigEndian.Uint32(data[n:]))
+			data = data[n+4:]
+		}
+		f.personal = append(f.personal, data...)
+		path := f.personal[:1+4*int(f.personal[0])]
+		message := f.personal[len(path):]
+		if len(message) < f.want {
+			return nil, SWOK
+		}
+		prefix := fmt.Sprintf("\x19Ethereum Signed Message:\n%d", len(message))
+		sig, recid := f.sign(path, address.Keccak256(append([]byte(prefix), message...)))
+		return append([]byte{27 + recid}, sig...), SWOK
+
+	case [2]byte{btcCLA, btcINSGetMasterFingerprint}:
+		return f.master.Fingerprint(), SWOK
+
+	case [2]byte{btcCLA, btcINSGetExtendedPubkey}:
+		xpub, _ := f.derive(data[1:]).Neuter()
+		return []byte(xpub.(*bip32.ExtendedKey).String()), SWOK
+
+	case [2]byte{btcCLA, btcINSSignMessage}:
+		return f.signBitcoinMessage(data)
+	}
+	return nil, SWINSNotSupported
+}
+
+// signBitcoinMessage fetches the committed message chunk by chunk, checking
+// every Merkle proof and preimage, then signs it.
+func (f *fakeLedger) signBitcoinMessage(data []byte) ([]byte, uint16) {
+	path := data[:1+4*int(data[0])]
+	size, n, _ := readCompactSize(data[len(path):])
+	root := data[len(path)+n:]
+
+	chunks := (int(size) + btcMessageChunkSize - 1) / btcMessageChunkSize
+	var message []byte
+	for i := range chunks {
+		req := append([]byte{cmdGetMerkleLeafProof}, root...)
+		req = appendCompactSize(appendCompactSize(req, uint64(chunks)), uint64(i))
+		resp := f.ask(req)
+		leaf, proof := resp[:32], f.collect(resp[34:], 32, int(resp[32]))
+		if got := rootFromProof(chunks, i, [32]byte(leaf), proof); !bytes.Equal(got[:], root) {
+			f.t.Errorf("Merkle proof for chunk %d does not reach the root", i)
+			return nil, SWInvalidData
+		}
+
+		resp = f.ask(append([]byte{cmdGetPreimage, 0}, leaf...))
+		length, n, _ := readCompactSize(resp)
+		preimage := bytes.Join(f.collect(resp[n+1:], 1, int(length)), nil)
+		if sha256.Sum256(preimage) != [32]byte(leaf) {
+			f.t.Errorf("preimage of chunk %d does not match its leaf", i)
+			return nil, SWInvalidData
+		}
+		message = append(message, preimage[1:]...)
+	}
+
+	digest := append([]byte("\x18Bitcoin Signed Message:\n"), appendCompactSize(nil, size)...)
+	first := sha256.Sum256(append(digest, message...))
+	hash := sha256.Sum256(first[:])
+	sig, recid := f.sign(path, hash[:])
+	return append([]byte{27 + 4 + recid}, sig...), SWOK
+}
+
+// ask suspends the current command with a client command and returns the
+// host's answer.
+func (f *fakeLedger) ask(request []byte) []byte {
+	f.out <- binary.BigEndian.AppendUint16(request, swInterrupted)
+	apdu := <-f.in
+	if apdu[0] != frameworkCLA || apdu[1] != frameworkINSContinue {
+		f.t.Errorf("expected CONTINUE_INTERRUPTED, got %x", apdu)
+	}
+	return apdu[5:]
+}
+
+// collect splits first into elements of size bytes and fetches the rest of
+// total elements with GET_MORE_ELEMENTS.
+func (f *fakeLedger) collect(first []byte, size, total int) [][]byte {
+	var elements [][]byte
+	for ; len(first) > 0; first = first[size:] {
+		elements = append(elements, first[:size])
+	}
+	for len(elements) < total {
+		resp := f.ask([]byte{cmdGetMoreElements})
+		n, size := int(resp[0]), int(resp[1])
+		for i := range n {
+			elements = append(elements, resp[2+i*size:2+(i+1)*size])
+		}
+	}
+	return elements
+}
+
+func (f *fakeLedger) derive(data []byte) *bip32.ExtendedKey {
+	path := make(bip32.DerivationPath, data[0])
+	for i := range path {
+		path[i] = binary.BigEndian.Uint32(data[1+4*i:])
+	}
+	key, err := f.master.DeriveFromPath(path)
+	if err != nil {
+		f.t.Errorf("DeriveFromPath(%s) error = %v", path, err)
+	}
+	return key
+}
+
+func (f *fakeLedger) sign(path, hash []byte) ([]byte, byte) {
+	sig, recid, err := secp256k1.SignECDSA(f.derive(path).PrivateKeyBytes(), hash)
+	if err != nil {
+		f.t.Errorf("SignECDSA() error = %v", err)
+	}
+	return sig, recid
+}
+
+// rootFromProof recomputes a Merkle root from a leaf and its proof.
+func rootFromProof(size, index int, leaf [32]byte, proof [][]byte) [32]byte {
+	if size == 1 {
+		return leaf
+	}
+	k := splitPoint(size)
+	top := [32]byte(proof[len(proof)-1])
+	if index < k {
+		return merkleNode(rootFromProof(k, index, leaf, proof[:len(proof)-1]), top)
+	}
+	return merkleNode(top, rootFromProof(size-k, index-k, leaf, proof[:len(proof)-1]))
+}
+
+func TestAPDU(t *testing.T) {
+	path := bip32.MustParsePath("m/44'/60'/0'/0/0")
+	apdu, err := EthGetAddressAPDU(path, true, false)
+	if err != nil {
+		t.Fatalf("EthGetAddressAPDU() error = %v", err)
+	}
+	command, err := apdu.Bytes()
+	if got, want := hex.EncodeToString(command), "e002010015058000002c8000003c800000000000000000000000"; err != nil || got != want {
+		t.Errorf("EthGetAddressAPDU() = %s, %v, want %s", got, err, want)
+	}
+	if _, err := (APDU{Data: make([]byte, 256)}).Bytes(); !errors.Is(err, ErrDataTooLong) {
+		t.Errorf("Bytes(256-byte data) error = %v, want ErrDataTooLong", err)
+	}
+
+	apdus, err := EthSignPersonalMessageAPDUs(path, bytes.Repeat([]byte{'a'}, 300))
+	if err != nil {
+		t.Fatalf("EthSignPersonalMessageAPDUs() error = %v", err)
+	}
+	if len(apdus) != 2 || len(apdus[0].Data) != 255 || apdus[0].P1 != 0x00 || apdus[1].P1 != 0x80 || len(apdus[1].Data) != 300-(255-25) {
+		t.Errorf("EthSignPersonalMessageAPDUs() chunks = %+v", apdus)
+	}
+
+	if _, err := EthGetAddressAPDU(nil, false, false); !errors.Is(err, ErrInvalidPath) {
+		t.Errorf("EthGetAddressAPDU(empty path) error = %v, want ErrInvalidPath", err)
+	}
+}
+
+func TestLedgerEthereum(t *testing.T) {
+	eth := NewLedgerEthereum(newFakeLedger(t))
+	path := bip32.MustParsePath("m/44'/60'/0'/0/0")
+
+	watch, err := WatchAccount(eth, address.ChainEthereum, path)
+	if err != nil {
+		t.Fatalf("WatchAccount() error = %v", err)
+	}
+	if watch.Address() != "0x9858EfFD232B4033E47d90003D41EC34EcaEda94" {
+		t.Errorf("WatchAccount().Address() = %s", watch.Address())
+	}
+
+	publicKey, err := eth.PublicKey(path)
+	if err != nil {
+		t.Fatalf("PublicKey() error = %v", err)
+	}
+	message := bytes.Repeat([]byte("hardware "), 40)
+	sig, err := eth.Sign(path, message)
+	if err != nil {
+		t.Fatalf("Sign() error = %v", err)
+	}
+	hash := address.Keccak256(append([]byte(fmt.Sprintf("\x19Ethereum Signed Message:\n%d", len(message))), message...))
+	recovered, err := secp256k1.RecoverPublicKey(hash, sig[:64], sig[64]-27)
+	if err != nil || !bytes.Equal(secp256k1.CompressPoint(recovered), publicKey) {
+		t.Errorf("Sign() signature does not recover the public key (err = %v)", err)
+	}
+}
+
+func TestLedgerBitcoin(t *testing.T) {
+	btc := NewLedgerBitcoin(newFakeLedger(t))
+	path := bip32.MustParsePath("m/84'/0'/0'/0/0")
+
+	watch, err := WatchAccount(btc, address.ChainBitcoin, path)
+	if err != nil {
+		t.Fatalf("WatchAccount() error = %v", err)
+	}
+	if watch.Address() != "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu" {
+		t.Errorf("WatchAccount().Address() = %s", watch.Address())
+	}
+
+	fingerprint, err := btc.MasterFingerprint()
+	if err != nil || hex.EncodeToString(fingerprint) != "73c5da0a" {
+		t.Errorf("MasterFingerprint() = %x, %v", fingerprint, err)
+	}
+
+	// Long enough that Merkle proofs overflow one response.
+	message := bytes.Repeat([]byte("0123456789"), 500)
+	publicKey, err := btc.PublicKey(path)
+	if err != nil {
+		t.Fatalf("PublicKey() error = %v", err)
+	}
+	sig, err := btc.Sign(path, message)
+	if err != nil {
+		t.Fatalf("Sign() error = %v", err)
+	}
+	digest := append([]byte("\x18Bitcoin Signed Message:\n"), appendCompactSize(nil, uint64(len(message)))...)
+	first := sha256.Sum256(append(digest, message...))
+	hash := sha256.Sum256(first[:])
+	recovered, err := secp256k1.RecoverPublicKey(hash[:], sig[1:], sig[0]-31)
+	if err != nil || !bytes.Equal(secp256k1.CompressPoint(recovered), publicKey) {
+		t.Errorf("Sign() signature does not recover the public key (err = %v)", err)
+	}
+}
+
+type statusTransport uint16
+
+func (s statusTransport) Exchange([]byte) ([]byte, error) {
+	return binary.BigEndian.AppendUint16(nil, uint16(s)), nil
+}
+
+func TestStatusError(t *testing.T) {
+	path := bip32.MustParsePath("m/44'/60'/0'/0/0")
+	tests := []struct {
+		sw   uint16
+		want error
+	}{
+		{SWDenied, ErrRejected},
+		{SWLocked, ErrLocked},
+		{SWCLANotSupported, ErrWrongApp},
+		{SWInvalidData, nil},
+	}
+	for _, tt := range tests {
+		_, err := NewLedgerEthereum(statusTransport(tt.sw)).Sign(path, []byte("hi"))
+		var status *StatusError
+		if !errors.As(err, &status) || status.SW != tt.sw {
+			t.Errorf("status %04x: error = %v, want *StatusError", tt.sw, err)
+		}
+		if tt.want != nil && !errors.Is(err, tt.want) {
+			t.Errorf("status %04x: error = %v, want %v", tt.sw, err, tt.want)
+		}
+	}
+
+	if _, err := NewLedgerBitcoin(statusTransport(SWOK)).MasterFingerprint(); !errors.Is(err, ErrInvalidResponse) {
+		t.Errorf("empty fingerprint: error = %v, want ErrInvalidResponse", err)
+	}
+}
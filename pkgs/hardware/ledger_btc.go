@@ -0,0 +1,371 @@
+package hardware
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/binary"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// Ledger Bitcoin app (2.x protocol) commands
+const (
+	btcCLA                     = 0xE1
+	btcINSGetExtendedPubkey    = 0x00
+	btcINSGetMasterFingerprint = 0x05
+	btcINSSignMessage          = 0x10
+	btcMessageChunkSize        = 64
+
+	frameworkCLA         = 0xF8
+	frameworkINSContinue = 0x01
+)
+
+// swInterrupted suspends a command so the app can request data from the host,
+// which answers with CONTINUE_INTERRUPTED on the framework class.
+const swInterrupted uint16 = 0xE000
+
+// Client commands the Bitcoin app sends in an interrupted response
+const (
+	cmdYield              = 0x10
+	cmdGetPreimage        = 0x40
+	cmdGetMerkleLeafProof = 0x41
+	cmdGetMerkleLeafIndex = 0x42
+	cmdGetMoreElements    = 0xA0
+)
+
+// BtcGetExtendedPubkeyAPDU builds GET_EXTENDED_PUBKEY. The app refuses
+// non-standard paths unless display is set and the user confirms the key.
+func BtcGetExtendedPubkeyAPDU(path bip32.DerivationPath, display bool) (APDU, error) {
+	encoded, err := encodePath(path)
+	if err != nil {
+		return APDU{}, err
+	}
+	data := []byte{0}
+	if display {
+		data[0] = 1
+	}
+	return APDU{CLA: btcCLA, INS: btcINSGetExtendedPubkey, Data: append(data, encoded...)}, nil
+}
+
+// BtcGetMasterFingerprintAPDU builds GET_MASTER_FINGERPRINT.
+func BtcGetMasterFingerprintAPDU() APDU {
+	return APDU{CLA: btcCLA, INS: btcINSGetMasterFingerprint}
+}
+
+// BtcSignMessageAPDU builds SIGN_MESSAGE. The message itself is not sent: the
+// APDU commits to it by length and Merkle root, and the app then fetches it in
+// 64-byte chunks through client commands, which LedgerBitcoin.Sign answers.
+func BtcSignMessageAPDU(path bip32.DerivationPath, message []byte) (APDU, error) {
+	data, err := encodePath(path)
+	if err != nil {
+		return APDU{}, err
+	}
+	data = appendCompactSize(data, uint64(len(message)))
+	root := merkleRoot(leafHashes(splitMessage(message)))
+	return APDU{CLA: btcCLA, INS: btcINSSignMessage, Data: append(data, root[:]...)}, nil
+}
+
+// LedgerBitcoin is the Ledger Bitcoin app, version 2.1 or later.
+type LedgerBitcoin struct {
+	transport Transport
+}
+
+var _ Signer = (*LedgerBitcoin)(nil)
+
+// NewLedgerBitcoin returns a Signer that talks to the Bitcoin app over t.
+func NewLedgerBitcoin(t Transport) *LedgerBitcoin {
+	return &LedgerBitcoin{transport: t}
+}
+
+// Scheme returns SchemeSecp256k1ECDSA.
+func (l *LedgerBitcoin) Scheme() signer.Scheme {
+	return signer.SchemeSecp256k1ECDSA
+}
+
+// MasterFingerprint returns the 4-byte fingerprint of the device's master key,
+// as used in PSBT key origins and output descriptors.
+func (l *LedgerBitcoin) MasterFingerprint() ([]byte, error) {
+	data, err := l.run(BtcGetMasterFingerprintAPDU(), newClientCommands())
+	if err != nil {
+		return nil, err
+	}
+	if len(data) != 4 {
+		return nil, fmt.Errorf("%w: fingerprint is %d bytes, want 4", ErrInvalidResponse, len(data))
+	}
+	return data, nil
+}
+
+// ExtendedPublicKey returns the extended public key at path, encoded with the
+// version bytes of the network the app is built for (xpub or tpub).
+func (l *LedgerBitcoin) ExtendedPublicKey(path bip32.DerivationPath, display bool) (string, error) {
+	apdu, err := BtcGetExtendedPubkeyAPDU(path, display)
+	if err != nil {
+		return "", err
+	}
+	data, err := l.run(apdu, newClientCommands())
+	if err != nil {
+		return "", err
+	}
+	return string(data), nil
+}
+
+// PublicKey returns the 33-byte compressed public key at path.
+func (l *LedgerBitcoin) PublicKey(path bip32.DerivationPath) ([]byte, error) {
+	xpub, err := l.ExtendedPublicKey(path, false)
+	if err != nil {
+		return nil, err
+	}
+	key, err := bip32.ParseExtendedKey(xpub)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidResponse, err)
+	}
+	return key.PublicKeyBytes(), nil
+}
+
+// Sign signs message as a Bitcoin signed message after the user confirms it,
+// and returns the 65-byte compact signature (header byte, r, s) that
+// base64-encoded is the format of Bitcoin Core's signmessage.
+func (l *LedgerBitcoin) Sign(path bip32.DerivationPath, message []byte) ([]byte, error) {
+	apdu, err := BtcSignMessageAPDU(path, message)
+	if err != nil {
+		return nil, err
+	}
+	cc := newClientCommands()
+	cc.addList(splitMessage(message))
+	sig, err := l.run(apdu, cc)
+	if err != nil {
+		return nil, err
+	}
+	if len(sig) != 65 {
+		return nil, fmt.Errorf("%w: signature is %d bytes, want 65", ErrInvalidResponse, len(sig))
+	}
+	return sig, nil
+}
+
+// run sends apdu and answers client commands until the app completes it.
+func (l *LedgerBitcoin) run(apdu APDU, cc *clientCommands) ([]byte, error) {
+	for {
+		data, sw, err := exchangeStatus(l.transport, apdu)
+		if err != nil {
+			return nil, err
+		}
+		switch sw {
+		case SWOK:
+			return data, nil
+		case swInterrupted:
+			resp, err := cc.execute(data)
+			if err != nil {
+				return nil, err
+			}
+			apdu = APDU{CLA: frameworkCLA, INS: frameworkINSContinue, Data: resp}
+		default:
+			return nil, &StatusError{SW: sw}
+		}
+	}
+}
+
+// clientCommands answers the app's requests for data the host committed to:
+// preimages of hashes and Merkle proofs for lists of elements. Answers longer
+// than one APDU are queued and drained with GET_MORE_ELEMENTS.
+type clientCommands struct {
+	preimages map[[32]byte][]byte
+	trees     map[[32]byte][][32]byte // root -> leaf hashes
+	queue     [][]byte
+}
+
+func newClientCommands() *clientCommands {
+	return &clientCommands{
+		preimages: make(map[[32]byte][]byte),
+		trees:     make(map[[32]byte][][32]byte),
+	}
+}
+
+// addList makes a Merkle-committed list of elements available to the app.
+func (c *clientCommands) addList(elements [][]byte) {
+	leaves := leafHashes(elements)
+	for i, el := range elements {
+		c.preimages[leaves[i]] = append([]byte{0x00}, el...)
+	}
+	c.trees[merkleRoot(leaves)] = leaves
+}
+
+// execute answers one client command.
+func (c *clientCommands) execute(request []byte) ([]byte, error) {
+	if len(request) == 0 {
+		return nil, fmt.Errorf("%w: empty client command", ErrInvalidResponse)
+	}
+	switch cmd, req := request[0], request[1:]; cmd {
+	case cmdYield:
+		return nil, nil // only sent while signing PSBTs
+
+	case cmdGetPreimage:
+		if len(req) != 33 || req[0] != 0 {
+			break
+		}
+		preimage, ok := c.preimages[[32]byte(req[1:])]
+		if !ok {
+			return nil, fmt.Errorf("%w: unknown preimage requested", ErrInvalidResponse)
+		}
+		resp := appendCompactSize(nil, uint64(len(preimage)))
+		n := min(maxDataLength-len(resp)-1, len(preimage))
+		for i := n; i < len(preimage); i++ {
+			c.queue = append(c.queue, preimage[i:i+1])
+		}
+		resp = append(resp, byte(n))
+		return append(resp, preimage[:n]...), nil
+
+	case cmdGetMerkleLeafProof:
+		if len(req) < 32 {
+			break
+		}
+		leaves, ok := c.trees[[32]byte(req[:32])]
+		size, n1, ok1 := readCompactSize(req[32:])
+		index, n2, ok2 := readCompactSize(req[32+n1:])
+		if !ok || !ok1 || !ok2 || len(req) != 32+n1+n2 || size != uint64(len(leaves)) || index >= size {
+			break
+		}
+		proof := merkleProof(leaves, int(index))
+		n := min((maxDataLength-32-2)/32, len(proof))
+		for _, h := range proof[n:] {
+			c.queue = append(c.queue, h[:])
+		}
+		resp := append(append([]byte{}, leaves[index][:]...), byte(len(proof)), byte(n))
+		for _, h := range proof[:n] {
+			resp = append(resp, h[:]...)
+		}
+		return resp, nil
+
+	case cmdGetMerkleLeafIndex:
+		if len(req) != 64 {
+			break
+		}
+		for i, leaf := range c.trees[[32]byte(req[:32])] {
+			if bytes.Equal(leaf[:], req[32:]) {
+				return appendCompactSize([]byte{1}, uint64(i)), nil
+			}
+		}
+		return []byte{0, 0}, nil
+
+	case cmdGetMoreElements:
+		if len(req) != 0 || len(c.queue) == 0 {
+			break
+		}
+		size := len(c.queue[0])
+		var elements []byte
+		n := 0
+		for n < len(c.queue) && len(c.queue[n]) == size && len(elements)+size <= maxDataLength-2 {
+			elements = append(elements, c.queue[n]...)
+			n++
+		}
+		c.queue = c.queue[n:]
+		return append([]byte{byte(n), byte(size)}, elements...), nil
+
+	default:
+		return nil, fmt.Errorf("%w: unknown client command %02x", ErrInvalidResponse, cmd)
+	}
+	return nil, fmt.Errorf("%w: malformed client command %02x", ErrInvalidResponse, request[0])
+}
+
+// splitMessage splits a message into the 64-byte chunks SIGN_MESSAGE commits to.
+func splitMessage(message []byte) [][]byte {
+	var chunks [][]byte
+	for len(message) > 0 {
+		n := min(len(message), btcMessageChunkSize)
+		chunks = append(chunks, message[:n])
+		message = message[n:]
+	}
+	return chunks
+}
+
+// leafHashes hashes list elements as Merkle leaves: SHA256(0x00 || element).
+func leafHashes(elements [][]byte) [][32]byte {
+	leaves := make([][32]byte, len(elements))
+	for i, el := range elements {
+		leaves[i] = sha256.Sum256(append([]byte{0x00}, el...))
+	}
+	return leaves
+}
+
+// merkleRoot returns the root of the app's Merkle tree over leaf hashes: the
+// left subtree holds the largest power of two strictly below the leaf count,
+// inner nodes are SHA256(0x01 || left || right) and the empty tree is all zeros.
+func merkleRoot(leaves [][32]byte) [32]byte {
+	switch len(leaves) {
+	case 0:
+		return [32]byte{}
+	case 1:
+		return leaves[0]
+	}
+	k := splitPoint(len(leaves))
+	return merkleNode(merkleRoot(leaves[:k]), merkleRoot(leaves[k:]))
+}
+
+// merkleProof returns the sibling hashes from the leaf at index up to the root.
+func merkleProof(leaves [][32]byte, index int) [][32]byte {
+	if len(leaves) <= 1 {
+		return nil
+	}
+	k := splitPoint(len(leaves))
+	if index < k {
+		return append(merkleProof(leaves[:k], index), merkleRoot(leaves[k:]))
+	}
+	return append(merkleProof(leaves[k:], index-k), merkleRoot(leaves[:k]))
+}
+
+// splitPoint returns the largest power of two strictly less than n (n >= 2).
+func splitPoint(n int) int {
+	k := 1
+	for k*2 < n {
+		k *= 2
+	}
+	return k
+}
+
+func merkleNode(left, right [32]byte) [32]byte {
+	data := make([]byte, 0, 65)
+	data = append(data, 0x01)
+	data = append(data, left[:]...)
+	return sha256.Sum256(append(data, right[:]...))
+}
+
+// appendCompactSize appends n as a Bitcoin CompactSize integer.
+func appendCompactSize(b []byte, n uint64) []byte {
+	switch {
+	case n < 0xFD:
+		return append(b, byte(n))
+	case n <= 0xFFFF:
+		return binary.LittleEndian.AppendUint16(append(b, 0xFD), uint16(n))
+	case n <= 0xFFFFFFFF:
+		return binary.LittleEndian.AppendUint32(append(b, 0xFE), uint32(n))
+	default:
+		return binary.LittleEndian.AppendUint64(append(b, 0xFF), n)
+	}
+}
+
+// readCompactSize decodes a CompactSize integer and returns it with its length.
+func readCompactSize(b []byte) (uint64, int, bool) {
+	if len(b) == 0 {
+		return 0, 0, false
+	}
+	switch b[0] {
+	case 0xFD:
+		if len(b) < 3 {
+			return 0, 0, false
+		}
+		return uint64(binary.LittleEndian.Uint16(b[1:])), 3, true
+	case 0xFE:
+		if len(b) < 5 {
+			return 0, 0, false
+		}
+		return uint64(binary.LittleEndian.Uint32(b[1:])), 5, true
+	case 0xFF:
+		if len(b) < 9 {
+			return 0, 0, false
+		}
+		return binary.LittleEndian.Uint64(b[1:]), 9, true
+	default:
+		return uint64(b[0]), 1, true
+	}
+}
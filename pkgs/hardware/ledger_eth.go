@@ -0,0 +1,240 @@
+package hardware
+
+import (
+	"encoding/binary"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// Ledger Ethereum app commands
+const (
+	ethCLA                 = 0xE0
+	ethINSGetAddress       = 0x02
+	ethINSSignTransaction  = 0x04
+	ethINSSignPersonal     = 0x08
+	ethINSSignEIP712Hashed = 0x0C
+
+	ethP1First     = 0x00
+	ethP1More      = 0x80
+	ethP1Display   = 0x01
+	ethP2ChainCode = 0x01
+)
+
+// EthGetAddressAPDU builds GET_ETH_PUBLIC_ADDRESS. With display set the device
+// shows the address and waits for the user to confirm it; with chainCode set
+// the response also carries the chain code.
+func EthGetAddressAPDU(path bip32.DerivationPath, display, chainCode bool) (APDU, error) {
+	data, err := encodePath(path)
+	if err != nil {
+		return APDU{}, err
+	}
+	apdu := APDU{CLA: ethCLA, INS: ethINSGetAddress, Data: data}
+	if display {
+		apdu.P1 = ethP1Display
+	}
+	if chainCode {
+		apdu.P2 = ethP2ChainCode
+	}
+	return apdu, nil
+}
+
+// EthSignTransactionAPDUs builds SIGN_ETH_TRANSACTION for an RLP-encoded
+// unsigned transaction (legacy, or typed with its type byte first), split
+// into as many APDUs as needed.
+func EthSignTransactionAPDUs(path bip32.DerivationPath, tx []byte) ([]APDU, error) {
+	header, err := encodePath(path)
+	if err != nil {
+		return nil, err
+	}
+	return ethChunks(ethINSSignTransaction, chunk(header, tx)), nil
+}
+
+// EthSignPersonalMessageAPDUs builds SIGN_ETH_PERSONAL_MESSAGE. The device
+// prefixes the message as EIP-191 personal_sign does before hashing it.
+func EthSignPersonalMessageAPDUs(path bip32.DerivationPath, message []byte) ([]APDU, error) {
+	header, err := encodePath(path)
+	if err != nil {
+		return nil, err
+	}
+	header = binary.BigEndian.AppendUint32(header, uint32(len(message)))
+	return ethChunks(ethINSSignPersonal, chunk(header, message)), nil
+}
+
+// EthSignEIP712HashedAPDU builds SIGN_ETH_EIP_712 in its hashed form, which
+// takes the domain separator and the hashStruct of the message.
+func EthSignEIP712HashedAPDU(path bip32.DerivationPath, domainHash, messageHash []byte) (APDU, error) {
+	if len(domainHash) != 32 || len(messageHash) != 32 {
+		return APDU{}, fmt.Errorf("hardware: EIP-712 hashes must be 32 bytes")
+	}
+	data, err := encodePath(path)
+	if err != nil {
+		return APDU{}, err
+	}
+	data = append(data, domainHash...)
+	data = append(data, messageHash...)
+	return APDU{CLA: ethCLA, INS: ethINSSignEIP712Hashed, Data: data}, nil
+}
+
+// ethChunks wraps data chunks in APDUs, marking all but the first as continuations.
+func ethChunks(ins byte, chunks [][]byte) []APDU {
+	apdus := make([]APDU, len(chunks))
+	for i, data := range chunks {
+		apdus[i] = APDU{CLA: ethCLA, INS: ins, P1: ethP1First, Data: data}
+		if i > 0 {
+			apdus[i].P1 = ethP1More
+		}
+	}
+	return apdus
+}
+
+// EthAddress is the response to GET_ETH_PUBLIC_ADDRESS.
+type EthAddress struct {
+	PublicKey []byte // 65-byte uncompressed
+	Address   string // EIP-55 checksummed, 0x-prefixed
+	ChainCode []byte // nil unless requested
+}
+
+// ParseEthAddressResponse decodes the response to GET_ETH_PUBLIC_ADDRESS and
+// checks that the address the device reports belongs to its public key.
+func ParseEthAddressResponse(data []byte) (*EthAddress, error) {
+	publicKey, rest, ok := cutLengthPrefixed(data)
+	if !ok || len(publicKey) != 65 {
+		return nil, fmt.Errorf("%w: public key", ErrInvalidResponse)
+	}
+	deviceAddress, rest, ok := cutLengthPrefixed(rest)
+	if !ok || len(deviceAddress) != 40 {
+		return nil, fmt.Errorf("%w: address", ErrInvalidResponse)
+	}
+	if len(rest) != 0 && len(rest) != 32 {
+		return nil, fmt.Errorf("%w: %d trailing bytes", ErrInvalidResponse, len(rest))
+	}
+
+	addr, err := address.NewEthereumAddress().Generate(publicKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidResponse, err)
+	}
+	if !strings.EqualFold(addr[2:], string(deviceAddress)) {
+		return nil, fmt.Errorf("%w: address %s does not match public key", ErrInvalidResponse, deviceAddress)
+	}
+
+	result := &EthAddress{PublicKey: publicKey, Address: addr}
+	if len(rest) == 32 {
+		result.ChainCode = rest
+	}
+	return result, nil
+}
+
+// parseEthSignature reorders the app's v || r || s response into the
+// r || s || v layout Ethereum tooling expects.
+func parseEthSignature(data []byte) ([]byte, error) {
+	if len(data) != 65 {
+		return nil, fmt.Errorf("%w: signature is %d bytes, want 65", ErrInvalidResponse, len(data))
+	}
+	sig := make([]byte, 0, 65)
+	sig = append(sig, data[1:]...)
+	return append(sig, data[0]), nil
+}
+
+// cutLengthPrefixed splits a one-byte length-prefixed field off data.
+func cutLengthPrefixed(data []byte) (field, rest []byte, ok bool) {
+	if len(data) == 0 || len(data) < 1+int(data[0]) {
+		return nil, nil, false
+	}
+	n := 1 + int(data[0])
+	return data[1:n], data[n:], true
+}
+
+// LedgerEthereum is the Ledger Ethereum app, which also serves other EVM chains.
+type LedgerEthereum struct {
+	transport Transport
+}
+
+var _ Signer = (*LedgerEthereum)(nil)
+
+// NewLedgerEthereum returns a Signer that talks to the Ethereum app over t.
+func NewLedgerEthereum(t Transport) *LedgerEthereum {
+	return &LedgerEthereum{transport: t}
+}
+
+// Scheme returns SchemeSecp256k1ECDSA.
+func (l *LedgerEthereum) Scheme() signer.Scheme {
+	return signer.SchemeSecp256k1ECDSA
+}
+
+// Address returns the public key and address at path. With display set the
+// user is asked to confirm the address on the device, which is how an
+// address shown by host software should be verified before receiving funds.
+func (l *LedgerEthereum) Address(path bip32.DerivationPath, display bool) (*EthAddress, error) {
+	apdu, err := EthGetAddressAPDU(path, display, false)
+	if err != nil {
+		return nil, err
+	}
+	data, err := exchange(l.transport, apdu)
+	if err != nil {
+		return nil, err
+	}
+	return ParseEthAddressResponse(data)
+}
+
+// PublicKey returns the 33-byte compressed public key at path.
+func (l *LedgerEthereum) PublicKey(path bip32.DerivationPath) ([]byte, error) {
+	addr, err := l.Address(path, false)
+	if err != nil {
+		return nil, err
+	}
+	point, err := secp256k1.ParsePublicKey(addr.PublicKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidResponse, err)
+	}
+	return secp256k1.CompressPoint(point), nil
+}
+
+// Sign signs message as an EIP-191 personal message (personal_sign) after the
+// user confirms it, and returns the 65-byte r || s || v signature with v 27 or 28.
+func (l *LedgerEthereum) Sign(path bip32.DerivationPath, message []byte) ([]byte, error) {
+	apdus, err := EthSignPersonalMessageAPDUs(path, message)
+	if err != nil {
+		return nil, err
+	}
+	return l.sign(apdus)
+}
+
+// SignTransaction signs an RLP-encoded unsigned transaction and returns
+// r || s || v. For typed transactions v is the recovery id; for legacy EIP-155
+// transactions it is the low byte of chainID*2 + 35 + recovery id, which the
+// caller must widen for chain IDs above 109.
+func (l *LedgerEthereum) SignTransaction(path bip32.DerivationPath, tx []byte) ([]byte, error) {
+	apdus, err := EthSignTransactionAPDUs(path, tx)
+	if err != nil {
+		return nil, err
+	}
+	return l.sign(apdus)
+}
+
+// SignTypedDataHash signs EIP-712 typed data given as the domain separator and
+// message hashes, and returns r || s || v with v 27 or 28. Devices show only
+// the hashes, so the user cannot check the contents.
+func (l *LedgerEthereum) SignTypedDataHash(path bip32.DerivationPath, domainHash, messageHash []byte) ([]byte, error) {
+	apdu, err := EthSignEIP712HashedAPDU(path, domainHash, messageHash)
+	if err != nil {
+		return nil, err
+	}
+	return l.sign([]APDU{apdu})
+}
+
+// sign sends the chunks of a signing command; the last response carries the signature.
+func (l *LedgerEthereum) sign(apdus []APDU) ([]byte, error) {
+	var data []byte
+	for _, apdu := range apdus {
+		var err error
+		if data, err = exchange(l.transport, apdu); err != nil {
+			return nil, err
+		}
+	}
+	return parseEthSignature(data)
+}
@@ -0,0 +1,43 @@
+package hardware
+
+import (
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// WatchAccount reads the public key at path from a device and wraps it in a
+// watch-only account, so the address comes from this module's address logic
+// and can be compared with the one the device displays. Bitcoin accounts take
+// their script type from the path's BIP-43 purpose (44, 49 or 84).
+func WatchAccount(s Signer, chain address.ChainID, path bip32.DerivationPath) (account.WatchAccount, error) {
+	var scriptType account.ScriptType
+	if chain == address.ChainBitcoin {
+		if len(path) == 0 {
+			return nil, fmt.Errorf("%w: empty path", ErrInvalidPath)
+		}
+		switch path[0] {
+		case bip32.Hardened(44):
+			scriptType = account.ScriptP2PKH
+		case bip32.Hardened(49):
+			scriptType = account.ScriptP2SHP2WPKH
+		case bip32.Hardened(84):
+			scriptType = account.ScriptP2WPKH
+		default:
+			return nil, fmt.Errorf("%w: no script type for purpose of %s", ErrInvalidPath, path)
+		}
+	} else if _, ok := address.EVMChains()[chain]; !ok {
+		return nil, fmt.Errorf("%w: %s", address.ErrUnsupportedChain, chain)
+	}
+
+	publicKey, err := s.PublicKey(path)
+	if err != nil {
+		return nil, err
+	}
+	if chain == address.ChainBitcoin {
+		return account.NewBitcoinWatchAccount(publicKey, scriptType)
+	}
+	return account.NewEVMWatchAccount(publicKey, chain)
+}
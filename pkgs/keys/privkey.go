@@ -0,0 +1,258 @@
+package keys
+
+import (
+	"errors"
+	"fmt"
+	"io"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// Curve identifies the curve a private key belongs to.
+type Curve string
+
+// Supported curves
+const (
+	CurveSecp256k1 Curve = "secp256k1"
+	CurveEd25519   Curve = "ed25519"
+	CurveP256      Curve = "p256"
+)
+
+// PrivateKeySize is the length of a raw private key on every supported curve.
+// For Ed25519 it is the seed, not the 64-byte expanded key.
+const PrivateKeySize = 32
+
+// SuiPrivateKeyHRP is the Bech32 prefix of Sui keytool private keys, which
+// encode a scheme flag followed by the key.
+const SuiPrivateKeyHRP = "suiprivkey"
+
+// Sui keytool scheme flags
+const (
+	suiFlagEd25519   = 0x00
+	suiFlagSecp256k1 = 0x01
+	suiFlagP256      = 0x02
+)
+
+var (
+	// ErrInvalidPrivateKey is returned for a key that is malformed or out of range for its curve.
+	ErrInvalidPrivateKey = errors.New("keys: invalid private key")
+
+	// ErrUnknownCurve is returned for an unsupported Curve.
+	ErrUnknownCurve = errors.New("keys: unknown curve")
+
+	// ErrCurveRequired is returned when parsing bare hex without a curve.
+	ErrCurveRequired = errors.New("keys: curve required for hex private key")
+
+	// ErrCurveMismatch is returned when an encoded key names a different curve than requested.
+	ErrCurveMismatch = errors.New("keys: private key curve mismatch")
+)
+
+// PrivateKey is a validated 32-byte private key tagged with its curve, held
+// in a secret buffer. Copies of a PrivateKey share the buffer, so Destroy
+// wipes the key for all of them. Printing it with any fmt verb shows the
+// curve only.
+type PrivateKey struct {
+	curve Curve
+	data  *secret.Bytes
+}
+
+// NewPrivateKey validates data as a raw private key on curve.
+func NewPrivateKey(curve Curve, data []byte) (PrivateKey, error) {
+	if len(data) != PrivateKeySize {
+		return PrivateKey{}, fmt.Errorf("%w: %d bytes", ErrInvalidPrivateKey, len(data))
+	}
+	switch curve {
+	case CurveSecp256k1:
+		if !secp256k1.IsValidPrivateKey(data) {
+			return PrivateKey{}, ErrInvalidPrivateKey
+		}
+	case CurveEd25519:
+	case CurveP256:
+		if _, err := p256.PrivateKeyToPublicKey(data); err != nil {
+			return PrivateKey{}, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+		}
+	default:
+		return PrivateKey{}, fmt.Errorf("%w: %q", ErrUnknownCurve, curve)
+	}
+	return PrivateKey{curve: curve, data: secret.Copy(data)}, nil
+}
+
+// ParsePrivateKey decodes a private key given as hex (with or without 0x),
+// WIF or a Sui "suiprivkey1" string. Hex carries no curve, so curve must be
+// set for it; WIF is secp256k1 unless curve is CurveP256, as Neo uses WIF for
+// P-256 keys; suiprivkey names its own curve. For the other formats an empty
+// curve accepts whatever the encoding says, and a different one is an error.
+func ParsePrivateKey(s string, curve Curve) (PrivateKey, error) {
+	s = strings.TrimSpace(s)
+
+	if strings.HasPrefix(s, SuiPrivateKeyHRP+"1") {
+		k, err := DecodeSuiPrivateKey(s)
+		if err != nil {
+			return PrivateKey{}, err
+		}
+		if curve != "" && curve != k.curve {
+			k.Destroy()
+			return PrivateKey{}, fmt.Errorf("%w: %s key, want %s", ErrCurveMismatch, k.curve, curve)
+		}
+		return k, nil
+	}
+
+	if raw, err := encoding.DecodeHex(s); err == nil {
+		defer secret.Wipe(raw)
+		if curve == "" {
+			return PrivateKey{}, ErrCurveRequired
+		}
+		return NewPrivateKey(curve, raw)
+	}
+
+	_, raw, _, err := DecodeWIF(s)
+	if err != nil {
+		return PrivateKey{}, fmt.Errorf("%w: not hex, WIF or suiprivkey", ErrInvalidPrivateKey)
+	}
+	defer secret.Wipe(raw)
+	switch curve {
+	case "", CurveSecp256k1:
+		return NewPrivateKey(CurveSecp256k1, raw)
+	case CurveP256:
+		return NewPrivateKey(CurveP256, raw)
+	default:
+		return PrivateKey{}, fmt.Errorf("%w: %s key, want %s", ErrCurveMismatch, CurveSecp256k1, curve)
+	}
+}
+
+// DecodeSuiPrivateKey decodes a Sui keytool "suiprivkey1" key, whose scheme
+// flag names its curve.
+func DecodeSuiPrivateKey(s string) (PrivateKey, error) {
+	hrp, payload, enc, err := address.Bech32Decode(s)
+	if err != nil {
+		return PrivateKey{}, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	defer secret.Wipe(payload)
+	if hrp != SuiPrivateKeyHRP || enc != address.Bech32Standard || len(payload) != 1+PrivateKeySize {
+		return PrivateKey{}, fmt.Errorf("%w: malformed suiprivkey", ErrInvalidPrivateKey)
+	}
+
+	var curve Curve
+	switch payload[0] {
+	case suiFlagEd25519:
+		curve = CurveEd25519
+	case suiFlagSecp256k1:
+		curve = CurveSecp256k1
+	case suiFlagP256:
+		curve = CurveP256
+	default:
+		return PrivateKey{}, fmt.Errorf("%w: unknown Sui key scheme 0x%02x", ErrInvalidPrivateKey, payload[0])
+	}
+	return NewPrivateKey(curve, payload[1:])
+}
+
+// DecodeWIF decodes a Wallet Import Format string of any version into its
+// version byte, private key and compression flag. The curve is not checked.
+func DecodeWIF(s string) (version byte, privateKey []byte, compressed bool, err error) {
+	version, payload, err := address.Base58CheckDecode(s)
+	if err != nil {
+		return 0, nil, false, fmt.Errorf("%w: %v", ErrInvalidPrivateKey, err)
+	}
+	switch {
+	case len(payload) == PrivateKeySize:
+		return version, payload, false, nil
+	case len(payload) == PrivateKeySize+1 && payload[PrivateKeySize] == 0x01:
+		return version, payload[:PrivateKeySize], true, nil
+	default:
+		secret.Wipe(payload)
+		return 0, nil, false, fmt.Errorf("%w: malformed WIF payload", ErrInvalidPrivateKey)
+	}
+}
+
+// Curve returns the key's curve.
+func (k PrivateKey) Curve() Curve { return k.curve }
+
+// Bytes returns a copy of the raw private key, or nil once destroyed. The
+// caller should wipe the copy once done.
+func (k PrivateKey) Bytes() []byte { return k.data.Bytes() }
+
+// Destroy wipes the key. The curve is kept; anything that needs the key fails.
+func (k PrivateKey) Destroy() { k.data.Destroy() }
+
+// IsZero reports whether k is the zero value.
+func (k PrivateKey) IsZero() bool { return k.curve == "" }
+
+// Equal reports whether two keys are the same, in constant time for equal curves.
+func (k PrivateKey) Equal(other PrivateKey) bool {
+	return k.curve == other.curve && k.data.Equal(other.data)
+}
+
+// PublicKey returns the public key: compressed for secp256k1 and P-256.
+func (k PrivateKey) PublicKey() (PublicKey, error) {
+	var pub PublicKey
+	var err error
+	k.data.Use(func(data []byte) {
+		if len(data) != PrivateKeySize {
+			err = ErrInvalidPrivateKey
+			return
+		}
+		switch k.curve {
+		case CurveSecp256k1:
+			pub = PublicKey{typ: Secp256k1Compressed, data: secp256k1.PrivateKeyToCompressedPublicKey(data)}
+		case CurveEd25519:
+			var b []byte
+			if b, err = ed25519.PrivateKeyToPublicKey(data); err == nil {
+				pub = PublicKey{typ: Ed25519, data: b}
+			}
+		case CurveP256:
+			var b []byte
+			if b, err = p256.PrivateKeyToCompressedPublicKey(data); err == nil {
+				pub = PublicKey{typ: P256, data: b}
+			}
+		default:
+			err = fmt.Errorf("%w: %q", ErrUnknownCurve, k.curve)
+		}
+	})
+	return pub, err
+}
+
+// Signer returns an in-memory ECDSA signer for a secp256k1 key or an Ed25519
+// signer. There is no signer scheme for P-256.
+func (k PrivateKey) Signer() (signer.Signer, error) {
+	var scheme signer.Scheme
+	switch k.curve {
+	case CurveSecp256k1:
+		scheme = signer.SchemeSecp256k1ECDSA
+	case CurveEd25519:
+		scheme = signer.SchemeEd25519
+	default:
+		return nil, fmt.Errorf("%w: %s", signer.ErrUnsupportedScheme, k.curve)
+	}
+
+	var s signer.Signer
+	var err error
+	k.data.Use(func(data []byte) { s, err = signer.New(scheme, data) })
+	return s, err
+}
+
+// String returns the curve with the key redacted.
+func (k PrivateKey) String() string {
+	return string(k.curve) + ":" + secret.Redacted
+}
+
+// GoString keeps %#v from printing the key bytes.
+func (k PrivateKey) GoString() string {
+	return "keys.PrivateKey{" + k.String() + "}"
+}
+
+// Format implements fmt.Formatter so that every verb, including %d, %x and
+// %+v, prints the redacted String form.
+func (k PrivateKey) Format(f fmt.State, verb rune) {
+	if verb == 'v' && f.Flag('#') {
+		io.WriteString(f, k.GoString())
+		return
+	}
+	io.WriteString(f, k.String())
+}
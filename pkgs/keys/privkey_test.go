@@ -0,0 +1,152 @@
+package keys
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// Bitcoin wiki WIF example, uncompressed and compressed.
+const (
+	wifKeyHex         = "0c28fca386c7a227600b2fe50b7cae11ec86d3bf1fbe471be89827e19d72aa1d"
+	wifUncompressed   = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ"
+	wifCompressed     = "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617"
+	secp256k1OrderHex = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+)
+
+func TestParsePrivateKey(t *testing.T) {
+	want := mustHex(t, wifKeyHex)
+	suiSecp, _ := address.Bech32Encode("suiprivkey", append([]byte{0x01}, want...), address.Bech32Standard)
+	suiEd, _ := address.Bech32Encode("suiprivkey", append([]byte{0x00}, want...), address.Bech32Standard)
+
+	for _, tt := range []struct {
+		in        string
+		curve     Curve
+		wantCurve Curve
+	}{
+		{wifKeyHex, CurveSecp256k1, CurveSecp256k1},
+		{"0x" + wifKeyHex, CurveEd25519, CurveEd25519},
+		{wifUncompressed, "", CurveSecp256k1},
+		{wifCompressed, CurveSecp256k1, CurveSecp256k1},
+		{wifCompressed, CurveP256, CurveP256},
+		{suiSecp, "", CurveSecp256k1},
+		{" " + suiEd + "\n", CurveEd25519, CurveEd25519},
+	} {
+		k, err := ParsePrivateKey(tt.in, tt.curve)
+		if err != nil {
+			t.Errorf("ParsePrivateKey(%q, %q) error = %v", tt.in, tt.curve, err)
+			continue
+		}
+		if k.Curve() != tt.wantCurve || !strings.EqualFold(fmt.Sprintf("%x", k.Bytes()), wifKeyHex) {
+			t.Errorf("ParsePrivateKey(%q) = %s %x", tt.in, k.Curve(), k.Bytes())
+		}
+	}
+
+	for _, tt := range []struct {
+		in    string
+		curve Curve
+		want  error
+	}{
+		{wifKeyHex, "", ErrCurveRequired},
+		{suiEd, CurveSecp256k1, ErrCurveMismatch},
+		{wifCompressed, CurveEd25519, ErrCurveMismatch},
+		{secp256k1OrderHex, CurveSecp256k1, ErrInvalidPrivateKey},
+		{wifKeyHex[:62], CurveSecp256k1, ErrInvalidPrivateKey},
+		{"not a key", CurveSecp256k1, ErrInvalidPrivateKey},
+		{wifKeyHex, "rsa", ErrUnknownCurve},
+	} {
+		if _, err := ParsePrivateKey(tt.in, tt.curve); !errors.Is(err, tt.want) {
+			t.Errorf("ParsePrivateKey(%q, %q) error = %v, want %v", tt.in, tt.curve, err, tt.want)
+		}
+	}
+}
+
+func TestPrivateKeyPublicKey(t *testing.T) {
+	one := make([]byte, PrivateKeySize)
+	one[31] = 1
+	k, err := NewPrivateKey(CurveSecp256k1, one)
+	if err != nil {
+		t.Fatalf("NewPrivateKey() error = %v", err)
+	}
+	pub, err := k.PublicKey()
+	if err != nil || pub.String() != "secp256k1:"+generatorCompressed {
+		t.Errorf("PublicKey() = %v, %v", pub, err)
+	}
+
+	s, err := k.Signer()
+	if err != nil || !pub.Equal(mustPublicKey(t, s.PublicKey())) {
+		t.Errorf("Signer() public key mismatch, err = %v", err)
+	}
+	p256Key, _ := NewPrivateKey(CurveP256, one)
+	if _, err := p256Key.Signer(); err == nil {
+		t.Error("P-256 Signer() succeeded")
+	}
+	if pub, err := p256Key.PublicKey(); err != nil || pub.Type() != P256 {
+		t.Errorf("P-256 PublicKey() = %v, %v", pub, err)
+	}
+}
+
+func TestPrivateKeyRedacted(t *testing.T) {
+	k, _ := ParsePrivateKey(wifKeyHex, CurveSecp256k1)
+	for verb, want := range map[string]string{
+		"%v":  "secp256k1:[REDACTED]",
+		"%+v": "secp256k1:[REDACTED]",
+		"%s":  "secp256k1:[REDACTED]",
+		"%d":  "secp256k1:[REDACTED]",
+		"%x":  "secp256k1:[REDACTED]",
+		"%X":  "secp256k1:[REDACTED]",
+		"%#v": "keys.PrivateKey{secp256k1:[REDACTED]}",
+	} {
+		if out := fmt.Sprintf(verb, k); out != want {
+			t.Errorf("Sprintf(%s) = %s, want %s", verb, out, want)
+		}
+	}
+	if out := fmt.Sprintf("%d", struct{ K PrivateKey }{k}); strings.Contains(out, "12 40 252") {
+		t.Errorf("Sprintf(%%d) of a struct leaked the key: %s", out)
+	}
+}
+
+func TestPrivateKeyDestroy(t *testing.T) {
+	k, _ := ParsePrivateKey(wifKeyHex, CurveSecp256k1)
+	c := k
+	k.Destroy()
+	if c.Bytes() != nil {
+		t.Errorf("Bytes() after Destroy = %x, want nil", c.Bytes())
+	}
+	if _, err := c.PublicKey(); !errors.Is(err, ErrInvalidPrivateKey) {
+		t.Errorf("PublicKey() after Destroy error = %v, want ErrInvalidPrivateKey", err)
+	}
+	if _, err := c.Signer(); err == nil {
+		t.Error("Signer() after Destroy succeeded")
+	}
+}
+
+func TestDecodeWIF(t *testing.T) {
+	for _, tt := range []struct {
+		in         string
+		compressed bool
+	}{
+		{wifUncompressed, false},
+		{wifCompressed, true},
+	} {
+		version, key, compressed, err := DecodeWIF(tt.in)
+		if err != nil || version != 0x80 || compressed != tt.compressed || fmt.Sprintf("%x", key) != wifKeyHex {
+			t.Errorf("DecodeWIF(%s) = %#x %x %v, %v", tt.in, version, key, compressed, err)
+		}
+	}
+	if _, _, _, err := DecodeWIF(wifKeyHex); !errors.Is(err, ErrInvalidPrivateKey) {
+		t.Errorf("DecodeWIF(hex) error = %v, want ErrInvalidPrivateKey", err)
+	}
+}
+
+func mustPublicKey(t *testing.T, data []byte) PublicKey {
+	t.Helper()
+	k, err := ParseSecp256k1PublicKey(data)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return k
+}
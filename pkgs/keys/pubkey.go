@@ -0,0 +1,255 @@
+// Package keys provides a curve-tagged public key type shared by every chain,
+// so cross-chain code can pass one value around instead of juggling 33-byte,
+// 65-byte and 32-byte slices whose curve is only known from context.
+package keys
+
+import (
+	"bytes"
+	"crypto/ecdh"
+	"crypto/elliptic"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/p256"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// KeyType identifies a curve and its public key encoding.
+type KeyType string
+
+// Supported public key types
+const (
+	// Secp256k1Compressed is a 33-byte SEC1 compressed secp256k1 key.
+	Secp256k1Compressed KeyType = "secp256k1"
+
+	// Secp256k1Uncompressed is a 65-byte SEC1 uncompressed secp256k1 key.
+	Secp256k1Uncompressed KeyType = "secp256k1-uncompressed"
+
+	// Secp256k1XOnly is a 32-byte BIP-340 x-only secp256k1 key.
+	Secp256k1XOnly KeyType = "secp256k1-xonly"
+
+	// Ed25519 is a 32-byte Ed25519 key.
+	Ed25519 KeyType = "ed25519"
+
+	// P256 is a 33-byte SEC1 compressed NIST P-256 key.
+	P256 KeyType = "p256"
+)
+
+var (
+	// ErrInvalidPublicKey is returned for bytes that are not a point of the given type.
+	ErrInvalidPublicKey = errors.New("keys: invalid public key")
+
+	// ErrUnknownKeyType is returned for an unsupported KeyType.
+	ErrUnknownKeyType = errors.New("keys: unknown key type")
+
+	// ErrIncompatibleType is returned when a conversion crosses curves.
+	ErrIncompatibleType = errors.New("keys: incompatible key type")
+)
+
+// PublicKey is a validated public key tagged with its curve and encoding.
+// The zero value is not a valid key.
+type PublicKey struct {
+	typ  KeyType
+	data []byte
+}
+
+// NewPublicKey validates data as a public key of type typ. A 65-byte
+// uncompressed P-256 key is accepted and stored compressed.
+func NewPublicKey(typ KeyType, data []byte) (PublicKey, error) {
+	var ok bool
+	switch typ {
+	case Secp256k1Compressed:
+		_, err := secp256k1.DecompressPoint(data)
+		ok = err == nil
+	case Secp256k1Uncompressed:
+		ok = len(data) == secp256k1.UncompressedPubKeyLen && onSecp256k1(data)
+	case Secp256k1XOnly:
+		_, err := secp256k1.LiftX(data)
+		ok = err == nil
+	case Ed25519:
+		ok = ed25519.IsOnCurve(data)
+	case P256:
+		if len(data) == p256.UncompressedPubKeyLen {
+			if _, err := ecdh.P256().NewPublicKey(data); err != nil {
+				return PublicKey{}, fmt.Errorf("%w: %v", ErrInvalidPublicKey, err)
+			}
+			data, _ = p256.CompressPublicKey(data)
+		}
+		x, _ := elliptic.UnmarshalCompressed(elliptic.P256(), data)
+		ok = x != nil
+	default:
+		return PublicKey{}, fmt.Errorf("%w: %q", ErrUnknownKeyType, typ)
+	}
+	if !ok {
+		return PublicKey{}, fmt.Errorf("%w: %d bytes as %s", ErrInvalidPublicKey, len(data), typ)
+	}
+	return PublicKey{typ: typ, data: bytes.Clone(data)}, nil
+}
+
+// ParseSecp256k1PublicKey parses a secp256k1 key of any encoding, picking
+// the type from its length: 33 compressed, 65 uncompressed or 32 x-only.
+func ParseSecp256k1PublicKey(data []byte) (PublicKey, error) {
+	switch len(data) {
+	case secp256k1.CompressedPubKeyLen:
+		return NewPublicKey(Secp256k1Compressed, data)
+	case secp256k1.UncompressedPubKeyLen:
+		return NewPublicKey(Secp256k1Uncompressed, data)
+	case secp256k1.XOnlyPubKeyLen:
+		return NewPublicKey(Secp256k1XOnly, data)
+	default:
+		return PublicKey{}, fmt.Errorf("%w: %d bytes", ErrInvalidPublicKey, len(data))
+	}
+}
+
+// ParsePublicKey parses the "type:hex" form written by String.
+func ParsePublicKey(s string) (PublicKey, error) {
+	typ, h, ok := strings.Cut(s, ":")
+	if !ok {
+		return PublicKey{}, fmt.Errorf("%w: missing key type", ErrInvalidPublicKey)
+	}
+	data, err := hex.DecodeString(h)
+	if err != nil {
+		return PublicKey{}, fmt.Errorf("%w: %v", ErrInvalidPublicKey, err)
+	}
+	return NewPublicKey(KeyType(typ), data)
+}
+
+// onSecp256k1 reports whether a 65-byte uncompressed key lies on the curve.
+func onSecp256k1(data []byte) bool {
+	if data[0] != secp256k1.PrefixUncompressed {
+		return false
+	}
+	p, err := secp256k1.DecompressPoint(append([]byte{secp256k1.PrefixEven | data[64]&1}, data[1:33]...))
+	return err == nil && bytes.Equal(secp256k1.SerializeUncompressed(p), data)
+}
+
+// Type returns the key's curve and encoding.
+func (k PublicKey) Type() KeyType { return k.typ }
+
+// Bytes returns a copy of the encoded key.
+func (k PublicKey) Bytes() []byte { return bytes.Clone(k.data) }
+
+// IsZero reports whether k is the zero value.
+func (k PublicKey) IsZero() bool { return k.typ == "" }
+
+// Equal reports whether two keys have the same type and bytes. Use
+// SamePoint to compare secp256k1 keys across encodings.
+func (k PublicKey) Equal(other PublicKey) bool {
+	return k.typ == other.typ && bytes.Equal(k.data, other.data)
+}
+
+// SamePoint reports whether two keys are the same point on the same curve.
+// An x-only key matches either Y parity.
+func (k PublicKey) SamePoint(other PublicKey) bool {
+	a, errA := k.XOnly()
+	b, errB := other.XOnly()
+	if errA == nil && errB == nil {
+		if k.typ == Secp256k1XOnly || other.typ == Secp256k1XOnly {
+			return a.Equal(b)
+		}
+		ca, _ := k.Compressed()
+		cb, _ := other.Compressed()
+		return ca.Equal(cb)
+	}
+	return k.Equal(other)
+}
+
+// String returns the key as "type:hex".
+func (k PublicKey) String() string {
+	return string(k.typ) + ":" + hex.EncodeToString(k.data)
+}
+
+// MarshalText implements encoding.TextMarshaler using the String form.
+func (k PublicKey) MarshalText() ([]byte, error) {
+	return []byte(k.String()), nil
+}
+
+// UnmarshalText implements encoding.TextUnmarshaler.
+func (k *PublicKey) UnmarshalText(text []byte) error {
+	parsed, err := ParsePublicKey(string(text))
+	if err != nil {
+		return err
+	}
+	*k = parsed
+	return nil
+}
+
+// isSecp256k1 reports whether the key is on secp256k1 in any encoding.
+func (k PublicKey) isSecp256k1() bool {
+	return k.typ == Secp256k1Compressed || k.typ == Secp256k1Uncompressed || k.typ == Secp256k1XOnly
+}
+
+// point returns the secp256k1 point; x-only keys lift to even Y.
+func (k PublicKey) point() (*secp256k1.Point, error) {
+	switch k.typ {
+	case Secp256k1Compressed, Secp256k1Uncompressed:
+		return secp256k1.ParsePublicKey(k.data)
+	case Secp256k1XOnly:
+		return secp256k1.LiftX(k.data)
+	default:
+		return nil, fmt.Errorf("%w: %s is not secp256k1", ErrIncompatibleType, k.typ)
+	}
+}
+
+// Compressed returns the 33-byte encoding of a secp256k1 or P-256 key. An
+// x-only key is given even Y, as BIP-340 defines.
+func (k PublicKey) Compressed() (PublicKey, error) {
+	if k.typ == Secp256k1Compressed || k.typ == P256 {
+		return k, nil
+	}
+	p, err := k.point()
+	if err != nil {
+		return PublicKey{}, err
+	}
+	return PublicKey{typ: Secp256k1Compressed, data: secp256k1.CompressPoint(p)}, nil
+}
+
+// Uncompressed returns the 65-byte encoding of a secp256k1 key.
+func (k PublicKey) Uncompressed() (PublicKey, error) {
+	p, err := k.point()
+	if err != nil {
+		return PublicKey{}, err
+	}
+	return PublicKey{typ: Secp256k1Uncompressed, data: secp256k1.SerializeUncompressed(p)}, nil
+}
+
+// XOnly returns the 32-byte BIP-340 encoding of a secp256k1 key.
+func (k PublicKey) XOnly() (PublicKey, error) {
+	if !k.isSecp256k1() {
+		return PublicKey{}, fmt.Errorf("%w: %s is not secp256k1", ErrIncompatibleType, k.typ)
+	}
+	x := k.data
+	if k.typ != Secp256k1XOnly {
+		x = k.data[1:33]
+	}
+	return PublicKey{typ: Secp256k1XOnly, data: bytes.Clone(x)}, nil
+}
+
+// Scheme returns the signature scheme the key verifies. P-256 keys have no
+// signer scheme and return "".
+func (k PublicKey) Scheme() signer.Scheme {
+	switch k.typ {
+	case Secp256k1Compressed, Secp256k1Uncompressed:
+		return signer.SchemeSecp256k1ECDSA
+	case Secp256k1XOnly:
+		return signer.SchemeSecp256k1Schnorr
+	case Ed25519:
+		return signer.SchemeEd25519
+	default:
+		return ""
+	}
+}
+
+// Verify checks a signature with the key's scheme; see signer.Signer for the
+// message and signature formats. It returns false for P-256 keys.
+func (k PublicKey) Verify(message, signature []byte) bool {
+	scheme := k.Scheme()
+	if scheme == "" {
+		return false
+	}
+	return signer.Verify(scheme, k.data, message, signature)
+}
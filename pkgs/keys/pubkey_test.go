@@ -0,0 +1,134 @@
+package keys
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// Public key of private key 1: the secp256k1 generator.
+const (
+	generatorCompressed   = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+	generatorUncompressed = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798" +
+		"483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+)
+
+func mustHex(t *testing.T, s string) []byte {
+	t.Helper()
+	b, err := hex.DecodeString(s)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return b
+}
+
+func TestSecp256k1Conversions(t *testing.T) {
+	compressed, err := ParseSecp256k1PublicKey(mustHex(t, generatorCompressed))
+	if err != nil || compressed.Type() != Secp256k1Compressed {
+		t.Fatalf("ParseSecp256k1PublicKey(compressed) = %v, %v", compressed, err)
+	}
+	uncompressed, err := compressed.Uncompressed()
+	if err != nil || hex.EncodeToString(uncompressed.Bytes()) != generatorUncompressed {
+		t.Fatalf("Uncompressed() = %v, %v", uncompressed, err)
+	}
+	back, _ := uncompressed.Compressed()
+	if !back.Equal(compressed) {
+		t.Errorf("Compressed() = %v, want %v", back, compressed)
+	}
+
+	xonly, err := uncompressed.XOnly()
+	if err != nil || hex.EncodeToString(xonly.Bytes()) != generatorCompressed[2:] {
+		t.Fatalf("XOnly() = %v, %v", xonly, err)
+	}
+	if xonly.Equal(compressed) || !xonly.SamePoint(compressed) || !uncompressed.SamePoint(compressed) {
+		t.Error("Equal/SamePoint across encodings")
+	}
+
+	if _, err := compressed.XOnly(); err != nil {
+		t.Errorf("XOnly(compressed) error = %v", err)
+	}
+	ed, _ := NewPublicKey(Ed25519, make([]byte, 32))
+	if _, err := ed.Uncompressed(); !errors.Is(err, ErrIncompatibleType) {
+		t.Errorf("Ed25519 Uncompressed() error = %v, want ErrIncompatibleType", err)
+	}
+}
+
+func TestNewPublicKeyRejectsInvalid(t *testing.T) {
+	offCurve := mustHex(t, generatorUncompressed)
+	offCurve[64] ^= 0x02
+
+	for _, tt := range []struct {
+		name string
+		typ  KeyType
+		data []byte
+	}{
+		{"uncompressed off curve", Secp256k1Uncompressed, offCurve},
+		{"compressed bad prefix", Secp256k1Compressed, append([]byte{0x05}, make([]byte, 32)...)},
+		{"uncompressed as compressed", Secp256k1Compressed, mustHex(t, generatorUncompressed)},
+		{"short ed25519", Ed25519, make([]byte, 31)},
+		{"p256 bad length", P256, make([]byte, 32)},
+	} {
+		if _, err := NewPublicKey(tt.typ, tt.data); !errors.Is(err, ErrInvalidPublicKey) {
+			t.Errorf("%s: error = %v, want ErrInvalidPublicKey", tt.name, err)
+		}
+	}
+	if _, err := NewPublicKey("rsa", nil); !errors.Is(err, ErrUnknownKeyType) {
+		t.Errorf("unknown type error = %v", err)
+	}
+}
+
+func TestPublicKeyText(t *testing.T) {
+	k, _ := NewPublicKey(Secp256k1Compressed, mustHex(t, generatorCompressed))
+	if k.String() != "secp256k1:"+generatorCompressed {
+		t.Errorf("String() = %s", k)
+	}
+
+	data, err := json.Marshal(struct{ Key PublicKey }{k})
+	if err != nil || !strings.Contains(string(data), generatorCompressed) {
+		t.Fatalf("json.Marshal() = %s, %v", data, err)
+	}
+	var decoded struct{ Key PublicKey }
+	if err := json.Unmarshal(data, &decoded); err != nil || !decoded.Key.Equal(k) {
+		t.Errorf("json round trip = %v, %v", decoded.Key, err)
+	}
+	if _, err := ParsePublicKey(generatorCompressed); !errors.Is(err, ErrInvalidPublicKey) {
+		t.Errorf("ParsePublicKey(no type) error = %v", err)
+	}
+}
+
+func TestPublicKeyVerify(t *testing.T) {
+	privateKey := mustHex(t, strings.Repeat("42", 32))
+	digest := sha256.Sum256([]byte("hello"))
+
+	for _, scheme := range []signer.Scheme{signer.SchemeSecp256k1ECDSA, signer.SchemeSecp256k1Schnorr, signer.SchemeEd25519} {
+		s, _ := signer.New(scheme, privateKey)
+		sig, _ := s.Sign(digest[:])
+
+		var k PublicKey
+		var err error
+		if scheme == signer.SchemeEd25519 {
+			k, err = NewPublicKey(Ed25519, s.PublicKey())
+		} else {
+			k, err = ParseSecp256k1PublicKey(s.PublicKey())
+		}
+		if err != nil {
+			t.Fatalf("%s: %v", scheme, err)
+		}
+		if k.Scheme() != scheme || !k.Verify(digest[:], sig) {
+			t.Errorf("%s: Scheme() = %s, Verify() failed", scheme, k.Scheme())
+		}
+	}
+
+	uncompressed, _ := NewPublicKey(Secp256k1Uncompressed, secp256k1.SerializeUncompressed(secp256k1.PrivateKeyToPublicKey(privateKey)))
+	ecdsaSigner, _ := signer.New(signer.SchemeSecp256k1ECDSA, privateKey)
+	sig, _ := ecdsaSigner.Sign(digest[:])
+	if !uncompressed.Verify(digest[:], sig) {
+		t.Error("uncompressed key Verify() failed")
+	}
+}
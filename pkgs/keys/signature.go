@@ -0,0 +1,209 @@
+package keys
+
+import (
+	"bytes"
+	"encoding/base64"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"math/big"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+// SignatureSize is the length of a compact signature in every scheme:
+// r || s for ECDSA, BIP-340 for Schnorr and R || S for Ed25519.
+const SignatureSize = 64
+
+var (
+	// ErrInvalidSignature is returned for a malformed signature.
+	ErrInvalidSignature = errors.New("keys: invalid signature")
+
+	// ErrNotRecoverable is returned when a recovery id is needed but absent.
+	ErrNotRecoverable = errors.New("keys: signature has no recovery id")
+)
+
+// Signature is a compact signature tagged with its scheme. An ECDSA signature
+// may also carry its recovery id, and converts to DER or to the 65-byte
+// r || s || v form used by Ethereum.
+type Signature struct {
+	scheme      signer.Scheme
+	data        []byte
+	recoverable bool
+	recoveryID  byte
+}
+
+// NewSignature wraps a 64-byte compact signature of scheme. ECDSA r and s
+// must be in [1, n).
+func NewSignature(scheme signer.Scheme, data []byte) (Signature, error) {
+	if len(data) != SignatureSize {
+		return Signature{}, fmt.Errorf("%w: %d bytes", ErrInvalidSignature, len(data))
+	}
+	switch scheme {
+	case signer.SchemeSecp256k1ECDSA:
+		if !validScalar(data[:32]) || !validScalar(data[32:]) {
+			return Signature{}, fmt.Errorf("%w: r or s out of range", ErrInvalidSignature)
+		}
+	case signer.SchemeSecp256k1Schnorr, signer.SchemeEd25519:
+	default:
+		return Signature{}, fmt.Errorf("%w: %q", signer.ErrUnsupportedScheme, scheme)
+	}
+	return Signature{scheme: scheme, data: bytes.Clone(data)}, nil
+}
+
+// NewRecoverableSignature wraps a compact ECDSA signature and its recovery id (0-3).
+func NewRecoverableSignature(data []byte, recoveryID byte) (Signature, error) {
+	if recoveryID > 3 {
+		return Signature{}, fmt.Errorf("%w: recovery id %d", ErrInvalidSignature, recoveryID)
+	}
+	sig, err := NewSignature(signer.SchemeSecp256k1ECDSA, data)
+	if err != nil {
+		return Signature{}, err
+	}
+	sig.recoverable, sig.recoveryID = true, recoveryID
+	return sig, nil
+}
+
+// ParseDERSignature parses a DER-encoded ECDSA signature.
+func ParseDERSignature(der []byte) (Signature, error) {
+	compact, err := secp256k1.ParseDERSignature(der)
+	if err != nil {
+		return Signature{}, fmt.Errorf("%w: %v", ErrInvalidSignature, err)
+	}
+	return NewSignature(signer.SchemeSecp256k1ECDSA, compact)
+}
+
+// ParseRecoverableSignature parses a 65-byte r || s || v ECDSA signature. V
+// may be the bare recovery id (0-3), Ethereum's 27-28 or a Bitcoin signed-
+// message header value (27-34); the offset is removed.
+func ParseRecoverableSignature(data []byte) (Signature, error) {
+	if len(data) != SignatureSize+1 {
+		return Signature{}, fmt.Errorf("%w: %d bytes", ErrInvalidSignature, len(data))
+	}
+	v := data[SignatureSize]
+	switch {
+	case v <= 3:
+	case v >= 27 && v <= 34:
+		v = (v - 27) & 3
+	default:
+		return Signature{}, fmt.Errorf("%w: v = %d", ErrInvalidSignature, v)
+	}
+	return NewRecoverableSignature(data[:SignatureSize], v)
+}
+
+// Sign signs message with s and tags the result with the signer's scheme.
+// An ECDSA signer.RecoverableSigner also provides the recovery id.
+func Sign(s signer.Signer, message []byte) (Signature, error) {
+	if rs, ok := s.(signer.RecoverableSigner); ok && s.Scheme() == signer.SchemeSecp256k1ECDSA {
+		data, id, err := rs.SignRecoverable(message)
+		if err != nil {
+			return Signature{}, err
+		}
+		return NewRecoverableSignature(data, id)
+	}
+	data, err := s.Sign(message)
+	if err != nil {
+		return Signature{}, err
+	}
+	return NewSignature(s.Scheme(), data)
+}
+
+// validScalar reports whether b is in [1, n).
+func validScalar(b []byte) bool {
+	x := new(big.Int).SetBytes(b)
+	return x.Sign() > 0 && x.Cmp(secp256k1.N) < 0
+}
+
+// Scheme returns the signature scheme.
+func (s Signature) Scheme() signer.Scheme { return s.scheme }
+
+// Bytes returns a copy of the 64-byte compact signature.
+func (s Signature) Bytes() []byte { return bytes.Clone(s.data) }
+
+// RecoveryID returns the ECDSA recovery id, if known.
+func (s Signature) RecoveryID() (byte, bool) {
+	return s.recoveryID, s.recoverable
+}
+
+// Equal reports whether two signatures have the same scheme, bytes and
+// recovery id.
+func (s Signature) Equal(other Signature) bool {
+	return s.scheme == other.scheme && s.recoverable == other.recoverable &&
+		s.recoveryID == other.recoveryID && bytes.Equal(s.data, other.data)
+}
+
+// DER returns an ECDSA signature in DER, as Bitcoin scripts and X.509 use.
+func (s Signature) DER() ([]byte, error) {
+	if s.scheme != signer.SchemeSecp256k1ECDSA {
+		return nil, fmt.Errorf("%w: %s signature has no DER form", ErrIncompatibleType, s.scheme)
+	}
+	return secp256k1.SerializeDERSignature(s.data)
+}
+
+// Recoverable returns r || s || v with v = recovery id + vOffset. Use 27 for
+// Ethereum personal_sign and legacy transactions, 0 for typed transactions.
+func (s Signature) Recoverable(vOffset byte) ([]byte, error) {
+	id, ok := s.RecoveryID()
+	if !ok {
+		return nil, ErrNotRecoverable
+	}
+	return append(bytes.Clone(s.data), id+vOffset), nil
+}
+
+// Normalize returns an ECDSA signature with low S (BIP-62, EIP-2), adjusting
+// the recovery id to match. Other schemes are returned unchanged.
+func (s Signature) Normalize() (Signature, error) {
+	if s.scheme != signer.SchemeSecp256k1ECDSA {
+		return s, nil
+	}
+	data, flipped, err := secp256k1.NormalizeSignature(s.data)
+	if err != nil {
+		return Signature{}, err
+	}
+	out := Signature{scheme: s.scheme, data: data, recoverable: s.recoverable, recoveryID: s.recoveryID}
+	if flipped && s.recoverable {
+		out.recoveryID ^= 1
+	}
+	return out, nil
+}
+
+// RecoverPublicKey returns the compressed key that produced a recoverable
+// ECDSA signature of a 32-byte hash.
+func (s Signature) RecoverPublicKey(hash []byte) (PublicKey, error) {
+	id, ok := s.RecoveryID()
+	if !ok {
+		return PublicKey{}, ErrNotRecoverable
+	}
+	p, err := secp256k1.RecoverPublicKey(hash, s.data, id)
+	if err != nil {
+		return PublicKey{}, err
+	}
+	return PublicKey{typ: Secp256k1Compressed, data: secp256k1.CompressPoint(p)}, nil
+}
+
+// Hex returns the compact signature in hex, followed by the recovery id
+// byte when it is known.
+func (s Signature) Hex() string {
+	if raw, err := s.Recoverable(0); err == nil {
+		return hex.EncodeToString(raw)
+	}
+	return hex.EncodeToString(s.data)
+}
+
+// Base64 returns the same bytes as Hex in standard Base64.
+func (s Signature) Base64() string {
+	if raw, err := s.Recoverable(0); err == nil {
+		return base64.StdEncoding.EncodeToString(raw)
+	}
+	return base64.StdEncoding.EncodeToString(s.data)
+}
+
+// String returns Hex.
+func (s Signature) String() string { return s.Hex() }
+
+// VerifySignature checks sig against the key. The signature's scheme must
+// be the one the key verifies.
+func (k PublicKey) VerifySignature(message []byte, sig Signature) bool {
+	return sig.scheme != "" && sig.scheme == k.Scheme() && k.Verify(message, sig.data)
+}
@@ -0,0 +1,138 @@
+package keys
+
+import (
+	"crypto/sha256"
+	"encoding/base64"
+	"errors"
+	"math/big"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/signer"
+)
+
+func TestSignatureEncodings(t *testing.T) {
+	key, _ := NewPrivateKey(CurveSecp256k1, mustHex(t, strings.Repeat("42", 32)))
+	pub, _ := key.PublicKey()
+	s, _ := key.Signer()
+	digest := sha256.Sum256([]byte("hello"))
+
+	sig, err := Sign(s, digest[:])
+	if err != nil {
+		t.Fatalf("Sign() error = %v", err)
+	}
+	if _, ok := sig.RecoveryID(); !ok || sig.Scheme() != signer.SchemeSecp256k1ECDSA {
+		t.Fatalf("Sign() = %s, recoverable %v", sig.Scheme(), ok)
+	}
+	if !pub.VerifySignature(digest[:], sig) {
+		t.Error("VerifySignature() failed")
+	}
+	if recovered, err := sig.RecoverPublicKey(digest[:]); err != nil || !recovered.Equal(pub) {
+		t.Errorf("RecoverPublicKey() = %v, %v, want %v", recovered, err, pub)
+	}
+
+	der, err := sig.DER()
+	if err != nil {
+		t.Fatalf("DER() error = %v", err)
+	}
+	fromDER, err := ParseDERSignature(der)
+	if err != nil || !fromDER.Equal(mustSignature(t, signer.SchemeSecp256k1ECDSA, sig.Bytes())) {
+		t.Errorf("ParseDERSignature() = %v, %v", fromDER, err)
+	}
+	if _, ok := fromDER.RecoveryID(); ok {
+		t.Error("DER signature has a recovery id")
+	}
+
+	rsv, err := sig.Recoverable(27)
+	if err != nil || len(rsv) != 65 || (rsv[64] != 27 && rsv[64] != 28) {
+		t.Fatalf("Recoverable(27) = %x, %v", rsv, err)
+	}
+	if parsed, err := ParseRecoverableSignature(rsv); err != nil || !parsed.Equal(sig) {
+		t.Errorf("ParseRecoverableSignature() = %v, %v, want %v", parsed, err, sig)
+	}
+	if len(sig.Hex()) != 130 || sig.String() != sig.Hex() {
+		t.Errorf("Hex() = %s", sig.Hex())
+	}
+	if raw, _ := base64.StdEncoding.DecodeString(sig.Base64()); len(raw) != 65 {
+		t.Errorf("Base64() decodes to %d bytes", len(raw))
+	}
+	if _, err := fromDER.Recoverable(27); !errors.Is(err, ErrNotRecoverable) {
+		t.Errorf("Recoverable() without id error = %v", err)
+	}
+}
+
+func TestSignatureNormalize(t *testing.T) {
+	key, _ := NewPrivateKey(CurveSecp256k1, mustHex(t, strings.Repeat("42", 32)))
+	pub, _ := key.PublicKey()
+	s, _ := key.Signer()
+	digest := sha256.Sum256([]byte("hello"))
+	sig, _ := Sign(s, digest[:])
+
+	// Flip to high S, which also flips the recovery id
+	data := sig.Bytes()
+	highS := new(big.Int).Sub(secp256k1.N, new(big.Int).SetBytes(data[32:]))
+	highS.FillBytes(data[32:])
+	id, _ := sig.RecoveryID()
+	high, _ := NewRecoverableSignature(data, id^1)
+
+	low, err := high.Normalize()
+	if err != nil || !low.Equal(sig) {
+		t.Fatalf("Normalize() = %v, %v, want %v", low, err, sig)
+	}
+	if recovered, _ := high.RecoverPublicKey(digest[:]); !recovered.Equal(pub) {
+		t.Errorf("high-S RecoverPublicKey() = %v, want %v", recovered, pub)
+	}
+}
+
+func TestSignatureSchemes(t *testing.T) {
+	seed := mustHex(t, strings.Repeat("42", 32))
+	message := []byte("hello")
+
+	ed, _ := NewPrivateKey(CurveEd25519, seed)
+	edSigner, _ := ed.Signer()
+	sig, err := Sign(edSigner, message)
+	if err != nil || sig.Scheme() != signer.SchemeEd25519 {
+		t.Fatalf("Sign(ed25519) = %v, %v", sig, err)
+	}
+	edPub, _ := ed.PublicKey()
+	if !edPub.VerifySignature(message, sig) {
+		t.Error("Ed25519 VerifySignature() failed")
+	}
+	if _, err := sig.DER(); !errors.Is(err, ErrIncompatibleType) {
+		t.Errorf("Ed25519 DER() error = %v", err)
+	}
+	if _, ok := sig.RecoveryID(); ok || len(sig.Hex()) != 128 {
+		t.Errorf("Ed25519 Hex() = %s", sig.Hex())
+	}
+
+	// A valid signature under the wrong scheme does not verify
+	secpPub, _ := ParseSecp256k1PublicKey(secp256k1.PrivateKeyToCompressedPublicKey(seed))
+	if secpPub.VerifySignature(message, sig) {
+		t.Error("secp256k1 key verified an Ed25519 signature")
+	}
+
+	for _, tt := range []struct {
+		name string
+		err  error
+		fn   func() error
+	}{
+		{"short", ErrInvalidSignature, func() error { _, err := NewSignature(signer.SchemeEd25519, seed); return err }},
+		{"zero r", ErrInvalidSignature, func() error { _, err := NewSignature(signer.SchemeSecp256k1ECDSA, make([]byte, 64)); return err }},
+		{"bad v", ErrInvalidSignature, func() error { _, err := ParseRecoverableSignature(append(sig.Bytes(), 35)); return err }},
+		{"unknown scheme", signer.ErrUnsupportedScheme, func() error { _, err := NewSignature("rsa", sig.Bytes()); return err }},
+	} {
+		if err := tt.fn(); !errors.Is(err, tt.err) {
+			t.Errorf("%s: error = %v, want %v", tt.name, err, tt.err)
+		}
+	}
+}
+
+func mustSignature(t *testing.T, scheme signer.Scheme, data []byte) Signature {
+	t.Helper()
+	sig, err := NewSignature(scheme, data)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return sig
+}
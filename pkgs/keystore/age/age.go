@@ -0,0 +1,321 @@
+// Package age reads and writes age (https://age-encryption.org/v1) encrypted
+// files, so mnemonics, seeds and private keys can be backed up in a format
+// that the age and rage command line tools and their libraries decrypt as
+// well. Files are encrypted to X25519 recipients ("age1...") or to a
+// passphrase with scrypt, and may be ASCII-armored.
+//
+// The package implements the format with the standard library and
+// golang.org/x/crypto; it does not support plugin recipients or SSH keys.
+package age
+
+import (
+	"bytes"
+	"crypto/hmac"
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/base64"
+	"encoding/binary"
+	"encoding/pem"
+	"errors"
+	"fmt"
+	"io"
+	"strings"
+
+	"golang.org/x/crypto/chacha20poly1305"
+	"golang.org/x/crypto/hkdf"
+)
+
+var (
+	// ErrMalformed is returned for input that is not an age file.
+	ErrMalformed = errors.New("age: malformed file")
+
+	// ErrNoIdentityMatched is returned when none of the identities can
+	// unwrap the file key: a wrong key or passphrase.
+	ErrNoIdentityMatched = errors.New("age: no identity matched any recipient")
+
+	// ErrInvalidRecipient is returned for a malformed recipient, identity or
+	// recipient list.
+	ErrInvalidRecipient = errors.New("age: invalid recipient")
+
+	// ErrDecryptionFailed is returned when the header MAC or the payload
+	// does not authenticate.
+	ErrDecryptionFailed = errors.New("age: file is corrupted or was modified")
+)
+
+const (
+	version   = "age-encryption.org/v1"
+	armorType = "AGE ENCRYPTED FILE"
+
+	fileKeySize = 16
+	nonceSize   = 16
+	chunkSize   = 64 * 1024
+)
+
+// b64 is the unpadded, canonical base64 age uses in headers.
+var b64 = base64.RawStdEncoding.Strict()
+
+// Recipient wraps a file key for one reader: an *X25519Recipient or a
+// *ScryptRecipient.
+type Recipient interface {
+	wrap(fileKey []byte) (*stanza, error)
+}
+
+// Identity unwraps a file key: an *X25519Identity or a *ScryptIdentity.
+type Identity interface {
+	// unwrap returns errIncorrectIdentity for stanzas it cannot open.
+	unwrap(stanzas []*stanza) ([]byte, error)
+}
+
+// errIncorrectIdentity is returned by unwrap when no stanza is for the identity.
+var errIncorrectIdentity = errors.New("age: incorrect identity")
+
+// stanza is one recipient's wrapped copy of the file key.
+type stanza struct {
+	typ  string
+	args []string
+	body []byte
+}
+
+// Encrypt encrypts plaintext to every recipient. A passphrase
+// (*ScryptRecipient) must be the only recipient.
+func Encrypt(plaintext []byte, recipients ...Recipient) ([]byte, error) {
+	if len(recipients) == 0 {
+		return nil, fmt.Errorf("%w: no recipients", ErrInvalidRecipient)
+	}
+	for _, r := range recipients {
+		if _, ok := r.(*ScryptRecipient); ok && len(recipients) > 1 {
+			return nil, fmt.Errorf("%w: a passphrase must be the only recipient", ErrInvalidRecipient)
+		}
+	}
+
+	fileKey := make([]byte, fileKeySize)
+	if _, err := rand.Read(fileKey); err != nil {
+		return nil, err
+	}
+	defer clear(fileKey)
+
+	var header bytes.Buffer
+	header.WriteString(version + "\n")
+	for _, r := range recipients {
+		s, err := r.wrap(fileKey)
+		if err != nil {
+			return nil, err
+		}
+		writeStanza(&header, s)
+	}
+	header.WriteString("---")
+	mac := headerMAC(fileKey, header.Bytes())
+	header.WriteString(" " + b64.EncodeToString(mac) + "\n")
+
+	nonce := make([]byte, nonceSize)
+	if _, err := rand.Read(nonce); err != nil {
+		return nil, err
+	}
+	payload, err := sealPayload(fileKey, nonce, plaintext)
+	if err != nil {
+		return nil, err
+	}
+
+	out := append(header.Bytes(), nonce...)
+	return append(out, payload...), nil
+}
+
+// Decrypt decrypts an age file, binary or armored, with the first identity
+// that unwraps its file key.
+func Decrypt(ciphertext []byte, identities ...Identity) ([]byte, error) {
+	if bytes.HasPrefix(bytes.TrimSpace(ciphertext), []byte("-----BEGIN "+armorType)) {
+		var err error
+		if ciphertext, err = Dearmor(ciphertext); err != nil {
+			return nil, err
+		}
+	}
+	stanzas, headerEnd, mac, rest, err := parseHeader(ciphertext)
+	if err != nil {
+		return nil, err
+	}
+
+	scrypt := false
+	for _, s := range stanzas {
+		scrypt = scrypt || s.typ == scryptStanzaType
+	}
+	if scrypt && len(stanzas) != 1 {
+		return nil, fmt.Errorf("%w: scrypt stanza must be alone", ErrMalformed)
+	}
+
+	var fileKey []byte
+	for _, id := range identities {
+		fileKey, err = id.unwrap(stanzas)
+		if err == nil {
+			break
+		}
+		if !errors.Is(err, errIncorrectIdentity) {
+			return nil, err
+		}
+	}
+	if fileKey == nil {
+		return nil, ErrNoIdentityMatched
+	}
+	defer clear(fileKey)
+
+	if !hmac.Equal(mac, headerMAC(fileKey, ciphertext[:headerEnd])) {
+		return nil, ErrDecryptionFailed
+	}
+	if len(rest) < nonceSize {
+		return nil, fmt.Errorf("%w: missing payload nonce", ErrMalformed)
+	}
+	return openPayload(fileKey, rest[:nonceSize], rest[nonceSize:])
+}
+
+// Armor encodes an age file as PEM-style ASCII armor.
+func Armor(data []byte) []byte {
+	return pem.EncodeToMemory(&pem.Block{Type: armorType, Bytes: data})
+}
+
+// Dearmor decodes ASCII armor written by Armor or by age -a.
+func Dearmor(data []byte) ([]byte, error) {
+	block, rest := pem.Decode(data)
+	if block == nil || block.Type != armorType || len(block.Headers) != 0 || len(bytes.TrimSpace(rest)) != 0 {
+		return nil, fmt.Errorf("%w: invalid armor", ErrMalformed)
+	}
+	return block.Bytes, nil
+}
+
+// writeStanza writes "-> type args" and the body in 64-column lines; the
+// last line is always shorter than 64 characters, possibly empty.
+func writeStanza(w *bytes.Buffer, s *stanza) {
+	w.WriteString("-> " + s.typ)
+	for _, arg := range s.args {
+		w.WriteString(" " + arg)
+	}
+	w.WriteByte('\n')
+	body := b64.EncodeToString(s.body)
+	for len(body) >= 64 {
+		w.WriteString(body[:64] + "\n")
+		body = body[64:]
+	}
+	w.WriteString(body + "\n")
+}
+
+// parseHeader splits a file into its stanzas, the length of the header up
+// to and including "---", the decoded MAC and the bytes after the header.
+func parseHeader(data []byte) (stanzas []*stanza, headerEnd int, mac, rest []byte, err error) {
+	malformed := func(msg string) error { return fmt.Errorf("%w: %s", ErrMalformed, msg) }
+
+	line, pos, ok := nextLine(data, 0)
+	if !ok || line != version {
+		return nil, 0, nil, nil, malformed("not an age v1 file")
+	}
+	for {
+		start := pos
+		if line, pos, ok = nextLine(data, pos); !ok {
+			return nil, 0, nil, nil, malformed("truncated header")
+		}
+		if strings.HasPrefix(line, "--- ") {
+			if mac, err = b64.DecodeString(line[4:]); err != nil || len(mac) != sha256.Size {
+				return nil, 0, nil, nil, malformed("invalid header MAC")
+			}
+			return stanzas, start + 3, mac, data[pos:], nil
+		}
+
+		fields := strings.Split(line, " ")
+		if len(fields) < 2 || fields[0] != "->" || fields[1] == "" {
+			return nil, 0, nil, nil, malformed("invalid stanza")
+		}
+		s := &stanza{typ: fields[1], args: fields[2:]}
+		var body strings.Builder
+		for {
+			if line, pos, ok = nextLine(data, pos); !ok || len(line) > 64 {
+				return nil, 0, nil, nil, malformed("invalid stanza body")
+			}
+			body.WriteString(line)
+			if len(line) < 64 {
+				break
+			}
+		}
+		if s.body, err = b64.DecodeString(body.String()); err != nil {
+			return nil, 0, nil, nil, malformed("invalid stanza body")
+		}
+		stanzas = append(stanzas, s)
+	}
+}
+
+// nextLine returns the line starting at pos without its "\n" and the
+// position after it.
+func nextLine(data []byte, pos int) (string, int, bool) {
+	i := bytes.IndexByte(data[pos:], '\n')
+	if i < 0 {
+		return "", 0, false
+	}
+	return string(data[pos : pos+i]), pos + i + 1, true
+}
+
+// headerMAC authenticates the header with a key derived from the file key.
+func headerMAC(fileKey, header []byte) []byte {
+	h := hmac.New(sha256.New, hkdfKey(fileKey, nil, "header"))
+	h.Write(header)
+	return h.Sum(nil)
+}
+
+// hkdfKey derives a 32-byte key with HKDF-SHA256.
+func hkdfKey(secret, salt []byte, info string) []byte {
+	key := make([]byte, chacha20poly1305.KeySize)
+	if _, err := io.ReadFull(hkdf.New(sha256.New, secret, salt, []byte(info)), key); err != nil {
+		panic("age: hkdf: " + err.Error())
+	}
+	return key
+}
+
+// sealPayload encrypts plaintext in 64 KiB ChaCha20-Poly1305 chunks (the
+// STREAM construction): each nonce is an 11-byte chunk counter followed by
+// 0x01 for the last chunk and 0x00 otherwise.
+func sealPayload(fileKey, nonce, plaintext []byte) ([]byte, error) {
+	aead, err := chacha20poly1305.New(hkdfKey(fileKey, nonce, "payload"))
+	if err != nil {
+		return nil, err
+	}
+	out := make([]byte, 0, len(plaintext)+(len(plaintext)/chunkSize+1)*aead.Overhead())
+	for counter := uint64(0); ; counter++ {
+		n := min(len(plaintext), chunkSize)
+		last := n == len(plaintext)
+		out = aead.Seal(out, chunkNonce(counter, last), plaintext[:n], nil)
+		plaintext = plaintext[n:]
+		if last {
+			return out, nil
+		}
+	}
+}
+
+// openPayload decrypts the chunks written by sealPayload.
+func openPayload(fileKey, nonce, payload []byte) ([]byte, error) {
+	aead, err := chacha20poly1305.New(hkdfKey(fileKey, nonce, "payload"))
+	if err != nil {
+		return nil, err
+	}
+	var out []byte
+	for counter := uint64(0); ; counter++ {
+		n := min(len(payload), chunkSize+aead.Overhead())
+		last := n == len(payload)
+		chunk, err := aead.Open(nil, chunkNonce(counter, last), payload[:n], nil)
+		if err != nil {
+			return nil, ErrDecryptionFailed
+		}
+		// Only an empty file may end with an empty chunk
+		if last && len(chunk) == 0 && counter > 0 {
+			return nil, fmt.Errorf("%w: empty final chunk", ErrMalformed)
+		}
+		out = append(out, chunk...)
+		payload = payload[n:]
+		if last {
+			return out, nil
+		}
+	}
+}
+
+func chunkNonce(counter uint64, last bool) []byte {
+	nonce := make([]byte, chacha20poly1305.NonceSize)
+	binary.BigEndian.PutUint64(nonce[3:11], counter)
+	if last {
+		nonce[11] = 1
+	}
+	return nonce
+}
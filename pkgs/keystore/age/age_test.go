@@ -0,0 +1,150 @@
+package age
+
+import (
+	"bytes"
+	"encoding/base64"
+	"errors"
+	"testing"
+)
+
+const (
+	testIdentity  = "AGE-SECRET-KEY-1QYPQXPQ9QCRSSZG2PVXQ6RS0ZQG3YYC5Z5TPWXQERGD3C8G7RUSQGPQYEE"
+	testRecipient = "age1q73he0q5yzfu3d64msd3p6rvksnrwjk3d2598mgtmlqt9wrdr37q2vrn72"
+
+	testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+)
+
+// Files written by an independent implementation of the age v1 spec: one to
+// testRecipient holding "hello age\n", one to the passphrase
+// "correct horse" (work factor 10) holding "hello scrypt\n".
+const (
+	x25519File = "YWdlLWVuY3J5cHRpb24ub3JnL3YxCi0+IFgyNTUxOSBpWnR1bXN3b0tyZHNrbk9JZTJOek01UTI3SkhwMHlZeTQzcEdab1VyYTFjCjJ0czJOT1licXVkWmlhVzU1MnJRMmtiQU5uZWV3cE1pSGJLaFhIUUh6bE0KLS0tIDR6OGZEUFBrbzFtRDk4dTBHUlhMWElsUWVsTFRsZS81UVkrMkYyUFFud2sKxuX6LfNjmVJQEiz9412ENYhWNRWvC1TpcI/iJYkPSEJOEfVChLXqbYFO"
+	scryptFile = "YWdlLWVuY3J5cHRpb24ub3JnL3YxCi0+IHNjcnlwdCB6cnZUek8yck45OXovVytDbkszTm9BIDEwCktSQWRlYXBSSnFIMWRud2RMZzVUSmhQR3FNSFgxTmdscFpDNUNveDh1WkkKLS0tIEplbElJWHdTMmlieERyV2UybmRSWFhRR2VwTERLV3YyN2phQzJHeGVHV2sKPIdUapMFRSK6C9bHE2SAZ+JQBGmR+b3IRZy0Z2KopLKAUM6IFtW1LZtQAodA"
+)
+
+func testScryptRecipient(t *testing.T, passphrase string) *ScryptRecipient {
+	t.Helper()
+	r, err := NewScryptRecipient(passphrase)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := r.SetWorkFactor(10); err != nil {
+		t.Fatal(err)
+	}
+	return r
+}
+
+func TestKeys(t *testing.T) {
+	id, err := ParseX25519Identity(testIdentity)
+	if err != nil {
+		t.Fatalf("ParseX25519Identity() error = %v", err)
+	}
+	if id.String() != testIdentity || id.Recipient().String() != testRecipient {
+		t.Errorf("identity = %s, recipient = %s", id, id.Recipient())
+	}
+	if _, err := ParseX25519Recipient(testRecipient); err != nil {
+		t.Errorf("ParseX25519Recipient() error = %v", err)
+	}
+
+	for _, s := range []string{testRecipient, "age-secret-key-1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc5z5tpwxqergd3c8g7rusqgpqyee"} {
+		if _, err := ParseX25519Identity(s); !errors.Is(err, ErrInvalidRecipient) {
+			t.Errorf("ParseX25519Identity(%.20s) error = %v, want ErrInvalidRecipient", s, err)
+		}
+	}
+	if _, err := ParseX25519Recipient(testIdentity); !errors.Is(err, ErrInvalidRecipient) {
+		t.Errorf("ParseX25519Recipient(identity) error = %v, want ErrInvalidRecipient", err)
+	}
+}
+
+func TestDecryptVectors(t *testing.T) {
+	id, _ := ParseX25519Identity(testIdentity)
+	data, _ := base64.StdEncoding.DecodeString(x25519File)
+	if got, err := Decrypt(data, id); err != nil || string(got) != "hello age\n" {
+		t.Errorf("Decrypt(X25519) = %q, %v", got, err)
+	}
+
+	data, _ = base64.StdEncoding.DecodeString(scryptFile)
+	if got, err := Decrypt(data, NewScryptIdentity("correct horse")); err != nil || string(got) != "hello scrypt\n" {
+		t.Errorf("Decrypt(scrypt) = %q, %v", got, err)
+	}
+	if _, err := Decrypt(data, NewScryptIdentity("wrong horse")); !errors.Is(err, ErrNoIdentityMatched) {
+		t.Errorf("Decrypt(wrong passphrase) error = %v, want ErrNoIdentityMatched", err)
+	}
+}
+
+func TestRoundTrip(t *testing.T) {
+	alice, _ := GenerateX25519Identity()
+	bob, _ := GenerateX25519Identity()
+	eve, _ := GenerateX25519Identity()
+
+	for _, size := range []int{0, 1, chunkSize, chunkSize + 1, 3 * chunkSize} {
+		plaintext := bytes.Repeat([]byte{0xa5}, size)
+		data, err := Encrypt(plaintext, alice.Recipient(), bob.Recipient())
+		if err != nil {
+			t.Fatalf("Encrypt(%d bytes) error = %v", size, err)
+		}
+		for _, id := range []*X25519Identity{alice, bob} {
+			if got, err := Decrypt(data, eve, id); err != nil || !bytes.Equal(got, plaintext) {
+				t.Errorf("Decrypt(%d bytes) = %d bytes, %v", size, len(got), err)
+			}
+		}
+		if _, err := Decrypt(data, eve); !errors.Is(err, ErrNoIdentityMatched) {
+			t.Errorf("Decrypt(other identity) error = %v, want ErrNoIdentityMatched", err)
+		}
+	}
+
+	data, _ := Encrypt([]byte("secret"), testScryptRecipient(t, "pw"))
+	armored := Armor(data)
+	if !bytes.HasPrefix(armored, []byte("-----BEGIN AGE ENCRYPTED FILE-----\n")) {
+		t.Errorf("Armor() = %s", armored)
+	}
+	if got, err := Decrypt(armored, NewScryptIdentity("pw")); err != nil || string(got) != "secret" {
+		t.Errorf("Decrypt(armored) = %q, %v", got, err)
+	}
+
+	if _, err := Encrypt([]byte("x"), testScryptRecipient(t, "pw"), alice.Recipient()); !errors.Is(err, ErrInvalidRecipient) {
+		t.Errorf("Encrypt(passphrase and key) error = %v, want ErrInvalidRecipient", err)
+	}
+}
+
+func TestTampered(t *testing.T) {
+	id, _ := GenerateX25519Identity()
+	data, _ := Encrypt([]byte("payload"), id.Recipient())
+
+	payload := append([]byte(nil), data...)
+	payload[len(payload)-1] ^= 1
+	if _, err := Decrypt(payload, id); !errors.Is(err, ErrDecryptionFailed) {
+		t.Errorf("Decrypt(modified payload) error = %v, want ErrDecryptionFailed", err)
+	}
+
+	// Adding a stanza changes the header, which the MAC covers
+	other, _ := GenerateX25519Identity()
+	header := bytes.Replace(data, []byte("\n---"), []byte("\n-> X25519 "+other.Recipient().String()[4:20]+"\n\n---"), 1)
+	if _, err := Decrypt(header, id); err == nil {
+		t.Error("Decrypt(modified header) succeeded")
+	}
+
+	if _, err := Decrypt([]byte("not an age file\n"), id); !errors.Is(err, ErrMalformed) {
+		t.Errorf("Decrypt(garbage) error = %v, want ErrMalformed", err)
+	}
+}
+
+func TestExport(t *testing.T) {
+	id, _ := GenerateX25519Identity()
+	data, err := ExportMnemonic(testMnemonic, id.Recipient())
+	if err != nil {
+		t.Fatalf("ExportMnemonic() error = %v", err)
+	}
+	if got, err := ImportMnemonic(data, id); err != nil || got != testMnemonic {
+		t.Errorf("ImportMnemonic() = %q, %v", got, err)
+	}
+	if _, err := ExportMnemonic("abandon abandon", id.Recipient()); err == nil {
+		t.Error("ExportMnemonic(invalid) succeeded")
+	}
+
+	seed := bytes.Repeat([]byte{0x42}, 64)
+	data, _ = ExportKey(seed, testScryptRecipient(t, "pw"))
+	if got, err := ImportKey(data, NewScryptIdentity("pw")); err != nil || !bytes.Equal(got, seed) {
+		t.Errorf("ImportKey() = %x, %v", got, err)
+	}
+}
@@ -0,0 +1,58 @@
+package age
+
+import (
+	"encoding/hex"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+)
+
+// The export helpers write secrets as one line of text, so `age -d` prints
+// something a person can read back: the mnemonic words, or the seed or
+// private key in hex.
+
+// ExportMnemonic encrypts a BIP-39 mnemonic to the recipients.
+func ExportMnemonic(mnemonic string, recipients ...Recipient) ([]byte, error) {
+	if !bip39.ValidateMnemonic(mnemonic) {
+		return nil, bip39.ErrInvalidMnemonic
+	}
+	return Encrypt([]byte(mnemonic+"\n"), recipients...)
+}
+
+// ImportMnemonic decrypts a file written by ExportMnemonic, or by
+// `age -e` over a text file holding the words, and validates the mnemonic.
+func ImportMnemonic(data []byte, identities ...Identity) (string, error) {
+	plaintext, err := Decrypt(data, identities...)
+	if err != nil {
+		return "", err
+	}
+	defer clear(plaintext)
+	mnemonic := strings.Join(strings.Fields(string(plaintext)), " ")
+	if !bip39.ValidateMnemonic(mnemonic) {
+		return "", bip39.ErrInvalidMnemonic
+	}
+	return mnemonic, nil
+}
+
+// ExportKey encrypts a seed or raw private key to the recipients as hex.
+func ExportKey(key []byte, recipients ...Recipient) ([]byte, error) {
+	text := []byte(hex.EncodeToString(key) + "\n")
+	defer clear(text)
+	return Encrypt(text, recipients...)
+}
+
+// ImportKey decrypts a file written by ExportKey.
+func ImportKey(data []byte, identities ...Identity) ([]byte, error) {
+	plaintext, err := Decrypt(data, identities...)
+	if err != nil {
+		return nil, err
+	}
+	defer clear(plaintext)
+	key, err := encoding.DecodeHex(strings.TrimSpace(string(plaintext)))
+	if err != nil {
+		return nil, fmt.Errorf("%w: payload is not a hex key", ErrMalformed)
+	}
+	return key, nil
+}
@@ -0,0 +1,114 @@
+package age
+
+import (
+	"crypto/rand"
+	"fmt"
+	"strconv"
+
+	"golang.org/x/crypto/chacha20poly1305"
+	"golang.org/x/crypto/scrypt"
+)
+
+const (
+	scryptStanzaType = "scrypt"
+	scryptLabel      = "age-encryption.org/v1/scrypt"
+	scryptSaltSize   = 16
+
+	// DefaultWorkFactor is the scrypt log2(N) age uses, about one second.
+	DefaultWorkFactor = 18
+
+	// MaxWorkFactor bounds the log2(N) accepted when decrypting, so a
+	// crafted file cannot demand unbounded memory.
+	MaxWorkFactor = 22
+)
+
+// ScryptRecipient encrypts to a passphrase.
+type ScryptRecipient struct {
+	passphrase string
+	workFactor int
+}
+
+// ScryptIdentity decrypts with a passphrase.
+type ScryptIdentity struct {
+	passphrase string
+}
+
+// NewScryptRecipient encrypts to passphrase with DefaultWorkFactor.
+func NewScryptRecipient(passphrase string) (*ScryptRecipient, error) {
+	if passphrase == "" {
+		return nil, fmt.Errorf("%w: empty passphrase", ErrInvalidRecipient)
+	}
+	return &ScryptRecipient{passphrase: passphrase, workFactor: DefaultWorkFactor}, nil
+}
+
+// SetWorkFactor sets the scrypt log2(N), from 1 to MaxWorkFactor. Lower
+// values are only meant for tests.
+func (r *ScryptRecipient) SetWorkFactor(logN int) error {
+	if logN < 1 || logN > MaxWorkFactor {
+		return fmt.Errorf("%w: work factor %d", ErrInvalidRecipient, logN)
+	}
+	r.workFactor = logN
+	return nil
+}
+
+// NewScryptIdentity decrypts files encrypted to passphrase.
+func NewScryptIdentity(passphrase string) *ScryptIdentity {
+	return &ScryptIdentity{passphrase: passphrase}
+}
+
+// wrap derives a key from the passphrase and a random salt.
+func (r *ScryptRecipient) wrap(fileKey []byte) (*stanza, error) {
+	salt := make([]byte, scryptSaltSize)
+	if _, err := rand.Read(salt); err != nil {
+		return nil, err
+	}
+	key, err := scryptKey(r.passphrase, salt, r.workFactor)
+	if err != nil {
+		return nil, err
+	}
+	body, err := wrapKey(key, fileKey)
+	if err != nil {
+		return nil, err
+	}
+	args := []string{b64.EncodeToString(salt), strconv.Itoa(r.workFactor)}
+	return &stanza{typ: scryptStanzaType, args: args, body: body}, nil
+}
+
+// unwrap opens the file's scrypt stanza, which Decrypt has checked is alone.
+func (i *ScryptIdentity) unwrap(stanzas []*stanza) ([]byte, error) {
+	if len(stanzas) != 1 || stanzas[0].typ != scryptStanzaType {
+		return nil, errIncorrectIdentity
+	}
+	s := stanzas[0]
+	if len(s.args) != 2 || len(s.body) != fileKeySize+chacha20poly1305.Overhead {
+		return nil, fmt.Errorf("%w: invalid scrypt stanza", ErrMalformed)
+	}
+	salt, err := b64.DecodeString(s.args[0])
+	if err != nil || len(salt) != scryptSaltSize {
+		return nil, fmt.Errorf("%w: invalid scrypt salt", ErrMalformed)
+	}
+	// Decimal without leading zeros or signs, as the spec requires
+	logN, err := strconv.Atoi(s.args[1])
+	if err != nil || strconv.Itoa(logN) != s.args[1] || logN < 1 {
+		return nil, fmt.Errorf("%w: invalid scrypt work factor", ErrMalformed)
+	}
+	if logN > MaxWorkFactor {
+		return nil, fmt.Errorf("%w: scrypt work factor %d exceeds %d", ErrMalformed, logN, MaxWorkFactor)
+	}
+
+	key, err := scryptKey(i.passphrase, salt, logN)
+	if err != nil {
+		return nil, err
+	}
+	fileKey, err := unwrapKey(key, s.body)
+	if err != nil {
+		return nil, errIncorrectIdentity
+	}
+	return fileKey, nil
+}
+
+// scryptKey derives the stanza key with N = 2^logN, r = 8, p = 1.
+func scryptKey(passphrase string, salt []byte, logN int) ([]byte, error) {
+	labeled := append([]byte(scryptLabel), salt...)
+	return scrypt.Key([]byte(passphrase), labeled, 1<<logN, 8, 1, chacha20poly1305.KeySize)
+}
@@ -0,0 +1,172 @@
+package age
+
+import (
+	"crypto/rand"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"golang.org/x/crypto/chacha20poly1305"
+	"golang.org/x/crypto/curve25519"
+)
+
+const (
+	x25519StanzaType = "X25519"
+	x25519Label      = "age-encryption.org/v1/X25519"
+
+	recipientHRP = "age"
+	identityHRP  = "age-secret-key-"
+)
+
+// X25519Recipient is an age public key, "age1...".
+type X25519Recipient struct {
+	publicKey []byte
+}
+
+// X25519Identity is an age secret key, "AGE-SECRET-KEY-1...".
+type X25519Identity struct {
+	secretKey []byte
+	recipient *X25519Recipient
+}
+
+// GenerateX25519Identity creates a random identity, like age-keygen.
+func GenerateX25519Identity() (*X25519Identity, error) {
+	secretKey := make([]byte, curve25519.ScalarSize)
+	if _, err := rand.Read(secretKey); err != nil {
+		return nil, err
+	}
+	return newX25519Identity(secretKey)
+}
+
+// ParseX25519Identity parses an "AGE-SECRET-KEY-1..." string.
+func ParseX25519Identity(s string) (*X25519Identity, error) {
+	if s != strings.ToUpper(s) {
+		return nil, fmt.Errorf("%w: identity must be upper case", ErrInvalidRecipient)
+	}
+	secretKey, err := decodeBech32(s, identityHRP)
+	if err != nil {
+		return nil, err
+	}
+	return newX25519Identity(secretKey)
+}
+
+// ParseX25519Recipient parses an "age1..." string.
+func ParseX25519Recipient(s string) (*X25519Recipient, error) {
+	if s != strings.ToLower(s) {
+		return nil, fmt.Errorf("%w: recipient must be lower case", ErrInvalidRecipient)
+	}
+	publicKey, err := decodeBech32(s, recipientHRP)
+	if err != nil {
+		return nil, err
+	}
+	return &X25519Recipient{publicKey: publicKey}, nil
+}
+
+func newX25519Identity(secretKey []byte) (*X25519Identity, error) {
+	publicKey, err := curve25519.X25519(secretKey, curve25519.Basepoint)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidRecipient, err)
+	}
+	return &X25519Identity{secretKey: secretKey, recipient: &X25519Recipient{publicKey: publicKey}}, nil
+}
+
+// Recipient returns the public key files are encrypted to.
+func (i *X25519Identity) Recipient() *X25519Recipient {
+	return i.recipient
+}
+
+// String returns the "AGE-SECRET-KEY-1..." encoding, as age-keygen writes it.
+func (i *X25519Identity) String() string {
+	s, _ := address.Bech32Encode(identityHRP, i.secretKey, address.Bech32Standard)
+	return strings.ToUpper(s)
+}
+
+// String returns the "age1..." encoding.
+func (r *X25519Recipient) String() string {
+	s, _ := address.Bech32Encode(recipientHRP, r.publicKey, address.Bech32Standard)
+	return s
+}
+
+// wrap encrypts the file key to an ephemeral X25519 share.
+func (r *X25519Recipient) wrap(fileKey []byte) (*stanza, error) {
+	ephemeral := make([]byte, curve25519.ScalarSize)
+	if _, err := rand.Read(ephemeral); err != nil {
+		return nil, err
+	}
+	defer clear(ephemeral)
+
+	share, err := curve25519.X25519(ephemeral, curve25519.Basepoint)
+	if err != nil {
+		return nil, err
+	}
+	shared, err := curve25519.X25519(ephemeral, r.publicKey)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidRecipient, err)
+	}
+	body, err := wrapKey(x25519WrapKey(shared, share, r.publicKey), fileKey)
+	if err != nil {
+		return nil, err
+	}
+	return &stanza{typ: x25519StanzaType, args: []string{b64.EncodeToString(share)}, body: body}, nil
+}
+
+// unwrap opens the first X25519 stanza addressed to the identity.
+func (i *X25519Identity) unwrap(stanzas []*stanza) ([]byte, error) {
+	for _, s := range stanzas {
+		if s.typ != x25519StanzaType {
+			continue
+		}
+		if len(s.args) != 1 || len(s.body) != fileKeySize+chacha20poly1305.Overhead {
+			return nil, fmt.Errorf("%w: invalid X25519 stanza", ErrMalformed)
+		}
+		share, err := b64.DecodeString(s.args[0])
+		if err != nil || len(share) != curve25519.PointSize {
+			return nil, fmt.Errorf("%w: invalid X25519 share", ErrMalformed)
+		}
+		shared, err := curve25519.X25519(i.secretKey, share)
+		if err != nil {
+			return nil, fmt.Errorf("%w: %v", ErrMalformed, err)
+		}
+		if fileKey, err := unwrapKey(x25519WrapKey(shared, share, i.recipient.publicKey), s.body); err == nil {
+			return fileKey, nil
+		}
+	}
+	return nil, errIncorrectIdentity
+}
+
+// x25519WrapKey derives the stanza key from the shared secret, salted with
+// the ephemeral share and the recipient.
+func x25519WrapKey(shared, share, publicKey []byte) []byte {
+	salt := append(append([]byte(nil), share...), publicKey...)
+	return hkdfKey(shared, salt, x25519Label)
+}
+
+// wrapKey seals the file key under a single-use key with a zero nonce.
+func wrapKey(key, fileKey []byte) ([]byte, error) {
+	aead, err := chacha20poly1305.New(key)
+	if err != nil {
+		return nil, err
+	}
+	return aead.Seal(nil, make([]byte, chacha20poly1305.NonceSize), fileKey, nil), nil
+}
+
+// unwrapKey opens a body sealed by wrapKey.
+func unwrapKey(key, body []byte) ([]byte, error) {
+	aead, err := chacha20poly1305.New(key)
+	if err != nil {
+		return nil, err
+	}
+	return aead.Open(nil, make([]byte, chacha20poly1305.NonceSize), body, nil)
+}
+
+// decodeBech32 decodes a 32-byte key with the given HRP.
+func decodeBech32(s, hrp string) ([]byte, error) {
+	gotHRP, data, err := address.Bech32DecodeAs(s, address.Bech32Standard)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidRecipient, err)
+	}
+	if gotHRP != hrp || len(data) != curve25519.ScalarSize {
+		return nil, fmt.Errorf("%w: want a 32-byte %q key", ErrInvalidRecipient, hrp)
+	}
+	return data, nil
+}
@@ -0,0 +1,113 @@
+package keystore
+
+import (
+	"crypto/rand"
+	"fmt"
+	"runtime"
+	"time"
+)
+
+// DefaultCalibrationTarget is a reasonable unlock time for interactive use.
+const DefaultCalibrationTarget = 500 * time.Millisecond
+
+const (
+	// Calibrate never picks less than these, whatever the host.
+	minCalibratedScryptN      = 1 << 12
+	minCalibratedArgon2Memory = 8 * 1024 // KiB
+
+	// Calibrate stays under 1 GiB of memory, so a keystore calibrated on a
+	// large server can still be opened on a phone; past it argon2id spends
+	// the remaining time in passes instead.
+	maxCalibratedScryptN      = 1 << 20
+	maxCalibratedArgon2Memory = 1024 * 1024 // KiB
+)
+
+// Calibrate measures the key derivation function on this host and returns
+// the most expensive parameters that derive a key in about target:
+// scrypt's N for KDFScrypt, or argon2id's memory and then passes for
+// KDFArgon2id. Encrypt records the parameters in the keystore's kdfparams,
+// so decryption elsewhere uses them as is, at a cost relative to that
+// host's speed. Calibration itself takes up to about twice the target.
+func Calibrate(kdf KDF, target time.Duration) (Params, error) {
+	if target <= 0 {
+		return Params{}, fmt.Errorf("%w: calibration target %v", ErrInvalidParams, target)
+	}
+	switch kdf {
+	case KDFScrypt:
+		return calibrateScrypt(target)
+	case KDFArgon2id:
+		return calibrateArgon2id(target)
+	default:
+		return Params{}, fmt.Errorf("%w: %q", ErrUnsupportedKDF, kdf)
+	}
+}
+
+// calibrateScrypt doubles N from the minimum until a measured derivation
+// takes a quarter of the target, then extrapolates, since scrypt's time is
+// linear in N.
+func calibrateScrypt(target time.Duration) (Params, error) {
+	params := Params{KDF: KDFScrypt, N: minCalibratedScryptN, R: 8, P: 1}
+	elapsed, err := measureKDF(params)
+	if err != nil {
+		return Params{}, err
+	}
+	for elapsed < target/4 && params.N < maxCalibratedScryptN {
+		params.N <<= 1
+		if elapsed, err = measureKDF(params); err != nil {
+			return Params{}, err
+		}
+	}
+	for elapsed*2 <= target && params.N < maxCalibratedScryptN {
+		params.N <<= 1
+		elapsed *= 2
+	}
+	return params, nil
+}
+
+// calibrateArgon2id grows the memory the same way, then the number of
+// passes once the memory reaches its cap.
+func calibrateArgon2id(target time.Duration) (Params, error) {
+	params := Params{
+		KDF:     KDFArgon2id,
+		Time:    1,
+		Memory:  minCalibratedArgon2Memory,
+		Threads: uint8(min(runtime.NumCPU(), 4)),
+	}
+	elapsed, err := measureKDF(params)
+	if err != nil {
+		return Params{}, err
+	}
+	for elapsed < target/4 && params.Memory < maxCalibratedArgon2Memory {
+		params.Memory <<= 1
+		if elapsed, err = measureKDF(params); err != nil {
+			return Params{}, err
+		}
+	}
+	for elapsed*2 <= target && params.Memory < maxCalibratedArgon2Memory {
+		params.Memory <<= 1
+		elapsed *= 2
+	}
+	if elapsed <= 0 {
+		return params, nil
+	}
+	// Each pass costs about the same as the first
+	passes := uint32(min(int64(target/elapsed), maxArgon2Time))
+	params.Time = max(passes, 1)
+	return params, nil
+}
+
+// measureKDF returns how long one key derivation with params takes.
+func measureKDF(params Params) (time.Duration, error) {
+	if err := checkParams(params); err != nil {
+		return 0, err
+	}
+	salt := make([]byte, saltLen)
+	if _, err := rand.Read(salt); err != nil {
+		return 0, err
+	}
+	start := time.Now()
+	if _, err := deriveKey("calibration", salt, params); err != nil {
+		return 0, err
+	}
+	return time.Since(start), nil
+}
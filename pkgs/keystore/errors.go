@@ -0,0 +1,31 @@
+// Package keystore encrypts account secrets and mnemonics into versioned JSON files.
+package keystore
+
+import "errors"
+
+var (
+	// ErrDecryptionFailed is returned when the password is wrong or the keystore was modified.
+	ErrDecryptionFailed = errors.New("keystore: invalid password or corrupted data")
+
+	// ErrUnsupportedVersion is returned for keystore files written by an unknown version.
+	ErrUnsupportedVersion = errors.New("keystore: unsupported version")
+
+	// ErrUnsupportedKDF is returned when the key derivation function is not scrypt or argon2id.
+	ErrUnsupportedKDF = errors.New("keystore: unsupported kdf")
+
+	// ErrUnsupportedCipher is returned when the cipher is not aes-256-gcm.
+	ErrUnsupportedCipher = errors.New("keystore: unsupported cipher")
+
+	// ErrInvalidParams is returned when KDF parameters are missing or out of range.
+	ErrInvalidParams = errors.New("keystore: invalid kdf parameters")
+
+	// ErrWrongKind is returned when decrypting a keystore as the wrong kind of secret.
+	ErrWrongKind = errors.New("keystore: wrong secret kind")
+
+	// ErrNoSecret is returned when asking a watch-only wallet file for its mnemonic.
+	ErrNoSecret = errors.New("keystore: wallet file has no secret")
+
+	// ErrInvalidXpub is returned for a wallet entry whose xpub is malformed,
+	// private or does not match its path.
+	ErrInvalidXpub = errors.New("keystore: invalid extended public key")
+)
@@ -0,0 +1,380 @@
+package keystore
+
+import (
+	"context"
+	"crypto/aes"
+	"crypto/cipher"
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+	"golang.org/x/crypto/argon2"
+	"golang.org/x/crypto/scrypt"
+)
+
+// Version is the keystore format written by this package.
+const Version = 1
+
+// CipherAES256GCM is the only cipher used by version 1 keystores.
+const CipherAES256GCM = "aes-256-gcm"
+
+const (
+	keyLen   = 32
+	saltLen  = 32
+	nonceLen = 12
+
+	// Upper bounds accepted when decrypting, so a crafted file cannot demand
+	// unbounded memory or time.
+	maxScryptN      = 1 << 22
+	maxScryptR      = 32
+	maxScryptP      = 16
+	maxScryptMemory = 4 << 30         // bytes, 128*N*r
+	maxArgon2Memory = 4 * 1024 * 1024 // KiB
+	maxArgon2Time   = 64
+)
+
+// KDF names a password-based key derivation function.
+type KDF string
+
+// Supported key derivation functions
+const (
+	KDFScrypt   KDF = "scrypt"
+	KDFArgon2id KDF = "argon2id"
+)
+
+// Kind describes what an encrypted secret is.
+type Kind string
+
+// Secret kinds
+const (
+	KindMnemonic   Kind = "mnemonic"
+	KindPrivateKey Kind = "private-key"
+)
+
+// Params selects the KDF and its cost. N, R and P apply to scrypt;
+// Time, Memory (KiB) and Threads apply to argon2id.
+type Params struct {
+	KDF     KDF
+	N       int
+	R       int
+	P       int
+	Time    uint32
+	Memory  uint32
+	Threads uint8
+}
+
+var (
+	// DefaultScryptParams is scrypt with N=2^18, r=8, p=1 (about 256 MiB, ~1s).
+	DefaultScryptParams = Params{KDF: KDFScrypt, N: 1 << 18, R: 8, P: 1}
+
+	// LightScryptParams is scrypt with N=2^12 for constrained devices and tests.
+	LightScryptParams = Params{KDF: KDFScrypt, N: 1 << 12, R: 8, P: 1}
+
+	// DefaultArgon2idParams is argon2id with t=3, m=64 MiB, p=4 (RFC 9106 second recommendation).
+	DefaultArgon2idParams = Params{KDF: KDFArgon2id, Time: 3, Memory: 64 * 1024, Threads: 4}
+)
+
+// Keystore is an encrypted secret with optional, unencrypted account metadata.
+type Keystore struct {
+	Version int             `json:"version"`
+	ID      string          `json:"id"`
+	Kind    Kind            `json:"kind"`
+	Chain   address.ChainID `json:"chain,omitempty"`
+	Path    string          `json:"path,omitempty"`
+	Address string          `json:"address,omitempty"`
+	Crypto  CryptoJSON      `json:"crypto"`
+}
+
+// CryptoJSON holds the ciphertext and everything needed to decrypt it.
+type CryptoJSON struct {
+	Cipher     string        `json:"cipher"`
+	CipherText string        `json:"ciphertext"`
+	Nonce      string        `json:"nonce"`
+	KDF        KDF           `json:"kdf"`
+	KDFParams  KDFParamsJSON `json:"kdfparams"`
+}
+
+// KDFParamsJSON is the serialized form of Params plus the salt.
+type KDFParamsJSON struct {
+	Salt    string `json:"salt"`
+	DKLen   int    `json:"dklen"`
+	N       int    `json:"n,omitempty"`
+	R       int    `json:"r,omitempty"`
+	P       int    `json:"p,omitempty"`
+	Time    uint32 `json:"time,omitempty"`
+	Memory  uint32 `json:"memory,omitempty"`
+	Threads uint8  `json:"threads,omitempty"`
+}
+
+// Encrypt encrypts a secret with a key derived from password.
+func Encrypt(plaintext []byte, kind Kind, password string, params Params) (*Keystore, error) {
+	return encrypt(plaintext, &Keystore{Kind: kind}, password, params)
+}
+
+// encrypt fills in ks and encrypts plaintext into it. Everything in ks but
+// the ciphertext is authenticated as additional data, so the metadata must be
+// set before calling it.
+func encrypt(plaintext []byte, ks *Keystore, password string, params Params) (*Keystore, error) {
+	if err := checkParams(params); err != nil {
+		return nil, err
+	}
+
+	salt := make([]byte, saltLen)
+	nonce := make([]byte, nonceLen)
+	id := make([]byte, 16)
+	for _, b := range [][]byte{salt, nonce, id} {
+		if _, err := rand.Read(b); err != nil {
+			return nil, err
+		}
+	}
+
+	key, err := deriveKey(password, salt, params)
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(key)
+
+	aead, err := newAEAD(key)
+	if err != nil {
+		return nil, err
+	}
+
+	ks.Version = Version
+	ks.ID = formatUUID(id)
+	ks.Crypto = CryptoJSON{
+		Cipher: CipherAES256GCM,
+		Nonce:  hex.EncodeToString(nonce),
+		KDF:    params.KDF,
+		KDFParams: KDFParamsJSON{
+			Salt:    hex.EncodeToString(salt),
+			DKLen:   keyLen,
+			N:       params.N,
+			R:       params.R,
+			P:       params.P,
+			Time:    params.Time,
+			Memory:  params.Memory,
+			Threads: params.Threads,
+		},
+	}
+	header, err := ks.header()
+	if err != nil {
+		return nil, err
+	}
+	ks.Crypto.CipherText = hex.EncodeToString(aead.Seal(nil, nonce, plaintext, header))
+	return ks, nil
+}
+
+// EncryptMnemonic encrypts a BIP-39 mnemonic.
+func EncryptMnemonic(mnemonic, password string, params Params) (*Keystore, error) {
+	if !bip39.ValidateMnemonic(mnemonic) {
+		return nil, bip39.ErrInvalidMnemonic
+	}
+	plaintext := []byte(mnemonic)
+	defer secret.Wipe(plaintext)
+	return Encrypt(plaintext, KindMnemonic, password, params)
+}
+
+// EncryptAccount encrypts an account's private key and records its chain, path
+// and address in the clear so the file can be identified without the password.
+// They are authenticated with the key: editing them makes decryption fail.
+func EncryptAccount(a account.ChainAccount, password string, params Params) (*Keystore, error) {
+	ks := &Keystore{
+		Kind:    KindPrivateKey,
+		Chain:   a.Chain(),
+		Path:    a.Path(),
+		Address: a.Address(),
+	}
+	privateKey := a.PrivateKeyBytes()
+	defer secret.Wipe(privateKey)
+	return encrypt(privateKey, ks, password, params)
+}
+
+// Parse reads a keystore from JSON.
+func Parse(data []byte) (*Keystore, error) {
+	var ks Keystore
+	if err := json.Unmarshal(data, &ks); err != nil {
+		return nil, fmt.Errorf("keystore: %w", err)
+	}
+	if ks.Version != Version {
+		return nil, fmt.Errorf("%w: %d", ErrUnsupportedVersion, ks.Version)
+	}
+	return &ks, nil
+}
+
+// Marshal returns the keystore as indented JSON.
+func (ks *Keystore) Marshal() ([]byte, error) {
+	return json.MarshalIndent(ks, "", "  ")
+}
+
+// Decrypt returns the secret. A wrong password returns ErrDecryptionFailed.
+func (ks *Keystore) Decrypt(password string) ([]byte, error) {
+	if ks.Version != Version {
+		return nil, fmt.Errorf("%w: %d", ErrUnsupportedVersion, ks.Version)
+	}
+	if ks.Crypto.Cipher != CipherAES256GCM {
+		return nil, fmt.Errorf("%w: %s", ErrUnsupportedCipher, ks.Crypto.Cipher)
+	}
+
+	kp := ks.Crypto.KDFParams
+	params := Params{
+		KDF:     ks.Crypto.KDF,
+		N:       kp.N,
+		R:       kp.R,
+		P:       kp.P,
+		Time:    kp.Time,
+		Memory:  kp.Memory,
+		Threads: kp.Threads,
+	}
+	if err := checkParams(params); err != nil {
+		return nil, err
+	}
+	if kp.DKLen != keyLen {
+		return nil, fmt.Errorf("%w: dklen %d", ErrInvalidParams, kp.DKLen)
+	}
+
+	salt, err := hex.DecodeString(kp.Salt)
+	if err != nil || len(salt) == 0 {
+		return nil, fmt.Errorf("%w: salt", ErrInvalidParams)
+	}
+	nonce, err := hex.DecodeString(ks.Crypto.Nonce)
+	if err != nil || len(nonce) != nonceLen {
+		return nil, ErrDecryptionFailed
+	}
+	ciphertext, err := hex.DecodeString(ks.Crypto.CipherText)
+	if err != nil {
+		return nil, ErrDecryptionFailed
+	}
+
+	key, err := deriveKey(password, salt, params)
+	if err != nil {
+		return nil, err
+	}
+	defer secret.Wipe(key)
+
+	aead, err := newAEAD(key)
+	if err != nil {
+		return nil, err
+	}
+	header, err := ks.header()
+	if err != nil {
+		return nil, err
+	}
+
+	plaintext, err := aead.Open(nil, nonce, ciphertext, header)
+	if err != nil {
+		return nil, ErrDecryptionFailed
+	}
+	return plaintext, nil
+}
+
+// DecryptContext is Decrypt for callers that must not wait on the KDF, which
+// takes about a second with the default parameters. The decryption runs in
+// its own goroutine; if ctx is done first DecryptContext returns ctx.Err()
+// at once and the secret is wiped when the decryption finishes.
+func (ks *Keystore) DecryptContext(ctx context.Context, password string) ([]byte, error) {
+	if err := ctx.Err(); err != nil {
+		return nil, err
+	}
+	type result struct {
+		plaintext []byte
+		err       error
+	}
+	done := make(chan result, 1)
+	go func() {
+		plaintext, err := ks.Decrypt(password)
+		done <- result{plaintext, err}
+	}()
+
+	select {
+	case r := <-done:
+		return r.plaintext, r.err
+	case <-ctx.Done():
+		go func() { secret.Wipe((<-done).plaintext) }()
+		return nil, ctx.Err()
+	}
+}
+
+// DecryptMnemonic returns the mnemonic stored in a KindMnemonic keystore.
+func (ks *Keystore) DecryptMnemonic(password string) (string, error) {
+	if ks.Kind != KindMnemonic {
+		return "", fmt.Errorf("%w: %s", ErrWrongKind, ks.Kind)
+	}
+
+	plaintext, err := ks.Decrypt(password)
+	if err != nil {
+		return "", err
+	}
+	defer secret.Wipe(plaintext)
+	return string(plaintext), nil
+}
+
+// DecryptPrivateKey returns the private key stored in a KindPrivateKey keystore.
+func (ks *Keystore) DecryptPrivateKey(password string) ([]byte, error) {
+	if ks.Kind != KindPrivateKey {
+		return nil, fmt.Errorf("%w: %s", ErrWrongKind, ks.Kind)
+	}
+	return ks.Decrypt(password)
+}
+
+// checkParams validates KDF parameters before they are used.
+func checkParams(params Params) error {
+	switch params.KDF {
+	case KDFScrypt:
+		if params.N <= 1 || params.N&(params.N-1) != 0 || params.N > maxScryptN {
+			return fmt.Errorf("%w: scrypt n must be a power of two up to %d", ErrInvalidParams, maxScryptN)
+		}
+		if params.R <= 0 || params.R > maxScryptR || params.P <= 0 || params.P > maxScryptP {
+			return fmt.Errorf("%w: scrypt r must be 1 to %d and p 1 to %d", ErrInvalidParams, maxScryptR, maxScryptP)
+		}
+		if 128*uint64(params.N)*uint64(params.R) > maxScryptMemory {
+			return fmt.Errorf("%w: scrypt needs more than %d MiB", ErrInvalidParams, maxScryptMemory>>20)
+		}
+	case KDFArgon2id:
+		if params.Time == 0 || params.Time > maxArgon2Time {
+			return fmt.Errorf("%w: argon2id time", ErrInvalidParams)
+		}
+		if params.Threads == 0 || params.Memory < 8*uint32(params.Threads) || params.Memory > maxArgon2Memory {
+			return fmt.Errorf("%w: argon2id memory", ErrInvalidParams)
+		}
+	default:
+		return fmt.Errorf("%w: %q", ErrUnsupportedKDF, params.KDF)
+	}
+	return nil
+}
+
+// deriveKey derives the 32-byte encryption key from the password.
+func deriveKey(password string, salt []byte, params Params) ([]byte, error) {
+	if params.KDF == KDFArgon2id {
+		return argon2.IDKey([]byte(password), salt, params.Time, params.Memory, params.Threads, keyLen), nil
+	}
+	return scrypt.Key([]byte(password), salt, params.N, params.R, params.P, keyLen)
+}
+
+// header returns the keystore JSON without the ciphertext, the additional
+// data sealed with the secret. The version, KDF parameters and metadata
+// therefore cannot be changed without failing decryption.
+func (ks *Keystore) header() ([]byte, error) {
+	h := *ks
+	h.Crypto.CipherText = ""
+	return json.Marshal(&h)
+}
+
+func newAEAD(key []byte) (cipher.AEAD, error) {
+	block, err := aes.NewCipher(key)
+	if err != nil {
+		return nil, err
+	}
+	return cipher.NewGCM(block)
+}
+
+// formatUUID formats 16 random bytes as a version 4 UUID.
+func formatUUID(b []byte) string {
+	b[6] = b[6]&0x0f | 0x40
+	b[8] = b[8]&0x3f | 0x80
+	return fmt.Sprintf("%x-%x-%x-%x-%x", b[0:4], b[4:6], b[6:8], b[8:10], b[10:16])
+}
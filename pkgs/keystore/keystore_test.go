@@ -0,0 +1,291 @@
+package keystore
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+// Small argon2id cost so the tests stay fast
+var testArgon2idParams = Params{KDF: KDFArgon2id, Time: 1, Memory: 1024, Threads: 1}
+
+func TestMnemonicRoundTrip(t *testing.T) {
+	for _, params := range []Params{LightScryptParams, testArgon2idParams} {
+		t.Run(string(params.KDF), func(t *testing.T) {
+			ks, err := EncryptMnemonic(testMnemonic, "correct horse", params)
+			if err != nil {
+				t.Fatalf("EncryptMnemonic() error = %v", err)
+			}
+
+			data, err := ks.Marshal()
+			if err != nil {
+				t.Fatalf("Marshal() error = %v", err)
+			}
+			if strings.Contains(string(data), "abandon") {
+				t.Error("keystore JSON contains the mnemonic in the clear")
+			}
+
+			parsed, err := Parse(data)
+			if err != nil {
+				t.Fatalf("Parse() error = %v", err)
+			}
+			if parsed.Crypto.KDF != params.KDF || parsed.Kind != KindMnemonic {
+				t.Errorf("Parse() kdf = %s, kind = %s", parsed.Crypto.KDF, parsed.Kind)
+			}
+
+			mnemonic, err := parsed.DecryptMnemonic("correct horse")
+			if err != nil {
+				t.Fatalf("DecryptMnemonic() error = %v", err)
+			}
+			if mnemonic != testMnemonic {
+				t.Errorf("DecryptMnemonic() = %q, want %q", mnemonic, testMnemonic)
+			}
+
+			if _, err := parsed.DecryptMnemonic("wrong"); !errors.Is(err, ErrDecryptionFailed) {
+				t.Errorf("DecryptMnemonic(wrong) error = %v, want ErrDecryptionFailed", err)
+			}
+		})
+	}
+}
+
+func TestAccountRoundTrip(t *testing.T) {
+	acc, err := account.NewSolanaAccountFromMnemonic(testMnemonic, "", 0)
+	if err != nil {
+		t.Fatalf("NewSolanaAccountFromMnemonic() error = %v", err)
+	}
+
+	ks, err := EncryptAccount(acc, "pw", LightScryptParams)
+	if err != nil {
+		t.Fatalf("EncryptAccount() error = %v", err)
+	}
+	if ks.Chain != acc.Chain() || ks.Path != acc.Path() || ks.Address != acc.Address() {
+		t.Errorf("metadata = %s %s %s", ks.Chain, ks.Path, ks.Address)
+	}
+
+	key, err := ks.DecryptPrivateKey("pw")
+	if err != nil {
+		t.Fatalf("DecryptPrivateKey() error = %v", err)
+	}
+	if !bytes.Equal(key, acc.PrivateKeyBytes()) {
+		t.Error("DecryptPrivateKey() does not match the account key")
+	}
+
+	if _, err := ks.DecryptMnemonic("pw"); !errors.Is(err, ErrWrongKind) {
+		t.Errorf("DecryptMnemonic() error = %v, want ErrWrongKind", err)
+	}
+}
+
+func TestTamperedKeystore(t *testing.T) {
+	ks, err := Encrypt([]byte("secret"), KindPrivateKey, "pw", LightScryptParams)
+	if err != nil {
+		t.Fatalf("Encrypt() error = %v", err)
+	}
+
+	ct := []byte(ks.Crypto.CipherText)
+	if ct[0] == '0' {
+		ct[0] = '1'
+	} else {
+		ct[0] = '0'
+	}
+	ks.Crypto.CipherText = string(ct)
+
+	if _, err := ks.Decrypt("pw"); !errors.Is(err, ErrDecryptionFailed) {
+		t.Errorf("Decrypt() error = %v, want ErrDecryptionFailed", err)
+	}
+
+	// The metadata is authenticated along with the ciphertext
+	acc, _ := account.NewSolanaAccountFromMnemonic(testMnemonic, "", 0)
+	ks, err = EncryptAccount(acc, "pw", LightScryptParams)
+	if err != nil {
+		t.Fatalf("EncryptAccount() error = %v", err)
+	}
+	ks.Address = "11111111111111111111111111111111"
+	if _, err := ks.Decrypt("pw"); !errors.Is(err, ErrDecryptionFailed) {
+		t.Errorf("Decrypt(changed address) error = %v, want ErrDecryptionFailed", err)
+	}
+}
+
+func TestDecryptContext(t *testing.T) {
+	ks, err := EncryptMnemonic(testMnemonic, "pw", LightScryptParams)
+	if err != nil {
+		t.Fatalf("EncryptMnemonic() error = %v", err)
+	}
+
+	plaintext, err := ks.DecryptContext(context.Background(), "pw")
+	if err != nil || string(plaintext) != testMnemonic {
+		t.Errorf("DecryptContext() = %q, %v", plaintext, err)
+	}
+	if _, err := ks.DecryptContext(context.Background(), "wrong"); !errors.Is(err, ErrDecryptionFailed) {
+		t.Errorf("DecryptContext(wrong password) error = %v, want ErrDecryptionFailed", err)
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	cancel()
+	if _, err := ks.DecryptContext(ctx, "pw"); !errors.Is(err, context.Canceled) {
+		t.Errorf("DecryptContext(canceled) error = %v, want context.Canceled", err)
+	}
+}
+
+func TestParseRejectsInvalid(t *testing.T) {
+	if _, err := Parse([]byte(`{"version": 2}`)); !errors.Is(err, ErrUnsupportedVersion) {
+		t.Errorf("Parse(version 2) error = %v, want ErrUnsupportedVersion", err)
+	}
+
+	ks, err := Encrypt([]byte("secret"), KindPrivateKey, "pw", LightScryptParams)
+	if err != nil {
+		t.Fatalf("Encrypt() error = %v", err)
+	}
+	ks.Crypto.KDFParams.N = 1 << 30
+	if _, err := ks.Decrypt("pw"); !errors.Is(err, ErrInvalidParams) {
+		t.Errorf("Decrypt(huge n) error = %v, want ErrInvalidParams", err)
+	}
+	ks.Crypto.KDFParams.N = 1 << 12
+	ks.Crypto.KDFParams.R = 1 << 20
+	if _, err := ks.Decrypt("pw"); !errors.Is(err, ErrInvalidParams) {
+		t.Errorf("Decrypt(huge r) error = %v, want ErrInvalidParams", err)
+	}
+	// Both within their own bounds, but 16 GiB together
+	ks.Crypto.KDFParams.N = 1 << 22
+	ks.Crypto.KDFParams.R = 32
+	if _, err := ks.Decrypt("pw"); !errors.Is(err, ErrInvalidParams) {
+		t.Errorf("Decrypt(16 GiB) error = %v, want ErrInvalidParams", err)
+	}
+
+	if _, err := Encrypt([]byte("secret"), KindPrivateKey, "pw", Params{KDF: "pbkdf2"}); !errors.Is(err, ErrUnsupportedKDF) {
+		t.Errorf("Encrypt(pbkdf2) error = %v, want ErrUnsupportedKDF", err)
+	}
+}
+
+func TestWalletFile(t *testing.T) {
+	eth, _ := account.NewEVMAccountFromMnemonic(testMnemonic, "", address.ChainEthereum, 0)
+	watch, _ := account.NewBitcoinWatchAccountFromXpub(
+		"xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V",
+		account.ScriptP2WPKH, 0)
+
+	f, err := NewWalletFile("savings")
+	if err != nil {
+		t.Fatalf("NewWalletFile() error = %v", err)
+	}
+	f.AddAccount(eth, "main")
+	f.AddAccount(watch, "")
+	if err := f.AddXpub(address.ChainBitcoin, "m/84'/0'/0'", "xpub6CatWdiZiodmUeTDp8LT5or8nmbKNcuyvz7WyksVFkKB4RHwCD3XyuvPEbvqAQY3rAPshWcMLoP2fMFMKHPJ4ZeZXYVUhLv1VMrjPC7PW6V", "cold"); err != nil {
+		t.Fatalf("AddXpub() error = %v", err)
+	}
+	if _, err := f.Mnemonic("pw"); !errors.Is(err, ErrNoSecret) {
+		t.Errorf("Mnemonic() on a watch-only file error = %v, want ErrNoSecret", err)
+	}
+	if err := f.SetMnemonic(testMnemonic, "pw", LightScryptParams); err != nil {
+		t.Fatalf("SetMnemonic() error = %v", err)
+	}
+
+	path := filepath.Join(t.TempDir(), "wallet.json")
+	if err := f.Save(path); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+	loaded, err := LoadWalletFile(path)
+	if err != nil {
+		t.Fatalf("LoadWalletFile() error = %v", err)
+	}
+
+	data, _ := loaded.Marshal()
+	if strings.Contains(string(data), "abandon") || strings.Contains(string(data), "privateKey") {
+		t.Errorf("wallet file contains a secret in the clear:\n%s", data)
+	}
+	if loaded.ID != f.ID || loaded.Name != "savings" || !loaded.CreatedAt.Equal(f.CreatedAt) || len(loaded.Accounts) != 3 {
+		t.Fatalf("LoadWalletFile() = %+v, want %+v", loaded, f)
+	}
+	if got := loaded.Accounts[0]; got.Address != eth.Address() || got.Path != eth.Path() || got.Label != "main" {
+		t.Errorf("Accounts[0] = %+v", got)
+	}
+	if got := loaded.Accounts[1]; got.Address != watch.Address() || got.Chain != address.ChainBitcoin {
+		t.Errorf("Accounts[1] = %+v", got)
+	}
+	if mnemonic, err := loaded.Mnemonic("pw"); err != nil || mnemonic != testMnemonic {
+		t.Errorf("Mnemonic() = %q, %v", mnemonic, err)
+	}
+
+	if _, err := ParseWalletFile([]byte(`{"version":2}`)); !errors.Is(err, ErrUnsupportedVersion) {
+		t.Errorf("ParseWalletFile(version 2) error = %v, want ErrUnsupportedVersion", err)
+	}
+}
+
+func TestWalletFileRejectsInvalidXpub(t *testing.T) {
+	master, err := bip32.NewMasterKey(bytes.Repeat([]byte{1}, 16))
+	if err != nil {
+		t.Fatalf("NewMasterKey() error = %v", err)
+	}
+	neutered, _ := master.Neuter()
+	masterXpub := neutered.(*bip32.ExtendedKey).String()
+
+	f, _ := NewWalletFile("")
+	for name, tt := range map[string][2]string{
+		"garbage":    {"m/84'/0'/0'", "xpub-not-a-key"},
+		"private":    {"m", master.String()},
+		"wrong path": {"m/84'/0'/0'", masterXpub},
+	} {
+		if err := f.AddXpub(address.ChainBitcoin, tt[0], tt[1], ""); !errors.Is(err, ErrInvalidXpub) {
+			t.Errorf("AddXpub(%s) error = %v, want ErrInvalidXpub", name, err)
+		}
+	}
+	if len(f.Accounts) != 0 {
+		t.Errorf("rejected xpubs were recorded: %+v", f.Accounts)
+	}
+
+	if err := f.AddXpub(address.ChainBitcoin, "m", masterXpub, ""); err != nil {
+		t.Fatalf("AddXpub(master xpub at m) error = %v", err)
+	}
+	data, _ := f.Marshal()
+	tampered := strings.Replace(string(data), masterXpub, master.String(), 1)
+	if _, err := ParseWalletFile([]byte(tampered)); !errors.Is(err, ErrInvalidXpub) {
+		t.Errorf("ParseWalletFile(xprv entry) error = %v, want ErrInvalidXpub", err)
+	}
+}
+
+func TestCalibrate(t *testing.T) {
+	for _, kdf := range []KDF{KDFScrypt, KDFArgon2id} {
+		t.Run(string(kdf), func(t *testing.T) {
+			params, err := Calibrate(kdf, 20*time.Millisecond)
+			if err != nil {
+				t.Fatalf("Calibrate() error = %v", err)
+			}
+			if err := checkParams(params); err != nil {
+				t.Fatalf("Calibrate() = %+v: %v", params, err)
+			}
+			if params.N > maxCalibratedScryptN || params.Memory > maxCalibratedArgon2Memory {
+				t.Errorf("Calibrate() = %+v, over the memory cap", params)
+			}
+
+			ks, err := Encrypt([]byte("secret"), KindPrivateKey, "pw", params)
+			if err != nil {
+				t.Fatalf("Encrypt() error = %v", err)
+			}
+			if kp := ks.Crypto.KDFParams; kp.N != params.N || kp.Memory != params.Memory || kp.Time != params.Time {
+				t.Errorf("kdfparams = %+v, want %+v", kp, params)
+			}
+			if plaintext, err := ks.Decrypt("pw"); err != nil || string(plaintext) != "secret" {
+				t.Errorf("Decrypt() = %q, %v", plaintext, err)
+			}
+		})
+	}
+
+	// A target below the cost of the minimum parameters returns the minimum
+	if params, err := Calibrate(KDFScrypt, time.Nanosecond); err != nil || params.N != minCalibratedScryptN {
+		t.Errorf("Calibrate(1ns) = %+v, %v", params, err)
+	}
+	if _, err := Calibrate(KDFScrypt, 0); !errors.Is(err, ErrInvalidParams) {
+		t.Errorf("Calibrate(0) error = %v, want ErrInvalidParams", err)
+	}
+	if _, err := Calibrate("pbkdf2", time.Second); !errors.Is(err, ErrUnsupportedKDF) {
+		t.Errorf("Calibrate(pbkdf2) error = %v, want ErrUnsupportedKDF", err)
+	}
+}
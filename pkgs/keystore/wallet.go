@@ -0,0 +1,167 @@
+package keystore
+
+import (
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"os"
+	"time"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// WalletFileVersion is the wallet file format written by this package.
+const WalletFileVersion = 1
+
+// WalletFile is a versioned JSON description of a multi-chain wallet: its
+// accounts and extended public keys with labels, and optionally the mnemonic
+// encrypted as a Keystore. Without a secret it is a watch-only wallet and
+// safe to sync or back up in the clear.
+type WalletFile struct {
+	Version   int           `json:"version"`
+	ID        string        `json:"id"`
+	Name      string        `json:"name,omitempty"`
+	CreatedAt time.Time     `json:"createdAt"`
+	Accounts  []WalletEntry `json:"accounts"`
+	Secret    *Keystore     `json:"secret,omitempty"`
+}
+
+// WalletEntry is one account or account-level extended public key. Entries
+// with an Xpub describe a whole account; the others a single address.
+type WalletEntry struct {
+	Chain     address.ChainID `json:"chain"`
+	Path      string          `json:"path,omitempty"`
+	Address   string          `json:"address,omitempty"`
+	PublicKey string          `json:"publicKey,omitempty"`
+	Xpub      string          `json:"xpub,omitempty"`
+	Label     string          `json:"label,omitempty"`
+}
+
+// NewWalletFile returns an empty wallet file created now.
+func NewWalletFile(name string) (*WalletFile, error) {
+	id := make([]byte, 16)
+	if _, err := rand.Read(id); err != nil {
+		return nil, err
+	}
+	return &WalletFile{
+		Version:   WalletFileVersion,
+		ID:        formatUUID(id),
+		Name:      name,
+		CreatedAt: time.Now().UTC().Truncate(time.Second),
+		Accounts:  []WalletEntry{},
+	}, nil
+}
+
+// AddAccount records an account's chain, path, address and public key. The
+// private key of a ChainAccount is never written.
+func (f *WalletFile) AddAccount(a account.WatchAccount, label string) {
+	f.Accounts = append(f.Accounts, WalletEntry{
+		Chain:     a.Chain(),
+		Path:      a.Path(),
+		Address:   a.Address(),
+		PublicKey: hex.EncodeToString(a.PublicKeyBytes()),
+		Label:     label,
+	})
+}
+
+// AddXpub records an account-level extended public key at path. It returns
+// ErrInvalidXpub for a malformed key, for a private key, which would put the
+// account's secrets in the clear, or for a key whose depth and child index
+// do not match path.
+func (f *WalletFile) AddXpub(chain address.ChainID, path, xpub, label string) error {
+	if err := checkXpub(path, xpub); err != nil {
+		return err
+	}
+	f.Accounts = append(f.Accounts, WalletEntry{Chain: chain, Path: path, Xpub: xpub, Label: label})
+	return nil
+}
+
+// checkXpub parses xpub and checks that it is a public key at path.
+func checkXpub(path, xpub string) error {
+	key, err := bip32.ParseExtendedKey(xpub)
+	if err != nil {
+		return fmt.Errorf("%w: %v", ErrInvalidXpub, err)
+	}
+	if key.IsPrivate() {
+		key.Wipe()
+		return fmt.Errorf("%w: got a private key, export the xpub instead", ErrInvalidXpub)
+	}
+	if path == "" {
+		return nil
+	}
+	p, err := bip32.ParsePath(path)
+	if err != nil {
+		return fmt.Errorf("%w: %v", ErrInvalidXpub, err)
+	}
+	if len(p) != int(key.Depth()) || (len(p) > 0 && p[len(p)-1] != key.ChildIndex()) {
+		return fmt.Errorf("%w: key at depth %d is not at %s", ErrInvalidXpub, key.Depth(), path)
+	}
+	return nil
+}
+
+// SetMnemonic encrypts mnemonic into the file, replacing any previous secret.
+func (f *WalletFile) SetMnemonic(mnemonic, password string, params Params) error {
+	ks, err := EncryptMnemonic(mnemonic, password, params)
+	if err != nil {
+		return err
+	}
+	f.Secret = ks
+	return nil
+}
+
+// Mnemonic decrypts the stored mnemonic. A watch-only file returns ErrNoSecret.
+func (f *WalletFile) Mnemonic(password string) (string, error) {
+	if f.Secret == nil {
+		return "", ErrNoSecret
+	}
+	return f.Secret.DecryptMnemonic(password)
+}
+
+// Marshal returns the wallet file as indented JSON.
+func (f *WalletFile) Marshal() ([]byte, error) {
+	return json.MarshalIndent(f, "", "  ")
+}
+
+// ParseWalletFile reads a wallet file from JSON.
+func ParseWalletFile(data []byte) (*WalletFile, error) {
+	var f WalletFile
+	if err := json.Unmarshal(data, &f); err != nil {
+		return nil, fmt.Errorf("keystore: %w", err)
+	}
+	if f.Version != WalletFileVersion {
+		return nil, fmt.Errorf("%w: wallet file %d", ErrUnsupportedVersion, f.Version)
+	}
+	if f.Secret != nil && f.Secret.Version != Version {
+		return nil, fmt.Errorf("%w: %d", ErrUnsupportedVersion, f.Secret.Version)
+	}
+	for _, e := range f.Accounts {
+		if e.Xpub == "" {
+			continue
+		}
+		if err := checkXpub(e.Path, e.Xpub); err != nil {
+			return nil, err
+		}
+	}
+	return &f, nil
+}
+
+// Save writes the wallet file to path, readable only by the owner.
+func (f *WalletFile) Save(path string) error {
+	data, err := f.Marshal()
+	if err != nil {
+		return err
+	}
+	return os.WriteFile(path, data, 0600)
+}
+
+// LoadWalletFile reads a wallet file saved with Save.
+func LoadWalletFile(path string) (*WalletFile, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	return ParseWalletFile(data)
+}
@@ -0,0 +1,27 @@
+package qr
+
+import (
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// Address encodes an address at the medium error correction level. Bech32
+// addresses are upper-cased so they fit alphanumeric mode, as BIP-173
+// recommends; other formats are case-sensitive and encoded as given.
+func Address(addr string) (*Code, error) {
+	if isBech32(addr) {
+		addr = strings.ToUpper(addr)
+	}
+	return EncodeText(addr, Medium)
+}
+
+// isBech32 reports whether addr is a SegWit address or other Bech32 string.
+// SegWit data is not byte-aligned, so Bech32Decode alone rejects it.
+func isBech32(addr string) bool {
+	if _, _, _, err := address.SegWitDecode(addr); err == nil {
+		return true
+	}
+	_, _, _, err := address.Bech32Decode(addr)
+	return err == nil
+}
@@ -0,0 +1,21 @@
+// Package qr renders addresses and SeedQR mnemonic backups as QR codes
+// (ISO/IEC 18004) for air-gapped workflows and paper backups.
+package qr
+
+import "errors"
+
+var (
+	// ErrDataTooLong is returned when the data does not fit a version 40 symbol
+	// at the requested error correction level.
+	ErrDataTooLong = errors.New("qr: data too long")
+
+	// ErrInvalidCharacter is returned when text cannot be encoded in the
+	// requested segment mode.
+	ErrInvalidCharacter = errors.New("qr: invalid character for mode")
+
+	// ErrInvalidLevel is returned for an unknown error correction level.
+	ErrInvalidLevel = errors.New("qr: invalid error correction level")
+
+	// ErrInvalidSeedQR is returned when a SeedQR payload cannot be decoded.
+	ErrInvalidSeedQR = errors.New("qr: invalid SeedQR payload")
+)
@@ -0,0 +1,272 @@
+package qr
+
+import (
+	"fmt"
+)
+
+// Level is a QR error correction level.
+type Level int
+
+// Error correction levels, by the share of codewords that can be restored
+const (
+	Low      Level = iota // about 7%
+	Medium                // about 15%
+	Quartile              // about 25%
+	High                  // about 30%
+)
+
+// String returns the level's single-letter name.
+func (l Level) String() string {
+	if l < Low || l > High {
+		return fmt.Sprintf("Level(%d)", int(l))
+	}
+	return [...]string{"L", "M", "Q", "H"}[l]
+}
+
+// formatBits returns the level's 2-bit value in the format information.
+func (l Level) formatBits() int {
+	return [...]int{1, 0, 3, 2}[l]
+}
+
+// Symbol versions
+const (
+	MinVersion = 1
+	MaxVersion = 40
+)
+
+// eccPerBlock and numBlocks give, per level and version, the error correction
+// codewords in each block and the number of blocks (ISO/IEC 18004 table 9).
+// Index 0 is unused.
+var eccPerBlock = [4][MaxVersion + 1]int{
+	{0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30},
+	{0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28},
+	{0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30},
+	{0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30},
+}
+
+var numBlocks = [4][MaxVersion + 1]int{
+	{0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25},
+	{0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49},
+	{0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68},
+	{0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81},
+}
+
+// Code is a QR code symbol: a square matrix of dark and light modules.
+type Code struct {
+	version int
+	level   Level
+	mask    int
+	size    int
+	modules []bool
+
+	// isFunction marks finder, timing, alignment, format and version modules
+	// while the symbol is built; it is dropped afterwards.
+	isFunction []bool
+}
+
+// Version returns the symbol version, 1 to 40.
+func (c *Code) Version() int {
+	return c.version
+}
+
+// Level returns the error correction level.
+func (c *Code) Level() Level {
+	return c.level
+}
+
+// Mask returns the data mask pattern, 0 to 7.
+func (c *Code) Mask() int {
+	return c.mask
+}
+
+// Size returns the width and height in modules, 4*version+17, without the
+// quiet zone.
+func (c *Code) Size() int {
+	return c.size
+}
+
+// Module reports whether the module at column x, row y is dark. Coordinates
+// outside the symbol are light.
+func (c *Code) Module(x, y int) bool {
+	if x < 0 || y < 0 || x >= c.size || y >= c.size {
+		return false
+	}
+	return c.modules[y*c.size+x]
+}
+
+// EncodeText encodes text in the most compact of numeric, alphanumeric and
+// byte mode, in the smallest version that holds it.
+func EncodeText(text string, level Level) (*Code, error) {
+	return EncodeSegments([]Segment{textSegment(text)}, level)
+}
+
+// EncodeBytes encodes binary data in byte mode, in the smallest version that
+// holds it.
+func EncodeBytes(data []byte, level Level) (*Code, error) {
+	return EncodeSegments([]Segment{NewBytesSegment(data)}, level)
+}
+
+// EncodeSegments encodes segments in the smallest version that holds them at
+// the given level. The mask is chosen by the standard penalty rules.
+func EncodeSegments(segs []Segment, level Level) (*Code, error) {
+	if level < Low || level > High {
+		return nil, ErrInvalidLevel
+	}
+
+	version, used := 0, 0
+	for v := MinVersion; v <= MaxVersion; v++ {
+		used = totalBits(segs, v)
+		if used >= 0 && used <= numDataCodewords(v, level)*8 {
+			version = v
+			break
+		}
+	}
+	if version == 0 {
+		return nil, ErrDataTooLong
+	}
+
+	var bb bitBuffer
+	for _, seg := range segs {
+		bb.appendBits(seg.mode.indicator(), 4)
+		bb.appendBits(uint32(seg.chars), seg.mode.countBits(version))
+		bb = append(bb, seg.data...)
+	}
+
+	// Terminator, byte alignment, then alternating pad bytes
+	capacity := numDataCodewords(version, level) * 8
+	bb.appendBits(0, min(4, capacity-len(bb)))
+	bb.appendBits(0, (8-len(bb)%8)%8)
+	for pad := uint32(0xEC); len(bb) < capacity; pad ^= 0xEC ^ 0x11 {
+		bb.appendBits(pad, 8)
+	}
+
+	return newCode(version, level, addECCAndInterleave(bb.bytes(), version, level)), nil
+}
+
+// newCode draws the symbol for a version and its final codewords.
+func newCode(version int, level Level, codewords []byte) *Code {
+	size := version*4 + 17
+	c := &Code{
+		version:    version,
+		level:      level,
+		size:       size,
+		modules:    make([]bool, size*size),
+		isFunction: make([]bool, size*size),
+	}
+	c.drawFunctionPatterns()
+	c.drawCodewords(codewords)
+
+	best, bestPenalty := 0, -1
+	for mask := 0; mask < 8; mask++ {
+		c.applyMask(mask)
+		c.drawFormatBits(mask)
+		if penalty := c.penalty(); bestPenalty < 0 || penalty < bestPenalty {
+			best, bestPenalty = mask, penalty
+		}
+		c.applyMask(mask) // masking is its own inverse
+	}
+	c.mask = best
+	c.applyMask(best)
+	c.drawFormatBits(best)
+	c.isFunction = nil
+	return c
+}
+
+// numRawDataModules returns the modules left for data and error correction
+// once function patterns are placed; some versions leave remainder bits.
+func numRawDataModules(version int) int {
+	result := (16*version+128)*version + 64
+	if version >= 2 {
+		numAlign := version/7 + 2
+		result -= (25*numAlign-10)*numAlign - 55
+		if version >= 7 {
+			result -= 36
+		}
+	}
+	return result
+}
+
+// numDataCodewords returns the 8-bit data codewords a version holds at a level.
+func numDataCodewords(version int, level Level) int {
+	return numRawDataModules(version)/8 - eccPerBlock[level][version]*numBlocks[level][version]
+}
+
+// addECCAndInterleave splits data into blocks, appends each block's
+// Reed-Solomon codewords and interleaves the blocks.
+func addECCAndInterleave(data []byte, version int, level Level) []byte {
+	blockCount := numBlocks[level][version]
+	eccLen := eccPerBlock[level][version]
+	rawCodewords := numRawDataModules(version) / 8
+	numShort := blockCount - rawCodewords%blockCount
+	shortLen := rawCodewords / blockCount
+
+	divisor := reedSolomonDivisor(eccLen)
+	blocks := make([][]byte, blockCount)
+	for i, k := 0, 0; i < blockCount; i++ {
+		n := shortLen - eccLen
+		if i >= numShort {
+			n++
+		}
+		block := append([]byte(nil), data[k:k+n]...)
+		k += n
+		ecc := reedSolomonRemainder(block, divisor)
+		if i < numShort {
+			// Placeholder so every block has the same length; skipped below
+			block = append(block, 0)
+		}
+		blocks[i] = append(block, ecc...)
+	}
+
+	result := make([]byte, 0, rawCodewords)
+	for i := range blocks[0] {
+		for j, block := range blocks {
+			if i != shortLen-eccLen || j >= numShort {
+				result = append(result, block[i])
+			}
+		}
+	}
+	return result
+}
+
+// reedSolomonDivisor returns the generator polynomial of a degree, highest
+// coefficient first with the leading 1 omitted.
+func reedSolomonDivisor(degree int) []byte {
+	result := make([]byte, degree)
+	result[degree-1] = 1
+
+	root := byte(1)
+	for i := 0; i < degree; i++ {
+		for j := range result {
+			result[j] = gfMul(result[j], root)
+			if j+1 < len(result) {
+				result[j] ^= result[j+1]
+			}
+		}
+		root = gfMul(root, 0x02)
+	}
+	return result
+}
+
+// reedSolomonRemainder returns the error correction codewords of data.
+func reedSolomonRemainder(data, divisor []byte) []byte {
+	result := make([]byte, len(divisor))
+	for _, b := range data {
+		factor := b ^ result[0]
+		copy(result, result[1:])
+		result[len(result)-1] = 0
+		for i, coef := range divisor {
+			result[i] ^= gfMul(coef, factor)
+		}
+	}
+	return result
+}
+
+// gfMul multiplies in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1.
+func gfMul(x, y byte) byte {
+	var z int
+	for i := 7; i >= 0; i-- {
+		z = (z << 1) ^ ((z >> 7) * 0x11D)
+		z ^= int(y>>uint(i)&1) * int(x)
+	}
+	return byte(z)
+}
@@ -0,0 +1,294 @@
+package qr
+
+import (
+	"bytes"
+	"encoding/hex"
+	"errors"
+	"image/png"
+	"strings"
+	"testing"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+// readCodewords reads the codewords back out of a symbol by undoing its mask
+// and following the placement zigzag.
+func readCodewords(t *testing.T, c *Code) []byte {
+	t.Helper()
+
+	blank := &Code{
+		version:    c.version,
+		level:      c.level,
+		size:       c.size,
+		modules:    make([]bool, c.size*c.size),
+		isFunction: make([]bool, c.size*c.size),
+	}
+	blank.drawFunctionPatterns()
+
+	// Masking a copy of the symbol with the same pattern removes it
+	unmasked := &Code{size: c.size, modules: append([]bool(nil), c.modules...), isFunction: blank.isFunction}
+	unmasked.applyMask(c.mask)
+
+	var bb bitBuffer
+	for right := c.size - 1; right >= 1; right -= 2 {
+		if right == 6 {
+			right = 5
+		}
+		upward := (right+1)&2 == 0
+		for vert := 0; vert < c.size; vert++ {
+			y := vert
+			if upward {
+				y = c.size - 1 - vert
+			}
+			for j := 0; j < 2; j++ {
+				if x := right - j; !blank.isFunction[y*c.size+x] {
+					bb = append(bb, unmasked.modules[y*c.size+x])
+				}
+			}
+		}
+	}
+	return bb[:len(bb)/8*8].bytes()
+}
+
+func TestEncodeHelloWorld(t *testing.T) {
+	// ISO/IEC 18004 worked example: "HELLO WORLD" at 1-M
+	want, _ := hex.DecodeString("205b0b78d172dc4d4340ec11ec11ec11" + "c4232777ebd7e7e25d17")
+
+	code, err := EncodeText("HELLO WORLD", Medium)
+	if err != nil {
+		t.Fatalf("EncodeText() error = %v", err)
+	}
+	if code.Version() != 1 || code.Size() != 21 {
+		t.Fatalf("EncodeText() version = %d, size = %d, want 1, 21", code.Version(), code.Size())
+	}
+	if got := readCodewords(t, code); !bytes.Equal(got, want) {
+		t.Errorf("codewords = %x, want %x", got, want)
+	}
+}
+
+func TestFormatBits(t *testing.T) {
+	code, err := EncodeText("HELLO WORLD", Medium)
+	if err != nil {
+		t.Fatalf("EncodeText() error = %v", err)
+	}
+
+	// Read both copies of the format information, bit 0 first
+	var first, second int
+	for i := 0; i < 15; i++ {
+		var a, b bool
+		switch {
+		case i <= 5:
+			a = code.Module(8, i)
+		case i <= 7:
+			a = code.Module(8, i+1)
+		case i == 8:
+			a = code.Module(7, 8)
+		default:
+			a = code.Module(14-i, 8)
+		}
+		if i < 8 {
+			b = code.Module(code.Size()-1-i, 8)
+		} else {
+			b = code.Module(8, code.Size()-15+i)
+		}
+		if a {
+			first |= 1 << i
+		}
+		if b {
+			second |= 1 << i
+		}
+	}
+	if first != second {
+		t.Fatalf("format copies differ: %015b, %015b", first, second)
+	}
+
+	// The BCH code is systematic: the top five bits are the level and mask
+	if got := (first ^ 0x5412) >> 10; got != code.Level().formatBits()<<3|code.Mask() {
+		t.Errorf("format data = %05b, want level %s mask %d", got, code.Level(), code.Mask())
+	}
+	if !code.Module(8, code.Size()-8) {
+		t.Error("dark module is light")
+	}
+}
+
+func TestVersions(t *testing.T) {
+	// A larger symbol with several blocks and version information
+	data := bytes.Repeat([]byte("crypto-accounts "), 20)
+	code, err := EncodeBytes(data, Quartile)
+	if err != nil {
+		t.Fatalf("EncodeBytes() error = %v", err)
+	}
+	if code.Version() < 7 {
+		t.Fatalf("EncodeBytes() version = %d, want >= 7", code.Version())
+	}
+	for _, corner := range [][2]int{{0, 0}, {code.Size() - 7, 0}, {0, code.Size() - 7}} {
+		if !code.Module(corner[0], corner[1]) || code.Module(corner[0]+1, corner[1]+1) || !code.Module(corner[0]+3, corner[1]+3) {
+			t.Errorf("no finder pattern at %v", corner)
+		}
+	}
+
+	if _, err := EncodeBytes(make([]byte, 3000), Low); !errors.Is(err, ErrDataTooLong) {
+		t.Errorf("EncodeBytes(3000 bytes) error = %v, want ErrDataTooLong", err)
+	}
+	if _, err := EncodeText("x", Level(7)); !errors.Is(err, ErrInvalidLevel) {
+		t.Errorf("EncodeText(level 7) error = %v, want ErrInvalidLevel", err)
+	}
+}
+
+func TestSegments(t *testing.T) {
+	tests := []struct {
+		text string
+		mode Mode
+	}{
+		{"0123456789", Numeric},
+		{"BC1QCR8TE4KR609GCAWUTMRZA0J4XV80JY8Z306FYU", Alphanumeric},
+		{"bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu", Byte},
+	}
+	for _, tt := range tests {
+		if got := textSegment(tt.text).Mode(); got != tt.mode {
+			t.Errorf("textSegment(%q) mode = %s, want %s", tt.text, got, tt.mode)
+		}
+	}
+
+	if _, err := NewNumericSegment("12a"); !errors.Is(err, ErrInvalidCharacter) {
+		t.Errorf("NewNumericSegment() error = %v, want ErrInvalidCharacter", err)
+	}
+	if _, err := NewAlphanumericSegment("abc"); !errors.Is(err, ErrInvalidCharacter) {
+		t.Errorf("NewAlphanumericSegment() error = %v, want ErrInvalidCharacter", err)
+	}
+}
+
+func TestSeedQR(t *testing.T) {
+	const legal = "legal winner thank year wave sausage worth useful legal winner thank yellow"
+	const legalDigits = "101920151790203919831533203119191019201517902040"
+	const art24 = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon " +
+		"abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art"
+
+	digits, err := SeedQRDigits(legal)
+	if err != nil {
+		t.Fatalf("SeedQRDigits() error = %v", err)
+	}
+	if digits != legalDigits {
+		t.Errorf("SeedQRDigits() = %s, want %s", digits, legalDigits)
+	}
+	if mnemonic, err := ParseSeedQR(digits); err != nil || mnemonic != legal {
+		t.Errorf("ParseSeedQR() = %q, %v", mnemonic, err)
+	}
+
+	entropy, err := CompactSeedQRBytes(legal)
+	if err != nil {
+		t.Fatalf("CompactSeedQRBytes() error = %v", err)
+	}
+	if hex.EncodeToString(entropy) != "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f" {
+		t.Errorf("CompactSeedQRBytes() = %x", entropy)
+	}
+	if mnemonic, err := ParseCompactSeedQR(entropy); err != nil || mnemonic != legal {
+		t.Errorf("ParseCompactSeedQR() = %q, %v", mnemonic, err)
+	}
+
+	sizes := []struct {
+		mnemonic      string
+		standard      int
+		compact       int
+		compactLength int
+	}{
+		{testMnemonic, 25, 21, 16},
+		{art24, 29, 25, 32},
+	}
+	for _, tt := range sizes {
+		standard, err := SeedQR(tt.mnemonic)
+		if err != nil {
+			t.Fatalf("SeedQR() error = %v", err)
+		}
+		compact, err := CompactSeedQR(tt.mnemonic)
+		if err != nil {
+			t.Fatalf("CompactSeedQR() error = %v", err)
+		}
+		if standard.Size() != tt.standard || compact.Size() != tt.compact {
+			t.Errorf("sizes = %d, %d, want %d, %d", standard.Size(), compact.Size(), tt.standard, tt.compact)
+		}
+		if standard.Level() != Low || compact.Level() != Low {
+			t.Errorf("levels = %s, %s, want L", standard.Level(), compact.Level())
+		}
+
+		// Byte mode header: 0100, then the 8-bit length
+		codewords := readCodewords(t, compact)
+		if codewords[0] != 0x40|byte(tt.compactLength>>4) {
+			t.Errorf("compact header = %x", codewords[:2])
+		}
+	}
+
+	if _, err := SeedQR("abandon abandon"); err == nil {
+		t.Error("SeedQR(short) error = nil")
+	}
+	if _, err := ParseSeedQR("00000000000"); !errors.Is(err, ErrInvalidSeedQR) {
+		t.Errorf("ParseSeedQR(odd) error = %v, want ErrInvalidSeedQR", err)
+	}
+	if _, err := ParseSeedQR(strings.Repeat("2048", 12)); !errors.Is(err, ErrInvalidSeedQR) {
+		t.Errorf("ParseSeedQR(index 2048) error = %v, want ErrInvalidSeedQR", err)
+	}
+	if _, err := ParseSeedQR(strings.Repeat("0000", 12)); !errors.Is(err, ErrInvalidSeedQR) {
+		t.Errorf("ParseSeedQR(bad checksum) error = %v, want ErrInvalidSeedQR", err)
+	}
+}
+
+func TestAddress(t *testing.T) {
+	code, err := Address("bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu")
+	if err != nil {
+		t.Fatalf("Address() error = %v", err)
+	}
+	// Alphanumeric mode indicator 0010 leads the first codeword
+	if got := readCodewords(t, code)[0] >> 4; got != 0x2 {
+		t.Errorf("Address(bech32) mode indicator = %04b, want 0010", got)
+	}
+
+	code, err = Address("0x9858EfFD232B4033E47d90003D41EC34EcaEda94")
+	if err != nil {
+		t.Fatalf("Address() error = %v", err)
+	}
+	if got := readCodewords(t, code)[0] >> 4; got != 0x4 {
+		t.Errorf("Address(hex) mode indicator = %04b, want 0100", got)
+	}
+}
+
+func TestUR(t *testing.T) {
+	code, err := UR("ur:crypto-seed/oeadgdstaslplabghydrpfmkbggufgludprfgmaotpiecffltnlpqdenos")
+	if err != nil {
+		t.Fatalf("UR() error = %v", err)
+	}
+	if got := readCodewords(t, code)[0] >> 4; got != 0x2 {
+		t.Errorf("UR() mode indicator = %04b, want 0010", got)
+	}
+}
+
+func TestRender(t *testing.T) {
+	code, err := CompactSeedQR(testMnemonic)
+	if err != nil {
+		t.Fatalf("CompactSeedQR() error = %v", err)
+	}
+
+	data, err := code.PNG(4, DefaultBorder)
+	if err != nil {
+		t.Fatalf("PNG() error = %v", err)
+	}
+	img, err := png.Decode(bytes.NewReader(data))
+	if err != nil {
+		t.Fatalf("png.Decode() error = %v", err)
+	}
+	if width := img.Bounds().Dx(); width != (21+8)*4 {
+		t.Errorf("PNG width = %d, want %d", width, (21+8)*4)
+	}
+	if r, _, _, _ := img.At(4*4, 4*4).RGBA(); r != 0 {
+		t.Error("PNG finder corner is not black")
+	}
+
+	svg := code.SVG(DefaultBorder)
+	if !strings.Contains(svg, `viewBox="0 0 29 29"`) || !strings.Contains(svg, "M4,4h1v1h-1z") {
+		t.Errorf("SVG() = %s", svg)
+	}
+
+	lines := strings.Split(strings.TrimSuffix(code.Text(1), "\n"), "\n")
+	if len(lines) != 12 {
+		t.Errorf("Text() has %d lines, want 12", len(lines))
+	}
+}
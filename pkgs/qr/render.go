@@ -0,0 +1,92 @@
+package qr
+
+import (
+	"bytes"
+	"fmt"
+	"image"
+	"image/color"
+	"image/png"
+	"strings"
+)
+
+// DefaultBorder is the quiet zone the standard requires around a symbol, in modules.
+const DefaultBorder = 4
+
+// Image renders the symbol with scale pixels per module and a light border of
+// border modules on each side.
+func (c *Code) Image(scale, border int) image.Image {
+	scale, border = max(scale, 1), max(border, 0)
+	width := (c.size + 2*border) * scale
+
+	img := image.NewPaletted(image.Rect(0, 0, width, width), color.Palette{color.White, color.Black})
+	for y := 0; y < width; y++ {
+		for x := 0; x < width; x++ {
+			if c.Module(x/scale-border, y/scale-border) {
+				img.SetColorIndex(x, y, 1)
+			}
+		}
+	}
+	return img
+}
+
+// PNG renders the symbol as a two-colour PNG image.
+func (c *Code) PNG(scale, border int) ([]byte, error) {
+	var buf bytes.Buffer
+	if err := png.Encode(&buf, c.Image(scale, border)); err != nil {
+		return nil, err
+	}
+	return buf.Bytes(), nil
+}
+
+// SVG renders the symbol as an SVG document with one unit per module, so it
+// scales to any print size.
+func (c *Code) SVG(border int) string {
+	border = max(border, 0)
+	width := c.size + 2*border
+
+	var path strings.Builder
+	for y := 0; y < c.size; y++ {
+		for x := 0; x < c.size; x++ {
+			if c.Module(x, y) {
+				fmt.Fprintf(&path, "M%d,%dh1v1h-1z", x+border, y+border)
+			}
+		}
+	}
+
+	var sb strings.Builder
+	sb.WriteString(`<?xml version="1.0" encoding="UTF-8"?>` + "\n")
+	fmt.Fprintf(&sb, `<svg xmlns="http://www.w3.org/2000/svg" version="1.1" viewBox="0 0 %d %d" stroke="none" shape-rendering="crispEdges">`+"\n", width, width)
+	sb.WriteString(`<rect width="100%" height="100%" fill="#FFFFFF"/>` + "\n")
+	fmt.Fprintf(&sb, `<path d="%s" fill="#000000"/>`+"\n", path.String())
+	sb.WriteString("</svg>\n")
+	return sb.String()
+}
+
+// Text renders the symbol for a terminal, two module rows per line with
+// Unicode half blocks. Dark modules are drawn as blank space, so the output
+// scans when printed in a light font on a dark background.
+func (c *Code) Text(border int) string {
+	border = max(border, 0)
+
+	var sb strings.Builder
+	for y := -border; y < c.size+border; y += 2 {
+		for x := -border; x < c.size+border; x++ {
+			top, bottom := !c.Module(x, y), !c.Module(x, y+1)
+			if y+1 >= c.size+border {
+				bottom = false
+			}
+			switch {
+			case top && bottom:
+				sb.WriteString("█")
+			case top:
+				sb.WriteString("▀")
+			case bottom:
+				sb.WriteString("▄")
+			default:
+				sb.WriteString(" ")
+			}
+		}
+		sb.WriteByte('\n')
+	}
+	return sb.String()
+}
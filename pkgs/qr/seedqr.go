@@ -0,0 +1,92 @@
+package qr
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+// SeedQR is the SeedSigner format for scanning a BIP-39 English mnemonic into
+// an air-gapped signer. Standard SeedQR encodes each word's index as four
+// decimal digits in numeric mode; CompactSeedQR encodes the raw entropy in
+// byte mode. Both use the low error correction level, giving 25x25 and 29x29
+// standard or 21x21 and 25x25 compact symbols for 12 and 24 words.
+
+// SeedQRDigits returns the Standard SeedQR payload of a mnemonic.
+func SeedQRDigits(mnemonic string) (string, error) {
+	if _, err := bip39.MnemonicToEntropy(mnemonic); err != nil {
+		return "", err
+	}
+
+	var sb strings.Builder
+	for _, word := range strings.Fields(mnemonic) {
+		fmt.Fprintf(&sb, "%04d", bip39.DefaultWordList.WordIndex(word))
+	}
+	return sb.String(), nil
+}
+
+// CompactSeedQRBytes returns the CompactSeedQR payload of a mnemonic: its
+// entropy without the checksum bits.
+func CompactSeedQRBytes(mnemonic string) ([]byte, error) {
+	return bip39.MnemonicToEntropy(mnemonic)
+}
+
+// SeedQR encodes a mnemonic as a Standard SeedQR symbol.
+func SeedQR(mnemonic string) (*Code, error) {
+	digits, err := SeedQRDigits(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	seg, err := NewNumericSegment(digits)
+	if err != nil {
+		return nil, err
+	}
+	return EncodeSegments([]Segment{seg}, Low)
+}
+
+// CompactSeedQR encodes a mnemonic as a CompactSeedQR symbol.
+func CompactSeedQR(mnemonic string) (*Code, error) {
+	entropy, err := CompactSeedQRBytes(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	return EncodeBytes(entropy, Low)
+}
+
+// ParseSeedQR returns the mnemonic of a scanned Standard SeedQR payload.
+func ParseSeedQR(digits string) (string, error) {
+	if len(digits)%4 != 0 {
+		return "", fmt.Errorf("%w: %d digits is not a whole number of words", ErrInvalidSeedQR, len(digits))
+	}
+
+	words := make([]string, 0, len(digits)/4)
+	for i := 0; i < len(digits); i += 4 {
+		index := 0
+		for _, c := range []byte(digits[i : i+4]) {
+			if c < '0' || c > '9' {
+				return "", fmt.Errorf("%w: %q is not a digit", ErrInvalidSeedQR, c)
+			}
+			index = index*10 + int(c-'0')
+		}
+		if index >= bip39.DefaultWordList.Size() {
+			return "", fmt.Errorf("%w: word index %d", ErrInvalidSeedQR, index)
+		}
+		words = append(words, bip39.DefaultWordList.WordAt(index))
+	}
+
+	mnemonic := strings.Join(words, " ")
+	if _, err := bip39.MnemonicToEntropy(mnemonic); err != nil {
+		return "", fmt.Errorf("%w: %w", ErrInvalidSeedQR, err)
+	}
+	return mnemonic, nil
+}
+
+// ParseCompactSeedQR returns the mnemonic of a scanned CompactSeedQR payload.
+func ParseCompactSeedQR(entropy []byte) (string, error) {
+	mnemonic, err := bip39.NewMnemonic(entropy)
+	if err != nil {
+		return "", fmt.Errorf("%w: %w", ErrInvalidSeedQR, err)
+	}
+	return mnemonic, nil
+}
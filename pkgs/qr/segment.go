@@ -0,0 +1,161 @@
+package qr
+
+import (
+	"fmt"
+	"strings"
+)
+
+// Mode is the encoding mode of a QR segment.
+type Mode int
+
+// Segment modes, from most to least compact
+const (
+	Numeric      Mode = iota // digits 0-9, 10 bits per 3 digits
+	Alphanumeric             // 0-9, A-Z and " $%*+-./:", 11 bits per 2 characters
+	Byte                     // arbitrary bytes, 8 bits each
+)
+
+// alphanumericCharset is the 45-character set of alphanumeric mode.
+const alphanumericCharset = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:"
+
+// String returns the mode name.
+func (m Mode) String() string {
+	switch m {
+	case Numeric:
+		return "numeric"
+	case Alphanumeric:
+		return "alphanumeric"
+	case Byte:
+		return "byte"
+	default:
+		return fmt.Sprintf("Mode(%d)", int(m))
+	}
+}
+
+// indicator returns the 4-bit mode indicator.
+func (m Mode) indicator() uint32 {
+	return [...]uint32{0x1, 0x2, 0x4}[m]
+}
+
+// countBits returns the width of the character count field for a version.
+func (m Mode) countBits(version int) int {
+	widths := [...][3]int{
+		Numeric:      {10, 12, 14},
+		Alphanumeric: {9, 11, 13},
+		Byte:         {8, 16, 16},
+	}
+	switch {
+	case version <= 9:
+		return widths[m][0]
+	case version <= 26:
+		return widths[m][1]
+	default:
+		return widths[m][2]
+	}
+}
+
+// Segment is a run of data encoded in a single mode.
+type Segment struct {
+	mode  Mode
+	chars int
+	data  bitBuffer
+}
+
+// Mode returns the segment's encoding mode.
+func (s Segment) Mode() Mode {
+	return s.mode
+}
+
+// NewNumericSegment encodes a string of decimal digits.
+func NewNumericSegment(digits string) (Segment, error) {
+	var bb bitBuffer
+	for i := 0; i < len(digits); i += 3 {
+		group := digits[i:min(i+3, len(digits))]
+		var value uint32
+		for _, c := range []byte(group) {
+			if c < '0' || c > '9' {
+				return Segment{}, fmt.Errorf("%w: %q in numeric segment", ErrInvalidCharacter, c)
+			}
+			value = value*10 + uint32(c-'0')
+		}
+		bb.appendBits(value, len(group)*3+1)
+	}
+	return Segment{mode: Numeric, chars: len(digits), data: bb}, nil
+}
+
+// NewAlphanumericSegment encodes text in the 45-character alphanumeric set.
+// Lowercase letters are not part of the set.
+func NewAlphanumericSegment(text string) (Segment, error) {
+	var bb bitBuffer
+	for i := 0; i < len(text); i += 2 {
+		first := strings.IndexByte(alphanumericCharset, text[i])
+		if first < 0 {
+			return Segment{}, fmt.Errorf("%w: %q in alphanumeric segment", ErrInvalidCharacter, text[i])
+		}
+		if i+1 == len(text) {
+			bb.appendBits(uint32(first), 6)
+			break
+		}
+		second := strings.IndexByte(alphanumericCharset, text[i+1])
+		if second < 0 {
+			return Segment{}, fmt.Errorf("%w: %q in alphanumeric segment", ErrInvalidCharacter, text[i+1])
+		}
+		bb.appendBits(uint32(first*45+second), 11)
+	}
+	return Segment{mode: Alphanumeric, chars: len(text), data: bb}, nil
+}
+
+// NewBytesSegment encodes arbitrary bytes.
+func NewBytesSegment(data []byte) Segment {
+	var bb bitBuffer
+	for _, b := range data {
+		bb.appendBits(uint32(b), 8)
+	}
+	return Segment{mode: Byte, chars: len(data), data: bb}
+}
+
+// textSegment encodes text in the most compact single mode that can hold it.
+func textSegment(text string) Segment {
+	if seg, err := NewNumericSegment(text); err == nil {
+		return seg
+	}
+	if seg, err := NewAlphanumericSegment(text); err == nil {
+		return seg
+	}
+	return NewBytesSegment([]byte(text))
+}
+
+// totalBits returns the number of bits the segments take at a version, or -1
+// if a character count does not fit its field.
+func totalBits(segs []Segment, version int) int {
+	total := 0
+	for _, seg := range segs {
+		countBits := seg.mode.countBits(version)
+		if seg.chars >= 1<<countBits {
+			return -1
+		}
+		total += 4 + countBits + len(seg.data)
+	}
+	return total
+}
+
+// bitBuffer is a sequence of bits, most significant first.
+type bitBuffer []bool
+
+// appendBits appends the low n bits of value.
+func (bb *bitBuffer) appendBits(value uint32, n int) {
+	for i := n - 1; i >= 0; i-- {
+		*bb = append(*bb, value>>uint(i)&1 != 0)
+	}
+}
+
+// bytes packs the buffer into bytes; its length must be a multiple of 8.
+func (bb bitBuffer) bytes() []byte {
+	out := make([]byte, len(bb)/8)
+	for i, bit := range bb {
+		if bit {
+			out[i/8] |= 1 << (7 - uint(i%8))
+		}
+	}
+	return out
+}
@@ -0,0 +1,11 @@
+package qr
+
+import "strings"
+
+// UR encodes one part of a Uniform Resource (see the ur package) at the low
+// error correction level, as air-gapped signers expect. URs are
+// case-insensitive, so the part is upper-cased to fit alphanumeric mode.
+// Showing the parts of a ur.Encoder in turn makes an animated QR code.
+func UR(part string) (*Code, error) {
+	return EncodeText(strings.ToUpper(part), Low)
+}
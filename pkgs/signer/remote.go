@@ -0,0 +1,162 @@
+package signer
+
+import (
+	"context"
+	"crypto/ed25519"
+	"crypto/x509"
+	"errors"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+// ErrRemoteSignature is returned when a remote backend's signature does not
+// verify against its public key, e.g. because the backend is configured with
+// a different key or signed with a different digest algorithm.
+var ErrRemoteSignature = errors.New("remote signature does not verify")
+
+// RemoteBackend is a key held by a KMS or HSM (AWS KMS, GCP KMS, PKCS#11).
+// Adapters implement it on top of the provider's SDK; this package only
+// normalizes what they return.
+type RemoteBackend interface {
+	// PublicKey returns the key's public key, either raw in the scheme's
+	// encoding or as a DER SubjectPublicKeyInfo as KMS APIs return it.
+	PublicKey(ctx context.Context) ([]byte, error)
+
+	// Sign signs a message as Signer.Sign defines it (a 32-byte digest for
+	// secp256k1) and returns the signature: DER or compact r || s for ECDSA,
+	// 64 bytes for Schnorr and Ed25519. For ECDSA the backend must sign the
+	// digest as given, without hashing it again.
+	Sign(ctx context.Context, message []byte) ([]byte, error)
+}
+
+// Remote is a Signer backed by a RemoteBackend, so addresses can be derived
+// locally from the public key while signing happens in a KMS or HSM.
+//
+// Remote implements RecoverableSigner: ECDSA signatures are normalized to low
+// S and the recovery id is found by trial recovery against the public key.
+// Sign and SignRecoverable use context.Background; use the Context variants to
+// bound network calls.
+type Remote struct {
+	scheme    Scheme
+	backend   RemoteBackend
+	publicKey []byte
+}
+
+var _ RecoverableSigner = (*Remote)(nil)
+
+// NewRemote fetches the backend's public key and returns a signer for it.
+func NewRemote(ctx context.Context, scheme Scheme, backend RemoteBackend) (*Remote, error) {
+	raw, err := backend.PublicKey(ctx)
+	if err != nil {
+		return nil, fmt.Errorf("remote public key: %w", err)
+	}
+	publicKey, err := remotePublicKey(scheme, raw)
+	if err != nil {
+		return nil, err
+	}
+	return &Remote{scheme: scheme, backend: backend, publicKey: publicKey}, nil
+}
+
+// remotePublicKey converts a backend public key into the scheme's canonical encoding.
+func remotePublicKey(scheme Scheme, raw []byte) ([]byte, error) {
+	switch scheme {
+	case SchemeSecp256k1ECDSA:
+		if point, err := secp256k1.ParsePublicKey(raw); err == nil {
+			return secp256k1.CompressPoint(point), nil
+		}
+		return secp256k1.ParsePKIXPublicKey(raw)
+	case SchemeSecp256k1Schnorr:
+		if len(raw) != 32 {
+			return nil, secp256k1.ErrInvalidPublicKey
+		}
+		return append([]byte(nil), raw...), nil
+	case SchemeEd25519:
+		if len(raw) == ed25519.PublicKeySize {
+			return append([]byte(nil), raw...), nil
+		}
+		key, err := x509.ParsePKIXPublicKey(raw)
+		if publicKey, ok := key.(ed25519.PublicKey); err == nil && ok {
+			return publicKey, nil
+		}
+		return nil, errors.New("invalid Ed25519 public key")
+	default:
+		return nil, fmt.Errorf("%w: %s", ErrUnsupportedScheme, scheme)
+	}
+}
+
+// Scheme returns the signature algorithm.
+func (r *Remote) Scheme() Scheme {
+	return r.scheme
+}
+
+// PublicKey returns the public key in the scheme's canonical encoding.
+func (r *Remote) PublicKey() []byte {
+	return r.publicKey
+}
+
+// Sign calls SignContext with context.Background.
+func (r *Remote) Sign(message []byte) ([]byte, error) {
+	return r.SignContext(context.Background(), message)
+}
+
+// SignContext signs message with the backend and returns the signature in
+// the same form as the in-memory signers.
+func (r *Remote) SignContext(ctx context.Context, message []byte) ([]byte, error) {
+	if r.scheme == SchemeSecp256k1ECDSA {
+		sig, _, err := r.SignRecoverableContext(ctx, message)
+		return sig, err
+	}
+	sig, err := r.backend.Sign(ctx, message)
+	if err != nil {
+		return nil, fmt.Errorf("remote sign: %w", err)
+	}
+	if !r.Verify(message, sig) {
+		return nil, ErrRemoteSignature
+	}
+	return sig, nil
+}
+
+// SignRecoverable calls SignRecoverableContext with context.Background.
+func (r *Remote) SignRecoverable(hash []byte) ([]byte, byte, error) {
+	return r.SignRecoverableContext(context.Background(), hash)
+}
+
+// SignRecoverableContext signs a 32-byte digest and returns the low-S compact
+// signature with its recovery id. Only ECDSA backends support it.
+func (r *Remote) SignRecoverableContext(ctx context.Context, hash []byte) ([]byte, byte, error) {
+	if r.scheme != SchemeSecp256k1ECDSA {
+		return nil, 0, fmt.Errorf("%w: recovery needs %s, have %s", ErrSchemeMismatch, SchemeSecp256k1ECDSA, r.scheme)
+	}
+	if len(hash) != 32 {
+		return nil, 0, secp256k1.ErrInvalidHash
+	}
+	raw, err := r.backend.Sign(ctx, hash)
+	if err != nil {
+		return nil, 0, fmt.Errorf("remote sign: %w", err)
+	}
+
+	// Try DER first: a random r || s practically never forms a valid
+	// 64-byte SEQUENCE of two INTEGERs.
+	sig, err := secp256k1.ParseDERSignature(raw)
+	if err != nil && len(raw) == secp256k1.ECDSASignatureLen {
+		sig, err = raw, nil
+	}
+	if err != nil {
+		return nil, 0, fmt.Errorf("remote sign: %w", err)
+	}
+	sig, _, err = secp256k1.NormalizeSignature(sig)
+	if err != nil {
+		return nil, 0, err
+	}
+	recoveryID, err := secp256k1.RecoveryID(r.publicKey, hash, sig)
+	if err != nil {
+		return nil, 0, ErrRemoteSignature
+	}
+	return sig, recoveryID, nil
+}
+
+// Verify checks a signature against the backend's public key locally.
+func (r *Remote) Verify(message, signature []byte) bool {
+	return Verify(r.scheme, r.publicKey, message, signature)
+}
@@ -0,0 +1,93 @@
+package signer
+
+import (
+	"bytes"
+	"context"
+	"crypto/ed25519"
+	"crypto/sha256"
+	"crypto/x509"
+	"errors"
+	"math/big"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+// kmsBackend imitates a cloud KMS: the public key comes back as a
+// SubjectPublicKeyInfo and signatures as DER, with S left high.
+type kmsBackend struct {
+	local *Secp256k1ECDSA
+}
+
+func (b *kmsBackend) PublicKey(context.Context) ([]byte, error) {
+	return secp256k1.MarshalPKIXPublicKey(b.local.PublicKey())
+}
+
+func (b *kmsBackend) Sign(_ context.Context, hash []byte) ([]byte, error) {
+	sig, err := b.local.Sign(hash)
+	if err != nil {
+		return nil, err
+	}
+	s := new(big.Int).SetBytes(sig[32:])
+	s.Sub(secp256k1.N, s)
+	return secp256k1.SerializeDERSignature(append(sig[:32:32], s.FillBytes(make([]byte, 32))...))
+}
+
+// hsmBackend returns an Ed25519 key as a SubjectPublicKeyInfo.
+type hsmBackend struct {
+	key ed25519.PrivateKey
+}
+
+func (b *hsmBackend) PublicKey(context.Context) ([]byte, error) {
+	return x509.MarshalPKIXPublicKey(b.key.Public())
+}
+
+func (b *hsmBackend) Sign(_ context.Context, message []byte) ([]byte, error) {
+	return ed25519.Sign(b.key, message), nil
+}
+
+func TestRemote(t *testing.T) {
+	ctx := context.Background()
+	local, _ := NewSecp256k1ECDSA(bytes.Repeat([]byte{0x42}, 32))
+	digest := sha256.Sum256([]byte("hello"))
+
+	remote, err := NewRemote(ctx, SchemeSecp256k1ECDSA, &kmsBackend{local: local})
+	if err != nil {
+		t.Fatalf("NewRemote() error = %v", err)
+	}
+	if !bytes.Equal(remote.PublicKey(), local.PublicKey()) {
+		t.Errorf("PublicKey() = %x, want %x", remote.PublicKey(), local.PublicKey())
+	}
+
+	sig, recoveryID, err := remote.SignRecoverableContext(ctx, digest[:])
+	if err != nil {
+		t.Fatalf("SignRecoverableContext() error = %v", err)
+	}
+	wantSig, wantID, _ := local.SignRecoverable(digest[:])
+	if !bytes.Equal(sig, wantSig) || recoveryID != wantID {
+		t.Errorf("SignRecoverableContext() = %x, %d, want the low-S %x, %d", sig, recoveryID, wantSig, wantID)
+	}
+
+	other, _ := NewSecp256k1ECDSA(bytes.Repeat([]byte{0x43}, 32))
+	remote.backend = &kmsBackend{local: other}
+	if _, err := remote.Sign(digest[:]); !errors.Is(err, ErrRemoteSignature) {
+		t.Errorf("Sign() with the wrong backend key error = %v, want ErrRemoteSignature", err)
+	}
+
+	seed := bytes.Repeat([]byte{0x07}, 32)
+	edRemote, err := NewRemote(ctx, SchemeEd25519, &hsmBackend{key: ed25519.NewKeyFromSeed(seed)})
+	if err != nil {
+		t.Fatalf("NewRemote(ed25519) error = %v", err)
+	}
+	edLocal, _ := NewEd25519(seed)
+	if !bytes.Equal(edRemote.PublicKey(), edLocal.PublicKey()) {
+		t.Errorf("ed25519 PublicKey() = %x, want %x", edRemote.PublicKey(), edLocal.PublicKey())
+	}
+	edSig, err := edRemote.Sign([]byte("hello"))
+	if err != nil || !edLocal.Verify([]byte("hello"), edSig) {
+		t.Errorf("ed25519 Sign() = %x, %v", edSig, err)
+	}
+	if _, _, err := edRemote.SignRecoverable(digest[:]); !errors.Is(err, ErrSchemeMismatch) {
+		t.Errorf("ed25519 SignRecoverable() error = %v, want ErrSchemeMismatch", err)
+	}
+}
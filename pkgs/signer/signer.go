@@ -0,0 +1,326 @@
+// Package signer defines a curve-agnostic Signer interface with in-memory
+// secp256k1 ECDSA, secp256k1 Schnorr (BIP-340) and Ed25519 implementations.
+//
+// Chain code signs through a Signer rather than raw private key bytes, so a
+// remote or hardware signer can be substituted without changing chain logic.
+package signer
+
+import (
+	"crypto/rand"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"io"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// Scheme identifies a signature algorithm.
+type Scheme string
+
+// Supported signature schemes
+const (
+	SchemeSecp256k1ECDSA   Scheme = "secp256k1-ecdsa"
+	SchemeSecp256k1Schnorr Scheme = "secp256k1-schnorr"
+	SchemeEd25519          Scheme = "ed25519"
+)
+
+var (
+	// ErrUnsupportedScheme is returned for an unknown signature scheme.
+	ErrUnsupportedScheme = errors.New("unsupported signature scheme")
+
+	// ErrSchemeMismatch is returned when a signer of the wrong scheme is used.
+	ErrSchemeMismatch = errors.New("signer scheme mismatch")
+)
+
+// Signer signs messages with a key it holds or controls.
+//
+// What "message" means depends on the scheme: ECDSA and Schnorr signers sign a
+// 32-byte digest (hashing is chain-specific and done by the caller), while
+// Ed25519 signers sign the message itself.
+type Signer interface {
+	// Scheme returns the signature algorithm.
+	Scheme() Scheme
+
+	// PublicKey returns the public key in the scheme's canonical encoding:
+	// 33-byte compressed for ECDSA, 32-byte x-only for Schnorr, 32 bytes for Ed25519.
+	PublicKey() []byte
+
+	// Sign signs a message and returns the signature: 64-byte r || s for
+	// ECDSA, 64-byte BIP-340 for Schnorr, 64 bytes for Ed25519.
+	Sign(message []byte) ([]byte, error)
+
+	// Verify checks a signature of message against the signer's public key.
+	Verify(message, signature []byte) bool
+}
+
+// RecoverableSigner is an ECDSA signer that also returns the recovery id,
+// as needed by Ethereum (v) and Bitcoin signed messages.
+type RecoverableSigner interface {
+	Signer
+
+	// SignRecoverable signs a 32-byte digest and returns the compact signature
+	// with its recovery id (0-3).
+	SignRecoverable(hash []byte) ([]byte, byte, error)
+}
+
+// Verify checks a signature for any scheme without constructing a signer.
+func Verify(scheme Scheme, publicKey, message, signature []byte) bool {
+	switch scheme {
+	case SchemeSecp256k1ECDSA:
+		return secp256k1.VerifyECDSA(publicKey, message, signature)
+	case SchemeSecp256k1Schnorr:
+		return secp256k1.SchnorrVerify(publicKey, message, signature)
+	case SchemeEd25519:
+		return ed25519.Verify(publicKey, message, signature)
+	default:
+		return false
+	}
+}
+
+// New returns an in-memory signer for a private key of the given scheme.
+func New(scheme Scheme, privateKey []byte) (Signer, error) {
+	var s Signer
+	var err error
+	switch scheme {
+	case SchemeSecp256k1ECDSA:
+		s, err = NewSecp256k1ECDSA(privateKey)
+	case SchemeSecp256k1Schnorr:
+		s, err = NewSecp256k1Schnorr(privateKey)
+	case SchemeEd25519:
+		s, err = NewEd25519(privateKey)
+	default:
+		return nil, fmt.Errorf("%w: %s", ErrUnsupportedScheme, scheme)
+	}
+	if err != nil {
+		return nil, err
+	}
+	return s, nil
+}
+
+// Secp256k1ECDSA signs with ECDSA over secp256k1 using RFC 6979 nonces and low-S signatures.
+type Secp256k1ECDSA struct {
+	privateKey *secret.Bytes
+	publicKey  []byte
+}
+
+// NewSecp256k1ECDSA creates an ECDSA signer from a 32-byte private key.
+func NewSecp256k1ECDSA(privateKey []byte) (*Secp256k1ECDSA, error) {
+	if len(privateKey) != 32 || !secp256k1.IsValidPrivateKey(privateKey) {
+		return nil, secp256k1.ErrInvalidPrivateKey
+	}
+	return &Secp256k1ECDSA{
+		privateKey: secret.Copy(privateKey),
+		publicKey:  secp256k1.PrivateKeyToCompressedPublicKey(privateKey),
+	}, nil
+}
+
+// Scheme returns SchemeSecp256k1ECDSA.
+func (s *Secp256k1ECDSA) Scheme() Scheme {
+	return SchemeSecp256k1ECDSA
+}
+
+// PublicKey returns the 33-byte compressed public key.
+func (s *Secp256k1ECDSA) PublicKey() []byte {
+	return s.publicKey
+}
+
+// Sign signs a 32-byte digest and returns the 64-byte r || s signature.
+func (s *Secp256k1ECDSA) Sign(hash []byte) ([]byte, error) {
+	sig, _, err := s.SignRecoverable(hash)
+	return sig, err
+}
+
+// SignRecoverable signs a 32-byte digest and returns the signature and recovery id.
+func (s *Secp256k1ECDSA) SignRecoverable(hash []byte) (sig []byte, recoveryID byte, err error) {
+	s.privateKey.Use(func(key []byte) { sig, recoveryID, err = secp256k1.SignECDSA(key, hash) })
+	return sig, recoveryID, err
+}
+
+// SignWithEntropy signs a 32-byte digest with 32 bytes of extra entropy
+// mixed into the RFC 6979 nonce, and returns the signature and recovery id.
+func (s *Secp256k1ECDSA) SignWithEntropy(hash, extraEntropy []byte) (sig []byte, recoveryID byte, err error) {
+	s.privateKey.Use(func(key []byte) { sig, recoveryID, err = secp256k1.SignECDSAWithEntropy(key, hash, extraEntropy) })
+	return sig, recoveryID, err
+}
+
+// IsDeterministic reports whether signature is the signer's RFC 6979
+// signature of a 32-byte digest with the given extra entropy, or none.
+func (s *Secp256k1ECDSA) IsDeterministic(hash, signature, extraEntropy []byte) (ok bool) {
+	s.privateKey.Use(func(key []byte) { ok = secp256k1.IsDeterministicECDSA(key, hash, signature, extraEntropy) })
+	return ok
+}
+
+// Verify checks an r || s signature of a 32-byte digest.
+func (s *Secp256k1ECDSA) Verify(hash, signature []byte) bool {
+	return secp256k1.VerifyECDSA(s.publicKey, hash, signature)
+}
+
+// Destroy wipes the private key. Signing fails afterwards.
+func (s *Secp256k1ECDSA) Destroy() {
+	s.privateKey.Destroy()
+}
+
+// String returns the scheme and hex public key; the private key is never printed.
+func (s *Secp256k1ECDSA) String() string {
+	return signerString(s)
+}
+
+// GoString keeps %#v from printing the private key.
+func (s *Secp256k1ECDSA) GoString() string {
+	return "&signer.Secp256k1ECDSA{" + signerString(s) + "}"
+}
+
+// Format implements fmt.Formatter so that every verb prints String, or
+// GoString for %#v.
+func (s *Secp256k1ECDSA) Format(f fmt.State, verb rune) {
+	formatSigner(f, verb, s)
+}
+
+// Secp256k1Schnorr signs with BIP-340 Schnorr signatures and fresh auxiliary randomness.
+type Secp256k1Schnorr struct {
+	privateKey *secret.Bytes
+	publicKey  []byte
+}
+
+// NewSecp256k1Schnorr creates a BIP-340 signer from a 32-byte private key.
+func NewSecp256k1Schnorr(privateKey []byte) (*Secp256k1Schnorr, error) {
+	if len(privateKey) != 32 {
+		return nil, secp256k1.ErrInvalidPrivateKey
+	}
+	publicKey, err := secp256k1.XOnlyPublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+	return &Secp256k1Schnorr{
+		privateKey: secret.Copy(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// Scheme returns SchemeSecp256k1Schnorr.
+func (s *Secp256k1Schnorr) Scheme() Scheme {
+	return SchemeSecp256k1Schnorr
+}
+
+// PublicKey returns the 32-byte x-only public key.
+func (s *Secp256k1Schnorr) PublicKey() []byte {
+	return s.publicKey
+}
+
+// Sign signs a 32-byte message and returns the 64-byte BIP-340 signature.
+func (s *Secp256k1Schnorr) Sign(message []byte) ([]byte, error) {
+	auxRand := make([]byte, 32)
+	if _, err := rand.Read(auxRand); err != nil {
+		return nil, err
+	}
+	var sig []byte
+	var err error
+	s.privateKey.Use(func(key []byte) { sig, err = secp256k1.SchnorrSign(key, message, auxRand) })
+	return sig, err
+}
+
+// Verify checks a BIP-340 signature.
+func (s *Secp256k1Schnorr) Verify(message, signature []byte) bool {
+	return secp256k1.SchnorrVerify(s.publicKey, message, signature)
+}
+
+// Destroy wipes the private key. Signing fails afterwards.
+func (s *Secp256k1Schnorr) Destroy() {
+	s.privateKey.Destroy()
+}
+
+// String returns the scheme and hex public key; the private key is never printed.
+func (s *Secp256k1Schnorr) String() string {
+	return signerString(s)
+}
+
+// GoString keeps %#v from printing the private key.
+func (s *Secp256k1Schnorr) GoString() string {
+	return "&signer.Secp256k1Schnorr{" + signerString(s) + "}"
+}
+
+// Format implements fmt.Formatter so that every verb prints String, or
+// GoString for %#v.
+func (s *Secp256k1Schnorr) Format(f fmt.State, verb rune) {
+	formatSigner(f, verb, s)
+}
+
+// Ed25519 signs with Ed25519 (RFC 8032).
+type Ed25519 struct {
+	privateKey *secret.Bytes
+	publicKey  []byte
+}
+
+// NewEd25519 creates an Ed25519 signer from a 32-byte seed.
+func NewEd25519(privateKey []byte) (*Ed25519, error) {
+	publicKey, err := ed25519.PrivateKeyToPublicKey(privateKey)
+	if err != nil {
+		return nil, err
+	}
+	return &Ed25519{
+		privateKey: secret.Copy(privateKey),
+		publicKey:  publicKey,
+	}, nil
+}
+
+// Scheme returns SchemeEd25519.
+func (s *Ed25519) Scheme() Scheme {
+	return SchemeEd25519
+}
+
+// PublicKey returns the 32-byte public key.
+func (s *Ed25519) PublicKey() []byte {
+	return s.publicKey
+}
+
+// Sign signs the message and returns the 64-byte signature.
+func (s *Ed25519) Sign(message []byte) (sig []byte, err error) {
+	s.privateKey.Use(func(key []byte) { sig, err = ed25519.Sign(key, message) })
+	return sig, err
+}
+
+// Verify checks an Ed25519 signature.
+func (s *Ed25519) Verify(message, signature []byte) bool {
+	return ed25519.Verify(s.publicKey, message, signature)
+}
+
+// Destroy wipes the private key. Signing fails afterwards.
+func (s *Ed25519) Destroy() {
+	s.privateKey.Destroy()
+}
+
+// String returns the scheme and hex public key; the private key is never printed.
+func (s *Ed25519) String() string {
+	return signerString(s)
+}
+
+// GoString keeps %#v from printing the private key.
+func (s *Ed25519) GoString() string {
+	return "&signer.Ed25519{" + signerString(s) + "}"
+}
+
+// Format implements fmt.Formatter so that every verb prints String, or
+// GoString for %#v.
+func (s *Ed25519) Format(f fmt.State, verb rune) {
+	formatSigner(f, verb, s)
+}
+
+// signerString is the String form of the in-memory signers.
+func signerString(s Signer) string {
+	return string(s.Scheme()) + ":" + hex.EncodeToString(s.PublicKey())
+}
+
+// formatSigner writes GoString for %#v and String for every other verb.
+func formatSigner(f fmt.State, verb rune, s interface {
+	fmt.Stringer
+	fmt.GoStringer
+}) {
+	if verb == 'v' && f.Flag('#') {
+		io.WriteString(f, s.GoString())
+		return
+	}
+	io.WriteString(f, s.String())
+}
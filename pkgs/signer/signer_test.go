@@ -0,0 +1,108 @@
+package signer
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+)
+
+func TestSigners(t *testing.T) {
+	privateKey, _ := hex.DecodeString(strings.Repeat("42", 32))
+	digest := sha256.Sum256([]byte("hello"))
+
+	for _, scheme := range []Scheme{SchemeSecp256k1ECDSA, SchemeSecp256k1Schnorr, SchemeEd25519} {
+		t.Run(string(scheme), func(t *testing.T) {
+			s, err := New(scheme, privateKey)
+			if err != nil {
+				t.Fatalf("New() error = %v", err)
+			}
+			if s.Scheme() != scheme {
+				t.Errorf("Scheme() = %s, want %s", s.Scheme(), scheme)
+			}
+
+			sig, err := s.Sign(digest[:])
+			if err != nil {
+				t.Fatalf("Sign() error = %v", err)
+			}
+			if len(sig) != 64 {
+				t.Errorf("len(Sign()) = %d, want 64", len(sig))
+			}
+
+			if !s.Verify(digest[:], sig) {
+				t.Error("Verify() = false for own signature")
+			}
+			if !Verify(scheme, s.PublicKey(), digest[:], sig) {
+				t.Error("package Verify() = false for a valid signature")
+			}
+
+			sig[0] ^= 0x01
+			if s.Verify(digest[:], sig) {
+				t.Error("Verify() = true for a tampered signature")
+			}
+
+			s.(interface{ Destroy() }).Destroy()
+			if _, err := s.Sign(digest[:]); err == nil {
+				t.Error("Sign() after Destroy succeeded")
+			}
+		})
+	}
+}
+
+func TestRecoverableSigner(t *testing.T) {
+	privateKey, _ := hex.DecodeString(strings.Repeat("42", 32))
+	s, err := New(SchemeSecp256k1ECDSA, privateKey)
+	if err != nil {
+		t.Fatalf("New() error = %v", err)
+	}
+
+	recoverable, ok := s.(RecoverableSigner)
+	if !ok {
+		t.Fatal("ECDSA signer does not implement RecoverableSigner")
+	}
+
+	digest := sha256.Sum256([]byte("hello"))
+	sig, recoveryID, err := recoverable.SignRecoverable(digest[:])
+	if err != nil {
+		t.Fatalf("SignRecoverable() error = %v", err)
+	}
+	if recoveryID > 1 {
+		t.Errorf("recovery id = %d", recoveryID)
+	}
+	if !s.Verify(digest[:], sig) {
+		t.Error("Verify() = false for SignRecoverable() signature")
+	}
+}
+
+func TestSignerRedacted(t *testing.T) {
+	privateKey, _ := hex.DecodeString(strings.Repeat("42", 32))
+
+	for _, scheme := range []Scheme{SchemeSecp256k1ECDSA, SchemeSecp256k1Schnorr, SchemeEd25519} {
+		t.Run(string(scheme), func(t *testing.T) {
+			s, _ := New(scheme, privateKey)
+			want := string(scheme) + ":" + hex.EncodeToString(s.PublicKey())
+			for _, verb := range []string{"%v", "%+v", "%s", "%d", "%x"} {
+				if out := fmt.Sprintf(verb, s); out != want {
+					t.Errorf("Sprintf(%s) = %s, want %s", verb, out, want)
+				}
+			}
+			if out := fmt.Sprintf("%#v", s); !strings.HasPrefix(out, "&signer.") || !strings.Contains(out, want) {
+				t.Errorf("Sprintf(%%#v) = %s", out)
+			}
+			if out := fmt.Sprintf("%+v %d", struct{ S Signer }{s}, []Signer{s}); strings.Contains(out, strings.Repeat("42", 8)) || strings.Contains(out, "66 66 66") {
+				t.Errorf("Sprintf of a wrapped signer leaked the key: %s", out)
+			}
+		})
+	}
+}
+
+func TestNewErrors(t *testing.T) {
+	if _, err := New("rsa", make([]byte, 32)); !errors.Is(err, ErrUnsupportedScheme) {
+		t.Errorf("New(rsa) error = %v, want ErrUnsupportedScheme", err)
+	}
+	if s, err := New(SchemeSecp256k1ECDSA, make([]byte, 32)); err == nil || s != nil {
+		t.Errorf("New(zero key) = %v, %v; want nil signer and an error", s, err)
+	}
+}
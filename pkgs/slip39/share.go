@@ -0,0 +1,219 @@
+package slip39
+
+import (
+	"fmt"
+	"strings"
+)
+
+const (
+	// radixBits is the number of bits a word encodes.
+	radixBits = 10
+
+	// headerWords hold the identifier, extendable flag, iteration exponent
+	// and the group and member parameters: 15+1+4+4+4+4+4+4 = 40 bits.
+	headerWords = 4
+
+	// checksumWords hold the RS1024 checksum.
+	checksumWords = 3
+
+	// minMnemonicWords is a share of a 128-bit secret.
+	minMnemonicWords = headerWords + (8*MinSecretLength+radixBits-1)/radixBits + checksumWords
+)
+
+// Customization strings of the RS1024 checksum
+const (
+	customization           = "shamir"
+	customizationExtendable = "shamir_extendable"
+)
+
+// rsGenerator is the generator of the RS1024 checksum code.
+var rsGenerator = [10]uint32{
+	0xe0e040, 0x1c1c080, 0x3838100, 0x7070200, 0xe0e0009,
+	0x1c0c2412, 0x38086c24, 0x3090fc48, 0x21b1f890, 0x3f3f120,
+}
+
+// wordIndex maps each word to its index in words.
+var wordIndex = func() map[string]int {
+	m := make(map[string]int, len(words))
+	for i, w := range words {
+		m[w] = i
+	}
+	return m
+}()
+
+// Share is one decoded SLIP-39 share mnemonic.
+type Share struct {
+	// Identifier is the random 15-bit identifier common to all shares of
+	// a split.
+	Identifier uint16
+
+	// Extendable marks shares whose encryption does not depend on the
+	// identifier, so more shares can be added to the split later.
+	Extendable bool
+
+	// IterationExponent sets the PBKDF2 work: 10000 << IterationExponent
+	// iterations in total.
+	IterationExponent int
+
+	// GroupIndex is the share's group, from 0; GroupThreshold of the
+	// GroupCount groups are needed to recover the secret.
+	GroupIndex     int
+	GroupThreshold int
+	GroupCount     int
+
+	// MemberIndex is the share's position in its group, from 0;
+	// MemberThreshold shares of the group are needed to recover it.
+	MemberIndex     int
+	MemberThreshold int
+
+	// Value is the share value, as long as the master secret. It is as
+	// sensitive as the secret once enough shares are gathered.
+	Value []byte
+}
+
+// ParseShare decodes a share mnemonic and verifies its checksum. Errors name
+// the position of an unknown word, never the word.
+func ParseShare(mnemonic string) (*Share, error) {
+	fields := strings.Fields(strings.ToLower(mnemonic))
+	if len(fields) < minMnemonicWords {
+		return nil, fmt.Errorf("%w: got %d words, need at least %d", ErrInvalidMnemonic, len(fields), minMnemonicWords)
+	}
+	data := make([]int, len(fields))
+	for i, w := range fields {
+		index, ok := wordIndex[w]
+		if !ok {
+			return nil, fmt.Errorf("%w: word %d is not in the word list", ErrInvalidMnemonic, i+1)
+		}
+		data[i] = index
+	}
+
+	var header uint64
+	for _, v := range data[:headerWords] {
+		header = header<<radixBits | uint64(v)
+	}
+	s := &Share{
+		Identifier:        uint16(header >> 25),
+		Extendable:        header>>24&1 == 1,
+		IterationExponent: int(header >> 20 & 0xf),
+		GroupIndex:        int(header >> 16 & 0xf),
+		GroupThreshold:    int(header>>12&0xf) + 1,
+		GroupCount:        int(header>>8&0xf) + 1,
+		MemberIndex:       int(header >> 4 & 0xf),
+		MemberThreshold:   int(header&0xf) + 1,
+	}
+	if rs1024Polymod(s.customization(), data) != 1 {
+		return nil, ErrInvalidChecksum
+	}
+	if s.GroupThreshold > s.GroupCount {
+		return nil, fmt.Errorf("%w: group threshold %d of %d", ErrInvalidMnemonic, s.GroupThreshold, s.GroupCount)
+	}
+
+	value, err := decodeValue(data[headerWords : len(data)-checksumWords])
+	if err != nil {
+		return nil, err
+	}
+	s.Value = value
+	return s, nil
+}
+
+// Mnemonic encodes the share as words.
+func (s *Share) Mnemonic() string {
+	var ext uint64
+	if s.Extendable {
+		ext = 1
+	}
+	header := uint64(s.Identifier)<<25 | ext<<24 | uint64(s.IterationExponent)<<20 |
+		uint64(s.GroupIndex)<<16 | uint64(s.GroupThreshold-1)<<12 | uint64(s.GroupCount-1)<<8 |
+		uint64(s.MemberIndex)<<4 | uint64(s.MemberThreshold-1)
+
+	data := make([]int, 0, headerWords+len(s.Value)+checksumWords)
+	for i := headerWords - 1; i >= 0; i-- {
+		data = append(data, int(header>>(radixBits*i))&(1<<radixBits-1))
+	}
+	data = append(data, encodeValue(s.Value)...)
+
+	// The checksum makes the polymod of the whole share 1
+	check := rs1024Polymod(s.customization(), append(data, 0, 0, 0)) ^ 1
+	for i := checksumWords - 1; i >= 0; i-- {
+		data = append(data, int(check>>(radixBits*i))&(1<<radixBits-1))
+	}
+
+	names := make([]string, len(data))
+	for i, v := range data {
+		names[i] = words[v]
+	}
+	return strings.Join(names, " ")
+}
+
+func (s *Share) customization() string {
+	if s.Extendable {
+		return customizationExtendable
+	}
+	return customization
+}
+
+// encodeValue splits value into words, padding it with leading zero bits to
+// a multiple of radixBits.
+func encodeValue(value []byte) []int {
+	count := (8*len(value) + radixBits - 1) / radixBits
+	out := make([]int, 0, count)
+	acc, n := 0, count*radixBits-8*len(value)
+	for _, b := range value {
+		acc = acc<<8 | int(b)
+		n += 8
+		for n >= radixBits {
+			n -= radixBits
+			out = append(out, acc>>n)
+			acc &= 1<<n - 1
+		}
+	}
+	return out
+}
+
+// decodeValue is the inverse of encodeValue. The padding must be zero bits
+// and shorter than a byte, which makes the value an even number of bytes.
+func decodeValue(data []int) ([]byte, error) {
+	bits := radixBits * len(data)
+	padding := bits % 16
+	if padding > 8 {
+		return nil, fmt.Errorf("%w: %d bits of padding", ErrInvalidMnemonic, padding)
+	}
+	if data[0]>>(radixBits-padding) != 0 {
+		return nil, fmt.Errorf("%w: nonzero padding", ErrInvalidMnemonic)
+	}
+
+	value := make([]byte, 0, (bits-padding)/8)
+	acc, n := data[0], radixBits-padding
+	for _, v := range data[1:] {
+		acc = acc<<radixBits | v
+		n += radixBits
+		for n >= 8 {
+			n -= 8
+			value = append(value, byte(acc>>n))
+			acc &= 1<<n - 1
+		}
+	}
+	return value, nil
+}
+
+// rs1024Polymod computes the RS1024 checksum polynomial of the
+// customization string followed by data; a valid share gives 1.
+func rs1024Polymod(customization string, data []int) uint32 {
+	chk := uint32(1)
+	step := func(v uint32) {
+		b := chk >> 20
+		chk = (chk&0xfffff)<<radixBits ^ v
+		for i, g := range rsGenerator {
+			if b>>i&1 != 0 {
+				chk ^= g
+			}
+		}
+	}
+	for i := range len(customization) {
+		step(uint32(customization[i]))
+	}
+	for _, v := range data {
+		step(uint32(v))
+	}
+	return chk
+}
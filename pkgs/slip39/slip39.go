@@ -0,0 +1,373 @@
+// Package slip39 implements SLIP-39 Shamir backups, the share format of
+// Trezor Model T and Safe wallets. A master secret is encrypted with a
+// passphrase and split in two levels: into groups, any GroupThreshold of
+// which recover it, and each group's share into member shares, any
+// MemberThreshold of which recover the group's share. Every share is a
+// mnemonic from the SLIP-39 word list with an RS1024 checksum: 20 words for a
+// 128-bit secret, 33 for a 256-bit one.
+//
+// The master secret is used as the BIP-32 seed directly. Splitting the
+// entropy of a BIP-39 mnemonic and combining the shares gives the entropy
+// back, but a wallet restoring the shares derives different keys from it
+// than from the BIP-39 phrase.
+package slip39
+
+import (
+	"bytes"
+	"crypto/hmac"
+	"crypto/rand"
+	"crypto/sha256"
+	"encoding/binary"
+	"errors"
+	"fmt"
+	"io"
+	"slices"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/shamir"
+	"golang.org/x/crypto/pbkdf2"
+)
+
+var (
+	// ErrInvalidSecret is returned by Split for a master secret of odd
+	// length or shorter than MinSecretLength.
+	ErrInvalidSecret = errors.New("slip39: master secret must be an even number of bytes, at least 16")
+
+	// ErrInvalidPassphrase is returned by Split for a passphrase with
+	// characters other than printable ASCII, which other wallets cannot
+	// enter.
+	ErrInvalidPassphrase = errors.New("slip39: passphrase must be printable ASCII")
+
+	// ErrInvalidScheme is returned by Split for thresholds or counts out of
+	// range.
+	ErrInvalidScheme = errors.New("slip39: invalid group or member thresholds")
+
+	// ErrInvalidMnemonic is returned for a share mnemonic with unknown
+	// words, a bad length or bad padding.
+	ErrInvalidMnemonic = errors.New("slip39: invalid share mnemonic")
+
+	// ErrInvalidChecksum is returned when a share's RS1024 checksum does
+	// not match, usually because of a mistyped word.
+	ErrInvalidChecksum = errors.New("slip39: share checksum mismatch")
+
+	// ErrMismatchedShares is returned by Combine for shares of different
+	// splits.
+	ErrMismatchedShares = errors.New("slip39: shares are from different splits")
+
+	// ErrTooFewShares is returned by Combine when fewer than GroupThreshold
+	// groups have MemberThreshold shares.
+	ErrTooFewShares = errors.New("slip39: too few shares")
+
+	// ErrDigestMismatch is returned when the recovered group or master
+	// secret does not match its digest: a share is corrupted.
+	ErrDigestMismatch = errors.New("slip39: share digest mismatch")
+)
+
+const (
+	// MinSecretLength is the shortest master secret in bytes, 128 bits.
+	MinSecretLength = 16
+
+	// MaxShares is the largest number of groups, and of members in a group.
+	MaxShares = 16
+
+	// DefaultIterationExponent is the exponent Trezor uses, 20000 PBKDF2
+	// iterations in total.
+	DefaultIterationExponent = 1
+
+	// maxIterationExponent fits the exponent's 4 bits.
+	maxIterationExponent = 15
+)
+
+const (
+	// baseIterations is the PBKDF2 iteration count at exponent 0, spread
+	// over the Feistel rounds.
+	baseIterations = 10000
+	feistelRounds  = 4
+
+	// digestLength is the size of the digest share's HMAC prefix.
+	digestLength = 4
+
+	// The digest and secret are the points at x = 254 and 255.
+	digestIndex = 254
+	secretIndex = 255
+)
+
+// Group is one group of a Scheme: Count member shares, any Threshold of
+// which recover the group's share.
+type Group struct {
+	Threshold int
+	Count     int
+}
+
+// Scheme describes how Split shares a master secret.
+type Scheme struct {
+	// GroupThreshold is the number of groups needed to recover the secret.
+	GroupThreshold int
+
+	// Groups are the groups, at most MaxShares.
+	Groups []Group
+
+	// IterationExponent sets the PBKDF2 work of the passphrase encryption,
+	// from 0 to 15. SingleGroup uses DefaultIterationExponent.
+	IterationExponent int
+}
+
+// SingleGroup returns the common scheme of one group of count shares, any
+// threshold of which recover the secret.
+func SingleGroup(threshold, count int) Scheme {
+	return Scheme{
+		GroupThreshold:    1,
+		Groups:            []Group{{Threshold: threshold, Count: count}},
+		IterationExponent: DefaultIterationExponent,
+	}
+}
+
+func (s Scheme) validate() error {
+	if len(s.Groups) == 0 || len(s.Groups) > MaxShares || s.GroupThreshold < 1 || s.GroupThreshold > len(s.Groups) {
+		return fmt.Errorf("%w: %d of %d groups", ErrInvalidScheme, s.GroupThreshold, len(s.Groups))
+	}
+	if s.IterationExponent < 0 || s.IterationExponent > maxIterationExponent {
+		return fmt.Errorf("%w: iteration exponent %d", ErrInvalidScheme, s.IterationExponent)
+	}
+	for i, g := range s.Groups {
+		if g.Count > MaxShares || g.Threshold < 1 || g.Threshold > g.Count {
+			return fmt.Errorf("%w: group %d is %d of %d", ErrInvalidScheme, i+1, g.Threshold, g.Count)
+		}
+		// Several copies of one share add nothing over a 1-of-1 group
+		if g.Threshold == 1 && g.Count > 1 {
+			return fmt.Errorf("%w: group %d is 1 of %d, use 1 of 1", ErrInvalidScheme, i+1, g.Count)
+		}
+	}
+	return nil
+}
+
+// Split encrypts secret with passphrase, which may be empty, and splits it
+// according to scheme. It returns the share mnemonics of each group. Shares
+// are extendable, the current SLIP-39 format, and read their randomness
+// from rnd, or from crypto/rand.Reader when rnd is nil.
+func Split(rnd io.Reader, secret []byte, passphrase string, scheme Scheme) ([][]string, error) {
+	if len(secret) < MinSecretLength || len(secret)%2 != 0 {
+		return nil, fmt.Errorf("%w: got %d bytes", ErrInvalidSecret, len(secret))
+	}
+	for i := range len(passphrase) {
+		if passphrase[i] < 0x20 || passphrase[i] > 0x7e {
+			return nil, ErrInvalidPassphrase
+		}
+	}
+	if err := scheme.validate(); err != nil {
+		return nil, err
+	}
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+
+	var id [2]byte
+	if _, err := io.ReadFull(rnd, id[:]); err != nil {
+		return nil, err
+	}
+	identifier := binary.BigEndian.Uint16(id[:]) >> 1
+
+	encrypted := feistel(secret, passphrase, scheme.IterationExponent, identifier, true, false)
+	defer clear(encrypted)
+	groupShares, err := splitSecret(rnd, scheme.GroupThreshold, len(scheme.Groups), encrypted)
+	if err != nil {
+		return nil, err
+	}
+
+	mnemonics := make([][]string, len(scheme.Groups))
+	for i, g := range scheme.Groups {
+		members, err := splitSecret(rnd, g.Threshold, g.Count, groupShares[i])
+		if err != nil {
+			return nil, err
+		}
+		for j, value := range members {
+			s := Share{
+				Identifier:        identifier,
+				Extendable:        true,
+				IterationExponent: scheme.IterationExponent,
+				GroupIndex:        i,
+				GroupThreshold:    scheme.GroupThreshold,
+				GroupCount:        len(scheme.Groups),
+				MemberIndex:       j,
+				MemberThreshold:   g.Threshold,
+				Value:             value,
+			}
+			mnemonics[i] = append(mnemonics[i], s.Mnemonic())
+			clear(value)
+		}
+		clear(groupShares[i])
+	}
+	return mnemonics, nil
+}
+
+// Combine recovers the master secret from share mnemonics and the
+// passphrase they were split with. Groups with fewer than their threshold
+// of shares are skipped, as are shares beyond a threshold. A wrong
+// passphrase is not detected: it gives a different secret, by design.
+func Combine(mnemonics []string, passphrase string) ([]byte, error) {
+	if len(mnemonics) == 0 {
+		return nil, ErrTooFewShares
+	}
+	shares := make([]*Share, len(mnemonics))
+	for i, m := range mnemonics {
+		s, err := ParseShare(m)
+		if err != nil {
+			return nil, fmt.Errorf("share %d: %w", i+1, err)
+		}
+		shares[i] = s
+	}
+
+	// groups[i] holds the distinct members of group i
+	first := shares[0]
+	groups := make([][]*Share, first.GroupCount)
+	for i, s := range shares {
+		if s.Identifier != first.Identifier || s.Extendable != first.Extendable ||
+			s.IterationExponent != first.IterationExponent || s.GroupThreshold != first.GroupThreshold ||
+			s.GroupCount != first.GroupCount || len(s.Value) != len(first.Value) {
+			return nil, fmt.Errorf("%w: share %d does not match share 1", ErrMismatchedShares, i+1)
+		}
+		if s.GroupIndex >= s.GroupCount {
+			return nil, fmt.Errorf("%w: share %d has group %d of %d", ErrInvalidMnemonic, i+1, s.GroupIndex+1, s.GroupCount)
+		}
+		members := groups[s.GroupIndex]
+		if len(members) > 0 && s.MemberThreshold != members[0].MemberThreshold {
+			return nil, fmt.Errorf("%w: share %d has another member threshold", ErrMismatchedShares, i+1)
+		}
+		if !slices.ContainsFunc(members, func(m *Share) bool { return m.MemberIndex == s.MemberIndex }) {
+			groups[s.GroupIndex] = append(members, s)
+		}
+	}
+
+	var xs []byte
+	var ys [][]byte
+	defer func() {
+		for _, y := range ys {
+			clear(y)
+		}
+	}()
+	for index, members := range groups {
+		if len(xs) == first.GroupThreshold {
+			break
+		}
+		if len(members) == 0 || len(members) < members[0].MemberThreshold {
+			continue
+		}
+		members = members[:members[0].MemberThreshold]
+		memberXs := make([]byte, len(members))
+		memberYs := make([][]byte, len(members))
+		for i, s := range members {
+			memberXs[i], memberYs[i] = byte(s.MemberIndex), s.Value
+		}
+		value, err := recoverSecret(memberXs, memberYs)
+		if err != nil {
+			return nil, fmt.Errorf("group %d: %w", index+1, err)
+		}
+		xs, ys = append(xs, byte(index)), append(ys, value)
+	}
+	if len(xs) < first.GroupThreshold {
+		return nil, fmt.Errorf("%w: %d of %d groups complete", ErrTooFewShares, len(xs), first.GroupThreshold)
+	}
+
+	encrypted, err := recoverSecret(xs, ys)
+	if err != nil {
+		return nil, err
+	}
+	defer clear(encrypted)
+	return feistel(encrypted, passphrase, first.IterationExponent, first.Identifier, first.Extendable, true), nil
+}
+
+// splitSecret splits secret into count shares at x = 0, 1, ..., any
+// threshold of which recover it. The polynomial also passes through the
+// secret at x = 255 and, at x = 254, a digest share: the first four bytes
+// of HMAC-SHA256(r, secret) followed by the random r.
+func splitSecret(rnd io.Reader, threshold, count int, secret []byte) ([][]byte, error) {
+	shares := make([][]byte, count)
+	if threshold == 1 {
+		for i := range shares {
+			shares[i] = bytes.Clone(secret)
+		}
+		return shares, nil
+	}
+
+	// threshold-2 random shares, the digest and the secret fix the polynomial
+	randomCount := threshold - 2
+	xs := make([]byte, 0, threshold)
+	ys := make([][]byte, 0, threshold)
+	for i := range randomCount {
+		shares[i] = make([]byte, len(secret))
+		if _, err := io.ReadFull(rnd, shares[i]); err != nil {
+			return nil, err
+		}
+		xs, ys = append(xs, byte(i)), append(ys, shares[i])
+	}
+	random := make([]byte, len(secret)-digestLength)
+	if _, err := io.ReadFull(rnd, random); err != nil {
+		return nil, err
+	}
+	digest := append(shareDigest(random, secret), random...)
+	xs, ys = append(xs, digestIndex, secretIndex), append(ys, digest, secret)
+
+	for i := randomCount; i < count; i++ {
+		share, err := shamir.Interpolate(xs, ys, byte(i))
+		if err != nil {
+			return nil, err
+		}
+		shares[i] = share
+	}
+	clear(digest)
+	return shares, nil
+}
+
+// recoverSecret interpolates the secret from threshold shares and checks
+// it against the digest share.
+func recoverSecret(xs []byte, ys [][]byte) ([]byte, error) {
+	if len(xs) == 1 {
+		return bytes.Clone(ys[0]), nil
+	}
+	secret, err := shamir.Interpolate(xs, ys, secretIndex)
+	if err != nil {
+		return nil, err
+	}
+	digest, err := shamir.Interpolate(xs, ys, digestIndex)
+	if err != nil {
+		return nil, err
+	}
+	if !hmac.Equal(digest[:digestLength], shareDigest(digest[digestLength:], secret)) {
+		clear(secret)
+		return nil, ErrDigestMismatch
+	}
+	return secret, nil
+}
+
+func shareDigest(random, secret []byte) []byte {
+	mac := hmac.New(sha256.New, random)
+	mac.Write(secret)
+	return mac.Sum(nil)[:digestLength]
+}
+
+// feistel encrypts or decrypts a master secret with the passphrase in a
+// four-round Feistel network whose round function is PBKDF2-HMAC-SHA256.
+// Shares that are not extendable also salt it with the identifier.
+func feistel(data []byte, passphrase string, exponent int, identifier uint16, extendable, decrypt bool) []byte {
+	half := len(data) / 2
+	l, r := bytes.Clone(data[:half]), bytes.Clone(data[half:])
+
+	var salt []byte
+	if !extendable {
+		salt = binary.BigEndian.AppendUint16([]byte(customization), identifier)
+	}
+	iterations := baseIterations / feistelRounds << exponent
+
+	for i := range feistelRounds {
+		round := i
+		if decrypt {
+			round = feistelRounds - 1 - i
+		}
+		password := append([]byte{byte(round)}, passphrase...)
+		f := pbkdf2.Key(password, slices.Concat(salt, r), iterations, len(r), sha256.New)
+		for j := range l {
+			l[j] ^= f[j]
+		}
+		l, r = r, l
+	}
+	return append(r, l...)
+}
@@ -0,0 +1,182 @@
+package slip39
+
+import (
+	"bytes"
+	"encoding/hex"
+	"errors"
+	"strings"
+	"testing"
+)
+
+// Test vectors from https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json
+const (
+	vector1of1  = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard"
+	vector2of3a = "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed"
+	vector2of3b = "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking"
+)
+
+func TestCombineVectors(t *testing.T) {
+	tests := []struct {
+		name      string
+		mnemonics []string
+		secret    string // hex
+	}{
+		{"1 of 1", []string{vector1of1}, "bb54aac4b89dc868ba37d9cc21b2cece"},
+		{"2 of 3", []string{vector2of3a, vector2of3b}, "b43ceb7e57a0ea8766221624d01b0864"},
+		{"2 of 3 reversed", []string{vector2of3b, vector2of3a}, "b43ceb7e57a0ea8766221624d01b0864"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			secret, err := Combine(tt.mnemonics, "TREZOR")
+			if err != nil {
+				t.Fatalf("Combine() error = %v", err)
+			}
+			if got := hex.EncodeToString(secret); got != tt.secret {
+				t.Errorf("Combine() = %s, want %s", got, tt.secret)
+			}
+		})
+	}
+
+	if _, err := Combine([]string{vector2of3a}, "TREZOR"); !errors.Is(err, ErrTooFewShares) {
+		t.Errorf("Combine(1 of 2) error = %v, want ErrTooFewShares", err)
+	}
+}
+
+func TestParseShare(t *testing.T) {
+	s, err := ParseShare(vector2of3a)
+	if err != nil {
+		t.Fatalf("ParseShare() error = %v", err)
+	}
+	if s.Identifier != 25653 || s.Extendable || s.IterationExponent != 2 || s.GroupIndex != 0 ||
+		s.GroupThreshold != 1 || s.GroupCount != 1 || s.MemberIndex != 2 || s.MemberThreshold != 2 {
+		t.Errorf("ParseShare() = %+v", *s)
+	}
+	if got := hex.EncodeToString(s.Value); got != "08fb14b66e692e25dfe2edf53289ed62" {
+		t.Errorf("ParseShare() value = %s", got)
+	}
+	if got := s.Mnemonic(); got != vector2of3a {
+		t.Errorf("Mnemonic() = %q, want %q", got, vector2of3a)
+	}
+
+	words := strings.Fields(vector2of3a)
+	swapped := strings.Join(append([]string{words[1], words[0]}, words[2:]...), " ")
+	tests := []struct {
+		name     string
+		mnemonic string
+		wantErr  error
+	}{
+		{"upper case", strings.ToUpper(vector2of3a), nil},
+		{"swapped words", swapped, ErrInvalidChecksum},
+		{"mistyped word", strings.Replace(vector2of3a, "fancy", "family", 1), ErrInvalidChecksum},
+		{"unknown word", strings.Replace(vector2of3a, "fancy", "fancier", 1), ErrInvalidMnemonic},
+		{"too short", strings.Join(words[:19], " "), ErrInvalidMnemonic},
+		{"BIP-39 phrase", "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", ErrInvalidMnemonic},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := ParseShare(tt.mnemonic); !errors.Is(err, tt.wantErr) {
+				t.Errorf("ParseShare() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+}
+
+func TestSplitCombine(t *testing.T) {
+	secret := bytes.Repeat([]byte{0x5a, 0x01, 0xff, 0x3c}, 8)
+
+	// Two of three groups: a 1-of-1 group, a 2-of-3 group and a 3-of-5 group
+	scheme := Scheme{
+		GroupThreshold: 2,
+		Groups:         []Group{{1, 1}, {2, 3}, {3, 5}},
+	}
+	groups, err := Split(nil, secret, "hunter2", scheme)
+	if err != nil {
+		t.Fatalf("Split() error = %v", err)
+	}
+	if len(groups) != 3 || len(groups[0]) != 1 || len(groups[1]) != 3 || len(groups[2]) != 5 {
+		t.Fatalf("Split() = %d groups", len(groups))
+	}
+	if n := len(strings.Fields(groups[0][0])); n != 33 {
+		t.Errorf("Split() share of %d words, want 33", n)
+	}
+
+	for _, pick := range [][]string{
+		{groups[0][0], groups[1][2], groups[1][0]},
+		{groups[2][4], groups[2][1], groups[2][0], groups[0][0]},
+		{groups[1][1], groups[2][3], groups[1][2], groups[2][2], groups[2][0]},
+		// An incomplete group is skipped
+		{groups[2][0], groups[1][0], groups[1][1], groups[0][0]},
+	} {
+		got, err := Combine(pick, "hunter2")
+		if err != nil || !bytes.Equal(got, secret) {
+			t.Errorf("Combine() = %x, %v, want %x", got, err, secret)
+		}
+	}
+
+	if _, err := Combine([]string{groups[0][0], groups[1][0], groups[2][0], groups[2][1]}, "hunter2"); !errors.Is(err, ErrTooFewShares) {
+		t.Errorf("Combine(one complete group) error = %v, want ErrTooFewShares", err)
+	}
+
+	// A wrong passphrase gives another secret rather than an error
+	got, err := Combine([]string{groups[0][0], groups[1][0], groups[1][1]}, "hunter3")
+	if err != nil || bytes.Equal(got, secret) {
+		t.Errorf("Combine(wrong passphrase) = %x, %v", got, err)
+	}
+
+	other, _ := Split(nil, secret, "hunter2", scheme)
+	if _, err := Combine([]string{groups[0][0], other[1][0], other[1][1]}, "hunter2"); !errors.Is(err, ErrMismatchedShares) {
+		t.Errorf("Combine(mixed splits) error = %v, want ErrMismatchedShares", err)
+	}
+}
+
+func TestSplitSingleGroup(t *testing.T) {
+	secret, _ := hex.DecodeString("bb54aac4b89dc868ba37d9cc21b2cece")
+	scheme := SingleGroup(3, 5)
+	scheme.IterationExponent = 0
+	groups, err := Split(nil, secret, "", scheme)
+	if err != nil {
+		t.Fatalf("Split() error = %v", err)
+	}
+	shares := groups[0]
+	if n := len(strings.Fields(shares[0])); n != 20 {
+		t.Errorf("Split() share of %d words, want 20", n)
+	}
+	got, err := Combine([]string{shares[4], shares[0], shares[2]}, "")
+	if err != nil || !bytes.Equal(got, secret) {
+		t.Errorf("Combine() = %x, %v, want %x", got, err, secret)
+	}
+
+	// A repeated share does not count twice
+	if _, err := Combine([]string{shares[1], shares[1], shares[3]}, ""); !errors.Is(err, ErrTooFewShares) {
+		t.Errorf("Combine(repeated share) error = %v, want ErrTooFewShares", err)
+	}
+}
+
+func TestSplitInvalid(t *testing.T) {
+	secret := make([]byte, 16)
+	tests := []struct {
+		name       string
+		secret     []byte
+		passphrase string
+		scheme     Scheme
+		wantErr    error
+	}{
+		{"short secret", make([]byte, 14), "", SingleGroup(2, 3), ErrInvalidSecret},
+		{"odd secret", make([]byte, 17), "", SingleGroup(2, 3), ErrInvalidSecret},
+		{"non-ASCII passphrase", secret, "pässword", SingleGroup(2, 3), ErrInvalidPassphrase},
+		{"threshold above count", secret, "", SingleGroup(4, 3), ErrInvalidScheme},
+		{"1 of 2", secret, "", SingleGroup(1, 2), ErrInvalidScheme},
+		{"17 members", secret, "", SingleGroup(2, MaxShares+1), ErrInvalidScheme},
+		{"no groups", secret, "", Scheme{GroupThreshold: 1}, ErrInvalidScheme},
+		{"group threshold above count", secret, "", Scheme{GroupThreshold: 2, Groups: []Group{{2, 3}}}, ErrInvalidScheme},
+		{"iteration exponent", secret, "", Scheme{GroupThreshold: 1, Groups: []Group{{2, 3}}, IterationExponent: 16}, ErrInvalidScheme},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if _, err := Split(nil, tt.secret, tt.passphrase, tt.scheme); !errors.Is(err, tt.wantErr) {
+				t.Errorf("Split() error = %v, want %v", err, tt.wantErr)
+			}
+		})
+	}
+}
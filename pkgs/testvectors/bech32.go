@@ -0,0 +1,98 @@
+package testvectors
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// bech32File holds the BIP-173 (Bech32) and BIP-350 (Bech32m) vectors.
+type bech32File struct {
+	Bech32  bech32Strings `json:"bech32"`
+	Bech32m bech32Strings `json:"bech32m"`
+	SegWit  struct {
+		Valid []struct {
+			Address      string `json:"address"`
+			ScriptPubKey string `json:"scriptPubKey"`
+		} `json:"valid"`
+		Invalid []struct {
+			Address string `json:"address"`
+			Reason  string `json:"reason"`
+		} `json:"invalid"`
+	} `json:"segwit"`
+}
+
+type bech32Strings struct {
+	Valid   []string `json:"valid"`
+	Invalid []struct {
+		String string `json:"string"`
+		Reason string `json:"reason"`
+	} `json:"invalid"`
+}
+
+func runBech32(data []byte) ([]Result, error) {
+	var file bech32File
+	if err := json.Unmarshal(data, &file); err != nil {
+		return nil, err
+	}
+
+	var results []Result
+	for _, variant := range []struct {
+		encoding address.Bech32Encoding
+		strings  bech32Strings
+	}{
+		{address.Bech32Standard, file.Bech32},
+		{address.Bech32m, file.Bech32m},
+	} {
+		for _, s := range variant.strings.Valid {
+			_, _, err := address.Bech32DecodeAs(s, variant.encoding)
+			results = append(results, Result{Name: fmt.Sprintf("%s %q", variant.encoding, s), Err: err})
+		}
+		for _, v := range variant.strings.Invalid {
+			var err error
+			if _, _, decodeErr := address.Bech32DecodeAs(v.String, variant.encoding); decodeErr == nil {
+				err = errors.New("string was accepted")
+			}
+			results = append(results, Result{Name: fmt.Sprintf("%s invalid: %s", variant.encoding, v.Reason), Err: err})
+		}
+	}
+
+	for _, v := range file.SegWit.Valid {
+		results = append(results, Result{Name: v.Address, Err: checkSegWit(v.Address, v.ScriptPubKey)})
+	}
+	for _, v := range file.SegWit.Invalid {
+		var err error
+		if _, _, _, decodeErr := address.SegWitDecode(v.Address); decodeErr == nil {
+			err = errors.New("address was accepted")
+		}
+		results = append(results, Result{Name: "segwit invalid: " + v.Reason, Err: err})
+	}
+	return results, nil
+}
+
+// checkSegWit decodes an address, compares its scriptPubKey and re-encodes it.
+func checkSegWit(addr, scriptPubKey string) error {
+	hrp, version, program, err := address.SegWitDecode(addr)
+	if err != nil {
+		return err
+	}
+
+	opcode := byte(0)
+	if version > 0 {
+		opcode = byte(0x50 + version) // OP_1 .. OP_16
+	}
+	script := append([]byte{opcode, byte(len(program))}, program...)
+	if err := check("scriptPubKey", hex.EncodeToString(script), scriptPubKey); err != nil {
+		return err
+	}
+
+	encoded, err := address.SegWitEncode(hrp, version, program)
+	if err != nil {
+		return err
+	}
+	return check("re-encoded", encoded, strings.ToLower(addr))
+}
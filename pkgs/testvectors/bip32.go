@@ -0,0 +1,89 @@
+package testvectors
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// bip32File holds BIP-32 test vectors 1-4 and the invalid keys of vector 5.
+type bip32File struct {
+	Valid []struct {
+		Seed   string `json:"seed"`
+		Chains []struct {
+			Path string `json:"path"`
+			XPub string `json:"xpub"`
+			XPrv string `json:"xprv"`
+		} `json:"chains"`
+	} `json:"valid"`
+	Invalid []struct {
+		Key    string `json:"key"`
+		Reason string `json:"reason"`
+	} `json:"invalid"`
+}
+
+func runBIP32(data []byte) ([]Result, error) {
+	var file bip32File
+	if err := json.Unmarshal(data, &file); err != nil {
+		return nil, err
+	}
+
+	var results []Result
+	for i, v := range file.Valid {
+		seed, err := hex.DecodeString(v.Seed)
+		if err != nil {
+			return nil, fmt.Errorf("vector %d: %w", i+1, err)
+		}
+		for _, c := range v.Chains {
+			results = append(results, Result{
+				Name: fmt.Sprintf("vector %d %s", i+1, c.Path),
+				Err:  checkBIP32(seed, c.Path, c.XPub, c.XPrv),
+			})
+		}
+	}
+
+	for _, v := range file.Invalid {
+		var err error
+		if _, parseErr := bip32.ParseExtendedKey(v.Key); parseErr == nil {
+			err = errors.New("key was accepted")
+		}
+		results = append(results, Result{Name: "invalid: " + v.Reason, Err: err})
+	}
+	return results, nil
+}
+
+func checkBIP32(seed []byte, path, xpub, xprv string) error {
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		return fmt.Errorf("NewMasterKey: %w", err)
+	}
+	key, err := master.DeriveFromPathString(path)
+	if err != nil {
+		return fmt.Errorf("derive: %w", err)
+	}
+	if err := check("xprv", key.String(), xprv); err != nil {
+		return err
+	}
+
+	public, err := key.Neuter()
+	if err != nil {
+		return fmt.Errorf("Neuter: %w", err)
+	}
+	if err := check("xpub", public.String(), xpub); err != nil {
+		return err
+	}
+
+	for _, encoded := range []string{xprv, xpub} {
+		parsed, err := bip32.ParseExtendedKey(encoded)
+		if err != nil {
+			return fmt.Errorf("ParseExtendedKey: %w", err)
+		}
+		if err := check("round trip", parsed.String(), encoded); err != nil {
+			return err
+		}
+	}
+	return nil
+}
@@ -0,0 +1,79 @@
+package testvectors
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+	"sort"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+// TrezorPassphrase is the passphrase the Trezor BIP-39 vectors use.
+const TrezorPassphrase = "TREZOR"
+
+// runBIP39 runs vectors in the Trezor vectors.json layout: for each language,
+// a list of [entropy, mnemonic, seed, xprv].
+func runBIP39(data []byte) ([]Result, error) {
+	var file map[string][][]string
+	if err := json.Unmarshal(data, &file); err != nil {
+		return nil, err
+	}
+
+	languages := make([]string, 0, len(file))
+	for language := range file {
+		languages = append(languages, language)
+	}
+	sort.Strings(languages)
+
+	var results []Result
+	for _, language := range languages {
+		for i, v := range file[language] {
+			name := fmt.Sprintf("%s #%d", language, i)
+			if language != "english" {
+				results = append(results, Result{Name: name, Err: fmt.Errorf("no %s wordlist", language)})
+				continue
+			}
+			if len(v) != 4 {
+				return nil, fmt.Errorf("%s: want 4 fields, got %d", name, len(v))
+			}
+			results = append(results, Result{Name: name, Err: checkBIP39(v[0], v[1], v[2], v[3])})
+		}
+	}
+	return results, nil
+}
+
+func checkBIP39(entropyHex, mnemonic, seedHex, xprv string) error {
+	entropy, err := hex.DecodeString(entropyHex)
+	if err != nil {
+		return err
+	}
+
+	got, err := bip39.NewMnemonic(entropy)
+	if err != nil {
+		return fmt.Errorf("NewMnemonic: %w", err)
+	}
+	if err := check("mnemonic", got, mnemonic); err != nil {
+		return err
+	}
+
+	back, err := bip39.MnemonicToEntropy(mnemonic)
+	if err != nil {
+		return fmt.Errorf("MnemonicToEntropy: %w", err)
+	}
+	if err := check("entropy", hex.EncodeToString(back), entropyHex); err != nil {
+		return err
+	}
+
+	seed := bip39.NewSeed(mnemonic, TrezorPassphrase)
+	if err := check("seed", hex.EncodeToString(seed), seedHex); err != nil {
+		return err
+	}
+
+	master, err := bip32.NewMasterKey(seed)
+	if err != nil {
+		return fmt.Errorf("NewMasterKey: %w", err)
+	}
+	return check("xprv", master.String(), xprv)
+}
@@ -0,0 +1,42 @@
+package testvectors
+
+import (
+	"encoding/json"
+	"errors"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+func runEIP55(data []byte) ([]Result, error) {
+	var file struct {
+		Valid []string `json:"valid"`
+	}
+	if err := json.Unmarshal(data, &file); err != nil {
+		return nil, err
+	}
+
+	eth := address.NewEthereumAddress()
+	results := make([]Result, 0, len(file.Valid))
+	for _, addr := range file.Valid {
+		var err error
+		switch {
+		case !eth.ValidateChecksum(addr):
+			err = errors.New("checksum rejected")
+		case eth.ValidateChecksum(flipCase(addr)):
+			err = errors.New("checksum accepted with one letter's case flipped")
+		}
+		results = append(results, Result{Name: addr, Err: err})
+	}
+	return results, nil
+}
+
+// flipCase flips the case of the first hex letter after the 0x prefix.
+func flipCase(addr string) string {
+	i := strings.IndexAny(addr[2:], "abcdefABCDEF") + 2
+	if i < 2 {
+		return addr
+	}
+	c := addr[i] ^ 0x20
+	return addr[:i] + string(c) + addr[i+1:]
+}
@@ -0,0 +1,63 @@
+package testvectors
+
+import (
+	"encoding/hex"
+	"encoding/json"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+)
+
+// slip10File holds SLIP-10 vectors. The chain code is recorded but not
+// checked, since the Ed25519 derivation only returns the key pair.
+type slip10File []struct {
+	Curve  string `json:"curve"`
+	Seed   string `json:"seed"`
+	Chains []struct {
+		Path      string `json:"path"`
+		ChainCode string `json:"chainCode"`
+		Private   string `json:"private"`
+		Public    string `json:"public"` // 0x00-prefixed for Ed25519
+	} `json:"chains"`
+}
+
+func runSLIP10(data []byte) ([]Result, error) {
+	var file slip10File
+	if err := json.Unmarshal(data, &file); err != nil {
+		return nil, err
+	}
+
+	var results []Result
+	for i, v := range file {
+		seed, err := hex.DecodeString(v.Seed)
+		if err != nil {
+			return nil, fmt.Errorf("vector %d: %w", i+1, err)
+		}
+		for _, c := range v.Chains {
+			res := Result{Name: fmt.Sprintf("%s vector %d %s", v.Curve, i+1, c.Path)}
+			if v.Curve == "ed25519" {
+				res.Err = checkSLIP10Ed25519(seed, c.Path, c.Private, c.Public)
+			} else {
+				res.Err = fmt.Errorf("unsupported curve %q", v.Curve)
+			}
+			results = append(results, res)
+		}
+	}
+	return results, nil
+}
+
+func checkSLIP10Ed25519(seed []byte, path, private, public string) error {
+	parsed, err := bip32.ParsePath(path)
+	if err != nil {
+		return err
+	}
+	privateKey, publicKey, err := ed25519.DeriveKeyFromPath(seed, parsed)
+	if err != nil {
+		return fmt.Errorf("DeriveKeyFromPath: %w", err)
+	}
+	if err := check("private key", hex.EncodeToString(privateKey), private); err != nil {
+		return err
+	}
+	return check("public key", "00"+hex.EncodeToString(publicKey), public)
+}
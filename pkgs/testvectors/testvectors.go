@@ -0,0 +1,148 @@
+// Package testvectors checks this module's derivations and encodings against
+// published conformance vectors: the BIP-39 Trezor vectors, BIP-32 test
+// vectors 1-5, SLIP-10 Ed25519, EIP-55 and BIP-173/BIP-350.
+//
+// The vectors are embedded, so a fork or a dependent's CI can verify the
+// build it links against with a single call:
+//
+//	report, err := testvectors.Run()
+//	if err == nil {
+//		err = report.Err()
+//	}
+//
+// RunSuite runs vectors from another source in the same JSON format, e.g. the
+// upstream Trezor vectors.json. Nothing here is linked into a binary unless
+// the package is imported.
+package testvectors
+
+import (
+	"embed"
+	"errors"
+	"fmt"
+	"strings"
+)
+
+//go:embed testdata/*.json
+var testdata embed.FS
+
+// ErrUnknownSuite is returned for a suite name Run does not know.
+var ErrUnknownSuite = errors.New("testvectors: unknown suite")
+
+// Suite names a family of vectors.
+type Suite string
+
+// Supported suites
+const (
+	BIP39  Suite = "bip39"  // Trezor vectors: entropy, mnemonic, seed ("TREZOR" passphrase), xprv
+	BIP32  Suite = "bip32"  // test vectors 1-4 and the invalid keys of vector 5
+	SLIP10 Suite = "slip10" // Ed25519 derivation
+	EIP55  Suite = "eip55"  // mixed-case checksum addresses
+	Bech32 Suite = "bech32" // BIP-173 and BIP-350 strings and SegWit addresses
+)
+
+// Suites returns every suite in the order Run executes them.
+func Suites() []Suite {
+	return []Suite{BIP39, BIP32, SLIP10, EIP55, Bech32}
+}
+
+// Result is the outcome of one vector.
+type Result struct {
+	Suite Suite
+	Name  string // identifies the vector within its suite, e.g. "vector 1 m/0'/1"
+	Err   error  // nil when the vector passed
+}
+
+// Report collects the results of a run.
+type Report struct {
+	Results []Result
+}
+
+// Passed returns the number of vectors that passed.
+func (r *Report) Passed() int {
+	return len(r.Results) - len(r.Failed())
+}
+
+// Failed returns the results of the vectors that failed.
+func (r *Report) Failed() []Result {
+	var failed []Result
+	for _, res := range r.Results {
+		if res.Err != nil {
+			failed = append(failed, res)
+		}
+	}
+	return failed
+}
+
+// Err returns an error listing every failed vector, or nil if all passed.
+func (r *Report) Err() error {
+	failed := r.Failed()
+	if len(failed) == 0 {
+		return nil
+	}
+	lines := make([]string, len(failed))
+	for i, res := range failed {
+		lines[i] = fmt.Sprintf("%s %s: %v", res.Suite, res.Name, res.Err)
+	}
+	return fmt.Errorf("testvectors: %d of %d vectors failed:\n%s", len(failed), len(r.Results), strings.Join(lines, "\n"))
+}
+
+// Run runs the embedded vectors of the given suites, or of every suite when
+// none are given. The returned error reports unknown suites or unreadable
+// vectors; failing vectors are recorded in the report.
+func Run(suites ...Suite) (*Report, error) {
+	if len(suites) == 0 {
+		suites = Suites()
+	}
+
+	report := &Report{}
+	for _, suite := range suites {
+		data, err := testdata.ReadFile("testdata/" + string(suite) + ".json")
+		if err != nil {
+			return nil, fmt.Errorf("%w: %s", ErrUnknownSuite, suite)
+		}
+		results, err := RunSuite(suite, data)
+		if err != nil {
+			return nil, err
+		}
+		report.Results = append(report.Results, results...)
+	}
+	return report, nil
+}
+
+// RunSuite runs vectors for one suite from JSON data in the format of the
+// embedded files under testdata.
+func RunSuite(suite Suite, data []byte) ([]Result, error) {
+	var (
+		results []Result
+		err     error
+	)
+	switch suite {
+	case BIP39:
+		results, err = runBIP39(data)
+	case BIP32:
+		results, err = runBIP32(data)
+	case SLIP10:
+		results, err = runSLIP10(data)
+	case EIP55:
+		results, err = runEIP55(data)
+	case Bech32:
+		results, err = runBech32(data)
+	default:
+		return nil, fmt.Errorf("%w: %s", ErrUnknownSuite, suite)
+	}
+	if err != nil {
+		return nil, fmt.Errorf("testvectors: %s: %w", suite, err)
+	}
+	for i := range results {
+		results[i].Suite = suite
+	}
+	return results, nil
+}
+
+// check returns nil when got equals want and a mismatch error otherwise.
+func check(what, got, want string) error {
+	if got != want {
+		return fmt.Errorf("%s = %s, want %s", what, got, want)
+	}
+	return nil
+}
@@ -0,0 +1,61 @@
+package testvectors
+
+import (
+	"errors"
+	"strings"
+	"testing"
+)
+
+func TestRun(t *testing.T) {
+	report, err := Run()
+	if err != nil {
+		t.Fatalf("Run() error = %v", err)
+	}
+	if err := report.Err(); err != nil {
+		t.Fatal(err)
+	}
+
+	counts := make(map[Suite]int)
+	for _, res := range report.Results {
+		counts[res.Suite]++
+	}
+	for _, suite := range Suites() {
+		if counts[suite] == 0 {
+			t.Errorf("suite %s ran no vectors", suite)
+		}
+	}
+	if report.Passed() != len(report.Results) {
+		t.Errorf("Passed() = %d, want %d", report.Passed(), len(report.Results))
+	}
+}
+
+func TestRunSuiteReportsFailures(t *testing.T) {
+	data, err := testdata.ReadFile("testdata/bip39.json")
+	if err != nil {
+		t.Fatal(err)
+	}
+	// Corrupt the first seed: its vector fails, the others still pass
+	tampered := strings.Replace(string(data), "c55257c360c07c72", "d55257c360c07c72", 1)
+
+	results, err := RunSuite(BIP39, []byte(tampered))
+	if err != nil {
+		t.Fatalf("RunSuite() error = %v", err)
+	}
+	report := &Report{Results: results}
+	failed := report.Failed()
+	if len(failed) != 1 || failed[0].Suite != BIP39 || !strings.Contains(failed[0].Err.Error(), "seed") {
+		t.Errorf("Failed() = %v, want the first vector's seed", failed)
+	}
+	if report.Err() == nil || report.Passed() != len(results)-1 {
+		t.Errorf("Err() = %v, Passed() = %d", report.Err(), report.Passed())
+	}
+}
+
+func TestUnknownSuite(t *testing.T) {
+	if _, err := Run("bip85"); !errors.Is(err, ErrUnknownSuite) {
+		t.Errorf("Run(bip85) error = %v, want ErrUnknownSuite", err)
+	}
+	if _, err := RunSuite(BIP32, []byte("not json")); err == nil {
+		t.Error("RunSuite(bad JSON) error = nil")
+	}
+}
@@ -0,0 +1,268 @@
+// Package threshold implements FROST threshold signing (RFC 9591) for
+// secp256k1 Schnorr. A group of n participants holds shares of one key, and
+// any t of them sign together in two rounds without the key ever being
+// reassembled. Signatures are ordinary BIP-340 signatures of the x-only group
+// key, or of its Taproot output key, so they spend a P2TR output like a
+// single-signer key-path spend.
+//
+// Key generation uses a trusted dealer with Feldman commitments, so every
+// participant can check its share. The binding factors and nonces follow
+// RFC 9591 but are computed with BIP-340 tagged hashes rather than the RFC's
+// hash-to-field, so shares and signature shares do not interoperate with
+// other FROST implementations; the final signatures do.
+//
+// DealEd25519 and Ed25519KeyShare do the same for Ed25519 with RFC 9591's
+// FROST(Ed25519, SHA-512) ciphersuite, for Solana and Sui addresses
+// controlled by a group. Their signatures are standard Ed25519 signatures.
+package threshold
+
+import (
+	"crypto/rand"
+	"encoding/binary"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"math/big"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+var (
+	// ErrInvalidThreshold is returned for a threshold outside [2, participants].
+	ErrInvalidThreshold = errors.New("threshold: invalid threshold")
+
+	// ErrInvalidShare is returned for a key share that does not match its
+	// commitments.
+	ErrInvalidShare = errors.New("threshold: invalid key share")
+
+	// ErrInvalidCommitment is returned for a malformed or missing nonce commitment.
+	ErrInvalidCommitment = errors.New("threshold: invalid commitment")
+
+	// ErrNonceUsed is returned when a nonce is used to sign twice.
+	ErrNonceUsed = errors.New("threshold: nonce already used")
+
+	// ErrInvalidSignature is returned when the signature shares do not
+	// aggregate to a valid signature.
+	ErrInvalidSignature = errors.New("threshold: aggregate signature does not verify")
+)
+
+// MaxParticipants is the largest group Deal creates.
+const MaxParticipants = 1000
+
+// KeyShare is one participant's share of a group key. Secret must be kept
+// private; the other fields are the same for every participant. Printing a
+// KeyShare with any fmt verb redacts Secret, and JSON leaves it out; wrap the
+// share in a SecretKeyShare to serialize it with the secret.
+type KeyShare struct {
+	// ID is the participant's identifier, from 1 to the number of participants.
+	ID uint32 `json:"id"`
+
+	// Threshold is the number of participants needed to sign.
+	Threshold int `json:"threshold"`
+
+	// Secret is the 32-byte share of the group secret key.
+	Secret []byte `json:"-"`
+
+	// GroupKey is the 32-byte x-only group public key. The dealer picks the
+	// secret so that the full key has an even Y, as BIP-340 requires.
+	GroupKey []byte `json:"groupKey"`
+
+	// Commitments are the compressed Feldman commitments to the dealer's
+	// polynomial coefficients; the first one is the group key.
+	Commitments [][]byte `json:"commitments"`
+}
+
+// String describes the share with its secret redacted.
+func (k KeyShare) String() string {
+	return fmt.Sprintf("KeyShare{ID: %d, Threshold: %d, GroupKey: %x, Secret: %s}", k.ID, k.Threshold, k.GroupKey, secret.Redacted)
+}
+
+// GoString keeps %#v from printing the secret.
+func (k KeyShare) GoString() string {
+	return "threshold." + k.String()
+}
+
+// Format implements fmt.Formatter so that no verb prints the secret; %#v
+// uses GoString.
+func (k KeyShare) Format(f fmt.State, verb rune) {
+	formatShare(f, verb, k)
+}
+
+// SecretKeyShare opts a KeyShare in to JSON marshaling with its Secret, for
+// handing a share to its participant or backing it up.
+type SecretKeyShare struct {
+	*KeyShare
+}
+
+// MarshalJSON implements json.Marshaler, including the secret.
+func (k SecretKeyShare) MarshalJSON() ([]byte, error) {
+	if k.KeyShare == nil {
+		return nil, fmt.Errorf("%w: nil share", ErrInvalidShare)
+	}
+	type plain KeyShare
+	return json.Marshal(struct {
+		*plain
+		Secret []byte `json:"secret"`
+	}{(*plain)(k.KeyShare), k.Secret})
+}
+
+// UnmarshalJSON implements json.Unmarshaler, restoring the secret.
+func (k *SecretKeyShare) UnmarshalJSON(data []byte) error {
+	type plain KeyShare
+	var decoded struct {
+		plain
+		Secret []byte `json:"secret"`
+	}
+	if err := json.Unmarshal(data, &decoded); err != nil {
+		return err
+	}
+	share := KeyShare(decoded.plain)
+	share.Secret = decoded.Secret
+	k.KeyShare = &share
+	return nil
+}
+
+// Deal splits a fresh random key into shares for participants, any
+// threshold of which can sign. It reads from rnd, or from crypto/rand.Reader
+// when rnd is nil.
+func Deal(rnd io.Reader, threshold, participants int) ([]KeyShare, error) {
+	if participants > MaxParticipants || threshold < 2 || threshold > participants {
+		return nil, fmt.Errorf("%w: %d of %d", ErrInvalidThreshold, threshold, participants)
+	}
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+
+	coefficients := make([]*big.Int, threshold)
+	for i := range coefficients {
+		c, err := randomScalar(rnd)
+		if err != nil {
+			return nil, err
+		}
+		coefficients[i] = c
+	}
+
+	// Negating the polynomial negates the group key; keep the one with even Y
+	if secp256k1.ScalarBaseMult(scalarBytes(coefficients[0])).Y.Bit(0) == 1 {
+		for _, c := range coefficients {
+			c.Sub(secp256k1.N, c)
+		}
+	}
+
+	commitments := make([][]byte, threshold)
+	for i, c := range coefficients {
+		commitments[i] = secp256k1.CompressPoint(secp256k1.ScalarBaseMult(scalarBytes(c)))
+	}
+	groupKey := commitments[0][1:]
+
+	shares := make([]KeyShare, participants)
+	for i := range shares {
+		id := uint32(i + 1)
+		shares[i] = KeyShare{
+			ID:          id,
+			Threshold:   threshold,
+			Secret:      scalarBytes(evaluate(coefficients, id, secp256k1.N)),
+			GroupKey:    append([]byte(nil), groupKey...),
+			Commitments: commitments,
+		}
+	}
+	return shares, nil
+}
+
+// Verify checks the share against the dealer's commitments and group key.
+func (k *KeyShare) Verify() error {
+	if k.ID == 0 || k.Threshold < 2 || len(k.Commitments) != k.Threshold {
+		return fmt.Errorf("%w: malformed", ErrInvalidShare)
+	}
+	if len(k.Secret) != 32 || !secp256k1.IsValidPrivateKey(k.Secret) {
+		return fmt.Errorf("%w: secret out of range", ErrInvalidShare)
+	}
+	if len(k.GroupKey) != secp256k1.XOnlyPubKeyLen || len(k.Commitments[0]) != secp256k1.CompressedPubKeyLen ||
+		k.Commitments[0][0] != secp256k1.PrefixEven || string(k.Commitments[0][1:]) != string(k.GroupKey) {
+		return fmt.Errorf("%w: group key does not match commitments", ErrInvalidShare)
+	}
+	public, err := k.PublicShare()
+	if err != nil {
+		return err
+	}
+	if string(public) != string(secp256k1.PrivateKeyToCompressedPublicKey(k.Secret)) {
+		return fmt.Errorf("%w: secret does not match commitments", ErrInvalidShare)
+	}
+	return nil
+}
+
+// PublicShare returns the compressed public key of the participant's secret
+// share, computed from the commitments alone.
+func (k *KeyShare) PublicShare() ([]byte, error) {
+	return publicShare(k.Commitments, k.ID)
+}
+
+// publicShare evaluates the committed polynomial at id in the exponent:
+// the sum of C_j * id^j.
+func publicShare(commitments [][]byte, id uint32) ([]byte, error) {
+	x := new(big.Int).SetUint64(uint64(id))
+	power := big.NewInt(1)
+	sum := secp256k1.Infinity()
+	for _, c := range commitments {
+		p, err := secp256k1.DecompressPoint(c)
+		if err != nil {
+			return nil, fmt.Errorf("%w: bad commitment", ErrInvalidShare)
+		}
+		sum = secp256k1.Add(sum, secp256k1.ScalarMult(p, power))
+		power = new(big.Int).Mul(power, x)
+		power.Mod(power, secp256k1.N)
+	}
+	if sum.IsInfinity() {
+		return nil, fmt.Errorf("%w: public share is infinity", ErrInvalidShare)
+	}
+	return secp256k1.CompressPoint(sum), nil
+}
+
+// evaluate returns f(id) mod n for the polynomial with the given coefficients.
+func evaluate(coefficients []*big.Int, id uint32, n *big.Int) *big.Int {
+	x := new(big.Int).SetUint64(uint64(id))
+	result := new(big.Int)
+	for i := len(coefficients) - 1; i >= 0; i-- {
+		result.Mul(result, x)
+		result.Add(result, coefficients[i])
+		result.Mod(result, n)
+	}
+	return result
+}
+
+// randomScalar reads a random scalar in [1, n-1] from rnd.
+func randomScalar(rnd io.Reader) (*big.Int, error) {
+	b := make([]byte, 32)
+	for {
+		if _, err := io.ReadFull(rnd, b); err != nil {
+			return nil, fmt.Errorf("threshold: reading randomness: %w", err)
+		}
+		if secp256k1.IsValidPrivateKey(b) {
+			return new(big.Int).SetBytes(b), nil
+		}
+	}
+}
+
+// scalarBytes encodes n as 32 big-endian bytes.
+func scalarBytes(n *big.Int) []byte {
+	return n.FillBytes(make([]byte, 32))
+}
+
+// formatShare writes GoString for %#v and String for every other verb.
+func formatShare(f fmt.State, verb rune, share interface {
+	fmt.Stringer
+	fmt.GoStringer
+}) {
+	if verb == 'v' && f.Flag('#') {
+		io.WriteString(f, share.GoString())
+		return
+	}
+	io.WriteString(f, share.String())
+}
+
+// idBytes encodes a participant id for hashing.
+func idBytes(id uint32) []byte {
+	return binary.BigEndian.AppendUint32(nil, id)
+}
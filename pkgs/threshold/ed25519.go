@@ -0,0 +1,392 @@
+package threshold
+
+import (
+	"crypto/rand"
+	"crypto/sha512"
+	"encoding/json"
+	"fmt"
+	"io"
+	"math/big"
+	"slices"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// The Ed25519 variant follows the FROST(Ed25519, SHA-512) ciphersuite of
+// RFC 9591 exactly, so its shares, commitments and signature shares
+// interoperate with other implementations of the ciphersuite. Signatures are
+// plain RFC 8032 Ed25519 signatures of the group key, which is used directly
+// as a Solana or Sui public key. It reuses Commitment, SigningPackage and
+// SignatureShare; the rounds are the same as for secp256k1.
+
+// ed25519Context is the RFC 9591 context string of the ciphersuite.
+const ed25519Context = "FROST-ED25519-SHA512-v1"
+
+// edOrder is the order L of the Ed25519 scalar group.
+var edOrder = ed25519.Order()
+
+// Ed25519KeyShare is one participant's share of an Ed25519 group key.
+// Secret must be kept private; the other fields are the same for every
+// participant. As with KeyShare, printing redacts Secret and JSON leaves it
+// out; wrap the share in a SecretEd25519KeyShare to serialize it.
+type Ed25519KeyShare struct {
+	// ID is the participant's identifier, from 1 to the number of participants.
+	ID uint32 `json:"id"`
+
+	// Threshold is the number of participants needed to sign.
+	Threshold int `json:"threshold"`
+
+	// Secret is the 32-byte little-endian share of the group secret scalar.
+	Secret []byte `json:"-"`
+
+	// GroupKey is the 32-byte Ed25519 group public key.
+	GroupKey []byte `json:"groupKey"`
+
+	// Commitments are the encoded Feldman commitments to the dealer's
+	// polynomial coefficients; the first one is the group key.
+	Commitments [][]byte `json:"commitments"`
+}
+
+// String describes the share with its secret redacted.
+func (k Ed25519KeyShare) String() string {
+	return fmt.Sprintf("Ed25519KeyShare{ID: %d, Threshold: %d, GroupKey: %x, Secret: %s}", k.ID, k.Threshold, k.GroupKey, secret.Redacted)
+}
+
+// GoString keeps %#v from printing the secret.
+func (k Ed25519KeyShare) GoString() string {
+	return "threshold." + k.String()
+}
+
+// Format implements fmt.Formatter so that no verb prints the secret; %#v
+// uses GoString.
+func (k Ed25519KeyShare) Format(f fmt.State, verb rune) {
+	formatShare(f, verb, k)
+}
+
+// SecretEd25519KeyShare opts an Ed25519KeyShare in to JSON marshaling with
+// its Secret.
+type SecretEd25519KeyShare struct {
+	*Ed25519KeyShare
+}
+
+// MarshalJSON implements json.Marshaler, including the secret.
+func (k SecretEd25519KeyShare) MarshalJSON() ([]byte, error) {
+	if k.Ed25519KeyShare == nil {
+		return nil, fmt.Errorf("%w: nil share", ErrInvalidShare)
+	}
+	type plain Ed25519KeyShare
+	return json.Marshal(struct {
+		*plain
+		Secret []byte `json:"secret"`
+	}{(*plain)(k.Ed25519KeyShare), k.Secret})
+}
+
+// UnmarshalJSON implements json.Unmarshaler, restoring the secret.
+func (k *SecretEd25519KeyShare) UnmarshalJSON(data []byte) error {
+	type plain Ed25519KeyShare
+	var decoded struct {
+		plain
+		Secret []byte `json:"secret"`
+	}
+	if err := json.Unmarshal(data, &decoded); err != nil {
+		return err
+	}
+	share := Ed25519KeyShare(decoded.plain)
+	share.Secret = decoded.Secret
+	k.Ed25519KeyShare = &share
+	return nil
+}
+
+// DealEd25519 splits a fresh random Ed25519 scalar into shares for
+// participants, any threshold of which can sign. It reads from rnd, or from
+// crypto/rand.Reader when rnd is nil. The group secret is a scalar rather
+// than an RFC 8032 seed, so it cannot be imported into a single-key wallet.
+func DealEd25519(rnd io.Reader, threshold, participants int) ([]Ed25519KeyShare, error) {
+	if participants > MaxParticipants || threshold < 2 || threshold > participants {
+		return nil, fmt.Errorf("%w: %d of %d", ErrInvalidThreshold, threshold, participants)
+	}
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+
+	coefficients := make([]*big.Int, threshold)
+	commitments := make([][]byte, threshold)
+	for i := range coefficients {
+		c, err := randomEdScalar(rnd)
+		if err != nil {
+			return nil, err
+		}
+		coefficients[i] = c
+		commitments[i] = edBaseMult(c).Bytes()
+	}
+
+	shares := make([]Ed25519KeyShare, participants)
+	for i := range shares {
+		id := uint32(i + 1)
+		shares[i] = Ed25519KeyShare{
+			ID:          id,
+			Threshold:   threshold,
+			Secret:      edScalarBytes(evaluate(coefficients, id, edOrder)),
+			GroupKey:    append([]byte(nil), commitments[0]...),
+			Commitments: commitments,
+		}
+	}
+	return shares, nil
+}
+
+// Verify checks the share against the dealer's commitments and group key.
+func (k *Ed25519KeyShare) Verify() error {
+	if k.ID == 0 || k.Threshold < 2 || len(k.Commitments) != k.Threshold {
+		return fmt.Errorf("%w: malformed", ErrInvalidShare)
+	}
+	scalar := ed25519.ScalarFromBytes(k.Secret)
+	if len(k.Secret) != 32 || scalar.Sign() == 0 || scalar.Cmp(edOrder) >= 0 {
+		return fmt.Errorf("%w: secret out of range", ErrInvalidShare)
+	}
+	if string(k.Commitments[0]) != string(k.GroupKey) {
+		return fmt.Errorf("%w: group key does not match commitments", ErrInvalidShare)
+	}
+	public, err := k.PublicShare()
+	if err != nil {
+		return err
+	}
+	if string(public) != string(edBaseMult(scalar).Bytes()) {
+		return fmt.Errorf("%w: secret does not match commitments", ErrInvalidShare)
+	}
+	return nil
+}
+
+// PublicShare returns the encoded public key of the participant's secret
+// share, computed from the commitments alone.
+func (k *Ed25519KeyShare) PublicShare() ([]byte, error) {
+	x := new(big.Int).SetUint64(uint64(k.ID))
+	power := big.NewInt(1)
+	sum := ed25519.Identity()
+	for _, c := range k.Commitments {
+		p, err := decodeEdElement(c)
+		if err != nil {
+			return nil, fmt.Errorf("%w: bad commitment", ErrInvalidShare)
+		}
+		sum = ed25519.Add(sum, ed25519.ScalarMult(p, power))
+		power = new(big.Int).Mul(power, x)
+		power.Mod(power, edOrder)
+	}
+	if sum.Equal(ed25519.Identity()) {
+		return nil, fmt.Errorf("%w: public share is the identity", ErrInvalidShare)
+	}
+	return sum.Bytes(), nil
+}
+
+// Ed25519Nonce is a signer's secret nonce pair for one Ed25519 signature.
+// Sign consumes it.
+type Ed25519Nonce struct {
+	id              uint32
+	hiding, binding *big.Int
+}
+
+// Commit generates a nonce pair for one signature, hashing 32 fresh bytes
+// from rnd, or crypto/rand.Reader when rnd is nil, with the share's secret
+// as RFC 9591's nonce_generate does.
+func (k *Ed25519KeyShare) Commit(rnd io.Reader) (*Ed25519Nonce, Commitment, error) {
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+	nonce := &Ed25519Nonce{id: k.ID}
+	for _, n := range []**big.Int{&nonce.hiding, &nonce.binding} {
+		random := make([]byte, 32)
+		if _, err := io.ReadFull(rnd, random); err != nil {
+			return nil, Commitment{}, fmt.Errorf("threshold: reading randomness: %w", err)
+		}
+		*n = edHash([]byte(ed25519Context+"nonce"), random, k.Secret)
+		if (*n).Sign() == 0 {
+			return nil, Commitment{}, fmt.Errorf("threshold: nonce is zero")
+		}
+	}
+	return nonce, nonce.commitment(), nil
+}
+
+// commitment returns the public commitment to the nonce pair.
+func (n *Ed25519Nonce) commitment() Commitment {
+	return Commitment{
+		ID:      n.id,
+		Hiding:  edBaseMult(n.hiding).Bytes(),
+		Binding: edBaseMult(n.binding).Bytes(),
+	}
+}
+
+// Sign computes the share's signature share for pkg with a nonce from
+// Commit. pkg.Tweak must be empty. The nonce is consumed even on error.
+func (k *Ed25519KeyShare) Sign(nonce *Ed25519Nonce, pkg SigningPackage) (SignatureShare, error) {
+	if nonce == nil || nonce.hiding == nil {
+		return SignatureShare{}, ErrNonceUsed
+	}
+	hiding, binding := nonce.hiding, nonce.binding
+	own := nonce.commitment()
+	nonce.hiding, nonce.binding = nil, nil
+
+	if nonce.id != k.ID {
+		return SignatureShare{}, fmt.Errorf("%w: nonce belongs to participant %d", ErrInvalidCommitment, nonce.id)
+	}
+	s, err := newEdSession(k.GroupKey, k.Threshold, pkg)
+	if err != nil {
+		return SignatureShare{}, err
+	}
+	i, ok := commitmentIndex(s.commitments, k.ID)
+	if !ok || string(s.commitments[i].Hiding) != string(own.Hiding) || string(s.commitments[i].Binding) != string(own.Binding) {
+		return SignatureShare{}, fmt.Errorf("%w: package does not carry this nonce's commitment", ErrInvalidCommitment)
+	}
+
+	// z = d + e * rho + lambda * s * c
+	z := new(big.Int).Mul(binding, s.rho[i])
+	z.Add(z, hiding)
+	term := new(big.Int).Mul(lagrange(s.commitments, k.ID, edOrder), ed25519.ScalarFromBytes(k.Secret))
+	term.Mul(term, s.challenge)
+	z.Add(z, term)
+	z.Mod(z, edOrder)
+	return SignatureShare{ID: k.ID, Z: edScalarBytes(z)}, nil
+}
+
+// AggregateEd25519 combines one signature share per commitment in pkg into
+// a 64-byte Ed25519 signature of pkg.Message under the group key. It returns
+// ErrInvalidSignature if a share is wrong.
+func AggregateEd25519(groupKey []byte, threshold int, pkg SigningPackage, shares []SignatureShare) ([]byte, error) {
+	s, err := newEdSession(groupKey, threshold, pkg)
+	if err != nil {
+		return nil, err
+	}
+	if len(shares) != len(s.commitments) {
+		return nil, fmt.Errorf("%w: %d shares for %d commitments", ErrInvalidSignature, len(shares), len(s.commitments))
+	}
+
+	sum := new(big.Int)
+	seen := make(map[uint32]bool, len(shares))
+	for _, share := range shares {
+		if _, ok := commitmentIndex(s.commitments, share.ID); !ok || seen[share.ID] || len(share.Z) != 32 {
+			return nil, fmt.Errorf("%w: unexpected share from participant %d", ErrInvalidSignature, share.ID)
+		}
+		seen[share.ID] = true
+		sum.Add(sum, ed25519.ScalarFromBytes(share.Z))
+	}
+	sum.Mod(sum, edOrder)
+
+	sig := append(s.r.Bytes(), edScalarBytes(sum)...)
+	if !ed25519.Verify(groupKey, pkg.Message, sig) {
+		return nil, ErrInvalidSignature
+	}
+	return sig, nil
+}
+
+// edSession holds the values of one Ed25519 signing package that signers
+// and the coordinator compute alike.
+type edSession struct {
+	commitments []Commitment // sorted by ID
+	rho         []*big.Int   // binding factor per commitment
+	r           *ed25519.Point
+	challenge   *big.Int
+}
+
+func newEdSession(groupKey []byte, threshold int, pkg SigningPackage) (*edSession, error) {
+	if len(pkg.Commitments) < threshold {
+		return nil, fmt.Errorf("%w: %d commitments, need %d", ErrInvalidCommitment, len(pkg.Commitments), threshold)
+	}
+	if pkg.Tweak != nil {
+		return nil, fmt.Errorf("threshold: Ed25519 signing does not support tweaks")
+	}
+	if _, err := decodeEdElement(groupKey); err != nil {
+		return nil, fmt.Errorf("%w: group key", ErrInvalidShare)
+	}
+	s := &edSession{commitments: slices.Clone(pkg.Commitments)}
+	slices.SortFunc(s.commitments, func(a, b Commitment) int { return int(int64(a.ID) - int64(b.ID)) })
+
+	// encode_group_commitment_list
+	var encoded []byte
+	for i, c := range s.commitments {
+		if c.ID == 0 || (i > 0 && c.ID == s.commitments[i-1].ID) {
+			return nil, fmt.Errorf("%w: participant id %d repeated or zero", ErrInvalidCommitment, c.ID)
+		}
+		encoded = append(encoded, edIdentifier(c.ID)...)
+		encoded = append(encoded, c.Hiding...)
+		encoded = append(encoded, c.Binding...)
+	}
+	messageHash := sha512.Sum512(append([]byte(ed25519Context+"msg"), pkg.Message...))
+	commitmentsHash := sha512.Sum512(append([]byte(ed25519Context+"com"), encoded...))
+
+	// R = sum of D_i + rho_i * E_i
+	s.r = ed25519.Identity()
+	for _, c := range s.commitments {
+		d, err := decodeEdElement(c.Hiding)
+		if err != nil {
+			return nil, fmt.Errorf("%w: participant %d hiding nonce", ErrInvalidCommitment, c.ID)
+		}
+		e, err := decodeEdElement(c.Binding)
+		if err != nil {
+			return nil, fmt.Errorf("%w: participant %d binding nonce", ErrInvalidCommitment, c.ID)
+		}
+		rho := edHash([]byte(ed25519Context+"rho"), groupKey, messageHash[:], commitmentsHash[:], edIdentifier(c.ID))
+		s.rho = append(s.rho, rho)
+		s.r = ed25519.Add(s.r, ed25519.Add(d, ed25519.ScalarMult(e, rho)))
+	}
+	if s.r.Equal(ed25519.Identity()) {
+		return nil, fmt.Errorf("%w: group commitment is the identity", ErrInvalidCommitment)
+	}
+
+	// The RFC 8032 challenge, without a context string
+	s.challenge = edHash(s.r.Bytes(), groupKey, pkg.Message)
+	return s, nil
+}
+
+// decodeEdElement decodes a point, rejecting the identity and points outside
+// the prime-order subgroup as RFC 9591's DeserializeElement does.
+func decodeEdElement(b []byte) (*ed25519.Point, error) {
+	p, err := ed25519.DecodePoint(b)
+	if err != nil {
+		return nil, err
+	}
+	if p.Equal(ed25519.Identity()) || !ed25519.ScalarMult(p, edOrder).Equal(ed25519.Identity()) {
+		return nil, ed25519.ErrInvalidPoint
+	}
+	return p, nil
+}
+
+// edHash returns SHA-512 of the concatenated parts as a scalar mod L.
+func edHash(parts ...[]byte) *big.Int {
+	h := sha512.New()
+	for _, part := range parts {
+		h.Write(part)
+	}
+	k := ed25519.ScalarFromBytes(h.Sum(nil))
+	return k.Mod(k, edOrder)
+}
+
+// edBaseMult returns k*B.
+func edBaseMult(k *big.Int) *ed25519.Point {
+	return ed25519.ScalarMult(ed25519.BasePoint(), k)
+}
+
+// randomEdScalar reads a uniformly random non-zero scalar from rnd.
+func randomEdScalar(rnd io.Reader) (*big.Int, error) {
+	b := make([]byte, 64)
+	for {
+		if _, err := io.ReadFull(rnd, b); err != nil {
+			return nil, fmt.Errorf("threshold: reading randomness: %w", err)
+		}
+		k := ed25519.ScalarFromBytes(b)
+		if k.Mod(k, edOrder).Sign() != 0 {
+			return k, nil
+		}
+	}
+}
+
+// edScalarBytes encodes n as a 32-byte little-endian scalar.
+func edScalarBytes(n *big.Int) []byte {
+	b := n.FillBytes(make([]byte, 32))
+	slices.Reverse(b)
+	return b
+}
+
+// edIdentifier encodes a participant id as RFC 9591 serializes identifiers:
+// a 32-byte little-endian scalar.
+func edIdentifier(id uint32) []byte {
+	return edScalarBytes(new(big.Int).SetUint64(uint64(id)))
+}
@@ -0,0 +1,205 @@
+package threshold
+
+import (
+	"bytes"
+	stded25519 "crypto/ed25519"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// signEd25519 runs both rounds for the given signers and aggregates the shares.
+func signEd25519(t *testing.T, signers []Ed25519KeyShare, message []byte) ([]byte, error) {
+	t.Helper()
+	nonces := make([]*Ed25519Nonce, len(signers))
+	pkg := SigningPackage{Message: message}
+	for i := range signers {
+		nonce, commitment, err := signers[i].Commit(nil)
+		if err != nil {
+			t.Fatalf("Commit() error = %v", err)
+		}
+		nonces[i] = nonce
+		pkg.Commitments = append(pkg.Commitments, commitment)
+	}
+	var shares []SignatureShare
+	for i := range signers {
+		share, err := signers[i].Sign(nonces[i], pkg)
+		if err != nil {
+			t.Fatalf("Sign(%d) error = %v", signers[i].ID, err)
+		}
+		shares = append(shares, share)
+	}
+	return AggregateEd25519(signers[0].GroupKey, signers[0].Threshold, pkg, shares)
+}
+
+func mustHex(t *testing.T, s string) []byte {
+	t.Helper()
+	b, err := hex.DecodeString(s)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return b
+}
+
+// RFC 9591 Appendix E.1, FROST(Ed25519, SHA-512), participants 1 and 3
+func TestEd25519RFC9591Vector(t *testing.T) {
+	groupKey := mustHex(t, "15d21ccd7ee42959562fc8aa63224c8851fb3ec85a3faf66040d380fb9738673")
+	commitments := [][]byte{groupKey, mustHex(t, "6e4226d69664a098507f8b7de582bdd55f6763e54fdec46a061dc4df8a93160f")}
+	signers := []struct {
+		share      Ed25519KeyShare
+		randomness string
+		hiding     string
+		binding    string
+		z          string
+	}{
+		{
+			share:      Ed25519KeyShare{ID: 1, Secret: mustHex(t, "929dcc590407aae7d388761cddb0c0db6f5627aea8e217f4a033f2ec83d93509")},
+			randomness: "0fd2e39e111cdc266f6c0f4d0fd45c947761f1f5d3cb583dfcb9bbaf8d4c9fec69cd85f631d5f7f2721ed5e40519b1366f340a87c2f6856363dbdcda348a7501",
+			hiding:     "b5aa8ab305882a6fc69cbee9327e5a45e54c08af61ae77cb8207be3d2ce13de3",
+			binding:    "67e98ab55aa310c3120418e5050c9cf76cf387cb20ac9e4b6fdb6f82a469f932",
+			z:          "001719ab5a53ee1a12095cd088fd149702c0720ce5fd2f29dbecf24b7281b603",
+		},
+		{
+			share:      Ed25519KeyShare{ID: 3, Secret: mustHex(t, "d3cb090a075eb154e82fdb4b3cb507f110040905468bb9c46da8bdea643a9a02")},
+			randomness: "86d64a260059e495d0fb4fcc17ea3da7452391baa494d4b00321098ed2a0062f13e6b25afb2eba51716a9a7d44130c0dbae0004a9ef8d7b5550c8a0e07c61775",
+			hiding:     "cfbdb165bd8aad6eb79deb8d287bcc0ab6658ae57fdcc98ed12c0669e90aec91",
+			binding:    "7487bc41a6e712eea2f2af24681b58b1cf1da278ea11fe4e8b78398965f13552",
+			z:          "bd86125de990acc5e1f13781d8e32c03a9bbd4c53539bbc106058bfd14326007",
+		},
+	}
+
+	message := []byte("test")
+	pkg := SigningPackage{Message: message}
+	nonces := make([]*Ed25519Nonce, len(signers))
+	for i := range signers {
+		s := &signers[i]
+		s.share.Threshold, s.share.GroupKey, s.share.Commitments = 2, groupKey, commitments
+		if err := s.share.Verify(); err != nil {
+			t.Fatalf("share %d Verify() error = %v", s.share.ID, err)
+		}
+		nonce, commitment, err := s.share.Commit(bytes.NewReader(mustHex(t, s.randomness)))
+		if err != nil {
+			t.Fatalf("Commit() error = %v", err)
+		}
+		if hex.EncodeToString(commitment.Hiding) != s.hiding || hex.EncodeToString(commitment.Binding) != s.binding {
+			t.Errorf("share %d commitment = %x %x", s.share.ID, commitment.Hiding, commitment.Binding)
+		}
+		nonces[i] = nonce
+		pkg.Commitments = append(pkg.Commitments, commitment)
+	}
+
+	var shares []SignatureShare
+	for i, s := range signers {
+		share, err := s.share.Sign(nonces[i], pkg)
+		if err != nil {
+			t.Fatalf("Sign(%d) error = %v", s.share.ID, err)
+		}
+		if hex.EncodeToString(share.Z) != s.z {
+			t.Errorf("share %d z = %x, want %s", s.share.ID, share.Z, s.z)
+		}
+		shares = append(shares, share)
+	}
+
+	sig, err := AggregateEd25519(groupKey, 2, pkg, shares)
+	if err != nil {
+		t.Fatalf("AggregateEd25519() error = %v", err)
+	}
+	want := "36282629c383bb820a88b71cae937d41f2f2adfcc3d02e55507e2fb9e2dd3cbebd9d2b0844e49ae0f3fa935161e1419aab7b47d21a37ebeae1f17d4987b3160b"
+	if hex.EncodeToString(sig) != want {
+		t.Errorf("signature = %x, want %s", sig, want)
+	}
+}
+
+func TestEd25519TwoOfThree(t *testing.T) {
+	shares, err := DealEd25519(nil, 2, 3)
+	if err != nil {
+		t.Fatalf("DealEd25519() error = %v", err)
+	}
+	for _, share := range shares {
+		if err := share.Verify(); err != nil {
+			t.Errorf("share %d Verify() error = %v", share.ID, err)
+		}
+	}
+
+	groupKey := shares[0].GroupKey
+	if addr, err := address.NewSolanaAddress().Generate(groupKey); err != nil || addr == "" {
+		t.Errorf("Solana address of the group key = %q, %v", addr, err)
+	}
+
+	message := []byte("transfer 1 SOL")
+	for _, pair := range [][2]int{{0, 1}, {0, 2}, {2, 1}} {
+		sig, err := signEd25519(t, []Ed25519KeyShare{shares[pair[0]], shares[pair[1]]}, message)
+		if err != nil {
+			t.Fatalf("signers %v: AggregateEd25519() error = %v", pair, err)
+		}
+		if !stded25519.Verify(groupKey, message, sig) {
+			t.Errorf("signers %v: signature does not verify", pair)
+		}
+	}
+
+	if _, err := signEd25519(t, shares[:1], message); !errors.Is(err, ErrInvalidCommitment) {
+		t.Errorf("one signer: error = %v, want ErrInvalidCommitment", err)
+	}
+
+	// Tweaks are a Taproot feature and are rejected
+	nonce, c, _ := shares[0].Commit(nil)
+	_, c2, _ := shares[1].Commit(nil)
+	pkg := SigningPackage{Message: message, Commitments: []Commitment{c, c2}, Tweak: make([]byte, 32)}
+	if _, err := shares[0].Sign(nonce, pkg); err == nil {
+		t.Error("Sign() accepted a tweak")
+	}
+
+	// A corrupted share makes aggregation fail
+	nonce1, c1, _ := shares[0].Commit(nil)
+	nonce2, c2, _ := shares[1].Commit(nil)
+	pkg = SigningPackage{Message: message, Commitments: []Commitment{c1, c2}}
+	z1, _ := shares[0].Sign(nonce1, pkg)
+	z2, _ := shares[1].Sign(nonce2, pkg)
+	z2.Z[0] ^= 1
+	if _, err := AggregateEd25519(groupKey, 2, pkg, []SignatureShare{z1, z2}); !errors.Is(err, ErrInvalidSignature) {
+		t.Errorf("AggregateEd25519() with a bad share error = %v, want ErrInvalidSignature", err)
+	}
+}
+
+func TestEd25519KeyShareSecret(t *testing.T) {
+	shares, _ := DealEd25519(nil, 2, 3)
+	share := shares[1]
+	secretHex := hex.EncodeToString(share.Secret)
+	secretDecimal := strings.Trim(fmt.Sprint(share.Secret), "[]")
+
+	for _, verb := range []string{"%v", "%+v", "%#v", "%s", "%d", "%x"} {
+		for _, v := range []any{share, &share, shares, SecretEd25519KeyShare{&share}} {
+			if out := fmt.Sprintf(verb, v); strings.Contains(out, secretHex) || strings.Contains(out, secretDecimal) {
+				t.Errorf("Sprintf(%s, %T) leaked the secret: %s", verb, v, out)
+			}
+		}
+	}
+
+	data, err := json.Marshal(share)
+	if err != nil {
+		t.Fatalf("json.Marshal() error = %v", err)
+	}
+	if strings.Contains(string(data), `"secret"`) {
+		t.Errorf("json.Marshal(Ed25519KeyShare) wrote the secret: %s", data)
+	}
+
+	data, err = json.Marshal(SecretEd25519KeyShare{&share})
+	if err != nil {
+		t.Fatalf("json.Marshal(SecretEd25519KeyShare) error = %v", err)
+	}
+	var restored SecretEd25519KeyShare
+	if err := json.Unmarshal(data, &restored); err != nil {
+		t.Fatalf("json.Unmarshal(SecretEd25519KeyShare) error = %v", err)
+	}
+	if !bytes.Equal(restored.Secret, share.Secret) || restored.ID != share.ID || !bytes.Equal(restored.GroupKey, share.GroupKey) {
+		t.Errorf("SecretEd25519KeyShare round trip = %v", restored)
+	}
+	if err := restored.Verify(); err != nil {
+		t.Errorf("Verify() of the restored share error = %v", err)
+	}
+}
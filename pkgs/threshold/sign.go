@@ -0,0 +1,305 @@
+package threshold
+
+import (
+	"crypto/rand"
+	"fmt"
+	"io"
+	"math/big"
+	"slices"
+
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+// Signing takes two rounds. In the first, each signer calls Commit and sends
+// its Commitment to the coordinator, keeping the Nonce. In the second, the
+// coordinator sends every signer the same SigningPackage, each returns the
+// SignatureShare from Sign, and the coordinator combines them with Aggregate.
+
+// Nonce is a signer's secret nonce pair for one signature. Sign consumes it.
+type Nonce struct {
+	id              uint32
+	hiding, binding *big.Int
+}
+
+// Commitment is the public half of a Nonce: the compressed points D = d*G
+// and E = e*G.
+type Commitment struct {
+	ID      uint32 `json:"id"`
+	Hiding  []byte `json:"hiding"`
+	Binding []byte `json:"binding"`
+}
+
+// SigningPackage is what the coordinator sends to the signers in round two.
+type SigningPackage struct {
+	// Message is the message to sign, such as a BIP-341 sighash.
+	Message []byte `json:"message"`
+
+	// Commitments holds one commitment from each signer, at least the
+	// threshold of them.
+	Commitments []Commitment `json:"commitments"`
+
+	// Tweak, when set, is a 32-byte scalar added to the group key. Use
+	// TaprootTweak to sign for a Taproot output key.
+	Tweak []byte `json:"tweak,omitempty"`
+}
+
+// SignatureShare is one signer's contribution to the signature.
+type SignatureShare struct {
+	ID uint32 `json:"id"`
+	Z  []byte `json:"z"`
+}
+
+// Commit generates a nonce pair for one signature. The nonces mix fresh
+// randomness from rnd, or crypto/rand.Reader when rnd is nil, with the
+// share's secret, as RFC 9591 does to survive a weak random source.
+func (k *KeyShare) Commit(rnd io.Reader) (*Nonce, Commitment, error) {
+	if rnd == nil {
+		rnd = rand.Reader
+	}
+	nonce := &Nonce{id: k.ID}
+	for _, n := range []**big.Int{&nonce.hiding, &nonce.binding} {
+		random := make([]byte, 32)
+		if _, err := io.ReadFull(rnd, random); err != nil {
+			return nil, Commitment{}, fmt.Errorf("threshold: reading randomness: %w", err)
+		}
+		*n = new(big.Int).SetBytes(secp256k1.TaggedHash("FROST/nonce", random, k.Secret))
+		(*n).Mod(*n, secp256k1.N)
+		if (*n).Sign() == 0 {
+			return nil, Commitment{}, fmt.Errorf("threshold: nonce is zero")
+		}
+	}
+	return nonce, nonce.commitment(), nil
+}
+
+// commitment returns the public commitment to the nonce pair.
+func (n *Nonce) commitment() Commitment {
+	return Commitment{
+		ID:      n.id,
+		Hiding:  secp256k1.CompressPoint(secp256k1.ScalarBaseMult(scalarBytes(n.hiding))),
+		Binding: secp256k1.CompressPoint(secp256k1.ScalarBaseMult(scalarBytes(n.binding))),
+	}
+}
+
+// Sign computes the share's signature share for pkg with a nonce from Commit.
+// The nonce is consumed even on error, since reusing one with two different
+// packages reveals the secret share.
+func (k *KeyShare) Sign(nonce *Nonce, pkg SigningPackage) (SignatureShare, error) {
+	if nonce == nil || nonce.hiding == nil {
+		return SignatureShare{}, ErrNonceUsed
+	}
+	hiding, binding := nonce.hiding, nonce.binding
+	own := nonce.commitment()
+	nonce.hiding, nonce.binding = nil, nil
+
+	if nonce.id != k.ID {
+		return SignatureShare{}, fmt.Errorf("%w: nonce belongs to participant %d", ErrInvalidCommitment, nonce.id)
+	}
+	s, err := newSession(k.GroupKey, k.Threshold, pkg)
+	if err != nil {
+		return SignatureShare{}, err
+	}
+	i, ok := s.index(k.ID)
+	if !ok || string(s.commitments[i].Hiding) != string(own.Hiding) || string(s.commitments[i].Binding) != string(own.Binding) {
+		return SignatureShare{}, fmt.Errorf("%w: package does not carry this nonce's commitment", ErrInvalidCommitment)
+	}
+
+	// z = g_R * (d + e * rho) + lambda * s * c * g_Q
+	z := new(big.Int).Mul(binding, s.rho[i])
+	z.Add(z, hiding)
+	if s.negateR {
+		z.Neg(z)
+	}
+	term := new(big.Int).Mul(s.lagrange(k.ID), new(big.Int).SetBytes(k.Secret))
+	term.Mul(term, s.challenge)
+	if s.negateQ {
+		term.Neg(term)
+	}
+	z.Add(z, term)
+	z.Mod(z, secp256k1.N)
+	return SignatureShare{ID: k.ID, Z: scalarBytes(z)}, nil
+}
+
+// Aggregate combines one signature share per commitment in pkg into a
+// 64-byte BIP-340 signature of pkg.Message under the group key, or under
+// its tweaked key when pkg.Tweak is set. It returns ErrInvalidSignature if
+// a share is wrong.
+func Aggregate(groupKey []byte, threshold int, pkg SigningPackage, shares []SignatureShare) ([]byte, error) {
+	s, err := newSession(groupKey, threshold, pkg)
+	if err != nil {
+		return nil, err
+	}
+	if len(shares) != len(s.commitments) {
+		return nil, fmt.Errorf("%w: %d shares for %d commitments", ErrInvalidSignature, len(shares), len(s.commitments))
+	}
+
+	sum := new(big.Int)
+	seen := make(map[uint32]bool, len(shares))
+	for _, share := range shares {
+		if _, ok := s.index(share.ID); !ok || seen[share.ID] || len(share.Z) != 32 {
+			return nil, fmt.Errorf("%w: unexpected share from participant %d", ErrInvalidSignature, share.ID)
+		}
+		seen[share.ID] = true
+		sum.Add(sum, new(big.Int).SetBytes(share.Z))
+	}
+
+	// The tweak's part of the key is public, so the coordinator adds c * t
+	if s.tweak != nil {
+		ct := new(big.Int).Mul(s.challenge, s.tweak)
+		if s.negateQ {
+			ct.Neg(ct)
+		}
+		sum.Add(sum, ct)
+	}
+	sum.Mod(sum, secp256k1.N)
+
+	sig := append(scalarBytes(s.r.X), scalarBytes(sum)...)
+	if !secp256k1.SchnorrVerify(s.key, pkg.Message, sig) {
+		return nil, ErrInvalidSignature
+	}
+	return sig, nil
+}
+
+// TaprootTweak returns the BIP-341 tweak of an x-only internal key for a
+// script tree with the given Merkle root. An empty root gives the BIP-86
+// key-path-only tweak.
+func TaprootTweak(internalKey, merkleRoot []byte) []byte {
+	return secp256k1.TaggedHash("TapTweak", internalKey, merkleRoot)
+}
+
+// TweakedKey returns the x-only key P + t*G that signatures made with tweak
+// verify under; for a Taproot tweak it is the output key to pass to
+// address.BitcoinAddress.P2TR.
+func TweakedKey(groupKey, tweak []byte) ([]byte, error) {
+	q, _, err := tweakKey(groupKey, tweak)
+	if err != nil {
+		return nil, err
+	}
+	return scalarBytes(q.X), nil
+}
+
+// tweakKey lifts the x-only group key and adds tweak*G, returning the point
+// and the tweak as a scalar (nil for no tweak).
+func tweakKey(groupKey, tweak []byte) (*secp256k1.Point, *big.Int, error) {
+	p, err := secp256k1.LiftX(groupKey)
+	if err != nil {
+		return nil, nil, fmt.Errorf("%w: group key", ErrInvalidShare)
+	}
+	if tweak == nil {
+		return p, nil, nil
+	}
+	t := new(big.Int).SetBytes(tweak)
+	if len(tweak) != 32 || t.Cmp(secp256k1.N) >= 0 {
+		return nil, nil, fmt.Errorf("threshold: tweak is not a scalar")
+	}
+	q := secp256k1.Add(p, secp256k1.ScalarBaseMult(tweak))
+	if q.IsInfinity() {
+		return nil, nil, fmt.Errorf("threshold: tweaked key is infinity")
+	}
+	return q, t, nil
+}
+
+// session holds the values of one signing package that signers and the
+// coordinator compute alike.
+type session struct {
+	commitments []Commitment // sorted by ID
+	rho         []*big.Int   // binding factor per commitment
+	r           *secp256k1.Point
+	key         []byte // x-only key the signature verifies under
+	tweak       *big.Int
+	challenge   *big.Int
+
+	// The signature needs an even R and key, so the shares negate their
+	// nonce or key terms when the computed points have odd Y
+	negateR, negateQ bool
+}
+
+func newSession(groupKey []byte, threshold int, pkg SigningPackage) (*session, error) {
+	if len(pkg.Commitments) < threshold {
+		return nil, fmt.Errorf("%w: %d commitments, need %d", ErrInvalidCommitment, len(pkg.Commitments), threshold)
+	}
+	q, tweak, err := tweakKey(groupKey, pkg.Tweak)
+	if err != nil {
+		return nil, err
+	}
+	s := &session{
+		commitments: slices.Clone(pkg.Commitments),
+		key:         scalarBytes(q.X),
+		tweak:       tweak,
+		negateQ:     q.Y.Bit(0) == 1,
+	}
+	slices.SortFunc(s.commitments, func(a, b Commitment) int { return int(int64(a.ID) - int64(b.ID)) })
+
+	// The binding factors commit to the message and every signer's nonces
+	var encoded []byte
+	for i, c := range s.commitments {
+		if c.ID == 0 || (i > 0 && c.ID == s.commitments[i-1].ID) {
+			return nil, fmt.Errorf("%w: participant id %d repeated or zero", ErrInvalidCommitment, c.ID)
+		}
+		encoded = append(encoded, idBytes(c.ID)...)
+		encoded = append(encoded, c.Hiding...)
+		encoded = append(encoded, c.Binding...)
+	}
+	messageHash := secp256k1.TaggedHash("FROST/message", pkg.Message)
+	commitmentsHash := secp256k1.TaggedHash("FROST/commitments", encoded)
+
+	// R = sum of D_i + rho_i * E_i
+	s.r = secp256k1.Infinity()
+	for _, c := range s.commitments {
+		d, err := secp256k1.DecompressPoint(c.Hiding)
+		if err != nil {
+			return nil, fmt.Errorf("%w: participant %d hiding nonce", ErrInvalidCommitment, c.ID)
+		}
+		e, err := secp256k1.DecompressPoint(c.Binding)
+		if err != nil {
+			return nil, fmt.Errorf("%w: participant %d binding nonce", ErrInvalidCommitment, c.ID)
+		}
+		rho := new(big.Int).SetBytes(secp256k1.TaggedHash("FROST/rho", s.key, messageHash, commitmentsHash, idBytes(c.ID)))
+		rho.Mod(rho, secp256k1.N)
+		s.rho = append(s.rho, rho)
+		s.r = secp256k1.Add(s.r, secp256k1.Add(d, secp256k1.ScalarMult(e, rho)))
+	}
+	if s.r.IsInfinity() {
+		return nil, fmt.Errorf("%w: group commitment is infinity", ErrInvalidCommitment)
+	}
+	s.negateR = s.r.Y.Bit(0) == 1
+
+	s.challenge = new(big.Int).SetBytes(secp256k1.TaggedHash("BIP0340/challenge", scalarBytes(s.r.X), s.key, pkg.Message))
+	s.challenge.Mod(s.challenge, secp256k1.N)
+	return s, nil
+}
+
+// index returns the position of id's commitment.
+func (s *session) index(id uint32) (int, bool) {
+	return commitmentIndex(s.commitments, id)
+}
+
+// lagrange returns id's Lagrange coefficient at zero over the signing set.
+func (s *session) lagrange(id uint32) *big.Int {
+	return lagrange(s.commitments, id, secp256k1.N)
+}
+
+// commitmentIndex returns the position of id's commitment in commitments,
+// which are sorted by ID.
+func commitmentIndex(commitments []Commitment, id uint32) (int, bool) {
+	return slices.BinarySearchFunc(commitments, id, func(c Commitment, id uint32) int {
+		return int(int64(c.ID) - int64(id))
+	})
+}
+
+// lagrange returns id's Lagrange coefficient at zero over the signers of
+// commitments, modulo n: the product of j / (j - id) for every other signer j.
+func lagrange(commitments []Commitment, id uint32, n *big.Int) *big.Int {
+	num, den := big.NewInt(1), big.NewInt(1)
+	x := new(big.Int).SetUint64(uint64(id))
+	for _, c := range commitments {
+		if c.ID == id {
+			continue
+		}
+		j := new(big.Int).SetUint64(uint64(c.ID))
+		num.Mul(num, j)
+		den.Mul(den, new(big.Int).Sub(j, x))
+	}
+	den.Mod(den, n)
+	num.Mul(num, den.ModInverse(den, n))
+	return num.Mod(num, n)
+}
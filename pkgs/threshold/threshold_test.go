@@ -0,0 +1,194 @@
+package threshold
+
+import (
+	"bytes"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+// sign runs both rounds for the given signers and aggregates the shares.
+func sign(t *testing.T, signers []KeyShare, message, tweak []byte) ([]byte, error) {
+	t.Helper()
+	nonces := make([]*Nonce, len(signers))
+	pkg := SigningPackage{Message: message, Tweak: tweak}
+	for i := range signers {
+		nonce, commitment, err := signers[i].Commit(nil)
+		if err != nil {
+			t.Fatalf("Commit() error = %v", err)
+		}
+		nonces[i] = nonce
+		pkg.Commitments = append(pkg.Commitments, commitment)
+	}
+	var shares []SignatureShare
+	for i := range signers {
+		share, err := signers[i].Sign(nonces[i], pkg)
+		if err != nil {
+			t.Fatalf("Sign(%d) error = %v", signers[i].ID, err)
+		}
+		shares = append(shares, share)
+	}
+	return Aggregate(signers[0].GroupKey, signers[0].Threshold, pkg, shares)
+}
+
+func TestTwoOfThree(t *testing.T) {
+	shares, err := Deal(nil, 2, 3)
+	if err != nil {
+		t.Fatalf("Deal() error = %v", err)
+	}
+	for _, share := range shares {
+		if err := share.Verify(); err != nil {
+			t.Errorf("share %d Verify() error = %v", share.ID, err)
+		}
+	}
+
+	message := sha256.Sum256([]byte("spend"))
+	for _, pair := range [][2]int{{0, 1}, {0, 2}, {2, 1}} {
+		sig, err := sign(t, []KeyShare{shares[pair[0]], shares[pair[1]]}, message[:], nil)
+		if err != nil {
+			t.Fatalf("signers %v: Aggregate() error = %v", pair, err)
+		}
+		if !secp256k1.SchnorrVerify(shares[0].GroupKey, message[:], sig) {
+			t.Errorf("signers %v: signature does not verify", pair)
+		}
+	}
+
+	// A single signer is below the threshold
+	if _, err := sign(t, shares[:1], message[:], nil); !errors.Is(err, ErrInvalidCommitment) {
+		t.Errorf("one signer: error = %v, want ErrInvalidCommitment", err)
+	}
+}
+
+func TestThreeOfFiveTaproot(t *testing.T) {
+	shares, err := Deal(nil, 3, 5)
+	if err != nil {
+		t.Fatalf("Deal() error = %v", err)
+	}
+	tweak := TaprootTweak(shares[0].GroupKey, nil)
+	outputKey, err := TweakedKey(shares[0].GroupKey, tweak)
+	if err != nil {
+		t.Fatalf("TweakedKey() error = %v", err)
+	}
+	if addr, err := address.NewBitcoinAddress(false).P2TR(outputKey); err != nil || !strings.HasPrefix(addr, "bc1p") {
+		t.Errorf("P2TR() = %s, %v", addr, err)
+	}
+
+	message := sha256.Sum256([]byte("key path"))
+	sig, err := sign(t, []KeyShare{shares[4], shares[1], shares[2]}, message[:], tweak)
+	if err != nil {
+		t.Fatalf("Aggregate() error = %v", err)
+	}
+	if !secp256k1.SchnorrVerify(outputKey, message[:], sig) {
+		t.Error("signature does not verify under the output key")
+	}
+	if secp256k1.SchnorrVerify(shares[0].GroupKey, message[:], sig) {
+		t.Error("tweaked signature verifies under the internal key")
+	}
+
+	// Four signers of a 3-of-5 group work too
+	if _, err := sign(t, shares[:4], message[:], tweak); err != nil {
+		t.Errorf("four signers: error = %v", err)
+	}
+}
+
+func TestSignRejects(t *testing.T) {
+	shares, _ := Deal(nil, 2, 3)
+	message := []byte("message")
+
+	nonce1, c1, _ := shares[0].Commit(nil)
+	nonce2, c2, _ := shares[1].Commit(nil)
+	pkg := SigningPackage{Message: message, Commitments: []Commitment{c1, c2}}
+
+	if _, err := shares[0].Sign(nonce1, pkg); err != nil {
+		t.Fatalf("Sign() error = %v", err)
+	}
+	if _, err := shares[0].Sign(nonce1, pkg); !errors.Is(err, ErrNonceUsed) {
+		t.Errorf("Sign() with a used nonce error = %v, want ErrNonceUsed", err)
+	}
+	if _, err := shares[2].Sign(nonce2, pkg); !errors.Is(err, ErrInvalidCommitment) {
+		t.Errorf("Sign() with another signer's nonce error = %v", err)
+	}
+
+	_, c2, _ = shares[1].Commit(nil)
+	nonce2, _, _ = shares[1].Commit(nil)
+	pkg.Commitments[1] = c2
+	if _, err := shares[1].Sign(nonce2, pkg); !errors.Is(err, ErrInvalidCommitment) {
+		t.Errorf("Sign() with a mismatched commitment error = %v", err)
+	}
+
+	// A corrupted share makes aggregation fail
+	nonce1, c1, _ = shares[0].Commit(nil)
+	nonce2, c2, _ = shares[1].Commit(nil)
+	pkg.Commitments = []Commitment{c1, c2}
+	z1, _ := shares[0].Sign(nonce1, pkg)
+	z2, _ := shares[1].Sign(nonce2, pkg)
+	z2.Z[31] ^= 1
+	if _, err := Aggregate(shares[0].GroupKey, 2, pkg, []SignatureShare{z1, z2}); !errors.Is(err, ErrInvalidSignature) {
+		t.Errorf("Aggregate() with a bad share error = %v, want ErrInvalidSignature", err)
+	}
+}
+
+func TestDeal(t *testing.T) {
+	for _, tt := range [][2]int{{1, 3}, {4, 3}, {2, MaxParticipants + 1}} {
+		if _, err := Deal(nil, tt[0], tt[1]); !errors.Is(err, ErrInvalidThreshold) {
+			t.Errorf("Deal(%d, %d) error = %v", tt[0], tt[1], err)
+		}
+	}
+
+	shares, _ := Deal(bytes.NewReader(bytes.Repeat([]byte{7, 9}, 1024)), 2, 3)
+	if shares[0].Commitments[0][0] != secp256k1.PrefixEven {
+		t.Error("group key has odd Y")
+	}
+
+	tampered := shares[1]
+	tampered.Secret = append([]byte(nil), tampered.Secret...)
+	tampered.Secret[0] ^= 1
+	if err := tampered.Verify(); !errors.Is(err, ErrInvalidShare) {
+		t.Errorf("Verify() of a tampered share error = %v, want ErrInvalidShare", err)
+	}
+}
+
+func TestKeyShareSecret(t *testing.T) {
+	shares, _ := Deal(nil, 2, 3)
+	share := shares[0]
+	secretHex := hex.EncodeToString(share.Secret)
+	secretDecimal := strings.Trim(fmt.Sprint(share.Secret), "[]")
+
+	for _, verb := range []string{"%v", "%+v", "%#v", "%s", "%d", "%x"} {
+		for _, v := range []any{share, &share, shares, SecretKeyShare{&share}} {
+			if out := fmt.Sprintf(verb, v); strings.Contains(out, secretHex) || strings.Contains(out, secretDecimal) {
+				t.Errorf("Sprintf(%s, %T) leaked the secret: %s", verb, v, out)
+			}
+		}
+	}
+
+	data, err := json.Marshal(share)
+	if err != nil {
+		t.Fatalf("json.Marshal() error = %v", err)
+	}
+	if strings.Contains(string(data), `"secret"`) {
+		t.Errorf("json.Marshal(KeyShare) wrote the secret: %s", data)
+	}
+
+	data, err = json.Marshal(SecretKeyShare{&share})
+	if err != nil {
+		t.Fatalf("json.Marshal(SecretKeyShare) error = %v", err)
+	}
+	var restored SecretKeyShare
+	if err := json.Unmarshal(data, &restored); err != nil {
+		t.Fatalf("json.Unmarshal(SecretKeyShare) error = %v", err)
+	}
+	if !bytes.Equal(restored.Secret, share.Secret) || restored.ID != share.ID || !bytes.Equal(restored.GroupKey, share.GroupKey) {
+		t.Errorf("SecretKeyShare round trip = %v", restored)
+	}
+	if err := restored.Verify(); err != nil {
+		t.Errorf("Verify() of the restored share error = %v", err)
+	}
+}
@@ -0,0 +1,174 @@
+// Package ton implements the TON native mnemonic scheme used by Tonkeeper,
+// Tonhub and the TON wallet apps.
+//
+// TON mnemonics reuse the BIP-39 English word list but not its checksum or seed
+// derivation: the phrase is hashed with HMAC-SHA512 into an entropy value, and a
+// phrase is valid only if a cheap PBKDF2 round over that entropy yields a
+// digest starting with a zero byte. The key seed is a separate, slow PBKDF2 round.
+package ton
+
+import (
+	"crypto/hmac"
+	"crypto/rand"
+	"crypto/sha512"
+	"encoding/binary"
+	"errors"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/bip39"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"golang.org/x/crypto/pbkdf2"
+)
+
+const (
+	// MnemonicWords is the number of words in a TON mnemonic.
+	MnemonicWords = 24
+
+	// SeedSize is the size of the derived seed in bytes.
+	SeedSize = 64
+
+	// PBKDF2Iterations is the number of iterations for the key seed.
+	PBKDF2Iterations = 100000
+
+	// basicSeedIterations is the number of iterations for the validity check.
+	basicSeedIterations = PBKDF2Iterations / 256
+)
+
+// PBKDF2 salts used by the TON mnemonic scheme
+const (
+	seedSalt         = "TON default seed"
+	basicSeedSalt    = "TON seed version"
+	passwordSeedSalt = "TON fast seed version"
+)
+
+var (
+	// ErrInvalidMnemonic is returned when a phrase is not a valid TON mnemonic.
+	ErrInvalidMnemonic = errors.New("ton: invalid mnemonic")
+
+	// ErrInvalidWordCount is returned when a phrase does not have 24 words.
+	ErrInvalidWordCount = errors.New("ton: mnemonic must have 24 words")
+)
+
+// GenerateMnemonic generates a random 24-word TON mnemonic.
+// A non-empty password produces a password-protected mnemonic, which is only
+// valid together with that password.
+func GenerateMnemonic(password string) (string, error) {
+	wordList := bip39.English
+	random := make([]byte, 2*MnemonicWords)
+
+	for {
+		if _, err := rand.Read(random); err != nil {
+			return "", err
+		}
+
+		words := make([]string, MnemonicWords)
+		for i := range words {
+			words[i] = wordList.WordAt(int(binary.BigEndian.Uint16(random[2*i:]) & 2047))
+		}
+		mnemonic := strings.Join(words, " ")
+
+		if password != "" && !IsPasswordNeeded(mnemonic) {
+			continue
+		}
+		if !isBasicSeed(mnemonicToEntropy(mnemonic, password)) {
+			continue
+		}
+
+		return mnemonic, nil
+	}
+}
+
+// ValidateMnemonic checks whether a phrase is a valid TON mnemonic for the given
+// password (empty for no password).
+func ValidateMnemonic(mnemonic, password string) bool {
+	words, err := splitMnemonic(mnemonic)
+	if err != nil {
+		return false
+	}
+	mnemonic = strings.Join(words, " ")
+
+	if password != "" && !IsPasswordNeeded(mnemonic) {
+		return false
+	}
+	return isBasicSeed(mnemonicToEntropy(mnemonic, password))
+}
+
+// IsPasswordNeeded reports whether a phrase is a password-protected mnemonic.
+func IsPasswordNeeded(mnemonic string) bool {
+	words, err := splitMnemonic(mnemonic)
+	if err != nil {
+		return false
+	}
+
+	entropy := mnemonicToEntropy(strings.Join(words, " "), "")
+	return isPasswordSeed(entropy) && !isBasicSeed(entropy)
+}
+
+// MnemonicToSeed validates a TON mnemonic and derives its 64-byte seed.
+func MnemonicToSeed(mnemonic, password string) ([]byte, error) {
+	words, err := splitMnemonic(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	mnemonic = strings.Join(words, " ")
+
+	if !ValidateMnemonic(mnemonic, password) {
+		return nil, ErrInvalidMnemonic
+	}
+
+	entropy := mnemonicToEntropy(mnemonic, password)
+	return pbkdf2.Key(entropy, []byte(seedSalt), PBKDF2Iterations, SeedSize, sha512.New), nil
+}
+
+// MnemonicToKeyPair derives the Ed25519 wallet key pair from a TON mnemonic.
+// The private key is the first 32 bytes of the seed.
+// Returns (privateKey, publicKey, error)
+func MnemonicToKeyPair(mnemonic, password string) ([]byte, []byte, error) {
+	seed, err := MnemonicToSeed(mnemonic, password)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	privateKey := seed[:ed25519.PrivateKeySize]
+	publicKey, err := ed25519.PrivateKeyToPublicKey(privateKey)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	return privateKey, publicKey, nil
+}
+
+// splitMnemonic normalizes a phrase and checks its length and words.
+func splitMnemonic(mnemonic string) ([]string, error) {
+	words := strings.Fields(strings.ToLower(mnemonic))
+	if len(words) != MnemonicWords {
+		return nil, ErrInvalidWordCount
+	}
+
+	for _, word := range words {
+		if bip39.English.WordIndex(word) < 0 {
+			return nil, ErrInvalidMnemonic
+		}
+	}
+
+	return words, nil
+}
+
+// mnemonicToEntropy computes HMAC-SHA512(key = phrase, message = password).
+func mnemonicToEntropy(mnemonic, password string) []byte {
+	mac := hmac.New(sha512.New, []byte(mnemonic))
+	mac.Write([]byte(password))
+	return mac.Sum(nil)
+}
+
+// isBasicSeed reports whether entropy passes the validity check of a TON mnemonic.
+func isBasicSeed(entropy []byte) bool {
+	seed := pbkdf2.Key(entropy, []byte(basicSeedSalt), basicSeedIterations, SeedSize, sha512.New)
+	return seed[0] == 0
+}
+
+// isPasswordSeed reports whether entropy marks a password-protected mnemonic.
+func isPasswordSeed(entropy []byte) bool {
+	seed := pbkdf2.Key(entropy, []byte(passwordSeedSalt), 1, SeedSize, sha512.New)
+	return seed[0] == 1
+}
@@ -0,0 +1,77 @@
+package ton
+
+import (
+	"encoding/hex"
+	"strings"
+	"testing"
+)
+
+const testMnemonic = "nice satisfy envelope thought figure sound glimpse salt project boat lazy talent " +
+	"stage medal negative laundry analyst display slow spend happy desk use armor"
+
+func TestValidateMnemonic(t *testing.T) {
+	tests := []struct {
+		name     string
+		mnemonic string
+		password string
+		valid    bool
+	}{
+		{"valid", testMnemonic, "", true},
+		{"extra whitespace and case", "  " + strings.ToUpper(testMnemonic) + "\n", "", true},
+		{"unexpected password", testMnemonic, "secret", false},
+		{"BIP-39 phrase", strings.Repeat("abandon ", 23) + "art", "", false},
+		{"too short", "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", "", false},
+		{"unknown word", strings.Replace(testMnemonic, "nice", "nicer", 1), "", false},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := ValidateMnemonic(tt.mnemonic, tt.password); got != tt.valid {
+				t.Errorf("ValidateMnemonic() = %v, want %v", got, tt.valid)
+			}
+		})
+	}
+}
+
+func TestMnemonicToKeyPair(t *testing.T) {
+	seed, err := MnemonicToSeed(testMnemonic, "")
+	if err != nil {
+		t.Fatalf("MnemonicToSeed() error = %v", err)
+	}
+	expectedSeed := "753e3b3bd517fe60a1e3b2f1960c40be1ab31fedcc5075928c542117dc8b7bf7" +
+		"acf3fec21e7dbba871306f00607562529b9e46ec82db4fa2ea95fbf11de8658c"
+	if hex.EncodeToString(seed) != expectedSeed {
+		t.Errorf("MnemonicToSeed() = %x", seed)
+	}
+
+	priv, pub, err := MnemonicToKeyPair(testMnemonic, "")
+	if err != nil {
+		t.Fatalf("MnemonicToKeyPair() error = %v", err)
+	}
+	if hex.EncodeToString(priv) != expectedSeed[:64] {
+		t.Errorf("private key = %x", priv)
+	}
+	if hex.EncodeToString(pub) != "d4dc3d40dabc7000c63dee8440ea7a83251f3804a1c647a1d7371f377756227e" {
+		t.Errorf("public key = %x", pub)
+	}
+
+	if _, err := MnemonicToSeed(strings.Repeat("abandon ", 23)+"art", ""); err == nil {
+		t.Error("MnemonicToSeed() accepted an invalid mnemonic")
+	}
+}
+
+func TestGenerateMnemonic(t *testing.T) {
+	mnemonic, err := GenerateMnemonic("")
+	if err != nil {
+		t.Fatalf("GenerateMnemonic() error = %v", err)
+	}
+	if len(strings.Fields(mnemonic)) != MnemonicWords {
+		t.Errorf("GenerateMnemonic() returned %d words", len(strings.Fields(mnemonic)))
+	}
+	if !ValidateMnemonic(mnemonic, "") {
+		t.Error("generated mnemonic does not validate")
+	}
+	if IsPasswordNeeded(mnemonic) {
+		t.Error("generated mnemonic should not need a password")
+	}
+}
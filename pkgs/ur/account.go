@@ -0,0 +1,188 @@
+package ur
+
+import (
+	"encoding/binary"
+	"fmt"
+	"slices"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// TypeAccount is the UR type of an Account.
+const TypeAccount = "crypto-account"
+
+// Script expression tags of output descriptors (BCR-2020-010)
+const (
+	tagScriptHash           = 400
+	tagPublicKeyHash        = 403
+	tagWitnessPublicKeyHash = 404
+)
+
+// outputScripts are the script expressions wrapping each script type's key,
+// outermost first.
+var outputScripts = map[account.ScriptType][]uint64{
+	account.ScriptP2PKH:      {tagPublicKeyHash},
+	account.ScriptP2SHP2WPKH: {tagScriptHash, tagWitnessPublicKeyHash},
+	account.ScriptP2WPKH:     {tagWitnessPublicKeyHash},
+}
+
+// Account is a crypto-account (BCR-2020-015): a wallet's master fingerprint
+// and the output descriptors of its account-level xpubs, which Keystone and
+// SeedSigner export to set up a watch-only wallet.
+type Account struct {
+	// MasterFingerprint is the fingerprint of the master key.
+	MasterFingerprint uint32
+
+	// Outputs are the account's descriptors, one per script type.
+	Outputs []Output
+}
+
+// Output is a single-key output descriptor: pkh(), sh(wpkh()) or wpkh() of
+// an extended public key.
+type Output struct {
+	ScriptType account.ScriptType
+	Key        *HDKey
+}
+
+// NewAccount derives the P2PKH, P2SH-P2WPKH and P2WPKH account xpubs of a
+// Bitcoin master key at m/purpose'/coin'/account', as a signer exports them.
+func NewAccount(master *bip32.ExtendedKey, accountIndex uint32) (*Account, error) {
+	fingerprint := binary.BigEndian.Uint32(master.Fingerprint())
+	var coinType uint32
+	if master.Network() == bip32.TestNet {
+		coinType = 1
+	}
+
+	a := &Account{MasterFingerprint: fingerprint}
+	for _, scriptType := range []account.ScriptType{account.ScriptP2PKH, account.ScriptP2SHP2WPKH, account.ScriptP2WPKH} {
+		path := bip32.DerivationPath{
+			bip32.Hardened(scriptType.Purpose()), bip32.Hardened(coinType), bip32.Hardened(accountIndex),
+		}
+		key, err := master.DeriveFromPath(path)
+		if err != nil {
+			return nil, err
+		}
+		xpub, err := key.Neuter()
+		if err != nil {
+			return nil, err
+		}
+		a.Outputs = append(a.Outputs, Output{
+			ScriptType: scriptType,
+			Key:        NewHDKey(xpub, &Keypath{Path: path, SourceFingerprint: fingerprint}),
+		})
+	}
+	return a, nil
+}
+
+// WatchAccount returns a watch-only account for the receive address at
+// index, the key at .../0/index below the output's xpub.
+func (o Output) WatchAccount(index uint32) (*account.BitcoinWatchAccount, error) {
+	xpub, err := o.Key.ExtendedKey()
+	if err != nil {
+		return nil, err
+	}
+	return account.NewBitcoinWatchAccountFromXpub(xpub.String(), o.ScriptType, index)
+}
+
+// UR returns the account as a crypto-account UR.
+func (a *Account) UR() (*UR, error) {
+	var m cborMap
+	m.key(1).uint(uint64(a.MasterFingerprint))
+	outputs := m.key(2)
+	outputs.array(len(a.Outputs))
+	for _, o := range a.Outputs {
+		scripts, ok := outputScripts[o.ScriptType]
+		if !ok {
+			return nil, fmt.Errorf("%w: %s output", ErrUnsupported, o.ScriptType)
+		}
+		for _, tag := range scripts {
+			outputs.tag(tag)
+		}
+		outputs.tag(tagHDKey)
+		o.Key.encode(outputs)
+	}
+
+	var w cborWriter
+	w.writeMap(&m)
+	return &UR{Type: TypeAccount, CBOR: w.buf}, nil
+}
+
+// DecodeAccount decodes a crypto-account UR. Outputs other than single-key
+// pkh, sh(wpkh) and wpkh descriptors of extended keys, such as multisig or
+// Taproot ones, return ErrUnsupported.
+func DecodeAccount(u *UR) (*Account, error) {
+	if u.Type != TypeAccount {
+		return nil, fmt.Errorf("%w: %s, want %s", ErrWrongType, u.Type, TypeAccount)
+	}
+
+	a := &Account{}
+	r := &cborReader{data: u.CBOR}
+	err := r.readMap(func(key uint64) (bool, error) {
+		switch key {
+		case 1:
+			fingerprint, err := r.uint32()
+			a.MasterFingerprint = fingerprint
+			return true, err
+		case 2:
+			n, err := r.array()
+			if err != nil {
+				return true, err
+			}
+			for range n {
+				o, err := decodeOutput(r)
+				if err != nil {
+					return true, err
+				}
+				a.Outputs = append(a.Outputs, o)
+			}
+			return true, nil
+		default:
+			return false, nil
+		}
+	})
+	if err == nil {
+		err = r.end()
+	}
+	if err != nil {
+		return nil, err
+	}
+	return a, nil
+}
+
+// decodeOutput decodes a crypto-output: script expression tags around a key.
+func decodeOutput(r *cborReader) (Output, error) {
+	var scripts []uint64
+	for {
+		major, tag, err := r.peek()
+		if err != nil {
+			return Output{}, err
+		}
+		if major != majorTag || tag == tagHDKey || len(scripts) > 2 {
+			break
+		}
+		if err := r.tag(tag); err != nil {
+			return Output{}, err
+		}
+		scripts = append(scripts, tag)
+	}
+
+	major, tag, err := r.peek()
+	if err != nil {
+		return Output{}, err
+	}
+	if major != majorTag || tag != tagHDKey {
+		return Output{}, fmt.Errorf("%w: output without an extended key", ErrUnsupported)
+	}
+	for scriptType, want := range outputScripts {
+		if !slices.Equal(scripts, want) {
+			continue
+		}
+		key, err := decodeHDKey(r)
+		if err != nil {
+			return Output{}, err
+		}
+		return Output{ScriptType: scriptType, Key: key}, nil
+	}
+	return Output{}, fmt.Errorf("%w: output with script tags %v", ErrUnsupported, scripts)
+}
@@ -0,0 +1,77 @@
+package ur
+
+import (
+	"encoding/binary"
+	"fmt"
+	"hash/crc32"
+	"slices"
+	"strings"
+)
+
+// bytewords is the Bytewords list (BCR-2020-012): 256 four-letter words whose
+// first and last letters are unique, so the two letters alone identify a byte.
+var bytewords = strings.Fields(`
+able acid also apex aqua arch atom aunt away axis back bald barn belt beta bias
+blue body brag brew bulb buzz calm cash cats chef city claw code cola cook cost
+crux curl cusp cyan dark data days deli dice diet door down draw drop drum dull
+duty each easy echo edge epic even exam exit eyes fact fair fern figs film fish
+fizz flap flew flux foxy free frog fuel fund gala game gear gems gift girl glow
+good gray grim guru gush gyro half hang hard hawk heat help high hill holy hope
+horn huts iced idea idle inch inky into iris iron item jade jazz join jolt jowl
+judo jugs jump junk jury keep keno kept keys kick kiln king kite kiwi knob lamb
+lava lazy leaf legs liar limp lion list logo loud love luau luck lung main many
+math maze memo menu meow mild mint miss monk nail navy need news next noon note
+numb obey oboe omit onyx open oval owls paid part peck play plus poem pool pose
+puff puma purr quad quiz race ramp real redo rich road rock roof ruby ruin runs
+rust safe saga scar sets silk skew slot soap solo song stub surf swan taco task
+taxi tent tied time tiny toil tomb toys trip tuna twin ugly undo unit urge user
+vast very veto vial vibe view visa void vows wall wand warm wasp wave waxy webs
+what when whiz wolf work yank yawn yell yoga yurt zaps zero zest zinc zone zoom
+`)
+
+// minimalIndex maps the first and last letters of each word to its byte.
+var minimalIndex = func() map[string]byte {
+	index := make(map[string]byte, len(bytewords))
+	for i, word := range bytewords {
+		index[word[:1]+word[3:]] = byte(i)
+	}
+	return index
+}()
+
+// EncodeBytewords returns data and its CRC-32 in minimal Bytewords, two
+// letters per byte, the form URs use.
+func EncodeBytewords(data []byte) string {
+	var sb strings.Builder
+	sb.Grow(2 * (len(data) + 4))
+	// Clipping makes the append copy instead of writing past the caller's slice
+	for _, b := range binary.BigEndian.AppendUint32(slices.Clip(data), crc32.ChecksumIEEE(data)) {
+		word := bytewords[b]
+		sb.WriteByte(word[0])
+		sb.WriteByte(word[3])
+	}
+	return sb.String()
+}
+
+// DecodeBytewords decodes minimal Bytewords in either case and checks and
+// strips the CRC-32.
+func DecodeBytewords(s string) ([]byte, error) {
+	s = strings.ToLower(s)
+	if len(s)%2 != 0 || len(s) < 8 {
+		return nil, fmt.Errorf("%w: length %d", ErrInvalidBytewords, len(s))
+	}
+
+	data := make([]byte, len(s)/2)
+	for i := range data {
+		b, ok := minimalIndex[s[2*i:2*i+2]]
+		if !ok {
+			return nil, fmt.Errorf("%w: %q is not a byteword", ErrInvalidBytewords, s[2*i:2*i+2])
+		}
+		data[i] = b
+	}
+
+	body, checksum := data[:len(data)-4], binary.BigEndian.Uint32(data[len(data)-4:])
+	if crc32.ChecksumIEEE(body) != checksum {
+		return nil, ErrInvalidChecksum
+	}
+	return body, nil
+}
@@ -0,0 +1,309 @@
+package ur
+
+import (
+	"encoding/binary"
+	"fmt"
+)
+
+// The subset of CBOR (RFC 8949) the registry types use: definite-length
+// items, unsigned integers, byte and text strings, arrays, maps with integer
+// keys, tags and booleans. Other items are skipped when they appear under a
+// key the decoder does not know.
+
+// CBOR major types
+const (
+	majorUint   = 0
+	majorBytes  = 2
+	majorText   = 3
+	majorArray  = 4
+	majorMap    = 5
+	majorTag    = 6
+	majorSimple = 7
+)
+
+// Simple values of major type 7
+const (
+	simpleFalse = 20
+	simpleTrue  = 21
+)
+
+// maxCBORDepth bounds the nesting skip follows.
+const maxCBORDepth = 32
+
+type cborWriter struct {
+	buf []byte
+}
+
+// head writes an item head with the shortest encoding of n, as deterministic
+// CBOR requires.
+func (w *cborWriter) head(major byte, n uint64) {
+	switch {
+	case n < 24:
+		w.buf = append(w.buf, major<<5|byte(n))
+	case n <= 0xff:
+		w.buf = append(w.buf, major<<5|24, byte(n))
+	case n <= 0xffff:
+		w.buf = binary.BigEndian.AppendUint16(append(w.buf, major<<5|25), uint16(n))
+	case n <= 0xffffffff:
+		w.buf = binary.BigEndian.AppendUint32(append(w.buf, major<<5|26), uint32(n))
+	default:
+		w.buf = binary.BigEndian.AppendUint64(append(w.buf, major<<5|27), n)
+	}
+}
+
+func (w *cborWriter) uint(n uint64)  { w.head(majorUint, n) }
+func (w *cborWriter) array(n int)    { w.head(majorArray, uint64(n)) }
+func (w *cborWriter) tag(tag uint64) { w.head(majorTag, tag) }
+
+func (w *cborWriter) bytes(b []byte) {
+	w.head(majorBytes, uint64(len(b)))
+	w.buf = append(w.buf, b...)
+}
+
+func (w *cborWriter) text(s string) {
+	w.head(majorText, uint64(len(s)))
+	w.buf = append(w.buf, s...)
+}
+
+func (w *cborWriter) bool(b bool) {
+	if b {
+		w.head(majorSimple, simpleTrue)
+	} else {
+		w.head(majorSimple, simpleFalse)
+	}
+}
+
+// cborMap collects the entries of a map with integer keys, so optional
+// entries can be left out before the head gives their count. Keys must be
+// added in ascending order.
+type cborMap struct {
+	n    int
+	body cborWriter
+}
+
+// key starts an entry and returns the writer for its value.
+func (m *cborMap) key(k uint64) *cborWriter {
+	m.n++
+	m.body.uint(k)
+	return &m.body
+}
+
+func (w *cborWriter) writeMap(m *cborMap) {
+	w.head(majorMap, uint64(m.n))
+	w.buf = append(w.buf, m.body.buf...)
+}
+
+type cborReader struct {
+	data []byte
+	pos  int
+}
+
+// head reads an item head. Indefinite lengths are rejected.
+func (r *cborReader) head() (major byte, arg uint64, err error) {
+	if r.pos >= len(r.data) {
+		return 0, 0, fmt.Errorf("%w: unexpected end of data", ErrInvalidCBOR)
+	}
+	b := r.data[r.pos]
+	r.pos++
+	major, info := b>>5, b&0x1f
+	if info < 24 {
+		return major, uint64(info), nil
+	}
+	if info > 27 {
+		return 0, 0, fmt.Errorf("%w: additional information %d", ErrInvalidCBOR, info)
+	}
+	size := 1 << (info - 24)
+	if len(r.data)-r.pos < size {
+		return 0, 0, fmt.Errorf("%w: unexpected end of data", ErrInvalidCBOR)
+	}
+	for _, c := range r.data[r.pos : r.pos+size] {
+		arg = arg<<8 | uint64(c)
+	}
+	r.pos += size
+	return major, arg, nil
+}
+
+// peek returns the major type and argument of the next item without
+// consuming it.
+func (r *cborReader) peek() (byte, uint64, error) {
+	pos := r.pos
+	major, arg, err := r.head()
+	r.pos = pos
+	return major, arg, err
+}
+
+// expect reads an item head of the given major type.
+func (r *cborReader) expect(major byte) (uint64, error) {
+	got, arg, err := r.head()
+	if err != nil {
+		return 0, err
+	}
+	if got != major {
+		return 0, fmt.Errorf("%w: major type %d, want %d", ErrInvalidCBOR, got, major)
+	}
+	return arg, nil
+}
+
+func (r *cborReader) uint() (uint64, error) {
+	return r.expect(majorUint)
+}
+
+// uint32 reads an unsigned integer that fits 32 bits, such as a fingerprint
+// or child index.
+func (r *cborReader) uint32() (uint32, error) {
+	n, err := r.uint()
+	if err != nil {
+		return 0, err
+	}
+	if n > 0xffffffff {
+		return 0, fmt.Errorf("%w: %d overflows 32 bits", ErrInvalidCBOR, n)
+	}
+	return uint32(n), nil
+}
+
+// length reads an array or map head and checks the count against the
+// remaining data, each entry taking at least one byte.
+func (r *cborReader) length(major byte) (int, error) {
+	n, err := r.expect(major)
+	if err != nil {
+		return 0, err
+	}
+	if n > uint64(len(r.data)-r.pos) {
+		return 0, fmt.Errorf("%w: %d entries exceed the data", ErrInvalidCBOR, n)
+	}
+	return int(n), nil
+}
+
+func (r *cborReader) array() (int, error) {
+	return r.length(majorArray)
+}
+
+// readMap reads a map with integer keys, calling field to decode the value of
+// each. Values of keys field does not know, for which it returns false, are
+// skipped.
+func (r *cborReader) readMap(field func(key uint64) (bool, error)) error {
+	n, err := r.length(majorMap)
+	if err != nil {
+		return err
+	}
+	for range n {
+		key, err := r.uint()
+		if err != nil {
+			return err
+		}
+		known, err := field(key)
+		if err != nil {
+			return err
+		}
+		if !known {
+			if err := r.skip(); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
+
+func (r *cborReader) bytes() ([]byte, error) {
+	n, err := r.expect(majorBytes)
+	if err != nil {
+		return nil, err
+	}
+	return r.read(n)
+}
+
+func (r *cborReader) text() (string, error) {
+	n, err := r.expect(majorText)
+	if err != nil {
+		return "", err
+	}
+	b, err := r.read(n)
+	return string(b), err
+}
+
+func (r *cborReader) read(n uint64) ([]byte, error) {
+	if n > uint64(len(r.data)-r.pos) {
+		return nil, fmt.Errorf("%w: unexpected end of data", ErrInvalidCBOR)
+	}
+	b := append([]byte(nil), r.data[r.pos:r.pos+int(n)]...)
+	r.pos += int(n)
+	return b, nil
+}
+
+func (r *cborReader) bool() (bool, error) {
+	v, err := r.expect(majorSimple)
+	if err != nil {
+		return false, err
+	}
+	switch v {
+	case simpleFalse:
+		return false, nil
+	case simpleTrue:
+		return true, nil
+	default:
+		return false, fmt.Errorf("%w: simple value %d is not a boolean", ErrInvalidCBOR, v)
+	}
+}
+
+// tag reads a tag and checks it is want.
+func (r *cborReader) tag(want uint64) error {
+	tag, err := r.expect(majorTag)
+	if err != nil {
+		return err
+	}
+	if tag != want {
+		return fmt.Errorf("%w: tag %d, want %d", ErrInvalidCBOR, tag, want)
+	}
+	return nil
+}
+
+// optionalTag consumes tag if it is next. The registry types are tagged when
+// nested, but not at the top level of a UR.
+func (r *cborReader) optionalTag(tag uint64) error {
+	major, arg, err := r.peek()
+	if err != nil || major != majorTag || arg != tag {
+		return err
+	}
+	return r.tag(tag)
+}
+
+// skip skips one item with everything nested in it.
+func (r *cborReader) skip() error {
+	return r.skipDepth(0)
+}
+
+func (r *cborReader) skipDepth(depth int) error {
+	if depth > maxCBORDepth {
+		return fmt.Errorf("%w: nested too deeply", ErrInvalidCBOR)
+	}
+	major, arg, err := r.head()
+	if err != nil {
+		return err
+	}
+	var items uint64
+	switch major {
+	case majorBytes, majorText:
+		_, err = r.read(arg)
+		return err
+	case majorArray:
+		items = arg
+	case majorMap:
+		items = 2 * arg
+	case majorTag:
+		items = 1
+	}
+	for range items {
+		if err := r.skipDepth(depth + 1); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// end checks that the whole message was consumed.
+func (r *cborReader) end() error {
+	if r.pos != len(r.data) {
+		return fmt.Errorf("%w: %d trailing bytes", ErrInvalidCBOR, len(r.data)-r.pos)
+	}
+	return nil
+}
@@ -0,0 +1,465 @@
+package ur
+
+import (
+	"crypto/sha256"
+	"encoding/binary"
+	"fmt"
+	"hash/crc32"
+	"math/bits"
+	"slices"
+	"strconv"
+	"strings"
+)
+
+// DefaultMaxFragmentLen keeps each part of a multi-part UR in a QR code small
+// enough to scan reliably from a phone screen.
+const DefaultMaxFragmentLen = 200
+
+const (
+	// minFragmentLen is the shortest fragment an Encoder splits a message
+	// into.
+	minFragmentLen = 10
+
+	// maxMessageLen bounds what a Decoder allocates for a message, far above
+	// anything shown as an animated QR code.
+	maxMessageLen = 1 << 24
+)
+
+// Encoder produces the parts of a UR for an animated QR code. The first
+// SeqLen parts are the message's fragments in order; after them come parts
+// mixing pseudo-random subsets of the fragments, so a Decoder completes from
+// any sufficiently large set of parts, whichever frames it missed.
+type Encoder struct {
+	ur        *UR
+	fragments [][]byte
+	checksum  uint32
+	seqNum    uint32
+}
+
+// NewEncoder splits u into fragments of at most maxFragmentLen bytes, raised
+// to a minimum of 10. A message that fits one fragment is a single-part UR.
+func NewEncoder(u *UR, maxFragmentLen int) *Encoder {
+	messageLen := len(u.CBOR)
+	fragmentLen := max(nominalFragmentLen(messageLen, max(maxFragmentLen, minFragmentLen)), 1)
+
+	// The last fragment is padded with zeros
+	e := &Encoder{ur: u, checksum: crc32.ChecksumIEEE(u.CBOR)}
+	seqLen := max((messageLen+fragmentLen-1)/fragmentLen, 1)
+	for i := range seqLen {
+		fragment := make([]byte, fragmentLen)
+		copy(fragment, u.CBOR[min(i*fragmentLen, messageLen):])
+		e.fragments = append(e.fragments, fragment)
+	}
+	return e
+}
+
+// nominalFragmentLen returns the fragment length of the fewest fragments of
+// about equal length no longer than maxFragmentLen.
+func nominalFragmentLen(messageLen, maxFragmentLen int) int {
+	fragmentLen := messageLen
+	maxCount := max(messageLen/minFragmentLen, 1)
+	for count := 1; count <= maxCount; count++ {
+		fragmentLen = (messageLen + count - 1) / count
+		if fragmentLen <= maxFragmentLen {
+			break
+		}
+	}
+	return fragmentLen
+}
+
+// SeqLen returns the number of fragments.
+func (e *Encoder) SeqLen() int {
+	return len(e.fragments)
+}
+
+// IsSinglePart reports whether the UR fits one part.
+func (e *Encoder) IsSinglePart() bool {
+	return len(e.fragments) == 1
+}
+
+// NextPart returns the next part, such as ur:crypto-account/3-12/lpax....
+// A single-part UR is returned as is every time.
+func (e *Encoder) NextPart() string {
+	if e.IsSinglePart() {
+		return e.ur.String()
+	}
+
+	e.seqNum++
+	seqLen := len(e.fragments)
+	data := make([]byte, len(e.fragments[0]))
+	for _, i := range chooseFragments(e.seqNum, seqLen, e.checksum) {
+		xorInto(data, e.fragments[i])
+	}
+
+	var w cborWriter
+	w.array(5)
+	w.uint(uint64(e.seqNum))
+	w.uint(uint64(seqLen))
+	w.uint(uint64(len(e.ur.CBOR)))
+	w.uint(uint64(e.checksum))
+	w.bytes(data)
+	return fmt.Sprintf("ur:%s/%d-%d/%s", e.ur.Type, e.seqNum, seqLen, EncodeBytewords(w.buf))
+}
+
+// Decoder reassembles a UR from its parts in any order. It also accepts a
+// single-part UR, which completes it at once.
+type Decoder struct {
+	typ         string
+	seqLen      int
+	messageLen  int
+	checksum    uint32
+	fragmentLen int
+
+	fragments [][]byte
+	found     int
+	mixed     []fountainPart
+
+	result *UR
+	err    error
+}
+
+// fountainPart is a received part, reduced by the fragments already known.
+type fountainPart struct {
+	indexes []int
+	data    []byte
+}
+
+// NewDecoder returns an empty Decoder.
+func NewDecoder() *Decoder {
+	return &Decoder{}
+}
+
+// Receive adds a scanned part. Parts already received, or that add nothing
+// new, are accepted and ignored; a part of another message returns
+// ErrInvalidPart. Once the message is complete further parts are ignored.
+func (d *Decoder) Receive(part string) error {
+	if d.Complete() {
+		return nil
+	}
+
+	typ, components, err := splitUR(part)
+	if err != nil {
+		return err
+	}
+	if len(components) == 1 {
+		u, err := Parse(part)
+		if err != nil {
+			return err
+		}
+		d.result = u
+		return nil
+	}
+	if d.typ != "" && typ != d.typ {
+		return fmt.Errorf("%w: type %s, decoding %s", ErrInvalidPart, typ, d.typ)
+	}
+
+	seqNum, seqLen, err := parseSequence(components[0])
+	if err != nil {
+		return err
+	}
+	body, err := DecodeBytewords(components[1])
+	if err != nil {
+		return err
+	}
+	header, data, err := parsePart(body)
+	if err != nil {
+		return err
+	}
+	if header[0] != uint64(seqNum) || header[1] != uint64(seqLen) {
+		return fmt.Errorf("%w: sequence %s does not match its body", ErrInvalidPart, components[0])
+	}
+	messageLen, checksum := header[2], header[3]
+	if messageLen == 0 || messageLen > maxMessageLen || checksum > 0xffffffff ||
+		(messageLen+uint64(len(data))-1)/uint64(len(data)) != uint64(seqLen) {
+		return fmt.Errorf("%w: message length %d in %d fragments of %d bytes", ErrInvalidPart, messageLen, seqLen, len(data))
+	}
+
+	if d.typ == "" {
+		d.typ, d.seqLen, d.messageLen, d.checksum, d.fragmentLen = typ, seqLen, int(messageLen), uint32(checksum), len(data)
+		d.fragments = make([][]byte, seqLen)
+	} else if seqLen != d.seqLen || int(messageLen) != d.messageLen || uint32(checksum) != d.checksum || len(data) != d.fragmentLen {
+		return fmt.Errorf("%w: part %s belongs to another message", ErrInvalidPart, components[0])
+	}
+
+	d.add(fountainPart{indexes: chooseFragments(seqNum, seqLen, d.checksum), data: data})
+	if d.found == d.seqLen {
+		d.finish()
+	}
+	return nil
+}
+
+// parseSequence parses the seqNum-seqLen component of a part.
+func parseSequence(s string) (uint32, int, error) {
+	num, count, ok := strings.Cut(s, "-")
+	seqNum, err1 := strconv.ParseUint(num, 10, 32)
+	seqLen, err2 := strconv.ParseUint(count, 10, 32)
+	if !ok || err1 != nil || err2 != nil || seqNum == 0 || seqLen == 0 {
+		return 0, 0, fmt.Errorf("%w: sequence %q", ErrInvalidUR, s)
+	}
+	return uint32(seqNum), int(seqLen), nil
+}
+
+// parsePart decodes the CBOR of a part: [seqNum, seqLen, messageLen,
+// checksum, fragment].
+func parsePart(body []byte) ([4]uint64, []byte, error) {
+	var header [4]uint64
+	r := &cborReader{data: body}
+	n, err := r.array()
+	if err != nil {
+		return header, nil, err
+	}
+	if n != 5 {
+		return header, nil, fmt.Errorf("%w: part has %d fields, want 5", ErrInvalidCBOR, n)
+	}
+	for i := range header {
+		if header[i], err = r.uint(); err != nil {
+			return header, nil, err
+		}
+	}
+	data, err := r.bytes()
+	if err != nil {
+		return header, nil, err
+	}
+	if len(data) == 0 {
+		return header, nil, fmt.Errorf("%w: empty fragment", ErrInvalidPart)
+	}
+	return header, data, r.end()
+}
+
+// add reduces a part by the known fragments and the mixed parts it contains,
+// and any mixed part a fragment it yields completes, until nothing changes.
+func (d *Decoder) add(part fountainPart) {
+	queue := []fountainPart{part}
+	for len(queue) > 0 {
+		p := queue[0]
+		queue = queue[1:]
+		p = d.reduceKnown(p)
+		for _, m := range d.mixed {
+			if len(p.indexes) > 1 && isSubset(m.indexes, p.indexes) {
+				p = reduce(p, m)
+			}
+		}
+
+		switch len(p.indexes) {
+		case 0:
+			// Nothing new
+		case 1:
+			d.fragments[p.indexes[0]] = p.data
+			d.found++
+			// Requeue the mixed parts the new fragment reduces
+			kept := d.mixed[:0]
+			for _, m := range d.mixed {
+				if slices.Contains(m.indexes, p.indexes[0]) {
+					queue = append(queue, m)
+				} else {
+					kept = append(kept, m)
+				}
+			}
+			d.mixed = kept
+		default:
+			if !slices.ContainsFunc(d.mixed, func(m fountainPart) bool { return slices.Equal(m.indexes, p.indexes) }) {
+				d.mixed = append(d.mixed, p)
+			}
+		}
+	}
+}
+
+// reduceKnown removes the fragments already known from a part.
+func (d *Decoder) reduceKnown(p fountainPart) fountainPart {
+	for _, i := range p.indexes {
+		if d.fragments[i] != nil {
+			p = reduce(p, fountainPart{indexes: []int{i}, data: d.fragments[i]})
+		}
+	}
+	return p
+}
+
+// reduce removes part m, whose indexes are a subset of p's, from p.
+func reduce(p, m fountainPart) fountainPart {
+	data := slices.Clone(p.data)
+	xorInto(data, m.data)
+	indexes := slices.DeleteFunc(slices.Clone(p.indexes), func(i int) bool { return slices.Contains(m.indexes, i) })
+	return fountainPart{indexes: indexes, data: data}
+}
+
+func isSubset(a, b []int) bool {
+	for _, i := range a {
+		if !slices.Contains(b, i) {
+			return false
+		}
+	}
+	return true
+}
+
+// finish joins the fragments and checks the message's CRC-32.
+func (d *Decoder) finish() {
+	message := slices.Concat(d.fragments...)[:d.messageLen]
+	if crc32.ChecksumIEEE(message) != d.checksum {
+		d.err = ErrInvalidChecksum
+		return
+	}
+	d.result = &UR{Type: d.typ, CBOR: message}
+}
+
+// Complete reports whether the message has been reassembled, or failed its
+// checksum.
+func (d *Decoder) Complete() bool {
+	return d.result != nil || d.err != nil
+}
+
+// Progress returns the fraction of fragments recovered so far, for a progress
+// bar. Mixed parts count once they resolve, so it can jump toward the end.
+func (d *Decoder) Progress() float64 {
+	switch {
+	case d.result != nil:
+		return 1
+	case d.seqLen == 0:
+		return 0
+	default:
+		return float64(d.found) / float64(d.seqLen)
+	}
+}
+
+// Result returns the reassembled UR, or ErrIncomplete while parts are
+// missing.
+func (d *Decoder) Result() (*UR, error) {
+	if d.err != nil {
+		return nil, d.err
+	}
+	if d.result == nil {
+		return nil, ErrIncomplete
+	}
+	return d.result, nil
+}
+
+// chooseFragments returns the sorted indexes of the fragments part seqNum
+// mixes: fragment seqNum-1 for the first seqLen parts, then a random degree
+// and subset drawn from a generator seeded with the part's sequence number
+// and the message checksum.
+func chooseFragments(seqNum uint32, seqLen int, checksum uint32) []int {
+	if int(seqNum) <= seqLen {
+		return []int{int(seqNum) - 1}
+	}
+
+	var seed [8]byte
+	binary.BigEndian.PutUint32(seed[:4], seqNum)
+	binary.BigEndian.PutUint32(seed[4:], checksum)
+	rng := newXoshiro256(seed[:])
+
+	// Degree d is chosen with probability proportional to 1/d
+	weights := make([]float64, seqLen)
+	for i := range weights {
+		weights[i] = 1 / float64(i+1)
+	}
+	degree := newRandomSampler(weights).next(rng) + 1
+
+	remaining := make([]int, seqLen)
+	for i := range remaining {
+		remaining[i] = i
+	}
+	chosen := make([]int, 0, degree)
+	for range degree {
+		i := rng.nextInt(0, len(remaining)-1)
+		chosen = append(chosen, remaining[i])
+		remaining = slices.Delete(remaining, i, i+1)
+	}
+	slices.Sort(chosen)
+	return chosen
+}
+
+func xorInto(dst, src []byte) {
+	for i := range dst {
+		dst[i] ^= src[i]
+	}
+}
+
+// xoshiro256 is the xoshiro256** generator, seeded from SHA-256 as
+// BCR-2020-005 specifies so that encoders and decoders pick the same
+// fragments.
+type xoshiro256 [4]uint64
+
+func newXoshiro256(seed []byte) *xoshiro256 {
+	digest := sha256.Sum256(seed)
+	var x xoshiro256
+	for i := range x {
+		x[i] = binary.BigEndian.Uint64(digest[8*i:])
+	}
+	return &x
+}
+
+func (x *xoshiro256) next() uint64 {
+	result := bits.RotateLeft64(x[1]*5, 7) * 9
+	t := x[1] << 17
+	x[2] ^= x[0]
+	x[3] ^= x[1]
+	x[1] ^= x[2]
+	x[0] ^= x[3]
+	x[2] ^= t
+	x[3] = bits.RotateLeft64(x[3], 45)
+	return result
+}
+
+// nextDouble returns a float64 in [0, 1].
+func (x *xoshiro256) nextDouble() float64 {
+	return float64(x.next()) / (1 << 64)
+}
+
+// nextInt returns an integer in [low, high].
+func (x *xoshiro256) nextInt(low, high int) int {
+	return min(int(x.nextDouble()*float64(high-low+1))+low, high)
+}
+
+// randomSampler draws indexes with given weights by Vose's alias method, with
+// the reference implementation's exact order of operations so the draws match
+// bit for bit.
+type randomSampler struct {
+	probs   []float64
+	aliases []int
+}
+
+func newRandomSampler(weights []float64) *randomSampler {
+	n := len(weights)
+	var sum float64
+	for _, w := range weights {
+		sum += w
+	}
+	p := make([]float64, n)
+	var small, large []int
+	for i := n - 1; i >= 0; i-- {
+		p[i] = weights[i] * float64(n) / sum
+		if p[i] < 1 {
+			small = append(small, i)
+		} else {
+			large = append(large, i)
+		}
+	}
+
+	s := &randomSampler{probs: make([]float64, n), aliases: make([]int, n)}
+	for len(small) > 0 && len(large) > 0 {
+		a, g := small[len(small)-1], large[len(large)-1]
+		small, large = small[:len(small)-1], large[:len(large)-1]
+		s.probs[a], s.aliases[a] = p[a], g
+		p[g] += p[a] - 1
+		if p[g] < 1 {
+			small = append(small, g)
+		} else {
+			large = append(large, g)
+		}
+	}
+	for _, i := range large {
+		s.probs[i] = 1
+	}
+	for _, i := range small {
+		s.probs[i] = 1
+	}
+	return s
+}
+
+func (s *randomSampler) next(rng *xoshiro256) int {
+	r1, r2 := rng.nextDouble(), rng.nextDouble()
+	i := min(int(float64(len(s.probs))*r1), len(s.probs)-1)
+	if r2 < s.probs[i] {
+		return i
+	}
+	return s.aliases[i]
+}
@@ -0,0 +1,374 @@
+package ur
+
+import (
+	"encoding/binary"
+	"fmt"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+)
+
+// TypeHDKey is the UR type of an HDKey.
+const TypeHDKey = "crypto-hdkey"
+
+// Tags of the types nested in a crypto-hdkey
+const (
+	tagHDKey    = 303
+	tagKeypath  = 304
+	tagCoinInfo = 305
+)
+
+// HDKey is a crypto-hdkey (BCR-2020-007): a BIP-32 extended key with the path
+// it was derived at, which is how air-gapped signers export xpubs.
+type HDKey struct {
+	// IsMaster marks a master key, which is always private.
+	IsMaster bool
+
+	// IsPrivate marks a private key.
+	IsPrivate bool
+
+	// KeyData is 33 bytes: the compressed public key, or 0x00 and the
+	// private key.
+	KeyData []byte
+
+	// ChainCode is the 32-byte chain code, if present.
+	ChainCode []byte
+
+	// CoinType is the SLIP-44 coin type the key is for: 0 for Bitcoin, 60
+	// for Ethereum.
+	CoinType uint32
+
+	// Testnet marks a key for a test network.
+	Testnet bool
+
+	// Origin is the path the key was derived at, if known.
+	Origin *Keypath
+
+	// Children is the path of the keys a wallet derives from this key, such
+	// as 0/*, if given.
+	Children *Keypath
+
+	// ParentFingerprint is the fingerprint of the parent key, or zero if
+	// unknown.
+	ParentFingerprint uint32
+
+	// Name and Note are optional labels.
+	Name string
+	Note string
+}
+
+// Keypath is a crypto-keypath (BCR-2020-007): a derivation path and the
+// fingerprint of the key it starts from.
+type Keypath struct {
+	// Path is the derivation path.
+	Path bip32.DerivationPath
+
+	// Wildcard marks a trailing non-hardened * component, as in a children
+	// path such as 0/*.
+	Wildcard bool
+
+	// SourceFingerprint is the fingerprint of the key the path starts from,
+	// usually the master key, or zero if unknown.
+	SourceFingerprint uint32
+}
+
+// NewHDKey returns the HDKey of an extended key derived at origin, which may
+// be nil for a master key or when the path is unknown.
+func NewHDKey(key bip32.Key, origin *Keypath) *HDKey {
+	k := &HDKey{
+		IsMaster:          key.IsPrivate() && key.Depth() == 0,
+		IsPrivate:         key.IsPrivate(),
+		KeyData:           key.PublicKeyBytes(),
+		ChainCode:         key.ChainCode(),
+		Testnet:           key.Network() == bip32.TestNet,
+		Origin:            origin,
+		ParentFingerprint: binary.BigEndian.Uint32(key.ParentFingerprint()),
+	}
+	if key.IsPrivate() {
+		k.KeyData = append([]byte{0}, key.PrivateKeyBytes()...)
+	}
+	return k
+}
+
+// ExtendedKey returns the key as a BIP-32 extended key, such as an xpub to
+// import into a watch-only wallet. The depth and child index of a derived key
+// come from Origin.
+func (k *HDKey) ExtendedKey() (*bip32.ExtendedKey, error) {
+	if len(k.ChainCode) != 32 {
+		return nil, fmt.Errorf("%w: key without chain code", ErrUnsupported)
+	}
+
+	network := bip32.MainNet
+	if k.Testnet {
+		network = bip32.TestNet
+	}
+	version := network.PublicKeyID
+	if k.IsPrivate || k.IsMaster {
+		version = network.PrivateKeyID
+	}
+
+	var depth int
+	var childIndex, parentFingerprint uint32
+	if !k.IsMaster {
+		if k.Origin == nil || len(k.Origin.Path) == 0 || len(k.Origin.Path) > 255 {
+			return nil, fmt.Errorf("%w: derived key without origin path", ErrUnsupported)
+		}
+		depth = len(k.Origin.Path)
+		childIndex = k.Origin.Path[depth-1]
+		parentFingerprint = k.ParentFingerprint
+		// A child of the master key has the source as its parent
+		if parentFingerprint == 0 && depth == 1 {
+			parentFingerprint = k.Origin.SourceFingerprint
+		}
+	}
+
+	data := binary.BigEndian.AppendUint32(make([]byte, 0, bip32.SerializedKeyLength), version)
+	data = append(data, byte(depth))
+	data = binary.BigEndian.AppendUint32(data, parentFingerprint)
+	data = binary.BigEndian.AppendUint32(data, childIndex)
+	data = append(append(data, k.ChainCode...), k.KeyData...)
+	return bip32.DeserializeExtendedKey(data)
+}
+
+// UR returns the key as a crypto-hdkey UR.
+func (k *HDKey) UR() *UR {
+	var w cborWriter
+	k.encode(&w)
+	return &UR{Type: TypeHDKey, CBOR: w.buf}
+}
+
+// DecodeHDKey decodes a crypto-hdkey UR.
+func DecodeHDKey(u *UR) (*HDKey, error) {
+	if u.Type != TypeHDKey {
+		return nil, fmt.Errorf("%w: %s, want %s", ErrWrongType, u.Type, TypeHDKey)
+	}
+	r := &cborReader{data: u.CBOR}
+	k, err := decodeHDKey(r)
+	if err == nil {
+		err = r.end()
+	}
+	if err != nil {
+		return nil, err
+	}
+	return k, nil
+}
+
+func (k *HDKey) encode(w *cborWriter) {
+	var m cborMap
+	if k.IsMaster {
+		m.key(1).bool(true)
+	} else if k.IsPrivate {
+		m.key(2).bool(true)
+	}
+	m.key(3).bytes(k.KeyData)
+	if len(k.ChainCode) > 0 {
+		m.key(4).bytes(k.ChainCode)
+	}
+	if k.IsMaster {
+		w.writeMap(&m)
+		return
+	}
+
+	if k.CoinType != 0 || k.Testnet {
+		var info cborMap
+		if k.CoinType != 0 {
+			info.key(1).uint(uint64(k.CoinType))
+		}
+		if k.Testnet {
+			info.key(2).uint(1)
+		}
+		v := m.key(5)
+		v.tag(tagCoinInfo)
+		v.writeMap(&info)
+	}
+	if k.Origin != nil {
+		k.Origin.encode(m.key(6))
+	}
+	if k.Children != nil {
+		k.Children.encode(m.key(7))
+	}
+	if k.ParentFingerprint != 0 {
+		m.key(8).uint(uint64(k.ParentFingerprint))
+	}
+	if k.Name != "" {
+		m.key(9).text(k.Name)
+	}
+	if k.Note != "" {
+		m.key(10).text(k.Note)
+	}
+	w.writeMap(&m)
+}
+
+// decodeHDKey decodes a crypto-hdkey, tagged or not.
+func decodeHDKey(r *cborReader) (*HDKey, error) {
+	if err := r.optionalTag(tagHDKey); err != nil {
+		return nil, err
+	}
+
+	k := &HDKey{}
+	err := r.readMap(func(key uint64) (bool, error) {
+		var err error
+		switch key {
+		case 1:
+			k.IsMaster, err = r.bool()
+		case 2:
+			k.IsPrivate, err = r.bool()
+		case 3:
+			k.KeyData, err = r.bytes()
+		case 4:
+			k.ChainCode, err = r.bytes()
+		case 5:
+			err = k.decodeCoinInfo(r)
+		case 6:
+			k.Origin, err = decodeKeypath(r)
+		case 7:
+			k.Children, err = decodeKeypath(r)
+		case 8:
+			k.ParentFingerprint, err = r.uint32()
+		case 9:
+			k.Name, err = r.text()
+		case 10:
+			k.Note, err = r.text()
+		default:
+			return false, nil
+		}
+		return true, err
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	if len(k.KeyData) != 33 {
+		return nil, fmt.Errorf("%w: key data of %d bytes", ErrInvalidCBOR, len(k.KeyData))
+	}
+	if (k.IsMaster || k.IsPrivate) != (k.KeyData[0] == 0) {
+		return nil, fmt.Errorf("%w: key data does not match the private flag", ErrInvalidCBOR)
+	}
+	if k.IsMaster {
+		k.IsPrivate = true
+	}
+	return k, nil
+}
+
+func (k *HDKey) decodeCoinInfo(r *cborReader) error {
+	if err := r.tag(tagCoinInfo); err != nil {
+		return err
+	}
+	return r.readMap(func(key uint64) (bool, error) {
+		switch key {
+		case 1:
+			coinType, err := r.uint32()
+			k.CoinType = coinType
+			return true, err
+		case 2:
+			network, err := r.uint()
+			k.Testnet = network == 1
+			return true, err
+		default:
+			return false, nil
+		}
+	})
+}
+
+func (p *Keypath) encode(w *cborWriter) {
+	count := len(p.Path)
+	if p.Wildcard {
+		count++
+	}
+
+	// Components are pairs of an index and whether it is hardened
+	var m cborMap
+	components := m.key(1)
+	components.array(2 * count)
+	for _, index := range p.Path {
+		components.uint(uint64(index &^ bip32.HardenedKeyStart))
+		components.bool(bip32.IsHardened(index))
+	}
+	if p.Wildcard {
+		components.array(0)
+		components.bool(false)
+	}
+	if p.SourceFingerprint != 0 {
+		m.key(2).uint(uint64(p.SourceFingerprint))
+	}
+	w.tag(tagKeypath)
+	w.writeMap(&m)
+}
+
+// decodeKeypath decodes a tagged crypto-keypath. Child index ranges and
+// hardened wildcards are not supported.
+func decodeKeypath(r *cborReader) (*Keypath, error) {
+	if err := r.tag(tagKeypath); err != nil {
+		return nil, err
+	}
+
+	p := &Keypath{}
+	err := r.readMap(func(key uint64) (bool, error) {
+		switch key {
+		case 1:
+			return true, p.decodeComponents(r)
+		case 2:
+			fingerprint, err := r.uint32()
+			p.SourceFingerprint = fingerprint
+			return true, err
+		default:
+			return false, nil
+		}
+	})
+	if err != nil {
+		return nil, err
+	}
+	return p, nil
+}
+
+func (p *Keypath) decodeComponents(r *cborReader) error {
+	n, err := r.array()
+	if err != nil {
+		return err
+	}
+	if n%2 != 0 {
+		return fmt.Errorf("%w: keypath with %d items", ErrInvalidCBOR, n)
+	}
+
+	for range n / 2 {
+		if p.Wildcard {
+			return fmt.Errorf("%w: keypath component after a wildcard", ErrUnsupported)
+		}
+		major, _, err := r.peek()
+		if err != nil {
+			return err
+		}
+
+		// An index, or [] for a wildcard or [low, high] for a range
+		var index uint32
+		if major == majorArray {
+			size, err := r.array()
+			if err != nil {
+				return err
+			}
+			if size != 0 {
+				return fmt.Errorf("%w: keypath index range", ErrUnsupported)
+			}
+			p.Wildcard = true
+		} else if index, err = r.uint32(); err != nil {
+			return err
+		}
+		hardened, err := r.bool()
+		if err != nil {
+			return err
+		}
+
+		switch {
+		case p.Wildcard && hardened:
+			return fmt.Errorf("%w: hardened wildcard", ErrUnsupported)
+		case p.Wildcard:
+			// Kept as the Wildcard flag
+		case index >= bip32.HardenedKeyStart:
+			return fmt.Errorf("%w: keypath index %d", ErrInvalidCBOR, index)
+		case hardened:
+			p.Path = append(p.Path, bip32.Hardened(index))
+		default:
+			p.Path = append(p.Path, index)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,125 @@
+package ur
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+// TypeSeed is the UR type of a Seed.
+const TypeSeed = "crypto-seed"
+
+// Date tags: days since the epoch (RFC 8943), which Seed writes, and epoch
+// seconds, which older encoders used.
+const (
+	tagDays      = 100
+	tagEpochTime = 1
+)
+
+// Seed is a crypto-seed (BCR-2020-006): a binary seed, usually the entropy of
+// a BIP-39 mnemonic, with optional metadata.
+type Seed struct {
+	// Payload is the seed.
+	Payload []byte
+
+	// CreationDate is the day the seed was created, or zero if unknown.
+	CreationDate time.Time
+
+	// Name and Note are optional labels.
+	Name string
+	Note string
+}
+
+// NewSeedFromMnemonic returns the Seed of a BIP-39 mnemonic's entropy, the
+// form air-gapped signers import and export.
+func NewSeedFromMnemonic(mnemonic string) (*Seed, error) {
+	entropy, err := bip39.MnemonicToEntropy(mnemonic)
+	if err != nil {
+		return nil, err
+	}
+	return &Seed{Payload: entropy}, nil
+}
+
+// Mnemonic returns the BIP-39 mnemonic of the payload.
+func (s *Seed) Mnemonic() (string, error) {
+	return bip39.NewMnemonic(s.Payload)
+}
+
+// UR returns the seed as a crypto-seed UR.
+func (s *Seed) UR() *UR {
+	var m cborMap
+	m.key(1).bytes(s.Payload)
+	if !s.CreationDate.IsZero() && s.CreationDate.Unix() >= 0 {
+		w := m.key(2)
+		w.tag(tagDays)
+		w.uint(uint64(s.CreationDate.Unix() / 86400))
+	}
+	if s.Name != "" {
+		m.key(3).text(s.Name)
+	}
+	if s.Note != "" {
+		m.key(4).text(s.Note)
+	}
+
+	var w cborWriter
+	w.writeMap(&m)
+	return &UR{Type: TypeSeed, CBOR: w.buf}
+}
+
+// DecodeSeed decodes a crypto-seed UR.
+func DecodeSeed(u *UR) (*Seed, error) {
+	if u.Type != TypeSeed {
+		return nil, fmt.Errorf("%w: %s, want %s", ErrWrongType, u.Type, TypeSeed)
+	}
+
+	s := &Seed{}
+	r := &cborReader{data: u.CBOR}
+	err := r.readMap(func(key uint64) (bool, error) {
+		var err error
+		switch key {
+		case 1:
+			s.Payload, err = r.bytes()
+		case 2:
+			s.CreationDate, err = r.date()
+		case 3:
+			s.Name, err = r.text()
+		case 4:
+			s.Note, err = r.text()
+		default:
+			return false, nil
+		}
+		return true, err
+	})
+	if err == nil {
+		err = r.end()
+	}
+	if err != nil {
+		return nil, err
+	}
+	if len(s.Payload) == 0 {
+		return nil, fmt.Errorf("%w: seed without payload", ErrInvalidCBOR)
+	}
+	return s, nil
+}
+
+// date reads a date tagged as days or seconds since the epoch.
+func (r *cborReader) date() (time.Time, error) {
+	tag, err := r.expect(majorTag)
+	if err != nil {
+		return time.Time{}, err
+	}
+	n, err := r.uint()
+	if err != nil {
+		return time.Time{}, err
+	}
+	// Both bounds keep the seconds within int64
+	switch {
+	case tag == tagDays && n < 1<<32:
+		return time.Unix(int64(n)*86400, 0).UTC(), nil
+	case tag == tagEpochTime && n < 1<<62:
+		return time.Unix(int64(n), 0).UTC(), nil
+	default:
+		return time.Time{}, fmt.Errorf("%w: date with tag %d", ErrInvalidCBOR, tag)
+	}
+}
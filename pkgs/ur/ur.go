@@ -0,0 +1,129 @@
+// Package ur implements Uniform Resources (BCR-2020-005), the encoding
+// air-gapped signers such as Keystone and SeedSigner use to exchange seeds,
+// extended public keys and account descriptors through QR codes. A UR is a
+// typed CBOR message written in Bytewords; messages too large for one QR code
+// are split into a fountain-coded sequence of parts for an animated QR code.
+package ur
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+)
+
+var (
+	// ErrInvalidUR is returned for a string that is not a well-formed UR.
+	ErrInvalidUR = errors.New("ur: invalid UR")
+
+	// ErrInvalidBytewords is returned for Bytewords that do not decode.
+	ErrInvalidBytewords = errors.New("ur: invalid bytewords")
+
+	// ErrInvalidChecksum is returned when the CRC-32 of a UR or of a
+	// reassembled multi-part message does not match.
+	ErrInvalidChecksum = errors.New("ur: invalid checksum")
+
+	// ErrInvalidCBOR is returned for a malformed CBOR message or one that
+	// does not match the registry type's structure.
+	ErrInvalidCBOR = errors.New("ur: invalid CBOR")
+
+	// ErrWrongType is returned when decoding a UR of another type, such as a
+	// crypto-hdkey passed to DecodeSeed.
+	ErrWrongType = errors.New("ur: wrong UR type")
+
+	// ErrInvalidPart is returned for a multi-part UR part that does not
+	// belong to the message being decoded.
+	ErrInvalidPart = errors.New("ur: invalid part")
+
+	// ErrIncomplete is returned by Decoder.Result before all parts arrived.
+	ErrIncomplete = errors.New("ur: message incomplete")
+
+	// ErrUnsupported is returned for registry values this package does not
+	// model, such as multisig or Taproot outputs.
+	ErrUnsupported = errors.New("ur: unsupported value")
+)
+
+// UR is a single Uniform Resource: a registry type and its CBOR message.
+type UR struct {
+	// Type is the registry type, such as crypto-seed or crypto-account.
+	Type string
+
+	// CBOR is the message.
+	CBOR []byte
+}
+
+// New returns a UR of the given type. Types are lower-case letters, digits
+// and hyphens.
+func New(typ string, cbor []byte) (*UR, error) {
+	if !isValidType(typ) {
+		return nil, fmt.Errorf("%w: type %q", ErrInvalidUR, typ)
+	}
+	return &UR{Type: typ, CBOR: cbor}, nil
+}
+
+// Parse parses a single-part UR such as ur:crypto-seed/oeadgd.... It is case
+// insensitive, since QR codes carry URs upper-cased. Parts of a multi-part UR
+// are rejected; feed them to a Decoder instead.
+func Parse(s string) (*UR, error) {
+	typ, components, err := splitUR(s)
+	if err != nil {
+		return nil, err
+	}
+	if len(components) != 1 {
+		return nil, fmt.Errorf("%w: %s is part of a multi-part UR", ErrInvalidUR, components[0])
+	}
+	cbor, err := DecodeBytewords(components[0])
+	if err != nil {
+		return nil, err
+	}
+	return &UR{Type: typ, CBOR: cbor}, nil
+}
+
+// String returns the single-part encoding of the UR.
+func (u *UR) String() string {
+	return "ur:" + u.Type + "/" + EncodeBytewords(u.CBOR)
+}
+
+// MarshalText returns the single-part encoding of the UR.
+func (u *UR) MarshalText() ([]byte, error) {
+	return []byte(u.String()), nil
+}
+
+// UnmarshalText parses a single-part UR with Parse.
+func (u *UR) UnmarshalText(text []byte) error {
+	parsed, err := Parse(string(text))
+	if err != nil {
+		return err
+	}
+	*u = *parsed
+	return nil
+}
+
+// splitUR splits a UR into its type and the path components after it: the
+// Bytewords of a single-part UR, or the sequence and Bytewords of a part.
+func splitUR(s string) (string, []string, error) {
+	s = strings.ToLower(strings.TrimSpace(s))
+	rest, ok := strings.CutPrefix(s, "ur:")
+	if !ok {
+		return "", nil, fmt.Errorf("%w: missing ur: scheme", ErrInvalidUR)
+	}
+	components := strings.Split(rest, "/")
+	if len(components) < 2 || len(components) > 3 {
+		return "", nil, fmt.Errorf("%w: %d path components", ErrInvalidUR, len(components))
+	}
+	if !isValidType(components[0]) {
+		return "", nil, fmt.Errorf("%w: type %q", ErrInvalidUR, components[0])
+	}
+	return components[0], components[1:], nil
+}
+
+func isValidType(typ string) bool {
+	if typ == "" {
+		return false
+	}
+	for _, c := range typ {
+		if (c < 'a' || c > 'z') && (c < '0' || c > '9') && c != '-' {
+			return false
+		}
+	}
+	return true
+}
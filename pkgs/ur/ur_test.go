@@ -0,0 +1,329 @@
+package ur
+
+import (
+	"encoding/hex"
+	"errors"
+	"hash/crc32"
+	"slices"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+// makeMessage returns the reference implementation's deterministic test
+// message.
+func makeMessage(n int) []byte {
+	rng := newXoshiro256([]byte("Wolf"))
+	message := make([]byte, n)
+	for i := range message {
+		message[i] = byte(rng.nextInt(0, 255))
+	}
+	return message
+}
+
+// makeMessageUR wraps a test message as a CBOR byte string in a bytes UR.
+func makeMessageUR(n int) *UR {
+	var w cborWriter
+	w.bytes(makeMessage(n))
+	return &UR{Type: "bytes", CBOR: w.buf}
+}
+
+func TestBytewords(t *testing.T) {
+	data := []byte{0, 1, 2, 128, 255}
+	if got := EncodeBytewords(data); got != "aeadaolazmjendeoti" {
+		t.Errorf("EncodeBytewords() = %s", got)
+	}
+	decoded, err := DecodeBytewords("AEADAOLAZMJENDEOTI")
+	if err != nil || !slices.Equal(decoded, data) {
+		t.Errorf("DecodeBytewords() = %x, %v", decoded, err)
+	}
+
+	for _, s := range []string{"aeadaolazmjendeotj", "aeadaolazmjendeot", "aeadaolazmjendeoto", "aead"} {
+		if _, err := DecodeBytewords(s); err == nil {
+			t.Errorf("DecodeBytewords(%q) succeeded", s)
+		}
+	}
+	if _, err := DecodeBytewords("aeadaolazmjendeota"); !errors.Is(err, ErrInvalidChecksum) {
+		t.Errorf("DecodeBytewords() with a bad checksum error = %v, want ErrInvalidChecksum", err)
+	}
+}
+
+// Vectors from the reference implementation's tests (bc-ur)
+func TestFountainReferenceVectors(t *testing.T) {
+	rng := newXoshiro256([]byte("Wolf"))
+	var got []uint64
+	for range 10 {
+		got = append(got, rng.next()%100)
+	}
+	if want := []uint64{42, 81, 85, 8, 82, 84, 76, 73, 70, 88}; !slices.Equal(got, want) {
+		t.Errorf("xoshiro256 = %v, want %v", got, want)
+	}
+
+	checksum := crc32.ChecksumIEEE(makeMessage(1024))
+	var indexes [][]int
+	for seqNum := uint32(10); seqNum <= 17; seqNum++ {
+		indexes = append(indexes, chooseFragments(seqNum, 11, checksum))
+	}
+	want := [][]int{{9}, {10}, {9}, {2, 5, 6, 8, 9, 10}, {8}, {1, 5}, {1}, {0, 2, 4, 5, 8, 10}}
+	if !slices.EqualFunc(indexes, want, slices.Equal) {
+		t.Errorf("chooseFragments() = %v, want %v", indexes, want)
+	}
+
+	single := makeMessageUR(50)
+	if got := single.String(); got != "ur:bytes/hdeymejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtgwdpfnsboxgwlbaawzuefywkdplrsrjynbvygabwjldapfcsdwkbrkch" {
+		t.Errorf("single-part UR = %s", got)
+	}
+
+	e := NewEncoder(makeMessageUR(256), 30)
+	var parts []string
+	for range 12 {
+		parts = append(parts, e.NextPart())
+	}
+	for i, want := range map[int]string{
+		0:  "ur:bytes/1-9/lpadascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtdkgslpgh",
+		8:  "ur:bytes/9-9/lpasascfadaxcywenbpljkhdcajskecpmdckihdyhphfotjojtfmlnwmadspaxrkytbztpbauotbgtgtaeaevtgavtny",
+		9:  "ur:bytes/10-9/lpbkascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtwdkiplzs",
+		11: "ur:bytes/12-9/lpbnascfadaxcywenbpljkhdcarllaluzmdmgstospeyiefmwejlwtpedamktksrvlcygmzemovovllarodtmtbnptrs",
+	} {
+		if parts[i] != want {
+			t.Errorf("part %d = %s, want %s", i+1, parts[i], want)
+		}
+	}
+}
+
+func TestEncoderDecoder(t *testing.T) {
+	u := makeMessageUR(32767)
+	for _, seed := range []string{"a", "b", "c"} {
+		e := NewEncoder(u, 1000)
+		d := NewDecoder()
+		rng := newXoshiro256([]byte(seed))
+		for n := 0; !d.Complete(); n++ {
+			part := e.NextPart()
+			// Drop a third of the frames, as a camera would
+			if rng.nextInt(0, 2) == 0 {
+				continue
+			}
+			if err := d.Receive(strings.ToUpper(part)); err != nil {
+				t.Fatalf("Receive(%s) error = %v", part[:20], err)
+			}
+			if n > 10*e.SeqLen() {
+				t.Fatalf("seed %s: not complete after %d parts, progress %.2f", seed, n, d.Progress())
+			}
+		}
+		got, err := d.Result()
+		if err != nil || got.Type != "bytes" || !slices.Equal(got.CBOR, u.CBOR) {
+			t.Errorf("seed %s: Result() = %v, %v", seed, got, err)
+		}
+	}
+
+	// A part of another message is rejected
+	d := NewDecoder()
+	if _, err := d.Result(); !errors.Is(err, ErrIncomplete) {
+		t.Errorf("Result() before any part error = %v, want ErrIncomplete", err)
+	}
+	if err := d.Receive(NewEncoder(makeMessageUR(256), 30).NextPart()); err != nil {
+		t.Fatal(err)
+	}
+	if err := d.Receive(NewEncoder(makeMessageUR(300), 30).NextPart()); !errors.Is(err, ErrInvalidPart) {
+		t.Errorf("Receive() of another message error = %v, want ErrInvalidPart", err)
+	}
+
+	// A single-part UR completes the decoder at once
+	small := NewEncoder(makeMessageUR(50), DefaultMaxFragmentLen)
+	if !small.IsSinglePart() {
+		t.Fatal("a 52-byte message is not a single-part UR")
+	}
+	d = NewDecoder()
+	if err := d.Receive(small.NextPart()); err != nil || !d.Complete() {
+		t.Errorf("Receive(single part) error = %v, complete = %v", err, d.Complete())
+	}
+}
+
+func TestParse(t *testing.T) {
+	u, err := Parse("UR:BYTES/HDEYMEJTSWHHYLKEPMYKHHTSYTSNOYOYAXAEDSUTTYDMMHHPKTPMSRJTGWDPFNSBOXGWLBAAWZUEFYWKDPLRSRJYNBVYGABWJLDAPFCSDWKBRKCH")
+	if err != nil || !slices.Equal(u.CBOR, makeMessageUR(50).CBOR) {
+		t.Errorf("Parse() = %v, %v", u, err)
+	}
+
+	for _, s := range []string{
+		"bytes/hdeymejtswhhylkepmyk",
+		"ur:bytes",
+		"ur:by_tes/aeadaolazmjendeoti",
+		"ur:bytes/1-9/lpadascfadaxcywenbpljkhdcahkadaemejtswhhylkepmykhhtsytsnoyoyaxaedsuttydmmhhpktpmsrjtdkgslpgh",
+	} {
+		if _, err := Parse(s); !errors.Is(err, ErrInvalidUR) {
+			t.Errorf("Parse(%q) error = %v, want ErrInvalidUR", s, err)
+		}
+	}
+}
+
+// BCR-2020-006 example
+func TestSeed(t *testing.T) {
+	seed := &Seed{
+		Payload:      mustHex(t, "c7098580125e2ab0981253468b2dbc52"),
+		CreationDate: time.Date(2020, 5, 12, 0, 0, 0, 0, time.UTC),
+	}
+	want := "ur:crypto-seed/oeadgdstaslplabghydrpfmkbggufgludprfgmaotpiecffltnlpqdenos"
+	if got := seed.UR().String(); got != want {
+		t.Errorf("Seed.UR() = %s, want %s", got, want)
+	}
+
+	u, err := Parse(want)
+	if err != nil {
+		t.Fatal(err)
+	}
+	decoded, err := DecodeSeed(u)
+	if err != nil || !slices.Equal(decoded.Payload, seed.Payload) || !decoded.CreationDate.Equal(seed.CreationDate) {
+		t.Errorf("DecodeSeed() = %+v, %v", decoded, err)
+	}
+
+	fromMnemonic, err := NewSeedFromMnemonic(testMnemonic)
+	if err != nil {
+		t.Fatal(err)
+	}
+	fromMnemonic.Name = "test"
+	decoded, err = DecodeSeed(fromMnemonic.UR())
+	if err != nil {
+		t.Fatal(err)
+	}
+	if mnemonic, err := decoded.Mnemonic(); err != nil || mnemonic != testMnemonic || decoded.Name != "test" {
+		t.Errorf("Mnemonic() = %q, %v; name %q", mnemonic, err, decoded.Name)
+	}
+
+	if _, err := DecodeSeed(&UR{Type: TypeHDKey, CBOR: u.CBOR}); !errors.Is(err, ErrWrongType) {
+		t.Errorf("DecodeSeed(crypto-hdkey) error = %v, want ErrWrongType", err)
+	}
+}
+
+// BCR-2020-007 master key example, the master key of BIP-32 test vector 1
+func TestHDKey(t *testing.T) {
+	master, err := bip32.NewMasterKey(mustHex(t, "000102030405060708090a0b0c0d0e0f"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := "ur:crypto-hdkey/otadykaxhdclaevswfdmjpfswpwkahcywspsmndwmusoskprbbehetchsnpfcybbmwrhchspfxjeecaahdcxltfszmlyrtdlgmhfcnzcctvwcmkbpsftgonbgauefsehgrqzdmvodizmweemtlaybakiylat"
+	if got := NewHDKey(master, nil).UR().String(); got != want {
+		t.Errorf("HDKey.UR() = %s, want %s", got, want)
+	}
+
+	u, _ := Parse(want)
+	decoded, err := DecodeHDKey(u)
+	if err != nil {
+		t.Fatal(err)
+	}
+	key, err := decoded.ExtendedKey()
+	if err != nil || key.String() != master.String() {
+		t.Errorf("ExtendedKey() = %v, %v", key, err)
+	}
+
+	// A derived xpub with its origin, use info and children
+	path := bip32.MustParsePath("m/44'/1'/1'/0/1")
+	child, _ := master.DeriveFromPath(path)
+	xpub, _ := child.Neuter()
+	hd := NewHDKey(xpub, &Keypath{Path: path, SourceFingerprint: 0x3442193e})
+	hd.CoinType, hd.Testnet = 1, true
+	hd.Children = &Keypath{Path: bip32.DerivationPath{1}, Wildcard: true}
+	hd.Name = "test key"
+
+	decoded, err = DecodeHDKey(hd.UR())
+	if err != nil {
+		t.Fatal(err)
+	}
+	if decoded.CoinType != 1 || !decoded.Testnet || decoded.Name != "test key" ||
+		!slices.Equal(decoded.Origin.Path, path) || decoded.Origin.SourceFingerprint != 0x3442193e ||
+		!slices.Equal(decoded.Children.Path, bip32.DerivationPath{1}) || !decoded.Children.Wildcard {
+		t.Errorf("DecodeHDKey() = %+v", decoded)
+	}
+	if decoded.ParentFingerprint != hd.ParentFingerprint || decoded.IsPrivate || decoded.IsMaster {
+		t.Errorf("DecodeHDKey() parent %08x, private %v, master %v", decoded.ParentFingerprint, decoded.IsPrivate, decoded.IsMaster)
+	}
+	key, err = decoded.ExtendedKey()
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !slices.Equal(key.PublicKeyBytes(), xpub.PublicKeyBytes()) || key.Depth() != 5 || key.ChildIndex() != 1 {
+		t.Errorf("ExtendedKey() = %v", key)
+	}
+}
+
+func TestAccount(t *testing.T) {
+	master, err := bip32.NewMasterKey(bip39.NewSeed(testMnemonic, ""))
+	if err != nil {
+		t.Fatal(err)
+	}
+	acct, err := NewAccount(master, 0)
+	if err != nil {
+		t.Fatal(err)
+	}
+	u, err := acct.UR()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	// An account takes several frames at 100-byte fragments
+	e := NewEncoder(u, 100)
+	d := NewDecoder()
+	for !d.Complete() {
+		if err := d.Receive(e.NextPart()); err != nil {
+			t.Fatal(err)
+		}
+	}
+	if e.SeqLen() < 2 {
+		t.Errorf("SeqLen() = %d for a %d-byte account", e.SeqLen(), len(u.CBOR))
+	}
+	received, err := d.Result()
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	decoded, err := DecodeAccount(received)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if decoded.MasterFingerprint != 0x73c5da0a || len(decoded.Outputs) != 3 {
+		t.Fatalf("DecodeAccount() = fingerprint %08x, %d outputs", decoded.MasterFingerprint, len(decoded.Outputs))
+	}
+	want := map[account.ScriptType]string{
+		account.ScriptP2PKH:      "1LqBGSKuX5yYUonjxT5qGfpUsXKYYWeabA",
+		account.ScriptP2SHP2WPKH: "37VucYSaXLCAsxYyAPfbSi9eh4iEcbShgf",
+		account.ScriptP2WPKH:     "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+	}
+	for _, o := range decoded.Outputs {
+		watch, err := o.WatchAccount(0)
+		if err != nil {
+			t.Fatalf("%s WatchAccount() error = %v", o.ScriptType, err)
+		}
+		if watch.Address() != want[o.ScriptType] {
+			t.Errorf("%s address = %s, want %s", o.ScriptType, watch.Address(), want[o.ScriptType])
+		}
+	}
+
+	// A Taproot output is not modeled
+	var m cborMap
+	m.key(1).uint(0x73c5da0a)
+	outputs := m.key(2)
+	outputs.array(1)
+	outputs.tag(409)
+	outputs.tag(tagHDKey)
+	acct.Outputs[0].Key.encode(outputs)
+	var w cborWriter
+	w.writeMap(&m)
+	if _, err := DecodeAccount(&UR{Type: TypeAccount, CBOR: w.buf}); !errors.Is(err, ErrUnsupported) {
+		t.Errorf("DecodeAccount(tr) error = %v, want ErrUnsupported", err)
+	}
+}
+
+func mustHex(t *testing.T, s string) []byte {
+	t.Helper()
+	b, err := hex.DecodeString(s)
+	if err != nil {
+		t.Fatal(err)
+	}
+	return b
+}
@@ -0,0 +1,95 @@
+package uri
+
+import (
+	"fmt"
+	"net/url"
+	"sort"
+	"strings"
+)
+
+// BitcoinScheme is the BIP-21 URI scheme.
+const BitcoinScheme = "bitcoin"
+
+// BitcoinRequest is a BIP-21 payment request:
+//
+//	bitcoin:<address>[?amount=<btc>][&label=<label>][&message=<message>]
+type BitcoinRequest struct {
+	Address string
+	Amount  string // decimal BTC, e.g. "0.0015"; empty lets the payer choose
+	Label   string
+	Message string
+
+	// Params holds any other parameters, such as lightning or pj. Keys with the
+	// req- prefix must be understood by the payer.
+	Params map[string]string
+}
+
+// String returns the request as a URI. Known parameters come first, in BIP-21
+// order, followed by Params sorted by key.
+func (r *BitcoinRequest) String() string {
+	var params []string
+	if r.Amount != "" {
+		params = append(params, "amount="+r.Amount)
+	}
+	if r.Label != "" {
+		params = append(params, "label="+escape(r.Label))
+	}
+	if r.Message != "" {
+		params = append(params, "message="+escape(r.Message))
+	}
+
+	keys := make([]string, 0, len(r.Params))
+	for key := range r.Params {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	for _, key := range keys {
+		params = append(params, escape(key)+"="+escape(r.Params[key]))
+	}
+
+	if len(params) == 0 {
+		return BitcoinScheme + ":" + r.Address
+	}
+	return BitcoinScheme + ":" + r.Address + "?" + strings.Join(params, "&")
+}
+
+// ParseBitcoin parses a BIP-21 URI. The scheme is matched case-insensitively
+// and the amount must be a plain decimal with at most 8 decimals. Unknown req-
+// parameters make the whole URI invalid, as BIP-21 requires; the address is
+// not validated.
+func ParseBitcoin(s string) (*BitcoinRequest, error) {
+	addr, query, err := splitScheme(s, BitcoinScheme)
+	if err != nil {
+		return nil, err
+	}
+	values, err := url.ParseQuery(query)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %w", ErrInvalidURI, err)
+	}
+
+	r := &BitcoinRequest{Address: addr}
+	for key, vals := range values {
+		if len(vals) != 1 {
+			return nil, fmt.Errorf("%w: repeated parameter %q", ErrInvalidURI, key)
+		}
+		switch value := vals[0]; {
+		case key == "amount":
+			if _, err := ParseUnits(value, BitcoinDecimals); err != nil {
+				return nil, err
+			}
+			r.Amount = value
+		case key == "label":
+			r.Label = value
+		case key == "message":
+			r.Message = value
+		case strings.HasPrefix(key, "req-"):
+			return nil, fmt.Errorf("%w: %s", ErrUnsupportedParam, key)
+		default:
+			if r.Params == nil {
+				r.Params = make(map[string]string)
+			}
+			r.Params[key] = value
+		}
+	}
+	return r, nil
+}
@@ -0,0 +1,140 @@
+package uri
+
+import (
+	"fmt"
+	"math/big"
+	"net/url"
+	"strconv"
+	"strings"
+)
+
+// EthereumScheme is the EIP-681 URI scheme.
+const EthereumScheme = "ethereum"
+
+// EthereumRequest is an EIP-681 transaction request:
+//
+//	ethereum:[pay-]<target>[@<chain_id>][/<function>][?<params>]
+//
+// For a plain payment the target is the recipient and the value parameter is
+// the amount in wei; for a contract call the target is the contract.
+type EthereumRequest struct {
+	Target   string
+	ChainID  uint64 // 0 leaves the network to the wallet
+	Function string // e.g. "transfer"; empty for a plain payment
+	Params   url.Values
+}
+
+// NewEthereumPayment returns a request for wei sent to an address. A nil
+// amount lets the payer choose.
+func NewEthereumPayment(to string, chainID uint64, wei *big.Int) *EthereumRequest {
+	r := &EthereumRequest{Target: to, ChainID: chainID, Params: url.Values{}}
+	if wei != nil {
+		r.Params.Set("value", wei.String())
+	}
+	return r
+}
+
+// NewERC20Transfer returns a request for an ERC-20 transfer of amount base
+// units of token to an address.
+func NewERC20Transfer(token, to string, chainID uint64, amount *big.Int) *EthereumRequest {
+	return &EthereumRequest{
+		Target:   token,
+		ChainID:  chainID,
+		Function: "transfer",
+		Params: url.Values{
+			"address": {to},
+			"uint256": {amount.String()},
+		},
+	}
+}
+
+// String returns the request as a URI, with parameters sorted by key.
+func (r *EthereumRequest) String() string {
+	var sb strings.Builder
+	sb.WriteString(EthereumScheme + ":" + r.Target)
+	if r.ChainID != 0 {
+		sb.WriteString("@" + strconv.FormatUint(r.ChainID, 10))
+	}
+	if r.Function != "" {
+		sb.WriteString("/" + r.Function)
+	}
+	if len(r.Params) > 0 {
+		sb.WriteString("?" + strings.ReplaceAll(r.Params.Encode(), "+", "%20"))
+	}
+	return sb.String()
+}
+
+// Value returns the value parameter in wei, or nil when it is absent.
+func (r *EthereumRequest) Value() (*big.Int, error) {
+	if !r.Params.Has("value") {
+		return nil, nil
+	}
+	return ParseNumber(r.Params.Get("value"))
+}
+
+// ParseEthereum parses an EIP-681 URI. The pay- prefix is accepted and
+// dropped; the target is not validated, so ENS names are kept as given.
+func ParseEthereum(s string) (*EthereumRequest, error) {
+	body, query, err := splitScheme(s, EthereumScheme)
+	if err != nil {
+		return nil, err
+	}
+	body = strings.TrimPrefix(body, "pay-")
+
+	r := &EthereumRequest{}
+	body, r.Function, _ = strings.Cut(body, "/")
+	body, chainID, hasChain := strings.Cut(body, "@")
+	if body == "" {
+		return nil, fmt.Errorf("%w: missing target", ErrInvalidURI)
+	}
+	r.Target = body
+	if hasChain {
+		if r.ChainID, err = strconv.ParseUint(chainID, 10, 64); err != nil || r.ChainID == 0 {
+			return nil, fmt.Errorf("%w: chain id %q", ErrInvalidURI, chainID)
+		}
+	}
+
+	if r.Params, err = url.ParseQuery(query); err != nil {
+		return nil, fmt.Errorf("%w: %w", ErrInvalidURI, err)
+	}
+	for _, key := range []string{"value", "gas", "gasLimit", "gasPrice", "uint256"} {
+		if r.Params.Has(key) {
+			if _, err := ParseNumber(r.Params.Get(key)); err != nil {
+				return nil, fmt.Errorf("%w: %s: %w", ErrInvalidURI, key, err)
+			}
+		}
+	}
+	return r, nil
+}
+
+// ParseNumber parses an EIP-681 number: an integer, optionally written in
+// scientific notation such as "2.014e18", that must come out whole.
+func ParseNumber(s string) (*big.Int, error) {
+	mantissa, exp, hasExp := strings.Cut(strings.ToLower(s), "e")
+	whole, frac, _ := strings.Cut(mantissa, ".")
+	if whole == "" || !isDigits(whole) || !isDigits(frac) {
+		return nil, fmt.Errorf("%w: %q", ErrInvalidAmount, s)
+	}
+
+	shift := -len(frac)
+	if hasExp {
+		e, err := strconv.Atoi(exp)
+		if err != nil || e < 0 || e > 256 {
+			return nil, fmt.Errorf("%w: exponent in %q", ErrInvalidAmount, s)
+		}
+		shift += e
+	}
+
+	digits := whole + frac
+	if shift < 0 {
+		// Only trailing zeros may be dropped
+		if strings.Trim(digits[len(digits)+shift:], "0") != "" {
+			return nil, fmt.Errorf("%w: %q is not a whole number", ErrInvalidAmount, s)
+		}
+		digits = digits[:len(digits)+shift]
+		shift = 0
+	}
+
+	n, _ := new(big.Int).SetString("0"+digits+strings.Repeat("0", shift), 10)
+	return n, nil
+}
@@ -0,0 +1,117 @@
+package uri
+
+import (
+	"fmt"
+	"net/url"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+// SolanaScheme is the Solana Pay URI scheme.
+const SolanaScheme = "solana"
+
+// SolanaRequest is a Solana Pay transfer request:
+//
+//	solana:<recipient>?amount=<amount>&spl-token=<mint>&reference=<key>&label=...&message=...&memo=...
+type SolanaRequest struct {
+	Recipient  string
+	Amount     string   // decimal SOL, or token units when SPLToken is set
+	SPLToken   string   // mint address; empty for a SOL transfer
+	References []string // base58 public keys the payer adds to the transaction
+	Label      string
+	Message    string
+	Memo       string
+}
+
+// String returns the request as a URI, with parameters in Solana Pay order.
+func (r *SolanaRequest) String() string {
+	var params []string
+	if r.Amount != "" {
+		params = append(params, "amount="+r.Amount)
+	}
+	if r.SPLToken != "" {
+		params = append(params, "spl-token="+r.SPLToken)
+	}
+	for _, ref := range r.References {
+		params = append(params, "reference="+ref)
+	}
+	if r.Label != "" {
+		params = append(params, "label="+escape(r.Label))
+	}
+	if r.Message != "" {
+		params = append(params, "message="+escape(r.Message))
+	}
+	if r.Memo != "" {
+		params = append(params, "memo="+escape(r.Memo))
+	}
+
+	if len(params) == 0 {
+		return SolanaScheme + ":" + r.Recipient
+	}
+	return SolanaScheme + ":" + r.Recipient + "?" + strings.Join(params, "&")
+}
+
+// ParseSolana parses a Solana Pay transfer request. The recipient, token mint
+// and references must be 32-byte base58 public keys; transaction requests
+// (solana:https://...) are rejected.
+func ParseSolana(s string) (*SolanaRequest, error) {
+	recipient, query, err := splitScheme(s, SolanaScheme)
+	if err != nil {
+		return nil, err
+	}
+	if err := checkPublicKey(recipient); err != nil {
+		return nil, fmt.Errorf("%w: recipient: %w", ErrInvalidURI, err)
+	}
+	values, err := url.ParseQuery(query)
+	if err != nil {
+		return nil, fmt.Errorf("%w: %w", ErrInvalidURI, err)
+	}
+
+	r := &SolanaRequest{Recipient: recipient, References: values["reference"]}
+	for _, ref := range r.References {
+		if err := checkPublicKey(ref); err != nil {
+			return nil, fmt.Errorf("%w: reference: %w", ErrInvalidURI, err)
+		}
+	}
+	for key, vals := range values {
+		if key == "reference" {
+			continue
+		}
+		if len(vals) != 1 {
+			return nil, fmt.Errorf("%w: repeated parameter %q", ErrInvalidURI, key)
+		}
+		switch key {
+		case "amount":
+			// Token decimals are unknown here, so only the format is checked
+			if _, err := ParseUnits(vals[0], len(vals[0])); err != nil {
+				return nil, err
+			}
+			r.Amount = vals[0]
+		case "spl-token":
+			if err := checkPublicKey(vals[0]); err != nil {
+				return nil, fmt.Errorf("%w: spl-token: %w", ErrInvalidURI, err)
+			}
+			r.SPLToken = vals[0]
+		case "label":
+			r.Label = vals[0]
+		case "message":
+			r.Message = vals[0]
+		case "memo":
+			r.Memo = vals[0]
+		}
+	}
+	return r, nil
+}
+
+// checkPublicKey checks that s is a base58-encoded 32-byte public key.
+func checkPublicKey(s string) error {
+	decoded, err := address.Base58Decode(s)
+	if err != nil {
+		return err
+	}
+	if len(decoded) != 32 {
+		return fmt.Errorf("%d-byte key, want 32", len(decoded))
+	}
+	return nil
+}
@@ -0,0 +1,150 @@
+// Package uri builds and parses payment request URIs: BIP-21 for Bitcoin,
+// EIP-681 for Ethereum and other EVM chains, and Solana Pay transfer requests.
+package uri
+
+import (
+	"errors"
+	"fmt"
+	"math/big"
+	"net/url"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+var (
+	// ErrInvalidURI is returned when a string is not a well-formed payment URI.
+	ErrInvalidURI = errors.New("uri: invalid payment URI")
+
+	// ErrInvalidAmount is returned for a malformed or too precise amount.
+	ErrInvalidAmount = errors.New("uri: invalid amount")
+
+	// ErrUnsupportedParam is returned when a BIP-21 URI carries a required
+	// (req-) parameter this package does not understand.
+	ErrUnsupportedParam = errors.New("uri: unsupported required parameter")
+
+	// ErrUnsupportedChain is returned by ForAccount for chains without a
+	// payment URI scheme.
+	ErrUnsupportedChain = errors.New("uri: unsupported chain")
+)
+
+// Decimals of the native currency in each URI scheme
+const (
+	BitcoinDecimals  = 8
+	EthereumDecimals = 18
+	SolanaDecimals   = 9
+)
+
+// evmChainIDs maps EVM chains to their EIP-155 chain IDs for EIP-681 URIs.
+var evmChainIDs = map[address.ChainID]uint64{
+	address.ChainEthereum:        1,
+	address.ChainOptimism:        10,
+	address.ChainBSC:             56,
+	address.ChainEthereumClassic: 61,
+	address.ChainPolygon:         137,
+	address.ChainFantom:          250,
+	address.ChainTheta:           361,
+	address.ChainRonin:           2020,
+	address.ChainArbitrum:        42161,
+	address.ChainHarmony:         1666600000,
+}
+
+// ForAccount returns a payment request for an account's address: BIP-21 on
+// Bitcoin, EIP-681 on EVM chains and Solana Pay on Solana. amount is in whole
+// coins (BTC, ETH, SOL, ...) and may be empty to let the payer choose.
+func ForAccount(acc account.WatchAccount, amount string) (string, error) {
+	chain := acc.Chain()
+	switch {
+	case chain == address.ChainBitcoin:
+		if amount != "" {
+			if _, err := ParseUnits(amount, BitcoinDecimals); err != nil {
+				return "", err
+			}
+		}
+		return (&BitcoinRequest{Address: acc.Address(), Amount: amount}).String(), nil
+	case chain == address.ChainSolana:
+		if amount != "" {
+			if _, err := ParseUnits(amount, SolanaDecimals); err != nil {
+				return "", err
+			}
+		}
+		return (&SolanaRequest{Recipient: acc.Address(), Amount: amount}).String(), nil
+	case evmChainIDs[chain] != 0:
+		var wei *big.Int
+		if amount != "" {
+			var err error
+			if wei, err = ParseUnits(amount, EthereumDecimals); err != nil {
+				return "", err
+			}
+		}
+		to := acc.Address()
+		if evm, ok := acc.(interface{ EVMAddress() string }); ok {
+			to = evm.EVMAddress() // Harmony and Ronin show a native address by default
+		}
+		return NewEthereumPayment(to, evmChainIDs[chain], wei).String(), nil
+	default:
+		return "", fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+	}
+}
+
+// ParseUnits converts a decimal amount such as "0.015" to an integer count of
+// base units (satoshi, wei, lamports) with the given number of decimals.
+func ParseUnits(amount string, decimals int) (*big.Int, error) {
+	whole, frac, _ := strings.Cut(amount, ".")
+	if whole == "" && frac == "" || !isDigits(whole) || !isDigits(frac) {
+		return nil, fmt.Errorf("%w: %q", ErrInvalidAmount, amount)
+	}
+	if len(frac) > decimals {
+		return nil, fmt.Errorf("%w: %q has more than %d decimals", ErrInvalidAmount, amount, decimals)
+	}
+
+	units, _ := new(big.Int).SetString("0"+whole+frac+strings.Repeat("0", decimals-len(frac)), 10)
+	return units, nil
+}
+
+// FormatUnits converts an integer count of base units to a decimal amount
+// without trailing zeros, the inverse of ParseUnits.
+func FormatUnits(units *big.Int, decimals int) string {
+	digits := new(big.Int).Abs(units).String()
+	if len(digits) <= decimals {
+		digits = strings.Repeat("0", decimals-len(digits)+1) + digits
+	}
+
+	whole, frac := digits[:len(digits)-decimals], strings.TrimRight(digits[len(digits)-decimals:], "0")
+	if units.Sign() < 0 {
+		whole = "-" + whole
+	}
+	if frac == "" {
+		return whole
+	}
+	return whole + "." + frac
+}
+
+// isDigits reports whether s is empty or all ASCII digits.
+func isDigits(s string) bool {
+	for _, c := range []byte(s) {
+		if c < '0' || c > '9' {
+			return false
+		}
+	}
+	return true
+}
+
+// escape percent-encodes a query value, with %20 rather than + for spaces.
+func escape(s string) string {
+	return strings.ReplaceAll(url.QueryEscape(s), "+", "%20")
+}
+
+// splitScheme removes a case-insensitive scheme prefix such as "bitcoin:" and
+// splits the rest at the query.
+func splitScheme(s, scheme string) (body, query string, err error) {
+	if len(s) <= len(scheme) || !strings.EqualFold(s[:len(scheme)+1], scheme+":") {
+		return "", "", fmt.Errorf("%w: missing %s: scheme", ErrInvalidURI, scheme)
+	}
+	body, query, _ = strings.Cut(s[len(scheme)+1:], "?")
+	if body == "" {
+		return "", "", fmt.Errorf("%w: missing address", ErrInvalidURI)
+	}
+	return body, query, nil
+}
@@ -0,0 +1,223 @@
+package uri
+
+import (
+	"errors"
+	"math/big"
+	"strings"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+func TestBitcoinURI(t *testing.T) {
+	r := &BitcoinRequest{
+		Address: "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+		Amount:  "0.0015",
+		Label:   "Luke Jr",
+		Message: "Donation for project xyz",
+		Params:  map[string]string{"pj": "https://example.com/pj?v=1"},
+	}
+	const want = "bitcoin:bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu?amount=0.0015&label=Luke%20Jr" +
+		"&message=Donation%20for%20project%20xyz&pj=https%3A%2F%2Fexample.com%2Fpj%3Fv%3D1"
+	if got := r.String(); got != want {
+		t.Errorf("String() = %s, want %s", got, want)
+	}
+
+	parsed, err := ParseBitcoin(want)
+	if err != nil {
+		t.Fatalf("ParseBitcoin() error = %v", err)
+	}
+	if parsed.Address != r.Address || parsed.Amount != r.Amount || parsed.Label != r.Label ||
+		parsed.Message != r.Message || parsed.Params["pj"] != r.Params["pj"] {
+		t.Errorf("ParseBitcoin() = %+v", parsed)
+	}
+
+	if got := (&BitcoinRequest{Address: "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa"}).String(); got != "bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa" {
+		t.Errorf("String() = %s", got)
+	}
+	if _, err := ParseBitcoin("BITCOIN:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=50"); err != nil {
+		t.Errorf("ParseBitcoin(upper-case scheme) error = %v", err)
+	}
+
+	errs := []struct {
+		uri  string
+		want error
+	}{
+		{"bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?req-somethingyoudontunderstand=50", ErrUnsupportedParam},
+		{"bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=1,5", ErrInvalidAmount},
+		{"bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=0.000000001", ErrInvalidAmount},
+		{"bitcoin:1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa?amount=1&amount=2", ErrInvalidURI},
+		{"litecoin:LKDyUEtTR1HXamkiEphisSiBJu6o3ZPE34", ErrInvalidURI},
+		{"bitcoin:", ErrInvalidURI},
+	}
+	for _, tt := range errs {
+		if _, err := ParseBitcoin(tt.uri); !errors.Is(err, tt.want) {
+			t.Errorf("ParseBitcoin(%q) error = %v, want %v", tt.uri, err, tt.want)
+		}
+	}
+}
+
+func TestEthereumURI(t *testing.T) {
+	const to = "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+	const usdc = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+
+	wei, _ := new(big.Int).SetString("2014000000000000000", 10)
+	if got, want := NewEthereumPayment(to, 1, wei).String(), "ethereum:"+to+"@1?value=2014000000000000000"; got != want {
+		t.Errorf("NewEthereumPayment() = %s, want %s", got, want)
+	}
+	if got, want := NewEthereumPayment(to, 0, nil).String(), "ethereum:"+to; got != want {
+		t.Errorf("NewEthereumPayment(nil) = %s, want %s", got, want)
+	}
+
+	transfer := NewERC20Transfer(usdc, to, 1, big.NewInt(1_000_000))
+	want := "ethereum:" + usdc + "@1/transfer?address=" + to + "&uint256=1000000"
+	if got := transfer.String(); got != want {
+		t.Errorf("NewERC20Transfer() = %s, want %s", got, want)
+	}
+	parsed, err := ParseEthereum(want)
+	if err != nil {
+		t.Fatalf("ParseEthereum() error = %v", err)
+	}
+	if parsed.Target != usdc || parsed.ChainID != 1 || parsed.Function != "transfer" || parsed.Params.Get("address") != to {
+		t.Errorf("ParseEthereum() = %+v", parsed)
+	}
+
+	// EIP-681 example with scientific notation
+	parsed, err = ParseEthereum("ethereum:pay-" + to + "@1?value=2.014e18")
+	if err != nil {
+		t.Fatalf("ParseEthereum(pay-) error = %v", err)
+	}
+	if value, err := parsed.Value(); err != nil || value.Cmp(wei) != 0 {
+		t.Errorf("Value() = %v, %v, want %v", value, err, wei)
+	}
+
+	for _, bad := range []string{
+		"ethereum:" + to + "@mainnet",
+		"ethereum:" + to + "?value=1.5",
+		"ethereum:" + to + "?value=1e-3",
+		"ethereum:@1",
+	} {
+		if _, err := ParseEthereum(bad); !errors.Is(err, ErrInvalidURI) {
+			t.Errorf("ParseEthereum(%q) error = %v, want ErrInvalidURI", bad, err)
+		}
+	}
+}
+
+func TestSolanaURI(t *testing.T) {
+	r := &SolanaRequest{
+		Recipient:  "mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN",
+		Amount:     "0.01",
+		SPLToken:   "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+		References: []string{"82ZJ7nbGpixjeDCmEhUcmwXYfvurzAgGdtSMuHnUgyny"},
+		Label:      "Michael",
+		Message:    "Thanks for all the fish",
+		Memo:       "OrderId12345",
+	}
+	// Solana Pay specification example
+	const want = "solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?amount=0.01" +
+		"&spl-token=EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" +
+		"&reference=82ZJ7nbGpixjeDCmEhUcmwXYfvurzAgGdtSMuHnUgyny" +
+		"&label=Michael&message=Thanks%20for%20all%20the%20fish&memo=OrderId12345"
+	if got := r.String(); got != want {
+		t.Errorf("String() = %s, want %s", got, want)
+	}
+
+	parsed, err := ParseSolana(want)
+	if err != nil {
+		t.Fatalf("ParseSolana() error = %v", err)
+	}
+	if parsed.String() != want {
+		t.Errorf("ParseSolana() round trip = %s", parsed.String())
+	}
+
+	for _, bad := range []string{
+		"solana:https://example.com/solana-pay",
+		"solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?reference=abc",
+		"solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?spl-token=0x00",
+	} {
+		if _, err := ParseSolana(bad); !errors.Is(err, ErrInvalidURI) {
+			t.Errorf("ParseSolana(%q) error = %v, want ErrInvalidURI", bad, err)
+		}
+	}
+	if _, err := ParseSolana("solana:mvines9iiHiQTysrwkJjGf2gb9Ex9jXJX8ns3qwf2kN?amount=-1"); !errors.Is(err, ErrInvalidAmount) {
+		t.Errorf("ParseSolana(negative amount) error = %v, want ErrInvalidAmount", err)
+	}
+}
+
+func TestForAccount(t *testing.T) {
+	if address.IsSupported(address.ChainBitcoin) {
+		btc, err := account.NewBitcoinAccountFromMnemonic(testMnemonic, "", account.ScriptP2WPKH, 0, 0)
+		if err != nil {
+			t.Fatalf("NewBitcoinAccountFromMnemonic() error = %v", err)
+		}
+		if got, err := ForAccount(btc, "0.5"); err != nil || got != "bitcoin:bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu?amount=0.5" {
+			t.Errorf("ForAccount(btc) = %s, %v", got, err)
+		}
+		if _, err := ForAccount(btc, "0.123456789"); !errors.Is(err, ErrInvalidAmount) {
+			t.Errorf("ForAccount(9 decimals) error = %v, want ErrInvalidAmount", err)
+		}
+	}
+
+	if address.IsSupported(address.ChainEthereum) {
+		eth, err := account.NewEVMAccountFromMnemonic(testMnemonic, "", address.ChainPolygon, 0)
+		if err != nil {
+			t.Fatalf("NewEVMAccountFromMnemonic() error = %v", err)
+		}
+		got, err := ForAccount(eth, "1.5")
+		if err != nil || !strings.HasSuffix(got, "@137?value=1500000000000000000") {
+			t.Errorf("ForAccount(matic) = %s, %v", got, err)
+		}
+
+		harmony, err := account.NewHarmonyAccountFromMnemonic(testMnemonic, "", 0)
+		if err != nil {
+			t.Fatalf("NewHarmonyAccountFromMnemonic() error = %v", err)
+		}
+		if got, err := ForAccount(harmony, ""); err != nil || got != "ethereum:"+harmony.EVMAddress()+"@1666600000" {
+			t.Errorf("ForAccount(one) = %s, %v", got, err)
+		}
+	}
+
+	if address.IsSupported(address.ChainCosmos) {
+		atom, err := account.NewCosmosAccountFromMnemonic(testMnemonic, "", 0)
+		if err != nil {
+			t.Fatalf("NewCosmosAccountFromMnemonic() error = %v", err)
+		}
+		if _, err := ForAccount(atom, "1"); !errors.Is(err, ErrUnsupportedChain) {
+			t.Errorf("ForAccount(atom) error = %v, want ErrUnsupportedChain", err)
+		}
+	}
+}
+
+func TestUnits(t *testing.T) {
+	tests := []struct {
+		amount   string
+		decimals int
+		units    string
+		format   string
+	}{
+		{"0.0015", 8, "150000", "0.0015"},
+		{"21", 8, "2100000000", "21"},
+		{".5", 9, "500000000", "0.5"},
+		{"1.", 18, "1000000000000000000", "1"},
+		{"0", 18, "0", "0"},
+	}
+	for _, tt := range tests {
+		units, err := ParseUnits(tt.amount, tt.decimals)
+		if err != nil || units.String() != tt.units {
+			t.Errorf("ParseUnits(%q) = %v, %v, want %s", tt.amount, units, err, tt.units)
+			continue
+		}
+		if got := FormatUnits(units, tt.decimals); got != tt.format {
+			t.Errorf("FormatUnits(%s) = %s, want %s", units, got, tt.format)
+		}
+	}
+
+	for _, bad := range []string{"", ".", "1.2.3", "-1", "1e3", " 1"} {
+		if _, err := ParseUnits(bad, 8); !errors.Is(err, ErrInvalidAmount) {
+			t.Errorf("ParseUnits(%q) error = %v, want ErrInvalidAmount", bad, err)
+		}
+	}
+}
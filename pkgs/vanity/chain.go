@@ -0,0 +1,190 @@
+// Package vanity searches for keys whose address matches a pattern. A search
+// combines a Chain adapter (key to address), a KeySource (random keys or an HD
+// index scan) and a Matcher, and runs across goroutines with progress
+// reporting and cancellation.
+package vanity
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+)
+
+var (
+	// ErrNotFound is returned when MaxAttempts keys were tried without a match.
+	ErrNotFound = errors.New("vanity: no match found")
+
+	// ErrImpossiblePattern is returned when a prefix or suffix can never
+	// appear in the chain's addresses.
+	ErrImpossiblePattern = errors.New("vanity: pattern cannot match")
+
+	// ErrInvalidKey is returned by a KeySource for a candidate that is not a
+	// usable key; Search skips it.
+	ErrInvalidKey = errors.New("vanity: invalid key")
+
+	// ErrSourceExhausted is returned by a KeySource with no more candidates.
+	ErrSourceExhausted = errors.New("vanity: key source exhausted")
+
+	// ErrUnsupportedChain is returned by ForChain for chains without an adapter.
+	ErrUnsupportedChain = errors.New("vanity: unsupported chain")
+)
+
+// Curve is the signature curve of a chain's keys.
+type Curve int
+
+// Supported curves
+const (
+	Secp256k1 Curve = iota
+	Ed25519
+)
+
+// Address character sets
+const (
+	base58Charset = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+	bech32Charset = "qpzry9x8gf2tvdw0s3jn54khce6mua7l"
+	hexCharset    = "0123456789abcdefABCDEF"
+)
+
+// Chain turns private keys into addresses for one chain.
+type Chain struct {
+	ID    address.ChainID
+	Curve Curve
+
+	// FixedPrefix starts every address, e.g. "bc1q" or "0x".
+	FixedPrefix string
+
+	// Charset holds the characters that may follow FixedPrefix. Patterns using
+	// other characters are rejected up front; empty disables the check.
+	Charset string
+
+	// Address returns the address of a 32-byte private key.
+	Address func(privateKey []byte) (string, error)
+}
+
+// Bitcoin returns the adapter for mainnet Bitcoin addresses of a script type.
+func Bitcoin(scriptType account.ScriptType) Chain {
+	c := Chain{ID: address.ChainBitcoin, Curve: Secp256k1, Charset: base58Charset}
+	switch scriptType {
+	case account.ScriptP2WPKH:
+		c.FixedPrefix, c.Charset = "bc1q", bech32Charset
+	case account.ScriptP2SHP2WPKH:
+		c.FixedPrefix = "3"
+	default:
+		c.FixedPrefix = "1"
+	}
+	c.Address = func(privateKey []byte) (string, error) {
+		acc, err := account.NewBitcoinWatchAccount(secp256k1.PrivateKeyToCompressedPublicKey(privateKey), scriptType)
+		if err != nil {
+			return "", err
+		}
+		return acc.Address(), nil
+	}
+	return c
+}
+
+// EVM returns the adapter for an EVM chain. Addresses are in 0x form with the
+// EIP-55 checksum, so case-sensitive patterns match the checksummed case.
+func EVM(chain address.ChainID) Chain {
+	return Chain{
+		ID:          chain,
+		Curve:       Secp256k1,
+		FixedPrefix: "0x",
+		Charset:     hexCharset,
+		Address: func(privateKey []byte) (string, error) {
+			publicKey := secp256k1.SerializeUncompressed(secp256k1.PrivateKeyToPublicKey(privateKey))
+			return address.NewEthereumAddress().Generate(publicKey)
+		},
+	}
+}
+
+// Solana returns the adapter for Solana addresses (base58 Ed25519 public keys).
+func Solana() Chain {
+	return Chain{
+		ID:      address.ChainSolana,
+		Curve:   Ed25519,
+		Charset: base58Charset,
+		Address: func(privateKey []byte) (string, error) {
+			publicKey, err := ed25519.PrivateKeyToPublicKey(privateKey)
+			if err != nil {
+				return "", err
+			}
+			return address.NewSolanaAddress().Generate(publicKey)
+		},
+	}
+}
+
+// Cosmos returns the adapter for Cosmos SDK account addresses with a Bech32 prefix.
+func Cosmos(hrp string) Chain {
+	gen := address.NewCosmosAddressWithHRP(hrp, address.ChainCosmos)
+	return Chain{
+		ID:          address.ChainCosmos,
+		Curve:       Secp256k1,
+		FixedPrefix: hrp + "1",
+		Charset:     bech32Charset,
+		Address: func(privateKey []byte) (string, error) {
+			return gen.Generate(secp256k1.PrivateKeyToCompressedPublicKey(privateKey))
+		},
+	}
+}
+
+// ForChain returns the adapter for a chain ID: native SegWit for Bitcoin, 0x
+// addresses for EVM chains, and the chain's own prefix for Cosmos SDK chains.
+func ForChain(chain address.ChainID) (Chain, error) {
+	if !address.IsSupported(chain) {
+		return Chain{}, fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+	}
+	if gen, ok := address.CosmosBasedChains()[chain]; ok {
+		c := Cosmos(gen.HRP())
+		c.ID = chain
+		return c, nil
+	}
+	if _, ok := address.EVMChains()[chain]; ok {
+		return EVM(chain), nil
+	}
+
+	switch chain {
+	case address.ChainBitcoin:
+		return Bitcoin(account.ScriptP2WPKH), nil
+	case address.ChainSolana:
+		return Solana(), nil
+	default:
+		return Chain{}, fmt.Errorf("%w: %s", ErrUnsupportedChain, chain)
+	}
+}
+
+// CheckPattern reports ErrImpossiblePattern when no address of the chain can
+// start with prefix or end with suffix.
+func (c Chain) CheckPattern(prefix, suffix string, ignoreCase bool) error {
+	fixed, charset := c.FixedPrefix, c.Charset
+	if ignoreCase {
+		prefix, suffix = strings.ToLower(prefix), strings.ToLower(suffix)
+		fixed, charset = strings.ToLower(fixed), strings.ToLower(charset)
+	}
+
+	var rest string
+	switch {
+	case len(prefix) <= len(fixed):
+		if !strings.HasPrefix(fixed, prefix) {
+			return fmt.Errorf("%w: %s addresses start with %q", ErrImpossiblePattern, c.ID, c.FixedPrefix)
+		}
+	case !strings.HasPrefix(prefix, fixed):
+		return fmt.Errorf("%w: %s addresses start with %q", ErrImpossiblePattern, c.ID, c.FixedPrefix)
+	default:
+		rest = prefix[len(fixed):]
+	}
+
+	if charset == "" {
+		return nil
+	}
+	for _, r := range rest + suffix {
+		if !strings.ContainsRune(charset, r) {
+			return fmt.Errorf("%w: %q never appears in %s addresses", ErrImpossiblePattern, r, c.ID)
+		}
+	}
+	return nil
+}
@@ -0,0 +1,121 @@
+package vanity
+
+import (
+	"regexp"
+	"strings"
+)
+
+// Matcher decides whether an address is a hit. Search calls Match from
+// several goroutines at once.
+type Matcher interface {
+	Match(addr string) bool
+}
+
+// MatcherFunc adapts a function to the Matcher interface.
+type MatcherFunc func(addr string) bool
+
+// Match calls f(addr).
+func (f MatcherFunc) Match(addr string) bool {
+	return f(addr)
+}
+
+// affixMatcher matches a literal prefix and suffix.
+type affixMatcher struct {
+	prefix, suffix string
+	ignoreCase     bool
+}
+
+func (m affixMatcher) Match(addr string) bool {
+	if m.ignoreCase {
+		addr = strings.ToLower(addr)
+	}
+	return strings.HasPrefix(addr, m.prefix) && strings.HasSuffix(addr, m.suffix)
+}
+
+// Prefix matches addresses that start with prefix, including any fixed part
+// of the format such as "0x" or "bc1q".
+func Prefix(prefix string) Matcher {
+	return affixMatcher{prefix: prefix}
+}
+
+// Suffix matches addresses that end with suffix.
+func Suffix(suffix string) Matcher {
+	return affixMatcher{suffix: suffix}
+}
+
+// Affix matches addresses that start with prefix and end with suffix.
+func Affix(prefix, suffix string) Matcher {
+	return affixMatcher{prefix: prefix, suffix: suffix}
+}
+
+// regexpMatcher matches a regular expression.
+type regexpMatcher struct {
+	re *regexp.Regexp
+}
+
+func (m regexpMatcher) Match(addr string) bool {
+	return m.re.MatchString(addr)
+}
+
+// Regexp matches addresses containing a match of a regular expression; anchor
+// it with ^ and $ to match the whole address.
+func Regexp(expr string) (Matcher, error) {
+	re, err := regexp.Compile(expr)
+	if err != nil {
+		return nil, err
+	}
+	return regexpMatcher{re: re}, nil
+}
+
+// allMatcher matches when every matcher does.
+type allMatcher []Matcher
+
+func (m allMatcher) Match(addr string) bool {
+	for _, matcher := range m {
+		if !matcher.Match(addr) {
+			return false
+		}
+	}
+	return true
+}
+
+// All matches addresses that every matcher accepts.
+func All(matchers ...Matcher) Matcher {
+	return allMatcher(matchers)
+}
+
+// IgnoreCase makes a matcher case-insensitive. Other Matcher implementations
+// see the address in lower case.
+func IgnoreCase(m Matcher) Matcher {
+	switch m := m.(type) {
+	case affixMatcher:
+		return affixMatcher{prefix: strings.ToLower(m.prefix), suffix: strings.ToLower(m.suffix), ignoreCase: true}
+	case regexpMatcher:
+		return regexpMatcher{re: regexp.MustCompile("(?i)" + m.re.String())}
+	case allMatcher:
+		folded := make(allMatcher, len(m))
+		for i, matcher := range m {
+			folded[i] = IgnoreCase(matcher)
+		}
+		return folded
+	default:
+		return MatcherFunc(func(addr string) bool {
+			return m.Match(strings.ToLower(addr))
+		})
+	}
+}
+
+// checkPattern checks the literal parts of a matcher against a chain.
+func checkPattern(c Chain, m Matcher) error {
+	switch m := m.(type) {
+	case affixMatcher:
+		return c.CheckPattern(m.prefix, m.suffix, m.ignoreCase)
+	case allMatcher:
+		for _, matcher := range m {
+			if err := checkPattern(c, matcher); err != nil {
+				return err
+			}
+		}
+	}
+	return nil
+}
@@ -0,0 +1,151 @@
+package vanity
+
+import (
+	"context"
+	"errors"
+	"runtime"
+	"sync"
+	"sync/atomic"
+	"time"
+)
+
+// DefaultProgressInterval is how often Search reports progress when
+// Options.Progress is set and Options.ProgressInterval is not.
+const DefaultProgressInterval = time.Second
+
+// Options tunes a search.
+type Options struct {
+	// Workers is the number of goroutines (GOMAXPROCS when <= 0).
+	Workers int
+
+	// MaxAttempts stops the search with ErrNotFound after this many keys
+	// (0 for no limit).
+	MaxAttempts uint64
+
+	// Progress, if set, is called periodically from a single goroutine.
+	Progress         func(Progress)
+	ProgressInterval time.Duration
+}
+
+// Progress is a snapshot of a running search.
+type Progress struct {
+	Attempts uint64
+	Elapsed  time.Duration
+}
+
+// Rate returns the keys tried per second.
+func (p Progress) Rate() float64 {
+	if p.Elapsed <= 0 {
+		return 0
+	}
+	return float64(p.Attempts) / p.Elapsed.Seconds()
+}
+
+// Result is a matching key.
+type Result struct {
+	Address    string
+	PrivateKey []byte
+	Path       string // derivation path when the source is an HDSource
+	Index      uint64 // candidate number n passed to the KeySource
+	Attempts   uint64 // keys tried by all workers when the match was found
+}
+
+// Search tries keys from source until one's address on chain satisfies
+// matcher, the context is done, or MaxAttempts is reached. Prefixes and
+// suffixes that can never match the chain's address format fail fast with
+// ErrImpossiblePattern.
+func Search(ctx context.Context, chain Chain, source KeySource, matcher Matcher, opts Options) (*Result, error) {
+	if err := checkPattern(chain, matcher); err != nil {
+		return nil, err
+	}
+	workers := opts.Workers
+	if workers <= 0 {
+		workers = runtime.GOMAXPROCS(0)
+	}
+
+	parent := ctx
+	ctx, cancel := context.WithCancel(ctx)
+	defer cancel()
+
+	var (
+		next, attempts atomic.Uint64
+		once           sync.Once
+		found          *Result
+		failure        error
+	)
+	finish := func(r *Result, err error) {
+		once.Do(func() {
+			found, failure = r, err
+			cancel()
+		})
+	}
+
+	var wg sync.WaitGroup
+	for w := 0; w < workers; w++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for ctx.Err() == nil {
+				n := next.Add(1) - 1
+				if opts.MaxAttempts > 0 && n >= opts.MaxAttempts {
+					return
+				}
+
+				key, err := source.Key(n)
+				if errors.Is(err, ErrInvalidKey) {
+					continue
+				}
+				if err != nil {
+					finish(nil, err)
+					return
+				}
+				addr, err := chain.Address(key.PrivateKey)
+				if err != nil {
+					finish(nil, err)
+					return
+				}
+
+				tried := attempts.Add(1)
+				if matcher.Match(addr) {
+					finish(&Result{Address: addr, PrivateKey: key.PrivateKey, Path: key.Path, Index: n, Attempts: tried}, nil)
+					return
+				}
+			}
+		}()
+	}
+
+	if opts.Progress != nil {
+		interval := opts.ProgressInterval
+		if interval <= 0 {
+			interval = DefaultProgressInterval
+		}
+		done := make(chan struct{})
+		defer close(done)
+		go func() {
+			start := time.Now()
+			ticker := time.NewTicker(interval)
+			defer ticker.Stop()
+			for {
+				select {
+				case <-ticker.C:
+					opts.Progress(Progress{Attempts: attempts.Load(), Elapsed: time.Since(start)})
+				case <-done:
+					return
+				}
+			}
+		}()
+	}
+
+	wg.Wait()
+
+	switch {
+	case found != nil:
+		return found, nil
+	case failure != nil:
+		return nil, failure
+	case parent.Err() != nil:
+		return nil, parent.Err()
+	default:
+		return nil, ErrNotFound
+	}
+}
@@ -0,0 +1,154 @@
+package vanity
+
+import (
+	"crypto/rand"
+	"errors"
+	"fmt"
+	"math"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/bip32"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/secp256k1"
+	"github.com/study/crypto-accounts/pkgs/crypto/secret"
+)
+
+// Key is a candidate private key.
+type Key struct {
+	PrivateKey []byte
+	Path       string // derivation path for HD keys; empty for random keys
+}
+
+// KeySource produces candidate keys. Search calls Key concurrently, with each
+// n from 0 upward exactly once; deterministic sources map n to a key.
+type KeySource interface {
+	Key(n uint64) (Key, error)
+}
+
+// randomSource draws independent random keys.
+type randomSource struct{}
+
+// RandomSource returns a source of fresh random 32-byte keys, valid on both
+// secp256k1 and Ed25519.
+func RandomSource() KeySource {
+	return randomSource{}
+}
+
+func (randomSource) Key(uint64) (Key, error) {
+	privateKey := make([]byte, 32)
+	for {
+		if _, err := rand.Read(privateKey); err != nil {
+			return Key{}, err
+		}
+		if secp256k1.IsValidPrivateKey(privateKey) {
+			return Key{PrivateKey: privateKey}, nil
+		}
+	}
+}
+
+// HDSource scans the child indexes of a path format, so a hit can be
+// recovered from the seed and its index instead of a stored key.
+type HDSource struct {
+	seed       *secret.Bytes
+	pathFormat string
+	curve      Curve
+
+	// parent is the BIP-32 key above a trailing non-hardened index, derived
+	// once so each candidate costs a single child derivation.
+	parent *bip32.ExtendedKey
+}
+
+// NewHDSource returns a source deriving fmt.Sprintf(pathFormat, n) for n = 0,
+// 1, 2, ... from a seed: BIP-32 for Secp256k1 and SLIP-10 (hardened indexes
+// only) for Ed25519. pathFormat has one %d, e.g. "m/44'/60'/0'/0/%d" or
+// "m/44'/501'/%d'/0'". The source keeps its own copy of the seed until Destroy.
+func NewHDSource(seed []byte, pathFormat string, curve Curve) (*HDSource, error) {
+	if strings.Count(pathFormat, "%d") != 1 {
+		return nil, fmt.Errorf("vanity: path format %q must contain one %%d", pathFormat)
+	}
+	path, err := bip32.ParsePath(fmt.Sprintf(pathFormat, 0))
+	if err != nil {
+		return nil, err
+	}
+
+	s := &HDSource{seed: secret.Copy(seed), pathFormat: pathFormat, curve: curve}
+	switch curve {
+	case Secp256k1:
+		master, err := bip32.NewMasterKey(seed)
+		if err != nil {
+			return nil, err
+		}
+		if strings.HasSuffix(pathFormat, "/%d") {
+			if s.parent, err = master.DeriveFromPath(path[:len(path)-1]); err != nil {
+				return nil, err
+			}
+		}
+	case Ed25519:
+		for _, index := range path {
+			if !bip32.IsHardened(index) {
+				return nil, fmt.Errorf("vanity: SLIP-10 Ed25519 path %q must be fully hardened", pathFormat)
+			}
+		}
+	}
+	return s, nil
+}
+
+// Key derives the key at index n.
+func (s *HDSource) Key(n uint64) (Key, error) {
+	if n > math.MaxInt32 {
+		return Key{}, ErrSourceExhausted
+	}
+	pathStr := fmt.Sprintf(s.pathFormat, n)
+
+	if s.parent != nil {
+		child, err := s.parent.Child(uint32(n))
+		if errors.Is(err, bip32.ErrDerivationFailed) {
+			return Key{}, ErrInvalidKey
+		}
+		if err != nil {
+			return Key{}, err
+		}
+		return Key{PrivateKey: child.PrivateKeyBytes(), Path: pathStr}, nil
+	}
+
+	path, err := bip32.ParsePath(pathStr)
+	if err != nil {
+		return Key{}, err
+	}
+	if s.curve == Ed25519 {
+		var privateKey []byte
+		s.seed.Use(func(seed []byte) {
+			privateKey, _, err = ed25519.DeriveKeyFromPath(seed, path)
+		})
+		if err != nil {
+			return Key{}, err
+		}
+		return Key{PrivateKey: privateKey, Path: pathStr}, nil
+	}
+
+	var master *bip32.ExtendedKey
+	s.seed.Use(func(seed []byte) {
+		master, err = bip32.NewMasterKey(seed)
+	})
+	if err != nil {
+		return Key{}, err
+	}
+	defer master.Wipe()
+	key, err := master.DeriveFromPath(path)
+	if errors.Is(err, bip32.ErrDerivationFailed) {
+		return Key{}, ErrInvalidKey
+	}
+	if err != nil {
+		return Key{}, err
+	}
+	return Key{PrivateKey: key.PrivateKeyBytes(), Path: pathStr}, nil
+}
+
+// Destroy wipes the source's copy of the seed and its cached parent key.
+// Key must not be called afterwards.
+func (s *HDSource) Destroy() {
+	s.seed.Destroy()
+	if s.parent != nil {
+		s.parent.Wipe()
+	}
+}
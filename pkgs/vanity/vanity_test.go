@@ -0,0 +1,209 @@
+package vanity
+
+import (
+	"context"
+	"errors"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/bip39"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+// keySourceFunc adapts a function to KeySource.
+type keySourceFunc func(n uint64) (Key, error)
+
+func (f keySourceFunc) Key(n uint64) (Key, error) { return f(n) }
+
+func TestMatchers(t *testing.T) {
+	const addr = "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+
+	re, err := Regexp("^0x98.*94$")
+	if err != nil {
+		t.Fatalf("Regexp() error = %v", err)
+	}
+	tests := []struct {
+		name    string
+		matcher Matcher
+		want    bool
+	}{
+		{"prefix", Prefix("0x9858Ef"), true},
+		{"prefix case", Prefix("0x9858ef"), false},
+		{"prefix ignore case", IgnoreCase(Prefix("0X9858EF")), true},
+		{"suffix", Suffix("Eda94"), true},
+		{"affix", Affix("0x98", "94"), true},
+		{"affix miss", Affix("0x98", "95"), false},
+		{"regexp", re, true},
+		{"all", All(Prefix("0x98"), Suffix("da94")), true},
+		{"all ignore case", IgnoreCase(All(Prefix("0x9858EF"), Suffix("EDA94"))), true},
+		{"func", MatcherFunc(func(s string) bool { return strings.Contains(s, "ec34") }), false},
+		{"func ignore case", IgnoreCase(MatcherFunc(func(s string) bool { return strings.Contains(s, "ec34") })), true},
+	}
+	for _, tt := range tests {
+		if got := tt.matcher.Match(addr); got != tt.want {
+			t.Errorf("%s: Match() = %v, want %v", tt.name, got, tt.want)
+		}
+	}
+
+	if _, err := Regexp("("); err == nil {
+		t.Error("Regexp(\"(\") error = nil")
+	}
+}
+
+func TestCheckPattern(t *testing.T) {
+	tests := []struct {
+		chain      Chain
+		prefix     string
+		suffix     string
+		ignoreCase bool
+		ok         bool
+	}{
+		{Bitcoin(account.ScriptP2WPKH), "bc1qxyz", "", false, true},
+		{Bitcoin(account.ScriptP2WPKH), "bc", "", false, true},
+		{Bitcoin(account.ScriptP2WPKH), "bc1qbob", "", false, false}, // no 'b' in Bech32
+		{Bitcoin(account.ScriptP2WPKH), "1abc", "", false, false},
+		{Bitcoin(account.ScriptP2WPKH), "BC1QXYZ", "", false, false},
+		{Bitcoin(account.ScriptP2WPKH), "BC1QXYZ", "", true, true},
+		{Bitcoin(account.ScriptP2PKH), "1Love", "", false, true},
+		{Bitcoin(account.ScriptP2PKH), "1lOve", "", false, false}, // no 'l' or 'O' in Base58
+		{EVM(address.ChainEthereum), "0xbeef", "cafe", false, true},
+		{EVM(address.ChainEthereum), "0xgood", "", false, false},
+		{Solana(), "So1", "", false, true},
+		{Solana(), "", "0", false, false},
+		{Cosmos("osmo"), "osmo1qq", "", false, true},
+		{Cosmos("osmo"), "cosmos1", "", false, false},
+	}
+	for _, tt := range tests {
+		err := tt.chain.CheckPattern(tt.prefix, tt.suffix, tt.ignoreCase)
+		if tt.ok && err != nil {
+			t.Errorf("%s CheckPattern(%q, %q) error = %v", tt.chain.ID, tt.prefix, tt.suffix, err)
+		}
+		if !tt.ok && !errors.Is(err, ErrImpossiblePattern) {
+			t.Errorf("%s CheckPattern(%q, %q) error = %v, want ErrImpossiblePattern", tt.chain.ID, tt.prefix, tt.suffix, err)
+		}
+	}
+
+	_, err := Search(context.Background(), EVM(address.ChainEthereum), RandomSource(), IgnoreCase(Prefix("0xZZ")), Options{})
+	if !errors.Is(err, ErrImpossiblePattern) {
+		t.Errorf("Search(0xZZ) error = %v, want ErrImpossiblePattern", err)
+	}
+}
+
+func TestSearchRandom(t *testing.T) {
+	if !address.IsSupported(address.ChainEthereum) {
+		t.Skip("built without EVM chains")
+	}
+	chain := EVM(address.ChainEthereum)
+
+	result, err := Search(context.Background(), chain, RandomSource(), IgnoreCase(Prefix("0x0")), Options{Workers: 4})
+	if err != nil {
+		t.Fatalf("Search() error = %v", err)
+	}
+	if !strings.HasPrefix(strings.ToLower(result.Address), "0x0") || result.Attempts == 0 || result.Path != "" {
+		t.Errorf("Search() = %+v", result)
+	}
+	if addr, _ := chain.Address(result.PrivateKey); addr != result.Address {
+		t.Errorf("Search() address %s does not belong to its key (%s)", result.Address, addr)
+	}
+}
+
+func TestSearchHD(t *testing.T) {
+	seed := bip39.NewSeed(testMnemonic, "")
+
+	if address.IsSupported(address.ChainEthereum) {
+		source, err := NewHDSource(seed, "m/44'/60'/0'/0/%d", Secp256k1)
+		if err != nil {
+			t.Fatalf("NewHDSource() error = %v", err)
+		}
+		result, err := Search(context.Background(), EVM(address.ChainEthereum), source, Prefix("0x9858EfFD"), Options{Workers: 3})
+		if err != nil {
+			t.Fatalf("Search() error = %v", err)
+		}
+		if result.Index != 0 || result.Path != "m/44'/60'/0'/0/0" || result.Address != "0x9858EfFD232B4033E47d90003D41EC34EcaEda94" {
+			t.Errorf("Search() = %+v", result)
+		}
+	}
+
+	if address.IsSupported(address.ChainSolana) {
+		want, err := account.NewSolanaAccount(seed, 2)
+		if err != nil {
+			t.Fatalf("NewSolanaAccount() error = %v", err)
+		}
+		source, err := NewHDSource(seed, account.SolanaPathFormat, Ed25519)
+		if err != nil {
+			t.Fatalf("NewHDSource() error = %v", err)
+		}
+		result, err := Search(context.Background(), Solana(), source, Prefix(want.Address()), Options{Workers: 2})
+		if err != nil {
+			t.Fatalf("Search() error = %v", err)
+		}
+		if result.Index != 2 || result.Path != want.Path() {
+			t.Errorf("Search() = %+v, want index 2 at %s", result, want.Path())
+		}
+	}
+
+	if _, err := NewHDSource(seed, "m/44'/501'/%d/0'", Ed25519); err == nil {
+		t.Error("NewHDSource(non-hardened Ed25519) error = nil")
+	}
+	if _, err := NewHDSource(seed, "m/44'/60'/0'/0/0", Secp256k1); err == nil {
+		t.Error("NewHDSource(no %d) error = nil")
+	}
+}
+
+func TestSearchStops(t *testing.T) {
+	if !address.IsSupported(address.ChainEthereum) {
+		t.Skip("built without EVM chains")
+	}
+	chain := EVM(address.ChainEthereum)
+	never := Prefix("0x000000000000000000")
+
+	if _, err := Search(context.Background(), chain, RandomSource(), never, Options{MaxAttempts: 50}); !errors.Is(err, ErrNotFound) {
+		t.Errorf("Search(MaxAttempts) error = %v, want ErrNotFound", err)
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), 50*time.Millisecond)
+	defer cancel()
+	if _, err := Search(ctx, chain, RandomSource(), never, Options{}); !errors.Is(err, context.DeadlineExceeded) {
+		t.Errorf("Search(timeout) error = %v, want context.DeadlineExceeded", err)
+	}
+
+	errSource := errors.New("source failed")
+	failing := keySourceFunc(func(n uint64) (Key, error) {
+		if n == 10 {
+			return Key{}, errSource
+		}
+		if n%2 == 1 {
+			return Key{}, ErrInvalidKey // skipped
+		}
+		return RandomSource().Key(n)
+	})
+	if _, err := Search(context.Background(), chain, failing, never, Options{Workers: 1}); !errors.Is(err, errSource) {
+		t.Errorf("Search(failing source) error = %v, want source error", err)
+	}
+}
+
+func TestForChain(t *testing.T) {
+	for _, id := range []address.ChainID{address.ChainBitcoin, address.ChainPolygon, address.ChainSolana, address.ChainSei} {
+		if !address.IsSupported(id) {
+			continue
+		}
+		c, err := ForChain(id)
+		if err != nil {
+			t.Errorf("ForChain(%s) error = %v", id, err)
+			continue
+		}
+		if c.ID != id {
+			t.Errorf("ForChain(%s).ID = %s", id, c.ID)
+		}
+	}
+	if c, err := ForChain(address.ChainSei); err == nil && c.FixedPrefix != "sei1" {
+		t.Errorf("ForChain(sei).FixedPrefix = %q", c.FixedPrefix)
+	}
+	if _, err := ForChain(address.ChainStellar); !errors.Is(err, ErrUnsupportedChain) {
+		t.Errorf("ForChain(xlm) error = %v, want ErrUnsupportedChain", err)
+	}
+}
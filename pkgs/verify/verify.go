@@ -0,0 +1,228 @@
+// Package verify checks signed messages against an address without any
+// private key material, for services that only verify user proofs. Each
+// function accepts the signature in the text form account.SignMessage and
+// the chain's wallets produce, and returns a Result instead of an error so
+// that an invalid proof can be reported as data.
+package verify
+
+import (
+	"encoding/base64"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"strings"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+	"github.com/study/crypto-accounts/pkgs/crypto/ed25519"
+	"github.com/study/crypto-accounts/pkgs/crypto/encoding"
+	"github.com/study/crypto-accounts/pkgs/keys"
+)
+
+// ErrInvalid is wrapped by Result.Err for a signature that does not verify.
+var ErrInvalid = errors.New("verify: invalid signature")
+
+// Result is the outcome of verifying a signed message.
+type Result struct {
+	// Valid is true when the signature is for the message and the address.
+	Valid bool `json:"valid"`
+
+	Chain   address.ChainID `json:"chain"`
+	Address string          `json:"address"`
+
+	// PublicKey is the hex public key that made the signature, recovered
+	// from it or carried in it. It is set only for a valid signature.
+	PublicKey string `json:"publicKey,omitempty"`
+
+	// Reason says why the signature was rejected.
+	Reason string `json:"reason,omitempty"`
+}
+
+// Err returns nil for a valid result and an error wrapping ErrInvalid otherwise.
+func (r Result) Err() error {
+	if r.Valid {
+		return nil
+	}
+	return fmt.Errorf("%w: %s", ErrInvalid, r.Reason)
+}
+
+func valid(chain address.ChainID, addr string, publicKey []byte) Result {
+	return Result{Valid: true, Chain: chain, Address: addr, PublicKey: hex.EncodeToString(publicKey)}
+}
+
+func invalid(chain address.ChainID, addr, format string, args ...any) Result {
+	return Result{Chain: chain, Address: addr, Reason: fmt.Sprintf(format, args...)}
+}
+
+// EVM verifies an EIP-191 personal_sign signature, given as hex r || s || v
+// with or without 0x, by recovering the signer's address. The comparison
+// ignores address checksum case, and the result holds ChainEthereum for
+// every EVM chain.
+func EVM(addr string, message []byte, signature string) Result {
+	chain := address.ChainEthereum
+	raw, err := encoding.DecodeHex(signature)
+	if err != nil {
+		return invalid(chain, addr, "signature is not hex")
+	}
+	sig, err := keys.ParseRecoverableSignature(raw)
+	if err != nil {
+		return invalid(chain, addr, "%v", err)
+	}
+	pub, err := sig.RecoverPublicKey(account.EIP191Hash(message))
+	if err != nil {
+		return invalid(chain, addr, "recovery failed: %v", err)
+	}
+	uncompressed, _ := pub.Uncompressed()
+	recovered, err := address.NewEVMAddress(chain).Generate(uncompressed.Bytes())
+	if err != nil || !strings.EqualFold(recovered, addr) {
+		return invalid(chain, addr, "signed by %s", recovered)
+	}
+	return valid(chain, addr, uncompressed.Bytes())
+}
+
+// Bitcoin verifies a Base64 Bitcoin signed message for a P2PKH, nested
+// SegWit or native SegWit address. As in Electrum, any BIP-137 header is
+// accepted for any of the three address types of a compressed key.
+func Bitcoin(addr string, message []byte, signature string) Result {
+	a := address.NewBitcoinAddress(false)
+	return bitcoinFamily(address.ChainBitcoin, addr, message, signature, a.P2PKH, nestedSegWit(a.P2SH), a.P2WPKH)
+}
+
+// Litecoin verifies a Base64 Litecoin signed message; see Bitcoin.
+func Litecoin(addr string, message []byte, signature string) Result {
+	a := address.NewLitecoinAddress(false)
+	return bitcoinFamily(address.ChainLitecoin, addr, message, signature, a.P2PKH, nestedSegWit(a.P2SH), a.Bech32)
+}
+
+// Dogecoin verifies a Base64 Dogecoin signed message for a P2PKH address.
+func Dogecoin(addr string, message []byte, signature string) Result {
+	a := address.NewDogecoinAddress(false)
+	return bitcoinFamily(address.ChainDogecoin, addr, message, signature, a.P2PKH)
+}
+
+// nestedSegWit turns a P2SH encoder into a P2SH-P2WPKH one.
+func nestedSegWit(p2sh func([]byte) (string, error)) func([]byte) (string, error) {
+	return func(publicKey []byte) (string, error) {
+		return p2sh(append([]byte{0x00, 0x14}, address.Hash160(publicKey)...))
+	}
+}
+
+func bitcoinFamily(chain address.ChainID, addr string, message []byte, signature string, encoders ...func([]byte) (string, error)) Result {
+	raw, err := base64.StdEncoding.DecodeString(signature)
+	if err != nil || len(raw) != 1+keys.SignatureSize {
+		return invalid(chain, addr, "signature is not 65 bytes of Base64")
+	}
+	header := raw[0]
+	if header < account.BIP137Uncompressed || header >= account.BIP137P2WPKH+4 {
+		return invalid(chain, addr, "header %d out of range", header)
+	}
+	sig, err := keys.NewRecoverableSignature(raw[1:], (header-account.BIP137Uncompressed)&3)
+	if err != nil {
+		return invalid(chain, addr, "%v", err)
+	}
+	digest, err := account.BitcoinMessageHash(chain, message)
+	if err != nil {
+		return invalid(chain, addr, "%v", err)
+	}
+	pub, err := sig.RecoverPublicKey(digest)
+	if err != nil {
+		return invalid(chain, addr, "recovery failed: %v", err)
+	}
+
+	publicKey := pub.Bytes()
+	if header < account.BIP137P2PKH {
+		uncompressed, _ := pub.Uncompressed()
+		publicKey = uncompressed.Bytes()
+		encoders = encoders[:1]
+	}
+	for _, encode := range encoders {
+		if candidate, err := encode(publicKey); err == nil && candidate == addr {
+			return valid(chain, addr, publicKey)
+		}
+	}
+	return invalid(chain, addr, "signed by another key")
+}
+
+// Cosmos verifies an ADR-36 signature, given as Base64 r || s, for a
+// Bech32 account address of any Cosmos chain. The public key is recovered
+// from the signature, so the pub_key Keplr returns alongside it is not needed.
+func Cosmos(addr string, message []byte, signature string) Result {
+	chain := address.ChainCosmos
+	hrp, _, _, err := address.Bech32Decode(addr)
+	if err != nil {
+		return invalid(chain, addr, "address is not Bech32")
+	}
+	for id, c := range address.CosmosBasedChains() {
+		if c.HRP() == hrp {
+			chain = id
+			break
+		}
+	}
+
+	raw, err := base64.StdEncoding.DecodeString(signature)
+	if err != nil {
+		return invalid(chain, addr, "signature is not Base64")
+	}
+	encoder := address.NewCosmosAddressWithHRP(hrp, chain)
+	digest := address.SHA256Hash(account.ADR36SignDoc(addr, message))
+	for id := byte(0); id < 4; id++ {
+		sig, err := keys.NewRecoverableSignature(raw, id)
+		if err != nil {
+			return invalid(chain, addr, "%v", err)
+		}
+		pub, err := sig.RecoverPublicKey(digest)
+		if err != nil {
+			continue
+		}
+		if candidate, err := encoder.Generate(pub.Bytes()); err == nil && candidate == addr {
+			return valid(chain, addr, pub.Bytes())
+		}
+	}
+	return invalid(chain, addr, "signed by another key")
+}
+
+// Solana verifies a Base58 Ed25519 signature of message wrapped as an
+// off-chain message, as SignMessage produces. Wallets' signMessage signs the
+// bare bytes instead, so a signature of the raw message is accepted too; the
+// two cannot collide because an off-chain message is not valid UTF-8.
+func Solana(addr string, message []byte, signature string) Result {
+	chain := address.ChainSolana
+	publicKey, err := address.Base58Decode(addr)
+	if err != nil || len(publicKey) != ed25519.PublicKeySize {
+		return invalid(chain, addr, "address is not a Base58 Ed25519 key")
+	}
+	sig, err := address.Base58Decode(signature)
+	if err != nil || len(sig) != keys.SignatureSize {
+		return invalid(chain, addr, "signature is not 64 bytes of Base58")
+	}
+	if payload, err := account.SolanaOffchainMessage(message); err == nil && ed25519.Verify(publicKey, payload, sig) {
+		return valid(chain, addr, publicKey)
+	}
+	if ed25519.Verify(publicKey, message, sig) {
+		return valid(chain, addr, publicKey)
+	}
+	return invalid(chain, addr, "signature does not match")
+}
+
+// Sui verifies a serialized Sui personal message signature: Base64 of the
+// scheme flag, the signature and the public key. Only Ed25519 is supported.
+func Sui(addr string, message []byte, signature string) Result {
+	chain := address.ChainSui
+	raw, err := base64.StdEncoding.DecodeString(signature)
+	if err != nil || len(raw) != 1+keys.SignatureSize+ed25519.PublicKeySize {
+		return invalid(chain, addr, "signature is not a serialized Ed25519 signature")
+	}
+	if raw[0] != address.SuiEd25519Flag {
+		return invalid(chain, addr, "unsupported signature scheme 0x%02x", raw[0])
+	}
+	sig, publicKey := raw[1:1+keys.SignatureSize], raw[1+keys.SignatureSize:]
+
+	owner, err := address.NewSuiAddress().Generate(publicKey)
+	if err != nil || !strings.EqualFold(owner, addr) {
+		return invalid(chain, addr, "public key belongs to %s", owner)
+	}
+	if !ed25519.Verify(publicKey, account.SuiPersonalMessageDigest(message), sig) {
+		return invalid(chain, addr, "signature does not match")
+	}
+	return valid(chain, addr, publicKey)
+}
@@ -0,0 +1,81 @@
+package verify
+
+import (
+	"errors"
+	"testing"
+
+	"github.com/study/crypto-accounts/pkgs/account"
+	"github.com/study/crypto-accounts/pkgs/address"
+)
+
+const testMnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
+
+func TestVerify(t *testing.T) {
+	message := []byte("hello world")
+	evm, _ := account.NewEVMAccountFromMnemonic(testMnemonic, "", address.ChainEthereum, 0)
+	segwit, _ := account.NewBitcoinAccountFromMnemonic(testMnemonic, "", account.ScriptP2WPKH, 0, 0)
+	nested, _ := account.NewBitcoinAccountFromMnemonic(testMnemonic, "", account.ScriptP2SHP2WPKH, 0, 0)
+	legacy, _ := account.NewBitcoinAccountFromMnemonic(testMnemonic, "", account.ScriptP2PKH, 0, 0)
+	cosmos, _ := account.NewCosmosAccountFromMnemonic(testMnemonic, "", 0)
+	sui, _ := account.NewSuiAccountFromMnemonic(testMnemonic, "", 0)
+	sol, _ := account.NewSolanaAccountFromMnemonic(testMnemonic, "", 0)
+
+	for _, tt := range []struct {
+		account account.SigningAccount
+		verify  func(addr string, message []byte, signature string) Result
+	}{
+		{evm, EVM},
+		{segwit, Bitcoin},
+		{nested, Bitcoin},
+		{legacy, Bitcoin},
+		{cosmos, Cosmos},
+		{sui, Sui},
+		{sol, Solana},
+	} {
+		sig, err := account.SignMessage(tt.account, message)
+		if err != nil {
+			t.Fatalf("SignMessage(%s) error = %v", tt.account.Chain(), err)
+		}
+
+		r := tt.verify(tt.account.Address(), message, sig)
+		if !r.Valid || r.Chain != tt.account.Chain() || r.PublicKey == "" || r.Err() != nil {
+			t.Errorf("verify %s %s = %+v", tt.account.Chain(), tt.account.Address(), r)
+		}
+		if r := tt.verify(tt.account.Address(), []byte("hello world!"), sig); r.Valid || !errors.Is(r.Err(), ErrInvalid) {
+			t.Errorf("verify %s with another message = %+v", tt.account.Chain(), r)
+		}
+	}
+
+	// Another account's address must not verify
+	sig, _ := account.SignMessage(evm, message)
+	other, _ := account.NewEVMAccountFromMnemonic(testMnemonic, "", address.ChainEthereum, 1)
+	if r := EVM(other.Address(), message, sig); r.Valid {
+		t.Errorf("EVM(other address) = %+v", r)
+	}
+	if r := Bitcoin(segwit.Address(), message, "not base64"); r.Valid || r.Reason == "" {
+		t.Errorf("Bitcoin(malformed) = %+v", r)
+	}
+}
+
+func TestVerifyVectors(t *testing.T) {
+	message := []byte("hello world")
+	for _, tt := range []struct {
+		name   string
+		verify func(addr string, message []byte, signature string) Result
+		addr   string
+		sig    string
+	}{
+		{"evm", EVM, "0x9858EfFD232B4033E47d90003D41EC34EcaEda94",
+			"0xae35d9375b015664a7b115a63a4515142b68059b164dd187e0b5232d47ca69685104d05d1c6c58b1fe5842f28459e2ea5bd571c0196f10da25fd2140eeef47e51c"},
+		{"evm lowercase", EVM, "0x9858effd232b4033e47d90003d41ec34ecaeda94",
+			"ae35d9375b015664a7b115a63a4515142b68059b164dd187e0b5232d47ca69685104d05d1c6c58b1fe5842f28459e2ea5bd571c0196f10da25fd2140eeef47e51c"},
+		{"bitcoin", Bitcoin, "bc1qcr8te4kr609gcawutmrza0j4xv80jy8z306fyu",
+			"J18BiThkjafFLKfWaBanx1KmmnDfOg4R2FPIdVrFZ1bxK7gyRFRrNmD0L6XSXtSf/doM1j5Xv4FGcxIdbq2e4fU="},
+		{"cosmos", Cosmos, "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4",
+			"KFJQYf1nRW8ZeO8xtMyoXNzR2GyWcExHxZ1j6VIuAft9fNcciAmpWWgOJt/0+Pwl//s2t0w+dqXU4P8xPN/5Nw=="},
+	} {
+		if r := tt.verify(tt.addr, message, tt.sig); !r.Valid {
+			t.Errorf("%s: %+v", tt.name, r)
+		}
+	}
+}